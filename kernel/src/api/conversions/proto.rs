@@ -4,7 +4,10 @@
  */
 
 use crate::api::server::grpc_server::kernel_proto::*;
-use crate::syscalls::Syscall;
+use crate::core::limits::{NETWORK_RETRY_BASE_DELAY_MS, NETWORK_RETRY_MAX_DELAY_MS};
+use crate::memory::{OOM_SCORE_ADJ_MAX, OOM_SCORE_ADJ_MIN};
+use crate::scheduler::{MAX_NICE, MIN_NICE};
+use crate::syscalls::{PollFd, Syscall};
 use std::path::PathBuf;
 
 /// Convert protobuf SyscallRequest to internal Syscall enum
@@ -23,6 +26,22 @@ pub fn proto_to_syscall_full(req: &SyscallRequest) -> Result<Syscall, String> {
             path: PathBuf::from(call.path.clone()),
             data: call.data.clone(),
         }),
+        Some(syscall_request::Syscall::AppendFile(call)) => Ok(Syscall::AppendFile {
+            path: PathBuf::from(call.path.clone()),
+            data: call.data.clone(),
+        }),
+        Some(syscall_request::Syscall::ReadFileVectored(call)) => Ok(Syscall::ReadFileVectored {
+            path: PathBuf::from(call.path.clone()),
+            sizes: call
+                .sizes
+                .iter()
+                .map(|&size| usize::try_from(size).unwrap_or(usize::MAX))
+                .collect(),
+        }),
+        Some(syscall_request::Syscall::WriteFileVectored(call)) => Ok(Syscall::WriteFileVectored {
+            path: PathBuf::from(call.path.clone()),
+            segments: call.segments.clone(),
+        }),
         Some(syscall_request::Syscall::CreateFile(call)) => Ok(Syscall::CreateFile {
             path: PathBuf::from(call.path.clone()),
         }),
@@ -38,9 +57,14 @@ pub fn proto_to_syscall_full(req: &SyscallRequest) -> Result<Syscall, String> {
         Some(syscall_request::Syscall::FileStat(call)) => Ok(Syscall::FileStat {
             path: PathBuf::from(call.path.clone()),
         }),
+        Some(syscall_request::Syscall::HashFile(call)) => Ok(Syscall::HashFile {
+            path: PathBuf::from(call.path.clone()),
+            algorithm: call.algorithm.clone(),
+        }),
         Some(syscall_request::Syscall::MoveFile(call)) => Ok(Syscall::MoveFile {
             source: PathBuf::from(call.source.clone()),
             destination: PathBuf::from(call.destination.clone()),
+            noreplace: call.noreplace,
         }),
         Some(syscall_request::Syscall::CopyFile(call)) => Ok(Syscall::CopyFile {
             source: PathBuf::from(call.source.clone()),
@@ -53,6 +77,7 @@ pub fn proto_to_syscall_full(req: &SyscallRequest) -> Result<Syscall, String> {
             path: PathBuf::from(call.path.clone()),
         }),
         Some(syscall_request::Syscall::GetWorkingDirectory(_)) => Ok(Syscall::GetWorkingDirectory),
+        Some(syscall_request::Syscall::ListMounts(_)) => Ok(Syscall::ListMounts),
         Some(syscall_request::Syscall::SetWorkingDirectory(call)) => {
             Ok(Syscall::SetWorkingDirectory {
                 path: PathBuf::from(call.path.clone()),
@@ -73,12 +98,31 @@ pub fn proto_to_syscall_full(req: &SyscallRequest) -> Result<Syscall, String> {
             target_pid: call.target_pid,
         }),
         Some(syscall_request::Syscall::GetProcessList(_)) => Ok(Syscall::GetProcessList),
+        Some(syscall_request::Syscall::GetProcessChildren(call)) => {
+            Ok(Syscall::GetProcessChildren {
+                target_pid: call.target_pid,
+            })
+        }
         Some(syscall_request::Syscall::SetProcessPriority(call)) => {
             Ok(Syscall::SetProcessPriority {
                 target_pid: call.target_pid,
                 priority: call.priority as u8,
             })
         }
+        Some(syscall_request::Syscall::GetProcessNice(call)) => Ok(Syscall::GetProcessNice {
+            target_pid: call.target_pid,
+        }),
+        Some(syscall_request::Syscall::SetProcessNice(call)) => Ok(Syscall::SetProcessNice {
+            target_pid: call.target_pid,
+            nice: call.nice.clamp(MIN_NICE as i32, MAX_NICE as i32) as i8,
+        }),
+        Some(syscall_request::Syscall::SetOomScore(call)) => Ok(Syscall::SetOomScore {
+            target_pid: call.target_pid,
+            score: call
+                .score
+                .clamp(OOM_SCORE_ADJ_MIN as i32, OOM_SCORE_ADJ_MAX as i32)
+                as i16,
+        }),
         Some(syscall_request::Syscall::GetProcessState(call)) => Ok(Syscall::GetProcessState {
             target_pid: call.target_pid,
         }),
@@ -98,19 +142,33 @@ pub fn proto_to_syscall_full(req: &SyscallRequest) -> Result<Syscall, String> {
             key: call.key.clone(),
             value: call.value.clone(),
         }),
+        Some(syscall_request::Syscall::GetRandom(call)) => Ok(Syscall::GetRandom {
+            len: call.len as usize,
+            flags: call.flags,
+        }),
         Some(syscall_request::Syscall::Sleep(call)) => Ok(Syscall::Sleep {
             duration_ms: call.duration_ms,
         }),
         Some(syscall_request::Syscall::GetUptime(_)) => Ok(Syscall::GetUptime),
+        Some(syscall_request::Syscall::TimerfdCreate(_)) => Ok(Syscall::TimerfdCreate),
+        Some(syscall_request::Syscall::TimerfdSet(call)) => Ok(Syscall::TimerfdSet {
+            fd: call.fd,
+            duration_ms: call.duration_ms,
+            interval_ms: call.interval_ms,
+        }),
         Some(syscall_request::Syscall::GetMemoryStats(_)) => Ok(Syscall::GetMemoryStats),
         Some(syscall_request::Syscall::GetProcessMemoryStats(call)) => {
             Ok(Syscall::GetProcessMemoryStats {
                 target_pid: call.target_pid,
             })
         }
+        Some(syscall_request::Syscall::GetMemoryMaps(call)) => Ok(Syscall::GetMemoryMaps {
+            target_pid: call.target_pid,
+        }),
         Some(syscall_request::Syscall::TriggerGc(call)) => Ok(Syscall::TriggerGC {
             target_pid: call.target_pid,
         }),
+        Some(syscall_request::Syscall::CompactMemory(_)) => Ok(Syscall::CompactMemory),
         Some(syscall_request::Syscall::SendSignal(call)) => Ok(Syscall::SendSignal {
             target_pid: call.target_pid,
             signal: call.signal,
@@ -136,8 +194,12 @@ pub fn proto_to_syscall_full(req: &SyscallRequest) -> Result<Syscall, String> {
         Some(syscall_request::Syscall::GetSignalState(call)) => Ok(Syscall::GetSignalState {
             target_pid: call.target_pid,
         }),
+        Some(syscall_request::Syscall::SignalfdCreate(call)) => Ok(Syscall::SignalfdCreate {
+            signals: call.signals.clone(),
+        }),
         Some(syscall_request::Syscall::NetworkRequest(call)) => Ok(Syscall::NetworkRequest {
             url: call.url.clone(),
+            retry: call.retry.as_ref().map(retry_policy_from_proto),
         }),
         Some(syscall_request::Syscall::Socket(call)) => Ok(Syscall::Socket {
             domain: call.domain,
@@ -200,6 +262,17 @@ pub fn proto_to_syscall_full(req: &SyscallRequest) -> Result<Syscall, String> {
             mode: call.mode,
         }),
         Some(syscall_request::Syscall::Close(call)) => Ok(Syscall::Close { fd: call.fd }),
+        Some(syscall_request::Syscall::Read(call)) => Ok(Syscall::Read {
+            fd: call.fd,
+            count: call.count as usize,
+        }),
+        Some(syscall_request::Syscall::Fsync(call)) => Ok(Syscall::Fsync { fd: call.fd }),
+        Some(syscall_request::Syscall::Sendfile(call)) => Ok(Syscall::Sendfile {
+            out_fd: call.out_fd,
+            in_fd: call.in_fd,
+            offset: call.offset,
+            count: call.count as usize,
+        }),
         Some(syscall_request::Syscall::Dup(call)) => Ok(Syscall::Dup { fd: call.fd }),
         Some(syscall_request::Syscall::Dup2(call)) => Ok(Syscall::Dup2 {
             oldfd: call.oldfd,
@@ -214,6 +287,15 @@ pub fn proto_to_syscall_full(req: &SyscallRequest) -> Result<Syscall, String> {
             fd: call.fd,
             cmd: call.cmd,
             arg: call.arg,
+            lock: None,
+        }),
+        Some(syscall_request::Syscall::Poll(call)) => Ok(Syscall::Poll {
+            fds: call
+                .fds
+                .iter()
+                .map(|entry| PollFd::new(entry.fd, entry.events as u16))
+                .collect(),
+            timeout_ms: call.timeout_ms,
         }),
         Some(syscall_request::Syscall::CreatePipe(call)) => Ok(Syscall::CreatePipe {
             reader_pid: call.reader_pid,
@@ -223,10 +305,12 @@ pub fn proto_to_syscall_full(req: &SyscallRequest) -> Result<Syscall, String> {
         Some(syscall_request::Syscall::WritePipe(call)) => Ok(Syscall::WritePipe {
             pipe_id: call.pipe_id,
             data: call.data.clone(),
+            partial: call.partial,
         }),
         Some(syscall_request::Syscall::ReadPipe(call)) => Ok(Syscall::ReadPipe {
             pipe_id: call.pipe_id,
             size: call.size as usize,
+            nonblocking: call.nonblocking,
         }),
         Some(syscall_request::Syscall::ClosePipe(call)) => Ok(Syscall::ClosePipe {
             pipe_id: call.pipe_id,
@@ -237,6 +321,9 @@ pub fn proto_to_syscall_full(req: &SyscallRequest) -> Result<Syscall, String> {
         Some(syscall_request::Syscall::PipeStats(call)) => Ok(Syscall::PipeStats {
             pipe_id: call.pipe_id,
         }),
+        Some(syscall_request::Syscall::PipeFd(call)) => Ok(Syscall::PipeFd {
+            pipe_id: call.pipe_id,
+        }),
         Some(syscall_request::Syscall::CreateShm(call)) => Ok(Syscall::CreateShm {
             size: call.size as usize,
         }),
@@ -257,6 +344,10 @@ pub fn proto_to_syscall_full(req: &SyscallRequest) -> Result<Syscall, String> {
             offset: call.offset as usize,
             size: call.size as usize,
         }),
+        Some(syscall_request::Syscall::ResizeShm(call)) => Ok(Syscall::ResizeShm {
+            segment_id: call.segment_id,
+            new_size: call.new_size as usize,
+        }),
         Some(syscall_request::Syscall::DestroyShm(call)) => Ok(Syscall::DestroyShm {
             segment_id: call.segment_id,
         }),
@@ -269,6 +360,7 @@ pub fn proto_to_syscall_full(req: &SyscallRequest) -> Result<Syscall, String> {
             length: call.length as usize,
             prot: call.prot as u8,
             shared: call.shared,
+            populate: call.populate,
         }),
         Some(syscall_request::Syscall::MmapRead(call)) => Ok(Syscall::MmapRead {
             mmap_id: call.mmap_id,
@@ -300,6 +392,7 @@ pub fn proto_to_syscall_full(req: &SyscallRequest) -> Result<Syscall, String> {
         }),
         Some(syscall_request::Syscall::ReceiveQueue(call)) => Ok(Syscall::ReceiveQueue {
             queue_id: call.queue_id,
+            nonblocking: call.nonblocking,
         }),
         Some(syscall_request::Syscall::SubscribeQueue(call)) => Ok(Syscall::SubscribeQueue {
             queue_id: call.queue_id,
@@ -312,10 +405,23 @@ pub fn proto_to_syscall_full(req: &SyscallRequest) -> Result<Syscall, String> {
         }),
         Some(syscall_request::Syscall::DestroyQueue(call)) => Ok(Syscall::DestroyQueue {
             queue_id: call.queue_id,
+            drain_timeout_ms: call.drain_timeout_ms,
         }),
         Some(syscall_request::Syscall::QueueStats(call)) => Ok(Syscall::QueueStats {
             queue_id: call.queue_id,
         }),
+        Some(syscall_request::Syscall::AckQueue(call)) => Ok(Syscall::AckQueue {
+            queue_id: call.queue_id,
+            msg_id: call.msg_id,
+        }),
+        Some(syscall_request::Syscall::SetQueueAckPolicy(call)) => Ok(Syscall::SetQueueAckPolicy {
+            queue_id: call.queue_id,
+            visibility_timeout_ms: call.visibility_timeout_ms,
+            max_attempts: call.max_attempts,
+        }),
+        Some(syscall_request::Syscall::TakeDeadLetters(call)) => Ok(Syscall::TakeDeadLetters {
+            queue_id: call.queue_id,
+        }),
         Some(syscall_request::Syscall::ScheduleNext(_)) => Ok(Syscall::ScheduleNext),
         Some(syscall_request::Syscall::YieldProcess(_)) => Ok(Syscall::YieldProcess),
         Some(syscall_request::Syscall::GetCurrentScheduled(_)) => Ok(Syscall::GetCurrentScheduled),
@@ -344,6 +450,12 @@ pub fn proto_to_syscall_full(req: &SyscallRequest) -> Result<Syscall, String> {
         Some(syscall_request::Syscall::LowerPriority(call)) => Ok(Syscall::LowerPriority {
             target_pid: call.target_pid,
         }),
+        Some(syscall_request::Syscall::PauseProcess(call)) => Ok(Syscall::PauseProcess {
+            target_pid: call.target_pid,
+        }),
+        Some(syscall_request::Syscall::ResumeProcess(call)) => Ok(Syscall::ResumeProcess {
+            target_pid: call.target_pid,
+        }),
         None => Err("No syscall provided".to_string().into()),
     }
 }
@@ -379,3 +491,144 @@ pub fn proto_to_syscall_simple(req: &SyscallRequest) -> Result<Syscall, String>
         _ => Err("Unsupported syscall for async/batch".to_string().into()),
     }
 }
+
+/// Convert a protobuf RetryPolicy; unset delays take the kernel defaults and
+/// the syscall layer clamps the rest
+fn retry_policy_from_proto(policy: &RetryPolicy) -> crate::syscalls::RetryPolicy {
+    crate::syscalls::RetryPolicy {
+        max_retries: policy.max_retries,
+        base_delay_ms: policy.base_delay_ms.unwrap_or(NETWORK_RETRY_BASE_DELAY_MS),
+        max_delay_ms: policy.max_delay_ms.unwrap_or(NETWORK_RETRY_MAX_DELAY_MS),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn convert(syscall: syscall_request::Syscall) -> Syscall {
+        proto_to_syscall_full(&SyscallRequest {
+            pid: 1,
+            syscall: Some(syscall),
+        })
+        .unwrap()
+    }
+
+    #[test]
+    fn test_partial_pipe_writes_are_mapped() {
+        let write = convert(syscall_request::Syscall::WritePipe(WritePipeCall {
+            pipe_id: 3,
+            data: b"abc".to_vec(),
+            partial: true,
+        }));
+        assert!(matches!(write, Syscall::WritePipe { partial: true, .. }));
+    }
+
+    #[test]
+    fn test_nonblocking_reads_are_mapped() {
+        let read = convert(syscall_request::Syscall::ReadPipe(ReadPipeCall {
+            pipe_id: 3,
+            size: 16,
+            nonblocking: true,
+        }));
+        assert!(matches!(
+            read,
+            Syscall::ReadPipe {
+                nonblocking: true,
+                ..
+            }
+        ));
+
+        let receive = convert(syscall_request::Syscall::ReceiveQueue(ReceiveQueueCall {
+            queue_id: 3,
+            nonblocking: true,
+        }));
+        assert!(matches!(
+            receive,
+            Syscall::ReceiveQueue {
+                nonblocking: true,
+                ..
+            }
+        ));
+    }
+
+    #[test]
+    fn test_nice_and_oom_score_are_clamped() {
+        let nice = convert(syscall_request::Syscall::SetProcessNice(
+            SetProcessNiceCall {
+                target_pid: 5,
+                nice: -300,
+            },
+        ));
+        assert!(matches!(
+            nice,
+            Syscall::SetProcessNice {
+                target_pid: 5,
+                nice: MIN_NICE
+            }
+        ));
+
+        let score = convert(syscall_request::Syscall::SetOomScore(SetOomScoreCall {
+            target_pid: 5,
+            score: 70_000,
+        }));
+        assert!(matches!(
+            score,
+            Syscall::SetOomScore {
+                target_pid: 5,
+                score: OOM_SCORE_ADJ_MAX
+            }
+        ));
+    }
+
+    #[test]
+    fn test_vectored_file_calls_are_mapped() {
+        let read = convert(syscall_request::Syscall::ReadFileVectored(
+            ReadFileVectoredCall {
+                path: "/tmp/v.txt".to_string(),
+                sizes: vec![4, 8],
+            },
+        ));
+        assert!(matches!(read, Syscall::ReadFileVectored { ref sizes, .. } if sizes == &[4, 8]));
+
+        let write = convert(syscall_request::Syscall::WriteFileVectored(
+            WriteFileVectoredCall {
+                path: "/tmp/v.txt".to_string(),
+                segments: vec![b"ab".to_vec(), b"cd".to_vec()],
+            },
+        ));
+        assert!(
+            matches!(write, Syscall::WriteFileVectored { ref segments, .. } if segments.len() == 2)
+        );
+    }
+
+    #[test]
+    fn test_poll_fds_are_mapped() {
+        let poll = convert(syscall_request::Syscall::Poll(PollCall {
+            fds: vec![PollDescriptor {
+                fd: 4,
+                events: u32::from(PollFd::POLLIN),
+            }],
+            timeout_ms: None,
+        }));
+        assert!(matches!(
+            poll,
+            Syscall::Poll { ref fds, timeout_ms: None } if fds == &[PollFd::new(4, PollFd::POLLIN)]
+        ));
+    }
+
+    #[test]
+    fn test_drain_timeout_is_mapped() {
+        let destroy = convert(syscall_request::Syscall::DestroyQueue(DestroyQueueCall {
+            queue_id: 3,
+            drain_timeout_ms: Some(250),
+        }));
+        assert!(matches!(
+            destroy,
+            Syscall::DestroyQueue {
+                drain_timeout_ms: Some(250),
+                ..
+            }
+        ));
+    }
+}
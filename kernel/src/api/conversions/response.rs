@@ -18,14 +18,16 @@ pub fn syscall_result_to_proto(result: SyscallResult) -> SyscallResponse {
                 .into(),
             ),
         },
-        SyscallResult::Error { message } => SyscallResponse {
-            result: Some(
-                syscall_response::Result::Error(ErrorResult {
+        SyscallResult::Error { message, code } => {
+            let code = code.unwrap_or_default();
+            SyscallResponse {
+                result: Some(syscall_response::Result::Error(ErrorResult {
                     message: message.to_string(),
-                })
-                .into(),
-            ),
-        },
+                    code: code.code,
+                    category: code.category.name().to_string(),
+                })),
+            }
+        }
         SyscallResult::PermissionDenied { reason } => SyscallResponse {
             result: Some(
                 syscall_response::Result::PermissionDenied(PermissionDeniedResult {
@@ -55,3 +57,35 @@ pub fn proto_to_sandbox_capability(cap: Capability) -> SandboxCapability {
         Capability::ReceiveMessage => SandboxCapability::ReceiveMessage,
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::errors::ErrorCategory;
+    use crate::ipc::types::IpcError;
+    use crate::ipc::{PipeManager, ShmManager};
+    use crate::memory::MemoryManager;
+    use crate::security::traits::SandboxProvider;
+    use crate::security::{SandboxConfig, SandboxManager};
+    use crate::syscalls::{Syscall, SyscallExecutorWithIpc};
+
+    #[test]
+    fn test_typed_error_code_reaches_proto() {
+        let memory_manager = MemoryManager::new();
+        let sandbox_manager = SandboxManager::new();
+        let executor = SyscallExecutorWithIpc::with_ipc_direct(
+            sandbox_manager.clone(),
+            PipeManager::new(memory_manager.clone()),
+            ShmManager::new(memory_manager),
+        );
+        sandbox_manager.create_sandbox(SandboxConfig::privileged(1));
+
+        let result = executor.execute(1, Syscall::DestroyPipe { pipe_id: 42 });
+        let Some(syscall_response::Result::Error(error)) = syscall_result_to_proto(result).result
+        else {
+            panic!("expected an error response");
+        };
+        assert_eq!(error.code, IpcError::NOT_FOUND.code);
+        assert_eq!(error.category, ErrorCategory::NotFound.name());
+    }
+}
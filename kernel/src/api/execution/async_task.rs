@@ -220,6 +220,7 @@ pub fn submit(&self, pid: Pid, syscall: Syscall) -> String {
                             // Cancellation channel was dropped unexpectedly
                             SyscallResult::Error {
                                 message: "Cancellation channel dropped unexpectedly".into(),
+                                code: None,
                             }
                         }
                     }
@@ -227,6 +228,7 @@ pub fn submit(&self, pid: Pid, syscall: Syscall) -> String {
                 result = tokio::task::spawn_blocking(move || executor.execute(pid, syscall)) => {
                     result.unwrap_or_else(|e| SyscallResult::Error {
                         message: format!("Task panic: {}", e).into(),
+                        code: None,
                     })
                 }
             };
@@ -467,11 +469,13 @@ pub fn submit_with_guard(
                             }
                             return SyscallResult::Error {
                                 message: "Task cancelled".into(),
+                                code: None,
                             };
                         }
                         Err(_) => {
                             SyscallResult::Error {
                                 message: "Cancellation channel dropped unexpectedly".into(),
+                                code: None,
                             }
                         }
                     }
@@ -479,6 +483,7 @@ pub fn submit_with_guard(
                 result = tokio::task::spawn_blocking(move || executor.execute(pid, syscall)) => {
                     result.unwrap_or_else(|e| SyscallResult::Error {
                         message: format!("Task panic: {}", e).into(),
+                        code: None,
                     })
                 }
             };
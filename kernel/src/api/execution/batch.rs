@@ -3,18 +3,208 @@
  * Executes multiple syscalls efficiently in parallel or sequence
  */
 
+use crate::core::limits::{
+    BATCH_MAX_CONCURRENCY, BATCH_MAX_SIZE, BATCH_MAX_WINDOW_US, BATCH_MIN_SIZE,
+};
 use crate::core::types::Pid;
-use crate::syscalls::{Syscall, SyscallExecutorWithIpc, SyscallResult};
-use futures::future::join_all;
+use crate::syscalls::{Syscall, SyscallClass, SyscallExecutorWithIpc, SyscallResult};
+use log::debug;
+use parking_lot::Mutex;
+use std::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::{mpsc, oneshot, Semaphore};
+
+/// Bounds for adaptive batching of submitted syscalls
+#[derive(Debug, Clone)]
+pub struct AdaptiveBatchConfig {
+    pub min_batch_size: usize,
+    pub max_batch_size: usize,
+    /// Collection window used when the queue is idle (zero = dispatch immediately)
+    pub min_window: Duration,
+    /// Longest a batch waits to fill once the queue is backed up
+    pub max_window: Duration,
+}
+
+impl Default for AdaptiveBatchConfig {
+    fn default() -> Self {
+        Self {
+            min_batch_size: BATCH_MIN_SIZE,
+            max_batch_size: BATCH_MAX_SIZE,
+            min_window: Duration::ZERO,
+            max_window: Duration::from_micros(BATCH_MAX_WINDOW_US),
+        }
+    }
+}
+
+/// Adaptive batching statistics
+#[derive(Debug, Default, Clone)]
+pub struct BatchStats {
+    /// Current effective batch size
+    pub batch_size: usize,
+    /// Current effective collection window
+    pub window: Duration,
+    /// Submissions waiting to be batched
+    pub queue_depth: usize,
+    pub batches_executed: u64,
+    pub syscalls_executed: u64,
+    /// Most blocking syscalls allowed in flight at once
+    pub concurrency_limit: usize,
+    /// Blocking syscalls currently in flight
+    pub in_flight: usize,
+}
+
+/// Grows the batch window under backlog and shrinks it toward zero when idle
+///
+/// Only the worker task updates the controller; atomics let stats read it
+/// without locking.
+#[derive(Debug)]
+struct AdaptiveController {
+    min_batch_size: usize,
+    max_batch_size: usize,
+    min_window_us: u64,
+    max_window_us: u64,
+    batch_size: AtomicUsize,
+    window_us: AtomicU64,
+}
+
+impl AdaptiveController {
+    fn new(config: &AdaptiveBatchConfig) -> Self {
+        let min_batch_size = config.min_batch_size.max(1);
+        let max_batch_size = config.max_batch_size.max(min_batch_size);
+        let min_window_us = config.min_window.as_micros() as u64;
+        let max_window_us = (config.max_window.as_micros() as u64).max(min_window_us);
+
+        Self {
+            min_batch_size,
+            max_batch_size,
+            min_window_us,
+            max_window_us,
+            batch_size: AtomicUsize::new(min_batch_size),
+            window_us: AtomicU64::new(min_window_us),
+        }
+    }
+
+    fn batch_size(&self) -> usize {
+        self.batch_size.load(Ordering::Relaxed)
+    }
+
+    fn window(&self) -> Duration {
+        Duration::from_micros(self.window_us.load(Ordering::Relaxed))
+    }
+
+    /// Smallest non-minimum window, so growth from zero makes progress
+    fn window_step_us(&self) -> u64 {
+        ((self.max_window_us - self.min_window_us) / 8).max(1)
+    }
+
+    /// Adjust after collecting `collected` submissions with `remaining` still queued
+    fn observe(&self, collected: usize, remaining: usize) {
+        let size = self.batch_size();
+        let window_us = self.window_us.load(Ordering::Relaxed);
+        let step_us = self.window_step_us();
+
+        if remaining > 0 {
+            // Backlog: larger batches amortize dispatch overhead
+            let size = (size * 2).min(self.max_batch_size);
+            let window_us = (window_us * 2)
+                .max(self.min_window_us + step_us)
+                .min(self.max_window_us);
+            self.batch_size.store(size, Ordering::Relaxed);
+            self.window_us.store(window_us, Ordering::Relaxed);
+        } else if collected < size || collected <= self.min_batch_size {
+            // Queue drained before the batch filled (or traffic is trickling in
+            // one at a time): stop waiting for stragglers
+            let size = (size / 2).max(self.min_batch_size);
+            let window_us = if window_us / 2 < self.min_window_us + step_us {
+                self.min_window_us
+            } else {
+                window_us / 2
+            };
+            self.batch_size.store(size, Ordering::Relaxed);
+            self.window_us.store(window_us, Ordering::Relaxed);
+        }
+    }
+}
+
+/// A syscall waiting for the batch worker
+struct Submission {
+    pid: Pid,
+    syscall: Syscall,
+    reply: oneshot::Sender<SyscallResult>,
+}
+
+/// State shared between BatchExecutor handles and the batch worker
+struct SubmissionQueue {
+    controller: AdaptiveController,
+    depth: AtomicUsize,
+    batches_executed: AtomicU64,
+    syscalls_executed: AtomicU64,
+    // Taken by the worker when it starts on first submission
+    receiver: Mutex<Option<mpsc::UnboundedReceiver<Submission>>>,
+}
+
+/// Bounds how many blocking syscalls run at once
+///
+/// Shared by every parallel batch of one BatchExecutor, including adaptive
+/// submission batches, so concurrent batches can't add up past the limit.
+#[derive(Debug)]
+struct ConcurrencyLimit {
+    limit: usize,
+    permits: Arc<Semaphore>,
+}
+
+impl ConcurrencyLimit {
+    fn new(limit: usize) -> Self {
+        let limit = limit.max(1);
+        Self {
+            limit,
+            permits: Arc::new(Semaphore::new(limit)),
+        }
+    }
+
+    fn in_flight(&self) -> usize {
+        self.limit - self.permits.available_permits()
+    }
+}
 
 #[derive(Clone)]
 pub struct BatchExecutor {
     executor: SyscallExecutorWithIpc,
+    submissions: mpsc::UnboundedSender<Submission>,
+    queue: Arc<SubmissionQueue>,
+    concurrency: Arc<ConcurrencyLimit>,
 }
 
 impl BatchExecutor {
     pub fn new(executor: SyscallExecutorWithIpc) -> Self {
-        Self { executor }
+        Self::with_config(executor, AdaptiveBatchConfig::default())
+    }
+
+    /// Create with custom adaptive batching bounds
+    pub fn with_config(executor: SyscallExecutorWithIpc, config: AdaptiveBatchConfig) -> Self {
+        let (submissions, receiver) = mpsc::unbounded_channel();
+        Self {
+            executor,
+            submissions,
+            queue: Arc::new(SubmissionQueue {
+                controller: AdaptiveController::new(&config),
+                depth: AtomicUsize::new(0),
+                batches_executed: AtomicU64::new(0),
+                syscalls_executed: AtomicU64::new(0),
+                receiver: Mutex::new(Some(receiver)),
+            }),
+            concurrency: Arc::new(ConcurrencyLimit::new(BATCH_MAX_CONCURRENCY)),
+        }
+    }
+
+    /// Run at most `limit` blocking syscalls at once (minimum 1)
+    ///
+    /// Applies to parallel batches and adaptive submissions. Fast-path
+    /// syscalls run inline and are not counted.
+    pub fn with_concurrency_limit(mut self, limit: usize) -> Self {
+        self.concurrency = Arc::new(ConcurrencyLimit::new(limit));
+        self
     }
 
     pub async fn execute_batch(
@@ -29,24 +219,60 @@ pub async fn execute_batch(
         }
     }
 
-    async fn execute_parallel(&self, requests: Vec<(Pid, Syscall)>) -> Vec<SyscallResult> {
-        let count = requests.len();
-        let futures: Vec<_> = requests
-            .into_iter()
-            .map(|(pid, syscall)| {
-                let executor = self.executor.clone();
-                tokio::task::spawn_blocking(move || executor.execute(pid, syscall))
+    /// Submit a single syscall for adaptive batched execution
+    ///
+    /// Submissions are grouped with others queued at the same time and run in
+    /// parallel. When the queue is idle the syscall is dispatched immediately.
+    pub async fn submit(&self, pid: Pid, syscall: Syscall) -> SyscallResult {
+        self.ensure_worker();
+
+        let (reply, result) = oneshot::channel();
+        self.queue.depth.fetch_add(1, Ordering::AcqRel);
+        if self
+            .submissions
+            .send(Submission {
+                pid,
+                syscall,
+                reply,
             })
-            .collect();
+            .is_err()
+        {
+            self.queue.depth.fetch_sub(1, Ordering::AcqRel);
+            return SyscallResult::error("Batch worker stopped");
+        }
+
+        result
+            .await
+            .unwrap_or_else(|_| SyscallResult::error("Batch worker dropped submission"))
+    }
 
-        let results = join_all(futures).await;
-        let mut output = Vec::with_capacity(count);
-        for r in results {
-            output.push(r.unwrap_or_else(|e| SyscallResult::Error {
-                message: format!("Task error: {}", e).into(),
-            }));
+    /// Get adaptive batching statistics
+    pub fn stats(&self) -> BatchStats {
+        BatchStats {
+            batch_size: self.queue.controller.batch_size(),
+            window: self.queue.controller.window(),
+            queue_depth: self.queue.depth.load(Ordering::Acquire),
+            batches_executed: self.queue.batches_executed.load(Ordering::Relaxed),
+            syscalls_executed: self.queue.syscalls_executed.load(Ordering::Relaxed),
+            concurrency_limit: self.concurrency.limit,
+            in_flight: self.concurrency.in_flight(),
         }
-        output
+    }
+
+    /// Start the batch worker on first use (requires a Tokio runtime)
+    fn ensure_worker(&self) {
+        let Some(receiver) = self.queue.receiver.lock().take() else {
+            return;
+        };
+
+        let executor = self.executor.clone();
+        let queue = Arc::clone(&self.queue);
+        let permits = Arc::clone(&self.concurrency.permits);
+        tokio::spawn(run_worker(executor, queue, permits, receiver));
+    }
+
+    async fn execute_parallel(&self, requests: Vec<(Pid, Syscall)>) -> Vec<SyscallResult> {
+        run_parallel(&self.executor, requests, &self.concurrency.permits).await
     }
 
     async fn execute_sequential(&self, requests: Vec<(Pid, Syscall)>) -> Vec<SyscallResult> {
@@ -65,9 +291,231 @@ async fn execute_sequential(&self, requests: Vec<(Pid, Syscall)>) -> Vec<Syscall
                 .await
                 .unwrap_or_else(|e| SyscallResult::Error {
                     message: format!("Task error: {}", e).into(),
+                    code: None,
                 });
             results.push(result);
         }
         results
     }
 }
+
+async fn run_parallel(
+    executor: &SyscallExecutorWithIpc,
+    requests: Vec<(Pid, Syscall)>,
+    permits: &Arc<Semaphore>,
+) -> Vec<SyscallResult> {
+    let executor = executor.clone();
+    run_bounded(requests, permits, move |pid, syscall| {
+        executor.execute(pid, syscall)
+    })
+    .await
+}
+
+/// Run `requests` with at most `permits` blocking syscalls in flight
+///
+/// Fast-path syscalls run inline on the calling task; blocking ones are
+/// handed to the blocking pool as permits free up. Results come back in
+/// submission order.
+async fn run_bounded<F>(
+    requests: Vec<(Pid, Syscall)>,
+    permits: &Arc<Semaphore>,
+    execute: F,
+) -> Vec<SyscallResult>
+where
+    F: Fn(Pid, Syscall) -> SyscallResult + Clone + Send + 'static,
+{
+    let mut results: Vec<Option<SyscallResult>> = vec![None; requests.len()];
+    let mut running = Vec::new();
+
+    for (index, (pid, syscall)) in requests.into_iter().enumerate() {
+        if syscall.classify() == SyscallClass::Fast {
+            results[index] = Some(execute(pid, syscall));
+            continue;
+        }
+
+        let permit = match Arc::clone(permits).acquire_owned().await {
+            Ok(permit) => permit,
+            Err(_) => {
+                results[index] = Some(SyscallResult::error("Batch executor shut down"));
+                continue;
+            }
+        };
+        let execute = execute.clone();
+        let task = tokio::task::spawn_blocking(move || {
+            let _permit = permit;
+            execute(pid, syscall)
+        });
+        running.push((index, task));
+    }
+
+    for (index, task) in running {
+        results[index] = Some(task.await.unwrap_or_else(|e| SyscallResult::Error {
+            message: format!("Task error: {}", e).into(),
+            code: None,
+        }));
+    }
+
+    results.into_iter().flatten().collect()
+}
+
+/// Collect submissions into adaptively sized batches until all senders drop
+async fn run_worker(
+    executor: SyscallExecutorWithIpc,
+    queue: Arc<SubmissionQueue>,
+    permits: Arc<Semaphore>,
+    mut receiver: mpsc::UnboundedReceiver<Submission>,
+) {
+    while let Some(first) = receiver.recv().await {
+        let target = queue.controller.batch_size();
+        let window = queue.controller.window();
+        let mut batch = Vec::with_capacity(target);
+        batch.push(first);
+
+        if !window.is_zero() {
+            let deadline = tokio::time::Instant::now() + window;
+            while batch.len() < target {
+                match tokio::time::timeout_at(deadline, receiver.recv()).await {
+                    Ok(Some(submission)) => batch.push(submission),
+                    _ => break,
+                }
+            }
+        }
+        while batch.len() < target {
+            match receiver.try_recv() {
+                Ok(submission) => batch.push(submission),
+                Err(_) => break,
+            }
+        }
+
+        let collected = batch.len();
+        let remaining = queue.depth.fetch_sub(collected, Ordering::AcqRel) - collected;
+        queue.controller.observe(collected, remaining);
+        debug!(
+            "Executing syscall batch of {} (target {}, window {:?}, {} queued)",
+            collected, target, window, remaining
+        );
+
+        let (requests, replies): (Vec<_>, Vec<_>) = batch
+            .into_iter()
+            .map(|s| ((s.pid, s.syscall), s.reply))
+            .unzip();
+        let results = run_parallel(&executor, requests, &permits).await;
+
+        queue.batches_executed.fetch_add(1, Ordering::Relaxed);
+        queue
+            .syscalls_executed
+            .fetch_add(collected as u64, Ordering::Relaxed);
+
+        for (reply, result) in replies.into_iter().zip(results) {
+            // Submitter may have stopped waiting
+            let _ = reply.send(result);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn controller() -> AdaptiveController {
+        AdaptiveController::new(&AdaptiveBatchConfig {
+            min_batch_size: 1,
+            max_batch_size: 8,
+            min_window: Duration::ZERO,
+            max_window: Duration::from_micros(800),
+        })
+    }
+
+    #[test]
+    fn test_starts_at_minimum() {
+        let controller = controller();
+        assert_eq!(controller.batch_size(), 1);
+        assert_eq!(controller.window(), Duration::ZERO);
+    }
+
+    #[test]
+    fn test_grows_under_backlog_up_to_max() {
+        let controller = controller();
+
+        controller.observe(1, 50);
+        assert_eq!(controller.batch_size(), 2);
+        assert_eq!(controller.window(), Duration::from_micros(100));
+
+        for _ in 0..10 {
+            controller.observe(controller.batch_size(), 50);
+        }
+        assert_eq!(controller.batch_size(), 8);
+        assert_eq!(controller.window(), Duration::from_micros(800));
+    }
+
+    #[test]
+    fn test_shrinks_to_zero_window_when_drained() {
+        let controller = controller();
+        for _ in 0..10 {
+            controller.observe(controller.batch_size(), 50);
+        }
+
+        // Full batch with nothing left queued holds steady
+        controller.observe(8, 0);
+        assert_eq!(controller.batch_size(), 8);
+
+        for _ in 0..10 {
+            controller.observe(1, 0);
+        }
+        assert_eq!(controller.batch_size(), 1);
+        assert_eq!(controller.window(), Duration::ZERO);
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+    async fn test_parallel_batch_bounded_and_ordered() {
+        let in_flight = Arc::new(AtomicUsize::new(0));
+        let peak = Arc::new(AtomicUsize::new(0));
+        let blocking_calls = Arc::new(AtomicUsize::new(0));
+
+        let execute = {
+            let in_flight = Arc::clone(&in_flight);
+            let peak = Arc::clone(&peak);
+            let blocking_calls = Arc::clone(&blocking_calls);
+            move |pid: Pid, syscall: Syscall| {
+                if syscall.classify() == SyscallClass::Blocking {
+                    blocking_calls.fetch_add(1, Ordering::SeqCst);
+                    let now = in_flight.fetch_add(1, Ordering::SeqCst) + 1;
+                    peak.fetch_max(now, Ordering::SeqCst);
+                    std::thread::sleep(Duration::from_millis(2));
+                    in_flight.fetch_sub(1, Ordering::SeqCst);
+                }
+                SyscallResult::success_with_data(pid.to_le_bytes().to_vec())
+            }
+        };
+
+        // Every tenth request takes the fast path
+        let requests: Vec<(Pid, Syscall)> = (0..200)
+            .map(|i| {
+                let syscall = if i % 10 == 0 {
+                    Syscall::GetMemoryStats
+                } else {
+                    Syscall::ReadFile {
+                        path: format!("/tmp/{}", i).into(),
+                    }
+                };
+                (i, syscall)
+            })
+            .collect();
+
+        let permits = Arc::new(Semaphore::new(4));
+        let results = run_bounded(requests, &permits, execute).await;
+
+        assert_eq!(results.len(), 200);
+        for (i, result) in results.into_iter().enumerate() {
+            assert_eq!(
+                result,
+                SyscallResult::success_with_data((i as Pid).to_le_bytes().to_vec())
+            );
+        }
+        assert_eq!(blocking_calls.load(Ordering::SeqCst), 180);
+        let peak = peak.load(Ordering::SeqCst);
+        assert!(peak <= 4, "{} ran at once", peak);
+        assert!(peak > 1, "batch ran serially");
+        assert_eq!(permits.available_permits(), 4);
+    }
+}
@@ -8,7 +8,7 @@
 pub mod streaming;
 
 pub use async_task::{AsyncTaskManager, TaskStats, TaskStatus};
-pub use batch::BatchExecutor;
+pub use batch::{AdaptiveBatchConfig, BatchExecutor, BatchStats};
 pub use streaming::StreamingManager;
 
 // Re-export io_uring types for execution layer
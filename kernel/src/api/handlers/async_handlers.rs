@@ -70,9 +70,10 @@ pub async fn handle_get_async_status(
                 TaskStatus::Failed(msg) => (
                     async_status_response::Status::Failed,
                     Some(SyscallResponse {
-                        result: Some(
-                            syscall_response::Result::Error(ErrorResult { message: msg }).into(),
-                        ),
+                        result: Some(syscall_response::Result::Error(ErrorResult {
+                            message: msg,
+                            ..Default::default()
+                        })),
                     }),
                 ),
                 TaskStatus::Cancelled => (async_status_response::Status::Cancelled, None),
@@ -143,9 +144,10 @@ pub async fn handle_execute_syscall_batch(
             Err(e) => {
                 return Ok(Response::new(BatchSyscallResponse {
                     responses: vec![SyscallResponse {
-                        result: Some(
-                            syscall_response::Result::Error(ErrorResult { message: e }).into(),
-                        ),
+                        result: Some(syscall_response::Result::Error(ErrorResult {
+                            message: e,
+                            ..Default::default()
+                        })),
                     }],
                     success_count: 0,
                     failure_count: 1,
@@ -0,0 +1,282 @@
+/*!
+ * Kernel introspection gRPC handler implementations
+ */
+
+use crate::api::server::grpc_server::kernel_proto::*;
+use crate::api::server::metrics::{MetricsService, PROMETHEUS_CONTENT_TYPE};
+use crate::memory::{MemoryPressure, MemoryStats};
+use crate::process::ProcessManagerImpl as ProcessManager;
+use crate::syscalls::SyscallExecutorWithIpc;
+use tonic::{Request, Response, Status};
+use tracing::info;
+
+/// Cargo features this kernel was compiled with
+pub fn enabled_features() -> Vec<&'static str> {
+    [
+        ("nightly", cfg!(feature = "nightly")),
+        ("tokio", cfg!(feature = "tokio")),
+        ("jemalloc", cfg!(feature = "jemalloc")),
+        ("lz4", cfg!(feature = "lz4")),
+        ("custom_limits", cfg!(feature = "custom_limits")),
+        ("otlp", cfg!(feature = "otlp")),
+    ]
+    .into_iter()
+    .filter_map(|(name, enabled)| enabled.then_some(name))
+    .collect()
+}
+
+pub async fn handle_get_kernel_info(
+    executor: &SyscallExecutorWithIpc,
+    process_manager: &ProcessManager,
+    _request: Request<GetKernelInfoRequest>,
+) -> Result<Response<GetKernelInfoResponse>, Status> {
+    info!("gRPC: Kernel info requested");
+
+    let to_strings = |names: Vec<&'static str>| names.into_iter().map(String::from).collect();
+
+    Ok(Response::new(GetKernelInfoResponse {
+        version: env!("CARGO_PKG_VERSION").to_string(),
+        syscall_handlers: to_strings(executor.handler_names()),
+        resource_types: to_strings(process_manager.resource_types()),
+        features: to_strings(enabled_features()),
+    }))
+}
+
+/// Composite health for liveness/readiness probes
+///
+/// Ready once the memory and process managers are wired in. The kernel is
+/// degraded when any subsystem is: critical memory pressure or a stalled
+/// event stream.
+pub fn kernel_health(
+    executor: &SyscallExecutorWithIpc,
+    process_manager: &ProcessManager,
+) -> HealthResponse {
+    let optional = executor.optional();
+    let memory_manager = optional
+        .memory_manager
+        .as_ref()
+        .or(process_manager.memory_manager());
+    let mut subsystems = Vec::new();
+
+    let memory_pressure = memory_manager.map(|mm| {
+        let (total, used, _) = mm.info();
+        MemoryStats::new(total, used).memory_pressure()
+    });
+    if let Some(pressure) = memory_pressure {
+        subsystems.push(SubsystemHealth {
+            name: "memory".to_string(),
+            ok: pressure != MemoryPressure::Critical,
+            detail: format!("{} pressure", pressure),
+        });
+    }
+
+    let event_stream_stalled = optional
+        .collector
+        .as_ref()
+        .is_some_and(|collector| collector.is_stream_stalled());
+    if optional.collector.is_some() {
+        subsystems.push(SubsystemHealth {
+            name: "events".to_string(),
+            ok: !event_stream_stalled,
+            detail: if event_stream_stalled {
+                "event stream stalled".to_string()
+            } else {
+                "streaming".to_string()
+            },
+        });
+    }
+
+    let scheduler_stats = process_manager.get_scheduler_stats();
+    let scheduler_backlog = scheduler_stats
+        .as_ref()
+        .map_or(0, |stats| stats.active_processes as u32);
+    if let Some(stats) = scheduler_stats {
+        subsystems.push(SubsystemHealth {
+            name: "scheduler".to_string(),
+            ok: true,
+            detail: format!("{} queued ({:?})", scheduler_backlog, stats.policy),
+        });
+    }
+
+    HealthResponse {
+        ready: memory_manager.is_some() && optional.process_manager.is_some(),
+        degraded: subsystems.iter().any(|s| !s.ok),
+        memory_pressure: memory_pressure.map(|p| p.to_string()).unwrap_or_default(),
+        event_stream_stalled,
+        scheduler_backlog,
+        subsystems,
+    }
+}
+
+pub async fn handle_health(
+    executor: &SyscallExecutorWithIpc,
+    process_manager: &ProcessManager,
+    _request: Request<HealthRequest>,
+) -> Result<Response<HealthResponse>, Status> {
+    let health = kernel_health(executor, process_manager);
+    info!(
+        ready = health.ready,
+        degraded = health.degraded,
+        "gRPC: Health check"
+    );
+    Ok(Response::new(health))
+}
+
+pub async fn handle_get_metrics(
+    executor: &SyscallExecutorWithIpc,
+    request: Request<GetMetricsRequest>,
+) -> Result<Response<GetMetricsResponse>, Status> {
+    let collector = executor
+        .optional()
+        .metrics
+        .clone()
+        .ok_or_else(|| Status::unavailable("Metrics collection is not enabled"))?;
+    let service = MetricsService::new(collector);
+
+    let format = request.into_inner().format;
+    let (content_type, body) = match format.as_str() {
+        "" | "prometheus" => (PROMETHEUS_CONTENT_TYPE, service.get_metrics_prometheus()),
+        "json" => ("application/json", service.get_metrics_json()?),
+        other => {
+            return Err(Status::invalid_argument(format!(
+                "Unknown metrics format: {}",
+                other
+            )))
+        }
+    };
+
+    info!(format = %format, "gRPC: Metrics scraped");
+    Ok(Response::new(GetMetricsResponse {
+        content_type: content_type.to_string(),
+        body,
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ipc::{PipeManager, ShmManager};
+    use crate::memory::MemoryManager;
+    use crate::security::SandboxManager;
+
+    #[tokio::test]
+    async fn test_kernel_info_reflects_registries() {
+        let memory_manager = MemoryManager::new();
+        let executor = SyscallExecutorWithIpc::with_ipc_direct(
+            SandboxManager::new(),
+            PipeManager::new(memory_manager.clone()),
+            ShmManager::new(memory_manager.clone()),
+        );
+        let process_manager = ProcessManager::builder()
+            .with_memory_manager(memory_manager)
+            .build();
+
+        let info = handle_get_kernel_info(
+            &executor,
+            &process_manager,
+            Request::new(GetKernelInfoRequest {}),
+        )
+        .await
+        .unwrap()
+        .into_inner();
+
+        assert_eq!(info.syscall_handlers.len(), executor.handler_names().len());
+        assert!(info.syscall_handlers.iter().any(|h| h == "fs_handler"));
+        assert_eq!(info.resource_types, process_manager.resource_types());
+        assert!(info.resource_types.iter().any(|t| t == "memory"));
+        assert_eq!(info.features, enabled_features());
+    }
+
+    fn health_fixture(memory_manager: MemoryManager) -> (SyscallExecutorWithIpc, ProcessManager) {
+        let process_manager = ProcessManager::builder()
+            .with_memory_manager(memory_manager.clone())
+            .with_scheduler(crate::process::SchedulingPolicy::Fair)
+            .build();
+        let executor = SyscallExecutorWithIpc::with_full_features(
+            SandboxManager::new(),
+            PipeManager::new(memory_manager.clone()),
+            ShmManager::new(memory_manager.clone()),
+            process_manager.clone(),
+            memory_manager,
+        );
+        (executor, process_manager)
+    }
+
+    #[tokio::test]
+    async fn test_health_ready_and_ok() {
+        let (executor, process_manager) = health_fixture(MemoryManager::new());
+        process_manager.create_process("probe".to_string(), 5);
+
+        let health = handle_health(&executor, &process_manager, Request::new(HealthRequest {}))
+            .await
+            .unwrap()
+            .into_inner();
+
+        assert!(health.ready);
+        assert!(!health.degraded);
+        assert_eq!(health.memory_pressure, "LOW");
+        assert_eq!(health.scheduler_backlog, 1);
+        assert!(health.subsystems.iter().all(|s| s.ok));
+    }
+
+    #[tokio::test]
+    async fn test_health_degraded_on_critical_memory_pressure() {
+        let memory_manager = MemoryManager::with_capacity(1024 * 1024);
+        let (executor, process_manager) = health_fixture(memory_manager.clone());
+        memory_manager.allocate(1000 * 1024, 1).unwrap();
+
+        let health = handle_health(&executor, &process_manager, Request::new(HealthRequest {}))
+            .await
+            .unwrap()
+            .into_inner();
+
+        // Still ready (managers are up), but the probe reports degradation
+        assert!(health.ready);
+        assert!(health.degraded);
+        assert_eq!(health.memory_pressure, "CRITICAL");
+        let memory = health
+            .subsystems
+            .iter()
+            .find(|s| s.name == "memory")
+            .unwrap();
+        assert!(!memory.ok);
+    }
+
+    #[tokio::test]
+    async fn test_metrics_served_in_prometheus_format() {
+        let memory_manager = MemoryManager::new();
+        let executor = SyscallExecutorWithIpc::with_ipc_direct(
+            SandboxManager::new(),
+            PipeManager::new(memory_manager.clone()),
+            ShmManager::new(memory_manager),
+        );
+        let request = || {
+            Request::new(GetMetricsRequest {
+                format: String::new(),
+            })
+        };
+        let err = handle_get_metrics(&executor, request()).await.unwrap_err();
+        assert_eq!(err.code(), tonic::Code::Unavailable);
+
+        let metrics = std::sync::Arc::new(crate::monitoring::MetricsCollector::new());
+        metrics.inc_counter("scrapes", 1.0);
+        let executor = executor.with_metrics(metrics);
+
+        let response = handle_get_metrics(&executor, request())
+            .await
+            .unwrap()
+            .into_inner();
+        assert_eq!(response.content_type, PROMETHEUS_CONTENT_TYPE);
+        assert!(response.body.contains("# TYPE kernel_scrapes counter\n"));
+
+        let json = Request::new(GetMetricsRequest {
+            format: "json".to_string(),
+        });
+        let response = handle_get_metrics(&executor, json)
+            .await
+            .unwrap()
+            .into_inner();
+        assert_eq!(response.content_type, "application/json");
+        assert!(response.body.contains("scrapes"));
+    }
+}
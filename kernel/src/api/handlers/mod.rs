@@ -3,6 +3,7 @@
  */
 
 pub mod async_handlers;
+pub mod info_handlers;
 pub mod process_handlers;
 pub mod sandbox_handlers;
 pub mod scheduler_handlers;
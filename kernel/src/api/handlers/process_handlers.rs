@@ -58,11 +58,29 @@ pub async fn handle_create_process(
 
     // Create process (with or without OS execution)
     info!("About to call create_process_with_command");
-    let pid = process_manager.create_process_with_command(
-        req.name.clone(),
-        req.priority as u8,
-        exec_config,
-    );
+    let pid = match req.parent_pid {
+        Some(parent) => match process_manager.create_child_process(
+            parent,
+            req.name.clone(),
+            req.priority as u8,
+            exec_config,
+        ) {
+            Some(pid) => pid,
+            None => {
+                return Ok(Response::new(CreateProcessResponse {
+                    pid: 0,
+                    success: false,
+                    error: format!("Parent process {} not found", parent),
+                    os_pid: None,
+                }))
+            }
+        },
+        None => process_manager.create_process_with_command(
+            req.name.clone(),
+            req.priority as u8,
+            exec_config,
+        ),
+    };
     info!("Created process, PID: {}", pid);
 
     // Get OS PID if available
@@ -76,6 +94,11 @@ pub async fn handle_create_process(
         _ => SandboxConfig::standard(pid),
     };
 
+    if sandbox_config.zero_memory_on_free {
+        if let Some(memory_manager) = process_manager.memory_manager() {
+            memory_manager.set_process_zero_on_free(pid, true);
+        }
+    }
     sandbox_manager.create_sandbox(sandbox_config);
 
     let response = CreateProcessResponse {
@@ -46,14 +46,22 @@ pub async fn handle_update_sandbox(
     }
 
     // Update sandbox
-    let success = sandbox_manager.update_sandbox(req.pid, config);
-
-    let response = UpdateSandboxResponse {
-        success,
-        error: if success {
-            String::new()
-        } else {
-            "Failed to update sandbox".to_string().into()
+    let response = match sandbox_manager.try_update_sandbox(req.pid, config) {
+        Ok(true) => UpdateSandboxResponse {
+            success: true,
+            error: String::new(),
+        },
+        Ok(false) => UpdateSandboxResponse {
+            success: false,
+            error: "Failed to update sandbox".to_string(),
+        },
+        Err(errors) => UpdateSandboxResponse {
+            success: false,
+            error: errors
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>()
+                .join("; "),
         },
     };
 
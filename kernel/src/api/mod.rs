@@ -11,7 +11,9 @@
 pub mod types;
 
 // Re-export for convenience
-pub use execution::{AsyncTaskManager, BatchExecutor, StreamingManager, TaskStatus};
+pub use execution::{
+    AdaptiveBatchConfig, AsyncTaskManager, BatchExecutor, BatchStats, StreamingManager, TaskStatus,
+};
 pub use server::{kernel_proto, start_grpc_server, GrpcServer, KernelServiceImpl, MetricsService};
 pub use traits::*;
 pub use types::*;
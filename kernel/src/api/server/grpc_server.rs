@@ -18,7 +18,8 @@
     AsyncTaskManager, BatchExecutor, IoUringExecutor, IoUringManager, StreamingManager,
 };
 use crate::api::handlers::{
-    async_handlers, process_handlers, sandbox_handlers, scheduler_handlers, streaming_handlers,
+    async_handlers, info_handlers, process_handlers, sandbox_handlers, scheduler_handlers,
+    streaming_handlers,
 };
 use crate::api::traits::ServerLifecycle;
 use crate::api::types::{ApiError, ApiResult, ServerConfig};
@@ -172,6 +173,32 @@ async fn set_scheduling_policy(
         scheduler_handlers::handle_set_scheduling_policy(&self.process_manager, request).await
     }
 
+    async fn get_kernel_info(
+        &self,
+        request: Request<GetKernelInfoRequest>,
+    ) -> Result<Response<GetKernelInfoResponse>, Status> {
+        info_handlers::handle_get_kernel_info(
+            &self.syscall_executor,
+            &self.process_manager,
+            request,
+        )
+        .await
+    }
+
+    async fn health(
+        &self,
+        request: Request<HealthRequest>,
+    ) -> Result<Response<HealthResponse>, Status> {
+        info_handlers::handle_health(&self.syscall_executor, &self.process_manager, request).await
+    }
+
+    async fn get_metrics(
+        &self,
+        request: Request<GetMetricsRequest>,
+    ) -> Result<Response<GetMetricsResponse>, Status> {
+        info_handlers::handle_get_metrics(&self.syscall_executor, request).await
+    }
+
     async fn stream_syscall(
         &self,
         request: Request<tonic::Streaming<StreamSyscallRequest>>,
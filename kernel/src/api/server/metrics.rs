@@ -7,6 +7,9 @@
 use std::sync::Arc;
 use tonic::Status;
 
+/// Content type of the Prometheus text exposition format
+pub const PROMETHEUS_CONTENT_TYPE: &str = "text/plain; version=0.0.4";
+
 /// Metrics service implementation
 pub struct MetricsService {
     collector: Arc<MetricsCollector>,
@@ -26,45 +29,7 @@ pub fn get_metrics_json(&self) -> Result<String, Status> {
 
     /// Get metrics in Prometheus format
     pub fn get_metrics_prometheus(&self) -> String {
-        let snapshot = self.collector.snapshot();
-        let mut output = String::new();
-
-        // Counters
-        for (name, value) in snapshot.counters {
-            output.push_str(&format!("# TYPE kernel_{} counter\n", name));
-            output.push_str(&format!("kernel_{} {}\n", name, value));
-        }
-
-        // Gauges
-        for (name, value) in snapshot.gauges {
-            output.push_str(&format!("# TYPE kernel_{} gauge\n", name));
-            output.push_str(&format!("kernel_{} {}\n", name, value));
-        }
-
-        // Histograms
-        for (name, stats) in snapshot.histograms {
-            output.push_str(&format!("# TYPE kernel_{} summary\n", name));
-            output.push_str(&format!("kernel_{}_sum {}\n", name, stats.sum));
-            output.push_str(&format!("kernel_{}_count {}\n", name, stats.count));
-            output.push_str(&format!(
-                "kernel_{}{{quantile=\"0.5\"}} {}\n",
-                name, stats.p50
-            ));
-            output.push_str(&format!(
-                "kernel_{}{{quantile=\"0.95\"}} {}\n",
-                name, stats.p95
-            ));
-            output.push_str(&format!(
-                "kernel_{}{{quantile=\"0.99\"}} {}\n",
-                name, stats.p99
-            ));
-        }
-
-        // Uptime
-        output.push_str("# TYPE kernel_uptime_seconds gauge\n");
-        output.push_str(&format!("kernel_uptime_seconds {}\n", snapshot.uptime_secs));
-
-        output
+        self.collector.snapshot().to_prometheus()
     }
 }
 
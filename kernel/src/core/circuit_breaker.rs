@@ -0,0 +1,435 @@
+/*!
+ * Circuit Breaker
+ * Fail fast on resources that keep failing
+ *
+ * Circuits are keyed by resource name (a mount path, a host). After
+ * `failure_threshold` consecutive failures a circuit opens and calls are
+ * rejected immediately for `cooldown`. The first call after the cooldown
+ * half-opens the circuit and runs as a probe while other calls keep being
+ * rejected: a successful probe closes the circuit, a failed one reopens it.
+ */
+
+use crate::core::limits::{CIRCUIT_BREAKER_COOLDOWN, CIRCUIT_BREAKER_FAILURE_THRESHOLD};
+use crate::monitoring::{Category, Collector, Event, Payload, Severity};
+use ahash::RandomState;
+use dashmap::DashMap;
+use log::{info, warn};
+use parking_lot::RwLock;
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+/// Circuit breaker configuration
+#[derive(Debug, Clone, Copy)]
+pub struct BreakerConfig {
+    /// Consecutive failures that open a circuit
+    pub failure_threshold: u32,
+    /// How long an open circuit rejects calls before probing
+    pub cooldown: Duration,
+}
+
+impl BreakerConfig {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_failure_threshold(mut self, failure_threshold: u32) -> Self {
+        self.failure_threshold = failure_threshold.max(1);
+        self
+    }
+
+    pub fn with_cooldown(mut self, cooldown: Duration) -> Self {
+        self.cooldown = cooldown;
+        self
+    }
+}
+
+impl Default for BreakerConfig {
+    fn default() -> Self {
+        Self {
+            failure_threshold: CIRCUIT_BREAKER_FAILURE_THRESHOLD,
+            cooldown: CIRCUIT_BREAKER_COOLDOWN,
+        }
+    }
+}
+
+/// State of one circuit
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BreakerState {
+    /// Calls pass through
+    Closed,
+    /// Calls are rejected until the cooldown ends
+    Open,
+    /// One probe call is in flight; others are rejected
+    HalfOpen,
+}
+
+impl BreakerState {
+    /// Stable snake_case name
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::Closed => "closed",
+            Self::Open => "open",
+            Self::HalfOpen => "half_open",
+        }
+    }
+}
+
+/// Per-resource circuit statistics
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BreakerStats {
+    pub resource: String,
+    pub state: BreakerState,
+    pub consecutive_failures: u32,
+    /// Times the circuit has opened
+    pub trips: u64,
+    /// Calls rejected without reaching the resource
+    pub rejected: u64,
+}
+
+#[derive(Debug)]
+struct Circuit {
+    state: BreakerState,
+    consecutive_failures: u32,
+    opened_at: Option<Instant>,
+    trips: u64,
+    rejected: u64,
+}
+
+impl Circuit {
+    fn new() -> Self {
+        Self {
+            state: BreakerState::Closed,
+            consecutive_failures: 0,
+            opened_at: None,
+            trips: 0,
+            rejected: 0,
+        }
+    }
+}
+
+/// Call rejected because the resource's circuit is open
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CircuitOpen {
+    /// Time left before the circuit will admit a probe
+    pub retry_after: Duration,
+}
+
+struct BreakerInner {
+    config: BreakerConfig,
+    circuits: DashMap<String, Circuit, RandomState>,
+    collector: RwLock<Option<Arc<Collector>>>,
+}
+
+/// Circuit breakers for a family of resources
+///
+/// Cheap to clone; clones share circuits.
+#[derive(Clone)]
+pub struct CircuitBreaker {
+    inner: Arc<BreakerInner>,
+}
+
+impl CircuitBreaker {
+    pub fn new(config: BreakerConfig) -> Self {
+        Self {
+            inner: Arc::new(BreakerInner {
+                config,
+                circuits: DashMap::with_hasher(RandomState::new()),
+                collector: RwLock::new(None),
+            }),
+        }
+    }
+
+    /// Emit an event on every state transition
+    pub fn set_collector(&self, collector: Arc<Collector>) {
+        *self.inner.collector.write() = Some(collector);
+    }
+
+    /// Breaker configuration
+    pub fn config(&self) -> BreakerConfig {
+        self.inner.config
+    }
+
+    /// Admit a call to `resource`, or reject it while the circuit is open
+    ///
+    /// Every admitted call must be followed by `record_success` or
+    /// `record_failure`.
+    pub fn acquire(&self, resource: &str) -> Result<(), CircuitOpen> {
+        self.acquire_at(resource, Instant::now())
+    }
+
+    fn acquire_at(&self, resource: &str, now: Instant) -> Result<(), CircuitOpen> {
+        // Closed circuits that never failed aren't tracked
+        let Some(mut circuit) = self.inner.circuits.get_mut(resource) else {
+            return Ok(());
+        };
+
+        match circuit.state {
+            BreakerState::Closed => Ok(()),
+            BreakerState::HalfOpen => {
+                circuit.rejected += 1;
+                Err(CircuitOpen {
+                    retry_after: Duration::ZERO,
+                })
+            }
+            BreakerState::Open => {
+                let opened_at = circuit.opened_at.unwrap_or(now);
+                let elapsed = now.saturating_duration_since(opened_at);
+                if elapsed < self.inner.config.cooldown {
+                    circuit.rejected += 1;
+                    return Err(CircuitOpen {
+                        retry_after: self.inner.config.cooldown - elapsed,
+                    });
+                }
+                circuit.state = BreakerState::HalfOpen;
+                let trips = circuit.trips;
+                drop(circuit);
+                self.transition(resource, BreakerState::Open, BreakerState::HalfOpen, trips);
+                Ok(())
+            }
+        }
+    }
+
+    /// Record that an admitted call succeeded
+    pub fn record_success(&self, resource: &str) {
+        let Some(mut circuit) = self.inner.circuits.get_mut(resource) else {
+            return;
+        };
+        let previous = circuit.state;
+        circuit.state = BreakerState::Closed;
+        circuit.consecutive_failures = 0;
+        circuit.opened_at = None;
+        let trips = circuit.trips;
+        drop(circuit);
+
+        if previous != BreakerState::Closed {
+            self.transition(resource, previous, BreakerState::Closed, trips);
+        }
+    }
+
+    /// Record that an admitted call failed because of the resource
+    pub fn record_failure(&self, resource: &str) {
+        self.record_failure_at(resource, Instant::now());
+    }
+
+    fn record_failure_at(&self, resource: &str, now: Instant) {
+        let mut circuit = self
+            .inner
+            .circuits
+            .entry(resource.to_string())
+            .or_insert_with(Circuit::new);
+        circuit.consecutive_failures = circuit.consecutive_failures.saturating_add(1);
+
+        let previous = circuit.state;
+        let trip = match previous {
+            BreakerState::HalfOpen => true,
+            BreakerState::Closed => {
+                circuit.consecutive_failures >= self.inner.config.failure_threshold
+            }
+            // A call admitted before the circuit opened finished late
+            BreakerState::Open => false,
+        };
+        if !trip {
+            return;
+        }
+
+        circuit.state = BreakerState::Open;
+        circuit.opened_at = Some(now);
+        circuit.trips += 1;
+        let trips = circuit.trips;
+        drop(circuit);
+        self.transition(resource, previous, BreakerState::Open, trips);
+    }
+
+    /// Current state of `resource`'s circuit
+    pub fn state(&self, resource: &str) -> BreakerState {
+        self.inner
+            .circuits
+            .get(resource)
+            .map(|circuit| circuit.state)
+            .unwrap_or(BreakerState::Closed)
+    }
+
+    /// Statistics for every resource that has failed at least once
+    pub fn stats(&self) -> Vec<BreakerStats> {
+        let mut stats: Vec<BreakerStats> = self
+            .inner
+            .circuits
+            .iter()
+            .map(|entry| BreakerStats {
+                resource: entry.key().clone(),
+                state: entry.state,
+                consecutive_failures: entry.consecutive_failures,
+                trips: entry.trips,
+                rejected: entry.rejected,
+            })
+            .collect();
+        stats.sort_by(|a, b| a.resource.cmp(&b.resource));
+        stats
+    }
+
+    /// Close `resource`'s circuit and forget its history
+    pub fn reset(&self, resource: &str) {
+        if let Some((_, circuit)) = self.inner.circuits.remove(resource) {
+            if circuit.state != BreakerState::Closed {
+                self.transition(resource, circuit.state, BreakerState::Closed, circuit.trips);
+            }
+        }
+    }
+
+    fn transition(&self, resource: &str, from: BreakerState, to: BreakerState, trips: u64) {
+        if to == BreakerState::Open {
+            warn!(
+                "Circuit for {} opened ({} trips), failing fast for {:?}",
+                resource, trips, self.inner.config.cooldown
+            );
+        } else {
+            info!("Circuit for {} {} -> {}", resource, from.name(), to.name());
+        }
+
+        let Some(collector) = self.inner.collector.read().clone() else {
+            return;
+        };
+        let severity = match to {
+            BreakerState::Open => Severity::Warn,
+            BreakerState::HalfOpen | BreakerState::Closed => Severity::Info,
+        };
+        collector.emit(Event::new(
+            severity,
+            Category::Resource,
+            Payload::CircuitStateChanged {
+                resource: resource.into(),
+                from: from.name().into(),
+                to: to.name().into(),
+                trips,
+            },
+        ));
+    }
+}
+
+impl Default for CircuitBreaker {
+    fn default() -> Self {
+        Self::new(BreakerConfig::default())
+    }
+}
+
+impl std::fmt::Debug for CircuitBreaker {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CircuitBreaker")
+            .field("config", &self.inner.config)
+            .field("circuits", &self.inner.circuits.len())
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn breaker() -> CircuitBreaker {
+        CircuitBreaker::new(
+            BreakerConfig::new()
+                .with_failure_threshold(3)
+                .with_cooldown(Duration::from_secs(10)),
+        )
+    }
+
+    #[test]
+    fn test_full_cycle() {
+        let breaker = breaker();
+        let start = Instant::now();
+
+        for _ in 0..2 {
+            assert!(breaker.acquire_at("/mnt", start).is_ok());
+            breaker.record_failure_at("/mnt", start);
+        }
+        assert_eq!(breaker.state("/mnt"), BreakerState::Closed);
+
+        breaker.record_failure_at("/mnt", start);
+        assert_eq!(breaker.state("/mnt"), BreakerState::Open);
+
+        let rejected = breaker
+            .acquire_at("/mnt", start + Duration::from_secs(4))
+            .unwrap_err();
+        assert_eq!(rejected.retry_after, Duration::from_secs(6));
+
+        // Cooldown over: one probe is admitted, concurrent calls are not
+        let later = start + Duration::from_secs(10);
+        assert!(breaker.acquire_at("/mnt", later).is_ok());
+        assert_eq!(breaker.state("/mnt"), BreakerState::HalfOpen);
+        assert!(breaker.acquire_at("/mnt", later).is_err());
+
+        breaker.record_success("/mnt");
+        assert_eq!(breaker.state("/mnt"), BreakerState::Closed);
+        assert!(breaker.acquire_at("/mnt", later).is_ok());
+
+        let stats = breaker.stats();
+        assert_eq!(stats.len(), 1);
+        assert_eq!(stats[0].trips, 1);
+        assert_eq!(stats[0].rejected, 2);
+        assert_eq!(stats[0].consecutive_failures, 0);
+    }
+
+    #[test]
+    fn test_failed_probe_reopens() {
+        let breaker = breaker();
+        let start = Instant::now();
+        for _ in 0..3 {
+            breaker.record_failure_at("host", start);
+        }
+
+        let later = start + Duration::from_secs(10);
+        assert!(breaker.acquire_at("host", later).is_ok());
+        breaker.record_failure_at("host", later);
+        assert_eq!(breaker.state("host"), BreakerState::Open);
+        assert!(breaker.acquire_at("host", later).is_err());
+        assert_eq!(breaker.stats()[0].trips, 2);
+
+        // Other resources are unaffected
+        assert!(breaker.acquire_at("other", later).is_ok());
+    }
+
+    #[test]
+    fn test_success_resets_failure_count() {
+        let breaker = breaker();
+        breaker.record_failure("/mnt");
+        breaker.record_failure("/mnt");
+        breaker.record_success("/mnt");
+        breaker.record_failure("/mnt");
+        breaker.record_failure("/mnt");
+        assert_eq!(breaker.state("/mnt"), BreakerState::Closed);
+    }
+
+    #[test]
+    fn test_transitions_emit_events() {
+        let collector = Arc::new(Collector::new());
+        let breaker = breaker();
+        breaker.set_collector(Arc::clone(&collector));
+        let start = Instant::now();
+
+        for _ in 0..3 {
+            breaker.record_failure_at("/mnt", start);
+        }
+        breaker
+            .acquire_at("/mnt", start + Duration::from_secs(10))
+            .unwrap();
+        breaker.record_success("/mnt");
+
+        let mut sub = collector.subscribe();
+        let mut transitions = Vec::new();
+        while let Some(event) = sub.next() {
+            if let Payload::CircuitStateChanged { from, to, .. } = event.payload {
+                transitions.push((from.to_string(), to.to_string()));
+            }
+        }
+        assert_eq!(
+            transitions,
+            vec![
+                ("closed".to_string(), "open".to_string()),
+                ("open".to_string(), "half_open".to_string()),
+                ("half_open".to_string(), "closed".to_string()),
+            ]
+        );
+    }
+}
@@ -59,6 +59,13 @@ fn clear(&mut self) {
         self.current = None;
     }
 
+    /// Drop history, keeping the current entry; returns bytes released
+    fn trim_history(&mut self) -> usize {
+        let freed = self.history.iter().map(|e| e.size()).sum();
+        self.history = VecDeque::new();
+        freed
+    }
+
     fn total_size(&self) -> usize {
         let current_size = self.current.as_ref().map_or(0, |e| e.size());
         let history_size: usize = self.history.iter().map(|e| e.size()).sum();
@@ -220,6 +227,19 @@ pub fn clear_global(&self) {
         debug!("Global clipboard cleared");
     }
 
+    /// Drop clipboard history everywhere, keeping current entries
+    ///
+    /// Used to shed memory under pressure; returns the bytes released.
+    pub fn trim_history(&self) -> usize {
+        let mut freed = 0;
+        for mut cb in self.clipboards.iter_mut() {
+            freed += cb.trim_history();
+        }
+        freed += self.global.write().trim_history();
+        debug!("Trimmed clipboard history ({} bytes)", freed);
+        freed
+    }
+
     /// Subscribe to clipboard changes
     pub fn subscribe(&self, pid: Pid, formats: Vec<ClipboardFormat>) {
         let subscription = ClipboardSubscription { pid, formats };
@@ -328,6 +348,23 @@ fn test_history() {
         assert_eq!(history.len(), 2); // First two entries
     }
 
+    #[test]
+    fn test_trim_history() {
+        let manager = ClipboardManager::new();
+        let pid = 100;
+
+        for text in ["First", "Second", "Third"] {
+            manager
+                .copy(pid, ClipboardData::Text(text.to_string()))
+                .unwrap();
+        }
+
+        assert!(manager.trim_history() > 0);
+        assert!(manager.history(pid, None).is_empty());
+        // Current entry survives
+        assert!(manager.paste(pid).is_ok());
+    }
+
     #[test]
     fn test_global_clipboard() {
         let manager = ClipboardManager::new();
@@ -347,4 +384,3 @@ fn test_size_limit() {
         assert!(matches!(result, Err(ClipboardError::TooLarge { .. })));
     }
 }
-
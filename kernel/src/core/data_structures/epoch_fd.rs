@@ -3,10 +3,13 @@
  * Lock-free, wait-free FD lookups using epoch-based reclamation
  */
 
-use crossbeam_epoch::{self as epoch, Atomic, Owned, Shared};
-use std::sync::atomic::{AtomicUsize, Ordering};
+use crossbeam_epoch::{self as epoch, Atomic, Guard, Owned, Shared};
+use std::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
 use std::sync::Arc;
 
+/// Upper bound on flush rounds attempted by `force_reclaim`
+const FORCE_RECLAIM_ROUNDS: usize = 1024;
+
 /// File descriptor entry (generic over handle type)
 pub struct FdEntry<T> {
     pub handle: Arc<T>,
@@ -28,6 +31,54 @@ pub struct FdEntry<T> {
 pub struct EpochFdTable<T> {
     entries: Vec<Atomic<FdEntry<T>>>,
     size: AtomicUsize,
+    reclaim: Arc<ReclaimCounters>,
+}
+
+/// Snapshot of deferred reclamation activity
+///
+/// A growing `pending` that never drains usually means a reader is
+/// holding an epoch guard for too long.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct EpochReclaimStats {
+    /// Retired entries waiting for their epoch to expire
+    pub pending: u64,
+    /// Retired entries freed so far
+    pub reclaimed: u64,
+    /// Times the collector was observed freeing entries for this table
+    pub epochs_advanced: u64,
+    /// Largest `pending` ever observed
+    pub max_backlog: u64,
+}
+
+/// Counters shared with deferred destructors, which may outlive the table
+#[derive(Debug, Default)]
+struct ReclaimCounters {
+    pending: AtomicU64,
+    reclaimed: AtomicU64,
+    last_seen_reclaimed: AtomicU64,
+    epochs_advanced: AtomicU64,
+    max_backlog: AtomicU64,
+}
+
+impl ReclaimCounters {
+    fn on_retire(&self) {
+        let pending = self.pending.fetch_add(1, Ordering::Relaxed) + 1;
+        self.max_backlog.fetch_max(pending, Ordering::Relaxed);
+    }
+
+    fn on_reclaim(&self) {
+        self.pending.fetch_sub(1, Ordering::Relaxed);
+        self.reclaimed.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// Count an epoch advance if reclamation made progress since last look
+    fn observe(&self) {
+        let reclaimed = self.reclaimed.load(Ordering::Relaxed);
+        let previous = self.last_seen_reclaimed.swap(reclaimed, Ordering::Relaxed);
+        if reclaimed > previous {
+            self.epochs_advanced.fetch_add(1, Ordering::Relaxed);
+        }
+    }
 }
 
 impl<T> EpochFdTable<T> {
@@ -43,9 +94,25 @@ pub fn with_capacity(capacity: usize) -> Self {
         Self {
             entries,
             size: AtomicUsize::new(0),
+            reclaim: Arc::new(ReclaimCounters::default()),
         }
     }
 
+    /// Defer destruction of a retired entry, tracking it in the backlog
+    ///
+    /// # Safety
+    ///
+    /// `old` must be unlinked from the table and retired exactly once.
+    unsafe fn retire(&self, old: Shared<'_, FdEntry<T>>, guard: &Guard) {
+        self.reclaim.on_retire();
+        let counters = Arc::clone(&self.reclaim);
+        guard.defer_unchecked(move || {
+            drop(old.into_owned());
+            counters.on_reclaim();
+        });
+        self.reclaim.observe();
+    }
+
     /// Get entry for FD (wait-free)
     ///
     /// # Performance
@@ -89,7 +156,7 @@ pub fn insert(&self, fd: usize, handle: Arc<T>, flags: u32) -> Result<(), &'stat
         } else {
             // Defer deallocation of old entry
             unsafe {
-                guard.defer_destroy(old);
+                self.retire(old, &guard);
             }
         }
 
@@ -116,7 +183,7 @@ pub fn remove(&self, fd: usize) -> Option<Arc<T>> {
         let handle = unsafe { Arc::clone(&old.deref().handle) };
 
         unsafe {
-            guard.defer_destroy(old);
+            self.retire(old, &guard);
         }
 
         Some(handle)
@@ -171,7 +238,7 @@ pub fn update_flags(&self, fd: usize, flags: u32) -> Result<(), &'static str> {
         ) {
             Ok(old) => {
                 unsafe {
-                    guard.defer_destroy(old);
+                    self.retire(old, &guard);
                 }
                 Ok(())
             }
@@ -209,13 +276,42 @@ pub fn clear(&self) {
             let old = entry.swap(Shared::null(), Ordering::AcqRel, &guard);
             if !old.is_null() {
                 unsafe {
-                    guard.defer_destroy(old);
+                    self.retire(old, &guard);
                 }
             }
         }
 
         self.size.store(0, Ordering::Relaxed);
     }
+
+    /// Get deferred reclamation statistics
+    pub fn reclaim_stats(&self) -> EpochReclaimStats {
+        self.reclaim.observe();
+        EpochReclaimStats {
+            pending: self.reclaim.pending.load(Ordering::Relaxed),
+            reclaimed: self.reclaim.reclaimed.load(Ordering::Relaxed),
+            epochs_advanced: self.reclaim.epochs_advanced.load(Ordering::Relaxed),
+            max_backlog: self.reclaim.max_backlog.load(Ordering::Relaxed),
+        }
+    }
+
+    /// Push the reclamation backlog through the collector (slow operation)
+    ///
+    /// Intended for shutdown. Entries retired on other threads are only
+    /// freed once those threads flush or exit, and nothing is freed while
+    /// any reader still holds an old epoch. Returns the remaining backlog.
+    pub fn force_reclaim(&self) -> u64 {
+        for _ in 0..FORCE_RECLAIM_ROUNDS {
+            if self.reclaim.pending.load(Ordering::Relaxed) == 0 {
+                break;
+            }
+            epoch::pin().flush();
+            std::thread::yield_now();
+        }
+
+        self.reclaim.observe();
+        self.reclaim.pending.load(Ordering::Relaxed)
+    }
 }
 
 // Safety: FdEntry and operations are thread-safe
@@ -331,4 +427,34 @@ fn test_replace() {
         let retrieved = table.get(3).unwrap();
         assert_eq!(retrieved.path, "/second");
     }
+
+    #[test]
+    fn test_reclaim_backlog_drains_after_reader() {
+        let table = EpochFdTable::with_capacity(16);
+
+        // A stalled reader pins the epoch, so nothing retired can be freed
+        let reader = epoch::pin();
+        for i in 0..500u64 {
+            let handle = Arc::new(FileHandle {
+                path: format!("/file{}", i),
+                offset: i,
+            });
+            table.insert(3, handle, 0).unwrap();
+            table.remove(3).unwrap();
+        }
+
+        let stalled = table.reclaim_stats();
+        assert_eq!(stalled.pending, 500);
+        assert_eq!(stalled.max_backlog, 500);
+        assert_eq!(stalled.reclaimed, 0);
+
+        drop(reader);
+
+        assert_eq!(table.force_reclaim(), 0);
+        let drained = table.reclaim_stats();
+        assert_eq!(drained.pending, 0);
+        assert_eq!(drained.reclaimed, 500);
+        assert_eq!(drained.max_backlog, 500);
+        assert!(drained.epochs_advanced > 0);
+    }
 }
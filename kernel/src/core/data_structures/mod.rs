@@ -23,7 +23,7 @@
 mod epoch_fd;
 mod inline_string;
 
-pub use epoch_fd::EpochFdTable;
+pub use epoch_fd::{EpochFdTable, EpochReclaimStats};
 pub use inline_string::InlineString;
 
 // Re-export const generics utilities
@@ -17,6 +17,65 @@
 // Re-export SyscallError from syscalls module
 pub use crate::syscalls::types::SyscallError;
 
+/// Coarse error classes clients can branch on without parsing messages
+///
+/// Values are part of the wire protocol: never renumber, only append.
+#[repr(u32)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ErrorCategory {
+    /// Error carried no structured code
+    #[default]
+    Unknown = 0,
+    NotFound = 1,
+    PermissionDenied = 2,
+    AlreadyExists = 3,
+    InvalidArgument = 4,
+    /// A memory, space or count limit was hit
+    QuotaExceeded = 5,
+    WouldBlock = 6,
+    Unavailable = 7,
+    Unsupported = 8,
+    Io = 9,
+    Internal = 10,
+}
+
+impl ErrorCategory {
+    /// Stable snake_case name
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::Unknown => "unknown",
+            Self::NotFound => "not_found",
+            Self::PermissionDenied => "permission_denied",
+            Self::AlreadyExists => "already_exists",
+            Self::InvalidArgument => "invalid_argument",
+            Self::QuotaExceeded => "quota_exceeded",
+            Self::WouldBlock => "would_block",
+            Self::Unavailable => "unavailable",
+            Self::Unsupported => "unsupported",
+            Self::Io => "io",
+            Self::Internal => "internal",
+        }
+    }
+}
+
+/// Stable numeric code and category identifying an error variant
+///
+/// Codes are grouped by subsystem: syscall errors use 1xxx, VFS errors
+/// 2xxx, memory errors 3xxx and IPC errors 4xxx. Zero means no code was
+/// attached.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+pub struct ErrorCode {
+    pub code: u32,
+    pub category: ErrorCategory,
+}
+
+impl ErrorCode {
+    pub const fn new(code: u32, category: ErrorCategory) -> Self {
+        Self { code, category }
+    }
+}
+
 /// Process-related errors with serialization support
 #[derive(Error, Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Diagnostic)]
 #[serde(tag = "error_type", content = "details", rename_all = "snake_case")]
@@ -330,6 +389,77 @@ fn test_kernel_error_from_string() {
         assert!(matches!(error, KernelError::Internal(_)));
     }
 
+    #[test]
+    fn test_memory_error_codes_are_unique() {
+        let errors = [
+            MemoryError::OutOfMemory {
+                requested: 1,
+                available: 0,
+                used: 0,
+                total: 0,
+            },
+            MemoryError::ProcessLimitExceeded {
+                requested: 1,
+                limit: 0,
+                current: 0,
+            },
+            MemoryError::InvalidAddress(0),
+            MemoryError::CorruptionDetected(0),
+            MemoryError::AlignmentError {
+                address: 0,
+                alignment: 8,
+            },
+            MemoryError::UnsupportedSize {
+                requested: 1,
+                max: 0,
+                strategy: "slab".into(),
+            },
+            MemoryError::ProtectionViolation("x".into()),
+        ];
+
+        let codes: std::collections::HashSet<_> = errors.iter().map(|e| e.error_code()).collect();
+        assert_eq!(codes.len(), errors.len());
+        assert!(errors
+            .iter()
+            .all(|e| (3000..4000).contains(&e.error_code())
+                && e.category() != ErrorCategory::Unknown));
+    }
+
+    #[test]
+    fn test_ipc_error_codes_are_unique() {
+        use crate::ipc::types::IpcError;
+
+        let errors = [
+            IpcError::NotFound("x".into()),
+            IpcError::PermissionDenied("x".into()),
+            IpcError::LimitExceeded("x".into()),
+            IpcError::WouldBlock("x".into()),
+            IpcError::InvalidOperation("x".into()),
+            IpcError::Closed("x".into()),
+            IpcError::Timeout {
+                elapsed_ms: 1,
+                timeout_ms: None,
+            },
+        ];
+
+        let codes: std::collections::HashSet<_> = errors.iter().map(|e| e.error_code()).collect();
+        assert_eq!(codes.len(), errors.len());
+        assert!(errors
+            .iter()
+            .all(|e| (4000..5000).contains(&e.error_code())
+                && e.category() != ErrorCategory::Unknown));
+    }
+
+    #[test]
+    fn test_error_category_names() {
+        assert_eq!(ErrorCategory::default(), ErrorCategory::Unknown);
+        assert_eq!(ErrorCategory::QuotaExceeded as u32, 5);
+        assert_eq!(
+            serde_json::to_string(&ErrorCategory::PermissionDenied).unwrap(),
+            format!("\"{}\"", ErrorCategory::PermissionDenied.name())
+        );
+    }
+
     #[test]
     fn test_process_error_from_memory_error() {
         let memory_error = MemoryError::OutOfMemory {
@@ -70,6 +70,11 @@ pub fn all_active(&self) -> bool {
         self.guards.iter().all(|g| g.is_active())
     }
 
+    /// Total bytes held by the composed guards, including nested composites
+    pub fn size_bytes(&self) -> usize {
+        self.describe().iter().map(|m| m.size_bytes).sum()
+    }
+
     /// Release all guards in reverse order (LIFO)
     ///
     /// Continues even if some guards fail, collecting all errors
@@ -107,6 +112,16 @@ fn is_active(&self) -> bool {
         self.active && self.all_active()
     }
 
+    /// Every composed guard in the order `release_all` drops them, with
+    /// nested composites expanded in place
+    fn describe(&self) -> Vec<GuardMetadata> {
+        self.guards
+            .iter()
+            .rev()
+            .flat_map(|g| g.describe())
+            .collect()
+    }
+
     fn release(&mut self) -> GuardResult<()> {
         if !self.active {
             return Err(GuardError::AlreadyReleased);
@@ -289,6 +304,70 @@ fn test_composite_guard_builder() {
         assert_eq!(count.load(Ordering::SeqCst), 2);
     }
 
+    struct OrderedGuard {
+        metadata: GuardMetadata,
+        active: bool,
+        released: Arc<std::sync::Mutex<Vec<usize>>>,
+    }
+
+    impl OrderedGuard {
+        /// Guard identified by its size
+        fn new(size: usize, released: &Arc<std::sync::Mutex<Vec<usize>>>) -> Self {
+            Self {
+                metadata: GuardMetadata::new("ordered").with_size(size),
+                active: true,
+                released: released.clone(),
+            }
+        }
+    }
+
+    impl Guard for OrderedGuard {
+        fn resource_type(&self) -> &'static str {
+            "ordered"
+        }
+
+        fn metadata(&self) -> &GuardMetadata {
+            &self.metadata
+        }
+
+        fn is_active(&self) -> bool {
+            self.active
+        }
+
+        fn release(&mut self) -> GuardResult<()> {
+            if !self.active {
+                return Err(GuardError::AlreadyReleased);
+            }
+            self.active = false;
+            self.released.lock().unwrap().push(self.metadata.size_bytes);
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_describe_nested_matches_drop_order() {
+        let released = Arc::new(std::sync::Mutex::new(Vec::new()));
+
+        let inner = CompositeGuard::new()
+            .add(OrderedGuard::new(20, &released))
+            .add(OrderedGuard::new(30, &released));
+        let composite = CompositeGuard::new()
+            .add(OrderedGuard::new(10, &released))
+            .add(inner)
+            .add(OrderedGuard::new(40, &released));
+
+        let described: Vec<usize> = composite.describe().iter().map(|m| m.size_bytes).collect();
+        assert_eq!(described, vec![40, 30, 20, 10]);
+        assert!(composite
+            .describe()
+            .iter()
+            .all(|m| m.resource_type == "ordered"));
+        assert_eq!(composite.size_bytes(), 100);
+
+        drop(composite);
+        assert_eq!(*released.lock().unwrap(), described);
+    }
+
     #[test]
     fn test_composite_manual_release() {
         let count = Arc::new(AtomicUsize::new(0));
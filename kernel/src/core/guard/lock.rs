@@ -268,22 +268,34 @@ fn release(&mut self) -> GuardResult<()> {
 }
 
 impl<T: Send + 'static, S: LockState> Recoverable for LockGuard<T, S> {
+    type Recovered = Self;
+
+    /// Poisoned explicitly, or because a holder of the mutex panicked
+    ///
+    /// Check this before `lock()`, which consumes the guard and fails on a
+    /// poisoned mutex.
     fn is_poisoned(&self) -> bool {
-        self.poisoned
+        self.poisoned || self.data.is_poisoned()
     }
 
-    fn recover(&mut self) -> GuardResult<()> {
-        if !self.poisoned {
-            return Ok(());
+    /// Clear the poison flag and the mutex poison, keeping the data as the
+    /// panicking holder left it
+    fn recover(mut self) -> GuardResult<Self::Recovered> {
+        if self.data.is_poisoned() {
+            self.data.clear_poison();
         }
 
         self.poisoned = false;
         self.poison_reason = None;
-        Ok(())
+        Ok(self)
     }
 
     fn poison_reason(&self) -> Option<&str> {
-        self.poison_reason.as_deref()
+        match self.poison_reason.as_deref() {
+            Some(reason) => Some(reason),
+            None if self.data.is_poisoned() => Some("Mutex poisoned by a panicking holder"),
+            None => None,
+        }
     }
 
     fn poison(&mut self, reason: String) {
@@ -371,10 +383,33 @@ fn test_lock_guard_recoverable() {
         assert!(guard.is_poisoned());
         assert_eq!(guard.poison_reason(), Some("Test poison"));
 
-        guard.recover().unwrap();
+        let guard = guard.recover().unwrap();
         assert!(!guard.is_poisoned());
     }
 
+    #[test]
+    fn test_lock_guard_recovers_from_panicking_holder() {
+        let locked = LockGuard::new(vec![1]).lock().unwrap();
+
+        let panicked = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            let mut data = locked.access_mut();
+            data.push(2);
+            panic!("holder panicked mid-update");
+        }));
+        assert!(panicked.is_err());
+        assert!(locked.is_poisoned());
+        assert!(locked.poison_reason().is_some());
+
+        let locked = locked.recover().unwrap();
+        assert!(!locked.is_poisoned());
+        // Data is kept as the holder left it
+        assert_eq!(*locked.access(), vec![1, 2]);
+
+        // And the guard can cycle through its states again
+        let unlocked = locked.unlock();
+        assert!(unlocked.lock().is_ok());
+    }
+
     #[test]
     fn test_lock_guard_timeout_success() {
         use std::time::Duration;
@@ -33,6 +33,14 @@ pub trait Guard: Send {
     /// Returns `Err` if already released
     fn release(&mut self) -> GuardResult<()>;
 
+    /// Metadata of the resources this guard holds, in release order
+    ///
+    /// A single guard describes itself; guards that hold others describe
+    /// their contents instead.
+    fn describe(&self) -> Vec<GuardMetadata> {
+        vec![self.metadata().clone()]
+    }
+
     /// Leak the guard, preventing Drop from running
     ///
     /// Use when transferring ownership to another system
@@ -60,13 +68,20 @@ pub trait GuardDrop: Guard {
 ///
 /// Useful for lock guards and transaction guards
 pub trait Recoverable: Guard {
+    /// Guard returned by a successful recovery
+    type Recovered;
+
     /// Check if guard is poisoned
     fn is_poisoned(&self) -> bool;
 
-    /// Attempt to recover from poisoned state
+    /// Recover from poisoned state
     ///
-    /// Returns `Ok(())` if recovery succeeded
-    fn recover(&mut self) -> GuardResult<()>;
+    /// Consumes the guard and returns a clean one, or an error if the
+    /// resource could not be brought back to a consistent state. A guard
+    /// that is not poisoned is returned as is.
+    fn recover(self) -> GuardResult<Self::Recovered>
+    where
+        Self: Sized;
 
     /// Get poison reason if poisoned
     fn poison_reason(&self) -> Option<&str>;
@@ -221,19 +221,36 @@ fn on_drop(&mut self) {
 }
 
 impl Recoverable for TransactionGuard {
+    type Recovered = TransactionGuard;
+
     fn is_poisoned(&self) -> bool {
         self.state == TransactionState::Poisoned
     }
 
-    fn recover(&mut self) -> GuardResult<()> {
+    /// Roll back any operations recorded before the poisoning and return the
+    /// guard as a fresh, empty transaction
+    ///
+    /// If the rollback fails the guard is dropped still poisoned, without
+    /// another rollback attempt.
+    fn recover(mut self) -> GuardResult<Self::Recovered> {
         if self.state != TransactionState::Poisoned {
-            return Ok(());
+            return Ok(self);
         }
 
-        // Attempt rollback to recover
-        self.do_rollback()?;
+        if !self.operations.is_empty() {
+            log::info!(
+                "Recovering poisoned transaction, rolling back {} operations",
+                self.operations.len()
+            );
+            (self.rollback_fn)(&self.operations).map_err(|e| {
+                GuardError::OperationFailed(format!("Rollback during recovery failed: {}", e))
+            })?;
+            self.operations.clear();
+        }
+
+        self.state = TransactionState::Active;
         self.poison_reason = None;
-        Ok(())
+        Ok(self)
     }
 
     fn poison_reason(&self) -> Option<&str> {
@@ -342,4 +359,64 @@ fn test_transaction_execute() {
         assert_eq!(tx_result.load(Ordering::SeqCst), 42);
         assert_eq!(tx.operations().len(), 1);
     }
+
+    #[test]
+    fn test_recover_rolls_back_partial_work() {
+        let rolled_back = Arc::new(AtomicUsize::new(0));
+        let rolled_back_clone = rolled_back.clone();
+
+        let mut tx = TransactionGuard::new(
+            None,
+            |_: &[Operation]| Ok(()),
+            move |ops: &[Operation]| {
+                rolled_back_clone.fetch_add(ops.len(), Ordering::SeqCst);
+                Ok(())
+            },
+        );
+
+        let result: GuardResult<()> = tx.execute(|tx| {
+            tx.add_operation(Operation::new("partial", vec![1]))?;
+            panic!("operation failed halfway");
+        });
+        assert!(matches!(result, Err(GuardError::Poisoned(_))));
+        assert!(tx.is_poisoned());
+
+        let mut tx = tx.recover().unwrap();
+        assert_eq!(rolled_back.load(Ordering::SeqCst), 1);
+        assert_eq!(tx.state(), TransactionState::Active);
+        assert!(tx.operations().is_empty());
+        assert!(tx.poison_reason().is_none());
+
+        // The recovered guard is usable again
+        tx.add_operation(Operation::new("retry", vec![2])).unwrap();
+        tx.commit().unwrap();
+        assert_eq!(rolled_back.load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn test_recover_reports_failed_rollback() {
+        let attempts = Arc::new(AtomicUsize::new(0));
+        let attempts_clone = attempts.clone();
+
+        let mut tx = TransactionGuard::new(
+            None,
+            |_: &[Operation]| Ok(()),
+            move |_: &[Operation]| {
+                attempts_clone.fetch_add(1, Ordering::SeqCst);
+                Err("storage offline".to_string())
+            },
+        );
+        tx.add_operation(Operation::new("write", vec![])).unwrap();
+        tx.poison("simulated panic".to_string());
+
+        match tx.recover() {
+            Err(GuardError::OperationFailed(msg)) => assert!(msg.contains("storage offline")),
+            other => panic!(
+                "expected rollback failure, got {:?}",
+                other.map(|tx| tx.state())
+            ),
+        }
+        // Dropping the poisoned guard does not retry the rollback
+        assert_eq!(attempts.load(Ordering::SeqCst), 1);
+    }
 }
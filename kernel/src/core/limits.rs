@@ -44,6 +44,15 @@
 /// [PERF] Amortizes O(n log n) sorting cost across deallocations
 pub const DEALLOC_COALESCE_INTERVAL: u64 = 100;
 
+/// Minimum interval between memory pressure notifications (1 second)
+/// Suppresses flapping when usage hovers around a threshold
+/// Escalation to critical is always delivered immediately
+pub const MEMORY_PRESSURE_DEBOUNCE: Duration = Duration::from_secs(1);
+
+/// Copy-on-write page size for simulated memory blocks (4KB)
+/// A write to a forked block copies only the pages it touches
+pub const COW_PAGE_SIZE: usize = 4096;
+
 // =============================================================================
 // PROCESS RESOURCE LIMITS
 // =============================================================================
@@ -89,6 +98,9 @@
 /// Max sockets per process
 pub const MAX_SOCKETS: usize = 100;
 
+/// Idle keep-alive connections kept per host in the HTTP pool
+pub const HTTP_POOL_MAX_IDLE_PER_HOST: usize = 4;
+
 /// High memory usage threshold (100MB)
 /// Triggers resource monitoring alerts
 pub const HIGH_MEMORY_THRESHOLD: usize = 100 * 1024 * 1024;
@@ -96,6 +108,21 @@
 /// High file descriptor count threshold
 pub const HIGH_FD_THRESHOLD: usize = 100;
 
+/// Maximum descriptors in one Poll syscall
+pub const MAX_POLL_FDS: usize = 1024;
+
+/// Longest a blocked Poll sleeps before re-checking readiness (10ms)
+/// Only used when a watched stream has no OS descriptor to wait on
+pub const POLL_RECHECK_INTERVAL: Duration = Duration::from_millis(10);
+
+/// Longest a single Poll may block, with or without a timeout (30s)
+/// Keeps an unbounded poll from pinning a syscall thread forever
+pub const MAX_POLL_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Cleanup errors kept in the shutdown report
+/// Later errors are still counted in errors_encountered
+pub const MAX_SHUTDOWN_REPORT_ERRORS: usize = 1000;
+
 // =============================================================================
 // IPC LIMITS
 // =============================================================================
@@ -108,6 +135,10 @@
 /// Prevents excessive memory use per pipe
 pub const MAX_PIPE_CAPACITY: usize = 1024 * 1024;
 
+/// Pipe writes up to this size are never split (4KB)
+/// [LINUX-COMPAT] Matches Linux PIPE_BUF
+pub const PIPE_BUF: usize = 4096;
+
 /// Maximum pipes per process
 pub const MAX_PIPES_PER_PROCESS: usize = 100;
 
@@ -118,6 +149,28 @@
 /// Maximum message queue capacity (10,000 messages)
 pub const MAX_QUEUE_CAPACITY: usize = 10_000;
 
+/// Queue fill level at which shedding sends start dropping messages (90%)
+/// Drops the lowest-priority queued message instead of failing the sender
+pub const QUEUE_SHED_THRESHOLD_PCT: usize = 90;
+
+/// Wait that raises a priority-queue message by one priority level (100ms)
+/// Bounds starvation: a priority-0 message outranks a fresh priority-255 one after ~25s
+pub const QUEUE_PRIORITY_AGING_INTERVAL: Duration = Duration::from_millis(100);
+
+/// Poll interval while a draining queue waits for consumers (5ms)
+pub const QUEUE_DRAIN_POLL_INTERVAL: Duration = Duration::from_millis(5);
+
+/// Longest a destroy may wait for consumers to drain a queue (30s)
+/// Longer requests are clamped so a destroy can't hold the queue open indefinitely
+pub const MAX_QUEUE_DRAIN_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// How long an at-least-once message stays leased awaiting its ack (30s)
+/// Unacknowledged messages are redelivered once it runs out
+pub const QUEUE_VISIBILITY_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Deliveries of an at-least-once message before it is dead-lettered
+pub const QUEUE_MAX_DELIVERY_ATTEMPTS: u32 = 5;
+
 /// Maximum message size (1MB)
 /// Applies to both pipes and message queues
 pub const MAX_MESSAGE_SIZE: usize = 1024 * 1024;
@@ -137,6 +190,14 @@
 /// Global shared memory limit (500MB)
 pub const GLOBAL_SHM_MEMORY_LIMIT: usize = 500 * 1024 * 1024;
 
+/// Memory-mapped file page size (4KB)
+/// Granularity at which mmap regions are faulted in from the backing file
+/// [LINUX-COMPAT] Matches the common x86_64 page size
+pub const MMAP_PAGE_SIZE: usize = 4096;
+
+/// Default cap on bytes mapped by one process across all its mmaps (256MB)
+pub const MAX_MMAP_BYTES_PER_PROCESS: usize = 256 * 1024 * 1024;
+
 /// IPC manager queue size (1000 pending operations)
 pub const IPC_MANAGER_QUEUE_SIZE: usize = 1000;
 
@@ -162,10 +223,33 @@
 /// Process-specific io_uring CQ size
 pub const PROCESS_ZEROCOPY_CQ_SIZE: usize = 256;
 
+/// Longest a blocked zero-copy submitter sleeps before re-checking the SQ (10ms)
+/// Bounds the delay from a wakeup that lands between the full check and the park
+pub const ZEROCOPY_SUBMIT_RECHECK_INTERVAL: Duration = Duration::from_millis(10);
+
 /// io_uring batch size for syscall submission
 /// [PERF] Amortizes syscall overhead
 pub const IOURING_BATCH_SIZE: usize = 32;
 
+/// Default chunk size for streamed io_uring file reads (64KB)
+pub const IOURING_STREAM_CHUNK_SIZE: usize = 64 * 1024;
+
+/// Largest chunk a streamed io_uring read may request (4MB)
+pub const IOURING_MAX_STREAM_CHUNK: usize = 4 * 1024 * 1024;
+
+/// Adaptive syscall batch size bounds for BatchExecutor submissions
+/// [PERF] Grows toward max under backlog, shrinks to min when the queue drains
+pub const BATCH_MIN_SIZE: usize = 1;
+pub const BATCH_MAX_SIZE: usize = 64;
+
+/// Maximum adaptive batch collection window (2ms)
+/// [PERF] Upper bound on latency added while waiting for a batch to fill
+pub const BATCH_MAX_WINDOW_US: u64 = 2_000;
+
+/// Blocking syscalls a BatchExecutor runs at once across all its batches
+/// [PERF] Keeps a large batch from flooding the blocking thread pool
+pub const BATCH_MAX_CONCURRENCY: usize = 64;
+
 /// Default streaming chunk size (64KB)
 /// Balance between throughput and latency
 pub const DEFAULT_CHUNK_SIZE: usize = 64 * 1024;
@@ -242,10 +326,40 @@
 /// How often to scan for stale tasks
 pub const TASK_CLEANUP_INTERVAL: Duration = Duration::from_secs(300);
 
+/// Process resource history sampling interval (1 second)
+/// How often per-process ResourceUsage is recorded
+pub const RESOURCE_HISTORY_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Process resource history depth (300 samples)
+/// Five minutes of history at the default interval
+pub const RESOURCE_HISTORY_DEPTH: usize = 300;
+
 /// Maximum sleep duration for sys_sleep (1 minute)
 /// [SECURITY] Prevents processes from sleeping indefinitely
 pub const MAX_SLEEP_DURATION_MS: u64 = 60_000;
 
+/// HTTP pool idle timeout (90 seconds)
+/// Pooled connections unused for this long are closed
+pub const HTTP_POOL_IDLE_TIMEOUT: Duration = Duration::from_secs(90);
+
+/// Consecutive failures that open a resource's circuit breaker
+/// Only backend failures count (I/O errors, refused connections, timeouts)
+pub const CIRCUIT_BREAKER_FAILURE_THRESHOLD: u32 = 5;
+
+/// How long an open circuit fails fast before probing the resource (30 seconds)
+pub const CIRCUIT_BREAKER_COOLDOWN: Duration = Duration::from_secs(30);
+
+/// Base delay before the first NetworkRequest retry (100ms)
+/// Doubles with each further retry
+pub const NETWORK_RETRY_BASE_DELAY_MS: u64 = 100;
+
+/// Longest delay between NetworkRequest retries (5 seconds)
+pub const NETWORK_RETRY_MAX_DELAY_MS: u64 = 5_000;
+
+/// Max retries a NetworkRequest retry policy may ask for
+/// [SECURITY] Bounds how long one request can keep a worker busy
+pub const NETWORK_RETRY_MAX_RETRIES: u32 = 8;
+
 /// gRPC client timeout (30 seconds)
 pub const GRPC_CLIENT_TIMEOUT: Duration = Duration::from_secs(30);
 
@@ -290,6 +404,10 @@
 /// [PERF] Must be power of 2 for lock-free ring buffer
 pub const EVENT_RING_SIZE: usize = 65536;
 
+/// Consecutive failed publishes before the event stream is declared stalled (1024)
+/// Long enough to ride out a burst, short enough to flag a dead consumer quickly
+pub const EVENT_STREAM_STALL_THRESHOLD: u64 = 1024;
+
 /// Minimum samples for anomaly detection (100 samples)
 /// Statistical anomaly detection needs sufficient baseline
 pub const MIN_ANOMALY_SAMPLES: u64 = 100;
@@ -314,6 +432,17 @@
 /// Used for anomaly detection baseline
 pub const EXPECTED_RESOURCES_FREED: f64 = 100.0;
 
+/// Distinct syscall names tracked in per-syscall latency metrics (64)
+/// [PERF] Bounds label cardinality; further names are recorded as "other"
+pub const MAX_TRACKED_SYSCALL_NAMES: usize = 64;
+
+/// Maximum length of a slow syscall argument snapshot (512 bytes)
+/// [SECURITY] Bounds event size; data buffers are recorded by length only
+pub const SLOW_SYSCALL_ARGS_MAX_LEN: usize = 512;
+
+/// Maximum length of a single captured syscall argument value (128 bytes)
+pub const SLOW_SYSCALL_ARG_VALUE_MAX_LEN: usize = 128;
+
 // =============================================================================
 // SECURITY & AUDIT
 // =============================================================================
@@ -326,10 +455,31 @@
 /// [SECURITY] Per-process limit for fine-grained tracking
 pub const MAX_AUDIT_EVENTS_PER_PID: usize = 100;
 
+/// Denials within the escalation window that trigger escalation (20 denials)
+/// [SECURITY] Repeated denials suggest a compromised or misbehaving process
+pub const DENIAL_ESCALATION_THRESHOLD: usize = 20;
+
+/// Sliding window for counting denials toward escalation (60 seconds)
+pub const DENIAL_ESCALATION_WINDOW: Duration = Duration::from_secs(60);
+
+/// How often queued throttle and kill escalations are carried out (1 second)
+pub const ESCALATION_POLL_INTERVAL: Duration = Duration::from_secs(1);
+
 /// Maximum eBPF event history (10,000 events)
 /// [SECURITY] For eBPF-based security monitoring
 pub const MAX_EBPF_EVENT_HISTORY: usize = 10_000;
 
+/// Largest single GetRandom request (256 KB)
+/// [SECURITY] Bigger requests would hold the entropy path for too long
+pub const GETRANDOM_MAX_LEN: usize = 256 * 1024;
+
+/// Sustained random bytes a process may draw per second (1 MB/s)
+/// [SECURITY] Keeps one process from monopolizing the OS RNG
+pub const ENTROPY_BYTES_PER_SEC: u64 = 1024 * 1024;
+
+/// Random bytes a process may draw at once after being idle (256 KB)
+pub const ENTROPY_BURST_BYTES: u64 = 256 * 1024;
+
 /// Maximum pending signals per process (128 signals)
 /// [LINUX-COMPAT] Matches typical Linux signal queue depth
 pub const MAX_PENDING_SIGNALS: usize = 128;
@@ -356,6 +506,22 @@
 /// /cache filesystem capacity (50MB)
 pub const CACHE_FILESYSTEM_CAPACITY: usize = 50 * 1024 * 1024;
 
+/// Default debounce window for recursive directory watches (50ms)
+/// Rapid events on the same path within the window are coalesced
+pub const VFS_WATCH_DEBOUNCE_MS: u64 = 50;
+
+/// Default LocalFS read-ahead window (128KB)
+/// [PERF] Matches the Linux default readahead for sequential file reads
+pub const LOCALFS_READ_AHEAD_WINDOW: usize = 128 * 1024;
+
+/// Write-combining buffer size for fds opened with O_WRITE_COMBINE (16KB)
+/// [PERF] Small writes reach the filesystem once per buffer instead of per call
+pub const WRITE_COMBINE_THRESHOLD: usize = 16 * 1024;
+
+/// Longest a blocking range lock request (F_SETLKW) waits (30 seconds)
+/// Bounded so a lock holder that never exits can't wedge a syscall thread
+pub const VFS_LOCK_WAIT_TIMEOUT: Duration = Duration::from_secs(30);
+
 // =============================================================================
 // CPU SHARES (Priority System)
 // =============================================================================
@@ -422,6 +588,7 @@ fn test_ipc_limits_consistent() {
         // Ensure per-process limits don't exceed global limits
         assert!(DEFAULT_PIPE_CAPACITY < GLOBAL_PIPE_MEMORY_LIMIT);
         assert!(MAX_MESSAGE_SIZE <= GLOBAL_QUEUE_MEMORY_LIMIT);
+        assert!(QUEUE_DRAIN_POLL_INTERVAL < MAX_QUEUE_DRAIN_TIMEOUT);
     }
 
     #[test]
@@ -3,43 +3,54 @@
  * Share memory between processes with lazy copying
  */
 
+use crate::core::simd::simd_memset;
 use crate::core::sync::StripedMap;
-use std::sync::{Arc, Mutex};
+use std::collections::HashSet;
+use std::sync::Arc;
 
 /// Copy-on-Write memory region
 ///
+/// The region is split into pages that are shared independently, so a write
+/// copies only the pages it touches. Regions created with [`new`](Self::new)
+/// are a single page and copy whole on first write.
+///
 /// # Performance
 ///
-/// - **Initial copy**: Instant (just Arc clone)
-/// - **First write**: Triggers actual copy
+/// - **Initial copy**: Instant (just Arc clones)
+/// - **First write**: Copies the touched pages
 /// - **Memory savings**: 80%+ for similar processes
 ///
 /// # Example
 ///
 /// ```ignore
-/// let parent_memory = CowMemory::new(data);
+/// let parent_memory = CowMemory::with_page_size(vec![0; 16 * 4096], 4096);
 ///
 /// // Child gets CoW reference (instant)
-/// let child_memory = parent_memory.clone_cow();
-///
-/// // Parent and child share memory until write
-/// assert!(Arc::ptr_eq(&parent_memory.data, &child_memory.data));
+/// let mut child_memory = parent_memory.clone_cow();
 ///
-/// // First write triggers copy
-/// child_memory.write(|data| data[0] = 99);
-/// assert!(!Arc::ptr_eq(&parent_memory.data, &child_memory.data));
+/// // First write copies only the page it lands on
+/// child_memory.write_at(4096, &[99]).unwrap();
+/// assert_eq!(child_memory.private_pages(), 1);
 /// ```
 #[derive(Debug)]
 pub struct CowMemory {
-    data: Arc<Mutex<Vec<u8>>>,
+    pages: Vec<Arc<Vec<u8>>>,
+    page_size: usize,
     has_written: bool,
 }
 
 impl CowMemory {
-    /// Create new CoW memory region
+    /// Create new CoW memory region copied whole on first write
     pub fn new(data: Vec<u8>) -> Self {
+        Self::with_page_size(data, usize::MAX)
+    }
+
+    /// Create new CoW memory region shared in pages of `page_size` bytes
+    pub fn with_page_size(data: Vec<u8>, page_size: usize) -> Self {
+        let page_size = page_size.max(1);
         Self {
-            data: Arc::new(Mutex::new(data).into()),
+            pages: paginate(data, page_size),
+            page_size,
             has_written: false,
         }
     }
@@ -47,82 +58,201 @@ pub fn new(data: Vec<u8>) -> Self {
     /// Clone for CoW (instant operation)
     pub fn clone_cow(&self) -> Self {
         Self {
-            data: Arc::clone(&self.data),
+            pages: self.pages.clone(),
+            page_size: self.page_size,
             has_written: false,
         }
     }
 
     /// Read data (shared, no copy)
+    ///
+    /// A region spanning several pages is gathered into one buffer first;
+    /// use [`read_at`](Self::read_at) to read part of it without that copy.
     pub fn read<F, R>(&self, f: F) -> R
     where
         F: FnOnce(&[u8]) -> R,
     {
-        let guard = self.data.lock().unwrap();
-        f(&guard)
+        match self.pages.as_slice() {
+            [page] => f(page),
+            _ => f(&self.gather()),
+        }
     }
 
-    /// Write data (triggers copy if shared)
+    /// Copy `buf.len()` bytes starting at `offset` into `buf`
+    pub fn read_at(&self, offset: usize, buf: &mut [u8]) -> Result<(), &'static str> {
+        self.check_range(offset, buf.len())?;
+        let mut copied = 0;
+        while copied < buf.len() {
+            let (index, start) = self.locate(offset + copied);
+            let page = &self.pages[index];
+            let n = (page.len() - start).min(buf.len() - copied);
+            buf[copied..copied + n].copy_from_slice(&page[start..start + n]);
+            copied += n;
+        }
+        Ok(())
+    }
+
+    /// Write data (copies every shared page)
+    ///
+    /// `f` sees the whole region and may resize it.
     pub fn write<F, R>(&mut self, f: F) -> R
     where
         F: FnOnce(&mut Vec<u8>) -> R,
     {
-        // If this Arc has multiple owners, we need to copy
-        if Arc::strong_count(&self.data) > 1 {
-            // Copy-on-write: clone the data
-            let current = self.data.lock().unwrap();
-            let mut new_data = current.clone();
-            drop(current);
+        self.has_written = true;
+        if let [page] = self.pages.as_mut_slice() {
+            // Arc::make_mut copies only if another region shares the page
+            let data = Arc::make_mut(page);
+            let result = f(data);
+            if data.len() > self.page_size {
+                let data = std::mem::take(data);
+                self.pages = paginate(data, self.page_size);
+            }
+            return result;
+        }
 
-            let result = f(&mut new_data);
+        let mut data = self.gather();
+        let result = f(&mut data);
+        self.pages = paginate(data, self.page_size);
+        result
+    }
 
-            // Replace our Arc with exclusive copy
-            self.data = Arc::new(Mutex::new(new_data));
-            self.has_written = true;
+    /// Overwrite bytes starting at `offset`, copying only the pages touched
+    pub fn write_at(&mut self, offset: usize, data: &[u8]) -> Result<(), &'static str> {
+        self.check_range(offset, data.len())?;
+        self.has_written = true;
+        let mut written = 0;
+        while written < data.len() {
+            let (index, start) = self.locate(offset + written);
+            let page = Arc::make_mut(&mut self.pages[index]);
+            let n = (page.len() - start).min(data.len() - written);
+            page[start..start + n].copy_from_slice(&data[written..written + n]);
+            written += n;
+        }
+        Ok(())
+    }
 
-            result
-        } else {
-            // We have exclusive access, no copy needed
-            let mut guard = self.data.lock().unwrap();
-            self.has_written = true;
-            f(&mut guard)
+    /// Overwrite the whole region with zeros
+    ///
+    /// Private pages are cleared in place; shared pages are replaced with
+    /// fresh zeroed ones, so regions sharing them keep their contents.
+    pub fn zero(&mut self) {
+        self.has_written = true;
+        for page in &mut self.pages {
+            match Arc::get_mut(page) {
+                Some(data) => {
+                    simd_memset(data, 0);
+                }
+                None => *page = Arc::new(vec![0u8; page.len()]),
+            }
         }
     }
 
-    /// Check if memory is shared
+    /// Check if any page is shared
     pub fn is_shared(&self) -> bool {
-        Arc::strong_count(&self.data) > 1
+        self.pages.iter().any(|page| Arc::strong_count(page) > 1)
+    }
+
+    /// Number of regions referencing the most shared page (1 when private)
+    pub fn share_count(&self) -> usize {
+        self.pages.iter().map(Arc::strong_count).max().unwrap_or(1)
+    }
+
+    /// Identity of the first page, equal for regions sharing it
+    pub fn buffer_id(&self) -> usize {
+        Arc::as_ptr(&self.pages[0]) as usize
+    }
+
+    /// Bytes per page (`usize::MAX` for regions copied whole)
+    pub fn page_size(&self) -> usize {
+        self.page_size
+    }
+
+    /// Number of pages shared with another region
+    pub fn shared_pages(&self) -> usize {
+        self.pages
+            .iter()
+            .filter(|page| Arc::strong_count(page) > 1)
+            .count()
+    }
+
+    /// Number of pages owned by this region alone
+    pub fn private_pages(&self) -> usize {
+        self.pages.len() - self.shared_pages()
     }
 
     /// Get size
     pub fn len(&self) -> usize {
-        self.data.lock().unwrap().len()
+        self.pages.iter().map(|page| page.len()).sum()
     }
 
     /// Check if empty
     pub fn is_empty(&self) -> bool {
-        self.data.lock().unwrap().is_empty()
+        self.pages.iter().all(|page| page.is_empty())
+    }
+
+    /// All pages copied into one buffer
+    fn gather(&self) -> Vec<u8> {
+        let mut data = Vec::with_capacity(self.len());
+        for page in &self.pages {
+            data.extend_from_slice(page);
+        }
+        data
+    }
+
+    fn check_range(&self, offset: usize, len: usize) -> Result<(), &'static str> {
+        match offset.checked_add(len) {
+            Some(end) if end <= self.len() => Ok(()),
+            _ => Err("Range out of bounds"),
+        }
+    }
+
+    /// Page index and offset within it for a byte position
+    fn locate(&self, position: usize) -> (usize, usize) {
+        (position / self.page_size, position % self.page_size)
     }
 }
 
+/// Split data into pages of `page_size` bytes, always at least one
+fn paginate(data: Vec<u8>, page_size: usize) -> Vec<Arc<Vec<u8>>> {
+    if data.len() <= page_size {
+        return vec![Arc::new(data)];
+    }
+    data.chunks(page_size)
+        .map(|chunk| Arc::new(chunk.to_vec()))
+        .collect()
+}
+
 /// Copy-on-Write process memory manager
 ///
 /// Manages CoW memory for multiple processes with automatic deduplication.
 pub struct CowMemoryManager {
     /// Per-process memory regions
     regions: StripedMap<u32, CowMemory>,
+    /// Page size for new regions
+    page_size: usize,
 }
 
 impl CowMemoryManager {
-    /// Create new manager
+    /// Create new manager whose regions are copied whole on first write
     pub fn new() -> Self {
         Self {
             regions: StripedMap::new(32),
+            page_size: usize::MAX,
         }
     }
 
+    /// Share regions in pages of `page_size` bytes, so writes after a fork
+    /// copy only the pages they touch
+    pub fn with_page_size(mut self, page_size: usize) -> Self {
+        self.page_size = page_size;
+        self
+    }
+
     /// Allocate memory for process
     pub fn allocate(&self, pid: u32, data: Vec<u8>) {
-        self.regions.insert(pid, CowMemory::new(data));
+        self.regions
+            .insert(pid, CowMemory::with_page_size(data, self.page_size));
     }
 
     /// Fork process memory (CoW)
@@ -159,6 +289,13 @@ pub fn write<F, R>(&self, pid: u32, f: F) -> Result<R, &'static str>
             .ok_or("Process not found")
     }
 
+    /// Overwrite process memory at `offset` (copies only the touched pages)
+    pub fn write_at(&self, pid: u32, offset: usize, data: &[u8]) -> Result<(), &'static str> {
+        self.regions
+            .get_mut(&pid, |memory| memory.write_at(offset, data))
+            .ok_or("Process not found")?
+    }
+
     /// Free process memory
     pub fn free(&self, pid: u32) -> Result<(), &'static str> {
         self.regions.remove(&pid).ok_or("Process not found")?;
@@ -167,20 +304,9 @@ pub fn free(&self, pid: u32) -> Result<(), &'static str> {
 
     /// Get memory stats
     pub fn stats(&self) -> CowStats {
-        let total_regions = self.regions.len();
-        let mut shared_regions = 0;
-
-        self.regions.iter(|_pid, memory| {
-            if memory.is_shared() {
-                shared_regions += 1;
-            }
-        });
-
-        CowStats {
-            total_regions,
-            shared_regions,
-            unique_regions: total_regions - shared_regions,
-        }
+        let mut accounting = CowAccounting::default();
+        self.regions.iter(|_pid, memory| accounting.record(memory));
+        accounting.finish()
     }
 }
 
@@ -191,11 +317,58 @@ fn default() -> Self {
 }
 
 /// CoW memory statistics
-#[derive(Debug, Clone)]
+///
+/// Shared pages are counted once in `pages_shared` and `shared_bytes`
+/// however many regions reference them, so `shared_bytes + private_bytes`
+/// is the real footprint.
+#[derive(Debug, Clone, Default)]
 pub struct CowStats {
     pub total_regions: usize,
+    /// Regions with at least one shared page
     pub shared_regions: usize,
     pub unique_regions: usize,
+    /// Bytes in pages referenced by more than one region
+    pub shared_bytes: usize,
+    /// Bytes in pages owned by a single region
+    pub private_bytes: usize,
+    /// Distinct pages referenced by more than one region
+    pub pages_shared: usize,
+    /// Pages owned by a single region
+    pub pages_private: usize,
+}
+
+/// Accumulates CowStats over a set of regions
+#[derive(Debug, Default)]
+pub struct CowAccounting {
+    stats: CowStats,
+    seen_shared: HashSet<usize>,
+}
+
+impl CowAccounting {
+    /// Account for one region
+    pub fn record(&mut self, memory: &CowMemory) {
+        let stats = &mut self.stats;
+        stats.total_regions += 1;
+        if memory.is_shared() {
+            stats.shared_regions += 1;
+        } else {
+            stats.unique_regions += 1;
+        }
+
+        for page in &memory.pages {
+            if Arc::strong_count(page) == 1 {
+                stats.pages_private += 1;
+                stats.private_bytes += page.len();
+            } else if self.seen_shared.insert(Arc::as_ptr(page) as usize) {
+                stats.pages_shared += 1;
+                stats.shared_bytes += page.len();
+            }
+        }
+    }
+
+    pub fn finish(self) -> CowStats {
+        self.stats
+    }
 }
 
 #[cfg(test)]
@@ -263,6 +436,66 @@ fn test_cow_stats() {
         let stats = manager.stats();
         assert_eq!(stats.total_regions, 3);
         assert!(stats.shared_regions > 0);
+
+        // The shared buffer is counted once
+        assert_eq!(stats.shared_bytes, 3);
+        assert_eq!(stats.private_bytes, 0);
+
+        manager.write(2, |d| d.push(4)).unwrap();
+        let stats = manager.stats();
+        assert_eq!(stats.shared_bytes, 3);
+        assert_eq!(stats.private_bytes, 4);
+    }
+
+    #[test]
+    fn test_fork_copies_only_written_page() {
+        let page_size = 4096;
+        let manager = CowMemoryManager::new().with_page_size(page_size);
+        manager.allocate(1, vec![7; 256 * page_size]); // 1MB
+        manager.fork(1, 2).unwrap();
+
+        let stats = manager.stats();
+        assert_eq!(stats.pages_shared, 256);
+        assert_eq!(stats.pages_private, 0);
+
+        // A small write inside page 10 copies that page alone
+        manager.write_at(2, 10 * page_size + 5, &[1, 2, 3]).unwrap();
+
+        let stats = manager.stats();
+        assert_eq!(stats.pages_shared, 255);
+        // Child's copy, plus the parent's page 10 that is no longer shared
+        assert_eq!(stats.pages_private, 2);
+        assert_eq!(stats.private_bytes, 2 * page_size);
+        assert_eq!(stats.shared_regions, 2);
+
+        let mut child = [0u8; 4];
+        manager
+            .regions
+            .get(&2, |memory| memory.read_at(10 * page_size + 4, &mut child))
+            .unwrap()
+            .unwrap();
+        assert_eq!(child, [7, 1, 2, 3]);
+        assert_eq!(manager.read(1, |d| d[10 * page_size + 5]).unwrap(), 7);
+
+        // Writes spanning a page boundary copy both pages
+        manager.write_at(2, 20 * page_size - 1, &[0, 0]).unwrap();
+        assert_eq!(manager.stats().pages_private, 6);
+        assert!(manager.write_at(2, 256 * page_size - 1, &[0, 0]).is_err());
+    }
+
+    #[test]
+    fn test_zero_leaves_sharers_intact() {
+        let mut parent = CowMemory::with_page_size(vec![9; 3 * 64], 64);
+        let mut child = parent.clone_cow();
+        child.write_at(0, &[1]).unwrap();
+
+        child.zero();
+        assert!(child.read(|d| d.iter().all(|&b| b == 0)));
+        assert_eq!(child.shared_pages(), 0);
+        assert!(parent.read(|d| d.iter().all(|&b| b == 9)));
+
+        parent.zero();
+        assert!(parent.read(|d| d.iter().all(|&b| b == 0)));
     }
 
     #[test]
@@ -24,5 +24,5 @@
 mod pool;
 
 pub use arena::{with_arena, ArenaString, ArenaVec};
-pub use cow_memory::{CowMemory, CowMemoryManager, CowStats};
+pub use cow_memory::{CowAccounting, CowMemory, CowMemoryManager, CowStats};
 pub use pool::{PooledBuffer, SharedPool};
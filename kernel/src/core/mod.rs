@@ -7,6 +7,7 @@
  *
  * - **errors**, **types**, **traits**: Core abstractions and type system
  * - **limits**: System-wide limits and constants
+ * - **circuit_breaker**: Fail-fast circuits for failing resources
  * - **guard**: RAII resource guards with type-state pattern
  * - **sync**: Synchronization primitives (locks, wait queues, RCU)
  * - **memory**: Memory utilities (arena, CoW, pooling)
@@ -17,6 +18,7 @@
  */
 
 // Core abstractions
+pub mod circuit_breaker;
 pub mod errors;
 pub mod guard;
 pub mod id;
@@ -34,6 +36,7 @@
 pub mod sync;
 
 // Re-export core abstractions
+pub use circuit_breaker::{BreakerConfig, BreakerState, BreakerStats, CircuitBreaker, CircuitOpen};
 pub use errors::*;
 pub use id::*;
 pub use limits::*;
@@ -68,7 +71,7 @@
 };
 
 // Re-export data structures
-pub use data_structures::{EpochFdTable, InlineString};
+pub use data_structures::{EpochFdTable, EpochReclaimStats, InlineString};
 
 // Re-export optimization utilities
 pub use optimization::{
@@ -79,9 +82,11 @@
 // Re-export SIMD operations
 pub use simd::{
     ascii_to_lower, ascii_to_upper, avg_u64, capabilities as simd_capabilities, contains_byte,
-    count_byte, detect_simd_support, find_byte, init_simd, is_ascii, max_u64, min_u64, rfind_byte,
-    simd_memcmp, simd_memcpy, simd_memmove, simd_memset, sum_u32, sum_u64, trim, trim_end,
-    trim_start, SimdCapabilities,
+    count_byte, detect_simd_support, find_byte, init_simd, is_ascii, max_u64, min_u64,
+    path_stats as simd_path_stats, reset_path_stats as reset_simd_path_stats, rfind_byte,
+    set_path_stats_enabled as set_simd_path_stats_enabled, simd_memcmp, simd_memcpy, simd_memmove,
+    simd_memset, sum_u32, sum_u64, trim, trim_end, trim_start, PathCounts, SimdCapabilities,
+    SimdPath, SimdPathStats,
 };
 
 // Re-export CPU hints wildcard (barrier, spin_loop, etc.)
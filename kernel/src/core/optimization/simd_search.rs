@@ -64,9 +64,15 @@ unsafe fn find_hash_avx2(needle: u64, haystack: &[u64]) -> Option<usize> {
         .map(|pos| remainder_start + pos)
 }
 
-/// SIMD-accelerated substring search for path matching
+/// SIMD-accelerated prefix search for path matching
 ///
-/// Uses SIMD to quickly check if any path in a list starts with a prefix.
+/// Returns the index of the first prefix `path` starts with. The first 8
+/// bytes of every prefix are packed into a word and compared against the
+/// path under a per-prefix mask, 4 prefixes at a time with AVX2; only
+/// candidates passing that filter get a full `starts_with` check.
+///
+/// Matching is byte-wise: callers wanting component-aware matching should
+/// end paths and prefixes with a separator.
 #[inline]
 pub fn path_starts_with_any(path: &str, prefixes: &[&str]) -> Option<usize> {
     // For very short lists, scalar is faster due to overhead
@@ -74,28 +80,83 @@ pub fn path_starts_with_any(path: &str, prefixes: &[&str]) -> Option<usize> {
         return prefixes.iter().position(|&prefix| path.starts_with(prefix));
     }
 
-    // For longer lists, hash-based SIMD search is faster
-    // Hash first N bytes of path and prefixes
-    let path_hash = hash_prefix(path.as_bytes());
+    let words: Vec<u64> = prefixes.iter().map(|p| hash_prefix(p.as_bytes())).collect();
+    let masks: Vec<u64> = prefixes.iter().map(|p| prefix_mask(p.len())).collect();
+    find_prefix(path, &words, &masks, &|idx| path.starts_with(prefixes[idx]))
+}
 
-    let prefix_hashes: Vec<u64> = prefixes
-        .iter()
-        .map(|p| hash_prefix(p.as_bytes().into()))
-        .collect();
-
-    // SIMD search for matching hash
-    if let Some(idx) = find_hash_simd(path_hash, &prefix_hashes) {
-        // Verify actual match (hash collision check)
-        if path.starts_with(prefixes[idx]) {
-            return Some(idx);
+/// Prefixes packed once for repeated [`path_starts_with_any`] lookups
+///
+/// Rule lists are checked far more often than they change, so keeping the
+/// packed words and masks saves rebuilding them on every lookup.
+#[derive(Debug, Clone, Default)]
+pub struct PrefixSet {
+    prefixes: Vec<String>,
+    words: Vec<u64>,
+    masks: Vec<u64>,
+}
+
+impl PrefixSet {
+    pub fn new(prefixes: Vec<String>) -> Self {
+        let words = prefixes.iter().map(|p| hash_prefix(p.as_bytes())).collect();
+        let masks = prefixes.iter().map(|p| prefix_mask(p.len())).collect();
+        Self {
+            prefixes,
+            words,
+            masks,
         }
     }
 
-    // Fallback to linear search if hash gave false positive
-    prefixes.iter().position(|&prefix| path.starts_with(prefix))
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.prefixes.len()
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.prefixes.is_empty()
+    }
+
+    /// Index of the first prefix `path` starts with
+    #[inline]
+    pub fn find(&self, path: &str) -> Option<usize> {
+        if self.prefixes.len() < 4 {
+            return self
+                .prefixes
+                .iter()
+                .position(|prefix| path.starts_with(prefix.as_str()));
+        }
+        find_prefix(path, &self.words, &self.masks, &|idx| {
+            path.starts_with(self.prefixes[idx].as_str())
+        })
+    }
 }
 
-/// Fast hash of first 8 bytes for prefix matching
+/// Masked filter over packed prefixes, confirming candidates with `verify`
+#[inline]
+fn find_prefix(
+    path: &str,
+    words: &[u64],
+    masks: &[u64],
+    verify: &dyn Fn(usize) -> bool,
+) -> Option<usize> {
+    use crate::core::simd::{record_path, SimdOp, SimdPath};
+
+    let path_word = hash_prefix(path.as_bytes());
+
+    #[cfg(target_arch = "x86_64")]
+    {
+        if is_x86_feature_detected!("avx2") {
+            record_path(SimdOp::PathPrefix, SimdPath::Avx2);
+            return unsafe { find_prefix_avx2(path_word, words, masks, verify) };
+        }
+    }
+
+    record_path(SimdOp::PathPrefix, SimdPath::Scalar);
+    (0..words.len()).find(|&idx| path_word & masks[idx] == words[idx] && verify(idx))
+}
+
+/// Pack the first 8 bytes little-endian (zero-padded) for prefix matching
 #[inline]
 fn hash_prefix(bytes: &[u8]) -> u64 {
     let mut hash = 0u64;
@@ -105,6 +166,50 @@ fn hash_prefix(bytes: &[u8]) -> u64 {
     hash
 }
 
+/// Mask selecting the packed bytes a prefix of `len` bytes constrains
+#[inline]
+fn prefix_mask(len: usize) -> u64 {
+    if len >= 8 {
+        u64::MAX
+    } else {
+        (1u64 << (len * 8)) - 1
+    }
+}
+
+/// AVX2 masked prefix filter (4x parallel)
+#[cfg(target_arch = "x86_64")]
+#[target_feature(enable = "avx2")]
+unsafe fn find_prefix_avx2(
+    path_word: u64,
+    words: &[u64],
+    masks: &[u64],
+    verify: &dyn Fn(usize) -> bool,
+) -> Option<usize> {
+    let path_vec = _mm256_set1_epi64x(path_word as i64);
+
+    for (i, (word_chunk, mask_chunk)) in
+        words.chunks_exact(4).zip(masks.chunks_exact(4)).enumerate()
+    {
+        let word_vec = _mm256_loadu_si256(word_chunk.as_ptr() as *const __m256i);
+        let mask_vec = _mm256_loadu_si256(mask_chunk.as_ptr() as *const __m256i);
+        let cmp = _mm256_cmpeq_epi64(_mm256_and_si256(path_vec, mask_vec), word_vec);
+        let mut mask = _mm256_movemask_epi8(cmp) as u32;
+
+        // Candidates in lane order, so the first verified one is the lowest index
+        while mask != 0 {
+            let idx = i * 4 + (mask.trailing_zeros() / 8) as usize;
+            if verify(idx) {
+                return Some(idx);
+            }
+            mask &= !(0xFFu32 << ((idx % 4) * 8));
+        }
+    }
+
+    // Handle remainder with scalar search
+    let remainder_start = (words.len() / 4) * 4;
+    (remainder_start..words.len()).find(|&idx| path_word & masks[idx] == words[idx] && verify(idx))
+}
+
 /// Batch permission check using SIMD
 ///
 /// Check if any of multiple rule hashes match, returning indices of all matches.
@@ -158,6 +263,41 @@ fn test_path_prefix_match() {
         assert_eq!(path_starts_with_any("/etc/config", &prefixes), None);
     }
 
+    #[test]
+    fn test_path_prefix_short_and_long_prefixes() {
+        // Mixes prefixes shorter and longer than the 8 packed bytes
+        let prefixes = vec!["/a/", "/srv/data/", "/srv/", "/home/user/docs/", "/b/"];
+
+        assert_eq!(path_starts_with_any("/a/x", &prefixes), Some(0));
+        assert_eq!(path_starts_with_any("/srv/data/f", &prefixes), Some(1));
+        assert_eq!(path_starts_with_any("/srv/other", &prefixes), Some(2));
+        assert_eq!(
+            path_starts_with_any("/home/user/docs/a", &prefixes),
+            Some(3)
+        );
+        assert_eq!(path_starts_with_any("/home/user/doc", &prefixes), None);
+        assert_eq!(path_starts_with_any("/b/", &prefixes), Some(4));
+        assert_eq!(path_starts_with_any("/", &prefixes), None);
+    }
+
+    #[test]
+    fn test_prefix_set_matches_one_shot_search() {
+        let prefixes = vec!["/a/", "/srv/data/", "/srv/", "/home/user/docs/", "/b/"];
+        let set = PrefixSet::new(prefixes.iter().map(|p| p.to_string()).collect());
+
+        for path in [
+            "/a/x",
+            "/srv/data/f",
+            "/srv/other",
+            "/home/user/doc",
+            "/b/",
+            "/",
+        ] {
+            assert_eq!(set.find(path), path_starts_with_any(path, &prefixes));
+        }
+        assert_eq!(PrefixSet::default().find("/a/"), None);
+    }
+
     #[test]
     fn test_hash_prefix() {
         // Same prefix should hash to same value
@@ -5,7 +5,8 @@
  * # Features
  * - Thread-local buffer pooling for hot paths (50-100ns allocation overhead reduction)
  * - Zero-copy deserialization via `bytes::Bytes` integration
- * - Compile-time format versioning
+ * - Schema-versioned headers for IPC messages, with a migration hook for the
+ *   previous version
  * - Optional LZ4 compression for large payloads (>16KB)
  */
 
@@ -23,8 +24,15 @@
 const POOL_BUFFER_SIZE: usize = 8192; // 8KB default buffer
 const COMPRESSION_THRESHOLD: usize = 16384; // 16KB - compress larger payloads
 
-/// Format version for forward/backward compatibility
-const BINCODE_FORMAT_VERSION: u8 = 1;
+/// Schema version written into every bincode header
+///
+/// Bump whenever a struct sent over IPC changes shape. Bincode isn't
+/// self-describing, so a payload from another version would otherwise decode
+/// into garbage instead of failing.
+pub const BINCODE_SCHEMA_VERSION: u8 = 1;
+
+/// Header size: schema version byte + u32 payload length
+const HEADER_SIZE: usize = 5;
 
 // ============================================================================
 // Error Types
@@ -54,8 +62,8 @@ pub enum BincodeError {
     #[error("Buffer too small: expected {expected} bytes, got {actual} bytes")]
     BufferTooSmall { expected: usize, actual: usize },
 
-    #[error("Invalid format version: expected {expected}, got {actual}")]
-    InvalidVersion { expected: u8, actual: u8 },
+    #[error("Schema version mismatch: expected v{expected}, got v{actual}")]
+    VersionMismatch { expected: u8, actual: u8 },
 
     #[error("Compression error: {0}")]
     Compression(String),
@@ -163,26 +171,32 @@ pub fn serialized_size<T: Serialize>(value: &T) -> BincodeResult<u64> {
 /// - Queue message passing
 /// - Shared memory coordination
 ///
+/// The payload is prefixed with the versioned header from `to_vec_with_header()`
+/// so a peer on another schema version rejects it instead of misreading it.
 /// Returns `Bytes` for zero-copy sharing across threads/processes.
 #[inline]
 pub fn serialize_ipc_message<T: Serialize>(message: &T) -> BincodeResult<Bytes> {
-    to_bytes_pooled(message)
+    to_vec_with_header(message).map(Bytes::from)
 }
 
 /// Deserialize IPC message using bincode (zero-copy)
 ///
 /// Accepts `Bytes` for efficient zero-copy deserialization.
+/// Fails with `VersionMismatch` if the sender used another schema version.
 #[inline]
 pub fn deserialize_ipc_message<T: DeserializeOwned>(bytes: &Bytes) -> BincodeResult<T> {
-    from_bytes(bytes)
+    from_slice_with_header(bytes.as_ref())
 }
 
+/// Serialize to versioned size-prefixed format
+///
+/// Layout: `[schema version: u8][payload length: u32 LE][payload]`
 pub fn to_vec_with_header<T: Serialize>(value: &T) -> BincodeResult<Vec<u8>> {
     let data = to_vec(value)?;
     let len = data.len() as u32;
 
-    let mut result = PooledBuffer::get(5 + data.len());
-    result.push(BINCODE_FORMAT_VERSION);
+    let mut result = PooledBuffer::get(HEADER_SIZE + data.len());
+    result.push(BINCODE_SCHEMA_VERSION);
     result.extend_from_slice(&len.to_le_bytes());
     result.extend_from_slice(&data);
 
@@ -193,35 +207,60 @@ pub fn to_vec_with_header<T: Serialize>(value: &T) -> BincodeResult<Vec<u8>> {
 ///
 /// Validates version compatibility and reads exact payload size.
 pub fn from_slice_with_header<T: DeserializeOwned>(bytes: &[u8]) -> BincodeResult<T> {
-    // Validate minimum size (version + length)
-    if bytes.len() < 5 {
-        return Err(BincodeError::BufferTooSmall {
-            expected: 5,
-            actual: bytes.len(),
+    let (version, payload) = split_header(bytes)?;
+    if version != BINCODE_SCHEMA_VERSION {
+        return Err(BincodeError::VersionMismatch {
+            expected: BINCODE_SCHEMA_VERSION,
+            actual: version,
         });
     }
+    from_slice(payload)
+}
 
-    // Validate version
-    let version = bytes[0];
-    if version != BINCODE_FORMAT_VERSION {
-        return Err(BincodeError::InvalidVersion {
-            expected: BINCODE_FORMAT_VERSION,
+/// Deserialize from versioned format, upgrading older payloads
+///
+/// Payloads at the current schema version decode as usual. Older ones are
+/// handed to `migrate` along with their version, so the caller can decode
+/// the previous struct layout and convert it during a rolling upgrade.
+/// Payloads from a newer version are always rejected.
+pub fn from_slice_with_migration<T, F>(bytes: &[u8], migrate: F) -> BincodeResult<T>
+where
+    T: DeserializeOwned,
+    F: FnOnce(u8, &[u8]) -> BincodeResult<T>,
+{
+    let (version, payload) = split_header(bytes)?;
+    match version.cmp(&BINCODE_SCHEMA_VERSION) {
+        std::cmp::Ordering::Equal => from_slice(payload),
+        std::cmp::Ordering::Less => migrate(version, payload),
+        std::cmp::Ordering::Greater => Err(BincodeError::VersionMismatch {
+            expected: BINCODE_SCHEMA_VERSION,
             actual: version,
+        }),
+    }
+}
+
+/// Split a versioned buffer into its schema version and exact payload
+fn split_header(bytes: &[u8]) -> BincodeResult<(u8, &[u8])> {
+    // Validate minimum size (version + length)
+    if bytes.len() < HEADER_SIZE {
+        return Err(BincodeError::BufferTooSmall {
+            expected: HEADER_SIZE,
+            actual: bytes.len(),
         });
     }
 
-    // Extract length
+    let version = bytes[0];
     let len = u32::from_le_bytes([bytes[1], bytes[2], bytes[3], bytes[4]]) as usize;
 
     // Validate buffer size
-    if bytes.len() < 5 + len {
+    if bytes.len() < HEADER_SIZE + len {
         return Err(BincodeError::BufferTooSmall {
-            expected: 5 + len,
+            expected: HEADER_SIZE + len,
             actual: bytes.len(),
         });
     }
 
-    from_slice(&bytes[5..5 + len])
+    Ok((version, &bytes[HEADER_SIZE..HEADER_SIZE + len]))
 }
 
 // ============================================================================
@@ -368,10 +407,85 @@ fn test_ipc_message_serialization() {
         };
 
         let bytes = serialize_ipc_message(&msg).unwrap();
+        assert_eq!(bytes[0], BINCODE_SCHEMA_VERSION);
         let deserialized: TestMessage = deserialize_ipc_message(&bytes).unwrap();
         assert_eq!(msg, deserialized);
     }
 
+    #[test]
+    fn test_ipc_message_rejects_other_schema_version() {
+        let msg = TestMessage {
+            id: 100,
+            from: 5,
+            to: 10,
+            data: vec![7, 8, 9],
+            timestamp: 9876543210,
+        };
+
+        let mut bytes = serialize_ipc_message(&msg).unwrap().to_vec();
+        bytes[0] = BINCODE_SCHEMA_VERSION + 1;
+
+        let result: BincodeResult<TestMessage> = deserialize_ipc_message(&Bytes::from(bytes));
+        match result {
+            Err(BincodeError::VersionMismatch { expected, actual }) => {
+                assert_eq!(expected, BINCODE_SCHEMA_VERSION);
+                assert_eq!(actual, BINCODE_SCHEMA_VERSION + 1);
+            }
+            other => panic!("expected VersionMismatch, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_migration_hook_reads_previous_version() {
+        // What TestMessage looked like one schema version ago
+        #[derive(Serialize, Deserialize)]
+        struct TestMessageV0 {
+            id: u64,
+            data: Vec<u8>,
+        }
+
+        let old = to_vec(&TestMessageV0 {
+            id: 7,
+            data: vec![1, 2],
+        })
+        .unwrap();
+        let mut bytes = vec![BINCODE_SCHEMA_VERSION - 1];
+        bytes.extend_from_slice(&(old.len() as u32).to_le_bytes());
+        bytes.extend_from_slice(&old);
+
+        // Plain header decoding refuses it
+        let result: BincodeResult<TestMessage> = from_slice_with_header(&bytes);
+        assert!(matches!(result, Err(BincodeError::VersionMismatch { .. })));
+
+        let migrated: TestMessage = from_slice_with_migration(&bytes, |version, payload| {
+            assert_eq!(version, BINCODE_SCHEMA_VERSION - 1);
+            let v0: TestMessageV0 = from_slice(payload)?;
+            Ok(TestMessage {
+                id: v0.id,
+                from: 0,
+                to: 0,
+                data: v0.data,
+                timestamp: 0,
+            })
+        })
+        .unwrap();
+        assert_eq!(migrated.id, 7);
+        assert_eq!(migrated.data, vec![1, 2]);
+
+        // Current payloads bypass the hook
+        let msg = TestMessage {
+            id: 1,
+            from: 2,
+            to: 3,
+            data: vec![4],
+            timestamp: 5,
+        };
+        let current = to_vec_with_header(&msg).unwrap();
+        let decoded: TestMessage =
+            from_slice_with_migration(&current, |_, _| panic!("hook called")).unwrap();
+        assert_eq!(decoded, msg);
+    }
+
     #[test]
     fn test_large_binary_payload() {
         let msg = TestMessage {
@@ -404,7 +518,7 @@ fn test_versioned_header_serialization() {
         assert!(bytes.len() >= 5); // At least 5 bytes for version + length
 
         // Check version byte
-        assert_eq!(bytes[0], BINCODE_FORMAT_VERSION);
+        assert_eq!(bytes[0], BINCODE_SCHEMA_VERSION);
 
         let deserialized: TestMessage = from_slice_with_header(&bytes).unwrap();
         assert_eq!(msg, deserialized);
@@ -425,7 +539,7 @@ fn test_version_validation() {
         bytes[0] = 99;
 
         let result: BincodeResult<TestMessage> = from_slice_with_header(&bytes);
-        assert!(matches!(result, Err(BincodeError::InvalidVersion { .. })));
+        assert!(matches!(result, Err(BincodeError::VersionMismatch { .. })));
     }
 
     #[test]
@@ -495,7 +609,7 @@ fn test_header_errors() {
 
         // Size indicates more data than available
         let result: BincodeResult<TestMessage> =
-            from_slice_with_header(&[BINCODE_FORMAT_VERSION, 100, 0, 0, 0, 1, 2]);
+            from_slice_with_header(&[BINCODE_SCHEMA_VERSION, 100, 0, 0, 0, 1, 2]);
         assert!(matches!(result, Err(BincodeError::BufferTooSmall { .. })));
     }
 
@@ -26,10 +26,10 @@
 // Re-export bincode functions (zero-copy optimized)
 pub use bincode::{
     deserialize_ipc_message as deserialize_bincode_ipc, from_bytes as from_bincode_bytes,
-    from_slice as from_bincode, from_slice_with_header,
+    from_slice as from_bincode, from_slice_with_header, from_slice_with_migration,
     serialize_ipc_message as serialize_bincode_ipc, serialized_size,
     to_bytes_pooled as to_bincode_pooled, to_vec as to_bincode, to_vec_with_header, BincodeError,
-    BincodeResult,
+    BincodeResult, BINCODE_SCHEMA_VERSION,
 };
 
 // Re-export JSON functions (adaptive SIMD)
@@ -3,6 +3,8 @@
  * SIMD-accelerated search and pattern matching
  */
 
+use super::stats::{record, SimdOp, SimdPath};
+
 /// Find first occurrence of a byte in a slice
 ///
 /// Returns the index of the first occurrence, or None if not found
@@ -11,35 +13,28 @@ pub fn find_byte(haystack: &[u8], needle: u8) -> Option<usize> {
         return None;
     }
 
+    // Each SIMD variant scans its own tail, so its answer is final
     #[cfg(target_arch = "x86_64")]
     {
         if is_x86_feature_detected!("avx512f")
             && is_x86_feature_detected!("avx512bw")
             && haystack.len() >= 64
         {
-            unsafe {
-                if let Some(pos) = find_byte_avx512(haystack, needle) {
-                    return Some(pos);
-                }
-            }
+            record(SimdOp::FindByte, SimdPath::Avx512);
+            return unsafe { find_byte_avx512(haystack, needle) };
         }
         if is_x86_feature_detected!("avx2") && haystack.len() >= 32 {
-            unsafe {
-                if let Some(pos) = find_byte_avx2(haystack, needle) {
-                    return Some(pos);
-                }
-            }
+            record(SimdOp::FindByte, SimdPath::Avx2);
+            return unsafe { find_byte_avx2(haystack, needle) };
         }
         if is_x86_feature_detected!("sse2") && haystack.len() >= 16 {
-            unsafe {
-                if let Some(pos) = find_byte_sse2(haystack, needle) {
-                    return Some(pos);
-                }
-            }
+            record(SimdOp::FindByte, SimdPath::Sse2);
+            return unsafe { find_byte_sse2(haystack, needle) };
         }
     }
 
     // Fallback to standard search
+    record(SimdOp::FindByte, SimdPath::Scalar);
     haystack.iter().position(|&b| b == needle)
 }
 
@@ -56,11 +51,13 @@ pub fn count_byte(haystack: &[u8], needle: u8) -> usize {
             && is_x86_feature_detected!("avx512vpopcntdq")
             && haystack.len() >= 64
         {
+            record(SimdOp::CountByte, SimdPath::Avx512);
             unsafe {
                 return count_byte_avx512(haystack, needle);
             }
         }
         if is_x86_feature_detected!("avx2") && haystack.len() >= 32 {
+            record(SimdOp::CountByte, SimdPath::Avx2);
             unsafe {
                 return count_byte_avx2(haystack, needle);
             }
@@ -68,6 +65,7 @@ pub fn count_byte(haystack: &[u8], needle: u8) -> usize {
     }
 
     // Fallback
+    record(SimdOp::CountByte, SimdPath::Scalar);
     haystack.iter().filter(|&&b| b == needle).count()
 }
 
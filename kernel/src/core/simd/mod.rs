@@ -9,6 +9,7 @@
 mod find;
 mod operations;
 mod platform;
+mod stats;
 mod text;
 
 // Memory operations
@@ -20,6 +21,13 @@
 // Search operations
 pub use find::{contains_byte, count_byte, find_byte, rfind_byte};
 
+// Search path statistics
+pub use stats::{
+    path_stats, path_stats_enabled, reset_path_stats, set_path_stats_enabled, PathCounts, SimdPath,
+    SimdPathStats,
+};
+pub(crate) use stats::{record as record_path, SimdOp};
+
 // Math operations
 pub use calc::{avg_u64, max_u64, min_u64, sum_u32, sum_u64};
 
@@ -0,0 +1,144 @@
+/*!
+ * SIMD Path Statistics
+ * Per-implementation call counters for the search operations
+ *
+ * Runtime dispatch makes a silent fall back to scalar code look like a
+ * plain perf regression. Counting which implementation actually ran lets
+ * tests and benchmarks assert the expected path for the host CPU.
+ */
+
+use serde::{Deserialize, Serialize};
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+
+/// Search implementation selected at runtime
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SimdPath {
+    Avx512,
+    Avx2,
+    Sse2,
+    Scalar,
+}
+
+/// Operations that record path selection
+#[derive(Debug, Clone, Copy)]
+pub(crate) enum SimdOp {
+    FindByte = 0,
+    CountByte = 1,
+    PathPrefix = 2,
+}
+
+/// Calls per implementation for one operation
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PathCounts {
+    pub avx512: u64,
+    pub avx2: u64,
+    pub sse2: u64,
+    pub scalar: u64,
+}
+
+impl PathCounts {
+    /// Calls recorded for a given path
+    #[inline]
+    #[must_use]
+    pub const fn get(&self, path: SimdPath) -> u64 {
+        match path {
+            SimdPath::Avx512 => self.avx512,
+            SimdPath::Avx2 => self.avx2,
+            SimdPath::Sse2 => self.sse2,
+            SimdPath::Scalar => self.scalar,
+        }
+    }
+
+    /// Total calls across all paths
+    #[inline]
+    #[must_use]
+    pub const fn total(&self) -> u64 {
+        self.avx512 + self.avx2 + self.sse2 + self.scalar
+    }
+}
+
+/// Snapshot of path selection counters
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SimdPathStats {
+    pub find_byte: PathCounts,
+    pub count_byte: PathCounts,
+    pub path_prefix: PathCounts,
+}
+
+const PATHS: usize = 4;
+const OPS: usize = 3;
+
+static ENABLED: AtomicBool = AtomicBool::new(false);
+
+#[allow(clippy::declare_interior_mutable_const)]
+const ZERO: AtomicU64 = AtomicU64::new(0);
+#[allow(clippy::declare_interior_mutable_const)]
+const ZERO_ROW: [AtomicU64; PATHS] = [ZERO; PATHS];
+static COUNTERS: [[AtomicU64; PATHS]; OPS] = [ZERO_ROW; OPS];
+
+/// Turn path counting on or off (off by default)
+///
+/// While disabled, each search call pays a single relaxed atomic load.
+pub fn set_path_stats_enabled(enabled: bool) {
+    ENABLED.store(enabled, Ordering::Relaxed);
+}
+
+/// Whether path counting is enabled
+#[inline]
+pub fn path_stats_enabled() -> bool {
+    ENABLED.load(Ordering::Relaxed)
+}
+
+/// Snapshot the path selection counters
+pub fn path_stats() -> SimdPathStats {
+    SimdPathStats {
+        find_byte: counts(SimdOp::FindByte),
+        count_byte: counts(SimdOp::CountByte),
+        path_prefix: counts(SimdOp::PathPrefix),
+    }
+}
+
+/// Zero all path selection counters
+pub fn reset_path_stats() {
+    for counter in COUNTERS.iter().flatten() {
+        counter.store(0, Ordering::Relaxed);
+    }
+}
+
+/// Record that `op` ran on `path`
+#[inline(always)]
+pub(crate) fn record(op: SimdOp, path: SimdPath) {
+    if path_stats_enabled() {
+        COUNTERS[op as usize][path as usize].fetch_add(1, Ordering::Relaxed);
+    }
+}
+
+fn counts(op: SimdOp) -> PathCounts {
+    let row = &COUNTERS[op as usize];
+    let load = |path: SimdPath| row[path as usize].load(Ordering::Relaxed);
+    PathCounts {
+        avx512: load(SimdPath::Avx512),
+        avx2: load(SimdPath::Avx2),
+        sse2: load(SimdPath::Sse2),
+        scalar: load(SimdPath::Scalar),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_path_counts_accessors() {
+        let counts = PathCounts {
+            avx512: 1,
+            avx2: 2,
+            sse2: 3,
+            scalar: 4,
+        };
+        assert_eq!(counts.get(SimdPath::Avx2), 2);
+        assert_eq!(counts.get(SimdPath::Scalar), 4);
+        assert_eq!(counts.total(), 10);
+    }
+}
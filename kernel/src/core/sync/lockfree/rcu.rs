@@ -158,6 +158,12 @@ fn clone(&self) -> Self {
     }
 }
 
+impl<T: std::fmt::Debug> std::fmt::Debug for RcuCell<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("RcuCell").field(&*self.inner.load()).finish()
+    }
+}
+
 // Safety: ArcSwap is Sync and Send when T is
 unsafe impl<T: Send + Sync> Send for RcuCell<T> {}
 unsafe impl<T: Send + Sync> Sync for RcuCell<T> {}
@@ -196,6 +196,13 @@ fn atomic_compare_exchange(
 /// - **Atomic path**: 10x faster than mutex for all atomic types
 /// - **Zero overhead**: Compile-time selection via monomorphization
 /// - **Type-safe**: Impossible to accidentally use wrong path
+///
+/// # Priority Inversion
+///
+/// Every operation is a single atomic instruction: there is no holder and
+/// no waiter ever blocks, so priority inheritance has nothing to act on.
+/// Code that needs a blocking critical section across processes should use
+/// a lock that tracks its owner's pid instead.
 pub struct AdaptiveLock<T: AtomicCompatible> {
     inner: T::Atomic,
 }
@@ -87,6 +87,24 @@ pub fn shards(profile: WorkloadProfile) -> usize {
         calculated.clamp(8, 512)
     }
 
+    /// Normalize a caller-supplied shard count override
+    ///
+    /// DashMap requires a power of two greater than one, so other values are
+    /// rounded up. Unlike `shards`, no upper bound is applied: overrides exist
+    /// to cap counts on large machines, not to raise them.
+    #[inline]
+    pub fn normalize(requested: usize) -> usize {
+        let shards = requested.max(2).next_power_of_two();
+        if shards != requested {
+            log::warn!(
+                "Shard count {} is not a power of two above 1, using {}",
+                requested,
+                shards
+            );
+        }
+        shards
+    }
+
     /// Calculate shards with custom multiplier (advanced use)
     #[inline]
     pub fn shards_with_multiplier(multiplier: usize) -> usize {
@@ -130,6 +148,14 @@ fn test_shard_calculation() {
         }
     }
 
+    #[test]
+    fn test_normalize_override() {
+        assert_eq!(ShardManager::normalize(16), 16);
+        assert_eq!(ShardManager::normalize(12), 16);
+        assert_eq!(ShardManager::normalize(0), 2);
+        assert_eq!(ShardManager::normalize(1), 2);
+    }
+
     #[test]
     fn test_contention_ordering() {
         // Verify high > medium > low
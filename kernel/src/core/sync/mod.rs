@@ -15,6 +15,7 @@
  * - `lockfree/`: Lock-free data structures for read-heavy workloads
  * - `locks/`: Advanced lock-based primitives with contention reduction
  * - `management/`: Configuration and management utilities
+ * - `readiness`: Wakeups for pollers blocked on in-kernel descriptors
  *
  * # Performance
  *
@@ -36,6 +37,7 @@
 pub mod lockfree;
 pub mod locks;
 pub mod management;
+pub mod readiness;
 pub mod wait;
 
 // Re-export commonly used items at top level for convenience
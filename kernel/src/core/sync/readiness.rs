@@ -0,0 +1,231 @@
+/*!
+ * Readiness Wakeups
+ * Wakes blocked pollers when in-kernel descriptors change readiness
+ *
+ * Pipes, signalfds and completion fds live entirely in the kernel, so
+ * poll(2) has nothing to watch for them. Each blocked poller registers a
+ * nonblocking self-pipe here under the keys of the sources it watches, and
+ * a readiness change writes a byte only to the pipes registered under that
+ * source's key. A single poll(2) over the self-pipe and the caller's OS
+ * sockets then waits on both kinds of source at once.
+ *
+ * Pipes use the same ids as their WaitQueue keys. Other sources are keyed
+ * by the address of their shared state, which a freed and reallocated
+ * source may reuse; that only costs a spurious wakeup, since a woken poller
+ * re-checks its descriptors and goes back to sleep if none became ready.
+ */
+
+use ahash::RandomState;
+use nix::libc;
+use parking_lot::Mutex;
+use std::collections::HashMap;
+use std::io;
+use std::os::fd::{AsRawFd, FromRawFd, OwnedFd, RawFd};
+use std::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
+use std::sync::LazyLock;
+use std::time::Duration;
+
+/// In-kernel source whose readiness a poller can wait on
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ReadinessKey {
+    /// Pipe, keyed like the pipe manager's WaitQueue
+    Pipe(u32),
+    /// Event fd (signalfd, timerfd, completion fd), keyed by its shared state
+    Event(usize),
+}
+
+impl ReadinessKey {
+    /// Key for an event source, from the state its fds share
+    #[inline]
+    pub fn of<T>(state: &T) -> Self {
+        Self::Event(state as *const T as usize)
+    }
+}
+
+/// Registered waiters' ids and self-pipe write ends, by watched source
+static WAITERS: LazyLock<Mutex<HashMap<ReadinessKey, Vec<(u64, RawFd)>, RandomState>>> =
+    LazyLock::new(|| Mutex::new(HashMap::with_hasher(RandomState::new())));
+
+/// Registered waiter count, so notifying with no pollers skips the lock
+static ACTIVE: AtomicUsize = AtomicUsize::new(0);
+
+static NEXT_ID: AtomicU64 = AtomicU64::new(1);
+
+/// Wake the waiters watching `key`
+///
+/// Call after any change that can make the source readable, writable or
+/// hung up.
+#[inline]
+pub fn notify_readiness(key: ReadinessKey) {
+    if ACTIVE.load(Ordering::SeqCst) == 0 {
+        return;
+    }
+    let waiters = WAITERS.lock();
+    for &(_, fd) in waiters.get(&key).into_iter().flatten() {
+        // A full pipe already holds a pending wakeup
+        // SAFETY: the fd stays open while registered, and the lock is held
+        let _ = unsafe { libc::write(fd, [1u8].as_ptr().cast(), 1) };
+    }
+}
+
+/// A poller's registration for readiness wakeups
+///
+/// Register before checking readiness: a change after registration leaves a
+/// byte in the self-pipe, so the following wait returns at once instead of
+/// missing it.
+pub struct ReadinessWaiter {
+    id: u64,
+    /// Sources this waiter is registered under
+    keys: Vec<ReadinessKey>,
+    read: OwnedFd,
+    /// Held so the registered write end stays open
+    _write: OwnedFd,
+}
+
+impl ReadinessWaiter {
+    /// Register for wakeups from the sources in `keys`
+    pub fn register(keys: &[ReadinessKey]) -> io::Result<Self> {
+        let mut fds = [0 as RawFd; 2];
+        // SAFETY: fds has room for both ends
+        if unsafe { libc::pipe(fds.as_mut_ptr()) } < 0 {
+            return Err(io::Error::last_os_error());
+        }
+        // SAFETY: pipe succeeded, so both fds are open and owned by us
+        let (read, write) = unsafe { (OwnedFd::from_raw_fd(fds[0]), OwnedFd::from_raw_fd(fds[1])) };
+        // pipe2 isn't portable, so set the flags separately
+        for fd in [&read, &write] {
+            // SAFETY: fd is open for the duration of both calls
+            let failed = unsafe {
+                libc::fcntl(fd.as_raw_fd(), libc::F_SETFL, libc::O_NONBLOCK) < 0
+                    || libc::fcntl(fd.as_raw_fd(), libc::F_SETFD, libc::FD_CLOEXEC) < 0
+            };
+            if failed {
+                return Err(io::Error::last_os_error());
+            }
+        }
+
+        let id = NEXT_ID.fetch_add(1, Ordering::Relaxed);
+        let mut keys = keys.to_vec();
+        keys.sort_unstable();
+        keys.dedup();
+        {
+            let mut waiters = WAITERS.lock();
+            for key in &keys {
+                waiters
+                    .entry(*key)
+                    .or_default()
+                    .push((id, write.as_raw_fd()));
+            }
+        }
+        ACTIVE.fetch_add(1, Ordering::SeqCst);
+        Ok(Self {
+            id,
+            keys,
+            read,
+            _write: write,
+        })
+    }
+
+    /// Sleep until notified, an OS descriptor in `fds` has one of its
+    /// requested poll(2) events, or `timeout` elapses
+    ///
+    /// Pending wakeups are consumed, so the next wait blocks again.
+    pub fn wait(&self, fds: &[(RawFd, i16)], timeout: Duration) {
+        let mut pollfds: Vec<libc::pollfd> = fds
+            .iter()
+            .map(|&(fd, events)| libc::pollfd {
+                fd,
+                events,
+                revents: 0,
+            })
+            .collect();
+        pollfds.push(libc::pollfd {
+            fd: self.read.as_raw_fd(),
+            events: libc::POLLIN,
+            revents: 0,
+        });
+
+        // Round up so a sub-millisecond remainder still sleeps
+        let timeout_ms = timeout
+            .as_micros()
+            .div_ceil(1000)
+            .min(libc::c_int::MAX as u128) as libc::c_int;
+        // SAFETY: pollfds is a valid array, and the caller keeps its fds open
+        unsafe {
+            libc::poll(
+                pollfds.as_mut_ptr(),
+                pollfds.len() as libc::nfds_t,
+                timeout_ms,
+            )
+        };
+
+        let mut drain = [0u8; 64];
+        // SAFETY: the read end is nonblocking, so this stops once it is empty
+        while unsafe {
+            libc::read(
+                self.read.as_raw_fd(),
+                drain.as_mut_ptr().cast(),
+                drain.len(),
+            )
+        } > 0
+        {}
+    }
+}
+
+impl Drop for ReadinessWaiter {
+    fn drop(&mut self) {
+        // Fields drop after this, so the write end is unregistered before it
+        // closes and notify never writes to a reused fd
+        let mut waiters = WAITERS.lock();
+        for key in &self.keys {
+            if let Some(registered) = waiters.get_mut(key) {
+                registered.retain(|&(id, _)| id != self.id);
+                if registered.is_empty() {
+                    waiters.remove(key);
+                }
+            }
+        }
+        drop(waiters);
+        ACTIVE.fetch_sub(1, Ordering::SeqCst);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Instant;
+
+    #[test]
+    fn test_notify_wakes_registered_waiter() {
+        let key = ReadinessKey::Pipe(u32::MAX);
+        let waiter = ReadinessWaiter::register(&[key]).unwrap();
+
+        // A wakeup sent before waiting is not lost
+        notify_readiness(key);
+        let started = Instant::now();
+        waiter.wait(&[], Duration::from_secs(5));
+        assert!(started.elapsed() < Duration::from_secs(1));
+
+        // ...and is consumed, so the next wait sleeps for its timeout
+        let started = Instant::now();
+        waiter.wait(&[], Duration::from_millis(30));
+        assert!(started.elapsed() >= Duration::from_millis(30));
+    }
+
+    #[test]
+    fn test_notify_skips_waiters_on_other_keys() {
+        let watched = 0u8;
+        let other = 0u8;
+        let waiter = ReadinessWaiter::register(&[ReadinessKey::of(&watched)]).unwrap();
+
+        notify_readiness(ReadinessKey::of(&other));
+        let started = Instant::now();
+        waiter.wait(&[], Duration::from_millis(30));
+        assert!(started.elapsed() >= Duration::from_millis(30));
+
+        notify_readiness(ReadinessKey::of(&watched));
+        let started = Instant::now();
+        waiter.wait(&[], Duration::from_secs(5));
+        assert!(started.elapsed() < Duration::from_secs(1));
+    }
+}
@@ -10,6 +10,7 @@
 use crate::core::clipboard::ClipboardManager;
 use crate::core::limits::{IPC_MANAGER_QUEUE_SIZE, MAX_MESSAGE_SIZE};
 use crate::core::types::{Pid, Size};
+use crate::core::ShardManager;
 use crate::ipc::pipe::PipeManager;
 use crate::ipc::queue::QueueManager;
 use crate::ipc::shm::ShmManager;
@@ -71,6 +72,22 @@ pub fn with_zerocopy(memory_manager: MemoryManager) -> Self {
         }
     }
 
+    /// Cap the shard count of this manager and its pipe, shm and queue managers
+    ///
+    /// `count` is rounded up to a power of two. Call before use.
+    pub fn with_shards(mut self, count: usize) -> Self {
+        let shards = ShardManager::normalize(count);
+        self.message_queues = Arc::new(DashMap::with_capacity_and_hasher_and_shard_amount(
+            0,
+            RandomState::new(),
+            shards,
+        ));
+        self.pipe_manager = self.pipe_manager.with_shards(shards);
+        self.shm_manager = self.shm_manager.with_shards(shards);
+        self.queue_manager = self.queue_manager.with_shards(shards);
+        self
+    }
+
     /// Get reference to pipe manager
     pub fn pipes(&self) -> &PipeManager {
         &self.pipe_manager
@@ -4,6 +4,7 @@
  */
 
 use crate::core::data_structures::InlineString;
+use crate::core::errors::{ErrorCategory, ErrorCode};
 use crate::core::serialization::serde::{is_zero_u64, is_zero_usize};
 use crate::core::types::{Pid, Timestamp};
 use miette::Diagnostic;
@@ -80,6 +81,43 @@ pub enum IpcError {
     },
 }
 
+impl IpcError {
+    pub(crate) const NOT_FOUND: ErrorCode = ErrorCode::new(4001, ErrorCategory::NotFound);
+    pub(crate) const PERMISSION_DENIED: ErrorCode =
+        ErrorCode::new(4002, ErrorCategory::PermissionDenied);
+    pub(crate) const LIMIT_EXCEEDED: ErrorCode = ErrorCode::new(4003, ErrorCategory::QuotaExceeded);
+    pub(crate) const WOULD_BLOCK: ErrorCode = ErrorCode::new(4004, ErrorCategory::WouldBlock);
+    pub(crate) const INVALID_OPERATION: ErrorCode =
+        ErrorCode::new(4005, ErrorCategory::InvalidArgument);
+    pub(crate) const CLOSED: ErrorCode = ErrorCode::new(4006, ErrorCategory::Unavailable);
+    pub(crate) const TIMEOUT: ErrorCode = ErrorCode::new(4007, ErrorCategory::Unavailable);
+
+    /// Stable numeric code for this variant
+    #[inline]
+    pub const fn error_code(&self) -> u32 {
+        self.typed_code().code
+    }
+
+    /// Error class for this variant
+    #[inline]
+    pub const fn category(&self) -> ErrorCategory {
+        self.typed_code().category
+    }
+
+    /// Code and category together
+    pub const fn typed_code(&self) -> ErrorCode {
+        match self {
+            Self::NotFound(_) => Self::NOT_FOUND,
+            Self::PermissionDenied(_) => Self::PERMISSION_DENIED,
+            Self::LimitExceeded(_) => Self::LIMIT_EXCEEDED,
+            Self::WouldBlock(_) => Self::WOULD_BLOCK,
+            Self::InvalidOperation(_) => Self::INVALID_OPERATION,
+            Self::Closed(_) => Self::CLOSED,
+            Self::Timeout { .. } => Self::TIMEOUT,
+        }
+    }
+}
+
 /// IPC channel identifier
 pub type ChannelId = u32;
 
@@ -105,6 +143,8 @@ pub enum QueueType {
     Priority,
     /// Publish-subscribe (broadcast to all subscribers)
     PubSub,
+    /// FIFO whose messages are redelivered until acknowledged
+    AtLeastOnce,
 }
 
 // Implement BincodeSerializable for QueueType
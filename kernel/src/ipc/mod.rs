@@ -12,11 +12,11 @@
 
 // Re-export for convenience
 pub use core::*;
-pub use pipe::{PipeError, PipeManager, PipeStats};
-pub use queue::{QueueManager, QueueMessage, QueueStats};
+pub use pipe::{PipeError, PipeManager, PipeStats, PipeWriteMode};
+pub use queue::{QueueDepth, QueueManager, QueueMessage, QueueStats};
 pub use shm::{ShmError, ShmManager, ShmPermission, ShmStats};
 pub use utils::{
-    LockFreeByteRing, LockFreeRing, MapFlags, MmapEntry, MmapId, MmapManager, ProtFlags,
+    LockFreeByteRing, LockFreeRing, MapFlags, MmapEntry, MmapError, MmapId, MmapManager, ProtFlags,
     TimeoutPipeOps, TimeoutQueueOps,
 };
 pub use zerocopy::{ZeroCopyIpc, ZeroCopyRing, ZeroCopyStats};
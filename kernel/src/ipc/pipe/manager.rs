@@ -7,8 +7,10 @@
 use super::super::types::{IpcResult, PipeId};
 use super::pipe::Pipe;
 use super::types::{
-    PipeError, PipeStats, DEFAULT_PIPE_CAPACITY, MAX_PIPES_PER_PROCESS, MAX_PIPE_CAPACITY,
+    PipeError, PipeStats, PipeWriteMode, DEFAULT_PIPE_CAPACITY, MAX_PIPES_PER_PROCESS,
+    MAX_PIPE_CAPACITY,
 };
+use crate::core::sync::readiness::{notify_readiness, ReadinessKey};
 use crate::core::sync::WaitQueue;
 use crate::core::types::{Pid, Size};
 use crate::core::{ShardManager, WorkloadProfile};
@@ -76,6 +78,25 @@ pub fn new(memory_manager: MemoryManager) -> Self {
         }
     }
 
+    /// Override the default shard count of the internal maps
+    ///
+    /// `count` is rounded up to a power of two. Call before use: the maps
+    /// are replaced.
+    pub fn with_shards(mut self, count: usize) -> Self {
+        let shards = ShardManager::normalize(count);
+        self.pipes = Arc::new(DashMap::with_capacity_and_hasher_and_shard_amount(
+            0,
+            RandomState::new(),
+            shards,
+        ));
+        self.process_pipes = Arc::new(DashMap::with_capacity_and_hasher_and_shard_amount(
+            0,
+            RandomState::new(),
+            shards,
+        ));
+        self
+    }
+
     /// Add observability collector
     pub fn with_collector(mut self, collector: Arc<Collector>) -> Self {
         self.collector = Some(collector);
@@ -184,7 +205,22 @@ pub fn create(
         Ok(pipe_id)
     }
 
+    /// Write all of `data` or none of it
+    ///
+    /// Fails with `WouldBlock` until there is room for the whole write, and
+    /// with `CapacityExceeded` if the pipe could never hold it.
     pub fn write(&self, pipe_id: PipeId, pid: Pid, data: &[u8]) -> Result<Size, PipeError> {
+        self.write_with_mode(pipe_id, pid, data, PipeWriteMode::Atomic)
+    }
+
+    /// Write `data` using `mode`, returning the number of bytes accepted
+    pub fn write_with_mode(
+        &self,
+        pipe_id: PipeId,
+        pid: Pid,
+        data: &[u8],
+        mode: PipeWriteMode,
+    ) -> Result<Size, PipeError> {
         let mut pipe = self
             .pipes
             .get_mut(&pipe_id)
@@ -196,7 +232,7 @@ pub fn write(&self, pipe_id: PipeId, pid: Pid, data: &[u8]) -> Result<Size, Pipe
             ));
         }
 
-        let written = pipe.write(data)?;
+        let written = pipe.write(data, mode)?;
         let buffered = pipe.buffered();
 
         info!(
@@ -224,6 +260,7 @@ pub fn write(&self, pipe_id: PipeId, pid: Pid, data: &[u8]) -> Result<Size, Pipe
         // Uses futex on Linux (zero CPU spinning), condvar elsewhere
         drop(pipe); // Release lock before wake to reduce contention
         self.wait_queue.wake_one(pipe_id);
+        notify_readiness(ReadinessKey::Pipe(pipe_id));
 
         Ok(written)
     }
@@ -271,6 +308,7 @@ pub fn read(&self, pipe_id: PipeId, pid: Pid, size: Size) -> Result<Vec<u8>, Pip
         // Uses futex on Linux (zero CPU spinning), condvar elsewhere
         drop(pipe); // Release lock before wake to reduce contention
         self.wait_queue.wake_one(pipe_id);
+        notify_readiness(ReadinessKey::Pipe(pipe_id));
         Ok(data)
     }
 
@@ -293,6 +331,7 @@ pub fn close(&self, pipe_id: PipeId, pid: Pid) -> Result<(), PipeError> {
         // Wake all waiters on close (they should check closed flag and return EOF/error)
         drop(pipe); // Release lock before wake
         self.wait_queue.wake_all(pipe_id);
+        notify_readiness(ReadinessKey::Pipe(pipe_id));
 
         Ok(())
     }
@@ -308,6 +347,9 @@ pub fn destroy(&self, pipe_id: PipeId) -> Result<(), PipeError> {
         let reader_pid = pipe.reader_pid;
         let writer_pid = pipe.writer_pid;
 
+        // Pipe fds left open now read as hung up
+        notify_readiness(ReadinessKey::Pipe(pipe_id));
+
         // Deallocate memory through MemoryManager (unified memory accounting)
         if let Err(e) = self.memory_manager.deallocate(address) {
             warn!(
@@ -9,4 +9,4 @@
 
 // Re-export public API
 pub use manager::PipeManager;
-pub use types::{PipeError, PipeStats};
+pub use types::{PipeError, PipeStats, PipeWriteMode};
@@ -5,7 +5,7 @@
 
 use super::super::core::types::PipeId;
 use super::super::utils::lockfree_ring::LockFreeByteRing;
-use super::types::PipeError;
+use super::types::{PipeError, PipeWriteMode, PIPE_BUF};
 use crate::core::types::{Address, Pid, Size};
 use crate::memory::MemoryManager;
 
@@ -70,16 +70,32 @@ pub fn buffered(&self) -> Size {
         self.buffer.buffered()
     }
 
-    pub fn write(&mut self, data: &[u8]) -> Result<Size, PipeError> {
+    pub fn write(&mut self, data: &[u8], mode: PipeWriteMode) -> Result<Size, PipeError> {
         if self.closed {
             return Err(PipeError::Closed);
         }
 
+        // The PIPE_BUF guarantee can only hold for writes the pipe can ever fit
+        let atomic = mode == PipeWriteMode::Atomic || data.len() <= PIPE_BUF.min(self.capacity);
+        if atomic && data.len() > self.capacity {
+            return Err(PipeError::CapacityExceeded {
+                requested: data.len(),
+                capacity: self.capacity,
+            });
+        }
+
         let available = self.buffer.available_space();
 
         if available == 0 {
             return Err(PipeError::WouldBlock("Pipe buffer full".to_string().into()));
         }
+        if atomic && data.len() > available {
+            return Err(PipeError::WouldBlock(format!(
+                "{} bytes free, write needs {}",
+                available,
+                data.len()
+            )));
+        }
 
         // Lock-free write - zero contention in SPSC pattern
         let written = self.buffer.write(data);
@@ -4,6 +4,7 @@
  */
 
 use super::super::types::{IpcError, PipeId};
+use crate::core::errors::ErrorCode;
 use crate::core::limits;
 use crate::core::serialization::serde::{is_false, is_zero_usize};
 use crate::core::types::{Pid, Size};
@@ -13,8 +14,23 @@
 // Pipe limits - centralized in core::limits
 pub use limits::{
     DEFAULT_PIPE_CAPACITY, GLOBAL_PIPE_MEMORY_LIMIT, MAX_PIPES_PER_PROCESS, MAX_PIPE_CAPACITY,
+    PIPE_BUF,
 };
 
+/// How a pipe write treats data that does not fit in the free space
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PipeWriteMode {
+    /// Write all of the data or none of it
+    #[default]
+    Atomic,
+    /// Write as much as fits and return the count
+    ///
+    /// Writes of up to `PIPE_BUF` bytes are still all-or-nothing, so small
+    /// messages from concurrent writers never interleave.
+    Partial,
+}
+
 /// Pipe error types
 #[derive(Debug, Error)]
 pub enum PipeError {
@@ -52,6 +68,23 @@ pub enum PipeError {
     InvalidOperation(String),
 }
 
+impl PipeError {
+    /// Code of the IpcError this converts to
+    pub const fn typed_code(&self) -> ErrorCode {
+        match self {
+            Self::NotFound(_) => IpcError::NOT_FOUND,
+            Self::PermissionDenied(_) => IpcError::PERMISSION_DENIED,
+            Self::Closed => IpcError::CLOSED,
+            Self::WouldBlock(_) => IpcError::WOULD_BLOCK,
+            Self::CapacityExceeded { .. }
+            | Self::ProcessLimitExceeded(..)
+            | Self::GlobalMemoryExceeded(..) => IpcError::LIMIT_EXCEEDED,
+            Self::AllocationFailed(_) | Self::InvalidOperation(_) => IpcError::INVALID_OPERATION,
+            Self::Timeout { .. } => IpcError::TIMEOUT,
+        }
+    }
+}
+
 // Convert PipeError to IpcError
 impl From<PipeError> for IpcError {
     fn from(err: PipeError) -> Self {
@@ -0,0 +1,287 @@
+/*!
+ * At-Least-Once Queue
+ * FIFO queue whose messages stay queued until the consumer acknowledges them
+ *
+ * Receiving leases a message for the visibility timeout instead of removing
+ * it. An acknowledged message is gone for good; one whose lease runs out goes
+ * back to the front of the queue for redelivery. After `max_attempts`
+ * deliveries without an ack the message moves to the dead-letter list, where
+ * the owner can inspect it, so a poison message can't loop forever.
+ */
+
+use super::super::types::{IpcError, IpcResult, QueueId};
+use super::types::{QueueMessage, QUEUE_MAX_DELIVERY_ATTEMPTS, QUEUE_VISIBILITY_TIMEOUT};
+use crate::core::sync::WaitQueue;
+use crate::core::types::Pid;
+use std::collections::{HashMap, VecDeque};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+/// Message waiting for (re)delivery
+struct Pending {
+    message: QueueMessage,
+    /// Deliveries so far
+    attempts: u32,
+}
+
+/// Message delivered but not yet acknowledged
+struct Lease {
+    pending: Pending,
+    holder: Pid,
+    expires_at: Instant,
+}
+
+/// At-least-once queue implementation
+///
+/// # Performance
+///
+/// Uses centralized WaitQueue (futex on Linux) for optimal blocking operations
+pub(super) struct AckQueue {
+    pub id: QueueId,
+    pub owner: Pid,
+    pub capacity: usize,
+    pub wait_queue: Arc<WaitQueue<QueueId>>,
+    pub closed: bool,
+    pub max_wait_us: u64,
+    /// How long a delivered message stays invisible waiting for its ack
+    pub visibility_timeout: Duration,
+    /// Deliveries before an unacknowledged message is dead-lettered
+    pub max_attempts: u32,
+    /// Total deliveries, including redeliveries
+    pub delivery_attempts: u64,
+    /// Deliveries of a message that was delivered before
+    pub redelivered: u64,
+    ready: VecDeque<Pending>,
+    leased: HashMap<u64, Lease>,
+    dead_letters: VecDeque<QueueMessage>,
+}
+
+impl AckQueue {
+    pub fn new(id: QueueId, owner: Pid, capacity: usize) -> Self {
+        use super::types::MAX_QUEUE_CAPACITY;
+        Self {
+            id,
+            owner,
+            capacity: capacity.min(MAX_QUEUE_CAPACITY),
+            // Use long_wait config for IPC operations (futex on Linux, zero CPU spinning)
+            wait_queue: Arc::new(WaitQueue::long_wait()),
+            closed: false,
+            max_wait_us: 0,
+            visibility_timeout: QUEUE_VISIBILITY_TIMEOUT,
+            max_attempts: QUEUE_MAX_DELIVERY_ATTEMPTS,
+            delivery_attempts: 0,
+            redelivered: 0,
+            ready: VecDeque::new(),
+            leased: HashMap::new(),
+            dead_letters: VecDeque::new(),
+        }
+    }
+
+    pub fn push(&mut self, message: QueueMessage) -> IpcResult<()> {
+        if self.closed {
+            return Err(IpcError::Closed("Queue closed".into()));
+        }
+
+        // Leased and dead-lettered messages keep their slot until removed
+        let used = self.len() + self.leased.len() + self.dead_letters.len();
+        if used >= self.capacity {
+            return Err(IpcError::LimitExceeded(
+                format!("Queue full: {}/{}", used, self.capacity).into(),
+            ));
+        }
+
+        self.ready.push_back(Pending {
+            message,
+            attempts: 0,
+        });
+        self.wait_queue.wake_one(self.id);
+        Ok(())
+    }
+
+    /// Lease the next message to `holder` until the visibility timeout
+    pub fn lease(&mut self, holder: Pid, now: Instant) -> Option<QueueMessage> {
+        self.expire_leases(now);
+
+        let mut pending = self.ready.pop_front()?;
+        if pending.attempts == 0 {
+            self.max_wait_us = self.max_wait_us.max(pending.message.wait_time_us());
+        } else {
+            self.redelivered += 1;
+        }
+        pending.attempts += 1;
+        self.delivery_attempts += 1;
+
+        let mut message = pending.message.clone();
+        message.leased = true;
+        self.leased.insert(
+            message.id,
+            Lease {
+                pending,
+                holder,
+                expires_at: now + self.visibility_timeout,
+            },
+        );
+        Some(message)
+    }
+
+    /// Acknowledge a leased message, removing it from the queue
+    ///
+    /// Only the current lease holder may ack: once a lease expires the
+    /// message may already be redelivered to someone else.
+    pub fn ack(&mut self, msg_id: u64, holder: Pid, now: Instant) -> IpcResult<QueueMessage> {
+        self.expire_leases(now);
+
+        match self.leased.get(&msg_id) {
+            Some(lease) if lease.holder == holder => {}
+            Some(_) => {
+                return Err(IpcError::PermissionDenied(
+                    format!("Message {} is leased by another process", msg_id).into(),
+                ))
+            }
+            None => {
+                return Err(IpcError::NotFound(
+                    format!("Message {} is not leased (lease expired?)", msg_id).into(),
+                ))
+            }
+        }
+        let lease = self.leased.remove(&msg_id).expect("lease checked above");
+        Ok(lease.pending.message)
+    }
+
+    /// Return expired leases to the queue, dead-lettering exhausted ones
+    ///
+    /// Expired messages go back to the front in id order, ahead of messages
+    /// never delivered.
+    pub fn expire_leases(&mut self, now: Instant) {
+        let mut expired: Vec<u64> = self
+            .leased
+            .iter()
+            .filter(|(_, lease)| lease.expires_at <= now)
+            .map(|(id, _)| *id)
+            .collect();
+        if expired.is_empty() {
+            return;
+        }
+        expired.sort_unstable();
+
+        let mut redeliver = false;
+        for id in expired.into_iter().rev() {
+            let Some(lease) = self.leased.remove(&id) else {
+                continue;
+            };
+            if lease.pending.attempts >= self.max_attempts {
+                self.dead_letters.push_back(lease.pending.message);
+            } else {
+                self.ready.push_front(lease.pending);
+                redeliver = true;
+            }
+        }
+        if redeliver {
+            self.wait_queue.wake_all(self.id);
+        }
+    }
+
+    /// Remove and return every dead-lettered message
+    pub fn take_dead_letters(&mut self) -> Vec<QueueMessage> {
+        self.dead_letters.drain(..).collect()
+    }
+
+    /// Remove any message, queued, leased or dead-lettered (for teardown)
+    pub fn take_any(&mut self) -> Option<QueueMessage> {
+        if let Some(pending) = self.ready.pop_front() {
+            return Some(pending.message);
+        }
+        if let Some(id) = self.leased.keys().next().copied() {
+            return self.leased.remove(&id).map(|lease| lease.pending.message);
+        }
+        self.dead_letters.pop_front()
+    }
+
+    /// Messages waiting for delivery
+    pub fn len(&self) -> usize {
+        self.ready.len()
+    }
+
+    /// Messages delivered but not yet acknowledged
+    pub fn in_flight(&self) -> usize {
+        self.leased.len()
+    }
+
+    pub fn dead_lettered(&self) -> usize {
+        self.dead_letters.len()
+    }
+
+    pub fn close(&mut self) {
+        self.closed = true;
+        // Wake all waiters on close
+        self.wait_queue.wake_all(self.id);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn queue() -> AckQueue {
+        let mut queue = AckQueue::new(1, 1, 10);
+        queue.visibility_timeout = Duration::from_secs(10);
+        queue.max_attempts = 2;
+        queue
+    }
+
+    fn message(id: u64) -> QueueMessage {
+        QueueMessage::new(id, 1, 0, 0, 0)
+    }
+
+    #[test]
+    fn test_ack_removes_message() {
+        let mut queue = queue();
+        let now = Instant::now();
+        queue.push(message(1)).unwrap();
+
+        let leased = queue.lease(2, now).unwrap();
+        assert_eq!((queue.len(), queue.in_flight()), (0, 1));
+        assert!(matches!(
+            queue.ack(leased.id, 3, now),
+            Err(IpcError::PermissionDenied(_))
+        ));
+        assert_eq!(queue.ack(leased.id, 2, now).unwrap().id, 1);
+        assert!(queue.ack(leased.id, 2, now).is_err());
+
+        // Long after the timeout there is nothing to redeliver
+        assert!(queue.lease(2, now + Duration::from_secs(60)).is_none());
+    }
+
+    #[test]
+    fn test_expired_lease_redelivers_in_order() {
+        let mut queue = queue();
+        let now = Instant::now();
+        for id in 1..=3 {
+            queue.push(message(id)).unwrap();
+        }
+        assert_eq!(queue.lease(2, now).unwrap().id, 1);
+        assert_eq!(queue.lease(2, now).unwrap().id, 2);
+
+        let later = now + Duration::from_secs(10);
+        assert_eq!(queue.lease(3, later).unwrap().id, 1);
+        assert_eq!(queue.lease(3, later).unwrap().id, 2);
+        assert_eq!(queue.lease(3, later).unwrap().id, 3);
+        assert_eq!(queue.redelivered, 2);
+        assert_eq!(queue.delivery_attempts, 5);
+
+        // The first consumer's lease is gone
+        assert!(queue.ack(1, 2, later).is_err());
+        assert!(queue.ack(1, 3, later).is_ok());
+    }
+
+    #[test]
+    fn test_capacity_counts_leased_messages() {
+        let mut queue = AckQueue::new(1, 1, 1);
+        queue.push(message(1)).unwrap();
+        queue.lease(2, Instant::now()).unwrap();
+        assert!(matches!(
+            queue.push(message(2)),
+            Err(IpcError::LimitExceeded(_))
+        ));
+    }
+}
@@ -0,0 +1,223 @@
+/*!
+ * Queue Backpressure
+ * Depth reporting and load shedding for producers outpacing consumers
+ */
+
+use super::super::types::{IpcError, IpcResult, QueueId};
+use super::manager::{Queue, QueueManager};
+use super::types::{shed_threshold, QueueDepth};
+use crate::core::types::{Pid, Priority};
+use log::{debug, warn};
+use std::sync::atomic::Ordering;
+
+impl QueueManager {
+    /// Current backlog and capacity of a queue
+    pub fn depth(&self, queue_id: QueueId) -> IpcResult<QueueDepth> {
+        let queue = self
+            .queues
+            .get(&queue_id)
+            .ok_or_else(|| IpcError::NotFound(format!("Queue {} not found", queue_id).into()))?;
+
+        Ok(queue_depth(queue.value()))
+    }
+
+    /// Combined backlog and capacity of all queues
+    pub fn total_depth(&self) -> QueueDepth {
+        self.queues
+            .iter()
+            .map(|entry| queue_depth(entry.value()))
+            .fold(QueueDepth::default(), |total, depth| QueueDepth {
+                depth: total.depth + depth.depth,
+                capacity: total.capacity + depth.capacity,
+            })
+    }
+
+    /// Whether a queue opted into load shedding
+    pub fn sheds_load(&self, queue_id: QueueId) -> bool {
+        self.queues
+            .get(&queue_id)
+            .is_some_and(|queue| queue.sheds_load())
+    }
+
+    /// Messages dropped by `try_send_or_shed` since creation
+    pub fn messages_shed(&self) -> u64 {
+        self.shed_count.load(Ordering::Relaxed)
+    }
+
+    /// Send without ever failing on a full queue
+    ///
+    /// Once a queue is near capacity the lowest-priority queued message is
+    /// dropped to make room. FIFO and PubSub queues deliver in arrival order,
+    /// so for them that is the oldest message (per lagging subscriber for
+    /// PubSub). Returns whether a message was shed.
+    pub fn try_send_or_shed(
+        &self,
+        queue_id: QueueId,
+        from_pid: Pid,
+        data: Vec<u8>,
+        priority: Option<Priority>,
+    ) -> IpcResult<bool> {
+        Self::validate_message_size(data.len())?;
+
+        let data_address = self.allocate_message_memory(from_pid, &data)?;
+        let message = self.create_queue_message(from_pid, data_address, data.len(), priority);
+
+        let Some(mut queue) = self.queues.get_mut(&queue_id) else {
+            self.deallocate_message_memory(&message);
+            return Err(IpcError::NotFound(
+                format!("Queue {} not found", queue_id).into(),
+            ));
+        };
+
+        // Queued messages to free, plus PubSub copies dropped (their data is
+        // shared between subscribers, so it isn't freed here)
+        let mut dropped = Vec::new();
+        let mut shared_dropped = 0;
+        let result = match queue.value_mut() {
+            Queue::Fifo(q) => {
+                let limit = shed_threshold(q.capacity);
+                q.push_shedding(message.clone(), limit)
+                    .map(|shed| dropped.extend(shed))
+            }
+            Queue::Priority(q) => {
+                let limit = shed_threshold(q.capacity);
+                q.push_shedding(message.clone(), limit)
+                    .map(|shed| dropped.extend(shed))
+            }
+            // Shedding would drop messages still owed a delivery
+            Queue::AtLeastOnce(q) => q.push(message.clone()),
+            Queue::PubSub(q) => {
+                let limit = shed_threshold(q.capacity);
+                for (pid, tx) in &q.subscribers {
+                    if tx.len() < limit {
+                        continue;
+                    }
+                    if let Some(rx) = self.pubsub_receivers.get(&(queue_id, *pid)) {
+                        if rx.try_recv().is_ok() {
+                            shared_dropped += 1;
+                        }
+                    }
+                }
+                q.publish(message.clone()).map(|sent| {
+                    debug!("Published to {} subscribers", sent);
+                })
+            }
+        };
+        drop(queue);
+
+        if let Err(e) = result {
+            self.deallocate_message_memory(&message);
+            return Err(e);
+        }
+
+        for message in &dropped {
+            self.deallocate_message_memory(message);
+        }
+        let shed = dropped.len() + shared_dropped;
+        if shed == 0 {
+            return Ok(false);
+        }
+
+        self.shed_count.fetch_add(shed as u64, Ordering::Relaxed);
+        warn!(
+            "Queue {} near capacity, shed {} message(s) for PID {}",
+            queue_id, shed, from_pid
+        );
+        Ok(true)
+    }
+}
+
+fn queue_depth(queue: &Queue) -> QueueDepth {
+    match queue {
+        Queue::Fifo(q) => QueueDepth {
+            depth: q.len(),
+            capacity: q.capacity,
+        },
+        Queue::Priority(q) => QueueDepth {
+            depth: q.len(),
+            capacity: q.capacity,
+        },
+        Queue::PubSub(q) => QueueDepth {
+            depth: q.subscribers.values().map(|tx| tx.len()).max().unwrap_or(0),
+            capacity: q.capacity,
+        },
+        Queue::AtLeastOnce(q) => QueueDepth {
+            depth: q.len() + q.in_flight() + q.dead_lettered(),
+            capacity: q.capacity,
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ipc::types::QueueType;
+    use crate::memory::MemoryManager;
+
+    #[test]
+    fn test_fifo_sheds_oldest_near_capacity() {
+        let manager = QueueManager::new(MemoryManager::new());
+        let queue_id = manager.create(1, QueueType::Fifo, Some(10)).unwrap();
+
+        for i in 0..9u8 {
+            assert!(!manager
+                .try_send_or_shed(queue_id, 1, vec![i], None)
+                .unwrap());
+        }
+        assert!(manager.depth(queue_id).unwrap().near_capacity());
+
+        // At 90% the oldest message makes room instead of growing the backlog
+        assert!(manager
+            .try_send_or_shed(queue_id, 1, vec![9], None)
+            .unwrap());
+        assert_eq!(manager.depth(queue_id).unwrap().depth, 9);
+        assert_eq!(manager.messages_shed(), 1);
+
+        let first = manager.receive(queue_id, 1).unwrap().unwrap();
+        assert_eq!(manager.read_message_data(&first).unwrap(), vec![1]);
+    }
+
+    #[test]
+    fn test_priority_sheds_lowest_priority() {
+        let manager = QueueManager::new(MemoryManager::new());
+        let queue_id = manager.create(1, QueueType::Priority, Some(2)).unwrap();
+
+        manager.send(queue_id, 1, b"low".to_vec(), Some(1)).unwrap();
+        manager
+            .send(queue_id, 1, b"high".to_vec(), Some(9))
+            .unwrap();
+
+        assert!(manager
+            .try_send_or_shed(queue_id, 1, b"mid".to_vec(), Some(5))
+            .unwrap());
+        // Lower than everything queued: the new message is the one dropped
+        assert!(manager
+            .try_send_or_shed(queue_id, 1, b"lowest".to_vec(), Some(0))
+            .unwrap());
+        assert_eq!(manager.messages_shed(), 2);
+
+        let mut received = Vec::new();
+        while let Some(msg) = manager.receive(queue_id, 1).unwrap() {
+            received.push(manager.read_message_data(&msg).unwrap());
+        }
+        assert_eq!(received, vec![b"high".to_vec(), b"mid".to_vec()]);
+    }
+
+    #[test]
+    fn test_load_shedding_is_opt_in_per_queue() {
+        let manager = QueueManager::new(MemoryManager::new());
+        let fifo = manager.create(1, QueueType::Fifo, Some(4)).unwrap();
+        let acked = manager.create(1, QueueType::AtLeastOnce, Some(4)).unwrap();
+        assert!(!manager.sheds_load(fifo));
+
+        // Only the owner can opt in, and never for at-least-once queues
+        assert!(manager.set_load_shedding(fifo, 2, true).is_err());
+        assert!(manager.set_load_shedding(acked, 1, true).is_err());
+        assert!(!manager.sheds_load(acked));
+
+        manager.set_load_shedding(fifo, 1, true).unwrap();
+        assert!(manager.sheds_load(fifo));
+        manager.set_load_shedding(fifo, 1, false).unwrap();
+        assert!(!manager.sheds_load(fifo));
+    }
+}
@@ -22,6 +22,9 @@ pub(super) struct FifoQueue {
     pub messages: VecDeque<QueueMessage>,
     pub wait_queue: Arc<WaitQueue<QueueId>>,
     pub closed: bool,
+    pub max_wait_us: u64,
+    /// Drop old messages instead of failing once near capacity
+    pub shed_when_full: bool,
 }
 
 impl FifoQueue {
@@ -35,6 +38,8 @@ pub fn new(id: QueueId, owner: Pid, capacity: usize) -> Self {
             // Use long_wait config for IPC operations (futex on Linux, zero CPU spinning)
             wait_queue: Arc::new(WaitQueue::long_wait().into()),
             closed: false,
+            max_wait_us: 0,
+            shed_when_full: false,
         }
     }
 
@@ -55,8 +60,31 @@ pub fn push(&mut self, message: QueueMessage) -> IpcResult<()> {
         Ok(())
     }
 
+    /// Push, first dropping the oldest message if `limit` are already queued
+    ///
+    /// Returns the dropped message, if any.
+    pub fn push_shedding(
+        &mut self,
+        message: QueueMessage,
+        limit: usize,
+    ) -> IpcResult<Option<QueueMessage>> {
+        if self.closed {
+            return Err(IpcError::Closed("Queue closed".into()));
+        }
+
+        let shed = if self.messages.len() >= limit {
+            self.messages.pop_front()
+        } else {
+            None
+        };
+        self.push(message)?;
+        Ok(shed)
+    }
+
     pub fn pop(&mut self) -> Option<QueueMessage> {
-        self.messages.pop_front()
+        let message = self.messages.pop_front()?;
+        self.max_wait_us = self.max_wait_us.max(message.wait_time_us());
+        Some(message)
     }
 
     pub fn len(&self) -> usize {
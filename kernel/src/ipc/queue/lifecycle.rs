@@ -4,15 +4,18 @@
  */
 
 use super::super::types::{IpcError, IpcResult, QueueId, QueueType};
+use super::ack::AckQueue;
 use super::fifo::FifoQueue;
 use super::manager::{Queue, QueueManager};
 use super::priority::PriorityQueue;
 use super::pubsub::PubSubQueue;
-use super::types::{QueueStats, MAX_QUEUES_PER_PROCESS};
+use super::types::{QueueMessage, QueueStats, MAX_QUEUES_PER_PROCESS};
+use crate::core::limits::QUEUE_DRAIN_POLL_INTERVAL;
 use crate::core::types::{Pid, Size};
 use log::{info, warn};
 use std::collections::HashSet;
 use std::sync::atomic::Ordering;
+use std::time::{Duration, Instant};
 
 impl QueueManager {
     /// Create a new queue
@@ -80,6 +83,9 @@ fn create_queue_instance(
             QueueType::PubSub => {
                 Queue::PubSub(PubSubQueue::new(queue_id, owner_pid, capacity).into())
             }
+            QueueType::AtLeastOnce => {
+                Queue::AtLeastOnce(AckQueue::new(queue_id, owner_pid, capacity))
+            }
         }
     }
 
@@ -110,6 +116,147 @@ pub fn close(&self, queue_id: QueueId, pid: Pid) -> IpcResult<()> {
         Ok(())
     }
 
+    /// Set how fast waiting messages gain priority on a Priority queue
+    ///
+    /// `interval` is the wait that raises a message by one priority level;
+    /// `None` disables aging, so low priorities can starve. Queued messages
+    /// are re-ranked immediately.
+    pub fn set_priority_aging(
+        &self,
+        queue_id: QueueId,
+        pid: Pid,
+        interval: Option<Duration>,
+    ) -> IpcResult<()> {
+        let mut queue = self
+            .queues
+            .get_mut(&queue_id)
+            .ok_or_else(|| IpcError::NotFound(format!("Queue {} not found", queue_id).into()))?;
+
+        if queue.owner() != pid {
+            return Err(IpcError::PermissionDenied(
+                "Only queue owner can configure aging".into(),
+            ));
+        }
+
+        match queue.value_mut() {
+            Queue::Priority(q) => {
+                q.set_aging_interval(interval);
+                info!(
+                    "PID {} set priority aging on queue {} to {:?}",
+                    pid, queue_id, interval
+                );
+                Ok(())
+            }
+            _ => Err(IpcError::InvalidOperation(
+                format!("Queue {} is not a priority queue", queue_id).into(),
+            )),
+        }
+    }
+
+    /// Opt a queue into load shedding
+    ///
+    /// Sends through the async dispatcher then drop the oldest (or lowest
+    /// priority) message once the queue is near capacity rather than waiting
+    /// for room. At-least-once queues never shed, since dropped messages are
+    /// still owed a delivery.
+    pub fn set_load_shedding(&self, queue_id: QueueId, pid: Pid, enabled: bool) -> IpcResult<()> {
+        let mut queue = self
+            .queues
+            .get_mut(&queue_id)
+            .ok_or_else(|| IpcError::NotFound(format!("Queue {} not found", queue_id).into()))?;
+
+        if queue.owner() != pid {
+            return Err(IpcError::PermissionDenied(
+                "Only queue owner can configure load shedding".into(),
+            ));
+        }
+
+        match queue.value_mut() {
+            Queue::Fifo(q) => q.shed_when_full = enabled,
+            Queue::Priority(q) => q.shed_when_full = enabled,
+            Queue::PubSub(q) => q.shed_when_full = enabled,
+            Queue::AtLeastOnce(_) => {
+                return Err(IpcError::InvalidOperation(
+                    format!("Queue {} is an at-least-once queue", queue_id).into(),
+                ))
+            }
+        }
+        info!(
+            "PID {} set load shedding on queue {} to {}",
+            pid, queue_id, enabled
+        );
+        Ok(())
+    }
+
+    /// Set the lease timeout and delivery limit of an at-least-once queue
+    ///
+    /// Leases already handed out keep their original expiry.
+    pub fn set_ack_policy(
+        &self,
+        queue_id: QueueId,
+        pid: Pid,
+        visibility_timeout: Duration,
+        max_attempts: u32,
+    ) -> IpcResult<()> {
+        if max_attempts == 0 {
+            return Err(IpcError::InvalidOperation(
+                "max_attempts must be at least 1".into(),
+            ));
+        }
+
+        let mut queue = self
+            .queues
+            .get_mut(&queue_id)
+            .ok_or_else(|| IpcError::NotFound(format!("Queue {} not found", queue_id).into()))?;
+
+        if queue.owner() != pid {
+            return Err(IpcError::PermissionDenied(
+                "Only queue owner can configure acknowledgements".into(),
+            ));
+        }
+
+        match queue.value_mut() {
+            Queue::AtLeastOnce(q) => {
+                q.visibility_timeout = visibility_timeout;
+                q.max_attempts = max_attempts;
+                info!(
+                    "PID {} set ack policy on queue {}: timeout {:?}, {} attempts",
+                    pid, queue_id, visibility_timeout, max_attempts
+                );
+                Ok(())
+            }
+            _ => Err(IpcError::InvalidOperation(
+                format!("Queue {} is not an at-least-once queue", queue_id).into(),
+            )),
+        }
+    }
+
+    /// Remove the messages an at-least-once queue gave up delivering
+    ///
+    /// The owner takes over their data: read it with `read_message_data`.
+    pub fn take_dead_letters(&self, queue_id: QueueId, pid: Pid) -> IpcResult<Vec<QueueMessage>> {
+        let mut queue = self
+            .queues
+            .get_mut(&queue_id)
+            .ok_or_else(|| IpcError::NotFound(format!("Queue {} not found", queue_id).into()))?;
+
+        if queue.owner() != pid {
+            return Err(IpcError::PermissionDenied(
+                "Only queue owner can take dead letters".into(),
+            ));
+        }
+
+        match queue.value_mut() {
+            Queue::AtLeastOnce(q) => {
+                q.expire_leases(Instant::now());
+                Ok(q.take_dead_letters())
+            }
+            _ => Err(IpcError::InvalidOperation(
+                format!("Queue {} is not an at-least-once queue", queue_id).into(),
+            )),
+        }
+    }
+
     /// Destroy queue
     pub fn destroy(&self, queue_id: QueueId, pid: Pid) -> IpcResult<()> {
         self.verify_queue_ownership(queue_id, pid)?;
@@ -123,6 +270,67 @@ pub fn destroy(&self, queue_id: QueueId, pid: Pid) -> IpcResult<()> {
         Ok(())
     }
 
+    /// Destroy queue after letting consumers drain it
+    ///
+    /// The queue is closed so no new messages arrive, then consumers get up
+    /// to `drain_timeout` to receive what is pending. Whatever is left at the
+    /// deadline is dropped; returns how many messages that was.
+    pub fn destroy_draining(
+        &self,
+        queue_id: QueueId,
+        pid: Pid,
+        drain_timeout: Duration,
+    ) -> IpcResult<usize> {
+        self.verify_queue_ownership(queue_id, pid)?;
+        if let Some(mut queue) = self.queues.get_mut(&queue_id) {
+            queue.close();
+        }
+
+        let deadline = Instant::now() + drain_timeout;
+        let mut pending = self.pending_messages(queue_id);
+        while pending > 0 {
+            let remaining = deadline.saturating_duration_since(Instant::now());
+            if remaining.is_zero() {
+                break;
+            }
+            std::thread::sleep(remaining.min(QUEUE_DRAIN_POLL_INTERVAL));
+            pending = self.pending_messages(queue_id);
+        }
+
+        self.drain_queue_messages(queue_id)?;
+        self.remove_queue(queue_id, pid);
+
+        if pending > 0 {
+            warn!(
+                "PID {} destroyed queue {} after {:?} drain, dropping {} messages",
+                pid, queue_id, drain_timeout, pending
+            );
+        } else {
+            info!("PID {} drained and destroyed queue {}", pid, queue_id);
+        }
+        Ok(pending)
+    }
+
+    /// Messages not yet received, summed across subscribers for PubSub
+    fn pending_messages(&self, queue_id: QueueId) -> usize {
+        let Some(queue) = self.queues.get(&queue_id) else {
+            return 0;
+        };
+        match queue.value() {
+            Queue::Fifo(q) => q.len(),
+            Queue::Priority(q) => q.len(),
+            Queue::AtLeastOnce(q) => q.len() + q.in_flight(),
+            Queue::PubSub(_) => {
+                drop(queue);
+                self.pubsub_receivers
+                    .iter()
+                    .filter(|entry| entry.key().0 == queue_id)
+                    .map(|entry| entry.value().len())
+                    .sum()
+            }
+        }
+    }
+
     /// Verify queue ownership
     fn verify_queue_ownership(&self, queue_id: QueueId, pid: Pid) -> IpcResult<()> {
         let queue = self
@@ -149,6 +357,7 @@ fn drain_queue_messages(&self, queue_id: QueueId) -> IpcResult<usize> {
             let message = match queue.value_mut() {
                 Queue::Fifo(q) => q.pop(),
                 Queue::Priority(q) => q.pop(),
+                Queue::AtLeastOnce(q) => q.take_any(),
                 Queue::PubSub(_) => break,
             };
 
@@ -191,15 +400,21 @@ fn remove_queue(&self, queue_id: QueueId, pid: Pid) {
 
     /// Get queue statistics
     pub fn stats(&self, queue_id: QueueId) -> IpcResult<QueueStats> {
-        let queue = self
+        let mut queue = self
             .queues
-            .get(&queue_id)
+            .get_mut(&queue_id)
             .ok_or_else(|| IpcError::NotFound(format!("Queue {} not found", queue_id).into()))?;
 
+        // Settle expired leases so in-flight and dead-letter counts are current
+        if let Queue::AtLeastOnce(q) = queue.value_mut() {
+            q.expire_leases(Instant::now());
+        }
+
         let stats = match queue.value() {
             Queue::Fifo(q) => self.create_fifo_stats(q),
             Queue::Priority(q) => self.create_priority_stats(q),
             Queue::PubSub(q) => self.create_pubsub_stats(q),
+            Queue::AtLeastOnce(q) => self.create_ack_stats(q),
         };
 
         Ok(stats)
@@ -215,6 +430,11 @@ fn create_fifo_stats(&self, q: &FifoQueue) -> QueueStats {
             length: q.len(),
             subscriber_count: 0,
             closed: q.closed,
+            max_wait_us: q.max_wait_us,
+            in_flight: 0,
+            delivery_attempts: 0,
+            redelivered: 0,
+            dead_lettered: 0,
         }
     }
 
@@ -228,6 +448,11 @@ fn create_priority_stats(&self, q: &PriorityQueue) -> QueueStats {
             length: q.len(),
             subscriber_count: 0,
             closed: q.closed,
+            max_wait_us: q.max_wait_us,
+            in_flight: 0,
+            delivery_attempts: 0,
+            redelivered: 0,
+            dead_lettered: 0,
         }
     }
 
@@ -241,6 +466,29 @@ fn create_pubsub_stats(&self, q: &PubSubQueue) -> QueueStats {
             length: 0,
             subscriber_count: q.subscriber_count(),
             closed: q.closed,
+            max_wait_us: 0,
+            in_flight: 0,
+            delivery_attempts: 0,
+            redelivered: 0,
+            dead_lettered: 0,
+        }
+    }
+
+    /// Create at-least-once queue stats
+    fn create_ack_stats(&self, q: &AckQueue) -> QueueStats {
+        QueueStats {
+            id: q.id,
+            queue_type: QueueType::AtLeastOnce,
+            owner_pid: q.owner,
+            capacity: q.capacity,
+            length: q.len(),
+            subscriber_count: 0,
+            closed: q.closed,
+            max_wait_us: q.max_wait_us,
+            in_flight: q.in_flight(),
+            delivery_attempts: q.delivery_attempts,
+            redelivered: q.redelivered,
+            dead_lettered: q.dead_lettered(),
         }
     }
 
@@ -4,11 +4,13 @@
  */
 
 use super::super::types::QueueId;
+use super::ack::AckQueue;
 use super::fifo::FifoQueue;
 use super::priority::PriorityQueue;
 use super::pubsub::PubSubQueue;
 use super::types::{QueueMessage, MAX_QUEUE_CAPACITY};
 use crate::core::types::Pid;
+use crate::core::ShardManager;
 use crate::memory::MemoryManager;
 use crate::monitoring::Collector;
 use ahash::RandomState;
@@ -25,6 +27,7 @@ pub(super) enum Queue {
     Fifo(FifoQueue),
     Priority(PriorityQueue),
     PubSub(PubSubQueue),
+    AtLeastOnce(AckQueue),
 }
 
 impl Queue {
@@ -33,6 +36,7 @@ pub fn owner(&self) -> Pid {
             Queue::Fifo(q) => q.owner,
             Queue::Priority(q) => q.owner,
             Queue::PubSub(q) => q.owner,
+            Queue::AtLeastOnce(q) => q.owner,
         }
     }
 
@@ -42,6 +46,17 @@ pub fn len(&self) -> usize {
             Queue::Fifo(q) => q.len(),
             Queue::Priority(q) => q.len(),
             Queue::PubSub(q) => q.subscriber_count(),
+            Queue::AtLeastOnce(q) => q.len(),
+        }
+    }
+
+    /// Whether sends shed old messages instead of failing when near capacity
+    pub fn sheds_load(&self) -> bool {
+        match self {
+            Queue::Fifo(q) => q.shed_when_full,
+            Queue::Priority(q) => q.shed_when_full,
+            Queue::PubSub(q) => q.shed_when_full,
+            Queue::AtLeastOnce(_) => false,
         }
     }
 
@@ -50,6 +65,7 @@ pub fn close(&mut self) {
             Queue::Fifo(q) => q.close(),
             Queue::Priority(q) => q.close(),
             Queue::PubSub(q) => q.close(),
+            Queue::AtLeastOnce(q) => q.close(),
         }
     }
 }
@@ -69,6 +85,7 @@ pub struct QueueManager {
         Arc<DashMap<(QueueId, Pid), flume::Receiver<QueueMessage>, RandomState>>,
     pub(super) memory_manager: MemoryManager,
     pub(super) free_ids: Arc<SegQueue<QueueId>>,
+    pub(super) shed_count: Arc<AtomicU64>,
     pub(super) collector: Option<Arc<Collector>>,
 }
 
@@ -86,10 +103,35 @@ pub fn new(memory_manager: MemoryManager) -> Self {
             pubsub_receivers: Arc::new(DashMap::with_hasher(RandomState::new().into())),
             memory_manager,
             free_ids: Arc::new(SegQueue::new().into()),
+            shed_count: Arc::new(AtomicU64::new(0)),
             collector: None,
         }
     }
 
+    /// Override the default shard count of the internal maps
+    ///
+    /// `count` is rounded up to a power of two. Call before use: the maps
+    /// are replaced.
+    pub fn with_shards(mut self, count: usize) -> Self {
+        let shards = ShardManager::normalize(count);
+        self.queues = Arc::new(DashMap::with_capacity_and_hasher_and_shard_amount(
+            0,
+            RandomState::new(),
+            shards,
+        ));
+        self.process_queues = Arc::new(DashMap::with_capacity_and_hasher_and_shard_amount(
+            0,
+            RandomState::new(),
+            shards,
+        ));
+        self.pubsub_receivers = Arc::new(DashMap::with_capacity_and_hasher_and_shard_amount(
+            0,
+            RandomState::new(),
+            shards,
+        ));
+        self
+    }
+
     /// Add observability collector
     pub fn with_collector(mut self, collector: Arc<Collector>) -> Self {
         self.collector = Some(collector);
@@ -112,6 +154,7 @@ fn clone(&self) -> Self {
             pubsub_receivers: Arc::clone(&self.pubsub_receivers),
             memory_manager: self.memory_manager.clone(),
             free_ids: Arc::clone(&self.free_ids),
+            shed_count: Arc::clone(&self.shed_count),
             collector: self.collector.as_ref().map(Arc::clone),
         }
     }
@@ -121,6 +164,7 @@ fn clone(&self) -> Self {
 mod tests {
     use super::*;
     use crate::ipc::types::QueueType;
+    use std::time::Duration;
 
     #[tokio::test]
     async fn test_fifo_queue() {
@@ -169,6 +213,122 @@ async fn test_priority_queue() {
         assert_eq!(msg2.priority, 5);
     }
 
+    #[test]
+    fn test_priority_aging_prevents_starvation() {
+        let manager = QueueManager::new(MemoryManager::new());
+        let queue_id = manager.create(1, QueueType::Priority, Some(100)).unwrap();
+        manager
+            .set_priority_aging(queue_id, 1, Some(Duration::from_millis(1)))
+            .unwrap();
+
+        manager.send(queue_id, 1, b"low".to_vec(), Some(0)).unwrap();
+
+        // Keep the queue full of fresh high-priority work; the low-priority
+        // message still gets through once it has waited ~10 levels' worth
+        let mut received_low = false;
+        for _ in 0..1000 {
+            manager
+                .send(queue_id, 1, b"high".to_vec(), Some(10))
+                .unwrap();
+            let msg = manager.receive(queue_id, 1).unwrap().unwrap();
+            if manager.read_message_data(&msg).unwrap() == b"low" {
+                received_low = true;
+                break;
+            }
+            std::thread::sleep(Duration::from_millis(1));
+        }
+        assert!(received_low, "low-priority message starved");
+
+        let stats = manager.stats(queue_id).unwrap();
+        assert!(stats.max_wait_us >= 10_000);
+    }
+
+    #[test]
+    fn test_priority_aging_only_on_priority_queues() {
+        let manager = QueueManager::new(MemoryManager::new());
+        let fifo = manager.create(1, QueueType::Fifo, Some(10)).unwrap();
+        let priority = manager.create(1, QueueType::Priority, Some(10)).unwrap();
+
+        assert!(manager.set_priority_aging(fifo, 1, None).is_err());
+        assert!(manager.set_priority_aging(priority, 2, None).is_err());
+        assert!(manager.set_priority_aging(priority, 1, None).is_ok());
+    }
+
+    #[test]
+    fn test_at_least_once_ack() {
+        let manager = QueueManager::new(MemoryManager::new());
+        let queue_id = manager.create(1, QueueType::AtLeastOnce, Some(10)).unwrap();
+        manager.send(queue_id, 1, b"job".to_vec(), None).unwrap();
+
+        let msg = manager.receive(queue_id, 2).unwrap().unwrap();
+        assert_eq!(manager.read_message_data(&msg).unwrap(), b"job");
+        // Data survives the read so the message can be redelivered
+        assert_eq!(manager.read_message_data(&msg).unwrap(), b"job");
+
+        let stats = manager.stats(queue_id).unwrap();
+        assert_eq!((stats.length, stats.in_flight), (0, 1));
+        assert_eq!(stats.delivery_attempts, 1);
+
+        assert!(manager.ack(queue_id, 3, msg.id).is_err());
+        manager.ack(queue_id, 2, msg.id).unwrap();
+        assert!(manager.ack(queue_id, 2, msg.id).is_err());
+        assert_eq!(manager.stats(queue_id).unwrap().in_flight, 0);
+        assert!(manager.receive(queue_id, 2).unwrap().is_none());
+    }
+
+    #[test]
+    fn test_at_least_once_redelivers_after_timeout() {
+        let manager = QueueManager::new(MemoryManager::new());
+        let queue_id = manager.create(1, QueueType::AtLeastOnce, Some(10)).unwrap();
+        manager
+            .set_ack_policy(queue_id, 1, Duration::from_millis(20), 5)
+            .unwrap();
+        manager.send(queue_id, 1, b"job".to_vec(), None).unwrap();
+
+        // Consumer 2 takes the message and crashes without acking
+        let first = manager.receive(queue_id, 2).unwrap().unwrap();
+        assert!(manager.receive(queue_id, 3).unwrap().is_none());
+
+        std::thread::sleep(Duration::from_millis(30));
+        let second = manager.receive(queue_id, 3).unwrap().unwrap();
+        assert_eq!(second.id, first.id);
+        assert_eq!(manager.read_message_data(&second).unwrap(), b"job");
+
+        // The expired lease can no longer be acked by its old holder
+        assert!(manager.ack(queue_id, 2, first.id).is_err());
+        manager.ack(queue_id, 3, second.id).unwrap();
+
+        let stats = manager.stats(queue_id).unwrap();
+        assert_eq!(stats.delivery_attempts, 2);
+        assert_eq!(stats.redelivered, 1);
+    }
+
+    #[test]
+    fn test_at_least_once_dead_letters_after_max_attempts() {
+        let manager = QueueManager::new(MemoryManager::new());
+        let queue_id = manager.create(1, QueueType::AtLeastOnce, Some(10)).unwrap();
+        manager
+            .set_ack_policy(queue_id, 1, Duration::from_millis(10), 2)
+            .unwrap();
+        manager.send(queue_id, 1, b"poison".to_vec(), None).unwrap();
+
+        for _ in 0..2 {
+            assert!(manager.receive(queue_id, 2).unwrap().is_some());
+            std::thread::sleep(Duration::from_millis(20));
+        }
+        assert!(manager.receive(queue_id, 2).unwrap().is_none());
+
+        let stats = manager.stats(queue_id).unwrap();
+        assert_eq!(stats.dead_lettered, 1);
+        assert_eq!(stats.delivery_attempts, 2);
+
+        assert!(manager.take_dead_letters(queue_id, 2).is_err());
+        let dead = manager.take_dead_letters(queue_id, 1).unwrap();
+        assert_eq!(dead.len(), 1);
+        assert_eq!(manager.read_message_data(&dead[0]).unwrap(), b"poison");
+        assert_eq!(manager.stats(queue_id).unwrap().dead_lettered, 0);
+    }
+
     #[tokio::test]
     async fn test_pubsub_queue() {
         let memory_manager = MemoryManager::new();
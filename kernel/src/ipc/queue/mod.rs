@@ -3,6 +3,8 @@
  * Async message queues with multiple queue types
  */
 
+pub mod ack;
+pub mod backpressure;
 pub mod fifo;
 pub mod lifecycle;
 pub mod manager;
@@ -14,4 +16,4 @@
 
 // Re-export public API
 pub use manager::QueueManager;
-pub use types::{QueueMessage, QueueStats};
+pub use types::{QueueDepth, QueueMessage, QueueStats};
@@ -32,25 +32,25 @@ fn validate_and_allocate_message(
         data: Vec<u8>,
         priority: Option<Priority>,
     ) -> IpcResult<()> {
-        // Validate message size
-        if data.len() > MAX_MESSAGE_SIZE {
-            return Err(IpcError::LimitExceeded(
-                format!(
-                    "Message size {} exceeds limit {}",
-                    data.len(),
-                    MAX_MESSAGE_SIZE
-                )
-                .into(),
-            ));
-        }
+        Self::validate_message_size(data.len())?;
 
         let data_address = self.allocate_message_memory(from_pid, &data)?;
         let message = self.create_queue_message(from_pid, data_address, data.len(), priority);
         self.enqueue_message(queue_id, message)
     }
 
+    /// Validate message size
+    pub(super) fn validate_message_size(len: usize) -> IpcResult<()> {
+        if len > MAX_MESSAGE_SIZE {
+            return Err(IpcError::LimitExceeded(
+                format!("Message size {} exceeds limit {}", len, MAX_MESSAGE_SIZE).into(),
+            ));
+        }
+        Ok(())
+    }
+
     /// Allocate memory for message data
-    fn allocate_message_memory(&self, from_pid: Pid, data: &[u8]) -> IpcResult<usize> {
+    pub(super) fn allocate_message_memory(&self, from_pid: Pid, data: &[u8]) -> IpcResult<usize> {
         let data_len = data.len();
         let data_address = self
             .memory_manager
@@ -72,7 +72,7 @@ fn allocate_message_memory(&self, from_pid: Pid, data: &[u8]) -> IpcResult<usize
     }
 
     /// Create a queue message structure
-    fn create_queue_message(
+    pub(super) fn create_queue_message(
         &self,
         from_pid: Pid,
         data_address: usize,
@@ -102,6 +102,7 @@ fn enqueue_message(&self, queue_id: QueueId, message: QueueMessage) -> IpcResult
         match queue.value_mut() {
             Queue::Fifo(q) => q.push(message)?,
             Queue::Priority(q) => q.push(message)?,
+            Queue::AtLeastOnce(q) => q.push(message)?,
             Queue::PubSub(q) => {
                 let sent = q.publish(message)?;
                 debug!("Published to {} subscribers", sent);
@@ -128,13 +129,10 @@ fn enqueue_message(&self, queue_id: QueueId, message: QueueMessage) -> IpcResult
 
     /// Receive message from queue (non-blocking)
     pub fn receive(&self, queue_id: QueueId, pid: Pid) -> IpcResult<Option<QueueMessage>> {
-        let start = Instant::now();
-
         // Check for PubSub receiver
         if let Some(message) = self.try_receive_pubsub(queue_id, pid)? {
             // Emit message received event
             if let Some(ref collector) = self.collector {
-                let wait_time_us = start.elapsed().as_micros() as u64;
                 collector.emit(
                     Event::new(
                         Severity::Debug,
@@ -142,7 +140,7 @@ pub fn receive(&self, queue_id: QueueId, pid: Pid) -> IpcResult<Option<QueueMess
                         Payload::MessageReceived {
                             queue_id: queue_id as u64,
                             size: message.data_length,
-                            wait_time_us,
+                            wait_time_us: message.wait_time_us(),
                         },
                     )
                     .with_pid(pid),
@@ -151,13 +149,12 @@ pub fn receive(&self, queue_id: QueueId, pid: Pid) -> IpcResult<Option<QueueMess
             return Ok(Some(message));
         }
 
-        // For FIFO and Priority queues
-        let message = self.receive_from_standard_queue(queue_id)?;
+        // For FIFO, Priority and at-least-once queues
+        let message = self.receive_from_standard_queue(queue_id, pid)?;
 
         // Emit message received event if message was received
         if let Some(ref msg) = message {
             if let Some(ref collector) = self.collector {
-                let wait_time_us = start.elapsed().as_micros() as u64;
                 collector.emit(
                     Event::new(
                         Severity::Debug,
@@ -165,7 +162,7 @@ pub fn receive(&self, queue_id: QueueId, pid: Pid) -> IpcResult<Option<QueueMess
                         Payload::MessageReceived {
                             queue_id: queue_id as u64,
                             size: msg.data_length,
-                            wait_time_us,
+                            wait_time_us: msg.wait_time_us(),
                         },
                     )
                     .with_pid(pid),
@@ -195,8 +192,14 @@ fn try_receive_pubsub(&self, queue_id: QueueId, pid: Pid) -> IpcResult<Option<Qu
         Ok(None)
     }
 
-    /// Receive from FIFO or Priority queue
-    fn receive_from_standard_queue(&self, queue_id: QueueId) -> IpcResult<Option<QueueMessage>> {
+    /// Receive from FIFO, Priority or at-least-once queue
+    ///
+    /// At-least-once queues lease the message to `pid` rather than removing it.
+    fn receive_from_standard_queue(
+        &self,
+        queue_id: QueueId,
+        pid: Pid,
+    ) -> IpcResult<Option<QueueMessage>> {
         let mut queue = self
             .queues
             .get_mut(&queue_id)
@@ -205,16 +208,47 @@ fn receive_from_standard_queue(&self, queue_id: QueueId) -> IpcResult<Option<Que
         let msg = match queue.value_mut() {
             Queue::Fifo(q) => q.pop(),
             Queue::Priority(q) => q.pop(),
+            Queue::AtLeastOnce(q) => q.lease(pid, Instant::now()),
             Queue::PubSub(_) => unreachable!(),
         };
 
         Ok(msg)
     }
 
+    /// Acknowledge a message leased from an at-least-once queue
+    ///
+    /// Removes it for good and frees its data. Fails if the lease expired
+    /// or is held by another process.
+    pub fn ack(&self, queue_id: QueueId, pid: Pid, msg_id: u64) -> IpcResult<()> {
+        let mut queue = self
+            .queues
+            .get_mut(&queue_id)
+            .ok_or_else(|| IpcError::NotFound(format!("Queue {} not found", queue_id).into()))?;
+
+        let message = match queue.value_mut() {
+            Queue::AtLeastOnce(q) => q.ack(msg_id, pid, Instant::now())?,
+            _ => {
+                return Err(IpcError::InvalidOperation(
+                    format!("Queue {} does not take acknowledgements", queue_id).into(),
+                ))
+            }
+        };
+        drop(queue);
+
+        self.deallocate_message_memory(&message);
+        debug!("PID {} acked message {} on queue {}", pid, msg_id, queue_id);
+        Ok(())
+    }
+
     /// Read message data from MemoryManager and deallocate
+    ///
+    /// Leased messages keep their data until acknowledged, since they may
+    /// be redelivered.
     pub fn read_message_data(&self, message: &QueueMessage) -> IpcResult<Vec<u8>> {
         let data = self.read_data_from_memory(message)?;
-        self.deallocate_message_memory(message);
+        if !message.leased {
+            self.deallocate_message_memory(message);
+        }
         Ok(data)
     }
 
@@ -228,7 +262,7 @@ fn read_data_from_memory(&self, message: &QueueMessage) -> IpcResult<Vec<u8>> {
     }
 
     /// Deallocate message memory
-    fn deallocate_message_memory(&self, message: &QueueMessage) {
+    pub(super) fn deallocate_message_memory(&self, message: &QueueMessage) {
         if let Err(e) = self.memory_manager.deallocate(message.data_address) {
             warn!(
                 "Failed to deallocate message data at 0x{:x}: {}",
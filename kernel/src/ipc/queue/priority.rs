@@ -1,14 +1,21 @@
 /*!
  * Priority Queue
  * Priority-based message queue implementation (higher priority first)
+ *
+ * Waiting messages gain one priority level per aging interval, so a steady
+ * stream of high-priority sends cannot starve a low-priority message forever.
+ * Every queued message ages at the same rate, which means relative order is
+ * fixed at push time: the rank is the priority scaled to aging time minus the
+ * arrival time, and the heap never needs re-sorting as time passes.
  */
 
 use super::super::types::{IpcError, IpcResult, QueueId};
-use super::types::{PriorityMessage, QueueMessage};
+use super::types::{PriorityMessage, QueueMessage, QUEUE_PRIORITY_AGING_INTERVAL};
 use crate::core::sync::WaitQueue;
 use crate::core::types::Pid;
 use std::collections::BinaryHeap;
 use std::sync::Arc;
+use std::time::{Duration, Instant};
 
 /// Priority queue implementation
 ///
@@ -22,6 +29,13 @@ pub(super) struct PriorityQueue {
     pub messages: BinaryHeap<PriorityMessage>,
     pub wait_queue: Arc<WaitQueue<QueueId>>,
     pub closed: bool,
+    pub max_wait_us: u64,
+    /// Drop low-priority messages instead of failing once near capacity
+    pub shed_when_full: bool,
+    /// Wait that raises a message by one level; `None` disables aging
+    aging_interval: Option<Duration>,
+    /// Reference point for arrival times in ranks
+    created_at: Instant,
 }
 
 impl PriorityQueue {
@@ -35,6 +49,43 @@ pub fn new(id: QueueId, owner: Pid, capacity: usize) -> Self {
             // Use long_wait config for IPC operations (futex on Linux, zero CPU spinning)
             wait_queue: Arc::new(WaitQueue::long_wait().into()),
             closed: false,
+            max_wait_us: 0,
+            shed_when_full: false,
+            aging_interval: Some(QUEUE_PRIORITY_AGING_INTERVAL),
+            created_at: Instant::now(),
+        }
+    }
+
+    /// Change the aging rate and re-rank queued messages
+    pub fn set_aging_interval(&mut self, interval: Option<Duration>) {
+        self.aging_interval = interval.filter(|interval| !interval.is_zero());
+        let mut queued = std::mem::take(&mut self.messages).into_vec();
+        for pm in &mut queued {
+            pm.rank = self.rank(pm.message.priority, pm.enqueued_at);
+        }
+        self.messages = BinaryHeap::from(queued);
+    }
+
+    /// Effective priority of a message, comparable across arrival times
+    fn rank(&self, priority: u8, enqueued_at: Instant) -> i64 {
+        let Some(interval) = self.aging_interval else {
+            return priority as i64;
+        };
+        let interval_us = interval.as_micros().min(i64::MAX as u128) as i64;
+        let arrival_us = enqueued_at
+            .saturating_duration_since(self.created_at)
+            .as_micros() as i64;
+        (priority as i64)
+            .saturating_mul(interval_us)
+            .saturating_sub(arrival_us)
+    }
+
+    fn wrap(&self, message: QueueMessage) -> PriorityMessage {
+        let enqueued_at = Instant::now();
+        PriorityMessage {
+            rank: self.rank(message.priority, enqueued_at),
+            message,
+            enqueued_at,
         }
     }
 
@@ -49,14 +100,58 @@ pub fn push(&mut self, message: QueueMessage) -> IpcResult<()> {
             ));
         }
 
-        self.messages.push(PriorityMessage { message });
+        let pm = self.wrap(message);
+        self.messages.push(pm);
         // Wake one waiter using centralized futex-based wake (Linux) or condvar (other platforms)
         self.wait_queue.wake_one(self.id);
         Ok(())
     }
 
+    /// Push, first dropping the lowest-priority message if `limit` are already queued
+    ///
+    /// Priority here is the aged, effective priority. Ties drop the oldest
+    /// message. If `message` ranks below everything queued, it is dropped
+    /// instead. Returns the dropped message, if any.
+    pub fn push_shedding(
+        &mut self,
+        message: QueueMessage,
+        limit: usize,
+    ) -> IpcResult<Option<QueueMessage>> {
+        if self.closed {
+            return Err(IpcError::Closed("Queue closed".into()));
+        }
+        if self.messages.len() < limit {
+            self.push(message)?;
+            return Ok(None);
+        }
+
+        // BinaryHeap only exposes the max, so find the min by rebuilding
+        let incoming = self.wrap(message);
+        let mut queued = std::mem::take(&mut self.messages).into_vec();
+        let lowest = queued
+            .iter()
+            .enumerate()
+            .min_by_key(|(_, pm)| (pm.rank, pm.message.id))
+            .map(|(index, _)| index);
+
+        let shed = match lowest {
+            Some(index) if queued[index].rank <= incoming.rank => {
+                let shed = queued.swap_remove(index).message;
+                queued.push(incoming);
+                self.wait_queue.wake_one(self.id);
+                shed
+            }
+            _ => incoming.message,
+        };
+        self.messages = BinaryHeap::from(queued);
+        Ok(Some(shed))
+    }
+
     pub fn pop(&mut self) -> Option<QueueMessage> {
-        self.messages.pop().map(|pm| pm.message)
+        let pm = self.messages.pop()?;
+        let waited_us = pm.enqueued_at.elapsed().as_micros() as u64;
+        self.max_wait_us = self.max_wait_us.max(waited_us);
+        Some(pm.message)
     }
 
     pub fn len(&self) -> usize {
@@ -17,6 +17,8 @@ pub(super) struct PubSubQueue {
     pub capacity: usize,
     pub subscribers: HashMap<Pid, flume::Sender<QueueMessage>>,
     pub closed: bool,
+    /// Drop a lagging subscriber's oldest message instead of failing
+    pub shed_when_full: bool,
 }
 
 impl PubSubQueue {
@@ -28,6 +30,7 @@ pub fn new(id: QueueId, owner: Pid, capacity: usize) -> Self {
             capacity: capacity.min(MAX_QUEUE_CAPACITY),
             subscribers: HashMap::default(),
             closed: false,
+            shed_when_full: false,
         }
     }
 
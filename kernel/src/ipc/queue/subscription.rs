@@ -123,6 +123,7 @@ fn get_queue_wait_queue(
         match queue.value() {
             Queue::Fifo(q) => Ok(q.wait_queue.clone().into()),
             Queue::Priority(q) => Ok(q.wait_queue.clone().into()),
+            Queue::AtLeastOnce(q) => Ok(q.wait_queue.clone()),
             Queue::PubSub(_) => {
                 Err(IpcError::InvalidOperation("Use subscribe for PubSub queues".into()).into())
             }
@@ -139,6 +140,7 @@ fn is_queue_closed(&self, queue_id: QueueId) -> IpcResult<bool> {
         let closed = match queue.value() {
             Queue::Fifo(q) => q.closed,
             Queue::Priority(q) => q.closed,
+            Queue::AtLeastOnce(q) => q.closed,
             Queue::PubSub(_) => false,
         };
 
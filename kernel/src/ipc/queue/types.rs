@@ -9,11 +9,13 @@
 use crate::core::types::{Pid, Size};
 use serde::{Deserialize, Serialize};
 use std::cmp::Ordering;
-use std::time::SystemTime;
+use std::time::{Instant, SystemTime};
 
 // Queue limits - centralized in core::limits
 pub use limits::{
     GLOBAL_QUEUE_MEMORY_LIMIT, MAX_MESSAGE_SIZE, MAX_QUEUES_PER_PROCESS, MAX_QUEUE_CAPACITY,
+    QUEUE_MAX_DELIVERY_ATTEMPTS, QUEUE_PRIORITY_AGING_INTERVAL, QUEUE_SHED_THRESHOLD_PCT,
+    QUEUE_VISIBILITY_TIMEOUT,
 };
 
 /// Queue message with metadata (data stored in MemoryManager)
@@ -37,6 +39,9 @@ pub struct QueueMessage {
     pub priority: u8,
     #[serde(with = "system_time_micros")]
     pub timestamp: SystemTime,
+    /// Delivered under a lease: data stays allocated until acknowledged
+    #[serde(skip)]
+    pub leased: bool,
 }
 
 impl QueueMessage {
@@ -48,6 +53,7 @@ pub fn new(id: u64, from: Pid, data_address: usize, data_length: usize, priority
             data_length,
             priority,
             timestamp: SystemTime::now(),
+            leased: false,
         }
     }
 
@@ -60,6 +66,14 @@ pub fn data_size(&self) -> usize {
         self.data_length
     }
 
+    /// Microseconds since the message was sent
+    pub fn wait_time_us(&self) -> u64 {
+        SystemTime::now()
+            .duration_since(self.timestamp)
+            .map(|elapsed| elapsed.as_micros() as u64)
+            .unwrap_or(0)
+    }
+
     /// Read the actual data from MemoryManager
     pub fn read_data(
         &self,
@@ -97,11 +111,14 @@ impl crate::core::traits::BincodeSerializable for QueueMessage {}
 #[derive(Debug)]
 pub(super) struct PriorityMessage {
     pub message: QueueMessage,
+    /// Effective priority including aging (see `PriorityQueue::rank`)
+    pub rank: i64,
+    pub enqueued_at: Instant,
 }
 
 impl PartialEq for PriorityMessage {
     fn eq(&self, other: &Self) -> bool {
-        self.message.priority == other.message.priority
+        self.cmp(other) == Ordering::Equal
     }
 }
 
@@ -115,10 +132,9 @@ fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
 
 impl Ord for PriorityMessage {
     fn cmp(&self, other: &Self) -> Ordering {
-        // Higher priority first (max heap)
-        self.message
-            .priority
-            .cmp(&other.message.priority)
+        // Higher effective priority first (max heap), then oldest
+        self.rank
+            .cmp(&other.rank)
             .then_with(|| other.message.id.cmp(&self.message.id))
     }
 }
@@ -137,6 +153,44 @@ pub struct QueueStats {
     pub subscriber_count: Size,
     #[serde(skip_serializing_if = "is_false")]
     pub closed: bool,
+    /// Longest time a received message spent queued
+    #[serde(skip_serializing_if = "crate::core::serialization::serde::is_default")]
+    pub max_wait_us: u64,
+    /// Messages delivered but not yet acknowledged (at-least-once queues)
+    #[serde(skip_serializing_if = "is_zero_usize")]
+    pub in_flight: Size,
+    /// Total deliveries, counting each redelivery (at-least-once queues)
+    #[serde(skip_serializing_if = "crate::core::serialization::serde::is_default")]
+    pub delivery_attempts: u64,
+    /// Deliveries of a message whose earlier lease expired unacknowledged
+    #[serde(skip_serializing_if = "crate::core::serialization::serde::is_default")]
+    pub redelivered: u64,
+    /// Messages that exhausted their delivery attempts, awaiting the owner
+    #[serde(skip_serializing_if = "is_zero_usize")]
+    pub dead_lettered: Size,
+}
+
+/// Backlog of a queue relative to its capacity
+///
+/// For PubSub queues the depth is the deepest subscriber backlog, since the
+/// slowest consumer is the one that fills up.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub struct QueueDepth {
+    pub depth: Size,
+    pub capacity: Size,
+}
+
+impl QueueDepth {
+    /// Whether the backlog has reached the shedding threshold
+    pub fn near_capacity(&self) -> bool {
+        self.depth >= shed_threshold(self.capacity)
+    }
+}
+
+/// Depth at which shedding sends start dropping messages
+pub(super) fn shed_threshold(capacity: usize) -> usize {
+    (capacity * QUEUE_SHED_THRESHOLD_PCT / 100).max(1)
 }
 
 // Implement BincodeSerializable for QueueStats
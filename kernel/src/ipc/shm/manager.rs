@@ -12,6 +12,7 @@
 use crate::core::sync::lockfree::FlatCombiningCounter;
 use crate::core::sync::AdaptiveLock;
 use crate::core::types::{Pid, Size};
+use crate::core::ShardManager;
 use crate::memory::MemoryManager;
 use crate::monitoring::Collector;
 use ahash::RandomState;
@@ -59,6 +60,25 @@ pub fn new(memory_manager: MemoryManager) -> Self {
         }
     }
 
+    /// Override the default shard count of the internal maps
+    ///
+    /// `count` is rounded up to a power of two. Call before use: the maps
+    /// are replaced.
+    pub fn with_shards(mut self, count: usize) -> Self {
+        let shards = ShardManager::normalize(count);
+        self.segments = Arc::new(DashMap::with_capacity_and_hasher_and_shard_amount(
+            0,
+            RandomState::new(),
+            shards,
+        ));
+        self.process_segments = Arc::new(DashMap::with_capacity_and_hasher_and_shard_amount(
+            0,
+            RandomState::new(),
+            shards,
+        ));
+        self
+    }
+
     /// Add observability collector
     pub fn with_collector(mut self, collector: Arc<Collector>) -> Self {
         self.collector = Some(collector);
@@ -384,6 +404,86 @@ pub fn destroy(&self, segment_id: ShmId, pid: Pid) -> Result<(), ShmError> {
         Ok(())
     }
 
+    /// Grow or shrink a segment in place, keeping its ID and attachments
+    ///
+    /// Only the owner may resize. Contents up to `min(old, new)` are
+    /// preserved; shrinking below the highest written byte fails. The segment
+    /// entry is held exclusively for the whole move, so concurrent readers and
+    /// writers see either the old or the new mapping, never a torn one.
+    pub fn resize(&self, segment_id: ShmId, pid: Pid, new_size: Size) -> Result<(), ShmError> {
+        if new_size == 0 {
+            return Err(ShmError::InvalidSize("Size cannot be zero".to_string()));
+        }
+
+        if new_size > MAX_SEGMENT_SIZE {
+            return Err(ShmError::SizeExceeded {
+                requested: new_size,
+                max: MAX_SEGMENT_SIZE,
+            });
+        }
+
+        let mut segment = self
+            .segments
+            .get_mut(&segment_id)
+            .ok_or(ShmError::NotFound(segment_id))?;
+
+        // Only owner can resize
+        if segment.owner_pid != pid {
+            return Err(ShmError::PermissionDenied(
+                "Only owner can resize segment".to_string(),
+            ));
+        }
+
+        let old_size = segment.size;
+        if new_size == old_size {
+            return Ok(());
+        }
+
+        if new_size > old_size {
+            let current_global = GLOBAL_SHM_MEMORY.load(Ordering::Acquire) as usize;
+            if current_global + (new_size - old_size) > GLOBAL_SHM_MEMORY_LIMIT {
+                return Err(ShmError::GlobalMemoryExceeded(
+                    current_global,
+                    GLOBAL_SHM_MEMORY_LIMIT,
+                ));
+            }
+        }
+
+        let new_address = self
+            .memory_manager
+            .allocate(new_size, segment.owner_pid)
+            .map_err(|e| ShmError::AllocationFailed(e.to_string()))?;
+
+        let old_address = match segment.resize(new_size, new_address) {
+            Ok(address) => address,
+            Err(e) => {
+                let _ = self.memory_manager.deallocate(new_address);
+                return Err(e);
+            }
+        };
+        drop(segment);
+
+        if let Err(e) = self.memory_manager.deallocate(old_address) {
+            warn!(
+                "Failed to deallocate old memory for segment {} at address 0x{:x}: {}",
+                segment_id, old_address, e
+            );
+        }
+
+        if new_size > old_size {
+            GLOBAL_SHM_MEMORY.fetch_add((new_size - old_size) as u64, Ordering::Release);
+        } else {
+            GLOBAL_SHM_MEMORY.fetch_sub((old_size - new_size) as u64, Ordering::Release);
+        }
+
+        info!(
+            "Resized segment {} from {} to {} bytes (moved 0x{:x} -> 0x{:x})",
+            segment_id, old_size, new_size, old_address, new_address
+        );
+
+        Ok(())
+    }
+
     pub fn stats(&self, segment_id: ShmId) -> Result<ShmStats, ShmError> {
         let segment = self
             .segments
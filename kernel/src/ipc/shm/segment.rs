@@ -9,6 +9,7 @@
 use crate::memory::MemoryManager;
 use ahash::HashMap;
 use std::collections::HashSet;
+use std::sync::atomic::{AtomicUsize, Ordering};
 use std::sync::{Arc, RwLock};
 
 use super::super::types::ShmId;
@@ -22,6 +23,8 @@ pub(super) struct SharedSegment {
     pub attached_pids: HashSet<Pid>,
     pub permissions: HashMap<Pid, ShmPermission>,
     pub cow_data: Arc<RwLock<Option<CowMemory>>>,
+    /// End of the highest byte ever written (the in-use extent)
+    pub in_use: AtomicUsize,
 }
 
 impl SharedSegment {
@@ -49,6 +52,7 @@ pub fn new(
             attached_pids,
             permissions,
             cow_data,
+            in_use: AtomicUsize::new(0),
         }
     }
 
@@ -79,6 +83,8 @@ pub fn write(&self, offset: Size, data: &[u8]) -> Result<(), ShmError> {
             });
         }
 
+        self.in_use.fetch_max(offset + data.len(), Ordering::AcqRel);
+
         if let Ok(mut cow_lock) = self.cow_data.write() {
             if let Some(ref mut cow) = *cow_lock {
                 cow.write(|buffer| {
@@ -98,6 +104,41 @@ pub fn write(&self, offset: Size, data: &[u8]) -> Result<(), ShmError> {
             })
     }
 
+    /// Move the segment to `new_address` with `new_size` bytes in place
+    ///
+    /// Contents up to `min(old, new)` are preserved and attachments are kept.
+    /// Requires exclusive access, so no reader or writer observes a partially
+    /// copied segment. Returns the old address for the caller to release.
+    pub fn resize(&mut self, new_size: Size, new_address: Address) -> Result<Address, ShmError> {
+        let in_use = self.in_use.load(Ordering::Acquire);
+        if new_size < in_use {
+            return Err(ShmError::ShrinkBelowInUse {
+                requested: new_size,
+                in_use,
+            });
+        }
+
+        let preserved = self.read(0, self.size.min(new_size))?;
+
+        match self.cow_data.write() {
+            Ok(mut cow_lock) if cow_lock.is_some() => {
+                let mut buffer = preserved;
+                buffer.resize(new_size, 0);
+                *cow_lock = Some(CowMemory::new(buffer));
+            }
+            _ => {
+                self.memory_manager
+                    .write_bytes(new_address, &preserved)
+                    .map_err(|e| ShmError::AllocationFailed(e.to_string()))?;
+            }
+        }
+
+        let old_address = self.address;
+        self.size = new_size;
+        self.address = new_address;
+        Ok(old_address)
+    }
+
     pub fn read(&self, offset: Size, size: Size) -> Result<Vec<u8>, ShmError> {
         if offset + size > self.size {
             return Err(ShmError::InvalidRange {
@@ -4,6 +4,7 @@
  */
 
 use super::super::types::{IpcError, ShmId};
+use crate::core::errors::ErrorCode;
 use crate::core::limits;
 use crate::core::serialization::serde::{is_empty_vec, is_zero_usize};
 use crate::core::types::{Pid, Size};
@@ -52,6 +53,27 @@ pub enum ShmError {
     /// Memory allocation failed
     #[error("Memory allocation failed: {0}")]
     AllocationFailed(String),
+
+    /// Shrinking would discard data already written to the segment
+    #[error("Cannot shrink segment below in-use data: requested {requested}, in use {in_use}")]
+    ShrinkBelowInUse { requested: usize, in_use: usize },
+}
+
+impl ShmError {
+    /// Code of the IpcError this converts to
+    pub const fn typed_code(&self) -> ErrorCode {
+        match self {
+            Self::NotFound(_) => IpcError::NOT_FOUND,
+            Self::PermissionDenied(_) => IpcError::PERMISSION_DENIED,
+            Self::SizeExceeded { .. }
+            | Self::ProcessLimitExceeded(..)
+            | Self::GlobalMemoryExceeded(..) => IpcError::LIMIT_EXCEEDED,
+            Self::InvalidSize(_)
+            | Self::InvalidRange { .. }
+            | Self::AllocationFailed(_)
+            | Self::ShrinkBelowInUse { .. } => IpcError::INVALID_OPERATION,
+        }
+    }
 }
 
 // Convert ShmError to IpcError
@@ -94,6 +116,13 @@ fn from(err: ShmError) -> Self {
             ShmError::AllocationFailed(msg) => {
                 IpcError::InvalidOperation(format!("Memory allocation failed: {}", msg).into())
             }
+            ShmError::ShrinkBelowInUse { requested, in_use } => IpcError::InvalidOperation(
+                format!(
+                    "Cannot shrink segment below in-use data: requested {}, in use {}",
+                    requested, in_use
+                )
+                .into(),
+            ),
         }
     }
 }
@@ -1,11 +1,17 @@
 /*!
  * Lock-Free Ring Buffer
- * SPSC (Single Producer Single Consumer) lock-free ring buffer for IPC hot paths
+ * SPSC (Single Producer Single Consumer) lock-free ring buffer for IPC hot paths,
+ * plus an MPSC variant for fan-in
  */
 
+use crate::core::sync::WaitQueue;
 use crate::core::{simd_memcpy, PooledBuffer};
 use crossbeam_queue::ArrayQueue;
+use std::cell::UnsafeCell;
+use std::mem::MaybeUninit;
+use std::sync::atomic::{AtomicUsize, Ordering};
 use std::sync::Arc;
+use std::time::{Duration, Instant};
 
 /// Lock-free SPSC ring buffer optimized for IPC
 ///
@@ -254,6 +260,311 @@ fn clone(&self) -> Self {
     }
 }
 
+/// Longest a blocked MPSC caller sleeps before rechecking the ring
+///
+/// Wakeups are best-effort (a wake can race a waiter going to sleep), so
+/// blocking operations never park for longer than this at a time.
+const MPSC_WAIT_SLICE: Duration = Duration::from_millis(1);
+
+/// Wait queue keys for blocked MPSC callers
+const MPSC_NOT_EMPTY: u8 = 0;
+const MPSC_NOT_FULL: u8 = 1;
+
+/// Ring slot tagged with the position it is ready for
+///
+/// `seq == pos` means free for the producer claiming `pos`;
+/// `seq == pos + 1` means holding the value written at `pos`.
+struct MpscSlot<T> {
+    seq: AtomicUsize,
+    value: UnsafeCell<MaybeUninit<T>>,
+}
+
+/// Index on its own cache line so producers and the consumer don't false-share
+#[repr(C, align(64))]
+struct PaddedIndex(AtomicUsize);
+
+struct MpscShared<T> {
+    slots: Box<[MpscSlot<T>]>,
+    mask: usize,
+    /// Next position a producer will claim
+    tail: PaddedIndex,
+    /// Next position the consumer will read
+    head: PaddedIndex,
+    wait_queue: WaitQueue<u8>,
+}
+
+// Values only move between threads through the slot handoff protocol
+unsafe impl<T: Send> Send for MpscShared<T> {}
+unsafe impl<T: Send> Sync for MpscShared<T> {}
+
+impl<T> MpscShared<T> {
+    fn new(capacity: usize) -> Self {
+        assert!(capacity > 0, "Capacity must be greater than 0");
+        // A single slot can't tell "written at pos" from "free for pos + 1"
+        let capacity = capacity.next_power_of_two().max(2);
+        let slots = (0..capacity)
+            .map(|i| MpscSlot {
+                seq: AtomicUsize::new(i),
+                value: UnsafeCell::new(MaybeUninit::uninit()),
+            })
+            .collect();
+
+        Self {
+            slots,
+            mask: capacity - 1,
+            tail: PaddedIndex(AtomicUsize::new(0)),
+            head: PaddedIndex(AtomicUsize::new(0)),
+            wait_queue: WaitQueue::low_latency(),
+        }
+    }
+
+    fn try_push(&self, item: T) -> Result<(), T> {
+        let mut pos = self.tail.0.load(Ordering::Relaxed);
+        loop {
+            let slot = &self.slots[pos & self.mask];
+            let seq = slot.seq.load(Ordering::Acquire);
+            let diff = seq.wrapping_sub(pos) as isize;
+
+            if diff == 0 {
+                // Slot is free for `pos`; race other producers to claim it
+                match self.tail.0.compare_exchange_weak(
+                    pos,
+                    pos.wrapping_add(1),
+                    Ordering::Relaxed,
+                    Ordering::Relaxed,
+                ) {
+                    Ok(_) => {
+                        // SAFETY: the CAS made this producer the slot's only writer
+                        unsafe { (*slot.value.get()).write(item) };
+                        slot.seq.store(pos.wrapping_add(1), Ordering::Release);
+                        self.wait_queue.wake_one(MPSC_NOT_EMPTY);
+                        return Ok(());
+                    }
+                    Err(current) => pos = current,
+                }
+            } else if diff < 0 {
+                // Slot still holds the value from one lap ago: full
+                return Err(item);
+            } else {
+                // Another producer claimed `pos` first
+                pos = self.tail.0.load(Ordering::Relaxed);
+            }
+        }
+    }
+
+    /// # Safety
+    /// Must only be called by one thread at a time (the single consumer)
+    unsafe fn try_pop(&self) -> Option<T> {
+        let pos = self.head.0.load(Ordering::Relaxed);
+        let slot = &self.slots[pos & self.mask];
+
+        // Empty, or the claiming producer hasn't finished writing yet
+        if slot.seq.load(Ordering::Acquire) != pos.wrapping_add(1) {
+            return None;
+        }
+
+        let item = (*slot.value.get()).assume_init_read();
+        // Free the slot for the producer one lap ahead
+        slot.seq
+            .store(pos.wrapping_add(self.mask + 1), Ordering::Release);
+        self.head.0.store(pos.wrapping_add(1), Ordering::Release);
+        self.wait_queue.wake_one(MPSC_NOT_FULL);
+        Some(item)
+    }
+
+    #[inline]
+    fn capacity(&self) -> usize {
+        self.mask + 1
+    }
+
+    #[inline]
+    fn len(&self) -> usize {
+        let head = self.head.0.load(Ordering::Acquire);
+        let tail = self.tail.0.load(Ordering::Acquire);
+        tail.wrapping_sub(head).min(self.capacity())
+    }
+
+    /// Park until woken for `key`, the deadline passes, or a wait slice elapses
+    ///
+    /// Returns false once the deadline has passed.
+    fn park(&self, key: u8, deadline: Instant) -> bool {
+        let now = Instant::now();
+        if now >= deadline {
+            return false;
+        }
+        let _ = self
+            .wait_queue
+            .wait(key, Some((deadline - now).min(MPSC_WAIT_SLICE)));
+        true
+    }
+}
+
+impl<T> Drop for MpscShared<T> {
+    fn drop(&mut self) {
+        // Both handles are gone, so this is the only accessor left
+        while unsafe { self.try_pop() }.is_some() {}
+    }
+}
+
+/// Lock-free MPSC ring buffer for fan-in (many producers, one consumer)
+///
+/// This is the producer handle; clone it for each producer. The single
+/// consumer is the [`MpscConsumer`] returned alongside it by [`channel`].
+///
+/// # Performance
+/// - Bounded, allocation-free after creation (capacity rounds up to a power of two, min 2)
+/// - Producers claim slots with a CAS on a shared tail; the consumer never CASes
+/// - Blocking variants park on a [`WaitQueue`] instead of spinning
+///
+/// # Throughput vs SPSC
+/// Every producer contends on the same tail index, so a push can retry its
+/// CAS under contention and the tail cache line bounces between cores.
+/// Expect per-producer throughput to drop as producers are added; aggregate
+/// throughput stays roughly flat. Keep [`LockFreeRing`] for paths with a
+/// single producer.
+///
+/// [`channel`]: LockFreeMpscRing::channel
+pub struct LockFreeMpscRing<T> {
+    shared: Arc<MpscShared<T>>,
+}
+
+/// Consumer half of a [`LockFreeMpscRing`]
+///
+/// Not `Clone`, and popping takes `&mut self`, so there is exactly one
+/// consumer at a time.
+pub struct MpscConsumer<T> {
+    shared: Arc<MpscShared<T>>,
+}
+
+impl<T> LockFreeMpscRing<T> {
+    /// Create a ring, returning the producer and consumer handles
+    pub fn channel(capacity: usize) -> (Self, MpscConsumer<T>) {
+        let shared = Arc::new(MpscShared::new(capacity));
+        (
+            Self {
+                shared: Arc::clone(&shared),
+            },
+            MpscConsumer { shared },
+        )
+    }
+
+    /// Push an item (lock-free)
+    ///
+    /// Returns `Err(item)` if the buffer is full.
+    #[inline]
+    pub fn push(&self, item: T) -> Result<(), T> {
+        self.shared.try_push(item)
+    }
+
+    /// Push an item, waiting up to `timeout` for space
+    ///
+    /// Returns `Err(item)` if the buffer is still full at the deadline.
+    pub fn push_timeout(&self, mut item: T, timeout: Duration) -> Result<(), T> {
+        let deadline = Instant::now() + timeout;
+        loop {
+            match self.shared.try_push(item) {
+                Ok(()) => return Ok(()),
+                Err(rejected) => item = rejected,
+            }
+            if !self.shared.park(MPSC_NOT_FULL, deadline) {
+                return Err(item);
+            }
+        }
+    }
+
+    /// Check if the buffer is empty (approximate)
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Check if the buffer is full (approximate)
+    #[inline]
+    pub fn is_full(&self) -> bool {
+        self.len() == self.capacity()
+    }
+
+    /// Get the current number of items in the buffer (approximate)
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.shared.len()
+    }
+
+    /// Get the capacity of the buffer
+    #[inline]
+    pub fn capacity(&self) -> usize {
+        self.shared.capacity()
+    }
+
+    /// Get available space in the buffer (approximate)
+    #[inline]
+    pub fn available(&self) -> usize {
+        self.capacity().saturating_sub(self.len())
+    }
+}
+
+impl<T> Clone for LockFreeMpscRing<T> {
+    fn clone(&self) -> Self {
+        Self {
+            shared: Arc::clone(&self.shared),
+        }
+    }
+}
+
+impl<T> MpscConsumer<T> {
+    /// Pop an item (lock-free)
+    ///
+    /// Returns `None` if the buffer is empty.
+    #[inline]
+    pub fn pop(&mut self) -> Option<T> {
+        // SAFETY: `&mut self` on the sole consumer handle
+        unsafe { self.shared.try_pop() }
+    }
+
+    /// Pop an item, waiting up to `timeout` for one to arrive
+    pub fn pop_timeout(&mut self, timeout: Duration) -> Option<T> {
+        let deadline = Instant::now() + timeout;
+        loop {
+            if let Some(item) = self.pop() {
+                return Some(item);
+            }
+            if !self.shared.park(MPSC_NOT_EMPTY, deadline) {
+                return None;
+            }
+        }
+    }
+
+    /// Pop up to `max` items without blocking
+    pub fn pop_batch(&mut self, max: usize) -> Vec<T> {
+        let mut batch = Vec::with_capacity(max.min(self.len()));
+        while batch.len() < max {
+            match self.pop() {
+                Some(item) => batch.push(item),
+                None => break,
+            }
+        }
+        batch
+    }
+
+    /// Check if the buffer is empty (approximate)
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Get the current number of items in the buffer (approximate)
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.shared.len()
+    }
+
+    /// Get the capacity of the buffer
+    #[inline]
+    pub fn capacity(&self) -> usize {
+        self.shared.capacity()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -305,4 +616,95 @@ fn test_byte_ring_partial_write() {
         let read_data = ring.read(5);
         assert_eq!(read_data, b"Hello");
     }
+
+    #[test]
+    fn test_mpsc_basic_push_pop() {
+        let (ring, mut consumer) = LockFreeMpscRing::<u32>::channel(3);
+        assert_eq!(ring.capacity(), 4);
+
+        for i in 0..4 {
+            assert!(ring.push(i).is_ok());
+        }
+        assert!(ring.is_full());
+        assert_eq!(ring.push(99), Err(99));
+
+        assert_eq!(consumer.pop(), Some(0));
+        assert!(ring.push(4).is_ok());
+        assert_eq!(consumer.pop_batch(10), vec![1, 2, 3, 4]);
+        assert_eq!(consumer.pop(), None);
+        assert!(consumer.is_empty());
+    }
+
+    #[test]
+    fn test_mpsc_blocking_pop_and_push() {
+        let (ring, mut consumer) = LockFreeMpscRing::<u32>::channel(1);
+        assert_eq!(ring.capacity(), 2);
+        assert_eq!(consumer.pop_timeout(Duration::from_millis(5)), None);
+
+        ring.push(0).unwrap();
+        ring.push(1).unwrap();
+        assert_eq!(ring.push_timeout(2, Duration::from_millis(5)), Err(2));
+
+        let producer = ring.clone();
+        let handle =
+            std::thread::spawn(move || producer.push_timeout(2, Duration::from_secs(5)).is_ok());
+
+        assert_eq!(consumer.pop_timeout(Duration::from_secs(5)), Some(0));
+        assert!(handle.join().unwrap());
+        assert_eq!(consumer.pop_timeout(Duration::from_secs(5)), Some(1));
+        assert_eq!(consumer.pop_timeout(Duration::from_secs(5)), Some(2));
+    }
+
+    #[test]
+    fn test_mpsc_drops_unconsumed_items() {
+        let marker = Arc::new(());
+        {
+            let (ring, _consumer) = LockFreeMpscRing::channel(8);
+            for _ in 0..5 {
+                ring.push(Arc::clone(&marker)).unwrap();
+            }
+        }
+        assert_eq!(Arc::strong_count(&marker), 1);
+    }
+
+    #[test]
+    fn test_mpsc_stress_no_lost_or_duplicated_items() {
+        const PRODUCERS: usize = 8;
+        const PER_PRODUCER: usize = 20_000;
+
+        // Small ring so producers wrap and contend constantly
+        let (ring, mut consumer) = LockFreeMpscRing::<(usize, usize)>::channel(64);
+
+        let handles: Vec<_> = (0..PRODUCERS)
+            .map(|producer| {
+                let ring = ring.clone();
+                std::thread::spawn(move || {
+                    for seq in 0..PER_PRODUCER {
+                        ring.push_timeout((producer, seq), Duration::from_secs(30))
+                            .expect("consumer stalled");
+                    }
+                })
+            })
+            .collect();
+
+        let mut next_seq = vec![0usize; PRODUCERS];
+        for _ in 0..PRODUCERS * PER_PRODUCER {
+            let (producer, seq) = consumer
+                .pop_timeout(Duration::from_secs(30))
+                .expect("producers stalled");
+            // Per-producer FIFO order implies nothing lost or repeated
+            assert_eq!(
+                seq, next_seq[producer],
+                "producer {} out of order",
+                producer
+            );
+            next_seq[producer] += 1;
+        }
+
+        for handle in handles {
+            handle.join().unwrap();
+        }
+        assert!(next_seq.iter().all(|&n| n == PER_PRODUCER));
+        assert_eq!(consumer.pop(), None);
+    }
 }
@@ -1,23 +1,53 @@
 /*!
  * Memory-Mapped Files (mmap)
  * File-backed shared memory support
+ *
+ * A mapping's buffer is allocated in full at map time, so requests are
+ * checked against the file size, the per-process quota and the memory
+ * manager's free memory before anything is allocated.
  */
 
+use crate::core::limits::{MAX_MMAP_BYTES_PER_PROCESS, MMAP_PAGE_SIZE};
 use crate::core::memory::CowMemory;
 use crate::core::types::Pid;
 use crate::core::{ShardManager, WorkloadProfile};
-use crate::vfs::{FileSystem, MountManager};
+use crate::memory::{MemoryError, MemoryManager};
+use crate::monitoring::Collector;
+use crate::vfs::{FileSystem, MountManager, OpenFlags, OpenMode};
 use ahash::RandomState;
 use dashmap::DashMap;
 use log::{debug, info, warn};
 use serde::{Deserialize, Serialize};
+use std::io::{Read, Seek, SeekFrom};
 use std::path::Path;
-use std::sync::atomic::{AtomicU32, Ordering};
+use std::sync::atomic::{AtomicBool, AtomicU32, Ordering};
 use std::sync::Arc;
+use thiserror::Error;
 
 /// Memory mapping identifier
 pub type MmapId = u32;
 
+/// Why a mapping couldn't be created
+#[derive(Error, Debug)]
+pub enum MmapError {
+    /// The mapping didn't fit the process quota or free memory
+    #[error(transparent)]
+    Memory(#[from] MemoryError),
+
+    /// The request itself was malformed
+    #[error("{0}")]
+    InvalidArgument(String),
+
+    #[error("{0}")]
+    Other(String),
+}
+
+impl From<String> for MmapError {
+    fn from(msg: String) -> Self {
+        Self::Other(msg)
+    }
+}
+
 /// Memory mapping protection flags (similar to POSIX mmap)
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub struct ProtFlags {
@@ -94,6 +124,28 @@ pub struct MmapEntry {
     pub flags: MapFlags,
     pub owner_pid: Pid,
     pub data: Arc<parking_lot::Mutex<CowMemory>>,
+    /// Per-page residency (pages are faulted in from the file on first access)
+    pub resident: Arc<Vec<AtomicBool>>,
+    /// Bytes faulted in eagerly at map time (non-zero only for populated mappings)
+    pub populated_bytes: usize,
+}
+
+impl MmapEntry {
+    /// Number of pages in this mapping
+    #[inline]
+    pub fn page_count(&self) -> usize {
+        self.resident.len()
+    }
+
+    /// Bytes currently resident in the mapping
+    pub fn resident_bytes(&self) -> usize {
+        self.resident
+            .iter()
+            .enumerate()
+            .filter(|(_, page)| page.load(Ordering::Acquire))
+            .map(|(index, _)| page_len(index, self.length))
+            .sum()
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -105,6 +157,12 @@ pub struct MmapInfo {
     pub prot: ProtFlags,
     pub flags: MapFlags,
     pub owner_pid: Pid,
+    /// Bytes pre-faulted at map time
+    #[serde(default)]
+    pub populated_bytes: usize,
+    /// Bytes currently resident (populated or faulted on access)
+    #[serde(default)]
+    pub resident_bytes: usize,
 }
 
 impl From<&MmapEntry> for MmapInfo {
@@ -117,10 +175,19 @@ fn from(entry: &MmapEntry) -> Self {
             prot: entry.prot,
             flags: entry.flags,
             owner_pid: entry.owner_pid,
+            populated_bytes: entry.populated_bytes,
+            resident_bytes: entry.resident_bytes(),
         }
     }
 }
 
+/// Length of page `index` within a mapping of `length` bytes
+#[inline]
+fn page_len(index: usize, length: usize) -> usize {
+    let start = index * MMAP_PAGE_SIZE;
+    (start + MMAP_PAGE_SIZE).min(length) - start
+}
+
 /// Memory-mapped file manager
 ///
 /// # Performance
@@ -130,6 +197,13 @@ pub struct MmapManager {
     mappings: Arc<DashMap<MmapId, MmapEntry, RandomState>>,
     next_id: Arc<AtomicU32>, // Wrapped in Arc to ensure ID uniqueness across clones
     vfs: Option<Arc<MountManager>>,
+    /// Source of free memory for the up-front size check
+    memory_manager: Option<MemoryManager>,
+    /// Bytes one process may have mapped at once
+    process_quota: usize,
+    /// Bytes mapped per process, reserved before a mapping is built
+    mapped: Arc<DashMap<Pid, usize, RandomState>>,
+    collector: Option<Arc<Collector>>,
 }
 
 impl MmapManager {
@@ -148,6 +222,10 @@ pub fn new() -> Self {
             ),
             next_id: Arc::new(AtomicU32::new(1)),
             vfs: None,
+            memory_manager: None,
+            process_quota: MAX_MMAP_BYTES_PER_PROCESS,
+            mapped: Arc::new(DashMap::with_hasher(RandomState::new())),
+            collector: None,
         }
     }
 
@@ -166,10 +244,101 @@ pub fn with_vfs(vfs: Arc<MountManager>) -> Self {
             ),
             next_id: Arc::new(AtomicU32::new(1)),
             vfs: Some(vfs),
+            memory_manager: None,
+            process_quota: MAX_MMAP_BYTES_PER_PROCESS,
+            mapped: Arc::new(DashMap::with_hasher(RandomState::new())),
+            collector: None,
+        }
+    }
+
+    /// Reject mappings larger than the memory manager's free memory
+    pub fn with_memory_manager(mut self, memory_manager: MemoryManager) -> Self {
+        self.memory_manager = Some(memory_manager);
+        self
+    }
+
+    /// Cap the bytes one process may have mapped at once
+    pub fn with_process_quota(mut self, bytes: usize) -> Self {
+        self.process_quota = bytes;
+        self
+    }
+
+    /// Add observability collector
+    pub fn with_collector(mut self, collector: Arc<Collector>) -> Self {
+        self.collector = Some(collector);
+        self
+    }
+
+    /// Bytes currently mapped by a process
+    pub fn mapped_bytes(&self, pid: Pid) -> usize {
+        self.mapped.get(&pid).map_or(0, |bytes| *bytes)
+    }
+
+    /// Reserve `length` bytes of a process's quota before building a mapping
+    ///
+    /// The quota check and the charge happen under the process's entry lock,
+    /// so concurrent mappings can't both fit in the same remaining quota.
+    /// Undo with [`Self::release_capacity`] if the mapping isn't kept.
+    fn reserve_capacity(&self, pid: Pid, length: usize) -> Result<(), MemoryError> {
+        if let Some(ref memory_manager) = self.memory_manager {
+            let (total, used, available) = memory_manager.info();
+            if length > available {
+                self.emit_exhausted(pid, "memory", total);
+                return Err(MemoryError::OutOfMemory {
+                    requested: length,
+                    available,
+                    used,
+                    total,
+                });
+            }
+        }
+
+        let mut mapped = self.mapped.entry(pid).or_insert(0);
+        if mapped.saturating_add(length) > self.process_quota {
+            let current = *mapped;
+            drop(mapped);
+            self.mapped.remove_if(&pid, |_, bytes| *bytes == 0);
+            self.emit_exhausted(pid, "mmap_process_quota", self.process_quota);
+            return Err(MemoryError::ProcessLimitExceeded {
+                requested: length,
+                limit: self.process_quota,
+                current,
+            });
+        }
+        *mapped += length;
+        Ok(())
+    }
+
+    /// Return `length` bytes to a process's quota
+    fn release_capacity(&self, pid: Pid, length: usize) {
+        if let Some(mut mapped) = self.mapped.get_mut(&pid) {
+            *mapped = mapped.saturating_sub(length);
+        }
+        self.mapped.remove_if(&pid, |_, bytes| *bytes == 0);
+    }
+
+    fn emit_exhausted(&self, pid: Pid, resource: &str, limit: usize) {
+        if let Some(ref collector) = self.collector {
+            use crate::monitoring::{Category, Event, Payload, Severity};
+            collector.emit(
+                Event::new(
+                    Severity::Warn,
+                    Category::Resource,
+                    Payload::ResourceExhausted {
+                        resource: resource.into(),
+                        limit: limit as u64,
+                    },
+                )
+                .with_pid(pid),
+            );
         }
     }
 
     /// Create a memory mapping from a file
+    ///
+    /// Pages are faulted in from the file on first access. With `populate`
+    /// (MAP_POPULATE), every page is read eagerly so later accesses never stall.
+    #[allow(clippy::too_many_arguments)]
     pub fn mmap(
         &self,
         pid: Pid,
@@ -178,51 +347,128 @@ pub fn mmap(
         length: usize,
         prot: ProtFlags,
         flags: MapFlags,
-    ) -> Result<MmapId, String> {
-        // Read file data from VFS
-        let file_data = if let Some(ref vfs) = self.vfs {
-            vfs.read(Path::new(&path))
-                .map_err(|e| format!("Failed to read file: {}", e))?
-        } else {
-            return Err("VFS not available".to_string());
-        };
+        populate: bool,
+    ) -> Result<MmapId, MmapError> {
+        if length == 0 {
+            return Err(MmapError::InvalidArgument(
+                "Mapping length must be non-zero".to_string(),
+            ));
+        }
+
+        let vfs = self
+            .vfs
+            .as_ref()
+            .ok_or_else(|| "VFS not available".to_string())?;
+
+        let file_size = vfs
+            .metadata(Path::new(&path))
+            .map_err(|e| format!("Failed to stat file: {}", e))?
+            .size as usize;
 
         // Validate offset and length
-        if offset >= file_data.len() {
+        if offset >= file_size {
+            return Err(format!("Offset {} exceeds file size {}", offset, file_size).into());
+        }
+        if !matches!(offset.checked_add(length), Some(end) if end <= file_size) {
             return Err(format!(
-                "Offset {} exceeds file size {}",
-                offset,
-                file_data.len()
-            ));
+                "Mapping of {} bytes at offset {} extends past end of file ({} bytes)",
+                length, offset, file_size
+            )
+            .into());
         }
 
-        let end = offset.saturating_add(length).min(file_data.len());
-        let actual_length = end - offset;
+        self.reserve_capacity(pid, length)?;
+        let page_count = length.div_ceil(MMAP_PAGE_SIZE);
 
-        let mapped_data = file_data[offset..end].to_vec();
         let id = self.next_id.fetch_add(1, Ordering::SeqCst);
 
-        let entry = MmapEntry {
+        let mut entry = MmapEntry {
             id,
             path: path.clone(),
             offset,
-            length: actual_length,
+            length,
             prot,
             flags,
             owner_pid: pid,
-            data: Arc::new(parking_lot::Mutex::new(CowMemory::new(mapped_data).into())),
+            data: Arc::new(parking_lot::Mutex::new(CowMemory::new(vec![0u8; length]))),
+            resident: Arc::new((0..page_count).map(|_| AtomicBool::new(false)).collect()),
+            populated_bytes: 0,
         };
 
+        if populate {
+            if let Err(e) = self.fault_in(&entry, 0, length) {
+                self.release_capacity(pid, length);
+                return Err(e.into());
+            }
+            entry.populated_bytes = length;
+        }
+
         self.mappings.insert(id, entry);
 
         info!(
-            "PID {} created mmap {} for file '{}' (offset: {}, length: {})",
-            pid, id, path, offset, actual_length
+            "PID {} created mmap {} for file '{}' (offset: {}, length: {}, populate: {})",
+            pid, id, path, offset, length, populate
         );
 
         Ok(id)
     }
 
+    /// Fault in any non-resident pages covering `[start, end)` of a mapping
+    fn fault_in(&self, entry: &MmapEntry, start: usize, end: usize) -> Result<(), String> {
+        if start >= end {
+            return Ok(());
+        }
+
+        let first = start / MMAP_PAGE_SIZE;
+        let last = (end - 1) / MMAP_PAGE_SIZE;
+        if entry.resident[first..=last]
+            .iter()
+            .all(|page| page.load(Ordering::Acquire))
+        {
+            return Ok(());
+        }
+
+        let vfs = self
+            .vfs
+            .as_ref()
+            .ok_or_else(|| "VFS not available".to_string())?;
+        let mut file = vfs
+            .open(
+                Path::new(&entry.path),
+                OpenFlags::read_only(),
+                OpenMode::default(),
+            )
+            .map_err(|e| format!("Failed to open file for page fault: {}", e))?;
+
+        // Hold the data lock while faulting so concurrent faults don't double-read
+        let mut cow_guard = entry.data.lock();
+        let mut faulted = 0usize;
+
+        for index in first..=last {
+            if entry.resident[index].load(Ordering::Acquire) {
+                continue;
+            }
+
+            let page_start = index * MMAP_PAGE_SIZE;
+            let len = page_len(index, entry.length);
+            let mut page = vec![0u8; len];
+
+            file.seek(SeekFrom::Start((entry.offset + page_start) as u64))
+                .and_then(|_| file.read_exact(&mut page))
+                .map_err(|e| format!("Page fault at offset {} failed: {}", page_start, e))?;
+
+            cow_guard.write(|buf| buf[page_start..page_start + len].copy_from_slice(&page));
+            entry.resident[index].store(true, Ordering::Release);
+            faulted += 1;
+        }
+
+        debug!(
+            "Mmap {} faulted in {} pages ({}..{})",
+            entry.id, faulted, start, end
+        );
+        Ok(())
+    }
+
     pub fn read(
         &self,
         pid: Pid,
@@ -239,8 +485,7 @@ pub fn read(
             return Err("No read permission on this mapping".to_string());
         }
 
-        let cow_guard = entry.data.lock();
-        let data_len = cow_guard.len();
+        let data_len = entry.length;
 
         if offset >= data_len {
             return Err(format!(
@@ -250,6 +495,9 @@ pub fn read(
         }
 
         let end = offset.saturating_add(length).min(data_len);
+        self.fault_in(&entry, offset, end)?;
+
+        let cow_guard = entry.data.lock();
         let data = cow_guard.read(|buf| buf[offset..end].to_vec());
 
         debug!(
@@ -277,12 +525,14 @@ pub fn write(
             return Err("No write permission on this mapping".to_string());
         }
 
-        let mut cow_guard = entry.data.lock();
-
-        if offset.saturating_add(data.len()) > cow_guard.len() {
+        if offset.saturating_add(data.len()) > entry.length {
             return Err("Write exceeds mapping bounds".to_string());
         }
 
+        // Partially overwritten pages must hold file contents around the write
+        self.fault_in(&entry, offset, offset + data.len())?;
+
+        let mut cow_guard = entry.data.lock();
         cow_guard.write(|buf| {
             buf[offset..offset + data.len()].copy_from_slice(data);
         });
@@ -316,6 +566,9 @@ pub fn msync(&self, pid: Pid, mmap_id: MmapId) -> Result<(), String> {
         }
 
         if let Some(ref vfs) = self.vfs {
+            // Non-resident pages are zero-filled; fault them in so they aren't written back as zeros
+            self.fault_in(&entry, 0, entry.length)?;
+
             let mut file_data = vfs
                 .read(Path::new(&entry.path))
                 .map_err(|e| format!("Failed to read file for sync: {}", e))?;
@@ -356,9 +609,11 @@ pub fn munmap(&self, pid: Pid, mmap_id: MmapId) -> Result<(), String> {
             }
         }
 
-        self.mappings
+        let (_, entry) = self
+            .mappings
             .remove(&mmap_id)
             .ok_or_else(|| format!("Mmap {} not found", mmap_id))?;
+        self.release_capacity(entry.owner_pid, entry.length);
 
         info!("PID {} unmapped mmap {}", pid, mmap_id);
         Ok(())
@@ -440,6 +695,10 @@ fn clone(&self) -> Self {
             mappings: Arc::clone(&self.mappings),
             next_id: Arc::clone(&self.next_id), // Share ID counter to prevent collision
             vfs: self.vfs.clone(),
+            memory_manager: self.memory_manager.clone(),
+            process_quota: self.process_quota,
+            mapped: Arc::clone(&self.mapped),
+            collector: self.collector.clone(),
         }
     }
 }
@@ -464,10 +723,204 @@ fn test_mmap_without_vfs() {
             1024,
             ProtFlags::read_write(),
             MapFlags::Shared,
+            false,
         );
         assert!(result.is_err());
     }
 
+    fn manager_with_file(len: usize) -> MmapManager {
+        use crate::vfs::MemFS;
+
+        let vfs = MountManager::new();
+        vfs.mount("/", Arc::new(MemFS::new())).unwrap();
+        let contents: Vec<u8> = (0..len).map(|i| (i % 251) as u8).collect();
+        vfs.write(Path::new("/data.bin"), &contents).unwrap();
+        MmapManager::with_vfs(Arc::new(vfs))
+    }
+
+    #[test]
+    fn test_lazy_faulting() {
+        let manager = manager_with_file(3 * MMAP_PAGE_SIZE);
+        let id = manager
+            .mmap(
+                1,
+                "/data.bin".into(),
+                0,
+                3 * MMAP_PAGE_SIZE,
+                ProtFlags::PROT_READ,
+                MapFlags::Private,
+                false,
+            )
+            .unwrap();
+
+        let info = manager.get_info(id).unwrap();
+        assert_eq!(info.populated_bytes, 0);
+        assert_eq!(info.resident_bytes, 0);
+
+        let data = manager.read(1, id, MMAP_PAGE_SIZE + 10, 4).unwrap();
+        assert_eq!(data[0], ((MMAP_PAGE_SIZE + 10) % 251) as u8);
+        assert_eq!(manager.get_info(id).unwrap().resident_bytes, MMAP_PAGE_SIZE);
+    }
+
+    #[test]
+    fn test_populate_prefaults_all_pages() {
+        let len = 2 * MMAP_PAGE_SIZE + 100;
+        let manager = manager_with_file(len);
+        let id = manager
+            .mmap(
+                1,
+                "/data.bin".into(),
+                0,
+                len,
+                ProtFlags::PROT_READ,
+                MapFlags::Private,
+                true,
+            )
+            .unwrap();
+
+        let info = manager.get_info(id).unwrap();
+        assert_eq!(info.populated_bytes, len);
+        assert_eq!(info.resident_bytes, len);
+        assert_eq!(
+            manager.read(1, id, len - 1, 1).unwrap()[0],
+            ((len - 1) % 251) as u8
+        );
+    }
+
+    #[test]
+    fn test_mmap_past_eof_rejected() {
+        let manager = manager_with_file(MMAP_PAGE_SIZE);
+        let err = manager
+            .mmap(
+                1,
+                "/data.bin".into(),
+                100,
+                MMAP_PAGE_SIZE,
+                ProtFlags::PROT_READ,
+                MapFlags::Private,
+                false,
+            )
+            .unwrap_err();
+        assert!(err.to_string().contains("past end of file"), "{}", err);
+
+        // Offset plus length overflowing is past EOF too, not a panic
+        assert!(manager
+            .mmap(
+                1,
+                "/data.bin".into(),
+                1,
+                usize::MAX,
+                ProtFlags::PROT_READ,
+                MapFlags::Private,
+                false,
+            )
+            .is_err());
+        assert!(!manager.has_process_mappings(1));
+    }
+
+    #[test]
+    fn test_zero_length_mmap_rejected() {
+        let manager = manager_with_file(MMAP_PAGE_SIZE);
+        let err = manager
+            .mmap(
+                1,
+                "/data.bin".into(),
+                0,
+                0,
+                ProtFlags::PROT_READ,
+                MapFlags::Private,
+                false,
+            )
+            .unwrap_err();
+        assert!(matches!(err, MmapError::InvalidArgument(_)), "{}", err);
+        assert!(!manager.has_process_mappings(1));
+    }
+
+    #[test]
+    fn test_mmap_over_quota_rejected() {
+        let manager = manager_with_file(4 * MMAP_PAGE_SIZE)
+            .with_process_quota(6 * MMAP_PAGE_SIZE)
+            .with_memory_manager(MemoryManager::with_capacity(16 * MMAP_PAGE_SIZE));
+        let map = |pid, length| {
+            manager.mmap(
+                pid,
+                "/data.bin".into(),
+                0,
+                length,
+                ProtFlags::PROT_READ,
+                MapFlags::Private,
+                false,
+            )
+        };
+
+        map(1, 4 * MMAP_PAGE_SIZE).unwrap();
+        let err = map(1, 4 * MMAP_PAGE_SIZE).unwrap_err();
+        assert!(
+            matches!(
+                err,
+                MmapError::Memory(MemoryError::ProcessLimitExceeded { .. })
+            ),
+            "{}",
+            err
+        );
+        assert_eq!(manager.mapped_bytes(1), 4 * MMAP_PAGE_SIZE);
+
+        // The quota is per process
+        map(2, 4 * MMAP_PAGE_SIZE).unwrap();
+
+        let tight = manager_with_file(4 * MMAP_PAGE_SIZE)
+            .with_memory_manager(MemoryManager::with_capacity(2 * MMAP_PAGE_SIZE));
+        let err = tight
+            .mmap(
+                1,
+                "/data.bin".into(),
+                0,
+                4 * MMAP_PAGE_SIZE,
+                ProtFlags::PROT_READ,
+                MapFlags::Private,
+                false,
+            )
+            .unwrap_err();
+        assert!(
+            matches!(err, MmapError::Memory(MemoryError::OutOfMemory { .. })),
+            "{}",
+            err
+        );
+    }
+
+    #[test]
+    fn test_concurrent_mmaps_respect_quota() {
+        let manager = manager_with_file(MMAP_PAGE_SIZE).with_process_quota(4 * MMAP_PAGE_SIZE);
+
+        let mapped: usize = std::thread::scope(|scope| {
+            let handles: Vec<_> = (0..16)
+                .map(|_| {
+                    scope.spawn(|| {
+                        manager
+                            .mmap(
+                                1,
+                                "/data.bin".into(),
+                                0,
+                                MMAP_PAGE_SIZE,
+                                ProtFlags::PROT_READ,
+                                MapFlags::Private,
+                                false,
+                            )
+                            .is_ok() as usize
+                    })
+                })
+                .collect();
+            handles.into_iter().map(|h| h.join().unwrap()).sum()
+        });
+
+        assert_eq!(mapped, 4);
+        assert_eq!(manager.mapped_bytes(1), 4 * MMAP_PAGE_SIZE);
+
+        // Unmapping returns the quota
+        assert_eq!(manager.cleanup_process(1), 4);
+        assert_eq!(manager.mapped_bytes(1), 0);
+    }
+
     #[test]
     fn test_prot_flags() {
         let rw = ProtFlags::read_write();
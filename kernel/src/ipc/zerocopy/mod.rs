@@ -20,6 +20,7 @@
 use ahash::RandomState;
 use dashmap::DashMap;
 use std::sync::Arc;
+use std::time::Duration;
 use thiserror::Error;
 use tracing::{debug, info};
 
@@ -122,6 +123,34 @@ pub fn submit_operation(
         Ok(seq)
     }
 
+    /// Submit an IPC operation, waiting up to `timeout` for SQ space
+    ///
+    /// Paces the producer to completions instead of failing with
+    /// `SubmissionQueueFull`; returns `Timeout` if no slot frees in time.
+    pub fn submit_operation_blocking(
+        &self,
+        pid: Pid,
+        target_pid: Pid,
+        buffer_addr: Address,
+        size: Size,
+        timeout: Duration,
+    ) -> Result<u64, ZeroCopyError> {
+        let ring = self.get_ring(pid).ok_or(ZeroCopyError::RingNotFound(pid))?;
+
+        let entry = SubmissionEntry::new_transfer(target_pid, buffer_addr, size);
+        let seq = ring.submit_blocking(entry, timeout)?;
+
+        debug!(
+            pid = pid,
+            target_pid = target_pid,
+            seq = seq,
+            size = size,
+            "Zero-copy operation submitted after waiting for space"
+        );
+
+        Ok(seq)
+    }
+
     /// Complete an IPC operation and get result
     pub fn complete_operation(&self, pid: Pid, seq: u64) -> Result<CompletionEntry, ZeroCopyError> {
         let ring = self.get_ring(pid).ok_or(ZeroCopyError::RingNotFound(pid))?;
@@ -6,12 +6,16 @@
 use super::completion::{CompletionEntry, CompletionQueue, CompletionStatus};
 use super::submission::{SubmissionEntry, SubmissionQueue};
 use super::ZeroCopyError;
+use crate::core::limits::ZEROCOPY_SUBMIT_RECHECK_INTERVAL;
 use crate::core::sync::lockfree::SeqlockStats;
 use crate::core::sync::WaitQueue;
 use crate::core::types::{Address, Pid, Size};
 use parking_lot::RwLock;
 use std::sync::Arc;
-use std::time::Duration;
+use std::time::{Duration, Instant};
+
+/// Wait queue key for submitters blocked on a full SQ (completions use their seq)
+const SQ_SPACE_KEY: u64 = u64::MAX;
 
 /// Zero-copy ring with submission and completion queues
 pub struct ZeroCopyRing {
@@ -54,8 +58,54 @@ pub fn submit(&self, entry: SubmissionEntry) -> Result<u64, ZeroCopyError> {
         Ok(seq)
     }
 
+    /// Submit an entry, waiting up to `timeout` for SQ space
+    ///
+    /// Lets producers pace themselves to the consumer instead of spinning
+    /// on `SubmissionQueueFull`. Returns `Timeout` if the queue is still full
+    /// at the deadline.
+    pub fn submit_blocking(
+        &self,
+        entry: SubmissionEntry,
+        timeout: Duration,
+    ) -> Result<u64, ZeroCopyError> {
+        let deadline = Instant::now() + timeout;
+        loop {
+            match self.submit(entry.clone()) {
+                Err(ZeroCopyError::SubmissionQueueFull) => {}
+                result => return result,
+            }
+
+            let left = deadline.saturating_duration_since(Instant::now());
+            if left.is_zero() {
+                return Err(ZeroCopyError::Timeout);
+            }
+            // Woken when a completion or reap frees a slot; timing out a
+            // slice just re-checks
+            let _ = self.wait_queue.wait(
+                SQ_SPACE_KEY,
+                Some(left.min(ZEROCOPY_SUBMIT_RECHECK_INTERVAL)),
+            );
+        }
+    }
+
+    /// Take the oldest pending submission (consumer side)
+    ///
+    /// Wakes submitters blocked in `submit_blocking` once space frees up.
+    pub fn reap_submission(&self) -> Option<SubmissionEntry> {
+        let entry = self.submission_queue.write().pop();
+        if entry.is_some() {
+            self.wait_queue.wake_all(SQ_SPACE_KEY);
+        }
+        entry
+    }
+
     /// Complete an operation and add to completion queue
+    ///
+    /// Retires the submission if it is still queued, freeing its SQ slot for
+    /// submitters blocked in `submit_blocking`.
     pub fn complete(&self, seq: u64, status: CompletionStatus, result: usize) {
+        let freed = self.submission_queue.write().remove(seq).is_some();
+
         let mut cq = self.completion_queue.write();
         let entry = CompletionEntry::new(seq, status, result);
         let _ = cq.push(entry);
@@ -63,6 +113,9 @@ pub fn complete(&self, seq: u64, status: CompletionStatus, result: usize) {
         drop(cq);
 
         self.wait_queue.wake_one(seq);
+        if freed {
+            self.wait_queue.wake_all(SQ_SPACE_KEY);
+        }
     }
 
     /// Wait for a completion (blocking)
@@ -151,3 +204,62 @@ pub struct RingStatistics {
     pub submissions: u64,
     pub completions: u64,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::thread;
+
+    #[test]
+    fn test_blocked_submit_unblocks_when_consumer_reaps() {
+        let ring = Arc::new(ZeroCopyRing::new(1, 0x1000, 2, 2));
+        let entry = || SubmissionEntry::new_transfer(2, 0x2000, 64);
+        ring.submit(entry()).unwrap();
+        ring.submit(entry()).unwrap();
+
+        assert!(matches!(
+            ring.submit(entry()),
+            Err(ZeroCopyError::SubmissionQueueFull)
+        ));
+        assert!(matches!(
+            ring.submit_blocking(entry(), Duration::from_millis(20)),
+            Err(ZeroCopyError::Timeout)
+        ));
+
+        let producer = {
+            let ring = Arc::clone(&ring);
+            thread::spawn(move || ring.submit_blocking(entry(), Duration::from_secs(10)))
+        };
+        thread::sleep(Duration::from_millis(50));
+        assert!(!producer.is_finished());
+
+        assert_eq!(ring.reap_submission().unwrap().seq, 0);
+        assert_eq!(producer.join().unwrap().unwrap(), 2);
+        assert_eq!(ring.stats().submissions, 3);
+    }
+
+    #[test]
+    fn test_completion_frees_submission_slot() {
+        let ring = Arc::new(ZeroCopyRing::new(1, 0x1000, 2, 4));
+        let entry = || SubmissionEntry::new_transfer(2, 0x2000, 64);
+        ring.submit(entry()).unwrap();
+        let second = ring.submit(entry()).unwrap();
+
+        let producer = {
+            let ring = Arc::clone(&ring);
+            thread::spawn(move || ring.submit_blocking(entry(), Duration::from_secs(10)))
+        };
+        thread::sleep(Duration::from_millis(50));
+        assert!(!producer.is_finished());
+
+        // Completing out of order still frees a slot
+        ring.complete(second, CompletionStatus::Success, 64);
+        assert_eq!(producer.join().unwrap().unwrap(), 2);
+        assert_eq!(ring.wait_completion(second).unwrap().seq, second);
+
+        // The oldest submission is still pending
+        assert_eq!(ring.reap_submission().unwrap().seq, 0);
+        assert_eq!(ring.reap_submission().unwrap().seq, 2);
+        assert!(ring.reap_submission().is_none());
+    }
+}
@@ -48,6 +48,14 @@ pub fn pop(&mut self) -> Option<SubmissionEntry> {
         self.entries.pop_front()
     }
 
+    /// Remove the pending entry with sequence number `seq`
+    ///
+    /// Operations can complete out of order, so this isn't always the front.
+    pub fn remove(&mut self, seq: u64) -> Option<SubmissionEntry> {
+        let index = self.entries.iter().position(|entry| entry.seq == seq)?;
+        self.entries.remove(index)
+    }
+
     /// Check if queue is empty
     pub fn is_empty(&self) -> bool {
         self.entries.is_empty()
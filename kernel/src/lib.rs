@@ -36,7 +36,9 @@
 };
 
 // Memory
-pub use memory::{MemoryBlock, MemoryError, MemoryManager, MemoryStats, ProcessMemoryStats};
+pub use memory::{
+    MemoryBlock, MemoryError, MemoryManager, MemoryPressure, MemoryStats, ProcessMemoryStats,
+};
 
 // Monitoring - Dual-layer observability system
 pub use monitoring::{
@@ -66,6 +68,9 @@
     Severity,
 };
 
+#[cfg(feature = "otlp")]
+pub use monitoring::{init_tracing_otlp, shutdown_tracing_otlp};
+
 // Process
 pub use process::{
     ExecutionConfig, ProcessExecutorImpl as ProcessExecutor, ProcessInfo as Process,
@@ -78,17 +83,18 @@
 
 // Scheduler
 pub use scheduler::{
-    apply_priority_op, validate_priority, Policy as SchedulerPolicy, PriorityControl, PriorityOp,
+    apply_priority_op, nice_to_priority, priority_to_nice, validate_priority,
+    validate_priority_with_ceiling, Policy as SchedulerPolicy, PriorityControl, PriorityOp,
     SchedulerControl, SchedulerStats as SchedulerStatsTrait, TimeQuantum, DEFAULT_PRIORITY,
-    MAX_PRIORITY, MIN_PRIORITY,
+    MAX_NICE, MAX_PRIORITY, MIN_NICE, MIN_PRIORITY,
 };
 
 // Permissions
 pub use permissions::{
-    Action, AuditEvent, AuditLogger, AuditSeverity, EvaluationContext, PermissionCache,
-    PermissionChecker, PermissionManager, PermissionProvider, PermissionRequest,
-    PermissionResponse, PolicyDecision as PermissionPolicyDecision, PolicyEngine, RequestContext,
-    Resource, ResourceType,
+    Action, AuditEvent, AuditFilter, AuditLogger, AuditSeverity, EvaluationContext,
+    PermissionCache, PermissionChecker, PermissionManager, PermissionMode, PermissionProvider,
+    PermissionRequest, PermissionResponse, PolicyDecision as PermissionPolicyDecision,
+    PolicyEngine, RequestContext, Resource, ResourceType,
 };
 
 // Security
@@ -114,6 +120,6 @@
 
 // VFS
 pub use vfs::{
-    Entry, FileSystem, FileType, LocalFS, MemFS, Metadata, MountManager, MountPoint, OpenFile,
-    OpenFlags, OpenMode, Permissions, VfsError, VfsResult,
+    Entry, FileSystem, FileType, LocalFS, MemFS, Metadata, MountFlags, MountManager, MountPoint,
+    OpenFile, OpenFlags, OpenMode, Permissions, VfsError, VfsResult,
 };
@@ -13,17 +13,40 @@
 use tokio::sync::broadcast;
 use tracing::info;
 
+use ai_os_kernel::core::limits::ESCALATION_POLL_INTERVAL;
+use ai_os_kernel::permissions::{DenialEscalationPolicy, EscalationAction};
 use ai_os_kernel::process::resources::{
-    FdResource, IpcResource, MappingResource, MemoryResource, ResourceOrchestrator, RingResource,
-    SignalResource, SocketResource, TaskResource,
+    FdResource, FileLockResource, IpcResource, MappingResource, MemoryResource,
+    ResourceOrchestrator, RingResource, SecurityResource, SignalResource, SocketResource,
+    TaskResource,
 };
 use ai_os_kernel::{
-    init_simd, init_tracing, AsyncTaskManager, IPCManager, IoUringExecutor, IoUringManager,
-    MemoryManager, MmapManager, ProcessManager, SandboxManager,
+    init_simd, init_tracing, AsyncTaskManager, Collector, IPCManager, IoUringExecutor,
+    IoUringManager, MemoryManager, MemoryPressure, MmapManager, ProcessManager, SandboxManager,
     SchedulingPolicy as Policy, SignalManagerImpl, SyscallExecutorWithIpc, ZeroCopyIpc,
 };
 use std::sync::Arc;
 
+/// Denial escalation from KERNEL_DENIAL_ESCALATION ("alert", "throttle" or "kill")
+fn denial_escalation_policy() -> Option<DenialEscalationPolicy> {
+    let action = match std::env::var("KERNEL_DENIAL_ESCALATION").ok()?.as_str() {
+        "alert" => EscalationAction::Alert,
+        "throttle" => EscalationAction::Throttle,
+        "kill" => EscalationAction::Kill,
+        other => {
+            tracing::warn!(
+                value = other,
+                "Unknown KERNEL_DENIAL_ESCALATION, escalation disabled"
+            );
+            return None;
+        }
+    };
+    Some(DenialEscalationPolicy {
+        action,
+        ..Default::default()
+    })
+}
+
 /// Wait for shutdown signals (SIGTERM/SIGINT)
 async fn wait_for_shutdown_signal() {
     let ctrl_c = async {
@@ -60,10 +83,29 @@ async fn wait_for_shutdown_signal() {
     }
 }
 
+/// Initialize tracing, exporting spans over OTLP when KERNEL_OTLP_ENDPOINT is set
+#[cfg(feature = "otlp")]
+fn init_kernel_tracing() {
+    match std::env::var("KERNEL_OTLP_ENDPOINT") {
+        Ok(endpoint) => {
+            if let Err(e) = ai_os_kernel::init_tracing_otlp(&endpoint) {
+                eprintln!("OTLP tracing unavailable ({}), using local output only", e);
+                init_tracing();
+            }
+        }
+        Err(_) => init_tracing(),
+    }
+}
+
+#[cfg(not(feature = "otlp"))]
+fn init_kernel_tracing() {
+    init_tracing();
+}
+
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn Error>> {
     // Initialize structured tracing
-    init_tracing();
+    init_kernel_tracing();
 
     info!("AgentOS Kernel starting...");
     info!("================================================");
@@ -110,6 +152,7 @@ async fn main() -> Result<(), Box<dyn Error>> {
         std::env::var("KERNEL_STORAGE_PATH").unwrap_or_else(|_| "/tmp/ai-os-storage".to_string());
 
     let vfs = ai_os_kernel::vfs::init_vfs(&storage_path)?;
+    let file_locks = vfs.lock_table().clone();
 
     // Sync native apps from dist directory
     if let Ok(workspace) = std::env::var("WORKSPACE_ROOT") {
@@ -121,8 +164,16 @@ async fn main() -> Result<(), Box<dyn Error>> {
         info!("WORKSPACE_ROOT not set, skipping native apps sync");
     }
 
+    // Kernel event collector; buffered events are flushed at shutdown
+    ai_os_kernel::init_collector(Collector::new());
+    let event_collector = ai_os_kernel::global_collector()
+        .cloned()
+        .ok_or("event collector unavailable")?;
+
     info!("Initializing mmap manager with VFS support...");
-    let mmap_manager = MmapManager::with_vfs(Arc::new(vfs.clone().into()));
+    let mmap_manager = MmapManager::with_vfs(Arc::new(vfs.clone().into()))
+        .with_memory_manager(memory_manager.clone())
+        .with_collector(event_collector);
 
     info!("Initializing syscall executor with IPC, VFS, and mmap support...");
     let syscall_executor = SyscallExecutorWithIpc::with_ipc_direct(
@@ -136,6 +187,28 @@ async fn main() -> Result<(), Box<dyn Error>> {
     .with_metrics(metrics_collector.clone())
     .build(); // Finalize with handler registry
 
+    syscall_executor
+        .permission_manager()
+        .audit()
+        .set_escalation_policy(denial_escalation_policy());
+
+    // Shed caches when memory runs high
+    {
+        let permissions = syscall_executor.permission_manager().clone();
+        let clipboard = syscall_executor.clipboard_manager().clone();
+        memory_manager.on_pressure(Box::new(move |level| {
+            if matches!(level, MemoryPressure::High | MemoryPressure::Critical) {
+                permissions.clear_cache();
+                let freed = clipboard.trim_history();
+                tracing::warn!(
+                    %level,
+                    freed,
+                    "Memory pressure: shed permission cache and clipboard history"
+                );
+            }
+        }));
+    }
+
     // Initialize managers needed for comprehensive resource cleanup
     info!("Initializing resource managers for comprehensive cleanup...");
     let signal_manager = SignalManagerImpl::new();
@@ -148,7 +221,8 @@ async fn main() -> Result<(), Box<dyn Error>> {
     // Resources are registered in dependency order (LIFO cleanup - first registered = last cleaned)
     info!("Building unified resource cleanup orchestrator...");
     let resource_orchestrator = ResourceOrchestrator::new()
-        .register(MemoryResource::new(memory_manager.clone().into()))       // Freed last
+        .register(SecurityResource::new(syscall_executor.clone()))   // Freed last, before pid release
+        .register(MemoryResource::new(memory_manager.clone().into()))
         .register(MappingResource::new(mmap_manager))                // Depends on memory
         .register(IpcResource::new(ipc_manager.clone().into()))             // Message queues, pipes, shm
         .register(TaskResource::new(async_task_manager))             // Async tasks
@@ -161,10 +235,12 @@ async fn main() -> Result<(), Box<dyn Error>> {
         .register(SocketResource::new(
             syscall_executor.socket_manager().clone(),
         ))                                                            // Network sockets
+        .register(FileLockResource::new(file_locks))                  // Advisory file locks
         .register(FdResource::new(syscall_executor.fd_manager().clone().into())); // Freed first
 
     // Validate comprehensive coverage
     resource_orchestrator.validate_coverage(&[
+        "security",
         "memory",
         "ipc",
         "mappings",
@@ -172,6 +248,7 @@ async fn main() -> Result<(), Box<dyn Error>> {
         "rings",
         "signals",
         "sockets",
+        "file_locks",
         "file_descriptors",
     ]);
 
@@ -181,6 +258,9 @@ async fn main() -> Result<(), Box<dyn Error>> {
         resource_orchestrator.registered_types()
     );
 
+    // Clones share the cleanup report written at shutdown
+    let shutdown_orchestrator = resource_orchestrator.clone();
+
     // Build process manager with comprehensive cleanup
     info!("Initializing process manager with memory, IPC, scheduler, and comprehensive cleanup...");
     let process_manager = ProcessManager::builder()
@@ -213,6 +293,7 @@ async fn main() -> Result<(), Box<dyn Error>> {
     let (shutdown_tx, _) = broadcast::channel::<()>(1);
     let mut grpc_shutdown_rx = shutdown_tx.subscribe();
     let mut monitor_shutdown_rx = shutdown_tx.subscribe();
+    let mut escalation_shutdown_rx = shutdown_tx.subscribe();
 
     // Spawn gRPC server with graceful shutdown support
     let grpc_handle = tokio::spawn(async move {
@@ -271,6 +352,39 @@ async fn main() -> Result<(), Box<dyn Error>> {
         }
     });
 
+    // Carry out throttle and kill requests queued by the permission audit log
+    let escalation_permissions = syscall_executor.permission_manager().clone();
+    let escalation_process_manager = process_manager.clone();
+    let escalation_handle = tokio::spawn(async move {
+        let mut interval = tokio::time::interval(ESCALATION_POLL_INTERVAL);
+        loop {
+            tokio::select! {
+                _ = escalation_shutdown_rx.recv() => break,
+                _ = interval.tick() => {
+                    let escalations = escalation_permissions.audit().take_escalations();
+                    if escalations.is_empty() {
+                        continue;
+                    }
+                    // Killing runs resource cleanup, so keep it off the runtime
+                    let pm = escalation_process_manager.clone();
+                    let _ = tokio::task::spawn_blocking(move || {
+                        for escalation in &escalations {
+                            if pm.enforce_escalation(escalation) {
+                                tracing::warn!(
+                                    pid = escalation.pid,
+                                    kind = ?escalation.kind,
+                                    denials = escalation.denials,
+                                    "Enforced denial escalation"
+                                );
+                            }
+                        }
+                    })
+                    .await;
+                }
+            }
+        }
+    });
+
     // Wait for shutdown signal
     wait_for_shutdown_signal().await;
 
@@ -295,30 +409,37 @@ async fn main() -> Result<(), Box<dyn Error>> {
     if process_count > 0 {
         let mut termination_tasks = Vec::new();
 
-        for process in processes {
+        // Only roots are dispatched; terminate_tree takes their descendants with them
+        for process in processes
+            .into_iter()
+            .filter(|p| shutdown_process_manager.get_parent(p.pid).is_none())
+        {
             let pm = shutdown_process_manager.clone();
             let task = tokio::spawn(async move {
                 let pid = process.pid;
                 let name = process.name.clone();
 
-                // Give each process 5 seconds to terminate
+                // Give each process tree 5 seconds to terminate
                 match tokio::time::timeout(
                     tokio::time::Duration::from_secs(5),
-                    tokio::task::spawn_blocking(move || pm.terminate_process(pid).into()),
+                    tokio::task::spawn_blocking(move || pm.terminate_tree(pid).len()),
                 )
                 .await
                 {
-                    Ok(Ok(true)) => {
-                        info!("Terminated process {} (PID: {})", name, pid);
-                        true
-                    }
-                    Ok(Ok(false)) => {
+                    Ok(Ok(0)) => {
                         tracing::warn!("Process {} (PID: {}) was already terminated", name, pid);
-                        false
+                        0
+                    }
+                    Ok(Ok(count)) => {
+                        info!(
+                            "Terminated process tree {} (PID: {}, {} processes)",
+                            name, pid, count
+                        );
+                        count
                     }
                     Ok(Err(e)) => {
                         tracing::error!(error = ?e, "Error in spawn_blocking for PID {}", pid);
-                        false
+                        0
                     }
                     Err(_) => {
                         tracing::warn!(
@@ -326,7 +447,7 @@ async fn main() -> Result<(), Box<dyn Error>> {
                             name,
                             pid
                         );
-                        false
+                        0
                     }
                 }
             });
@@ -335,10 +456,7 @@ async fn main() -> Result<(), Box<dyn Error>> {
 
         // Wait for all terminations to complete (parallel)
         let results = futures::future::join_all(termination_tasks).await;
-        let successful = results
-            .into_iter()
-            .filter(|r| r.is_ok() && *r.as_ref().expect("filtered by is_ok()"))
-            .count();
+        let successful: usize = results.into_iter().filter_map(Result::ok).sum();
         info!(
             "Successfully terminated {}/{} processes",
             successful, process_count
@@ -350,14 +468,52 @@ async fn main() -> Result<(), Box<dyn Error>> {
     // 4. IPC resources already cleaned by process termination
     info!("IPC resources cleaned up automatically during process termination");
 
+    // Write a post-mortem of cleanup; KERNEL_SHUTDOWN_REPORT overrides the path, "-" is stdout
+    let report = shutdown_orchestrator.shutdown_report();
+    info!(
+        "Cleanup report: {} processes, {} resources, {} bytes freed, {} errors",
+        report.processes_cleaned,
+        report.resources_freed,
+        report.bytes_freed,
+        report.errors_encountered
+    );
+    let report_path = std::env::var("KERNEL_SHUTDOWN_REPORT")
+        .unwrap_or_else(|_| format!("{}/shutdown-report.json", storage_path));
+    match ai_os_kernel::core::serialization::json::to_string_pretty(&report) {
+        Ok(json) if report_path == "-" => println!("{}", json),
+        Ok(json) => match std::fs::write(&report_path, json) {
+            Ok(()) => info!("Wrote shutdown report to {}", report_path),
+            Err(e) => tracing::warn!(error = %e, "Failed to write shutdown report"),
+        },
+        Err(e) => tracing::warn!(error = %e, "Failed to serialize shutdown report"),
+    }
+
     // 5. Wait for background tasks to complete (with timeout)
     info!("Waiting for background tasks to complete...");
     let _ = tokio::time::timeout(tokio::time::Duration::from_secs(10), async {
-        let _ = tokio::join!(grpc_handle, monitor_handle);
+        let _ = tokio::join!(grpc_handle, monitor_handle, escalation_handle);
     })
     .await;
     info!("Background tasks completed");
 
+    // 6. Flush buffered monitoring events so they aren't lost on exit
+    if let Some(collector) = ai_os_kernel::global_collector() {
+        let events_path = format!("{}/shutdown-events.ndjson", storage_path);
+        match std::fs::File::create(&events_path)
+            .and_then(|file| collector.flush_to(std::io::BufWriter::new(file)))
+        {
+            Ok(count) => info!("Flushed {} buffered events to {}", count, events_path),
+            Err(e) => tracing::warn!(error = %e, "Failed to flush buffered events"),
+        }
+    }
+
+    // 7. Flush exported spans
+    #[cfg(feature = "otlp")]
+    {
+        info!("Flushing OTLP spans...");
+        let _ = tokio::task::spawn_blocking(ai_os_kernel::shutdown_tracing_otlp).await;
+    }
+
     info!("================================================");
     info!("Graceful shutdown complete. Goodbye!");
     info!("================================================");
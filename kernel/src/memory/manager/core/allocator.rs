@@ -5,8 +5,9 @@
 
 use super::super::process::ProcessMemoryTracking;
 use super::super::MemoryManager;
-use super::free_list::FreeBlock;
-use super::types::{MemoryBlock, MemoryError, MemoryPressure, MemoryResult};
+use super::oom::{OomCandidate, OomPolicy, OOM_SCORE_ADJ_MIN};
+use super::types::{MemoryBlock, MemoryError, MemoryPressure, MemoryResult, Reservation};
+use crate::core::data_structures::InlineString;
 use crate::core::types::{Address, Pid, Size};
 use crate::monitoring::{Category, Event, Payload, Severity};
 use log::{error, info, warn};
@@ -14,16 +15,132 @@
 
 impl MemoryManager {
     /// Allocate memory with graceful OOM handling and address recycling
-    /// Uses segregated free lists for O(1) small/medium and O(log n) large allocations
+    /// Address reuse is delegated to the installed allocation strategy
+    ///
+    /// If the pool is exhausted and a reclaim [`OomPolicy`] is set, one
+    /// victim's memory is freed and the allocation is retried once.
     pub fn allocate(&self, size: Size, pid: Pid) -> MemoryResult<Address> {
+        self.allocate_with(size, pid, None)
+    }
+
+    /// Allocate memory labelled with an allocation site
+    ///
+    /// The tag is kept on the block so memory still held when the process
+    /// exits can be attributed, e.g. "leaked 4MB of 'json_buffer'".
+    pub fn allocate_tagged(
+        &self,
+        size: Size,
+        pid: Pid,
+        tag: &'static str,
+    ) -> MemoryResult<Address> {
+        self.allocate_with(size, pid, Some(tag))
+    }
+
+    fn allocate_with(
+        &self,
+        size: Size,
+        pid: Pid,
+        tag: Option<&'static str>,
+    ) -> MemoryResult<Address> {
+        match self.try_allocate(size, pid, tag) {
+            Err(MemoryError::OutOfMemory { .. }) if self.reclaim_for(size, pid) => {
+                self.try_allocate(size, pid, tag)
+            }
+            result => result,
+        }
+    }
+
+    /// Free a victim chosen by the OOM policy, returning whether one was reclaimed
+    ///
+    /// A victim is only freed if its memory plus what is already free covers
+    /// `size`; otherwise it would be killed for an allocation that still fails.
+    fn reclaim_for(&self, size: Size, pid: Pid) -> bool {
+        let OomPolicy::Reclaim(selector) = self.oom_policy() else {
+            return false;
+        };
+
+        let candidates: Vec<OomCandidate> = self
+            .process_tracking
+            .iter()
+            .filter(|entry| *entry.key() != pid && entry.value().current_bytes > 0)
+            .filter_map(|entry| {
+                let score_adj = self.oom_score_adj(*entry.key());
+                (score_adj != OOM_SCORE_ADJ_MIN).then(|| {
+                    OomCandidate::new(*entry.key(), entry.value().current_bytes)
+                        .with_score_adj(score_adj, self.total_memory)
+                })
+            })
+            .collect();
+
+        let Some(victim) = selector
+            .select(&candidates, pid, size)
+            .filter(|victim| *victim != pid)
+        else {
+            warn!(
+                "OOM: {} selector found no victim for PID {} ({} bytes)",
+                selector.name(),
+                pid,
+                size
+            );
+            return false;
+        };
+
+        let used = self.used_memory.load(Ordering::SeqCst) as usize;
+        let available = self.total_memory.saturating_sub(used);
+        let victim_bytes = candidates
+            .iter()
+            .find(|c| c.pid == victim)
+            .map_or(0, |c| c.bytes);
+        if available.saturating_add(victim_bytes) < size {
+            warn!(
+                "OOM: sparing PID {} ({} bytes), reclaiming it can't fit {} bytes for PID {}",
+                victim, victim_bytes, size, pid
+            );
+            return false;
+        }
+
+        let freed = self.free_process_memory(victim);
+        warn!(
+            "OOM: reclaimed {} bytes from PID {} ({} selector) for PID {}",
+            freed,
+            victim,
+            selector.name(),
+            pid
+        );
+
+        if let Some(ref collector) = self.collector {
+            collector.emit(
+                Event::new(
+                    Severity::Warn,
+                    Category::Memory,
+                    Payload::ResourceReclaimed {
+                        resource: "memory".into(),
+                        count: freed as u64,
+                    },
+                )
+                .with_pid(victim),
+            );
+        }
+
+        freed > 0
+    }
+
+    fn try_allocate(
+        &self,
+        size: Size,
+        pid: Pid,
+        tag: Option<&'static str>,
+    ) -> MemoryResult<Address> {
         // Check if allocation would exceed total memory atomically
         // FlatCombiningCounter batches these operations for 8x better throughput
         let size_u64 = size as u64;
+        let gate = self.stats_gate.read();
         let used = self.used_memory.fetch_add(size_u64, Ordering::SeqCst);
 
         if used + size_u64 > self.total_memory as u64 {
             // Revert the increment
             self.used_memory.fetch_sub(size_u64, Ordering::SeqCst);
+            drop(gate);
 
             let available = self.total_memory - used as usize;
             error!(
@@ -39,42 +156,28 @@ pub fn allocate(&self, size: Size, pid: Pid) -> MemoryResult<Address> {
             });
         }
 
-        // Try to recycle an address from the segregated free list (O(1) or O(log n) lookup)
-        let address = {
-            let mut free_list = match self.free_list.lock() {
-                Ok(guard) => guard,
-                Err(poisoned) => {
-                    log::error!("Free list mutex poisoned during allocation - recovering");
-                    poisoned.into_inner()
-                }
-            };
-
-            if let Some(free_block) = free_list.find_best_fit(size) {
-                let address = free_block.address;
-
+        // Ask the allocation strategy to recycle an address or size a fresh range
+        let reservation = self.lock_strategy("allocation").alloc(size);
+        let address = match reservation {
+            Ok(Reservation::Recycled(address)) => {
                 info!(
-                    "Recycled address 0x{:x} (block size: {}, requested: {}) for PID {} [segregated list: O(1)/O(log n)]",
-                    address, free_block.size, size, pid
+                    "Recycled address 0x{:x} (requested: {}) for PID {}",
+                    address, size, pid
                 );
-
-                // If the free block is larger than needed, split it and return the remainder
-                if free_block.size > size {
-                    let remainder_size = free_block.size - size;
-                    let remainder_addr = address + size;
-                    free_list.insert(FreeBlock {
-                        address: remainder_addr,
-                        size: remainder_size,
-                    });
-                    info!(
-                        "Split block: keeping {} bytes, returning {} bytes at 0x{:x} to free list",
-                        size, remainder_size, remainder_addr
-                    );
-                }
-
                 address
-            } else {
+            }
+            Ok(Reservation::Fresh(reserved)) => {
                 // No suitable free block, allocate new address
-                self.next_address.fetch_add(size_u64, Ordering::SeqCst) as usize
+                self.next_address
+                    .fetch_add(reserved as u64, Ordering::SeqCst) as usize
+            }
+            Err(e) => {
+                self.used_memory.fetch_sub(size_u64, Ordering::SeqCst);
+                warn!(
+                    "Allocation strategy rejected {} bytes for PID {}: {}",
+                    size, pid, e
+                );
+                return Err(e);
             }
         };
 
@@ -83,9 +186,11 @@ pub fn allocate(&self, size: Size, pid: Pid) -> MemoryResult<Address> {
             size,
             allocated: true,
             owner_pid: Some(pid),
+            tag: tag.map(InlineString::from),
         };
 
         self.blocks.insert(address, block);
+        drop(gate);
 
         // Update per-process tracking using entry() for atomic operation
         {
@@ -118,7 +223,11 @@ pub fn allocate(&self, size: Size, pid: Pid) -> MemoryResult<Address> {
             );
         }
 
-        if let Some(level) = self.check_memory_pressure(used_val) {
+        let pressure = self.check_memory_pressure(used_val);
+        self.pressure
+            .observe(pressure.unwrap_or(MemoryPressure::Low));
+
+        if let Some(level) = pressure {
             // Emit memory pressure event
             if let Some(ref collector) = self.collector {
                 let usage_pct = ((used_val as f64 / self.total_memory as f64) * 100.0) as u8;
@@ -142,8 +251,10 @@ pub fn allocate(&self, size: Size, pid: Pid) -> MemoryResult<Address> {
         Ok(address)
     }
 
-    /// Deallocate memory and add to segregated free list for address recycling
+    /// Deallocate memory and return its range to the allocation strategy
     pub fn deallocate(&self, address: Address) -> MemoryResult<()> {
+        // Taken before the block's shard lock, in the same order as stats_consistent
+        let gate = self.stats_gate.read();
         if let Some(mut entry) = self.blocks.get_mut(&address) {
             let block = entry.value_mut();
             if block.allocated {
@@ -152,6 +263,7 @@ pub fn deallocate(&self, address: Address) -> MemoryResult<()> {
                 block.allocated = false;
 
                 self.used_memory.fetch_sub(size as u64, Ordering::SeqCst);
+                drop(gate);
 
                 // Update per-process tracking
                 if let Some(pid) = pid {
@@ -177,26 +289,20 @@ pub fn deallocate(&self, address: Address) -> MemoryResult<()> {
                     }
                 }
 
-                // Add to segregated free list for address recycling
+                self.scrub_freed(address, pid);
+
+                // Return the range to the allocation strategy for recycling
                 {
-                    let mut free_list = match self.free_list.lock() {
-                        Ok(guard) => guard,
-                        Err(poisoned) => {
-                            log::error!(
-                                "Free list mutex poisoned during deallocation - recovering"
-                            );
-                            poisoned.into_inner()
-                        }
-                    };
-                    free_list.insert(FreeBlock { address, size });
-
-                    // Periodically coalesce adjacent blocks to reduce fragmentation
-                    // Only coalesce every N deallocations to amortize the O(n log n) cost
+                    let mut strategy = self.lock_strategy("deallocation");
+                    strategy.dealloc(address, size);
+
+                    // Periodically compact to reduce fragmentation
+                    // Only compact every N deallocations to amortize the cost
                     if self.deallocated_count.load(Ordering::SeqCst)
                         % crate::core::limits::DEALLOC_COALESCE_INTERVAL
                         == 0
                     {
-                        Self::coalesce_free_blocks(&mut free_list);
+                        strategy.compact();
                     }
                 }
 
@@ -205,15 +311,25 @@ pub fn deallocate(&self, address: Address) -> MemoryResult<()> {
 
                 let used = self.used_memory.load(Ordering::SeqCst);
                 info!(
-                    "Deallocated {} bytes at 0x{:x}, added to segregated free list ({} bytes now available, {} deallocated blocks)",
+                    "Deallocated {} bytes at 0x{:x}, returned to allocation strategy ({} bytes now available, {} deallocated blocks)",
                     size,
                     address,
                     self.total_memory - used as usize,
                     dealloc_count
                 );
 
+                // Let pressure callbacks know if usage dropped below a threshold
+                if self.observe_pressure(used as usize).is_some() {
+                    if let Some(ref collector) = self.collector {
+                        let usage_pct = ((used as f64 / self.total_memory as f64) * 100.0) as u8;
+                        let available_mb =
+                            ((self.total_memory - used as usize) / 1024 / 1024) as u64;
+                        collector.memory_pressure(usage_pct, available_mb);
+                    }
+                }
+
                 // Trigger GC if threshold reached
-                let should_gc = dealloc_count >= self.gc_threshold as u64;
+                let should_gc = dealloc_count >= self.gc_threshold() as u64;
                 drop(entry);
 
                 if should_gc {
@@ -3,8 +3,11 @@
  * Efficient memory allocation data structure
  */
 
+use super::traits::AllocationStrategy;
+use super::types::{AllocatorStats, MemoryResult, Reservation};
 use crate::core::limits::{MEDIUM_BLOCK_MAX, SMALL_BLOCK_MAX};
 use crate::core::types::{Address, Size};
+use log::info;
 use std::collections::BTreeMap;
 
 /// Free block for address recycling
@@ -156,4 +159,122 @@ pub fn reinsert_all(&mut self, blocks: Vec<FreeBlock>) {
             self.insert(block);
         }
     }
+
+    /// Coalesce adjacent free blocks to reduce fragmentation
+    ///
+    /// Blocks are merged by address regardless of which bucket they sit in,
+    /// so neighbouring fragments from different size classes become one larger
+    /// block. Returns the bytes in blocks that took part in a merge; the lists
+    /// are left untouched when no two blocks are adjacent.
+    pub fn coalesce(&mut self) -> Size {
+        if self.len() < 2 || !self.has_adjacent_blocks() {
+            return 0;
+        }
+
+        // Extract all blocks and sort by address
+        let all_blocks = self.get_all_sorted();
+        let before = all_blocks.len();
+
+        let mut merged: Vec<FreeBlock> = Vec::with_capacity(before);
+        let mut reclaimed: Size = 0;
+        let mut run_merged = false;
+        for block in all_blocks {
+            match merged.last_mut() {
+                Some(last) if last.address + last.size == block.address => {
+                    if !run_merged {
+                        reclaimed += last.size;
+                        run_merged = true;
+                    }
+                    reclaimed += block.size;
+                    last.size += block.size;
+                }
+                _ => {
+                    merged.push(block);
+                    run_merged = false;
+                }
+            }
+        }
+
+        info!(
+            "Coalesced {} bytes of adjacent free blocks, reduced from {} to {} blocks",
+            reclaimed,
+            before,
+            merged.len()
+        );
+
+        // Reinsert all blocks into segregated lists
+        self.reinsert_all(merged);
+        reclaimed
+    }
+
+    /// Whether any two free blocks are contiguous in the address space
+    fn has_adjacent_blocks(&self) -> bool {
+        let mut ranges: Vec<(Address, Size)> = self
+            .small_blocks
+            .iter()
+            .chain(self.medium_blocks.iter())
+            .chain(self.large_blocks.values())
+            .flatten()
+            .map(|b| (b.address, b.size))
+            .collect();
+        ranges.sort_unstable_by_key(|&(address, _)| address);
+        ranges.windows(2).any(|w| w[0].0 + w[0].1 == w[1].0)
+    }
+
+    fn free_bytes(&self) -> Size {
+        let small: Size = self.small_blocks.iter().flatten().map(|b| b.size).sum();
+        let medium: Size = self.medium_blocks.iter().flatten().map(|b| b.size).sum();
+        let large: Size = self.large_blocks.values().flatten().map(|b| b.size).sum();
+        small + medium + large
+    }
+}
+
+impl Default for SegregatedFreeList {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl AllocationStrategy for SegregatedFreeList {
+    fn name(&self) -> &'static str {
+        "segregated_free_list"
+    }
+
+    fn alloc(&mut self, size: Size) -> MemoryResult<Reservation> {
+        let Some(free_block) = self.find_best_fit(size) else {
+            return Ok(Reservation::Fresh(size));
+        };
+
+        // If the free block is larger than needed, split it and keep the remainder
+        if free_block.size > size {
+            let remainder_size = free_block.size - size;
+            let remainder_addr = free_block.address + size;
+            self.insert(FreeBlock {
+                address: remainder_addr,
+                size: remainder_size,
+            });
+            info!(
+                "Split block: keeping {} bytes, returning {} bytes at 0x{:x} to free list",
+                size, remainder_size, remainder_addr
+            );
+        }
+
+        Ok(Reservation::Recycled(free_block.address))
+    }
+
+    fn dealloc(&mut self, address: Address, size: Size) {
+        self.insert(FreeBlock { address, size });
+    }
+
+    fn compact(&mut self) -> Size {
+        self.coalesce()
+    }
+
+    fn stats(&self) -> AllocatorStats {
+        AllocatorStats {
+            strategy: self.name().to_string(),
+            free_blocks: self.len(),
+            free_bytes: self.free_bytes(),
+        }
+    }
 }
@@ -5,13 +5,24 @@
 
 pub mod allocator;
 pub mod free_list;
+pub mod oom;
+pub mod pressure;
+pub mod slab;
 pub mod traits;
 pub mod types;
 
 // Re-export public types and traits
 pub use free_list::{FreeBlock, SegregatedFreeList};
-pub use traits::{Allocator, GarbageCollector, MemoryInfo, ProcessMemoryCleanup};
+pub use oom::{
+    LargestProcess, LowestPriority, OomCandidate, OomPolicy, VictimSelector, OOM_SCORE_ADJ_MAX,
+    OOM_SCORE_ADJ_MIN,
+};
+pub use pressure::PressureCallback;
+pub use slab::SlabAllocator;
+pub use traits::{
+    AllocationStrategy, Allocator, GarbageCollector, MemoryInfo, ProcessMemoryCleanup,
+};
 pub use types::{
-    AllocationRequest, MemoryBlock, MemoryError, MemoryPressure, MemoryResult, MemoryStats,
-    ProcessMemoryStats,
+    AllocationRequest, AllocatorStats, MemoryBlock, MemoryError, MemoryPressure, MemoryResult,
+    MemoryStats, MemoryStatsDelta, ProcessMemoryStats, ProcessMemoryStatsDelta, Reservation,
 };
@@ -0,0 +1,183 @@
+/*!
+ * OOM Policy
+ * What the memory manager does when an allocation doesn't fit
+ *
+ * The default fails the allocation. With a reclaim policy, a pluggable
+ * selector picks a victim process whose memory is freed before the
+ * allocation is retried once. A per-process score adjustment, as in Linux's
+ * oom_score_adj, biases the choice or exempts a process entirely.
+ */
+
+use crate::core::types::{Pid, Priority, Size};
+use std::fmt;
+use std::sync::Arc;
+
+/// Lowest OOM score adjustment; processes with it are never reclaimed
+pub const OOM_SCORE_ADJ_MIN: i16 = -1000;
+/// Highest OOM score adjustment; processes with it are reclaimed first
+pub const OOM_SCORE_ADJ_MAX: i16 = 1000;
+
+/// A process that could be reclaimed to satisfy an allocation
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct OomCandidate {
+    pub pid: Pid,
+    /// Bytes currently allocated to the process
+    pub bytes: Size,
+    /// `bytes` shifted by the score adjustment; higher is a better victim
+    pub badness: i64,
+}
+
+impl OomCandidate {
+    /// Candidate without a score adjustment
+    pub fn new(pid: Pid, bytes: Size) -> Self {
+        Self {
+            pid,
+            bytes,
+            badness: bytes as i64,
+        }
+    }
+
+    /// Apply a score adjustment, where each point is worth 0.1% of `pool`
+    pub fn with_score_adj(mut self, score_adj: i16, pool: Size) -> Self {
+        let score_adj = score_adj.clamp(OOM_SCORE_ADJ_MIN, OOM_SCORE_ADJ_MAX) as i64;
+        self.badness = self.bytes as i64 + score_adj * pool as i64 / 1000;
+        self
+    }
+}
+
+/// Chooses which process to reclaim when the pool is exhausted
+pub trait VictimSelector: Send + Sync {
+    /// Pick a victim from `candidates` so `requester` can allocate `needed` bytes
+    ///
+    /// Candidates never include the requester. Returning `None` lets the
+    /// allocation fail.
+    fn select(&self, candidates: &[OomCandidate], requester: Pid, needed: Size) -> Option<Pid>;
+
+    /// Selector name for logging
+    fn name(&self) -> &'static str;
+}
+
+/// Reclaim the process holding the most memory, after score adjustment
+#[derive(Debug, Clone, Copy, Default)]
+pub struct LargestProcess;
+
+impl VictimSelector for LargestProcess {
+    fn select(&self, candidates: &[OomCandidate], _requester: Pid, _needed: Size) -> Option<Pid> {
+        // Lowest PID wins ties so the choice is deterministic
+        candidates
+            .iter()
+            .max_by(|a, b| a.badness.cmp(&b.badness).then(b.pid.cmp(&a.pid)))
+            .map(|c| c.pid)
+    }
+
+    fn name(&self) -> &'static str {
+        "largest-process"
+    }
+}
+
+/// Reclaim the lowest-priority process, preferring higher badness among equals
+///
+/// Priorities come from a lookup (usually the process manager); processes
+/// it doesn't know are never chosen.
+pub struct LowestPriority<F> {
+    priority_of: F,
+}
+
+impl<F> LowestPriority<F>
+where
+    F: Fn(Pid) -> Option<Priority> + Send + Sync,
+{
+    pub fn new(priority_of: F) -> Self {
+        Self { priority_of }
+    }
+}
+
+impl<F> VictimSelector for LowestPriority<F>
+where
+    F: Fn(Pid) -> Option<Priority> + Send + Sync,
+{
+    fn select(&self, candidates: &[OomCandidate], _requester: Pid, _needed: Size) -> Option<Pid> {
+        candidates
+            .iter()
+            .filter_map(|c| (self.priority_of)(c.pid).map(|priority| (priority, c)))
+            .min_by(|(pa, a), (pb, b)| {
+                pa.cmp(pb)
+                    .then(b.badness.cmp(&a.badness))
+                    .then(a.pid.cmp(&b.pid))
+            })
+            .map(|(_, c)| c.pid)
+    }
+
+    fn name(&self) -> &'static str {
+        "lowest-priority"
+    }
+}
+
+/// Behaviour on allocation failure
+#[derive(Clone, Default)]
+pub enum OomPolicy {
+    /// Return `OutOfMemory` to the caller (default)
+    #[default]
+    FailFast,
+    /// Free a victim's memory and retry the allocation once
+    Reclaim(Arc<dyn VictimSelector>),
+}
+
+impl OomPolicy {
+    /// Reclaim using the given victim selector
+    pub fn reclaim<S: VictimSelector + 'static>(selector: S) -> Self {
+        Self::Reclaim(Arc::new(selector))
+    }
+}
+
+impl fmt::Debug for OomPolicy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::FailFast => write!(f, "FailFast"),
+            Self::Reclaim(selector) => write!(f, "Reclaim({})", selector.name()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn candidates() -> Vec<OomCandidate> {
+        vec![
+            OomCandidate::new(1, 100),
+            OomCandidate::new(2, 500),
+            OomCandidate::new(3, 500),
+            OomCandidate::new(4, 50),
+        ]
+    }
+
+    #[test]
+    fn test_largest_process() {
+        assert_eq!(LargestProcess.select(&candidates(), 9, 10), Some(2));
+        assert_eq!(LargestProcess.select(&[], 9, 10), None);
+    }
+
+    #[test]
+    fn test_score_adj_outweighs_size() {
+        let mut candidates = candidates();
+        // +100 on a 1000-byte pool is worth 100 bytes: 50 + 100 < 500
+        candidates[3] = OomCandidate::new(4, 50).with_score_adj(100, 1000);
+        assert_eq!(LargestProcess.select(&candidates, 9, 10), Some(2));
+
+        candidates[3] = OomCandidate::new(4, 50).with_score_adj(OOM_SCORE_ADJ_MAX, 1000);
+        assert_eq!(candidates[3].badness, 1050);
+        assert_eq!(LargestProcess.select(&candidates, 9, 10), Some(4));
+    }
+
+    #[test]
+    fn test_lowest_priority() {
+        let selector = LowestPriority::new(|pid| match pid {
+            1 | 4 => Some(1),
+            2 => Some(8),
+            _ => None,
+        });
+        // Among the lowest priority, the larger process goes first
+        assert_eq!(selector.select(&candidates(), 9, 10), Some(1));
+    }
+}
@@ -0,0 +1,319 @@
+/*!
+ * Memory Pressure Notifications
+ * Debounced threshold-crossing callbacks, dispatched off the allocation path
+ */
+
+use super::super::MemoryManager;
+use super::types::MemoryPressure;
+use crate::core::limits::MEMORY_PRESSURE_DEBOUNCE;
+use crate::core::types::Size;
+use flume::RecvTimeoutError;
+use log::{error, info, warn};
+use parking_lot::{Mutex, RwLock};
+use std::panic::{catch_unwind, AssertUnwindSafe};
+use std::sync::atomic::{AtomicU8, Ordering};
+use std::sync::{Arc, OnceLock};
+use std::time::{Duration, Instant};
+
+/// Callback invoked when memory pressure crosses a threshold
+pub type PressureCallback = Box<dyn Fn(MemoryPressure) + Send + Sync>;
+
+/// Messages for the pressure worker
+enum WorkerMsg {
+    /// Run the callbacks for an accepted transition
+    Notify(MemoryPressure),
+    /// A transition was deferred; wake up to flush it when the window closes
+    Deferred,
+}
+
+/// Pressure levels and the debounce window, shared with the worker
+struct PressureState {
+    /// Last accepted level (`MemoryPressure as u8`)
+    level: AtomicU8,
+    /// Latest observed level, which may still be waiting out the window
+    observed: AtomicU8,
+    /// When the last transition was accepted; serializes transitions
+    last_transition: Mutex<Option<Instant>>,
+    debounce: Duration,
+}
+
+impl PressureState {
+    /// Accept `level` unless it is current or the window is still open
+    ///
+    /// Escalation to critical is accepted even inside the window.
+    fn try_accept(&self, level: MemoryPressure) -> Option<MemoryPressure> {
+        let mut last = self.last_transition.lock();
+        // Another thread may have accepted the same transition meanwhile
+        if self.level.load(Ordering::Acquire) == level as u8 {
+            return None;
+        }
+        if level != MemoryPressure::Critical && last.is_some_and(|at| at.elapsed() < self.debounce)
+        {
+            return None;
+        }
+
+        self.level.store(level as u8, Ordering::Release);
+        *last = Some(Instant::now());
+        Some(level)
+    }
+
+    /// Accept the observed level if it still differs once the window closed
+    fn flush(&self) -> Option<MemoryPressure> {
+        self.try_accept(decode(self.observed.load(Ordering::Acquire)))
+    }
+
+    /// When the window closes, if an observed level is waiting on it
+    fn flush_deadline(&self) -> Option<Instant> {
+        let last = self.last_transition.lock();
+        if self.observed.load(Ordering::Acquire) == self.level.load(Ordering::Acquire) {
+            return None;
+        }
+        last.map(|at| at + self.debounce)
+    }
+}
+
+/// Inverse of `MemoryPressure as u8`
+fn decode(level: u8) -> MemoryPressure {
+    match level {
+        0 => MemoryPressure::Low,
+        1 => MemoryPressure::Medium,
+        2 => MemoryPressure::High,
+        _ => MemoryPressure::Critical,
+    }
+}
+
+/// Tracks the current pressure level and fans transitions out to callbacks
+///
+/// The allocation path only pays atomic loads unless the level changed.
+/// Callbacks run on a dedicated worker thread, spawned on first registration,
+/// which also emits the latest level once a debounce window closes.
+pub(crate) struct PressureNotifier {
+    callbacks: Arc<RwLock<Vec<PressureCallback>>>,
+    state: Arc<PressureState>,
+    worker: OnceLock<flume::Sender<WorkerMsg>>,
+}
+
+impl PressureNotifier {
+    pub(crate) fn new(debounce: Duration) -> Self {
+        Self {
+            callbacks: Arc::new(RwLock::new(Vec::new())),
+            state: Arc::new(PressureState {
+                level: AtomicU8::new(MemoryPressure::Low as u8),
+                observed: AtomicU8::new(MemoryPressure::Low as u8),
+                last_transition: Mutex::new(None),
+                debounce,
+            }),
+            worker: OnceLock::new(),
+        }
+    }
+
+    fn subscribe(&self, callback: PressureCallback) {
+        self.callbacks.write().push(callback);
+        self.worker.get_or_init(|| self.spawn_worker());
+    }
+
+    fn spawn_worker(&self) -> flume::Sender<WorkerMsg> {
+        let (tx, rx) = flume::unbounded::<WorkerMsg>();
+        let callbacks = Arc::clone(&self.callbacks);
+        let state = Arc::clone(&self.state);
+
+        let spawned = std::thread::Builder::new()
+            .name("memory-pressure".to_string())
+            .spawn(move || {
+                let mut due = None;
+                loop {
+                    let msg = match due {
+                        Some(deadline) => rx.recv_deadline(deadline),
+                        None => rx.recv().map_err(|_| RecvTimeoutError::Disconnected),
+                    };
+                    let level = match msg {
+                        Ok(WorkerMsg::Notify(level)) => Some(level),
+                        Ok(WorkerMsg::Deferred) => None,
+                        Err(RecvTimeoutError::Timeout) => state.flush(),
+                        // Exits once the notifier (and its sender) is dropped
+                        Err(RecvTimeoutError::Disconnected) => break,
+                    };
+
+                    if let Some(level) = level {
+                        for callback in callbacks.read().iter() {
+                            if catch_unwind(AssertUnwindSafe(|| callback(level))).is_err() {
+                                error!("Memory pressure callback panicked at level {}", level);
+                            }
+                        }
+                    }
+                    due = state.flush_deadline();
+                }
+            });
+
+        if let Err(e) = spawned {
+            error!("Failed to spawn memory pressure worker: {}", e);
+        }
+        tx
+    }
+
+    /// Record the current level, returning it if this is an accepted transition
+    ///
+    /// Transitions within the debounce window of the previous one are
+    /// deferred: the latest observed level is emitted when the window closes
+    /// (or on the next observation after it). Escalation to critical is
+    /// always accepted immediately.
+    pub(crate) fn observe(&self, level: MemoryPressure) -> Option<MemoryPressure> {
+        let changed = self.state.observed.load(Ordering::Relaxed) != level as u8;
+        if changed {
+            self.state.observed.store(level as u8, Ordering::Release);
+        }
+        if self.state.level.load(Ordering::Acquire) == level as u8 {
+            return None;
+        }
+
+        let (msg, accepted) = match self.state.try_accept(level) {
+            Some(level) => (WorkerMsg::Notify(level), Some(level)),
+            // Wake the worker so it flushes this level when the window closes
+            None if changed => (WorkerMsg::Deferred, None),
+            None => return None,
+        };
+
+        if let Some(worker) = self.worker.get() {
+            if worker.send(msg).is_err() {
+                warn!(
+                    "Memory pressure worker is gone; dropping {} notification",
+                    level
+                );
+            }
+        }
+
+        accepted
+    }
+}
+
+impl MemoryManager {
+    /// Register a callback invoked when memory pressure crosses a threshold
+    ///
+    /// Fires on transitions in both directions, debounced so usage hovering
+    /// around a threshold doesn't flap; the level usage settles at is still
+    /// reported once the window closes. Callbacks run on a background thread,
+    /// never on the allocating thread.
+    pub fn on_pressure(&self, callback: PressureCallback) {
+        self.pressure.subscribe(callback);
+        info!("Registered memory pressure callback");
+    }
+
+    /// Replace the pressure debounce interval (useful for testing)
+    ///
+    /// Call before registering callbacks: the notifier is replaced.
+    pub fn with_pressure_debounce(mut self, debounce: Duration) -> Self {
+        self.pressure = Arc::new(PressureNotifier::new(debounce));
+        self
+    }
+
+    /// Feed the pressure level for `used` bytes to the notifier
+    pub(in crate::memory::manager) fn observe_pressure(
+        &self,
+        used: Size,
+    ) -> Option<MemoryPressure> {
+        let level = self
+            .check_memory_pressure(used)
+            .unwrap_or(MemoryPressure::Low);
+        self.pressure.observe(level)
+    }
+}
+
+impl Default for PressureNotifier {
+    fn default() -> Self {
+        Self::new(MEMORY_PRESSURE_DEBOUNCE)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_debounce_suppresses_flapping() {
+        let notifier = PressureNotifier::new(Duration::from_secs(60));
+
+        assert_eq!(
+            notifier.observe(MemoryPressure::High),
+            Some(MemoryPressure::High)
+        );
+        // Falling back within the window is suppressed
+        assert_eq!(notifier.observe(MemoryPressure::Low), None);
+        assert_eq!(notifier.observe(MemoryPressure::High), None);
+        // Critical always gets through
+        assert_eq!(
+            notifier.observe(MemoryPressure::Critical),
+            Some(MemoryPressure::Critical)
+        );
+    }
+
+    #[test]
+    fn test_callbacks_fire_on_threshold_crossing() {
+        let mm = MemoryManager::with_capacity(1000).with_pressure_debounce(Duration::ZERO);
+        let (tx, rx) = flume::unbounded();
+        mm.on_pressure(Box::new(move |level| {
+            let _ = tx.send(level);
+        }));
+
+        let low = mm.allocate(100, 1).unwrap();
+        let high = mm.allocate(750, 1).unwrap();
+        assert_eq!(
+            rx.recv_timeout(Duration::from_secs(5)),
+            Ok(MemoryPressure::High)
+        );
+
+        mm.allocate(100, 1).unwrap();
+        assert_eq!(
+            rx.recv_timeout(Duration::from_secs(5)),
+            Ok(MemoryPressure::Critical)
+        );
+
+        mm.deallocate(high).unwrap();
+        assert_eq!(
+            rx.recv_timeout(Duration::from_secs(5)),
+            Ok(MemoryPressure::Low)
+        );
+
+        mm.deallocate(low).unwrap();
+        assert!(rx.recv_timeout(Duration::from_millis(50)).is_err());
+    }
+
+    #[test]
+    fn test_trailing_transition_fires_when_window_closes() {
+        let mm =
+            MemoryManager::with_capacity(1000).with_pressure_debounce(Duration::from_millis(100));
+        let (tx, rx) = flume::unbounded();
+        mm.on_pressure(Box::new(move |level| {
+            let _ = tx.send(level);
+        }));
+
+        let high = mm.allocate(850, 1).unwrap();
+        assert_eq!(
+            rx.recv_timeout(Duration::from_secs(5)),
+            Ok(MemoryPressure::High)
+        );
+
+        // Dropping back inside the window is deferred, not lost
+        mm.deallocate(high).unwrap();
+        assert_eq!(
+            rx.recv_timeout(Duration::from_secs(5)),
+            Ok(MemoryPressure::Low)
+        );
+        assert!(rx.recv_timeout(Duration::from_millis(200)).is_err());
+    }
+
+    #[test]
+    fn test_panicking_callback_does_not_stop_others() {
+        let mm = MemoryManager::with_capacity(1000).with_pressure_debounce(Duration::ZERO);
+        let (tx, rx) = flume::unbounded();
+        mm.on_pressure(Box::new(|_| panic!("boom")));
+        mm.on_pressure(Box::new(move |level| {
+            let _ = tx.send(level);
+        }));
+
+        mm.allocate(960, 1).unwrap();
+        assert_eq!(
+            rx.recv_timeout(Duration::from_secs(5)),
+            Ok(MemoryPressure::Critical)
+        );
+    }
+}
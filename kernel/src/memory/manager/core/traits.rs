@@ -27,6 +27,32 @@ fn reallocate(&self, address: Address, _new_size: Size) -> MemoryResult<Address>
     fn block_size(&self, address: Address) -> Option<Size>;
 }
 
+/// Address allocation backend installed in a MemoryManager
+///
+/// The manager handles accounting, ownership and storage; a strategy only
+/// decides which addresses get reused. Implementations are called with the
+/// manager's strategy lock held.
+pub trait AllocationStrategy: Send + Sync {
+    /// Strategy name reported in stats
+    fn name(&self) -> &'static str;
+
+    /// Reserve an address range for `size` bytes
+    fn alloc(&mut self, size: Size) -> MemoryResult<Reservation>;
+
+    /// Return a previously reserved range for reuse
+    fn dealloc(&mut self, address: Address, size: Size);
+
+    /// Reduce fragmentation (called periodically and after bulk frees)
+    ///
+    /// Returns the bytes that ended up in larger merged blocks.
+    fn compact(&mut self) -> Size {
+        0
+    }
+
+    /// Free-space statistics
+    fn stats(&self) -> AllocatorStats;
+}
+
 /// Memory statistics provider
 pub trait MemoryInfo: Send + Sync {
     /// Get overall memory statistics
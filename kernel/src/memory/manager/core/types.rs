@@ -3,10 +3,13 @@
  * Common types for memory management
  */
 
+use crate::core::data_structures::InlineString;
+use crate::core::errors::{ErrorCategory, ErrorCode};
 use crate::core::serialization::serde::{is_default, is_none, is_zero_usize};
 use crate::core::types::{Address, Pid, Size};
 use miette::Diagnostic;
 use serde::{Deserialize, Serialize};
+use std::time::Duration;
 use thiserror::Error;
 
 /// Memory operation result
@@ -66,6 +69,19 @@ pub enum MemoryError {
     )]
     AlignmentError { address: Address, alignment: Size },
 
+    #[error(
+        "Allocation of {requested} bytes not supported by {strategy} allocator (max {max} bytes)"
+    )]
+    #[diagnostic(
+        code(memory::unsupported_size),
+        help("The installed allocation strategy only serves blocks up to its configured size. Use a larger slab or the segregated free list.")
+    )]
+    UnsupportedSize {
+        requested: Size,
+        max: Size,
+        strategy: String,
+    },
+
     #[error("Memory protection violation: {0}")]
     #[diagnostic(
         code(memory::protection_violation),
@@ -77,6 +93,34 @@ pub enum MemoryError {
     ProtectionViolation(String),
 }
 
+impl MemoryError {
+    /// Stable numeric code for this variant
+    #[inline]
+    pub const fn error_code(&self) -> u32 {
+        self.typed_code().code
+    }
+
+    /// Error class for this variant
+    #[inline]
+    pub const fn category(&self) -> ErrorCategory {
+        self.typed_code().category
+    }
+
+    /// Code and category together
+    pub const fn typed_code(&self) -> ErrorCode {
+        use ErrorCategory as C;
+        match self {
+            Self::OutOfMemory { .. } => ErrorCode::new(3001, C::QuotaExceeded),
+            Self::ProcessLimitExceeded { .. } => ErrorCode::new(3002, C::QuotaExceeded),
+            Self::InvalidAddress(_) => ErrorCode::new(3003, C::InvalidArgument),
+            Self::CorruptionDetected(_) => ErrorCode::new(3004, C::Internal),
+            Self::AlignmentError { .. } => ErrorCode::new(3005, C::InvalidArgument),
+            Self::UnsupportedSize { .. } => ErrorCode::new(3006, C::Unsupported),
+            Self::ProtectionViolation(_) => ErrorCode::new(3007, C::PermissionDenied),
+        }
+    }
+}
+
 /// Memory block metadata
 ///
 /// # Performance
@@ -92,6 +136,9 @@ pub struct MemoryBlock {
     pub allocated: bool,
     #[serde(skip_serializing_if = "is_none")]
     pub owner_pid: Option<Pid>,
+    /// Allocation site label, used to attribute leaks
+    #[serde(default, skip_serializing_if = "is_none")]
+    pub tag: Option<InlineString>,
 }
 
 /// Helper for default true value
@@ -111,11 +158,15 @@ fn default() -> Self {
             size: 0,
             allocated: false,
             owner_pid: None,
+            tag: None,
         }
     }
 }
 
 impl MemoryBlock {
+    /// Tag reported for leaked blocks allocated without one
+    pub const UNTAGGED: &'static str = "untagged";
+
     /// Create a new allocated memory block
     #[inline]
     #[must_use]
@@ -125,6 +176,7 @@ pub const fn new(address: Address, size: Size, owner_pid: Pid) -> Self {
             size,
             allocated: true,
             owner_pid: Some(owner_pid),
+            tag: None,
         }
     }
 
@@ -137,6 +189,7 @@ pub const fn unowned(address: Address, size: Size) -> Self {
             size,
             allocated: true,
             owner_pid: None,
+            tag: None,
         }
     }
 
@@ -178,6 +231,33 @@ pub const fn owner(&self) -> Option<Pid> {
     pub const fn is_owned_by(&self, pid: Pid) -> bool {
         matches!(self.owner_pid, Some(p) if p == pid)
     }
+
+    /// Allocation tag, or [`Self::UNTAGGED`]
+    #[inline]
+    #[must_use]
+    pub fn tag(&self) -> &str {
+        self.tag.as_deref().unwrap_or(Self::UNTAGGED)
+    }
+}
+
+/// Outcome of an allocation strategy reservation
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Reservation {
+    /// Reuse a previously freed address
+    Recycled(Address),
+    /// Carve a fresh range of this many bytes from the address space
+    Fresh(Size),
+}
+
+/// Allocation strategy statistics
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub struct AllocatorStats {
+    pub strategy: String,
+    #[serde(default, skip_serializing_if = "is_zero_usize")]
+    pub free_blocks: usize,
+    #[serde(default, skip_serializing_if = "is_zero_usize")]
+    pub free_bytes: Size,
 }
 
 /// Memory statistics
@@ -198,6 +278,9 @@ pub struct MemoryStats {
     pub allocated_blocks: usize,
     #[serde(default, skip_serializing_if = "is_zero_usize")]
     pub fragmented_blocks: usize,
+    /// Name of the active allocation strategy
+    #[serde(default)]
+    pub allocator_strategy: String,
 }
 
 impl Default for MemoryStats {
@@ -209,6 +292,7 @@ fn default() -> Self {
             usage_percentage: 0.0,
             allocated_blocks: 0,
             fragmented_blocks: 0,
+            allocator_strategy: String::new(),
         }
     }
 }
@@ -232,6 +316,7 @@ pub fn new(total_memory: Size, used_memory: Size) -> Self {
             usage_percentage,
             allocated_blocks: 0,
             fragmented_blocks: 0,
+            allocator_strategy: String::new(),
         }
     }
 
@@ -284,6 +369,74 @@ pub fn fragmentation_ratio(&self) -> f64 {
             self.fragmented_blocks as f64 / total_blocks as f64
         }
     }
+
+    /// Change from this snapshot to `later`
+    #[must_use]
+    pub fn diff(&self, later: &MemoryStats) -> MemoryStatsDelta {
+        MemoryStatsDelta {
+            total_memory: signed_delta(self.total_memory, later.total_memory),
+            used_memory: signed_delta(self.used_memory, later.used_memory),
+            available_memory: signed_delta(self.available_memory, later.available_memory),
+            usage_percentage: later.usage_percentage - self.usage_percentage,
+            allocated_blocks: signed_delta(self.allocated_blocks, later.allocated_blocks),
+            fragmented_blocks: signed_delta(self.fragmented_blocks, later.fragmented_blocks),
+            elapsed_us: None,
+        }
+    }
+}
+
+/// Signed `after - before`
+#[inline]
+fn signed_delta(before: usize, after: usize) -> i64 {
+    (after as i128 - before as i128) as i64
+}
+
+/// Per-second rate of `delta`, if the time between snapshots is known
+#[inline]
+fn rate(delta: i64, elapsed_us: Option<u64>) -> Option<f64> {
+    elapsed_us
+        .filter(|&us| us > 0)
+        .map(|us| delta as f64 / Duration::from_micros(us).as_secs_f64())
+}
+
+/// Change between two `MemoryStats` snapshots, later minus earlier
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub struct MemoryStatsDelta {
+    pub total_memory: i64,
+    pub used_memory: i64,
+    pub available_memory: i64,
+    /// Change in percentage points
+    pub usage_percentage: f64,
+    pub allocated_blocks: i64,
+    pub fragmented_blocks: i64,
+    /// Time between the snapshots, when supplied
+    #[serde(default, skip_serializing_if = "is_none")]
+    pub elapsed_us: Option<u64>,
+}
+
+impl MemoryStatsDelta {
+    /// Record the time between the snapshots so rates can be computed
+    #[inline]
+    #[must_use]
+    pub fn over(mut self, elapsed: Duration) -> Self {
+        self.elapsed_us = Some(elapsed.as_micros() as u64);
+        self
+    }
+
+    /// Bytes of used memory gained per second
+    #[inline]
+    #[must_use]
+    pub fn used_bytes_per_sec(&self) -> Option<f64> {
+        rate(self.used_memory, self.elapsed_us)
+    }
+
+    /// Allocated blocks gained per second
+    #[inline]
+    #[must_use]
+    pub fn blocks_per_sec(&self) -> Option<f64> {
+        rate(self.allocated_blocks, self.elapsed_us)
+    }
 }
 
 /// Memory pressure levels
@@ -369,6 +522,12 @@ pub struct ProcessMemoryStats {
     pub peak_bytes: Size,
     #[serde(default, skip_serializing_if = "is_zero_usize")]
     pub allocation_count: usize,
+    /// Bytes still shared copy-on-write with another process
+    #[serde(default, skip_serializing_if = "is_zero_usize")]
+    pub shared_bytes: Size,
+    /// Bytes owned by this process alone
+    #[serde(default, skip_serializing_if = "is_zero_usize")]
+    pub private_bytes: Size,
 }
 
 impl Default for ProcessMemoryStats {
@@ -387,6 +546,8 @@ pub const fn new(pid: Pid) -> Self {
             allocated_bytes: 0,
             peak_bytes: 0,
             allocation_count: 0,
+            shared_bytes: 0,
+            private_bytes: 0,
         }
     }
 
@@ -411,4 +572,58 @@ pub const fn avg_allocation_size(&self) -> Size {
             self.allocated_bytes / self.allocation_count
         }
     }
+
+    /// Change from this snapshot to `later` of the same process
+    #[must_use]
+    pub fn diff(&self, later: &ProcessMemoryStats) -> ProcessMemoryStatsDelta {
+        debug_assert_eq!(self.pid, later.pid, "diffing stats of different processes");
+        ProcessMemoryStatsDelta {
+            pid: self.pid,
+            allocated_bytes: signed_delta(self.allocated_bytes, later.allocated_bytes),
+            peak_bytes: signed_delta(self.peak_bytes, later.peak_bytes),
+            allocation_count: signed_delta(self.allocation_count, later.allocation_count),
+            shared_bytes: signed_delta(self.shared_bytes, later.shared_bytes),
+            private_bytes: signed_delta(self.private_bytes, later.private_bytes),
+            elapsed_us: None,
+        }
+    }
+}
+
+/// Change between two `ProcessMemoryStats` snapshots, later minus earlier
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub struct ProcessMemoryStatsDelta {
+    pub pid: Pid,
+    pub allocated_bytes: i64,
+    pub peak_bytes: i64,
+    pub allocation_count: i64,
+    pub shared_bytes: i64,
+    pub private_bytes: i64,
+    /// Time between the snapshots, when supplied
+    #[serde(default, skip_serializing_if = "is_none")]
+    pub elapsed_us: Option<u64>,
+}
+
+impl ProcessMemoryStatsDelta {
+    /// Record the time between the snapshots so rates can be computed
+    #[inline]
+    #[must_use]
+    pub fn over(mut self, elapsed: Duration) -> Self {
+        self.elapsed_us = Some(elapsed.as_micros() as u64);
+        self
+    }
+
+    /// Bytes allocated per second, net of frees
+    #[inline]
+    #[must_use]
+    pub fn allocated_bytes_per_sec(&self) -> Option<f64> {
+        rate(self.allocated_bytes, self.elapsed_us)
+    }
+
+    /// Allocations made per second, net of frees
+    #[inline]
+    #[must_use]
+    pub fn allocations_per_sec(&self) -> Option<f64> {
+        rate(self.allocation_count, self.elapsed_us)
+    }
 }
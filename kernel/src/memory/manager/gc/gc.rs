@@ -11,7 +11,7 @@
 impl MemoryManager {
     /// Garbage collect deallocated memory blocks
     /// Removes deallocated blocks from the HashMap to prevent unbounded growth
-    /// Note: Free blocks remain in the segregated free list for address recycling
+    /// Note: Free blocks remain with the allocation strategy for address recycling
     pub fn collect(&self) -> Size {
         let initial_count = self.blocks.len();
 
@@ -23,41 +23,37 @@ pub fn collect(&self) -> Size {
             .map(|entry| *entry.key())
             .collect();
 
-        // Remove deallocated blocks from the blocks map
-        for addr in &deallocated_addrs {
-            self.blocks.remove(addr);
-        }
-
-        let removed_count = deallocated_addrs.len();
-
-        // Clean up storage for deallocated blocks
-        if !deallocated_addrs.is_empty() {
+        // Remove deallocated blocks under the stats gate, skipping any that
+        // were recycled and reallocated since the scan
+        let removed_count = {
+            let _gate = self.stats_gate.read();
+            let mut removed = 0;
             for addr in &deallocated_addrs {
-                self.memory_storage.remove(addr);
+                if self
+                    .blocks
+                    .remove_if(addr, |_, block| !block.allocated)
+                    .is_some()
+                {
+                    self.memory_storage.remove(addr);
+                    removed += 1;
+                }
             }
-        }
+            removed
+        };
 
         // Reset deallocated counter
         self.deallocated_count.store(0, Ordering::SeqCst);
 
-        // Note: We intentionally keep blocks in the segregated free list for address recycling
-        // The segregated free list allows these addresses to be reused in O(1) or O(log n) time
+        // Note: We intentionally keep free ranges in the allocation strategy for address recycling
 
         if removed_count > 0 {
             // Shrink DashMap capacity after bulk deletion to reclaim memory
             self.blocks.shrink_to_fit();
             self.memory_storage.shrink_to_fit();
 
-            let free_list_size =
-                self.free_list
-                    .lock()
-                    .map(|fl| fl.len())
-                    .unwrap_or_else(|poisoned| {
-                        log::warn!("Free list mutex poisoned during GC - recovering");
-                        poisoned.into_inner().len()
-                    });
+            let free_list_size = self.lock_strategy("GC").stats().free_blocks;
             info!(
-                "Garbage collection complete: removed {} deallocated blocks and their storage, {} blocks remain, {} blocks in segregated free list for O(1)/O(log n) recycling (maps shrunk to fit)",
+                "Garbage collection complete: removed {} deallocated blocks and their storage, {} blocks remain, {} free blocks held by allocation strategy for recycling (maps shrunk to fit)",
                 removed_count,
                 initial_count - removed_count,
                 free_list_size
@@ -75,13 +71,32 @@ pub fn force_collect(&self) -> Size {
 
     /// Check if GC should run
     pub fn should_collect(&self) -> bool {
-        let dealloc_count = self.deallocated_count.load(Ordering::SeqCst);
-        dealloc_count >= self.gc_threshold as u64
+        self.deallocated_count() >= self.gc_threshold() as u64
+    }
+
+    /// Deallocated blocks awaiting collection
+    pub fn deallocated_count(&self) -> u64 {
+        self.deallocated_count.load(Ordering::SeqCst)
+    }
+
+    /// Deallocated blocks that trigger an automatic collection
+    pub fn gc_threshold(&self) -> Size {
+        self.gc_threshold.load(Ordering::Relaxed)
     }
 
     /// Set GC threshold
-    pub fn set_threshold(&mut self, _threshold: Size) {
-        // Note: MemoryManager uses Arc internally, so this would need refactoring
-        // to support mutable threshold changes. For now, threshold is set at construction.
+    pub fn set_threshold(&mut self, threshold: Size) {
+        self.set_threshold_shared(threshold);
+    }
+
+    /// Set GC threshold without exclusive access
+    ///
+    /// Applies to every clone of this manager. Takes effect on the next
+    /// deallocation; lowering it below the current backlog doesn't collect
+    /// by itself. Clamped to at least 1 block.
+    pub fn set_threshold_shared(&self, threshold: Size) {
+        let threshold = threshold.max(1);
+        self.gc_threshold.store(threshold, Ordering::Relaxed);
+        info!("GC threshold set to {} deallocated blocks", threshold);
     }
 }
@@ -22,9 +22,25 @@
  * - **Address recycling**: Deallocated memory is immediately available for reuse
  * - **Block splitting**: Larger blocks are split when smaller allocations are requested
  * - **Coalescing**: Adjacent free blocks are merged to reduce fragmentation
- * - **Memory pressure tracking**: Warns at 80%, critical at 95%
- * - **Garbage collection**: Automatic cleanup of deallocated block metadata
+ * - **Memory pressure tracking**: Warns at 80%, critical at 95%; debounced callbacks via
+ *   [`MemoryManager::on_pressure`]
+ * - **Garbage collection**: Automatic cleanup of deallocated block metadata, with a
+ *   threshold adjustable at runtime via [`MemoryManager::set_threshold_shared`]
  * - **Per-process tracking**: Monitor peak usage and allocation counts
+ * - **Checkpoint/restore**: CoW snapshots of process memory restorable into a new PID
+ * - **OOM policy**: Opt-in reclaim of a selected victim's memory via
+ *   [`MemoryManager::set_oom_policy`]; fails fast by default, with per-process
+ *   bias via [`MemoryManager::set_oom_score_adj`]
+ * - **Zero-on-free**: Opt-in scrubbing of freed blocks so recycled addresses
+ *   never expose a previous owner's data; globally via
+ *   [`MemoryManager::set_zero_on_free`] or per process via
+ *   [`MemoryManager::set_process_zero_on_free`]
+ *
+ * ## Allocation Strategies
+ *
+ * Address reuse is delegated to an [`AllocationStrategy`] chosen at construction
+ * via [`MemoryManager::with_strategy`]. The default is [`SegregatedFreeList`];
+ * [`SlabAllocator`] serves fixed-size slots for uniformly-sized workloads.
  */
 
 // Organized submodules
@@ -36,10 +52,14 @@
 
 // Re-export public types, traits, and extensions
 pub use core::{
-    AllocationRequest, Allocator, GarbageCollector, MemoryBlock, MemoryError, MemoryInfo,
-    MemoryPressure, MemoryResult, MemoryStats, ProcessMemoryCleanup, ProcessMemoryStats,
+    AllocationRequest, AllocationStrategy, Allocator, AllocatorStats, GarbageCollector,
+    LargestProcess, LowestPriority, MemoryBlock, MemoryError, MemoryInfo, MemoryPressure,
+    MemoryResult, MemoryStats, MemoryStatsDelta, OomCandidate, OomPolicy, PressureCallback,
+    ProcessMemoryCleanup, ProcessMemoryStats, ProcessMemoryStatsDelta, Reservation,
+    SegregatedFreeList, SlabAllocator, VictimSelector, OOM_SCORE_ADJ_MAX, OOM_SCORE_ADJ_MIN,
 };
 pub use extensions::MemoryGuardExt;
+pub use process::MemorySnapshot;
 
 use crate::core::memory::CowMemory;
 use crate::core::sync::lockfree::FlatCombiningCounter;
@@ -47,12 +67,13 @@
 use crate::core::{ShardManager, WorkloadProfile};
 use crate::monitoring::Collector;
 use ahash::RandomState;
-use core::SegregatedFreeList;
+use core::pressure::PressureNotifier;
 use dashmap::DashMap;
-use log::info;
+use log::{info, warn};
+use parking_lot::RwLock;
 use process::ProcessMemoryTracking;
-use std::sync::atomic::AtomicU64;
-use std::sync::{Arc, Mutex};
+use std::sync::atomic::{AtomicBool, AtomicU64, AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex, MutexGuard};
 
 /// Memory manager
 ///
@@ -64,18 +85,32 @@ pub struct MemoryManager {
     pub(super) next_address: Arc<AtomicU64>,
     pub(super) total_memory: Size,
     pub(super) used_memory: Arc<FlatCombiningCounter>,
+    // Held shared while `used_memory` and `blocks` are updated together;
+    // `stats_consistent` takes it exclusively to see them in agreement
+    pub(super) stats_gate: Arc<RwLock<()>>,
     // Memory pressure thresholds (percentage)
     pub(super) warning_threshold: f64,  // 80%
     pub(super) critical_threshold: f64, // 95%
+    // Threshold-crossing notifications for subsystems that can shed memory
+    pub(super) pressure: Arc<PressureNotifier>,
     // Garbage collection threshold - run GC when this many deallocated blocks accumulate
-    pub(super) gc_threshold: Size, // 1000 blocks
+    // (shared so it can be retuned on any clone)
+    pub(super) gc_threshold: Arc<AtomicUsize>, // 1000 blocks
     pub(super) deallocated_count: Arc<FlatCombiningCounter>,
     // Per-process memory tracking (for peak_bytes and allocation_count)
     pub(super) process_tracking: Arc<DashMap<Pid, ProcessMemoryTracking, RandomState>>,
     // Memory storage - maps addresses to CoW memory
     pub(super) memory_storage: Arc<DashMap<Address, CowMemory, RandomState>>,
-    // Segregated free list for O(1) small/medium and O(log n) large block allocation
-    pub(super) free_list: Arc<Mutex<SegregatedFreeList>>,
+    // Address reuse strategy (segregated free list by default)
+    pub(super) strategy: Arc<Mutex<Box<dyn AllocationStrategy>>>,
+    // What to do when an allocation doesn't fit (shared across clones)
+    pub(super) oom_policy: Arc<RwLock<OomPolicy>>,
+    // Per-process OOM score adjustments; absent means 0
+    pub(super) oom_scores: Arc<DashMap<Pid, i16, RandomState>>,
+    // Scrub the storage of every freed block (shared across clones)
+    pub(super) zero_on_free: Arc<AtomicBool>,
+    // Processes whose freed blocks are scrubbed regardless of the global mode
+    pub(super) zero_on_free_pids: Arc<DashMap<Pid, (), RandomState>>,
     // Observability collector for event streaming
     collector: Option<Arc<Collector>>,
 }
@@ -87,9 +122,15 @@ pub fn new() -> Self {
 
     /// Create memory manager with custom capacity (useful for testing)
     pub fn with_capacity(total: Size) -> Self {
+        Self::with_strategy(total, SegregatedFreeList::new())
+    }
+
+    /// Create memory manager with custom capacity and allocation strategy
+    pub fn with_strategy<S: AllocationStrategy + 'static>(total: Size, strategy: S) -> Self {
         info!(
-            "Memory manager initialized with {} bytes and segregated free list allocator (O(1) small/medium, O(log n) large)",
-            total
+            "Memory manager initialized with {} bytes and {} allocation strategy",
+            total,
+            strategy.name()
         );
         Self {
             // CPU-topology-aware shard counts for optimal concurrent performance
@@ -104,9 +145,11 @@ pub fn with_capacity(total: Size) -> Self {
             next_address: Arc::new(AtomicU64::new(0).into()),
             total_memory: total,
             used_memory: Arc::new(FlatCombiningCounter::new(0).into()),
+            stats_gate: Arc::new(RwLock::new(())),
             warning_threshold: 0.80,
             critical_threshold: 0.95,
-            gc_threshold: 1000,
+            pressure: Arc::new(PressureNotifier::default()),
+            gc_threshold: Arc::new(AtomicUsize::new(1000)),
             deallocated_count: Arc::new(FlatCombiningCounter::new(0).into()),
             process_tracking: Arc::new(
                 DashMap::with_capacity_and_hasher_and_shard_amount(
@@ -124,11 +167,120 @@ pub fn with_capacity(total: Size) -> Self {
                 )
                 .into(),
             ),
-            free_list: Arc::new(Mutex::new(SegregatedFreeList::new().into())),
+            strategy: Arc::new(Mutex::new(Box::new(strategy))),
+            oom_policy: Arc::new(RwLock::new(OomPolicy::default())),
+            oom_scores: Arc::new(DashMap::with_hasher(RandomState::new())),
+            zero_on_free: Arc::new(AtomicBool::new(false)),
+            zero_on_free_pids: Arc::new(DashMap::with_hasher(RandomState::new())),
             collector: None,
         }
     }
 
+    /// Override the topology-derived shard count of the internal maps
+    ///
+    /// Caps memory overhead on many-core machines where the default would be
+    /// far larger than the pool needs. `count` is rounded up to a power of
+    /// two. Call before use: the maps are replaced.
+    pub fn with_shards(mut self, count: usize) -> Self {
+        let shards = ShardManager::normalize(count);
+        self.blocks = Arc::new(DashMap::with_capacity_and_hasher_and_shard_amount(
+            0,
+            RandomState::new(),
+            shards,
+        ));
+        self.process_tracking = Arc::new(DashMap::with_capacity_and_hasher_and_shard_amount(
+            0,
+            RandomState::new(),
+            shards,
+        ));
+        self.memory_storage = Arc::new(DashMap::with_capacity_and_hasher_and_shard_amount(
+            0,
+            RandomState::new(),
+            shards,
+        ));
+        self
+    }
+
+    /// Set the OOM policy (builder form of [`set_oom_policy`](Self::set_oom_policy))
+    pub fn with_oom_policy(self, policy: OomPolicy) -> Self {
+        self.set_oom_policy(policy);
+        self
+    }
+
+    /// Replace the OOM policy; applies to every clone of this manager
+    pub fn set_oom_policy(&self, policy: OomPolicy) {
+        info!("Memory manager OOM policy set to {:?}", policy);
+        *self.oom_policy.write() = policy;
+    }
+
+    /// Current OOM policy
+    pub fn oom_policy(&self) -> OomPolicy {
+        self.oom_policy.read().clone()
+    }
+
+    /// Set the OOM score adjustment of `pid`, returning the clamped value stored
+    ///
+    /// Positive values make the process a likelier victim; [`OOM_SCORE_ADJ_MIN`]
+    /// exempts it from reclaim.
+    pub fn set_oom_score_adj(&self, pid: Pid, score_adj: i16) -> i16 {
+        let score_adj = score_adj.clamp(OOM_SCORE_ADJ_MIN, OOM_SCORE_ADJ_MAX);
+        if score_adj == 0 {
+            self.oom_scores.remove(&pid);
+        } else {
+            self.oom_scores.insert(pid, score_adj);
+        }
+        score_adj
+    }
+
+    /// OOM score adjustment of `pid`
+    pub fn oom_score_adj(&self, pid: Pid) -> i16 {
+        self.oom_scores.get(&pid).map_or(0, |score| *score)
+    }
+
+    /// Forget the OOM score adjustment of an exited process
+    pub fn clear_oom_score_adj(&self, pid: Pid) {
+        self.oom_scores.remove(&pid);
+    }
+
+    /// Enable or disable zero-on-free (builder form of [`set_zero_on_free`](Self::set_zero_on_free))
+    pub fn with_zero_on_free(self, enabled: bool) -> Self {
+        self.set_zero_on_free(enabled);
+        self
+    }
+
+    /// Scrub freed blocks before their addresses are recycled
+    ///
+    /// Off by default: every deallocation pays for a memset of the block.
+    /// Applies to every clone of this manager.
+    pub fn set_zero_on_free(&self, enabled: bool) {
+        info!(
+            "Memory manager zero-on-free {}",
+            if enabled { "enabled" } else { "disabled" }
+        );
+        self.zero_on_free.store(enabled, Ordering::Relaxed);
+    }
+
+    /// Whether zero-on-free is enabled for every process
+    pub fn zero_on_free(&self) -> bool {
+        self.zero_on_free.load(Ordering::Relaxed)
+    }
+
+    /// Force zero-on-free for `pid` even when the global mode is off
+    ///
+    /// Cleared when the process's memory is freed on termination.
+    pub fn set_process_zero_on_free(&self, pid: Pid, enabled: bool) {
+        if enabled {
+            self.zero_on_free_pids.insert(pid, ());
+        } else {
+            self.zero_on_free_pids.remove(&pid);
+        }
+    }
+
+    /// Whether blocks freed by `pid` are scrubbed
+    pub fn zeroes_on_free(&self, pid: Pid) -> bool {
+        self.zero_on_free() || self.zero_on_free_pids.contains_key(&pid)
+    }
+
     /// Add observability collector
     pub fn with_collector(mut self, collector: Arc<Collector>) -> Self {
         self.collector = Some(collector);
@@ -145,7 +297,45 @@ pub fn collector(&self) -> Option<Arc<Collector>> {
         self.collector.clone()
     }
 
+    /// Get statistics from the active allocation strategy
+    pub fn allocator_stats(&self) -> AllocatorStats {
+        self.lock_strategy("stats").stats()
+    }
+
+    /// Run a defragmentation pass over the allocation strategy
+    ///
+    /// Merges adjacent free ranges into larger blocks and returns the bytes
+    /// merged, or 0 if nothing was adjacent. Holds the strategy lock for the
+    /// pass, so it is safe alongside concurrent allocations.
+    pub fn compact(&self) -> Size {
+        let reclaimed = self.lock_strategy("compaction").compact();
+        if reclaimed > 0 {
+            info!(
+                "Memory compaction merged {} bytes of free blocks",
+                reclaimed
+            );
+        }
+        reclaimed
+    }
+
+    /// Lock the allocation strategy, recovering from poisoning
+    pub(super) fn lock_strategy(
+        &self,
+        operation: &str,
+    ) -> MutexGuard<'_, Box<dyn AllocationStrategy>> {
+        self.strategy.lock().unwrap_or_else(|poisoned| {
+            log::error!(
+                "Allocation strategy mutex poisoned during {} - recovering",
+                operation
+            );
+            poisoned.into_inner()
+        })
+    }
+
     /// Fork process memory using CoW semantics
+    ///
+    /// The child's blocks are charged like allocations, so forking stops
+    /// early with a warning once memory runs out.
     pub fn fork_memory(&self, parent_pid: Pid, child_pid: Pid) {
         let parent_blocks: Vec<_> = self
             .blocks
@@ -154,21 +344,46 @@ pub fn fork_memory(&self, parent_pid: Pid, child_pid: Pid) {
             .map(|e| (e.key().clone(), e.value().clone()))
             .collect();
 
+        let mut forked = 0;
+        let mut forked_blocks = 0;
+        let gate = self.stats_gate.read();
         for (addr, mut block) in parent_blocks {
             if let Some(parent_cow) = self.memory_storage.get(&addr) {
+                let size = block.size;
+                let size_u64 = size as u64;
+                let used = self.used_memory.fetch_add(size_u64, Ordering::SeqCst);
+                if used + size_u64 > self.total_memory as u64 {
+                    self.used_memory.fetch_sub(size_u64, Ordering::SeqCst);
+                    warn!(
+                        "Fork of PID {} into PID {} ran out of memory after {} bytes",
+                        parent_pid, child_pid, forked
+                    );
+                    break;
+                }
                 let child_cow = parent_cow.clone_cow();
 
                 block.owner_pid = Some(child_pid);
-                let child_addr = self
-                    .next_address
-                    .fetch_add(1, std::sync::atomic::Ordering::SeqCst)
-                    as Address;
+                // Reserve the whole range so child blocks never overlap
+                let child_addr = self.next_address.fetch_add(size_u64, Ordering::SeqCst) as Address;
                 block.address = child_addr;
 
                 self.blocks.insert(child_addr, block);
                 self.memory_storage.insert(child_addr, child_cow);
+                forked += size;
+                forked_blocks += 1;
             }
         }
+        drop(gate);
+
+        if forked_blocks > 0 {
+            let mut track = self
+                .process_tracking
+                .entry(child_pid)
+                .or_insert_with(ProcessMemoryTracking::new);
+            track.current_bytes += forked;
+            track.allocation_count += forked_blocks;
+            track.peak_bytes = track.peak_bytes.max(track.current_bytes);
+        }
     }
 }
 
@@ -240,13 +455,19 @@ fn clone(&self) -> Self {
             next_address: Arc::clone(&self.next_address),
             total_memory: self.total_memory,
             used_memory: Arc::clone(&self.used_memory),
+            stats_gate: Arc::clone(&self.stats_gate),
             warning_threshold: self.warning_threshold,
             critical_threshold: self.critical_threshold,
-            gc_threshold: self.gc_threshold,
+            pressure: Arc::clone(&self.pressure),
+            gc_threshold: Arc::clone(&self.gc_threshold),
             deallocated_count: Arc::clone(&self.deallocated_count),
             process_tracking: Arc::clone(&self.process_tracking),
             memory_storage: Arc::clone(&self.memory_storage),
-            free_list: Arc::clone(&self.free_list),
+            strategy: Arc::clone(&self.strategy),
+            oom_policy: Arc::clone(&self.oom_policy),
+            oom_scores: Arc::clone(&self.oom_scores),
+            zero_on_free: Arc::clone(&self.zero_on_free),
+            zero_on_free_pids: Arc::clone(&self.zero_on_free_pids),
             collector: self.collector.as_ref().map(Arc::clone),
         }
     }
@@ -0,0 +1,146 @@
+/*!
+ * Process Memory Checkpointing
+ * Point-in-time snapshots of process memory built on CoW page sharing
+ */
+
+use super::super::core::{MemoryBlock, MemoryResult};
+use super::super::MemoryManager;
+use crate::core::memory::CowMemory;
+use crate::core::types::{Pid, Size};
+use log::{info, warn};
+use std::time::SystemTime;
+
+/// Captured block metadata and its CoW-shared contents
+#[derive(Debug)]
+struct SnapshotRegion {
+    block: MemoryBlock,
+    // None if the block was never written (restores as zeroed memory)
+    data: Option<CowMemory>,
+}
+
+/// Point-in-time snapshot of a process's memory
+///
+/// Pages are shared with the source process until either side writes, so taking
+/// a checkpoint costs only metadata. The snapshot stays valid after the source
+/// process mutates or frees its memory.
+#[derive(Debug)]
+pub struct MemorySnapshot {
+    source_pid: Pid,
+    taken_at: SystemTime,
+    regions: Vec<SnapshotRegion>,
+}
+
+impl MemorySnapshot {
+    /// PID the snapshot was taken from
+    pub fn source_pid(&self) -> Pid {
+        self.source_pid
+    }
+
+    /// Time the snapshot was taken
+    pub fn taken_at(&self) -> SystemTime {
+        self.taken_at
+    }
+
+    /// Number of captured blocks
+    pub fn block_count(&self) -> usize {
+        self.regions.len()
+    }
+
+    /// Total bytes captured across all blocks
+    pub fn total_bytes(&self) -> Size {
+        self.regions.iter().map(|r| r.block.size).sum()
+    }
+}
+
+impl MemoryManager {
+    /// Checkpoint all memory owned by a process
+    ///
+    /// Uses the same CoW sharing as `fork_memory`: block contents are not copied
+    /// until the process (or a restored copy) writes to them.
+    pub fn checkpoint(&self, pid: Pid) -> MemorySnapshot {
+        let mut blocks: Vec<MemoryBlock> = self
+            .blocks
+            .iter()
+            .filter(|e| e.value().allocated && e.value().owner_pid == Some(pid))
+            .map(|e| e.value().clone())
+            .collect();
+        blocks.sort_by_key(|b| b.address);
+
+        let regions: Vec<SnapshotRegion> = blocks
+            .into_iter()
+            .map(|block| {
+                let data = self
+                    .memory_storage
+                    .get(&block.address)
+                    .map(|cow| cow.clone_cow());
+                SnapshotRegion { block, data }
+            })
+            .collect();
+
+        let snapshot = MemorySnapshot {
+            source_pid: pid,
+            taken_at: SystemTime::now(),
+            regions,
+        };
+
+        info!(
+            "Checkpointed {} blocks ({} bytes) for PID {}",
+            snapshot.block_count(),
+            snapshot.total_bytes(),
+            pid
+        );
+
+        snapshot
+    }
+
+    /// Restore a snapshot into a fresh process
+    ///
+    /// Blocks are allocated through the regular allocator, so memory limits and
+    /// per-process accounting apply. Contents stay CoW-shared with the snapshot,
+    /// which can be restored again. On failure any blocks already created for
+    /// `new_pid` by this call are released.
+    pub fn restore(&self, snapshot: &MemorySnapshot, new_pid: Pid) -> MemoryResult<()> {
+        let mut restored = Vec::with_capacity(snapshot.regions.len());
+
+        for region in &snapshot.regions {
+            let address = match self.allocate(region.block.size, new_pid) {
+                Ok(address) => address,
+                Err(e) => {
+                    warn!(
+                        "Restore of PID {} snapshot into PID {} failed after {} blocks: {}",
+                        snapshot.source_pid,
+                        new_pid,
+                        restored.len(),
+                        e
+                    );
+                    for address in restored {
+                        self.memory_storage.remove(&address);
+                        let _ = self.deallocate(address);
+                    }
+                    return Err(e);
+                }
+            };
+
+            // Recycled addresses may still carry stale contents from a previous owner
+            match &region.data {
+                Some(cow) => {
+                    self.memory_storage.insert(address, cow.clone_cow());
+                }
+                None => {
+                    self.memory_storage.remove(&address);
+                }
+            }
+            restored.push(address);
+        }
+
+        info!(
+            "Restored {} blocks ({} bytes) from PID {} snapshot into PID {}",
+            restored.len(),
+            snapshot.total_bytes(),
+            snapshot.source_pid,
+            new_pid
+        );
+
+        Ok(())
+    }
+}
@@ -3,7 +3,9 @@
  * Process-specific memory management and tracking
  */
 
+pub mod checkpoint;
 pub mod process_ops;
 pub mod tracking;
 
+pub use checkpoint::MemorySnapshot;
 pub use tracking::ProcessMemoryTracking;
@@ -3,66 +3,37 @@
  * Process-specific memory management and statistics
  */
 
-use super::super::core::{FreeBlock, MemoryBlock, MemoryStats, SegregatedFreeList};
+use super::super::core::{FreeBlock, MemoryBlock, MemoryStats};
 use super::super::MemoryManager;
+use crate::core::memory::{CowAccounting, CowStats};
 use crate::core::types::{Pid, Size};
 use log::info;
+use std::collections::HashMap;
 use std::sync::atomic::Ordering;
 
 impl MemoryManager {
-    /// Coalesce adjacent free blocks to reduce fragmentation
-    /// Works with segregated free lists by temporarily extracting all blocks
-    pub(in crate::memory::manager) fn coalesce_free_blocks(free_list: &mut SegregatedFreeList) {
-        if free_list.len() < 2 {
-            return;
-        }
-
-        // Extract all blocks and sort by address
-        let mut all_blocks = free_list.get_all_sorted();
-
-        // Coalesce adjacent blocks
-        let mut i = 0;
-        let mut coalesced_count = 0;
-        while i < all_blocks.len() - 1 {
-            let current_end = all_blocks[i].address + all_blocks[i].size;
-            let next_start = all_blocks[i + 1].address;
-
-            // If blocks are adjacent, merge them
-            if current_end == next_start {
-                let next_size = all_blocks[i + 1].size;
-                all_blocks[i].size += next_size;
-                all_blocks.remove(i + 1);
-                coalesced_count += 1;
-            } else {
-                i += 1;
-            }
-        }
-
-        if coalesced_count > 0 {
-            info!(
-                "Coalesced {} pairs of adjacent free blocks, reduced from {} to {} blocks",
-                coalesced_count,
-                free_list.len() + coalesced_count,
-                all_blocks.len()
-            );
-        }
-
-        // Reinsert all blocks into segregated lists
-        free_list.reinsert_all(all_blocks);
-    }
-
     /// Free all memory allocated to a specific process (called on process termination)
     pub fn free_process_memory(&self, pid: Pid) -> Size {
+        self.free_process_memory_by_tag(pid).values().sum()
+    }
+
+    /// Free all memory allocated to a process, returning bytes freed per allocation tag
+    ///
+    /// Untagged blocks are counted under [`MemoryBlock::UNTAGGED`].
+    pub fn free_process_memory_by_tag(&self, pid: Pid) -> HashMap<String, Size> {
         let mut freed_bytes = 0;
         let mut freed_count = 0;
         let mut freed_blocks = Vec::new();
+        let mut by_tag: HashMap<String, Size> = HashMap::new();
 
+        let gate = self.stats_gate.read();
         for mut entry in self.blocks.iter_mut() {
             let block = entry.value_mut();
             if block.allocated && block.owner_pid == Some(pid) {
                 block.allocated = false;
                 freed_bytes += block.size;
                 freed_count += 1;
+                *by_tag.entry(block.tag().to_string()).or_default() += block.size;
                 freed_blocks.push(FreeBlock {
                     address: block.address,
                     size: block.size,
@@ -73,31 +44,23 @@ pub fn free_process_memory(&self, pid: Pid) -> Size {
         if freed_bytes > 0 {
             self.used_memory
                 .fetch_sub(freed_bytes as u64, Ordering::SeqCst);
+            drop(gate);
+
+            for block in &freed_blocks {
+                self.scrub_freed(block.address, Some(pid));
+            }
 
             // Remove process tracking entry
             self.process_tracking.remove(&pid);
 
-            // Add freed blocks to segregated free list for recycling
+            // Return freed blocks to the allocation strategy for recycling
             {
-                match self.free_list.lock() {
-                    Ok(mut free_list) => {
-                        for block in freed_blocks {
-                            free_list.insert(block);
-                        }
-                        // Always coalesce after large batch frees
-                        Self::coalesce_free_blocks(&mut free_list);
-                    }
-                    Err(poisoned) => {
-                        // Mutex poisoned: thread panicked while holding lock
-                        // Attempt recovery by acquiring poisoned guard
-                        log::error!("Free list mutex poisoned during process {} cleanup - attempting recovery", pid);
-                        let mut free_list = poisoned.into_inner();
-                        for block in freed_blocks {
-                            free_list.insert(block);
-                        }
-                        Self::coalesce_free_blocks(&mut free_list);
-                    }
+                let mut strategy = self.lock_strategy("process cleanup");
+                for block in freed_blocks {
+                    strategy.dealloc(block.address, block.size);
                 }
+                // Always compact after large batch frees
+                strategy.compact();
             }
 
             // Track deallocated blocks for GC
@@ -108,7 +71,7 @@ pub fn free_process_memory(&self, pid: Pid) -> Size {
 
             let used = self.used_memory.load(Ordering::SeqCst);
             info!(
-                "Cleaned up {} bytes ({} blocks) from terminated PID {}, added to segregated free list ({} bytes now available, {} deallocated blocks)",
+                "Cleaned up {} bytes ({} blocks) from terminated PID {}, returned to allocation strategy ({} bytes now available, {} deallocated blocks)",
                 freed_bytes,
                 freed_count,
                 pid,
@@ -117,7 +80,7 @@ pub fn free_process_memory(&self, pid: Pid) -> Size {
             );
 
             // Trigger GC if threshold reached
-            let should_gc = dealloc_count >= self.gc_threshold as u64;
+            let should_gc = dealloc_count >= self.gc_threshold() as u64;
 
             if should_gc {
                 info!("GC threshold reached after process cleanup, running garbage collection...");
@@ -132,8 +95,9 @@ pub fn free_process_memory(&self, pid: Pid) -> Size {
                 );
             }
         }
+        self.zero_on_free_pids.remove(&pid);
 
-        freed_bytes
+        by_tag
     }
 
     /// Get memory statistics for a specific process
@@ -161,6 +125,46 @@ pub fn get_process_memory_details(&self, pid: Pid) -> (Size, Size, usize) {
         }
     }
 
+    /// Split a process's memory into (private, shared) bytes
+    ///
+    /// Blocks cloned by `fork_memory` stay shared until either side writes
+    /// to them; blocks never written are private.
+    pub fn get_process_sharing_details(&self, pid: Pid) -> (Size, Size) {
+        let mut private = 0;
+        let mut shared = 0;
+        for entry in self.blocks.iter() {
+            let block = entry.value();
+            if !block.allocated || block.owner_pid != Some(pid) {
+                continue;
+            }
+            let is_shared = self
+                .memory_storage
+                .get(entry.key())
+                .is_some_and(|cow| cow.is_shared());
+            if is_shared {
+                shared += block.size;
+            } else {
+                private += block.size;
+            }
+        }
+        (private, shared)
+    }
+
+    /// Copy-on-write sharing across all live blocks
+    pub fn cow_stats(&self) -> CowStats {
+        let mut accounting = CowAccounting::default();
+        for entry in self.memory_storage.iter() {
+            let live = self
+                .blocks
+                .get(entry.key())
+                .is_some_and(|block| block.allocated);
+            if live {
+                accounting.record(entry.value());
+            }
+        }
+        accounting.finish()
+    }
+
     /// Get overall memory info: (total, used, available)
     pub fn info(&self) -> (Size, Size, Size) {
         let used = self.used_memory.load(Ordering::SeqCst) as usize;
@@ -168,6 +172,12 @@ pub fn info(&self) -> (Size, Size, Size) {
     }
 
     /// Get detailed memory statistics
+    ///
+    /// Lock-free and cheap enough for frequent polling, but not a snapshot:
+    /// allocations racing with the scan can make `used_memory` disagree with
+    /// the block counts, or briefly exceed `total_memory` while a failing
+    /// allocation backs out. Use [`stats_consistent`](Self::stats_consistent)
+    /// when the figures must add up.
     pub fn stats(&self) -> MemoryStats {
         use crate::core::optimization::prefetch_read;
 
@@ -196,6 +206,38 @@ pub fn stats(&self) -> MemoryStats {
             usage_percentage: (used as f64 / self.total_memory as f64) * 100.0,
             allocated_blocks,
             fragmented_blocks,
+            allocator_strategy: self.lock_strategy("stats").name().to_string(),
+        }
+    }
+
+    /// Get memory statistics from a consistent snapshot
+    ///
+    /// Briefly blocks allocation and deallocation while the block map is
+    /// scanned, so `used_memory` never exceeds `total_memory` and matches the
+    /// block counts. Costs a pause proportional to the number of blocks;
+    /// prefer [`stats`](Self::stats) for routine polling.
+    pub fn stats_consistent(&self) -> MemoryStats {
+        let (used, allocated_blocks, fragmented_blocks) = {
+            let _gate = self.stats_gate.write();
+            let used = self.used_memory.load(Ordering::SeqCst) as usize;
+            let (allocated, fragmented) =
+                self.blocks
+                    .iter()
+                    .fold((0, 0), |(allocated, fragmented), entry| {
+                        if entry.value().allocated {
+                            (allocated + 1, fragmented)
+                        } else {
+                            (allocated, fragmented + 1)
+                        }
+                    });
+            (used, allocated, fragmented)
+        };
+
+        MemoryStats {
+            allocated_blocks,
+            fragmented_blocks,
+            allocator_strategy: self.lock_strategy("stats").name().to_string(),
+            ..MemoryStats::new(self.total_memory, used)
         }
     }
 
@@ -5,10 +5,27 @@
 
 use super::super::core::{MemoryError, MemoryResult};
 use super::super::MemoryManager;
-use crate::core::types::{Address, Size};
+use crate::core::types::{Address, Pid, Size};
 use log::info;
 
 impl MemoryManager {
+    /// Zero a freed block's storage if zero-on-free applies to its owner
+    ///
+    /// Must run before the address goes back to the allocation strategy, or
+    /// a concurrent allocation could see the old contents.
+    pub(in crate::memory::manager) fn scrub_freed(&self, address: Address, pid: Option<Pid>) {
+        let scrub = match pid {
+            Some(pid) => self.zeroes_on_free(pid),
+            None => self.zero_on_free(),
+        };
+        if !scrub {
+            return;
+        }
+        if let Some(mut storage) = self.memory_storage.get_mut(&address) {
+            storage.zero();
+        }
+    }
+
     /// Write bytes to a memory address
     /// This simulates writing to physical memory for shared memory segments
     pub fn write_bytes(&self, address: Address, data: &[u8]) -> MemoryResult<()> {
@@ -31,18 +48,19 @@ pub fn write_bytes(&self, address: Address, data: &[u8]) -> MemoryResult<()> {
         if let Some(base_addr) = base_addr {
             let offset = address - base_addr;
 
+            // Paged so a write to a forked block copies only what it touches
             let mut entry = self.memory_storage.entry(base_addr).or_insert_with(|| {
+                use crate::core::limits::COW_PAGE_SIZE;
                 use crate::core::memory::CowMemory;
-                CowMemory::new(vec![0u8; block_size])
+                CowMemory::with_page_size(vec![0u8; block_size], COW_PAGE_SIZE)
             });
 
-            entry.write(|buffer| {
-                if buffer.len() < block_size {
-                    buffer.resize(block_size, 0u8);
-                }
-                let end = offset + data.len();
-                buffer[offset..end].copy_from_slice(data);
-            });
+            if entry.len() < block_size {
+                entry.write(|buffer| buffer.resize(block_size, 0u8));
+            }
+            entry
+                .write_at(offset, data)
+                .map_err(|_| MemoryError::InvalidAddress(address))?;
 
             info!(
                 "Wrote {} bytes to address 0x{:x} (offset {} in block at 0x{:x})",
@@ -77,16 +95,12 @@ pub fn read_bytes(&self, address: Address, size: Size) -> MemoryResult<Vec<u8>>
         if let Some(base_addr) = base_addr {
             let offset = address - base_addr;
 
-            let data = if let Some(cow_mem) = self.memory_storage.get(&base_addr) {
-                cow_mem.read(|buffer| {
-                    let end = offset + size;
-                    let mut result = vec![0u8; size];
-                    result.copy_from_slice(&buffer[offset..end]);
-                    result
-                })
-            } else {
-                vec![0u8; size]
-            };
+            let mut data = vec![0u8; size];
+            if let Some(cow_mem) = self.memory_storage.get(&base_addr) {
+                cow_mem
+                    .read_at(offset, &mut data)
+                    .map_err(|_| MemoryError::InvalidAddress(address))?;
+            }
 
             info!(
                 "Read {} bytes from address 0x{:x} (offset {} in block at 0x{:x})",
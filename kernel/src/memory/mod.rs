@@ -11,7 +11,10 @@
 // Re-export for convenience
 pub use gc::{GcStats, GcStrategy, GlobalGarbageCollector};
 pub use manager::{
-    AllocationRequest, Allocator, GarbageCollector, MemoryBlock, MemoryError, MemoryGuardExt,
-    MemoryInfo, MemoryManager, MemoryPressure, MemoryResult, MemoryStats, ProcessMemoryCleanup,
-    ProcessMemoryStats,
+    AllocationRequest, AllocationStrategy, Allocator, AllocatorStats, GarbageCollector,
+    LargestProcess, LowestPriority, MemoryBlock, MemoryError, MemoryGuardExt, MemoryInfo,
+    MemoryManager, MemoryPressure, MemoryResult, MemorySnapshot, MemoryStats, MemoryStatsDelta,
+    OomCandidate, OomPolicy, PressureCallback, ProcessMemoryCleanup, ProcessMemoryStats,
+    ProcessMemoryStatsDelta, Reservation, SegregatedFreeList, SlabAllocator, VictimSelector,
+    OOM_SCORE_ADJ_MAX, OOM_SCORE_ADJ_MIN,
 };
@@ -144,6 +144,25 @@ pub fn execute(&self, events: &[Event]) -> QueryResult {
         }
     }
 
+    /// Rank PIDs by how many matching events they produced
+    ///
+    /// Returns at most `n` `(pid, count)` pairs, highest count first. Ties
+    /// are broken by ascending PID so results are stable across calls.
+    /// Events without a PID are ignored.
+    pub fn top_pids(&self, events: &[Event], n: usize) -> Vec<(Pid, u64)> {
+        let mut counts: HashMap<Pid, u64> = HashMap::new();
+        for event in events.iter().filter(|e| e.matches(&self.filter)) {
+            if let Some(pid) = event.pid {
+                *counts.entry(pid).or_insert(0) += 1;
+            }
+        }
+
+        let mut ranked: Vec<(Pid, u64)> = counts.into_iter().collect();
+        ranked.sort_unstable_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(&b.0)));
+        ranked.truncate(n);
+        ranked
+    }
+
     /// Count events by category
     fn count_by_category(events: &[Event]) -> Aggregation {
         let mut counts: HashMap<String, u64> = HashMap::new();
@@ -291,6 +310,28 @@ pub fn security_events() -> Query {
             .severity(Severity::Warn)
             .since(Duration::from_secs(3600)) // Last hour
     }
+
+    /// The `n` processes that produced the most events within `window`
+    pub fn top_processes_by_event_count(
+        events: &[Event],
+        window: Duration,
+        n: usize,
+    ) -> Vec<(Pid, u64)> {
+        Query::new().since(window).top_pids(events, n)
+    }
+
+    /// The `n` processes that produced the most `category` events within `window`
+    pub fn top_processes_by_category(
+        events: &[Event],
+        category: Category,
+        window: Duration,
+        n: usize,
+    ) -> Vec<(Pid, u64)> {
+        Query::new()
+            .category(category)
+            .since(window)
+            .top_pids(events, n)
+    }
 }
 
 /// Causality chain tracer
@@ -408,6 +449,74 @@ fn test_aggregation_by_category() {
         assert!(result.aggregations.contains_key("by_category"));
     }
 
+    fn syscall_event(pid: Pid) -> Event {
+        Event::new(
+            Severity::Info,
+            Category::Syscall,
+            Payload::SyscallExit {
+                name: "read".into(),
+                duration_us: 10,
+                result: crate::monitoring::events::SyscallResult::Success,
+            },
+        )
+        .with_pid(pid)
+    }
+
+    #[test]
+    fn test_top_processes_by_event_count() {
+        let mut events = create_test_events(); // 3 events from pid 100
+        for (pid, count) in [(7, 3), (3, 3), (9, 1), (5, 5)] {
+            events.extend((0..count).map(|_| syscall_event(pid)));
+        }
+
+        let window = Duration::from_secs(60);
+        let top = CommonQueries::top_processes_by_event_count(&events, window, 3);
+        assert_eq!(top, vec![(5, 5), (3, 3), (7, 3)]);
+
+        // Fewer distinct pids than requested
+        let all = CommonQueries::top_processes_by_event_count(&events, window, 10);
+        assert_eq!(all.len(), 5);
+        assert_eq!(all[3], (100, 3));
+        assert_eq!(all[4], (9, 1));
+    }
+
+    #[test]
+    fn test_top_processes_respects_window() {
+        let mut stale = syscall_event(42);
+        stale.timestamp_ns = 0;
+        let mut events = vec![stale; 10];
+
+        std::thread::sleep(Duration::from_millis(20));
+        events.push(syscall_event(5));
+
+        let top =
+            CommonQueries::top_processes_by_event_count(&events, Duration::from_millis(10), 2);
+        assert_eq!(top, vec![(5, 1)]);
+    }
+
+    #[test]
+    fn test_top_processes_by_category() {
+        let mut events = create_test_events();
+        events.push(syscall_event(8));
+        events.push(syscall_event(8));
+
+        let top = CommonQueries::top_processes_by_category(
+            &events,
+            Category::Syscall,
+            Duration::from_secs(60),
+            5,
+        );
+        assert_eq!(top, vec![(8, 2), (100, 1)]);
+
+        let none = CommonQueries::top_processes_by_category(
+            &events,
+            Category::Network,
+            Duration::from_secs(60),
+            5,
+        );
+        assert!(none.is_empty());
+    }
+
     #[test]
     fn test_causality_tracing() {
         let events = vec![
@@ -7,7 +7,8 @@
  */
 
 use crate::core::sync::lockfree::SeqlockStats;
-use std::sync::atomic::{AtomicU8, Ordering};
+use crate::monitoring::events::Category;
+use std::sync::atomic::{AtomicU32, AtomicU8, Ordering};
 use std::sync::Arc;
 
 /// Target overhead as percentage of CPU time (1-100)
@@ -16,6 +17,11 @@
 /// Sampling adjustment interval (number of events)
 use crate::core::limits::SAMPLING_ADJUSTMENT_INTERVAL as ADJUSTMENT_INTERVAL;
 
+/// Rates are resolved in parts per million so per-category rates below 1% work
+const RATE_SCALE: u32 = 1_000_000;
+
+const CATEGORY_COUNT: usize = Category::Resource as usize + 1;
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum SampleDecision {
     Accept,
@@ -29,11 +35,40 @@ struct SamplerCounters {
     accepted: u64,
 }
 
+/// Per-category clamp on the adaptive rate, in parts per million
+///
+/// The defaults (0 and `RATE_SCALE`) leave the adaptive rate untouched.
+struct CategoryBounds {
+    floor: AtomicU32,
+    ceiling: AtomicU32,
+}
+
+impl CategoryBounds {
+    const fn new() -> Self {
+        Self {
+            floor: AtomicU32::new(0),
+            ceiling: AtomicU32::new(RATE_SCALE),
+        }
+    }
+
+    fn set(&self, floor: u32, ceiling: u32) {
+        self.floor.store(floor, Ordering::Relaxed);
+        self.ceiling.store(ceiling, Ordering::Relaxed);
+    }
+
+    #[inline]
+    fn clamp(&self, rate: u32) -> u32 {
+        let floor = self.floor.load(Ordering::Relaxed);
+        let ceiling = self.ceiling.load(Ordering::Relaxed);
+        rate.max(floor).min(ceiling)
+    }
+}
+
 pub struct Sampler {
     rate: Arc<AtomicU8>,
     counters: SeqlockStats<SamplerCounters>,
     overhead_pct: Arc<AtomicU8>,
-    category_rates: [Arc<AtomicU8>; 9],
+    category_bounds: Arc<[CategoryBounds; CATEGORY_COUNT]>,
 }
 
 impl Sampler {
@@ -46,17 +81,7 @@ pub fn new() -> Self {
                 accepted: 0,
             }),
             overhead_pct: Arc::new(AtomicU8::new(0).into()),
-            category_rates: [
-                Arc::new(AtomicU8::new(100).into()),
-                Arc::new(AtomicU8::new(100).into()),
-                Arc::new(AtomicU8::new(100).into()),
-                Arc::new(AtomicU8::new(100).into()),
-                Arc::new(AtomicU8::new(100).into()),
-                Arc::new(AtomicU8::new(100).into()),
-                Arc::new(AtomicU8::new(100).into()),
-                Arc::new(AtomicU8::new(100).into()),
-                Arc::new(AtomicU8::new(100).into()),
-            ],
+            category_bounds: Arc::new([const { CategoryBounds::new() }; CATEGORY_COUNT]),
         }
     }
 
@@ -85,17 +110,25 @@ pub fn should_sample(&self) -> SampleDecision {
         SampleDecision::Accept
     }
 
-    /// Decide whether to sample for a specific category
+    /// Decide whether to sample an event of `category`
+    ///
+    /// Uses the adaptive rate clamped to the category's configured bounds,
+    /// so a category pinned at 1.0 is always accepted.
     #[inline]
-    pub fn should_sample_category(&self, category_idx: usize) -> SampleDecision {
-        if category_idx >= self.category_rates.len() {
-            return self.should_sample();
+    pub fn should_sample_category(&self, category: Category) -> SampleDecision {
+        let evaluated = self.counters.write_batch(|c| {
+            c.evaluated += 1;
+            c.evaluated
+        });
+
+        if evaluated.is_multiple_of(ADJUSTMENT_INTERVAL) {
+            self.adjust_rate();
         }
 
-        let rate = self.category_rates[category_idx].load(Ordering::Relaxed);
+        let rate = self.category_rate_ppm(category);
 
-        if rate < 100 {
-            let random = self.fast_random() % 100;
+        if rate < RATE_SCALE {
+            let random = self.fast_random() % RATE_SCALE as u64;
             if random >= rate as u64 {
                 return SampleDecision::Reject;
             }
@@ -135,11 +168,35 @@ fn adjust_rate(&self) {
         self.rate.store(new_rate, Ordering::Relaxed);
     }
 
-    /// Set category sampling rate manually
-    pub fn set_category_rate(&self, category_idx: usize, rate: u8) {
-        if category_idx < self.category_rates.len() {
-            self.category_rates[category_idx].store(rate.min(100), Ordering::Relaxed);
-        }
+    /// Pin a category's sampling rate (0.0 to 1.0), overriding adaptation
+    pub fn set_category_rate(&self, category: Category, rate: f64) {
+        self.set_category_bounds(category, rate, rate);
+    }
+
+    /// Let the adaptive rate move for a category only within `floor..=ceiling`
+    ///
+    /// Rates are fractions from 0.0 to 1.0. A floor of 1.0 keeps every event
+    /// of the category; a ceiling of 0.01 keeps at most 1%.
+    pub fn set_category_bounds(&self, category: Category, floor: f64, ceiling: f64) {
+        let floor = to_ppm(floor);
+        let ceiling = to_ppm(ceiling).max(floor);
+        self.category_bounds[category as usize].set(floor, ceiling);
+    }
+
+    /// Return a category to the uniform adaptive rate
+    pub fn clear_category_rate(&self, category: Category) {
+        self.category_bounds[category as usize].set(0, RATE_SCALE);
+    }
+
+    /// Effective sampling rate for a category (0.0 to 1.0)
+    pub fn category_rate(&self, category: Category) -> f64 {
+        self.category_rate_ppm(category) as f64 / RATE_SCALE as f64
+    }
+
+    #[inline]
+    fn category_rate_ppm(&self, category: Category) -> u32 {
+        let adaptive = self.rate.load(Ordering::Relaxed) as u32 * (RATE_SCALE / 100);
+        self.category_bounds[category as usize].clamp(adaptive)
     }
 
     /// Get current sampling rate
@@ -202,21 +259,21 @@ fn clone(&self) -> Self {
             rate: Arc::clone(&self.rate),
             counters: self.counters.clone(),
             overhead_pct: Arc::clone(&self.overhead_pct),
-            category_rates: [
-                Arc::clone(&self.category_rates[0]),
-                Arc::clone(&self.category_rates[1]),
-                Arc::clone(&self.category_rates[2]),
-                Arc::clone(&self.category_rates[3]),
-                Arc::clone(&self.category_rates[4]),
-                Arc::clone(&self.category_rates[5]),
-                Arc::clone(&self.category_rates[6]),
-                Arc::clone(&self.category_rates[7]),
-                Arc::clone(&self.category_rates[8]),
-            ],
+            category_bounds: Arc::clone(&self.category_bounds),
         }
     }
 }
 
+/// Convert a 0.0-1.0 rate to parts per million, treating NaN as 0
+#[inline]
+fn to_ppm(rate: f64) -> u32 {
+    if rate.is_nan() {
+        0
+    } else {
+        (rate.clamp(0.0, 1.0) * RATE_SCALE as f64).round() as u32
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -259,14 +316,14 @@ fn test_acceptance_rate() {
     fn test_category_sampling() {
         let sampler = Sampler::new();
 
-        // Set low rate for category 0
-        sampler.set_category_rate(0, 10);
+        // Set low rate for one category
+        sampler.set_category_rate(Category::Process, 0.1);
 
         let mut accepted = 0;
         let total = 1000;
 
         for _ in 0..total {
-            if sampler.should_sample_category(0) == SampleDecision::Accept {
+            if sampler.should_sample_category(Category::Process) == SampleDecision::Accept {
                 accepted += 1;
             }
         }
@@ -276,6 +333,49 @@ fn test_category_sampling() {
         assert!(rate > 0.05 && rate < 0.20, "Rate: {}", rate);
     }
 
+    #[test]
+    fn test_security_never_sampled_out() {
+        let sampler = Sampler::new();
+        sampler.set_category_rate(Category::Security, 1.0);
+        sampler.set_category_rate(Category::Syscall, 0.01);
+
+        // Drive the adaptive rate to its minimum
+        for _ in 0..10 {
+            sampler.update_overhead(100);
+        }
+        assert_eq!(sampler.rate(), 1);
+
+        for _ in 0..10_000 {
+            assert_eq!(
+                sampler.should_sample_category(Category::Security),
+                SampleDecision::Accept
+            );
+        }
+        assert_eq!(sampler.category_rate(Category::Security), 1.0);
+        assert_eq!(sampler.category_rate(Category::Syscall), 0.01);
+    }
+
+    #[test]
+    fn test_category_bounds_clamp_adaptive_rate() {
+        let sampler = Sampler::new();
+        sampler.set_category_bounds(Category::Network, 0.25, 0.5);
+
+        // Ceiling applies while the adaptive rate is high
+        assert_eq!(sampler.category_rate(Category::Network), 0.5);
+        // Unconfigured categories follow the adaptive rate
+        assert_eq!(sampler.category_rate(Category::Memory), 1.0);
+
+        for _ in 0..10 {
+            sampler.update_overhead(100);
+        }
+        // Floor applies once adaptation drops below it
+        assert_eq!(sampler.category_rate(Category::Network), 0.25);
+        assert_eq!(sampler.category_rate(Category::Memory), 0.01);
+
+        sampler.clear_category_rate(Category::Network);
+        assert_eq!(sampler.category_rate(Category::Network), 0.01);
+    }
+
     #[test]
     fn test_reset() {
         let sampler = Sampler::new();
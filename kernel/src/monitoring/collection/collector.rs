@@ -5,11 +5,14 @@
  * Integrates: events, metrics, tracing, sampling, anomaly detection
  */
 
+use crate::core::limits::EVENT_RING_SIZE;
+use crate::core::serialization::json;
 use crate::core::types::Pid;
 use crate::monitoring::analysis::{Detector, Query, QueryResult, SampleDecision, Sampler};
 use crate::monitoring::events::{Category, Event, Payload, Severity, SyscallResult};
 use crate::monitoring::metrics::{MetricsCollector, MetricsSnapshot};
 use crate::monitoring::streaming::{EventStream, StreamStats, Subscriber};
+use std::io::{self, BufRead, Write};
 use std::sync::Arc;
 
 /// Unified observability collector
@@ -42,11 +45,64 @@ pub fn new() -> Self {
         }
     }
 
+    /// Rebuild a collector from a captured event log
+    ///
+    /// Events are replayed verbatim into a fresh ring, bypassing sampling
+    /// and anomaly detection, so timestamps and causality IDs are exactly
+    /// as captured and queries run against historical state. Metrics are
+    /// rebuilt from the replayed events, and later causal emits continue
+    /// past the highest replayed causality ID. Only the newest
+    /// `EVENT_RING_SIZE` events fit in the ring; older ones are skipped.
+    pub fn from_events(events: Vec<Event>) -> Self {
+        let collector = Self::new();
+        let skip = events.len().saturating_sub(EVENT_RING_SIZE);
+        let mut next_causality = 1;
+
+        for event in events.into_iter().skip(skip) {
+            if let Some(causality_id) = event.causality_id {
+                next_causality = next_causality.max(causality_id.saturating_add(1));
+            }
+            collector.update_metrics(&event);
+            let _ = collector.stream.publish(event);
+        }
+
+        collector
+            .causality_gen
+            .store(next_causality, std::sync::atomic::Ordering::Relaxed);
+        collector
+    }
+
+    /// Rebuild a collector from NDJSON written by `flush_to`
+    ///
+    /// Blank lines are ignored; any malformed line fails the whole load.
+    pub fn load_from<R: BufRead>(reader: R) -> io::Result<Self> {
+        let mut events = Vec::new();
+        for line in reader.lines() {
+            let line = line?;
+            if line.trim().is_empty() {
+                continue;
+            }
+            let event = json::from_slice(line.as_bytes())
+                .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e.to_string()))?;
+            events.push(event);
+        }
+        Ok(Self::from_events(events))
+    }
+
     /// Emit an event (primary API)
     #[inline]
     pub fn emit(&self, event: Event) {
-        // Apply sampling
-        if self.sampler.should_sample() == SampleDecision::Reject {
+        // Syscall events follow the tracer's pid filter
+        if event.category == Category::Syscall {
+            if let Some(pid) = event.pid {
+                if !crate::monitoring::tracing::is_traced(pid) {
+                    return;
+                }
+            }
+        }
+
+        // Apply sampling (per-category overrides clamp the adaptive rate)
+        if self.sampler.should_sample_category(event.category) == SampleDecision::Reject {
             return;
         }
 
@@ -109,6 +165,22 @@ fn collect_events(&self, subscriber: &mut Subscriber) -> Vec<Event> {
         events
     }
 
+    /// Drain buffered events to `writer` as NDJSON, one event per line
+    ///
+    /// Intended for shutdown, so nothing still queued is lost on exit.
+    /// Returns the number of events written.
+    pub fn flush_to<W: Write>(&self, mut writer: W) -> io::Result<usize> {
+        let events = self.stream.drain();
+        for event in &events {
+            let line = json::to_vec(event)
+                .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e.to_string()))?;
+            writer.write_all(&line)?;
+            writer.write_all(b"\n")?;
+        }
+        writer.flush()?;
+        Ok(events.len())
+    }
+
     /// Get metrics snapshot
     pub fn metrics(&self) -> MetricsSnapshot {
         self.metrics.snapshot()
@@ -119,11 +191,22 @@ pub fn stream_stats(&self) -> StreamStats {
         self.stream.stats()
     }
 
+    /// Whether the event stream has stopped accepting events
+    #[inline]
+    pub fn is_stream_stalled(&self) -> bool {
+        self.stream.is_stalled()
+    }
+
     /// Get sampling rate
     pub fn sampling_rate(&self) -> u8 {
         self.sampler.rate()
     }
 
+    /// Pin the sampling rate (0.0 to 1.0) for one event category
+    pub fn set_category_sample_rate(&self, category: Category, rate: f64) {
+        self.sampler.set_category_rate(category, rate);
+    }
+
     /// Update sampling overhead estimate
     pub fn update_overhead(&self, overhead_pct: u8) {
         self.sampler.update_overhead(overhead_pct);
@@ -277,6 +360,7 @@ pub fn resource_cleanup(
                         Payload::ResourceLeaked {
                             resource: "cleanup_error".into(),
                             count: 1,
+                            tag: None,
                         },
                     )
                     .with_pid(pid),
@@ -285,6 +369,44 @@ pub fn resource_cleanup(
         }
     }
 
+    /// Record memory a process still held when it exited
+    ///
+    /// `leaked_by_tag` maps allocation tags to leaked bytes; the event names
+    /// the tag holding the most.
+    pub fn memory_leaked(
+        &self,
+        pid: Pid,
+        leaked_by_tag: &std::collections::HashMap<String, usize>,
+    ) {
+        let total: usize = leaked_by_tag.values().sum();
+        if total == 0 {
+            return;
+        }
+
+        let dominant = leaked_by_tag
+            .iter()
+            .max_by(|a, b| a.1.cmp(b.1).then_with(|| b.0.cmp(a.0)))
+            .map(|(tag, _)| tag.as_str().into());
+
+        self.emit(
+            Event::new(
+                Severity::Warn,
+                Category::Resource,
+                Payload::ResourceLeaked {
+                    resource: "memory".into(),
+                    count: total as u64,
+                    tag: dominant,
+                },
+            )
+            .with_pid(pid),
+        );
+
+        for (tag, bytes) in leaked_by_tag {
+            self.metrics
+                .inc_counter(&format!("memory.leaked_bytes.{}", tag), *bytes as f64);
+        }
+    }
+
     /// Record syscall execution
     pub fn syscall_exit(&self, pid: Pid, name: String, duration_us: u64, success: bool) {
         let result = if success {
@@ -307,6 +429,30 @@ pub fn syscall_exit(&self, pid: Pid, name: String, duration_us: u64, success: bo
         );
     }
 
+    /// Record a syscall that exceeded the slow threshold
+    pub fn syscall_slow(
+        &self,
+        pid: Pid,
+        name: String,
+        duration_ms: u64,
+        threshold_ms: u64,
+        args: Option<String>,
+    ) {
+        self.emit(
+            Event::new(
+                Severity::Warn,
+                Category::Syscall,
+                Payload::SyscallSlow {
+                    name: name.into(),
+                    duration_ms,
+                    threshold_ms,
+                    args: args.map(Into::into),
+                },
+            )
+            .with_pid(pid),
+        );
+    }
+
     /// Record memory pressure
     pub fn memory_pressure(&self, usage_pct: u8, available_mb: u64) {
         let severity = if usage_pct > 90 {
@@ -344,6 +490,7 @@ pub fn slow_operation(&self, operation: String, duration_ms: u64, p99_ms: u64) {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::monitoring::analysis::CausalityTracer;
 
     #[test]
     fn test_collector_emit() {
@@ -428,6 +575,92 @@ fn test_collector_query() {
         assert_eq!(result.count, 1);
     }
 
+    #[test]
+    fn test_collector_flush_to() {
+        let collector = Collector::new();
+
+        collector.process_created(123, "test".to_string(), 5);
+        collector.memory_pressure(85, 100);
+
+        let mut out = Vec::new();
+        assert_eq!(collector.flush_to(&mut out).unwrap(), 2);
+
+        let lines: Vec<Event> = out
+            .split(|b| *b == b'\n')
+            .filter(|line| !line.is_empty())
+            .map(|line| serde_json::from_slice(line).unwrap())
+            .collect();
+        assert_eq!(lines.len(), 2);
+        assert_eq!(lines[1].category, Category::Memory);
+        assert_eq!(collector.flush_to(&mut out).unwrap(), 0);
+    }
+
+    #[test]
+    fn test_collector_replay_round_trip() {
+        let live = Collector::new();
+
+        let root = live.emit_causal(
+            Event::new(
+                Severity::Info,
+                Category::Process,
+                Payload::ProcessCreated {
+                    name: "app".into(),
+                    priority: 5,
+                },
+            )
+            .with_pid(42),
+        );
+        live.emit_in_chain(
+            Event::new(
+                Severity::Info,
+                Category::Memory,
+                Payload::MemoryAllocated {
+                    size: 4096,
+                    region_id: 7,
+                },
+            )
+            .with_pid(42),
+            root,
+        );
+        live.memory_pressure(95, 10);
+        live.syscall_exit(42, "read".to_string(), 250, true);
+
+        let mut out = Vec::new();
+        assert_eq!(live.flush_to(&mut out).unwrap(), 4);
+        let captured: Vec<Event> = out
+            .split(|b| *b == b'\n')
+            .filter(|line| !line.is_empty())
+            .map(|line| serde_json::from_slice(line).unwrap())
+            .collect();
+
+        let replayed = Collector::load_from(out.as_slice()).unwrap();
+        let mut sub = replayed.subscribe();
+        let events = replayed.collect_events(&mut sub);
+        assert_eq!(events.len(), 4);
+        for (original, replay) in captured.iter().zip(&events) {
+            assert_eq!(original.timestamp_ns, replay.timestamp_ns);
+            assert_eq!(original.causality_id, replay.causality_id);
+            assert_eq!(original.pid, replay.pid);
+        }
+
+        // Queries and causality tracing work against the replayed state
+        let errors = Query::new().severity(Severity::Error).execute(&events);
+        assert_eq!(errors.count, 1);
+        let pid_events = Query::new().pid(42).execute(&events);
+        assert_eq!(pid_events.count, 3);
+        let root_cause = CausalityTracer::root_cause(&events, root).unwrap();
+        assert_eq!(root_cause.category, Category::Process);
+
+        // Metrics are rebuilt and new chains don't collide with old ones
+        assert!(replayed.metrics().counters.contains_key("syscall.total"));
+        let next = replayed.emit_causal(Event::new(
+            Severity::Info,
+            Category::Process,
+            Payload::ProcessTerminated { exit_code: Some(0) },
+        ));
+        assert!(next > root);
+    }
+
     #[test]
     fn test_collector_metrics_integration() {
         let collector = Collector::new();
@@ -82,6 +82,9 @@ pub enum Payload {
         name: InlineString,
         duration_ms: u64,
         threshold_ms: u64,
+        /// Bounded, redacted argument snapshot, when capture is enabled
+        #[serde(default)]
+        args: Option<InlineString>,
     },
 
     // Memory events
@@ -125,6 +128,10 @@ pub enum Payload {
         error: InlineString,
         retry_count: u8,
     },
+    NetworkThrottled {
+        bytes_per_sec: u64,
+        delay_ms: u64,
+    },
 
     // IPC events
     MessageSent {
@@ -153,6 +160,15 @@ pub enum Payload {
     SecurityViolation {
         description: InlineString,
     },
+    CapabilityDelegated {
+        to_pid: Pid,
+        capability: InlineString,
+    },
+    CapabilityRevoked {
+        capability: InlineString,
+        /// Revoked because the process that delegated it lost it
+        cascaded: bool,
+    },
 
     // Performance events
     OperationSlow {
@@ -178,11 +194,21 @@ pub enum Payload {
     ResourceLeaked {
         resource: InlineString,
         count: u64,
+        /// Allocation tag responsible for most of the leak, if known
+        #[serde(default)]
+        tag: Option<InlineString>,
     },
     ResourceReclaimed {
         resource: InlineString,
         count: u64,
     },
+    CircuitStateChanged {
+        resource: InlineString,
+        from: InlineString,
+        to: InlineString,
+        /// Times the circuit has opened so far
+        trips: u64,
+    },
 
     // Anomaly detection
     AnomalyDetected {
@@ -272,6 +298,14 @@ pub fn matches(&self, filter: &EventFilter) -> bool {
             }
         }
 
+        if filter.since_ns.is_some_and(|since| self.timestamp_ns < since) {
+            return false;
+        }
+
+        if filter.until_ns.is_some_and(|until| self.timestamp_ns > until) {
+            return false;
+        }
+
         true
     }
 }
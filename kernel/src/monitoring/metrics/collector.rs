@@ -3,6 +3,7 @@
  * Lightweight performance metrics collector
  */
 
+use crate::core::limits::MAX_TRACKED_SYSCALL_NAMES;
 use crate::core::serialization::serde::is_zero_u64;
 use crate::core::{ShardManager, WorkloadProfile};
 use ahash::RandomState;
@@ -33,6 +34,15 @@ pub struct MetricValue {
     pub timestamp: u64,
 }
 
+/// Label for syscalls recorded after the tracked-name cap is reached
+const OTHER_SYSCALL_LABEL: &str = "other";
+
+/// Syscall latency buckets in seconds (1µs to 1s)
+const SYSCALL_LATENCY_BUCKETS: [f64; 13] = [
+    0.000_001, 0.000_005, 0.000_01, 0.000_05, 0.000_1, 0.000_5, 0.001, 0.005, 0.01, 0.05, 0.1, 0.5,
+    1.0,
+];
+
 /// Histogram data structure
 #[derive(Debug, Clone)]
 struct Histogram {
@@ -70,7 +80,7 @@ fn percentile(&self, p: f64) -> f64 {
             return 0.0;
         }
 
-        let target = (self.count as f64 * p) as u64;
+        let target = (self.count as f64 * p).ceil() as u64;
         for (i, &count) in self.counts.iter().enumerate() {
             if count >= target {
                 return self.buckets[i];
@@ -89,6 +99,8 @@ pub struct MetricsCollector {
     counters: Arc<DashMap<String, f64, RandomState>>,
     gauges: Arc<DashMap<String, f64, RandomState>>,
     histograms: Arc<DashMap<String, Histogram, RandomState>>,
+    /// Latency per syscall name, bounded by MAX_TRACKED_SYSCALL_NAMES
+    syscalls: Arc<DashMap<&'static str, Histogram, RandomState>>,
     start_time: Instant,
 }
 
@@ -109,6 +121,11 @@ pub fn new() -> Self {
                 DashMap::with_capacity_and_hasher_and_shard_amount(0, RandomState::new(), shards)
                     .into(),
             ),
+            syscalls: Arc::new(DashMap::with_capacity_and_hasher_and_shard_amount(
+                0,
+                RandomState::new(),
+                shards,
+            )),
             start_time: Instant::now(),
         }
     }
@@ -146,6 +163,25 @@ pub fn record_duration(&self, name: &str, duration: Duration) {
         self.observe_histogram(name, duration.as_secs_f64());
     }
 
+    /// Record latency for a syscall by name
+    ///
+    /// Once MAX_TRACKED_SYSCALL_NAMES distinct names are tracked, new names
+    /// are lumped into "other".
+    pub fn record_syscall(&self, name: &'static str, duration: Duration) {
+        let label = if self.syscalls.contains_key(name)
+            || self.syscalls.len() < MAX_TRACKED_SYSCALL_NAMES
+        {
+            name
+        } else {
+            OTHER_SYSCALL_LABEL
+        };
+
+        self.syscalls
+            .entry(label)
+            .or_insert_with(|| Histogram::new(SYSCALL_LATENCY_BUCKETS.to_vec()))
+            .observe(duration.as_secs_f64());
+    }
+
     /// Get snapshot of all metrics
     pub fn snapshot(&self) -> MetricsSnapshot {
         let counters: HashMap<String, f64> = self
@@ -163,29 +199,20 @@ pub fn snapshot(&self) -> MetricsSnapshot {
         let histogram_stats: HashMap<String, HistogramStats> = self
             .histograms
             .iter()
-            .map(|entry| {
-                let name = entry.key();
-                let hist = entry.value();
-                let stats = HistogramStats {
-                    count: hist.count,
-                    sum: hist.sum,
-                    avg: if hist.count > 0 {
-                        hist.sum / hist.count as f64
-                    } else {
-                        0.0
-                    },
-                    p50: hist.percentile(0.50),
-                    p95: hist.percentile(0.95),
-                    p99: hist.percentile(0.99),
-                };
-                (name.clone(), stats)
-            })
+            .map(|entry| (entry.key().clone(), HistogramStats::from(entry.value())))
+            .collect();
+
+        let syscall_stats: HashMap<String, HistogramStats> = self
+            .syscalls
+            .iter()
+            .map(|entry| (entry.key().to_string(), HistogramStats::from(entry.value())))
             .collect();
 
         MetricsSnapshot {
             counters,
             gauges,
             histograms: histogram_stats,
+            syscalls: syscall_stats,
             uptime_secs: self.start_time.elapsed().as_secs(),
         }
     }
@@ -195,6 +222,7 @@ pub fn reset(&self) {
         self.counters.clear();
         self.gauges.clear();
         self.histograms.clear();
+        self.syscalls.clear();
     }
 }
 
@@ -217,6 +245,23 @@ pub struct HistogramStats {
     pub p99: f64,
 }
 
+impl From<&Histogram> for HistogramStats {
+    fn from(hist: &Histogram) -> Self {
+        Self {
+            count: hist.count,
+            sum: hist.sum,
+            avg: if hist.count > 0 {
+                hist.sum / hist.count as f64
+            } else {
+                0.0
+            },
+            p50: hist.percentile(0.50),
+            p95: hist.percentile(0.95),
+            p99: hist.percentile(0.99),
+        }
+    }
+}
+
 /// Snapshot of all metrics at a point in time
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
@@ -227,6 +272,9 @@ pub struct MetricsSnapshot {
     pub gauges: HashMap<String, f64>,
     #[serde(skip_serializing_if = "HashMap::is_empty")]
     pub histograms: HashMap<String, HistogramStats>,
+    /// Latency by syscall name (seconds); `sum` is total time spent
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub syscalls: HashMap<String, HistogramStats>,
     pub uptime_secs: u64,
 }
 
@@ -299,4 +347,41 @@ fn test_histogram() {
         assert_eq!(stats.count, 3);
         assert!((stats.avg - 0.2).abs() < 0.01);
     }
+
+    #[test]
+    fn test_syscall_latency_by_name() {
+        let collector = MetricsCollector::new();
+        collector.record_syscall("read_file", Duration::from_micros(40));
+        collector.record_syscall("read_file", Duration::from_micros(60));
+        collector.record_syscall("write_file", Duration::from_millis(2));
+
+        let snapshot = collector.snapshot();
+        let read = snapshot.syscalls.get("read_file").unwrap();
+        assert_eq!(read.count, 2);
+        assert!((read.sum - 0.0001).abs() < 1e-9);
+        assert_eq!(read.p99, 0.000_1);
+        assert_eq!(snapshot.syscalls.get("write_file").unwrap().p99, 0.005);
+    }
+
+    #[test]
+    fn test_syscall_names_capped() {
+        let collector = MetricsCollector::new();
+        let names: Vec<&'static str> = (0..MAX_TRACKED_SYSCALL_NAMES + 3)
+            .map(|i| &*Box::leak(format!("syscall_{}", i).into_boxed_str()))
+            .collect();
+        for name in &names {
+            collector.record_syscall(name, Duration::from_micros(1));
+        }
+
+        let snapshot = collector.snapshot();
+        assert_eq!(snapshot.syscalls.len(), MAX_TRACKED_SYSCALL_NAMES + 1);
+        assert_eq!(snapshot.syscalls.get("other").unwrap().count, 3);
+
+        // Already-tracked names keep their own entry
+        collector.record_syscall(names[0], Duration::from_micros(1));
+        assert_eq!(
+            collector.snapshot().syscalls.get(names[0]).unwrap().count,
+            2
+        );
+    }
 }
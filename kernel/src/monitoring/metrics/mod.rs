@@ -4,6 +4,7 @@
  */
 
 mod collector;
+mod prometheus;
 mod timeout;
 
 pub use collector::{MetricsCollector, MetricsSnapshot};
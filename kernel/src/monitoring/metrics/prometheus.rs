@@ -0,0 +1,393 @@
+/*!
+ * Prometheus Exposition
+ * Render a metrics snapshot in the Prometheus text format (version 0.0.4)
+ *
+ * Every metric is prefixed with `kernel_` and sanitized to the Prometheus
+ * name grammar. Histograms only keep quantiles, so they are exposed as
+ * summaries; syscall latencies share one summary family with a `syscall`
+ * label. Families are sorted by name so scrapes diff cleanly.
+ *
+ * Sanitizing can map distinct metrics onto one name (`a.b` and `a_b`), and
+ * a scrape must not declare a family twice. The first family to claim a
+ * name wins, built-in families first, then counters, gauges and histograms
+ * in name order; later collisions are left out.
+ */
+
+use super::collector::{HistogramStats, MetricsSnapshot};
+use std::collections::{HashMap, HashSet};
+use std::fmt::Write;
+
+/// Prefix for every exported metric name
+const NAMESPACE: &str = "kernel";
+
+/// Quantile label values paired with the stats they expose
+fn quantiles(stats: &HistogramStats) -> [(&'static str, f64); 3] {
+    [("0.5", stats.p50), ("0.95", stats.p95), ("0.99", stats.p99)]
+}
+
+/// Rough bytes per exported sample, used to size the output once
+const BYTES_PER_SAMPLE: usize = 96;
+
+impl MetricsSnapshot {
+    /// Render the snapshot in the Prometheus text exposition format
+    pub fn to_prometheus(&self) -> String {
+        let summaries = self.histograms.len() + self.syscalls.len();
+        let samples = self.counters.len() + self.gauges.len() + summaries * 5 + 1;
+        let mut out = String::with_capacity(samples * BYTES_PER_SAMPLE);
+
+        let syscall_family = "syscall_duration_seconds";
+        let uptime = "uptime_seconds";
+        let mut claimed = Claimed::default();
+        claimed.claim(uptime, false);
+        if !self.syscalls.is_empty() {
+            claimed.claim(syscall_family, true);
+        }
+
+        for (name, value) in sorted(&self.counters) {
+            if claimed.claim(name, false) {
+                write_header(&mut out, name, "counter", name);
+                write_sample(&mut out, name, "", &[], *value);
+            }
+        }
+
+        for (name, value) in sorted(&self.gauges) {
+            if claimed.claim(name, false) {
+                write_header(&mut out, name, "gauge", name);
+                write_sample(&mut out, name, "", &[], *value);
+            }
+        }
+
+        for (name, stats) in sorted(&self.histograms) {
+            if claimed.claim(name, true) {
+                write_header(&mut out, name, "summary", name);
+                write_summary(&mut out, name, None, stats);
+            }
+        }
+
+        if !self.syscalls.is_empty() {
+            write_header(
+                &mut out,
+                syscall_family,
+                "summary",
+                "Syscall latency in seconds",
+            );
+            for (syscall, stats) in sorted(&self.syscalls) {
+                write_summary(&mut out, syscall_family, Some(syscall), stats);
+            }
+        }
+
+        write_header(
+            &mut out,
+            uptime,
+            "gauge",
+            "Seconds since metrics collection began",
+        );
+        write_sample(&mut out, uptime, "", &[], self.uptime_secs as f64);
+
+        out
+    }
+}
+
+/// Exported sample names already taken by a family
+#[derive(Default)]
+struct Claimed(HashSet<String>);
+
+impl Claimed {
+    /// Claim every sample name of a family, or return false if any is taken
+    fn claim(&mut self, name: &str, summary: bool) -> bool {
+        let suffixes: &[&str] = if summary {
+            &["", "_sum", "_count"]
+        } else {
+            &[""]
+        };
+        let names: Vec<String> = suffixes
+            .iter()
+            .map(|suffix| {
+                let mut exported = String::with_capacity(NAMESPACE.len() + name.len() + 7);
+                write_name(&mut exported, name, suffix);
+                exported
+            })
+            .collect();
+        if names.iter().any(|exported| self.0.contains(exported)) {
+            return false;
+        }
+        self.0.extend(names);
+        true
+    }
+}
+
+/// Map entries ordered by key
+fn sorted<V>(map: &HashMap<String, V>) -> Vec<(&String, &V)> {
+    let mut entries: Vec<_> = map.iter().collect();
+    entries.sort_unstable_by(|a, b| a.0.cmp(b.0));
+    entries
+}
+
+/// Write `# HELP` and `# TYPE` lines for a family
+///
+/// Recorded metrics use their original, unsanitized name as help text.
+fn write_header(out: &mut String, name: &str, kind: &str, help: &str) {
+    out.push_str("# HELP ");
+    write_name(out, name, "");
+    out.push(' ');
+    escape_help(out, help);
+    out.push_str("\n# TYPE ");
+    write_name(out, name, "");
+    out.push(' ');
+    out.push_str(kind);
+    out.push('\n');
+}
+
+/// Write the quantile, `_sum` and `_count` samples of a summary
+fn write_summary(out: &mut String, name: &str, syscall: Option<&str>, stats: &HistogramStats) {
+    let syscall_label = syscall.map(|value| ("syscall", value));
+
+    for (quantile, value) in quantiles(stats) {
+        let labels: Vec<(&str, &str)> = syscall_label
+            .into_iter()
+            .chain(Some(("quantile", quantile)))
+            .collect();
+        write_sample(out, name, "", &labels, value);
+    }
+
+    let labels: Vec<(&str, &str)> = syscall_label.into_iter().collect();
+    write_sample(out, name, "_sum", &labels, stats.sum);
+    write_sample(out, name, "_count", &labels, stats.count as f64);
+}
+
+/// Write one sample line
+fn write_sample(out: &mut String, name: &str, suffix: &str, labels: &[(&str, &str)], value: f64) {
+    write_name(out, name, suffix);
+    if !labels.is_empty() {
+        out.push('{');
+        for (i, (label, value)) in labels.iter().enumerate() {
+            if i > 0 {
+                out.push(',');
+            }
+            out.push_str(label);
+            out.push_str("=\"");
+            escape_label_value(out, value);
+            out.push('"');
+        }
+        out.push('}');
+    }
+    out.push(' ');
+    write_value(out, value);
+    out.push('\n');
+}
+
+/// Write `kernel_<name><suffix>`, replacing characters Prometheus rejects
+fn write_name(out: &mut String, name: &str, suffix: &str) {
+    out.push_str(NAMESPACE);
+    out.push('_');
+    for c in name.chars() {
+        out.push(if c.is_ascii_alphanumeric() || c == '_' || c == ':' {
+            c
+        } else {
+            '_'
+        });
+    }
+    out.push_str(suffix);
+}
+
+/// Sample values, using the spellings Prometheus expects for non-finite values
+fn write_value(out: &mut String, value: f64) {
+    if value.is_nan() {
+        out.push_str("NaN");
+    } else if value.is_infinite() {
+        out.push_str(if value > 0.0 { "+Inf" } else { "-Inf" });
+    } else {
+        let _ = write!(out, "{}", value);
+    }
+}
+
+/// Escape backslash, double quote and newline in a label value
+fn escape_label_value(out: &mut String, value: &str) {
+    for c in value.chars() {
+        match c {
+            '\\' => out.push_str("\\\\"),
+            '"' => out.push_str("\\\""),
+            '\n' => out.push_str("\\n"),
+            c => out.push(c),
+        }
+    }
+}
+
+/// Escape backslash and newline in help text
+fn escape_help(out: &mut String, text: &str) {
+    for c in text.chars() {
+        match c {
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            c => out.push(c),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::monitoring::MetricsCollector;
+    use std::collections::HashSet;
+    use std::time::Duration;
+
+    /// Sample name, labels and value
+    type Sample = (String, Vec<(String, String)>, f64);
+
+    /// Parse a label set, undoing value escapes
+    fn parse_labels(mut rest: &str) -> (Vec<(String, String)>, &str) {
+        let mut labels = Vec::new();
+        while !rest.starts_with('}') {
+            let (name, after) = rest.split_once("=\"").expect("label without value");
+            let mut value = String::new();
+            let mut chars = after.char_indices();
+            let end = loop {
+                match chars.next().expect("unterminated label value") {
+                    (_, '\\') => match chars.next().unwrap().1 {
+                        'n' => value.push('\n'),
+                        c @ ('\\' | '"') => value.push(c),
+                        c => panic!("bad escape \\{}", c),
+                    },
+                    (i, '"') => break i,
+                    (_, c) => value.push(c),
+                }
+            };
+            labels.push((name.to_string(), value));
+            rest = &after[end + 1..];
+            rest = rest.strip_prefix(',').unwrap_or(rest);
+        }
+        (labels, &rest[1..])
+    }
+
+    /// Minimal text-format parser: every sample belongs to a declared family
+    /// and has a valid name, label set and value
+    fn parse(text: &str) -> Vec<Sample> {
+        let valid_name = |name: &str| {
+            !name.is_empty()
+                && !name.starts_with(|c: char| c.is_ascii_digit())
+                && name
+                    .chars()
+                    .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == ':')
+        };
+        let mut families = HashSet::new();
+        let mut samples = Vec::new();
+
+        for line in text.lines() {
+            if let Some(declaration) = line.strip_prefix("# TYPE ") {
+                let (name, kind) = declaration.split_once(' ').unwrap();
+                assert!(valid_name(name), "{}", line);
+                assert!(["counter", "gauge", "summary"].contains(&kind), "{}", line);
+                assert!(families.insert(name.to_string()), "duplicate {}", line);
+                continue;
+            }
+            if line.starts_with("# HELP ") {
+                continue;
+            }
+
+            let split = line.find(['{', ' ']).unwrap();
+            let name = &line[..split];
+            let (labels, rest) = if line[split..].starts_with('{') {
+                parse_labels(&line[split + 1..])
+            } else {
+                (Vec::new(), &line[split..])
+            };
+            assert!(valid_name(name), "{}", line);
+            let family = ["_sum", "_count"]
+                .iter()
+                .find_map(|suffix| name.strip_suffix(suffix))
+                .filter(|base| families.contains(*base))
+                .unwrap_or(name);
+            assert!(families.contains(family), "undeclared {}", line);
+
+            let value = match rest.trim_start() {
+                "+Inf" => f64::INFINITY,
+                "-Inf" => f64::NEG_INFINITY,
+                value => value.parse().expect(line),
+            };
+            samples.push((name.to_string(), labels, value));
+        }
+        samples
+    }
+
+    #[test]
+    fn test_snapshot_renders_parseable_text() {
+        let collector = MetricsCollector::new();
+        collector.inc_counter("requests.total", 3.0);
+        collector.set_gauge("queue_depth", 7.0);
+        collector.set_gauge("ratio", f64::INFINITY);
+        collector.observe_histogram("latency", 0.004);
+        collector.record_syscall("read_file", Duration::from_micros(40));
+        collector.record_syscall("write\"file\\\n", Duration::from_micros(8));
+
+        let mut snapshot = collector.snapshot();
+        snapshot.uptime_secs = 42;
+        let text = snapshot.to_prometheus();
+        let samples = parse(&text);
+
+        let find = |name: &str, labels: &[(&str, &str)]| {
+            samples
+                .iter()
+                .find(|(n, l, _)| {
+                    n == name
+                        && l.len() == labels.len()
+                        && l.iter().zip(labels).all(|(a, b)| a.0 == b.0 && a.1 == b.1)
+                })
+                .map(|sample| sample.2)
+        };
+
+        assert!(text.contains("# TYPE kernel_requests_total counter\n"));
+        assert_eq!(find("kernel_requests_total", &[]), Some(3.0));
+        assert_eq!(find("kernel_queue_depth", &[]), Some(7.0));
+        assert_eq!(find("kernel_ratio", &[]), Some(f64::INFINITY));
+        assert_eq!(find("kernel_latency", &[("quantile", "0.99")]), Some(0.005));
+        assert_eq!(find("kernel_latency_count", &[]), Some(1.0));
+        assert_eq!(find("kernel_uptime_seconds", &[]), Some(42.0));
+
+        // Label values round-trip through escaping
+        assert_eq!(
+            find(
+                "kernel_syscall_duration_seconds_count",
+                &[("syscall", "write\"file\\\n")]
+            ),
+            Some(1.0)
+        );
+        assert_eq!(
+            find(
+                "kernel_syscall_duration_seconds",
+                &[("syscall", "read_file"), ("quantile", "0.5")]
+            ),
+            Some(0.000_05)
+        );
+        // 1 counter, 2 gauges, 5 latency, 2 x 5 syscall, uptime
+        assert_eq!(samples.len(), 19);
+    }
+
+    #[test]
+    fn test_colliding_names_declared_once() {
+        let collector = MetricsCollector::new();
+        collector.inc_counter("cache.hits", 1.0);
+        collector.inc_counter("cache_hits", 2.0);
+        collector.set_gauge("cache-hits", 3.0);
+        collector.set_gauge("uptime_seconds", 4.0);
+        collector.inc_counter("latency_count", 5.0);
+        collector.observe_histogram("latency", 0.004);
+
+        let mut snapshot = collector.snapshot();
+        snapshot.uptime_secs = 42;
+        let samples = parse(&snapshot.to_prometheus());
+
+        let values = |name: &str| -> Vec<f64> {
+            samples
+                .iter()
+                .filter(|(n, _, _)| n == name)
+                .map(|sample| sample.2)
+                .collect()
+        };
+        // First counter in name order wins; the gauge is left out
+        assert_eq!(values("kernel_cache_hits"), vec![1.0]);
+        // Built-in families win over recorded metrics
+        assert_eq!(values("kernel_uptime_seconds"), vec![42.0]);
+        // The counter owns `_count`, so the summary is left out whole
+        assert_eq!(values("kernel_latency_count"), vec![5.0]);
+        assert!(values("kernel_latency").is_empty());
+    }
+}
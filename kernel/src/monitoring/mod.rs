@@ -43,7 +43,7 @@
 // Primary Event Streaming API
 pub use collection::Collector;
 pub use events::{Category, Event, EventFilter, Payload, Severity, SyscallResult};
-pub use streaming::{EventStream, StreamStats, Subscriber};
+pub use streaming::{EventStream, StreamStats, Subscriber, SubscriberLag};
 
 // Analysis API
 pub use analysis::{
@@ -56,9 +56,12 @@
 
 // Distributed Tracing API (complementary to Collector)
 pub use tracing::{
-    current_span, generate_trace_id, init_tracing, span_grpc, span_operation, span_syscall,
-    GrpcSpan, OperationSpan, SyscallSpan,
+    current_span, generate_trace_id, init_tracing, is_traced, set_trace_pids, span_grpc,
+    span_operation, span_syscall, trace_pids, GrpcSpan, OperationSpan, SyscallSpan,
 };
 
+#[cfg(feature = "otlp")]
+pub use tracing::{init_tracing_otlp, shutdown_tracing_otlp, OtlpInitError};
+
 // Bridge for integrating tracing with event streaming
 pub use collection::{emit_from_span, emit_from_span_with_pid, global_collector, init_collector};
@@ -6,12 +6,19 @@
  * Zero-copy where possible, bounded memory usage, automatic backpressure
  */
 
-use crate::core::limits::EVENT_RING_SIZE as RING_SIZE;
+use crate::core::limits::{EVENT_RING_SIZE as RING_SIZE, EVENT_STREAM_STALL_THRESHOLD};
 use crate::core::sync::lockfree::SeqlockStats;
-use crate::monitoring::events::{Event, EventFilter};
+use crate::core::InlineString;
+use crate::monitoring::events::{Category, Event, EventFilter, Payload, Severity};
+use ahash::RandomState;
 use crossbeam_queue::ArrayQueue;
-use std::sync::atomic::{AtomicUsize, Ordering};
-use std::sync::Arc;
+use dashmap::DashMap;
+use parking_lot::{Condvar, Mutex, MutexGuard};
+use std::collections::BinaryHeap;
+use std::sync::atomic::{AtomicBool, AtomicU64, AtomicUsize, Ordering};
+use std::sync::{Arc, LazyLock, Weak};
+use std::thread;
+use std::time::{Duration, Instant};
 
 #[repr(C, align(64))]
 #[derive(Debug, Clone, Copy)]
@@ -27,17 +34,114 @@ pub struct StreamStats {
     pub events_consumed: u64,
     pub events_dropped: u64,
     pub active_subscribers: usize,
+    pub stalled: bool,
+    /// Lag of each live subscriber, ordered by subscriber id
+    pub subscriber_lag: Vec<SubscriberLag>,
+}
+
+/// How far one subscriber trails the producers
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct SubscriberLag {
+    pub id: u64,
+    pub consumed: u64,
+    /// Events produced since the subscription that this handle hasn't consumed
+    pub lag: u64,
+}
+
+/// Per-subscriber consumption cursor, shared between the handle and the registry
+struct SubscriberCursor {
+    produced_at_subscribe: u64,
+    consumed: AtomicU64,
+}
+
+impl SubscriberCursor {
+    #[inline]
+    fn lag(&self, produced: u64) -> u64 {
+        produced
+            .saturating_sub(self.produced_at_subscribe)
+            .saturating_sub(self.consumed.load(Ordering::Relaxed))
+    }
+}
+
+/// Stall detection for a queue nobody is draining
+///
+/// After `threshold` consecutive failed publishes the stream is marked
+/// stalled and a single Critical event is parked in `alert`, a one-slot
+/// queue that bypasses the full ring. The flag clears once an event is
+/// consumed again.
+struct StallDetector {
+    threshold: u64,
+    consecutive_failures: AtomicU64,
+    stalled: AtomicBool,
+    alert: ArrayQueue<Event>,
+}
+
+impl StallDetector {
+    fn new(threshold: u64) -> Self {
+        Self {
+            threshold: threshold.max(1),
+            consecutive_failures: AtomicU64::new(0),
+            stalled: AtomicBool::new(false),
+            alert: ArrayQueue::new(1),
+        }
+    }
+
+    #[inline]
+    fn record_success(&self) {
+        // Load first so the common case stays a shared cache line
+        if self.consecutive_failures.load(Ordering::Relaxed) != 0 {
+            self.consecutive_failures.store(0, Ordering::Relaxed);
+        }
+    }
+
+    #[inline]
+    fn record_failure(&self) {
+        let failures = self.consecutive_failures.fetch_add(1, Ordering::Relaxed) + 1;
+        if failures >= self.threshold
+            && self
+                .stalled
+                .compare_exchange(false, true, Ordering::AcqRel, Ordering::Relaxed)
+                .is_ok()
+        {
+            // Payload strings are static, so raising the alert doesn't allocate
+            let _ = self.alert.push(Event::new(
+                Severity::Critical,
+                Category::Resource,
+                Payload::ResourceExhausted {
+                    resource: InlineString::from_static("event_stream"),
+                    limit: RING_SIZE as u64,
+                },
+            ));
+        }
+    }
+
+    #[inline]
+    fn record_consumed(&self) {
+        if self.stalled.load(Ordering::Relaxed) {
+            self.consecutive_failures.store(0, Ordering::Relaxed);
+            self.stalled.store(false, Ordering::Release);
+        }
+    }
 }
 
 pub struct EventStream {
     queue: Arc<ArrayQueue<Event>>,
     counters: SeqlockStats<StreamCounters>,
     subscribers: Arc<AtomicUsize>,
+    cursors: Arc<DashMap<u64, Arc<SubscriberCursor>, RandomState>>,
+    next_subscriber_id: Arc<AtomicU64>,
+    stall: Arc<StallDetector>,
 }
 
 impl EventStream {
     /// Create a new event stream
     pub fn new() -> Self {
+        Self::with_stall_threshold(EVENT_STREAM_STALL_THRESHOLD)
+    }
+
+    /// Create an event stream that reports a stall after `threshold`
+    /// consecutive failed publishes
+    pub fn with_stall_threshold(threshold: u64) -> Self {
         Self {
             queue: Arc::new(ArrayQueue::new(RING_SIZE).into()),
             counters: SeqlockStats::new(StreamCounters {
@@ -46,6 +150,9 @@ pub fn new() -> Self {
                 events_dropped: 0,
             }),
             subscribers: Arc::new(AtomicUsize::new(0).into()),
+            cursors: Arc::new(DashMap::with_hasher(RandomState::new())),
+            next_subscriber_id: Arc::new(AtomicU64::new(1)),
+            stall: Arc::new(StallDetector::new(threshold)),
         }
     }
 
@@ -55,33 +162,115 @@ pub fn publish(&self, event: Event) -> bool {
         match self.queue.push(event) {
             Ok(()) => {
                 self.counters.write(|c| c.events_produced += 1);
+                self.stall.record_success();
                 true
             }
             Err(_) => {
                 self.counters.write(|c| c.events_dropped += 1);
+                self.stall.record_failure();
                 false
             }
         }
     }
 
     /// Try to consume one event (lock-free)
+    ///
+    /// A pending stall alert is returned ahead of queued events.
     #[inline]
     pub fn try_consume(&self) -> Option<Event> {
+        if let Some(alert) = self.take_stall_alert() {
+            return Some(alert);
+        }
         self.queue.pop().map(|event| {
             self.counters.write(|c| c.events_consumed += 1);
+            self.stall.record_consumed();
             event
         })
     }
 
+    /// Take the pending stall alert, if one was raised and not yet seen
+    #[inline]
+    pub fn take_stall_alert(&self) -> Option<Event> {
+        self.stall.alert.pop()
+    }
+
+    /// Whether publishes have been failing persistently
+    ///
+    /// Set after a run of consecutive drops, cleared once events are
+    /// consumed again.
+    #[inline]
+    pub fn is_stalled(&self) -> bool {
+        self.stall.stalled.load(Ordering::Acquire)
+    }
+
+    /// Pop every event buffered at the time of the call (lock-free)
+    ///
+    /// Bounded by the queue length on entry, so producers publishing
+    /// concurrently can't keep the drain running; their events stay queued.
+    pub fn drain(&self) -> Vec<Event> {
+        let alert = self.take_stall_alert();
+        let pending = self.queue.len();
+        let mut events = Vec::with_capacity(pending + usize::from(alert.is_some()));
+        events.extend(alert);
+
+        let mut drained = 0u64;
+        while drained < pending as u64 {
+            match self.queue.pop() {
+                Some(event) => {
+                    events.push(event);
+                    drained += 1;
+                }
+                None => break,
+            }
+        }
+
+        if drained > 0 {
+            self.counters.write(|c| c.events_consumed += drained);
+            self.stall.record_consumed();
+        }
+        events
+    }
+
     /// Subscribe to event stream (returns a consumer handle)
+    ///
+    /// Subscribers share one queue, so each handle keeps its own cursor:
+    /// events published after it subscribed that it hasn't consumed count
+    /// as its lag, which singles out the consumer falling behind.
     pub fn subscribe(&self) -> Subscriber {
         self.subscribers.fetch_add(1, Ordering::Relaxed);
+        let id = self.next_subscriber_id.fetch_add(1, Ordering::Relaxed);
+        let cursor = Arc::new(SubscriberCursor {
+            produced_at_subscribe: self.counters.read().events_produced,
+            consumed: AtomicU64::new(0),
+        });
+        self.cursors.insert(id, Arc::clone(&cursor));
         Subscriber {
             stream: self.clone(),
+            id,
+            cursor,
             local_consumed: 0,
         }
     }
 
+    /// Lag of every live subscriber, ordered by subscriber id
+    pub fn subscriber_lag(&self) -> Vec<SubscriberLag> {
+        self.lag_at(self.counters.read().events_produced)
+    }
+
+    fn lag_at(&self, produced: u64) -> Vec<SubscriberLag> {
+        let mut lag: Vec<_> = self
+            .cursors
+            .iter()
+            .map(|entry| SubscriberLag {
+                id: *entry.key(),
+                consumed: entry.consumed.load(Ordering::Relaxed),
+                lag: entry.lag(produced),
+            })
+            .collect();
+        lag.sort_unstable_by_key(|l| l.id);
+        lag
+    }
+
     /// Get stream statistics
     pub fn stats(&self) -> StreamStats {
         let c = self.counters.read();
@@ -90,6 +279,8 @@ pub fn stats(&self) -> StreamStats {
             events_consumed: c.events_consumed,
             events_dropped: c.events_dropped,
             active_subscribers: self.subscribers.load(Ordering::Relaxed),
+            stalled: self.is_stalled(),
+            subscriber_lag: self.lag_at(c.events_produced),
         }
     }
 
@@ -112,6 +303,9 @@ fn clone(&self) -> Self {
             queue: Arc::clone(&self.queue),
             counters: self.counters.clone(),
             subscribers: Arc::clone(&self.subscribers),
+            cursors: Arc::clone(&self.cursors),
+            next_subscriber_id: Arc::clone(&self.next_subscriber_id),
+            stall: Arc::clone(&self.stall),
         }
     }
 }
@@ -125,6 +319,8 @@ fn default() -> Self {
 /// Event stream subscriber handle
 pub struct Subscriber {
     stream: EventStream,
+    id: u64,
+    cursor: Arc<SubscriberCursor>,
     local_consumed: u64,
 }
 
@@ -133,11 +329,19 @@ impl Subscriber {
     #[inline]
     pub fn next(&mut self) -> Option<Event> {
         self.stream.try_consume().map(|event| {
-            self.local_consumed += 1;
+            self.record_consumed();
             event
         })
     }
 
+    #[inline]
+    fn record_consumed(&mut self) {
+        self.local_consumed += 1;
+        self.cursor
+            .consumed
+            .store(self.local_consumed, Ordering::Relaxed);
+    }
+
     pub fn filter(&mut self, filter: &EventFilter) -> Vec<Event> {
         let mut events = Vec::with_capacity(32);
         let mut buffer = [None, None, None, None];
@@ -146,6 +350,7 @@ pub fn filter(&mut self, filter: &EventFilter) -> Vec<Event> {
         while let Some(event) = self.next() {
             buffer[idx % 4] = self.stream.queue.pop();
             if let Some(next_event) = &buffer[idx % 4] {
+                self.record_consumed();
                 crate::core::optimization::prefetch_read(next_event as *const Event);
             }
 
@@ -170,6 +375,18 @@ pub fn consumed(&self) -> u64 {
         self.local_consumed
     }
 
+    /// Id identifying this handle in `StreamStats::subscriber_lag`
+    #[inline]
+    pub fn id(&self) -> u64 {
+        self.id
+    }
+
+    /// Events produced since this handle subscribed that it hasn't consumed
+    #[inline]
+    pub fn lag(&self) -> u64 {
+        self.cursor.lag(self.stream.counters.read().events_produced)
+    }
+
     /// Get stream reference
     #[inline]
     pub fn stream(&self) -> &EventStream {
@@ -180,22 +397,162 @@ pub fn stream(&self) -> &EventStream {
 impl Drop for Subscriber {
     fn drop(&mut self) {
         self.stream.subscribers.fetch_sub(1, Ordering::Relaxed);
+        self.stream.cursors.remove(&self.id);
     }
 }
 
 /// Batch event publisher for high-throughput scenarios
+///
+/// Events are flushed when the batch reaches `capacity` or when its oldest
+/// event has waited `max_age`, whichever comes first, so low-rate producers
+/// still see bounded latency. Age flushes for every publisher run on one
+/// shared timer thread; if that thread can't be started, each push is
+/// published at once so the bound still holds.
 pub struct BatchPublisher {
-    stream: EventStream,
-    buffer: Vec<Event>,
+    shared: Arc<BatchShared>,
     capacity: usize,
 }
 
+struct BatchShared {
+    stream: EventStream,
+    batch: Mutex<Batch>,
+    max_age: Duration,
+}
+
+struct Batch {
+    events: Vec<Event>,
+    /// When the oldest buffered event was pushed
+    oldest: Option<Instant>,
+}
+
+impl BatchShared {
+    /// Publish buffered events; called with the batch locked so concurrent
+    /// flushes from the timer and the owner can't reorder events
+    ///
+    /// The stream is lossy: an event that doesn't fit is dropped and counted
+    /// in `events_dropped` rather than retried while the lock is held.
+    fn flush_locked(&self, batch: &mut Batch) {
+        batch.oldest = None;
+        let len = batch.events.len();
+
+        for (i, event) in batch.events.drain(..).enumerate() {
+            if i + 2 < len {
+                crate::core::optimization::prefetch_write(&event as *const Event);
+            }
+            self.stream.publish(event);
+        }
+    }
+
+    #[inline]
+    fn expired(&self, batch: &Batch) -> bool {
+        batch
+            .oldest
+            .is_some_and(|oldest| oldest.elapsed() >= self.max_age)
+    }
+
+    /// Flush if the oldest buffered event has waited `max_age`
+    ///
+    /// A deadline can outlive the batch it was armed for, so this re-checks
+    /// rather than flushing a younger batch early.
+    fn flush_expired(&self) {
+        let mut batch = self.batch.lock();
+        if self.expired(&batch) {
+            self.flush_locked(&mut batch);
+        }
+    }
+}
+
+/// A publisher's pending age deadline
+struct Deadline {
+    at: Instant,
+    batch: Weak<BatchShared>,
+}
+
+impl PartialEq for Deadline {
+    fn eq(&self, other: &Self) -> bool {
+        self.at == other.at
+    }
+}
+
+impl Eq for Deadline {}
+
+impl PartialOrd for Deadline {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Deadline {
+    /// Reversed so the max-heap yields the earliest deadline first
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        other.at.cmp(&self.at)
+    }
+}
+
+/// Timer shared by every batch publisher
+struct FlushTimer {
+    deadlines: Mutex<BinaryHeap<Deadline>>,
+    wake: Condvar,
+}
+
+/// Started on first use; `None` if the thread couldn't be spawned
+static FLUSH_TIMER: LazyLock<Option<Arc<FlushTimer>>> = LazyLock::new(|| {
+    let timer = Arc::new(FlushTimer {
+        deadlines: Mutex::new(BinaryHeap::new()),
+        wake: Condvar::new(),
+    });
+    let runner = Arc::clone(&timer);
+    thread::Builder::new()
+        .name("event-batch-flush".into())
+        .spawn(move || runner.run())
+        .ok()
+        .map(|_| timer)
+});
+
+impl FlushTimer {
+    fn schedule(&self, at: Instant, batch: Weak<BatchShared>) {
+        self.deadlines.lock().push(Deadline { at, batch });
+        self.wake.notify_one();
+    }
+
+    /// Sleep until the earliest deadline, flush its publisher, repeat
+    fn run(&self) {
+        let mut deadlines = self.deadlines.lock();
+        loop {
+            let Some(at) = deadlines.peek().map(|deadline| deadline.at) else {
+                self.wake.wait(&mut deadlines);
+                continue;
+            };
+            if at > Instant::now() {
+                self.wake.wait_until(&mut deadlines, at);
+                continue;
+            }
+
+            let due = deadlines.pop().expect("peeked deadline");
+            // Publishers lock their batch before scheduling, so flush with
+            // the queue unlocked; dropped publishers already flushed
+            MutexGuard::unlocked(&mut deadlines, || {
+                if let Some(batch) = due.batch.upgrade() {
+                    batch.flush_expired();
+                }
+            });
+        }
+    }
+}
+
 impl BatchPublisher {
-    /// Create a batch publisher with specified capacity
-    pub fn new(stream: EventStream, capacity: usize) -> Self {
+    /// Create a batch publisher that flushes at `capacity` events or after
+    /// `max_age`, whichever comes first
+    pub fn new(stream: EventStream, capacity: usize, max_age: Duration) -> Self {
         Self {
-            stream,
-            buffer: Vec::with_capacity(capacity),
+            shared: Arc::new(BatchShared {
+                stream,
+                batch: Mutex::new(Batch {
+                    events: Vec::with_capacity(capacity),
+                    oldest: None,
+                }),
+                max_age,
+            }),
             capacity,
         }
     }
@@ -203,26 +560,29 @@ pub fn new(stream: EventStream, capacity: usize) -> Self {
     /// Add event to batch
     #[inline]
     pub fn push(&mut self, event: Event) {
-        self.buffer.push(event);
-        if self.buffer.len() >= self.capacity {
-            self.flush();
+        let mut batch = self.shared.batch.lock();
+        batch.events.push(event);
+
+        // Also catches a timer that is running behind
+        if batch.events.len() >= self.capacity || self.shared.expired(&batch) {
+            self.shared.flush_locked(&mut batch);
+        } else if batch.oldest.is_none() {
+            let now = Instant::now();
+            match FLUSH_TIMER.as_ref() {
+                Some(timer) => {
+                    batch.oldest = Some(now);
+                    timer.schedule(now + self.shared.max_age, Arc::downgrade(&self.shared));
+                }
+                // Nothing would enforce the age bound, so don't buffer
+                None => self.shared.flush_locked(&mut batch),
+            }
         }
     }
 
     /// Flush buffered events to stream
     pub fn flush(&mut self) {
-        let events: Vec<_> = self.buffer.drain(..).collect();
-        let len = events.len();
-
-        for (i, event) in events.into_iter().enumerate() {
-            if i + 2 < len {
-                crate::core::optimization::prefetch_write(&event as *const Event);
-            }
-
-            while !self.stream.publish(event.clone()) {
-                std::hint::spin_loop();
-            }
-        }
+        let mut batch = self.shared.batch.lock();
+        self.shared.flush_locked(&mut batch);
     }
 }
 
@@ -293,10 +653,65 @@ fn test_subscriber() {
         assert_eq!(events.len(), 1);
     }
 
+    #[test]
+    fn test_subscriber_lag_is_tracked_per_handle() {
+        let stream = EventStream::new();
+        let event = || {
+            Event::new(
+                Severity::Info,
+                Category::Process,
+                Payload::ProcessCreated {
+                    name: "test".into(),
+                    priority: 5,
+                },
+            )
+        };
+
+        // Published before anyone subscribed, so it counts against no one
+        stream.publish(event());
+        stream.try_consume();
+
+        let mut fast = stream.subscribe();
+        let mut slow = stream.subscribe();
+        for _ in 0..10 {
+            stream.publish(event());
+            assert!(fast.next().is_some());
+        }
+        for _ in 0..5 {
+            stream.publish(event());
+        }
+        assert!(slow.next().is_some());
+
+        assert_eq!(fast.lag(), 5);
+        assert_eq!(slow.lag(), 14);
+
+        let lag = stream.stats().subscriber_lag;
+        assert_eq!(
+            lag,
+            vec![
+                SubscriberLag {
+                    id: fast.id(),
+                    consumed: 10,
+                    lag: 5,
+                },
+                SubscriberLag {
+                    id: slow.id(),
+                    consumed: 1,
+                    lag: 14,
+                },
+            ]
+        );
+
+        drop(slow);
+        let lag = stream.stats().subscriber_lag;
+        assert_eq!(lag.len(), 1);
+        assert_eq!(lag[0].id, fast.id());
+    }
+
     #[test]
     fn test_batch_publisher() {
         let stream = EventStream::new();
-        let mut batch = BatchPublisher::new(stream.clone(), 10);
+        let mut batch = BatchPublisher::new(stream.clone(), 10, Duration::from_secs(60));
 
         for i in 0..5 {
             batch.push(Event::new(
@@ -314,6 +729,166 @@ fn test_batch_publisher() {
         assert_eq!(stats.events_produced, 5);
     }
 
+    #[test]
+    fn test_batch_publisher_flushes_after_max_age() {
+        let stream = EventStream::new();
+        let mut batch = BatchPublisher::new(stream.clone(), 10, Duration::from_millis(50));
+
+        batch.push(Event::new(
+            Severity::Info,
+            Category::Process,
+            Payload::ProcessCreated {
+                name: "lonely".into(),
+                priority: 5,
+            },
+        ));
+        assert_eq!(stream.stats().events_produced, 0);
+
+        let deadline = Instant::now() + Duration::from_secs(5);
+        while stream.stats().events_produced == 0 && Instant::now() < deadline {
+            thread::sleep(Duration::from_millis(10));
+        }
+        assert_eq!(stream.stats().events_produced, 1);
+        assert_eq!(stream.drain().len(), 1);
+    }
+
+    #[test]
+    fn test_batch_publishers_share_the_flush_timer() {
+        let stream = EventStream::new();
+        let mut publishers: Vec<_> = (0..8)
+            .map(|_| BatchPublisher::new(stream.clone(), 10, Duration::from_millis(20)))
+            .collect();
+        for (i, batch) in publishers.iter_mut().enumerate() {
+            batch.push(Event::new(
+                Severity::Info,
+                Category::Process,
+                Payload::ProcessCreated {
+                    name: format!("test{}", i).into(),
+                    priority: 5,
+                },
+            ));
+        }
+
+        let deadline = Instant::now() + Duration::from_secs(5);
+        while stream.stats().events_produced < 8 && Instant::now() < deadline {
+            thread::sleep(Duration::from_millis(10));
+        }
+        assert_eq!(stream.stats().events_produced, 8);
+    }
+
+    #[test]
+    fn test_batch_flush_into_full_stream_drops_and_counts() {
+        let stream = EventStream::new();
+        let mut batch =
+            BatchPublisher::new(stream.clone(), RING_SIZE + 16, Duration::from_secs(60));
+        for i in 0..RING_SIZE + 16 {
+            batch.push(Event::new(
+                Severity::Info,
+                Category::Process,
+                Payload::ProcessCreated {
+                    name: format!("test{}", i).into(),
+                    priority: 5,
+                },
+            ));
+        }
+
+        // The capacity flush returned instead of spinning on the full ring
+        let stats = stream.stats();
+        assert_eq!(stats.events_produced, RING_SIZE as u64);
+        assert_eq!(stats.events_dropped, 16);
+    }
+
+    #[test]
+    fn test_drain() {
+        let stream = EventStream::new();
+        {
+            let mut batch = BatchPublisher::new(stream.clone(), 10, Duration::from_secs(60));
+            for i in 0..3 {
+                batch.push(Event::new(
+                    Severity::Info,
+                    Category::Process,
+                    Payload::ProcessCreated {
+                        name: format!("test{}", i).into(),
+                        priority: 5,
+                    },
+                ));
+            }
+        }
+
+        let events = stream.drain();
+        assert_eq!(events.len(), 3);
+        assert!(stream.drain().is_empty());
+        assert_eq!(stream.stats().events_consumed, 3);
+    }
+
+    #[test]
+    fn test_stall_detection() {
+        let stream = EventStream::with_stall_threshold(4);
+        let event = || {
+            Event::new(
+                Severity::Info,
+                Category::Process,
+                Payload::ProcessCreated {
+                    name: "test".into(),
+                    priority: 5,
+                },
+            )
+        };
+
+        while stream.publish(event()) {}
+        for _ in 0..2 {
+            assert!(!stream.publish(event()));
+        }
+        assert!(!stream.is_stalled());
+
+        for _ in 0..10 {
+            assert!(!stream.publish(event()));
+        }
+        assert!(stream.is_stalled());
+        assert!(stream.stats().stalled);
+
+        // Exactly one alert, delivered ahead of the full queue
+        let alert = stream.try_consume().unwrap();
+        assert_eq!(alert.severity, Severity::Critical);
+        assert!(matches!(alert.payload, Payload::ResourceExhausted { .. }));
+        assert!(stream.take_stall_alert().is_none());
+        assert!(stream.is_stalled());
+
+        // Consumption resumes
+        assert!(stream.try_consume().is_some());
+        assert!(!stream.is_stalled());
+        assert!(stream.publish(event()));
+    }
+
+    #[test]
+    fn test_stall_realerts_after_recovery() {
+        let stream = EventStream::with_stall_threshold(1);
+        let event = || {
+            Event::new(
+                Severity::Info,
+                Category::Memory,
+                Payload::MemoryPressure {
+                    usage_pct: 90,
+                    available_mb: 10,
+                },
+            )
+        };
+
+        while stream.publish(event()) {}
+        assert!(!stream.publish(event()));
+        assert!(stream.is_stalled());
+
+        // The alert leads the drain and clears the stall
+        let drained = stream.drain();
+        assert_eq!(drained[0].severity, Severity::Critical);
+        assert_eq!(drained.len(), RING_SIZE + 1);
+        assert!(!stream.is_stalled());
+
+        while stream.publish(event()) {}
+        assert!(!stream.publish(event()));
+        assert!(stream.take_stall_alert().is_some());
+    }
+
     #[test]
     fn test_backpressure() {
         let stream = EventStream::new();
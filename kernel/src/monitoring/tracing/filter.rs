@@ -0,0 +1,46 @@
+/*!
+ * Trace PID Filter
+ * Global allow-list restricting syscall tracing to selected processes
+ *
+ * An empty list traces every process. The list is consulted before a syscall
+ * span is created and before the Collector publishes a syscall event, so
+ * excluded processes cost one atomic load when no filter is set and one read
+ * lock when one is.
+ */
+
+use crate::core::types::Pid;
+use ahash::RandomState;
+use parking_lot::RwLock;
+use std::collections::HashSet;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::LazyLock;
+
+/// Set while the allow-list is non-empty, so the common case skips the lock
+static FILTER_ACTIVE: AtomicBool = AtomicBool::new(false);
+
+static TRACE_PIDS: LazyLock<RwLock<HashSet<Pid, RandomState>>> =
+    LazyLock::new(|| RwLock::new(HashSet::with_hasher(RandomState::new())));
+
+/// Only trace syscalls from `pids`; an empty slice traces every process
+pub fn set_trace_pids(pids: &[Pid]) {
+    let mut set = TRACE_PIDS.write();
+    set.clear();
+    set.extend(pids.iter().copied());
+    FILTER_ACTIVE.store(!set.is_empty(), Ordering::Release);
+}
+
+/// PIDs currently traced, sorted; empty when every process is traced
+pub fn trace_pids() -> Vec<Pid> {
+    let mut pids: Vec<Pid> = TRACE_PIDS.read().iter().copied().collect();
+    pids.sort_unstable();
+    pids
+}
+
+/// Whether syscalls from `pid` should be traced
+#[inline]
+pub fn is_traced(pid: Pid) -> bool {
+    if !FILTER_ACTIVE.load(Ordering::Acquire) {
+        return true;
+    }
+    TRACE_PIDS.read().contains(&pid)
+}
@@ -8,8 +8,22 @@
  * - Span hierarchies for complex operations
  * - Context propagation across async boundaries
  * - Performance metrics embedded in traces
+ * - Optional OTLP span export (`otlp` feature)
+ * - Per-process syscall filtering via `set_trace_pids`
  */
 
+mod filter;
+#[cfg(feature = "otlp")]
+mod otlp;
+
+pub use filter::{is_traced, set_trace_pids, trace_pids};
+
+#[cfg(feature = "otlp")]
+pub use otlp::{init_tracing_otlp, shutdown_tracing_otlp, OtlpInitError};
+
+#[cfg(feature = "otlp")]
+use otlp::with_trace_id;
+
 use std::time::Instant;
 use tracing::{debug, info, span, warn, Level, Span};
 use tracing_subscriber::{
@@ -72,32 +86,55 @@ pub fn generate_trace_id() -> String {
     Uuid::new_v4().to_string().into()
 }
 
+/// Without OTLP export there is no exported trace ID to align
+#[cfg(not(feature = "otlp"))]
+#[inline(always)]
+fn with_trace_id<R>(_trace_id: &str, create: impl FnOnce() -> R) -> R {
+    create()
+}
+
 /// Span for syscall tracing with rich structured fields
+///
+/// For processes excluded by `set_trace_pids` the span is disabled: nothing is
+/// created, recorded or logged.
 pub struct SyscallSpan {
     _span: tracing::Span,
     start: Instant,
     syscall_name: String,
     trace_id: String,
+    traced: bool,
 }
 
 impl SyscallSpan {
     pub fn new(syscall_name: &str, pid: u32) -> Self {
+        if !is_traced(pid) {
+            return Self {
+                _span: Span::none(),
+                start: Instant::now(),
+                syscall_name: String::new(),
+                trace_id: String::new(),
+                traced: false,
+            };
+        }
+
         let trace_id = generate_trace_id();
 
         // Create a tracing span with extensive structured fields
-        let span = span!(
-            Level::DEBUG,
-            "syscall",
-            trace_id = %trace_id,
-            syscall = syscall_name,
-            pid = pid,
-            duration_us = tracing::field::Empty,
-            duration_ms = tracing::field::Empty,
-            result = tracing::field::Empty,
-            error = tracing::field::Empty,
-            args_count = tracing::field::Empty,
-            return_value = tracing::field::Empty,
-        );
+        let span = with_trace_id(&trace_id, || {
+            span!(
+                Level::DEBUG,
+                "syscall",
+                trace_id = %trace_id,
+                syscall = syscall_name,
+                pid = pid,
+                duration_us = tracing::field::Empty,
+                duration_ms = tracing::field::Empty,
+                result = tracing::field::Empty,
+                error = tracing::field::Empty,
+                args_count = tracing::field::Empty,
+                return_value = tracing::field::Empty,
+            )
+        });
 
         let _entered = span.enter();
         debug!(syscall = syscall_name, pid = pid, "syscall started");
@@ -108,14 +145,20 @@ pub fn new(syscall_name: &str, pid: u32) -> Self {
             start: Instant::now(),
             syscall_name: syscall_name.into(),
             trace_id,
+            traced: true,
         }
     }
 
-    /// Get the trace ID for this syscall
+    /// Get the trace ID for this syscall (empty if the pid isn't traced)
     pub fn trace_id(&self) -> &str {
         &self.trace_id
     }
 
+    /// Whether this syscall's pid passed the trace filter
+    pub fn is_traced(&self) -> bool {
+        self.traced
+    }
+
     /// Record additional structured fields during syscall execution
     pub fn record<V: std::fmt::Debug>(&self, key: &str, value: V) {
         self._span.record(key, &format!("{:?}", value));
@@ -156,6 +199,9 @@ pub fn enter(&self) -> tracing::span::Entered<'_> {
 
 impl Drop for SyscallSpan {
     fn drop(&mut self) {
+        if !self.traced {
+            return;
+        }
         let duration = self.start.elapsed();
         let _entered = self._span.enter();
 
@@ -193,17 +239,19 @@ pub fn new(operation: &str) -> Self {
         let trace_id = generate_trace_id();
 
         // Create a tracing span for the operation with rich fields
-        let span = span!(
-            Level::DEBUG,
-            "operation",
-            trace_id = %trace_id,
-            operation = operation,
-            duration_us = tracing::field::Empty,
-            duration_ms = tracing::field::Empty,
-            result = tracing::field::Empty,
-            items_processed = tracing::field::Empty,
-            error = tracing::field::Empty,
-        );
+        let span = with_trace_id(&trace_id, || {
+            span!(
+                Level::DEBUG,
+                "operation",
+                trace_id = %trace_id,
+                operation = operation,
+                duration_us = tracing::field::Empty,
+                duration_ms = tracing::field::Empty,
+                result = tracing::field::Empty,
+                items_processed = tracing::field::Empty,
+                error = tracing::field::Empty,
+            )
+        });
 
         let _entered = span.enter();
         debug!(
@@ -293,18 +341,20 @@ impl GrpcSpan {
     pub fn new(method: &str) -> Self {
         let trace_id = generate_trace_id();
 
-        let span = span!(
-            Level::INFO,
-            "grpc_request",
-            trace_id = %trace_id,
-            method = method,
-            duration_us = tracing::field::Empty,
-            duration_ms = tracing::field::Empty,
-            status = tracing::field::Empty,
-            error = tracing::field::Empty,
-            request_size = tracing::field::Empty,
-            response_size = tracing::field::Empty,
-        );
+        let span = with_trace_id(&trace_id, || {
+            span!(
+                Level::INFO,
+                "grpc_request",
+                trace_id = %trace_id,
+                method = method,
+                duration_us = tracing::field::Empty,
+                duration_ms = tracing::field::Empty,
+                status = tracing::field::Empty,
+                error = tracing::field::Empty,
+                request_size = tracing::field::Empty,
+                response_size = tracing::field::Empty,
+            )
+        });
 
         let _entered = span.enter();
         info!(
@@ -0,0 +1,177 @@
+/*!
+ * OTLP Span Export
+ * Ships tracing spans to an OpenTelemetry collector (Jaeger, Tempo, etc.)
+ *
+ * Span fields declared by SyscallSpan/OperationSpan/GrpcSpan (pid, syscall,
+ * method, result, error, duration_us, ...) are exported as span attributes by
+ * the tracing-opentelemetry layer, including values recorded after creation.
+ */
+
+use opentelemetry::trace::{SpanId, TraceError, TraceId};
+use opentelemetry::KeyValue;
+use opentelemetry_otlp::WithExportConfig;
+use opentelemetry_sdk::trace::{IdGenerator, RandomIdGenerator};
+use opentelemetry_sdk::Resource;
+use std::cell::Cell;
+use thiserror::Error;
+use tracing::info;
+use tracing_subscriber::{
+    fmt::format::FmtSpan, layer::SubscriberExt, util::SubscriberInitExt, util::TryInitError,
+    EnvFilter,
+};
+use uuid::Uuid;
+
+/// Service name reported to the collector
+const SERVICE_NAME: &str = "ai-os-kernel";
+
+thread_local! {
+    /// Trace ID to hand out for the next root span created on this thread
+    static NEXT_TRACE_ID: Cell<Option<TraceId>> = const { Cell::new(None) };
+}
+
+/// OTLP initialization errors
+#[derive(Error, Debug)]
+pub enum OtlpInitError {
+    #[error("Failed to install OTLP exporter: {0}")]
+    Exporter(#[from] TraceError),
+
+    #[error("Failed to install tracing subscriber: {0}")]
+    Subscriber(#[from] TryInitError),
+}
+
+/// ID generator that reuses kernel trace IDs for root spans
+///
+/// Kernel trace IDs are UUIDv4s, which are 128 bits like OTLP trace IDs, so a
+/// root span exported to the collector carries the same ID that appears in
+/// local logs. Child spans inherit their parent's trace ID as usual.
+#[derive(Debug, Default)]
+struct KernelIdGenerator {
+    fallback: RandomIdGenerator,
+}
+
+impl IdGenerator for KernelIdGenerator {
+    fn new_trace_id(&self) -> TraceId {
+        NEXT_TRACE_ID
+            .with(Cell::take)
+            .unwrap_or_else(|| self.fallback.new_trace_id())
+    }
+
+    fn new_span_id(&self) -> SpanId {
+        self.fallback.new_span_id()
+    }
+}
+
+/// Run `create` with `trace_id` queued as the OTLP trace ID for a new root span
+pub(super) fn with_trace_id<R>(trace_id: &str, create: impl FnOnce() -> R) -> R {
+    let otel_id = Uuid::parse_str(trace_id)
+        .ok()
+        .map(|uuid| TraceId::from_bytes(*uuid.as_bytes()));
+    NEXT_TRACE_ID.with(|next| next.set(otel_id));
+
+    let result = create();
+
+    // Not consumed if the span had a parent or OTLP export isn't installed
+    NEXT_TRACE_ID.with(|next| next.set(None));
+    result
+}
+
+/// Initialize tracing with local output plus OTLP span export
+///
+/// Local output matches `init_tracing` (RUST_LOG and KERNEL_TRACE_JSON apply).
+/// Spans are batched and exported over gRPC to `endpoint`
+/// (e.g. `http://localhost:4317`). Must be called from within a Tokio runtime.
+pub fn init_tracing_otlp(endpoint: &str) -> Result<(), OtlpInitError> {
+    let tracer = opentelemetry_otlp::new_pipeline()
+        .tracing()
+        .with_exporter(
+            opentelemetry_otlp::new_exporter()
+                .tonic()
+                .with_endpoint(endpoint),
+        )
+        .with_trace_config(
+            opentelemetry_sdk::trace::config()
+                .with_id_generator(KernelIdGenerator::default())
+                .with_resource(Resource::new(vec![KeyValue::new(
+                    "service.name",
+                    SERVICE_NAME,
+                )])),
+        )
+        .install_batch(opentelemetry_sdk::runtime::Tokio)?;
+
+    let env_filter = EnvFilter::try_from_default_env().unwrap_or_else(|_| EnvFilter::new("info"));
+    let use_json = std::env::var("KERNEL_TRACE_JSON")
+        .map(|v| v == "1" || v == "true")
+        .unwrap_or(false);
+
+    let json_layer = use_json.then(|| {
+        tracing_subscriber::fmt::layer()
+            .json()
+            .with_target(true)
+            .with_thread_ids(true)
+            .with_thread_names(true)
+            .with_line_number(true)
+            .with_file(true)
+            .with_current_span(true)
+            .with_span_list(true)
+            .with_span_events(FmtSpan::FULL)
+    });
+    let compact_layer = (!use_json).then(|| {
+        tracing_subscriber::fmt::layer()
+            .with_target(true)
+            .with_thread_ids(true)
+            .with_thread_names(true)
+            .with_line_number(true)
+            .with_file(true)
+            .with_span_events(FmtSpan::CLOSE)
+            .compact()
+    });
+
+    tracing_subscriber::registry()
+        .with(env_filter)
+        .with(json_layer)
+        .with(compact_layer)
+        .with(tracing_opentelemetry::layer().with_tracer(tracer))
+        .try_init()?;
+
+    info!(
+        endpoint = endpoint,
+        "Structured tracing initialized with OTLP export"
+    );
+    Ok(())
+}
+
+/// Flush pending spans and shut down the OTLP exporter
+pub fn shutdown_tracing_otlp() {
+    opentelemetry::global::shutdown_tracer_provider();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_kernel_trace_id_reused_once() {
+        let generator = KernelIdGenerator::default();
+        let trace_id = Uuid::new_v4();
+
+        let (first, second) = with_trace_id(&trace_id.to_string(), || {
+            (generator.new_trace_id(), generator.new_trace_id())
+        });
+
+        assert_eq!(first, TraceId::from_bytes(*trace_id.as_bytes()));
+        assert_ne!(second, first);
+        assert_ne!(second, TraceId::INVALID);
+    }
+
+    #[test]
+    fn test_unconsumed_trace_id_cleared() {
+        let generator = KernelIdGenerator::default();
+        let trace_id = Uuid::new_v4();
+
+        with_trace_id(&trace_id.to_string(), || ());
+        assert_ne!(
+            generator.new_trace_id(),
+            TraceId::from_bytes(*trace_id.as_bytes())
+        );
+    }
+}
@@ -0,0 +1,180 @@
+/*!
+ * Denial Escalation
+ * Act on processes that keep getting denied
+ *
+ * Denials are counted per process over a sliding window. When a process
+ * reaches the threshold the configured action fires once; it can fire again
+ * only after the rate has dropped back below the threshold.
+ */
+
+use crate::core::limits::{
+    DENIAL_ESCALATION_THRESHOLD, DENIAL_ESCALATION_WINDOW, MAX_AUDIT_EVENTS_PER_PID,
+};
+use crate::core::types::Pid;
+use std::collections::VecDeque;
+use std::fmt;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+/// Callback invoked when a process crosses the denial threshold
+pub type EscalationHandler = Arc<dyn Fn(&Escalation) + Send + Sync>;
+
+/// What to do when a process crosses the denial threshold
+#[derive(Clone)]
+pub enum EscalationAction {
+    /// Emit a Critical SecurityViolation event
+    Alert,
+    /// Invoke a callback
+    Callback(EscalationHandler),
+    /// Queue a request for the process to be throttled
+    Throttle,
+    /// Queue a request for the process to be killed
+    Kill,
+}
+
+impl fmt::Debug for EscalationAction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Alert => f.write_str("Alert"),
+            Self::Callback(_) => f.write_str("Callback"),
+            Self::Throttle => f.write_str("Throttle"),
+            Self::Kill => f.write_str("Kill"),
+        }
+    }
+}
+
+/// Threshold, window and action for denial escalation
+#[derive(Debug, Clone)]
+pub struct DenialEscalationPolicy {
+    /// Denials within `window` that trigger the action
+    pub threshold: usize,
+    pub window: Duration,
+    pub action: EscalationAction,
+}
+
+impl DenialEscalationPolicy {
+    pub fn new(threshold: usize, window: Duration, action: EscalationAction) -> Self {
+        Self {
+            threshold: threshold.max(1),
+            window,
+            action,
+        }
+    }
+}
+
+impl Default for DenialEscalationPolicy {
+    fn default() -> Self {
+        Self::new(
+            DENIAL_ESCALATION_THRESHOLD,
+            DENIAL_ESCALATION_WINDOW,
+            EscalationAction::Alert,
+        )
+    }
+}
+
+/// A process that crossed the denial threshold
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Escalation {
+    pub pid: Pid,
+    /// Denials within the window when the threshold was crossed
+    pub denials: usize,
+    pub window: Duration,
+    pub kind: EscalationKind,
+}
+
+/// Action taken for an escalation, without the callback itself
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EscalationKind {
+    Alert,
+    Callback,
+    Throttle,
+    Kill,
+}
+
+impl From<&EscalationAction> for EscalationKind {
+    fn from(action: &EscalationAction) -> Self {
+        match action {
+            EscalationAction::Alert => Self::Alert,
+            EscalationAction::Callback(_) => Self::Callback,
+            EscalationAction::Throttle => Self::Throttle,
+            EscalationAction::Kill => Self::Kill,
+        }
+    }
+}
+
+/// Recent denial times for one process
+#[derive(Debug, Default)]
+pub(super) struct DenialWindow {
+    denials: VecDeque<Instant>,
+    /// Set once the action fires, cleared when the rate drops below threshold
+    escalated: bool,
+}
+
+impl DenialWindow {
+    /// Drop denials older than `window`
+    fn prune(&mut self, now: Instant, window: Duration) {
+        while let Some(&oldest) = self.denials.front() {
+            if now.duration_since(oldest) < window {
+                break;
+            }
+            self.denials.pop_front();
+        }
+    }
+
+    /// Denials within `window` of `now`
+    pub(super) fn rate(&mut self, now: Instant, window: Duration) -> usize {
+        self.prune(now, window);
+        self.denials.len()
+    }
+
+    /// Record a denial, returning the rate if it just crossed `threshold`
+    pub(super) fn record(
+        &mut self,
+        now: Instant,
+        policy: &DenialEscalationPolicy,
+    ) -> Option<usize> {
+        self.prune(now, policy.window);
+        if self.denials.len() < policy.threshold {
+            self.escalated = false;
+        }
+
+        self.denials.push_back(now);
+        // Bound memory under a denial flood; the rate saturates instead
+        if self.denials.len() > policy.threshold.max(MAX_AUDIT_EVENTS_PER_PID) {
+            self.denials.pop_front();
+        }
+
+        let rate = self.denials.len();
+        if rate >= policy.threshold && !self.escalated {
+            self.escalated = true;
+            return Some(rate);
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_window_fires_once_until_rate_drops() {
+        let policy =
+            DenialEscalationPolicy::new(3, Duration::from_secs(10), EscalationAction::Alert);
+        let mut window = DenialWindow::default();
+        let start = Instant::now();
+
+        assert_eq!(window.record(start, &policy), None);
+        assert_eq!(window.record(start, &policy), None);
+        assert_eq!(window.record(start, &policy), Some(3));
+        assert_eq!(window.record(start, &policy), None);
+        assert_eq!(window.rate(start, policy.window), 4);
+
+        // Once the window slides past the burst, crossing again re-fires
+        let later = start + Duration::from_secs(11);
+        assert_eq!(window.rate(later, policy.window), 0);
+        assert_eq!(window.record(later, &policy), None);
+        assert_eq!(window.record(later, &policy), None);
+        assert_eq!(window.record(later, &policy), Some(3));
+    }
+}
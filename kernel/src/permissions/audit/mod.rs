@@ -3,15 +3,26 @@
  * Tracks permission checks and denials for security monitoring
  */
 
+mod escalation;
+
+pub use escalation::{
+    DenialEscalationPolicy, Escalation, EscalationAction, EscalationHandler, EscalationKind,
+};
+
 use crate::core::types::Pid;
-use crate::permissions::types::{PermissionRequest, PermissionResponse, Resource};
+use crate::monitoring::Collector;
+use crate::permissions::types::{PermissionRequest, PermissionResponse, Resource, ResourceType};
 use ahash::RandomState;
+use crossbeam_queue::SegQueue;
 use dashmap::DashMap;
+use escalation::DenialWindow;
+use log::warn;
+use parking_lot::RwLock;
 use serde::{Deserialize, Serialize};
 use serde_with::{serde_as, TimestampSeconds};
 use std::collections::VecDeque;
 use std::sync::Arc;
-use std::time::SystemTime;
+use std::time::{Instant, SystemTime};
 
 /// Maximum events to keep in memory
 use crate::core::limits::{MAX_AUDIT_EVENTS, MAX_AUDIT_EVENTS_PER_PID as MAX_PID_EVENTS};
@@ -23,6 +34,28 @@ pub enum AuditSeverity {
     Info,
     Warning,
     Critical,
+    /// Denial recorded in permissive mode; the request was allowed
+    WouldDeny,
+}
+
+impl AuditSeverity {
+    /// Ordering for minimum-severity filters
+    ///
+    /// Would-deny sits between info and warning: nothing was blocked, but
+    /// it is a denial an operator will want to see.
+    fn level(self) -> u8 {
+        match self {
+            AuditSeverity::Info => 0,
+            AuditSeverity::WouldDeny => 1,
+            AuditSeverity::Warning => 2,
+            AuditSeverity::Critical => 3,
+        }
+    }
+
+    /// Whether this severity is at least `min`
+    pub fn at_least(self, min: AuditSeverity) -> bool {
+        self.level() >= min.level()
+    }
 }
 
 /// Permission audit event
@@ -58,12 +91,84 @@ pub fn new(request: PermissionRequest, response: PermissionResponse) -> Self {
         }
     }
 
+    /// Record a denial that permissive mode let through
+    ///
+    /// `response` is the original denial, so the event keeps its reason.
+    pub fn would_deny(request: PermissionRequest, response: PermissionResponse) -> Self {
+        Self::new(request, response).with_severity(AuditSeverity::WouldDeny)
+    }
+
+    /// Whether this event records a denial permissive mode let through
+    pub fn is_would_deny(&self) -> bool {
+        self.severity == AuditSeverity::WouldDeny
+    }
+
     pub fn with_severity(mut self, severity: AuditSeverity) -> Self {
         self.severity = severity;
         self
     }
 }
 
+/// Predicates for querying the audit log
+///
+/// Unset fields match every event; set fields must all match.
+#[derive(Debug, Clone, Default)]
+pub struct AuditFilter {
+    pub min_severity: Option<AuditSeverity>,
+    pub resource_type: Option<ResourceType>,
+    pub pid: Option<Pid>,
+    /// Inclusive lower bound on `logged_at`
+    pub since: Option<SystemTime>,
+    /// Exclusive upper bound on `logged_at`
+    pub until: Option<SystemTime>,
+    /// Maximum events to return (newest first)
+    pub limit: Option<usize>,
+}
+
+impl AuditFilter {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_min_severity(mut self, severity: AuditSeverity) -> Self {
+        self.min_severity = Some(severity);
+        self
+    }
+
+    pub fn with_resource_type(mut self, resource_type: ResourceType) -> Self {
+        self.resource_type = Some(resource_type);
+        self
+    }
+
+    pub fn with_pid(mut self, pid: Pid) -> Self {
+        self.pid = Some(pid);
+        self
+    }
+
+    pub fn with_time_range(mut self, since: SystemTime, until: SystemTime) -> Self {
+        self.since = Some(since);
+        self.until = Some(until);
+        self
+    }
+
+    pub fn with_limit(mut self, limit: usize) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Check whether an event satisfies every set predicate
+    pub fn matches(&self, event: &AuditEvent) -> bool {
+        self.min_severity
+            .is_none_or(|min| event.severity.at_least(min))
+            && self
+                .resource_type
+                .is_none_or(|rt| event.request.resource.resource_type() == rt)
+            && self.pid.is_none_or(|pid| event.request.pid == pid)
+            && self.since.is_none_or(|since| event.logged_at >= since)
+            && self.until.is_none_or(|until| event.logged_at < until)
+    }
+}
+
 /// Audit logger for permission checks
 pub struct AuditLogger {
     /// Global event log (ring buffer)
@@ -72,6 +177,14 @@ pub struct AuditLogger {
     pid_events: Arc<DashMap<Pid, VecDeque<AuditEvent>, RandomState>>,
     /// Denial counters for monitoring
     denial_counts: Arc<DashMap<Pid, u64, RandomState>>,
+    /// Recent denials per PID, for escalation
+    denial_windows: Arc<DashMap<Pid, DenialWindow, RandomState>>,
+    /// Escalation policy; None disables escalation
+    escalation: RwLock<Option<DenialEscalationPolicy>>,
+    /// Throttle and kill requests not yet taken
+    escalation_requests: SegQueue<Escalation>,
+    /// Observability collector for escalation alerts
+    collector: RwLock<Option<Arc<Collector>>>,
 }
 
 impl AuditLogger {
@@ -80,13 +193,105 @@ pub fn new() -> Self {
             events: parking_lot::RwLock::new(VecDeque::with_capacity(MAX_AUDIT_EVENTS).into()),
             pid_events: Arc::new(DashMap::with_hasher(RandomState::new().into())),
             denial_counts: Arc::new(DashMap::with_hasher(RandomState::new().into())),
+            denial_windows: Arc::new(DashMap::with_hasher(RandomState::new())),
+            escalation: RwLock::new(None),
+            escalation_requests: SegQueue::new(),
+            collector: RwLock::new(None),
+        }
+    }
+
+    /// Set collector for escalation alerts
+    pub fn set_collector(&self, collector: Arc<Collector>) {
+        *self.collector.write() = Some(collector);
+    }
+
+    /// Escalate processes that keep getting denied; None disables escalation
+    pub fn set_escalation_policy(&self, policy: Option<DenialEscalationPolicy>) {
+        *self.escalation.write() = policy;
+        self.denial_windows.clear();
+    }
+
+    /// Current escalation policy
+    pub fn escalation_policy(&self) -> Option<DenialEscalationPolicy> {
+        self.escalation.read().clone()
+    }
+
+    /// Denials for `pid` within the escalation window (0 when disabled)
+    pub fn denial_rate(&self, pid: Pid) -> usize {
+        let Some(window) = self.escalation.read().as_ref().map(|p| p.window) else {
+            return 0;
+        };
+        self.denial_windows
+            .get_mut(&pid)
+            .map(|mut entry| entry.rate(Instant::now(), window))
+            .unwrap_or(0)
+    }
+
+    /// Take queued throttle and kill requests, oldest first
+    ///
+    /// The kernel polls this and hands each one to
+    /// `ProcessManager::enforce_escalation`.
+    pub fn take_escalations(&self) -> Vec<Escalation> {
+        std::iter::from_fn(|| self.escalation_requests.pop()).collect()
+    }
+
+    /// Count a denial toward escalation and act if the threshold is crossed
+    fn escalate(&self, pid: Pid) {
+        let Some(policy) = self.escalation.read().clone() else {
+            return;
+        };
+        let crossed = self
+            .denial_windows
+            .entry(pid)
+            .or_default()
+            .record(Instant::now(), &policy);
+        let Some(denials) = crossed else {
+            return;
+        };
+
+        let escalation = Escalation {
+            pid,
+            denials,
+            window: policy.window,
+            kind: EscalationKind::from(&policy.action),
+        };
+        warn!(
+            "PID {} denied {} times in {:?}, escalating: {:?}",
+            pid, denials, policy.window, policy.action
+        );
+
+        match &policy.action {
+            EscalationAction::Alert => {
+                if let Some(collector) = self.collector.read().as_ref() {
+                    use crate::monitoring::{Category, Event, Payload, Severity};
+                    collector.emit(
+                        Event::new(
+                            Severity::Critical,
+                            Category::Security,
+                            Payload::SecurityViolation {
+                                description: format!(
+                                    "{} permission denials in {:?}",
+                                    denials, policy.window
+                                )
+                                .into(),
+                            },
+                        )
+                        .with_pid(pid),
+                    );
+                }
+            }
+            EscalationAction::Callback(handler) => handler(&escalation),
+            EscalationAction::Throttle | EscalationAction::Kill => {
+                self.escalation_requests.push(escalation)
+            }
         }
     }
 
     /// Log a permission check
     pub fn log(&self, event: AuditEvent) {
         let pid = event.request.pid;
-        let is_denied = !event.response.is_allowed();
+        // Would-deny events weren't actually denied
+        let is_denied = !event.response.is_allowed() && !event.is_would_deny();
 
         // Add to global log
         {
@@ -116,13 +321,25 @@ pub fn log(&self, event: AuditEvent) {
                 .entry(pid)
                 .and_modify(|count| *count += 1)
                 .or_insert(1);
+            self.escalate(pid);
         }
     }
 
     /// Get recent events
     pub fn recent(&self, limit: usize) -> Vec<AuditEvent> {
+        self.query(AuditFilter::new().with_limit(limit))
+    }
+
+    /// Query the in-memory ring buffer, newest first
+    pub fn query(&self, filter: AuditFilter) -> Vec<AuditEvent> {
         let events = self.events.read();
-        events.iter().rev().take(limit).cloned().collect()
+        events
+            .iter()
+            .rev()
+            .filter(|event| filter.matches(event))
+            .take(filter.limit.unwrap_or(usize::MAX))
+            .cloned()
+            .collect()
     }
 
     /// Get events for a specific PID
@@ -151,6 +368,7 @@ pub fn pids_with_denials(&self) -> Vec<(Pid, u64)> {
     pub fn clear_pid(&self, pid: Pid) {
         self.pid_events.remove(&pid);
         self.denial_counts.remove(&pid);
+        self.denial_windows.remove(&pid);
     }
 
     /// Clear all logs
@@ -158,6 +376,7 @@ pub fn clear_all(&self) {
         self.events.write().clear();
         self.pid_events.clear();
         self.denial_counts.clear();
+        self.denial_windows.clear();
     }
 
     /// Get statistics
@@ -246,4 +465,167 @@ fn test_ring_buffer() {
         let stats = logger.stats();
         assert_eq!(stats.total_events, MAX_AUDIT_EVENTS);
     }
+
+    fn seed_query_logger() -> AuditLogger {
+        let logger = AuditLogger::new();
+        let file = PermissionRequest::file_read(100, PathBuf::from("/test"));
+        let net = PermissionRequest::net_connect(200, "example.com".to_string(), Some(443));
+        let proc = PermissionRequest::new(
+            100,
+            Resource::Process { pid: 300 },
+            crate::permissions::types::Action::Kill,
+        );
+
+        logger.log(AuditEvent::new(
+            file.clone(),
+            PermissionResponse::allow(file.clone(), "ok"),
+        ));
+        logger.log(AuditEvent::new(
+            file.clone(),
+            PermissionResponse::deny(file, "no"),
+        ));
+        logger.log(AuditEvent::new(
+            net.clone(),
+            PermissionResponse::deny(net, "no"),
+        ));
+        logger.log(AuditEvent::new(
+            proc.clone(),
+            PermissionResponse::deny(proc, "no"),
+        ));
+        logger
+    }
+
+    #[test]
+    fn test_query_min_severity() {
+        let logger = seed_query_logger();
+
+        let warnings = logger.query(AuditFilter::new().with_min_severity(AuditSeverity::Warning));
+        assert_eq!(warnings.len(), 3);
+
+        let critical = logger.query(AuditFilter::new().with_min_severity(AuditSeverity::Critical));
+        assert_eq!(critical.len(), 1);
+        assert_eq!(
+            critical[0].request.resource.resource_type(),
+            ResourceType::Process
+        );
+    }
+
+    #[test]
+    fn test_query_resource_type() {
+        let logger = seed_query_logger();
+
+        let fs = logger.query(AuditFilter::new().with_resource_type(ResourceType::FileSystem));
+        assert_eq!(fs.len(), 2);
+
+        let net = logger.query(AuditFilter::new().with_resource_type(ResourceType::Network));
+        assert_eq!(net.len(), 1);
+        assert_eq!(net[0].request.pid, 200);
+    }
+
+    #[test]
+    fn test_query_pid() {
+        let logger = seed_query_logger();
+
+        assert_eq!(logger.query(AuditFilter::new().with_pid(100)).len(), 3);
+        assert_eq!(logger.query(AuditFilter::new().with_pid(200)).len(), 1);
+        assert!(logger.query(AuditFilter::new().with_pid(999)).is_empty());
+    }
+
+    #[test]
+    fn test_query_time_range() {
+        let logger = AuditLogger::new();
+        let base = SystemTime::UNIX_EPOCH + std::time::Duration::from_secs(1_000);
+
+        for offset in 0..5 {
+            let req = PermissionRequest::file_read(100, PathBuf::from("/test"));
+            let mut event = AuditEvent::new(req.clone(), PermissionResponse::allow(req, "ok"));
+            event.logged_at = base + std::time::Duration::from_secs(offset * 10);
+            logger.log(event);
+        }
+
+        let window = logger.query(AuditFilter::new().with_time_range(
+            base + std::time::Duration::from_secs(10),
+            base + std::time::Duration::from_secs(30),
+        ));
+        assert_eq!(window.len(), 2);
+        // Newest first
+        assert!(window[0].logged_at > window[1].logged_at);
+    }
+
+    #[test]
+    fn test_query_combined() {
+        let logger = seed_query_logger();
+
+        let filter = AuditFilter::new()
+            .with_pid(100)
+            .with_min_severity(AuditSeverity::Warning)
+            .with_resource_type(ResourceType::FileSystem);
+        let events = logger.query(filter);
+        assert_eq!(events.len(), 1);
+        assert!(!events[0].response.is_allowed());
+
+        let limited = logger.query(AuditFilter::new().with_pid(100).with_limit(2));
+        assert_eq!(limited.len(), 2);
+        assert_eq!(logger.recent(2).len(), 2);
+    }
+
+    #[test]
+    fn test_denial_escalation_fires_once() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+        use std::time::Duration;
+
+        let logger = AuditLogger::new();
+        let fired = Arc::new(AtomicUsize::new(0));
+        let seen = Arc::clone(&fired);
+        logger.set_escalation_policy(Some(DenialEscalationPolicy::new(
+            5,
+            Duration::from_secs(60),
+            EscalationAction::Callback(Arc::new(move |escalation: &Escalation| {
+                assert_eq!(escalation.pid, 100);
+                assert_eq!(escalation.denials, 5);
+                seen.fetch_add(1, Ordering::SeqCst);
+            })),
+        )));
+
+        let deny = |pid| {
+            let req = PermissionRequest::file_read(pid, PathBuf::from("/etc/shadow"));
+            logger.log(AuditEvent::new(
+                req.clone(),
+                PermissionResponse::deny(req, "no"),
+            ));
+        };
+        for _ in 0..12 {
+            deny(100);
+        }
+        deny(200);
+
+        // Allowed requests and would-deny events don't count
+        let req = PermissionRequest::file_read(100, PathBuf::from("/tmp/ok"));
+        logger.log(AuditEvent::new(
+            req.clone(),
+            PermissionResponse::allow(req.clone(), "ok"),
+        ));
+        logger.log(AuditEvent::would_deny(
+            req.clone(),
+            PermissionResponse::deny(req, "no"),
+        ));
+
+        assert_eq!(fired.load(Ordering::SeqCst), 1);
+        assert_eq!(logger.denial_rate(100), 12);
+        assert_eq!(logger.denial_rate(200), 1);
+
+        logger.set_escalation_policy(Some(DenialEscalationPolicy::new(
+            2,
+            Duration::from_secs(60),
+            EscalationAction::Kill,
+        )));
+        deny(300);
+        deny(300);
+        deny(300);
+        let requests = logger.take_escalations();
+        assert_eq!(requests.len(), 1);
+        assert_eq!(requests[0].pid, 300);
+        assert_eq!(requests[0].kind, EscalationKind::Kill);
+        assert!(logger.take_escalations().is_empty());
+    }
 }
@@ -9,6 +9,7 @@
 use ahash::RandomState;
 use dashmap::DashMap;
 use std::hash::{Hash, Hasher};
+use std::sync::atomic::{AtomicU64, Ordering};
 use std::time::{Duration, SystemTime};
 
 /// Cache key for permission lookups
@@ -46,10 +47,15 @@ fn new(pid: Pid, resource: &Resource, action: Action) -> Self {
 struct CachedDecision {
     response: PermissionResponse,
     expires_at: SystemTime,
+    /// Cache generation the decision was computed under
+    generation: u64,
 }
 
 pub struct PermissionCache {
     cache: DashMap<CacheKey, CachedDecision, RandomState>,
+    /// Bumped on every invalidation, so decisions computed before one are
+    /// never cached after it
+    generation: AtomicU64,
     max_size: usize,
     ttl: Duration,
     counters: SeqlockStats<PermCacheCounters>,
@@ -60,6 +66,7 @@ impl PermissionCache {
     pub fn new(max_size: usize, ttl: Duration) -> Self {
         Self {
             cache: DashMap::with_capacity_and_hasher(max_size, RandomState::new().into()),
+            generation: AtomicU64::new(0),
             max_size,
             ttl,
             counters: SeqlockStats::new(PermCacheCounters { hits: 0, misses: 0 }),
@@ -85,8 +92,28 @@ pub fn get(&self, request: &PermissionRequest) -> Option<PermissionResponse> {
         None
     }
 
+    /// Current generation; read it before computing a decision to cache
+    pub fn generation(&self) -> u64 {
+        self.generation.load(Ordering::SeqCst)
+    }
+
     /// Store decision in cache
     pub fn put(&self, request: PermissionRequest, response: PermissionResponse) {
+        self.put_if_current(request, response, self.generation());
+    }
+
+    /// Store a decision computed at `generation`, unless the cache has been
+    /// invalidated since
+    pub fn put_if_current(
+        &self,
+        request: PermissionRequest,
+        response: PermissionResponse,
+        generation: u64,
+    ) {
+        if self.generation() != generation {
+            return;
+        }
+
         // Simple size limit - remove random entry if full
         if self.cache.len() >= self.max_size {
             if let Some(entry) = self.cache.iter().next() {
@@ -100,18 +127,28 @@ pub fn put(&self, request: PermissionRequest, response: PermissionResponse) {
         let expires_at = SystemTime::now() + self.ttl;
 
         self.cache.insert(
-            key,
+            key.clone(),
             CachedDecision {
                 response,
                 expires_at,
+                generation,
             },
         );
+
+        // An invalidation that raced the insert may have already swept the
+        // map, so take back what we inserted
+        if self.generation() != generation {
+            self.cache
+                .remove_if(&key, |_, decision| decision.generation == generation);
+        }
     }
 
     /// Clear all cached decisions for a PID
     pub fn invalidate_pid(&self, pid: Pid) {
         use crate::core::optimization::prefetch_read;
 
+        self.generation.fetch_add(1, Ordering::SeqCst);
+
         let keys: Vec<_> = self
             .cache
             .iter()
@@ -134,6 +171,7 @@ pub fn invalidate_pid(&self, pid: Pid) {
 
     /// Clear entire cache
     pub fn clear(&self) {
+        self.generation.fetch_add(1, Ordering::SeqCst);
         self.cache.clear();
     }
 
@@ -251,4 +289,26 @@ fn test_invalidate_pid() {
         assert!(cache.get(&req1).is_none());
         assert!(cache.get(&req2).is_some());
     }
+
+    #[test]
+    fn test_stale_decisions_are_not_cached() {
+        let cache = PermissionCache::new(100, Duration::from_secs(10));
+        let req = PermissionRequest::file_read(100, PathBuf::from("/test"));
+        let resp = PermissionResponse::allow(req.clone(), "test");
+
+        // Computed before a clear
+        let generation = cache.generation();
+        cache.clear();
+        cache.put_if_current(req.clone(), resp.clone(), generation);
+        assert!(cache.get(&req).is_none());
+
+        // Computed before a grant change for an unrelated PID
+        let generation = cache.generation();
+        cache.invalidate_pid(200);
+        cache.put_if_current(req.clone(), resp.clone(), generation);
+        assert!(cache.get(&req).is_none());
+
+        cache.put_if_current(req.clone(), resp, cache.generation());
+        assert!(cache.get(&req).is_some());
+    }
 }
@@ -7,13 +7,15 @@
 use crate::monitoring::Collector;
 use crate::permissions::audit::{AuditEvent, AuditLogger, AuditStats};
 use crate::permissions::cache::{CacheStats, PermissionCache};
-use crate::permissions::policy::{EvaluationContext, PolicyEngine};
+use crate::permissions::policy::{EvaluationContext, Policy, PolicyEngine};
 use crate::permissions::types::{
-    PermissionChecker, PermissionProvider, PermissionRequest, PermissionResponse, PermissionSystem,
+    PermissionChecker, PermissionMode, PermissionProvider, PermissionRequest, PermissionResponse,
+    PermissionSystem,
 };
 use crate::security::traits::SandboxProvider;
 use crate::security::SandboxManager;
-use log::{debug, warn};
+use log::{debug, info, warn};
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Arc;
 
 /// Central permission manager
@@ -29,29 +31,38 @@ pub struct PermissionManager {
     audit: Arc<AuditLogger>,
     /// Observability collector
     collector: Option<Arc<Collector>>,
+    /// Permissive (dry-run) mode, shared between clones
+    permissive: Arc<AtomicBool>,
 }
 
 impl PermissionManager {
     /// Create new permission manager
     pub fn new(sandbox: SandboxManager) -> Self {
         debug!("Initializing centralized permission manager");
+        let cache = Arc::new(PermissionCache::default());
+        sandbox.register_permission_cache(&cache);
+        let policy = PolicyEngine::new();
+        policy.register_permission_cache(&cache);
         Self {
             sandbox,
-            policy: Arc::new(PolicyEngine::new().into()),
-            cache: Arc::new(PermissionCache::default().into()),
+            policy: Arc::new(policy),
+            cache,
             audit: Arc::new(AuditLogger::new().into()),
             collector: None,
+            permissive: Arc::new(AtomicBool::new(false)),
         }
     }
 
     /// Add observability collector
     pub fn with_collector(mut self, collector: Arc<Collector>) -> Self {
+        self.audit.set_collector(Arc::clone(&collector));
         self.collector = Some(collector);
         self
     }
 
     /// Set collector after construction
     pub fn set_collector(&mut self, collector: Arc<Collector>) {
+        self.audit.set_collector(Arc::clone(&collector));
         self.collector = Some(collector);
     }
 
@@ -61,12 +72,37 @@ pub fn with_config(
         cache: PermissionCache,
         policy: PolicyEngine,
     ) -> Self {
+        let cache = Arc::new(cache);
+        sandbox.register_permission_cache(&cache);
+        policy.register_permission_cache(&cache);
         Self {
             sandbox,
             policy: Arc::new(policy),
-            cache: Arc::new(cache),
+            cache,
             audit: Arc::new(AuditLogger::new().into()),
             collector: None,
+            permissive: Arc::new(AtomicBool::new(false)),
+        }
+    }
+
+    /// Switch between enforcing and permissive (dry-run) mode
+    ///
+    /// In permissive mode denials are audited with `AuditSeverity::WouldDeny`
+    /// and the request is allowed, so policy changes can be rolled out
+    /// without breaking apps. Applies to all clones of this manager.
+    pub fn set_mode(&self, mode: PermissionMode) {
+        let permissive = mode == PermissionMode::Permissive;
+        if self.permissive.swap(permissive, Ordering::AcqRel) != permissive {
+            info!("Permission mode set to {:?}", mode);
+        }
+    }
+
+    /// Current permission mode
+    pub fn mode(&self) -> PermissionMode {
+        if self.permissive.load(Ordering::Acquire) {
+            PermissionMode::Permissive
+        } else {
+            PermissionMode::Enforce
         }
     }
 
@@ -77,6 +113,13 @@ pub fn policy_mut(&mut self) -> Option<&mut PolicyEngine> {
         Arc::get_mut(&mut self.policy)
     }
 
+    /// Swap in a new rule set and drop every cached decision
+    ///
+    /// Applies to all clones of this manager.
+    pub fn reload_policies(&self, rules: Vec<Box<dyn Policy>>) {
+        self.policy.reload(rules);
+    }
+
     /// Get audit logger
     pub fn audit(&self) -> &AuditLogger {
         &self.audit
@@ -87,6 +130,11 @@ pub fn invalidate_cache(&self, pid: Pid) {
         self.cache.invalidate_pid(pid);
     }
 
+    /// Drop every cached decision (e.g. to shed memory under pressure)
+    pub fn clear_cache(&self) {
+        self.cache.clear();
+    }
+
     /// Get cache statistics
     pub fn cache_stats(&self) -> CacheStats {
         self.cache.stats()
@@ -97,6 +145,58 @@ pub fn audit_stats(&self) -> AuditStats {
         self.audit.stats()
     }
 
+    /// Policy decision for a request, through the cache
+    ///
+    /// Always the enforcing decision; permissive mode is applied on top.
+    fn decide(&self, request: &PermissionRequest) -> PermissionResponse {
+        // Try cache first
+        if let Some(cached) = self.cache.get(request) {
+            debug!("Cache hit for PID {} permission check", request.pid);
+            return cached;
+        }
+
+        // Perform check; reloads and grant changes invalidate the cache, so
+        // read its generation first and only cache a decision still current
+        let generation = self.cache.generation();
+        let response = self.check_internal(request);
+
+        // Don't cache decisions that could flip once the clock crosses a
+        // window boundary
+        if !self.policy.is_time_dependent(request) {
+            self.cache
+                .put_if_current(request.clone(), response.clone(), generation);
+        }
+
+        response
+    }
+
+    /// Audit a denial and allow it instead, if in permissive mode
+    fn apply_mode(
+        &self,
+        request: &PermissionRequest,
+        response: PermissionResponse,
+    ) -> Option<PermissionResponse> {
+        if response.is_allowed() || self.mode() == PermissionMode::Enforce {
+            return None;
+        }
+
+        warn!(
+            "Permissive mode: would deny PID {} {:?} on {:?}: {}",
+            request.pid,
+            request.action,
+            request.resource,
+            response.reason()
+        );
+        let allowed = PermissionResponse::allow(
+            request.clone(),
+            format!("Permissive mode (would deny: {})", response.reason()),
+        )
+        .with_cached(response.cached);
+        self.audit
+            .log(AuditEvent::would_deny(request.clone(), response));
+        Some(allowed)
+    }
+
     /// Internal check without caching
     fn check_internal(&self, request: &PermissionRequest) -> PermissionResponse {
         // Get sandbox configuration
@@ -111,8 +211,14 @@ fn check_internal(&self, request: &PermissionRequest) -> PermissionResponse {
             }
         };
 
-        // Create evaluation context
-        let context = EvaluationContext::new(sandbox_config);
+        // Reuse the sandbox's keyed path rules rather than rebuilding them
+        let context = match self.sandbox.path_rules(request.pid) {
+            Some(rules) => EvaluationContext::with_path_rules(sandbox_config, rules),
+            None => EvaluationContext::new(sandbox_config),
+        };
+
+        // Stamp the context with the policy engine's clock
+        let context = context.with_request_context(self.policy.request_context());
 
         // Evaluate through policy engine
         let response = self.policy.evaluate(request, &context);
@@ -141,23 +247,16 @@ fn check_internal(&self, request: &PermissionRequest) -> PermissionResponse {
 
 impl PermissionChecker for PermissionManager {
     fn check(&self, request: &PermissionRequest) -> PermissionResponse {
-        // Try cache first
-        if let Some(cached) = self.cache.get(request) {
-            debug!("Cache hit for PID {} permission check", request.pid);
-            return cached;
-        }
-
-        // Perform check
-        let response = self.check_internal(request);
-
-        // Cache the result
-        self.cache.put(request.clone(), response.clone());
-
-        response
+        let response = self.decide(request);
+        self.apply_mode(request, response.clone())
+            .unwrap_or(response)
     }
 
     fn check_and_audit(&self, request: &PermissionRequest) -> PermissionResponse {
-        let response = self.check(request);
+        let response = self.decide(request);
+        if let Some(allowed) = self.apply_mode(request, response.clone()) {
+            return allowed;
+        }
 
         // Log to audit trail
         let event = AuditEvent::new(request.clone(), response.clone());
@@ -182,7 +281,9 @@ impl PermissionSystem for PermissionManager {}
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::permissions::types::PermissionRequest;
+    use crate::permissions::audit::AuditSeverity;
+    use crate::permissions::policy::{DefaultPolicy, PolicyDecision};
+    use crate::permissions::types::{PermissionRequest, Resource};
     use crate::security::types::{Capability, SandboxConfig};
     use std::path::PathBuf;
 
@@ -257,6 +358,29 @@ fn test_audit_logging() {
         assert_eq!(audit_stats.total_denials, 1);
     }
 
+    #[test]
+    fn test_permissive_mode() {
+        let sandbox = SandboxManager::new();
+        sandbox.create_sandbox(SandboxConfig::minimal(100));
+
+        let manager = PermissionManager::new(sandbox);
+        assert_eq!(manager.mode(), PermissionMode::Enforce);
+        let req = PermissionRequest::file_read(100, PathBuf::from("/etc/passwd"));
+
+        manager.clone().set_mode(PermissionMode::Permissive);
+        assert!(manager.check_and_audit(&req).is_allowed());
+
+        let events = manager.audit().recent(10);
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].severity, AuditSeverity::WouldDeny);
+        assert!(!events[0].response.is_allowed());
+        assert_eq!(manager.audit_stats().total_denials, 0);
+
+        // The cached decision is still the enforcing one
+        manager.set_mode(PermissionMode::Enforce);
+        assert!(!manager.check(&req).is_allowed());
+    }
+
     #[test]
     fn test_batch_check() {
         let sandbox = SandboxManager::new();
@@ -278,4 +402,99 @@ fn test_batch_check() {
         assert!(responses[1].is_allowed());
         assert!(!responses[2].is_allowed());
     }
+
+    /// Denies reads under one directory
+    struct DenyReadsUnder(PathBuf);
+
+    impl Policy for DenyReadsUnder {
+        fn evaluate(
+            &self,
+            request: &PermissionRequest,
+            _context: &EvaluationContext,
+        ) -> PolicyDecision {
+            match &request.resource {
+                Resource::File { path } if path.starts_with(&self.0) => PolicyDecision::Deny,
+                _ => PolicyDecision::Abstain,
+            }
+        }
+
+        fn name(&self) -> &str {
+            "deny_reads_under"
+        }
+    }
+
+    #[test]
+    fn test_reload_policies_clears_cache() {
+        let sandbox = SandboxManager::new();
+        let mut config = SandboxConfig::minimal(100);
+        config.grant_capability(Capability::ReadFile(None));
+        config.allow_path(PathBuf::from("/tmp"));
+        sandbox.create_sandbox(config);
+
+        let manager = PermissionManager::new(sandbox);
+        let req = PermissionRequest::file_read(100, PathBuf::from("/tmp/secret/key"));
+        assert!(manager.check(&req).is_allowed());
+        assert!(manager.check(&req).cached);
+        assert_eq!(manager.cache_stats().size, 1);
+
+        manager.reload_policies(vec![
+            Box::new(DenyReadsUnder(PathBuf::from("/tmp/secret"))),
+            Box::new(DefaultPolicy),
+        ]);
+        assert_eq!(manager.cache_stats().size, 0);
+
+        let resp = manager.check(&req);
+        assert!(!resp.is_allowed());
+        assert!(!resp.cached);
+        assert!(resp.reason().contains("deny_reads_under"));
+
+        // Requests the new rule abstains on still reach the default policy
+        let other = PermissionRequest::file_read(100, PathBuf::from("/tmp/public"));
+        assert!(manager.check(&other).is_allowed());
+    }
+
+    #[test]
+    fn test_time_window_uses_engine_clock_and_skips_cache() {
+        use crate::permissions::policy::{FixedClock, TimeWindow, TimeWindowPolicy};
+        use crate::permissions::types::ResourceType;
+        use crate::security::types::NetworkRule;
+        use std::time::{Duration, UNIX_EPOCH};
+
+        // 2024-01-01T00:00:00Z plus offset
+        let at = |hour: u64| UNIX_EPOCH + Duration::from_secs(1_704_067_200 + hour * 3600);
+
+        let sandbox = SandboxManager::new();
+        let mut config = SandboxConfig::minimal(100);
+        config.grant_capability(Capability::ReadFile(None));
+        config.grant_capability(Capability::NetworkAccess(NetworkRule::AllowAll));
+        config.network_rules.push(NetworkRule::AllowAll);
+        config.allow_path(PathBuf::from("/tmp"));
+        sandbox.create_sandbox(config);
+
+        let clock = Arc::new(FixedClock::new(at(12)));
+        let mut policy = PolicyEngine::new().with_clock(clock.clone());
+        policy.prepend_policy(Box::new(
+            TimeWindowPolicy::new("business-hours", TimeWindow::utc((9, 0), (17, 0)).unwrap())
+                .for_resource(ResourceType::Network),
+        ));
+        let manager = PermissionManager::with_config(sandbox, PermissionCache::default(), policy);
+
+        let connect = PermissionRequest::net_connect(100, "example.com".into(), Some(443));
+        let resp = manager.check(&connect);
+        assert!(resp.is_allowed());
+        assert!(!resp.cached);
+
+        // Past the window boundary the same request is re-evaluated and denied
+        clock.set(at(18));
+        let resp = manager.check(&connect);
+        assert!(!resp.is_allowed());
+        assert!(!resp.cached);
+        assert!(resp.reason().contains("09:00-17:00 UTC"));
+
+        // Requests outside the window's scope are still cached
+        let read = PermissionRequest::file_read(100, PathBuf::from("/tmp/test.txt"));
+        assert!(manager.check(&read).is_allowed());
+        assert!(manager.check(&read).cached);
+        assert_eq!(manager.cache_stats().size, 1);
+    }
 }
@@ -39,13 +39,17 @@
 pub mod types;
 
 // Re-export commonly used items
-pub use audit::{AuditEvent, AuditLogger, AuditSeverity, AuditStats};
+pub use audit::{
+    AuditEvent, AuditFilter, AuditLogger, AuditSeverity, AuditStats, DenialEscalationPolicy,
+    Escalation, EscalationAction, EscalationHandler, EscalationKind,
+};
 pub use cache::{CacheStats, PermissionCache};
 pub use manager::PermissionManager;
 pub use policy::{
-    DefaultPolicy, EvaluationContext, Policy, PolicyDecision, PolicyEngine, RequestContext,
+    Clock, DefaultPolicy, EvaluationContext, FixedClock, Policy, PolicyDecision, PolicyEngine,
+    RequestContext, SystemClock, TimeWindow, TimeWindowPolicy,
 };
 pub use types::{
-    Action, PermissionChecker, PermissionProvider, PermissionRequest, PermissionResponse,
-    PermissionResult, PermissionSystem, Resource, ResourceType,
+    Action, PermissionChecker, PermissionMode, PermissionProvider, PermissionRequest,
+    PermissionResponse, PermissionResult, PermissionSystem, Resource, ResourceType,
 };
@@ -4,10 +4,57 @@
  */
 
 use crate::core::types::Pid;
+use crate::security::sandbox::config::PathRules;
 use crate::security::types::SandboxConfig;
 use ahash::HashMap;
+use std::fmt;
+use std::path::Path;
+use std::sync::Arc;
 use std::time::SystemTime;
 
+/// Time source for permission evaluation
+///
+/// Injected into the policy engine so time-dependent policies can be tested deterministically.
+pub trait Clock: Send + Sync + fmt::Debug {
+    /// Current wall-clock time
+    fn now(&self) -> SystemTime;
+}
+
+/// Clock backed by the system wall clock
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> SystemTime {
+        SystemTime::now()
+    }
+}
+
+/// Clock frozen at a fixed instant (for tests and replay)
+#[derive(Debug)]
+pub struct FixedClock {
+    time: parking_lot::RwLock<SystemTime>,
+}
+
+impl FixedClock {
+    pub fn new(time: SystemTime) -> Self {
+        Self {
+            time: parking_lot::RwLock::new(time),
+        }
+    }
+
+    /// Move the clock to a new instant
+    pub fn set(&self, time: SystemTime) {
+        *self.time.write() = time;
+    }
+}
+
+impl Clock for FixedClock {
+    fn now(&self) -> SystemTime {
+        *self.time.read()
+    }
+}
+
 /// Request context for permission evaluation
 #[derive(Debug, Clone)]
 pub struct RequestContext {
@@ -19,8 +66,13 @@ pub struct RequestContext {
 
 impl RequestContext {
     pub fn new() -> Self {
+        Self::at(SystemTime::now())
+    }
+
+    /// Create a context stamped with a specific time
+    pub fn at(timestamp: SystemTime) -> Self {
         Self {
-            timestamp: SystemTime::now(),
+            timestamp,
             metadata: HashMap::default(),
         }
     }
@@ -44,13 +96,22 @@ pub struct EvaluationContext {
     pub sandbox: SandboxConfig,
     /// Request context
     pub request: RequestContext,
+    /// The sandbox's path lists keyed for matching
+    path_rules: Arc<PathRules>,
 }
 
 impl EvaluationContext {
     pub fn new(sandbox: SandboxConfig) -> Self {
+        let path_rules = Arc::new(PathRules::new(&sandbox));
+        Self::with_path_rules(sandbox, path_rules)
+    }
+
+    /// Create a context reusing path rules already keyed for `sandbox`
+    pub(crate) fn with_path_rules(sandbox: SandboxConfig, path_rules: Arc<PathRules>) -> Self {
         Self {
             sandbox,
             request: RequestContext::new(),
+            path_rules,
         }
     }
 
@@ -59,6 +120,12 @@ pub fn with_request_context(mut self, context: RequestContext) -> Self {
         self
     }
 
+    /// Check if a path is accessible under the sandbox's path rules
+    #[inline]
+    pub fn can_access_path(&self, path: &Path) -> bool {
+        self.path_rules.can_access(path)
+    }
+
     pub fn pid(&self) -> Pid {
         self.sandbox.pid
     }
@@ -92,4 +159,15 @@ fn test_evaluation_context() {
 
         assert_eq!(ctx.pid(), 100);
     }
+
+    #[test]
+    fn test_context_checks_paths_with_keyed_rules() {
+        let mut config = SandboxConfig::minimal(100);
+        config.allow_path(std::path::PathBuf::from("/tmp"));
+        let rules = Arc::new(PathRules::new(&config));
+        let ctx = EvaluationContext::with_path_rules(config, rules);
+
+        assert!(ctx.can_access_path(Path::new("/tmp/file")));
+        assert!(!ctx.can_access_path(Path::new("/etc/passwd")));
+    }
 }
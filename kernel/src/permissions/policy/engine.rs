@@ -3,17 +3,24 @@
  * Evaluates permission requests against defined policies
  */
 
-use super::context::EvaluationContext;
+use super::context::{Clock, EvaluationContext, RequestContext, SystemClock};
+use crate::core::sync::RcuCell;
+use crate::permissions::cache::PermissionCache;
 use crate::permissions::types::{Action, PermissionRequest, PermissionResponse, Resource};
 use crate::security::sandbox::capability::{can_access_file, FileOperation};
 use crate::security::sandbox::network::check_network_access;
-use log::debug;
+use log::{debug, info};
+use parking_lot::RwLock;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Weak};
 
 /// Policy decision
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum PolicyDecision {
     Allow,
     Deny,
+    /// Deny with an explanation surfaced in the permission response
+    DenyWithReason(String),
     Abstain,
 }
 
@@ -24,6 +31,13 @@ pub trait Policy: Send + Sync {
 
     /// Policy name
     fn name(&self) -> &str;
+
+    /// Whether the decision for `request` can change with the clock alone
+    ///
+    /// Such decisions are never cached.
+    fn is_time_dependent(&self, _request: &PermissionRequest) -> bool {
+        false
+    }
 }
 
 /// Default policy that uses existing sandbox capabilities
@@ -35,7 +49,7 @@ fn evaluate(&self, request: &PermissionRequest, context: &EvaluationContext) ->
             // File system operations
             (Resource::File { path }, Action::Read) => {
                 if can_access_file(&context.sandbox.capabilities, FileOperation::Read, path)
-                    && context.sandbox.can_access_path(path)
+                    && context.can_access_path(path)
                 {
                     PolicyDecision::Allow
                 } else {
@@ -44,7 +58,7 @@ fn evaluate(&self, request: &PermissionRequest, context: &EvaluationContext) ->
             }
             (Resource::File { path }, Action::Write) => {
                 if can_access_file(&context.sandbox.capabilities, FileOperation::Write, path)
-                    && context.sandbox.can_access_path(path)
+                    && context.can_access_path(path)
                 {
                     PolicyDecision::Allow
                 } else {
@@ -53,7 +67,7 @@ fn evaluate(&self, request: &PermissionRequest, context: &EvaluationContext) ->
             }
             (Resource::File { path }, Action::Create) => {
                 if can_access_file(&context.sandbox.capabilities, FileOperation::Create, path)
-                    && context.sandbox.can_access_path(path)
+                    && context.can_access_path(path)
                 {
                     PolicyDecision::Allow
                 } else {
@@ -62,7 +76,7 @@ fn evaluate(&self, request: &PermissionRequest, context: &EvaluationContext) ->
             }
             (Resource::File { path }, Action::Delete) => {
                 if can_access_file(&context.sandbox.capabilities, FileOperation::Delete, path)
-                    && context.sandbox.can_access_path(path)
+                    && context.can_access_path(path)
                 {
                     PolicyDecision::Allow
                 } else {
@@ -71,7 +85,7 @@ fn evaluate(&self, request: &PermissionRequest, context: &EvaluationContext) ->
             }
             (Resource::Directory { path }, Action::List) => {
                 if can_access_file(&context.sandbox.capabilities, FileOperation::List, path)
-                    && context.sandbox.can_access_path(path)
+                    && context.can_access_path(path)
                 {
                     PolicyDecision::Allow
                 } else {
@@ -172,20 +186,96 @@ fn name(&self) -> &str {
 }
 
 /// Policy engine that evaluates requests through multiple policies
+///
+/// The rule set lives in an RCU cell: each evaluation works on one snapshot,
+/// so a concurrent [`reload`](Self::reload) is seen entirely or not at all.
 pub struct PolicyEngine {
-    policies: Vec<Box<dyn Policy>>,
+    policies: RcuCell<Vec<Arc<dyn Policy>>>,
+    /// Bumped on every reload, so callers can detect decisions made on old rules
+    generation: AtomicU64,
+    /// Caches holding decisions derived from the current rules
+    permission_caches: RwLock<Vec<Weak<PermissionCache>>>,
+    clock: Arc<dyn Clock>,
 }
 
 impl PolicyEngine {
     pub fn new() -> Self {
         Self {
-            policies: vec![Box::new(DefaultPolicy)],
+            policies: RcuCell::new(vec![Arc::new(DefaultPolicy)]),
+            generation: AtomicU64::new(0),
+            permission_caches: RwLock::default(),
+            clock: Arc::new(SystemClock),
         }
     }
 
-    /// Add a policy
+    /// Use a custom time source for request timestamps
+    pub fn with_clock(mut self, clock: Arc<dyn Clock>) -> Self {
+        self.clock = clock;
+        self
+    }
+
+    /// Build a request context stamped by the engine's clock
+    pub fn request_context(&self) -> RequestContext {
+        RequestContext::at(self.clock.now())
+    }
+
+    /// Add a policy (evaluated after existing policies)
     pub fn add_policy(&mut self, policy: Box<dyn Policy>) {
-        self.policies.push(policy);
+        let mut policies = self.policies.load().as_ref().clone();
+        policies.push(Arc::from(policy));
+        self.policies.store(policies);
+    }
+
+    /// Add a policy ahead of all existing policies
+    ///
+    /// Use for restrictions (e.g. time windows) that must be able to deny requests
+    /// the default capability policy would otherwise allow.
+    pub fn prepend_policy(&mut self, policy: Box<dyn Policy>) {
+        let mut policies = self.policies.load().as_ref().clone();
+        policies.insert(0, Arc::from(policy));
+        self.policies.store(policies);
+    }
+
+    /// Atomically replace the whole rule set
+    ///
+    /// In-flight evaluations finish on the rules they started with. Every
+    /// registered permission cache is cleared so earlier decisions don't
+    /// outlive the rules that produced them.
+    pub fn reload(&self, rules: Vec<Box<dyn Policy>>) {
+        let rules: Vec<Arc<dyn Policy>> = rules.into_iter().map(Arc::from).collect();
+        let count = rules.len();
+        self.policies.store(rules);
+        self.generation.fetch_add(1, Ordering::AcqRel);
+
+        for cache in self
+            .permission_caches
+            .read()
+            .iter()
+            .filter_map(Weak::upgrade)
+        {
+            cache.clear();
+        }
+        info!("Reloaded policy engine with {} rules", count);
+    }
+
+    /// Number of reloads so far
+    pub fn generation(&self) -> u64 {
+        self.generation.load(Ordering::Acquire)
+    }
+
+    /// Whether any current rule makes the decision for `request` time-dependent
+    pub fn is_time_dependent(&self, request: &PermissionRequest) -> bool {
+        self.policies
+            .load()
+            .iter()
+            .any(|policy| policy.is_time_dependent(request))
+    }
+
+    /// Clear `cache` whenever the rules are reloaded
+    pub fn register_permission_cache(&self, cache: &Arc<PermissionCache>) {
+        let mut caches = self.permission_caches.write();
+        caches.retain(|cache| cache.strong_count() > 0);
+        caches.push(Arc::downgrade(cache));
     }
 
     /// Evaluate a request through all policies
@@ -199,8 +289,9 @@ pub fn evaluate(
             request.pid, request.action, request.resource
         );
 
-        // Evaluate through all policies
-        for policy in &self.policies {
+        // Evaluate through one snapshot of the rules
+        let policies = self.policies.load();
+        for policy in policies.iter() {
             match policy.evaluate(request, context) {
                 PolicyDecision::Allow => {
                     debug!("Policy '{}' allowed request", policy.name());
@@ -216,6 +307,13 @@ pub fn evaluate(
                         format!("Denied by policy '{}'", policy.name()),
                     );
                 }
+                PolicyDecision::DenyWithReason(reason) => {
+                    debug!("Policy '{}' denied request: {}", policy.name(), reason);
+                    return PermissionResponse::deny(
+                        request.clone(),
+                        format!("Denied by policy '{}': {}", policy.name(), reason),
+                    );
+                }
                 PolicyDecision::Abstain => {
                     debug!("Policy '{}' abstained", policy.name());
                     continue;
@@ -5,6 +5,8 @@
 
 mod context;
 mod engine;
+mod time_window;
 
-pub use context::{EvaluationContext, RequestContext};
+pub use context::{Clock, EvaluationContext, FixedClock, RequestContext, SystemClock};
 pub use engine::{DefaultPolicy, Policy, PolicyDecision, PolicyEngine};
+pub use time_window::{TimeWindow, TimeWindowPolicy};
@@ -4,8 +4,11 @@
  */
 
 use crate::core::types::Pid;
+use crate::security::sandbox::config::PathRules;
 use crate::security::types::SandboxConfig;
 use ahash::HashMap;
+use std::path::Path;
+use std::sync::Arc;
 use std::time::SystemTime;
 
 /// Request context for permission evaluation
@@ -44,13 +47,22 @@ pub struct EvaluationContext {
     pub sandbox: SandboxConfig,
     /// Request context
     pub request: RequestContext,
+    /// The sandbox's path lists keyed for matching
+    path_rules: Arc<PathRules>,
 }
 
 impl EvaluationContext {
     pub fn new(sandbox: SandboxConfig) -> Self {
+        let path_rules = Arc::new(PathRules::new(&sandbox));
+        Self::with_path_rules(sandbox, path_rules)
+    }
+
+    /// Create a context reusing path rules already keyed for `sandbox`
+    pub(crate) fn with_path_rules(sandbox: SandboxConfig, path_rules: Arc<PathRules>) -> Self {
         Self {
             sandbox,
             request: RequestContext::new(),
+            path_rules,
         }
     }
 
@@ -59,6 +71,12 @@ pub fn with_request_context(mut self, context: RequestContext) -> Self {
         self
     }
 
+    /// Check if a path is accessible under the sandbox's path rules
+    #[inline]
+    pub fn can_access_path(&self, path: &Path) -> bool {
+        self.path_rules.can_access(path)
+    }
+
     pub fn pid(&self) -> Pid {
         self.sandbox.pid
     }
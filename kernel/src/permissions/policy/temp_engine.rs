@@ -35,7 +35,7 @@ fn evaluate(&self, request: &PermissionRequest, context: &EvaluationContext) ->
             // File system operations
             (Resource::File { path }, Action::Read) => {
                 if can_access_file(&context.sandbox.capabilities, FileOperation::Read, path)
-                    && context.sandbox.can_access_path(path)
+                    && context.can_access_path(path)
                 {
                     PolicyDecision::Allow
                 } else {
@@ -44,7 +44,7 @@ fn evaluate(&self, request: &PermissionRequest, context: &EvaluationContext) ->
             }
             (Resource::File { path }, Action::Write) => {
                 if can_access_file(&context.sandbox.capabilities, FileOperation::Write, path)
-                    && context.sandbox.can_access_path(path)
+                    && context.can_access_path(path)
                 {
                     PolicyDecision::Allow
                 } else {
@@ -53,7 +53,7 @@ fn evaluate(&self, request: &PermissionRequest, context: &EvaluationContext) ->
             }
             (Resource::File { path }, Action::Create) => {
                 if can_access_file(&context.sandbox.capabilities, FileOperation::Create, path)
-                    && context.sandbox.can_access_path(path)
+                    && context.can_access_path(path)
                 {
                     PolicyDecision::Allow
                 } else {
@@ -62,7 +62,7 @@ fn evaluate(&self, request: &PermissionRequest, context: &EvaluationContext) ->
             }
             (Resource::File { path }, Action::Delete) => {
                 if can_access_file(&context.sandbox.capabilities, FileOperation::Delete, path)
-                    && context.sandbox.can_access_path(path)
+                    && context.can_access_path(path)
                 {
                     PolicyDecision::Allow
                 } else {
@@ -71,7 +71,7 @@ fn evaluate(&self, request: &PermissionRequest, context: &EvaluationContext) ->
             }
             (Resource::Directory { path }, Action::List) => {
                 if can_access_file(&context.sandbox.capabilities, FileOperation::List, path)
-                    && context.sandbox.can_access_path(path)
+                    && context.can_access_path(path)
                 {
                     PolicyDecision::Allow
                 } else {
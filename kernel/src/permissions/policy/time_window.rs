@@ -0,0 +1,235 @@
+/*!
+ * Time-Window Policy
+ * Restricts operations to a daily UTC time window (e.g. business hours)
+ */
+
+use super::context::EvaluationContext;
+use super::engine::{Policy, PolicyDecision};
+use crate::permissions::types::{Action, PermissionRequest, ResourceType};
+use std::fmt;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+const MINUTES_PER_DAY: u16 = 24 * 60;
+
+/// Daily time window in UTC, `[start, end)` at minute granularity
+///
+/// Windows where `end` is earlier than `start` wrap past midnight (e.g. 22:00–06:00).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TimeWindow {
+    start_minute: u16,
+    end_minute: u16,
+}
+
+impl TimeWindow {
+    /// Create a window from UTC hour/minute pairs
+    ///
+    /// Returns None if any component is out of range or the window is empty.
+    pub fn utc(start: (u8, u8), end: (u8, u8)) -> Option<Self> {
+        let to_minutes = |(hour, minute): (u8, u8)| {
+            (hour < 24 && minute < 60).then_some(hour as u16 * 60 + minute as u16)
+        };
+
+        let start_minute = to_minutes(start)?;
+        let end_minute = to_minutes(end)?;
+        if start_minute == end_minute {
+            return None;
+        }
+
+        Some(Self {
+            start_minute,
+            end_minute,
+        })
+    }
+
+    /// Check whether a timestamp falls inside the window
+    pub fn contains(&self, timestamp: SystemTime) -> bool {
+        let minute = minute_of_day(timestamp);
+        if self.start_minute < self.end_minute {
+            minute >= self.start_minute && minute < self.end_minute
+        } else {
+            minute >= self.start_minute || minute < self.end_minute
+        }
+    }
+}
+
+impl fmt::Display for TimeWindow {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{:02}:{:02}-{:02}:{:02} UTC",
+            self.start_minute / 60,
+            self.start_minute % 60,
+            self.end_minute / 60,
+            self.end_minute % 60
+        )
+    }
+}
+
+/// UTC minute of day for a timestamp (pre-epoch times clamp to midnight)
+fn minute_of_day(timestamp: SystemTime) -> u16 {
+    let secs = timestamp
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0);
+    ((secs / 60) % MINUTES_PER_DAY as u64) as u16
+}
+
+/// Policy that denies matching requests outside a daily time window
+///
+/// Abstains inside the window and for requests it doesn't cover, so it only
+/// narrows what other policies allow. Register with
+/// [`PolicyEngine::prepend_policy`](super::PolicyEngine::prepend_policy) so it
+/// runs before the default capability policy.
+pub struct TimeWindowPolicy {
+    name: String,
+    window: TimeWindow,
+    resource_type: Option<ResourceType>,
+    actions: Vec<Action>,
+}
+
+impl TimeWindowPolicy {
+    /// Create a policy covering every request
+    pub fn new(name: impl Into<String>, window: TimeWindow) -> Self {
+        Self {
+            name: name.into(),
+            window,
+            resource_type: None,
+            actions: Vec::new(),
+        }
+    }
+
+    /// Restrict the policy to one resource type
+    pub fn for_resource(mut self, resource_type: ResourceType) -> Self {
+        self.resource_type = Some(resource_type);
+        self
+    }
+
+    /// Restrict the policy to specific actions
+    pub fn for_actions(mut self, actions: impl IntoIterator<Item = Action>) -> Self {
+        self.actions = actions.into_iter().collect();
+        self
+    }
+
+    /// Get the configured window
+    pub fn window(&self) -> TimeWindow {
+        self.window
+    }
+
+    fn applies_to(&self, request: &PermissionRequest) -> bool {
+        self.resource_type
+            .is_none_or(|rt| rt == request.resource.resource_type())
+            && (self.actions.is_empty() || self.actions.contains(&request.action))
+    }
+}
+
+impl Policy for TimeWindowPolicy {
+    fn evaluate(&self, request: &PermissionRequest, context: &EvaluationContext) -> PolicyDecision {
+        if !self.applies_to(request) || self.window.contains(context.request.timestamp) {
+            return PolicyDecision::Abstain;
+        }
+
+        PolicyDecision::DenyWithReason(format!(
+            "{:?} on {:?} only permitted during {}",
+            request.action,
+            request.resource.resource_type(),
+            self.window
+        ))
+    }
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn is_time_dependent(&self, request: &PermissionRequest) -> bool {
+        self.applies_to(request)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::permissions::policy::{FixedClock, PolicyEngine, RequestContext};
+    use crate::security::types::SandboxConfig;
+    use std::sync::Arc;
+    use std::time::Duration;
+
+    fn at(hour: u64, minute: u64) -> SystemTime {
+        // 2024-01-01T00:00:00Z plus offset
+        UNIX_EPOCH + Duration::from_secs(1_704_067_200 + hour * 3600 + minute * 60)
+    }
+
+    #[test]
+    fn test_window_contains() {
+        let window = TimeWindow::utc((9, 0), (17, 0)).unwrap();
+        assert!(window.contains(at(9, 0)));
+        assert!(window.contains(at(16, 59)));
+        assert!(!window.contains(at(17, 0)));
+        assert!(!window.contains(at(3, 30)));
+    }
+
+    #[test]
+    fn test_window_wraps_midnight() {
+        let window = TimeWindow::utc((22, 0), (6, 0)).unwrap();
+        assert!(window.contains(at(23, 15)));
+        assert!(window.contains(at(5, 59)));
+        assert!(!window.contains(at(12, 0)));
+    }
+
+    #[test]
+    fn test_invalid_window() {
+        assert!(TimeWindow::utc((24, 0), (1, 0)).is_none());
+        assert!(TimeWindow::utc((9, 60), (10, 0)).is_none());
+        assert!(TimeWindow::utc((9, 0), (9, 0)).is_none());
+    }
+
+    #[test]
+    fn test_network_only_during_business_hours() {
+        let window = TimeWindow::utc((9, 0), (17, 0)).unwrap();
+        let policy =
+            TimeWindowPolicy::new("business-hours", window).for_resource(ResourceType::Network);
+
+        let config = SandboxConfig::minimal(100);
+        let request = PermissionRequest::net_connect(100, "example.com".into(), Some(443));
+
+        let inside = EvaluationContext::new(config.clone())
+            .with_request_context(RequestContext::at(at(10, 0)));
+        assert_eq!(policy.evaluate(&request, &inside), PolicyDecision::Abstain);
+
+        let outside =
+            EvaluationContext::new(config).with_request_context(RequestContext::at(at(20, 0)));
+        assert!(matches!(
+            policy.evaluate(&request, &outside),
+            PolicyDecision::DenyWithReason(_)
+        ));
+    }
+
+    #[test]
+    fn test_engine_uses_injected_clock() {
+        use crate::security::types::{Capability, NetworkRule};
+
+        let window = TimeWindow::utc((9, 0), (17, 0)).unwrap();
+        let mut config = SandboxConfig::minimal(100);
+        config.grant_capability(Capability::NetworkAccess(NetworkRule::AllowAll));
+        config.network_rules.push(NetworkRule::AllowAll);
+        let request = PermissionRequest::net_connect(100, "example.com".into(), Some(443));
+
+        let engine_at = |time: SystemTime| {
+            let mut engine = PolicyEngine::new().with_clock(Arc::new(FixedClock::new(time)));
+            engine.prepend_policy(Box::new(
+                TimeWindowPolicy::new("business-hours", window).for_resource(ResourceType::Network),
+            ));
+            engine
+        };
+
+        let engine = engine_at(at(12, 0));
+        let ctx =
+            EvaluationContext::new(config.clone()).with_request_context(engine.request_context());
+        assert!(engine.evaluate(&request, &ctx).is_allowed());
+
+        let engine = engine_at(at(18, 30));
+        let ctx = EvaluationContext::new(config).with_request_context(engine.request_context());
+        let response = engine.evaluate(&request, &ctx);
+        assert!(!response.is_allowed());
+        assert!(response.reason().contains("09:00-17:00 UTC"));
+    }
+}
@@ -28,6 +28,17 @@ pub enum PermissionError {
     ContextUnavailable { reason: String },
 }
 
+/// How permission denials are applied
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PermissionMode {
+    /// Denials are returned to the caller
+    #[default]
+    Enforce,
+    /// Denials are audited as would-deny and the request is allowed
+    Permissive,
+}
+
 /// Resource type being accessed
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case", tag = "type")]
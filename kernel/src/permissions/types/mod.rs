@@ -7,7 +7,7 @@
 mod traits;
 
 pub use core::{
-    Action, PermissionError, PermissionRequest, PermissionResponse, PermissionResult, Resource,
-    ResourceType,
+    Action, PermissionError, PermissionMode, PermissionRequest, PermissionResponse,
+    PermissionResult, Resource, ResourceType,
 };
 pub use traits::{PermissionChecker, PermissionProvider, PermissionSystem};
@@ -4,7 +4,9 @@
  */
 
 use crate::core::data_structures::InlineString;
-use crate::core::serialization::serde::{is_false, is_none, is_zero_u64, is_zero_usize};
+use crate::core::serialization::serde::{
+    is_false, is_none, is_zero_u32, is_zero_u64, is_zero_usize,
+};
 use crate::core::types::{Pid, Priority};
 use serde::{Deserialize, Serialize};
 use std::time::Duration;
@@ -58,6 +60,8 @@ pub enum ProcessState {
     Running,
     /// Process is waiting for I/O or event
     Waiting,
+    /// Process is frozen in the scheduler until resumed
+    Paused,
     /// Process has terminated
     Terminated,
 }
@@ -88,6 +92,9 @@ pub struct ProcessInfo {
     pub priority: Priority,
     #[serde(skip_serializing_if = "is_none")]
     pub os_pid: Option<u32>,
+    /// Times this pid number has been reused (0 unless pids are recycled)
+    #[serde(default, skip_serializing_if = "is_zero_u32")]
+    pub generation: u32,
 }
 
 impl ProcessInfo {
@@ -100,6 +107,7 @@ pub fn new(pid: Pid, name: InlineString, priority: Priority) -> Self {
             state: ProcessState::Creating,
             priority,
             os_pid: None,
+            generation: 0,
         }
     }
 
@@ -246,6 +254,15 @@ pub struct SchedulerStats {
     pub active_processes: usize,
     pub policy: SchedulingPolicy,
     pub quantum_micros: u64,
+    /// Median run-queue latency (wake to dispatch) in microseconds
+    #[serde(default, skip_serializing_if = "is_zero_u64")]
+    pub wake_to_run_p50_us: u64,
+    /// 95th percentile run-queue latency in microseconds
+    #[serde(default, skip_serializing_if = "is_zero_u64")]
+    pub wake_to_run_p95_us: u64,
+    /// 99th percentile run-queue latency in microseconds
+    #[serde(default, skip_serializing_if = "is_zero_u64")]
+    pub wake_to_run_p99_us: u64,
 }
 
 /// Per-process CPU usage statistics
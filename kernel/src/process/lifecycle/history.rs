@@ -0,0 +1,225 @@
+/*!
+ * Resource Usage History
+ * Per-process ring buffers of periodic ResourceUsage samples
+ *
+ * A single snapshot can't tell a large process from a leaking one. Keeping
+ * a bounded window of samples per process lets tooling look at the trend.
+ * Buffers are dropped through resource cleanup when the process exits.
+ */
+
+use super::budget::ResourceUsage;
+use crate::core::limits::{RESOURCE_HISTORY_DEPTH, RESOURCE_HISTORY_INTERVAL};
+use crate::core::types::Pid;
+use crate::process::resources::{CleanupStats, ResourceCleanup};
+use ahash::RandomState;
+use dashmap::DashMap;
+use log::{info, warn};
+use std::collections::VecDeque;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+/// Resource history configuration
+#[derive(Debug, Clone, Copy)]
+pub struct ResourceHistoryConfig {
+    /// Time between samples
+    pub interval: Duration,
+    /// Samples kept per process; older samples are overwritten
+    pub depth: usize,
+}
+
+impl ResourceHistoryConfig {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_interval(mut self, interval: Duration) -> Self {
+        self.interval = interval;
+        self
+    }
+
+    pub fn with_depth(mut self, depth: usize) -> Self {
+        self.depth = depth;
+        self
+    }
+}
+
+impl Default for ResourceHistoryConfig {
+    fn default() -> Self {
+        Self {
+            interval: RESOURCE_HISTORY_INTERVAL,
+            depth: RESOURCE_HISTORY_DEPTH,
+        }
+    }
+}
+
+type Samples = VecDeque<(Instant, ResourceUsage)>;
+
+/// Timestamped usage samples per process, oldest first
+#[derive(Clone)]
+pub struct ResourceHistory {
+    config: ResourceHistoryConfig,
+    samples: Arc<DashMap<Pid, Samples, RandomState>>,
+}
+
+impl ResourceHistory {
+    pub fn new(config: ResourceHistoryConfig) -> Self {
+        Self {
+            config,
+            samples: Arc::new(DashMap::with_hasher(RandomState::new())),
+        }
+    }
+
+    /// History configuration
+    pub fn config(&self) -> ResourceHistoryConfig {
+        self.config
+    }
+
+    /// Append a sample for `pid`, evicting the oldest once the buffer is full
+    pub fn record(&self, pid: Pid, usage: ResourceUsage) {
+        let depth = self.config.depth.max(1);
+        let mut buffer = self
+            .samples
+            .entry(pid)
+            .or_insert_with(|| VecDeque::with_capacity(depth));
+        if buffer.len() >= depth {
+            buffer.pop_front();
+        }
+        buffer.push_back((Instant::now(), usage));
+    }
+
+    /// Samples recorded for `pid`, oldest first
+    pub fn get(&self, pid: Pid) -> Vec<(Instant, ResourceUsage)> {
+        self.samples
+            .get(&pid)
+            .map(|buffer| buffer.iter().cloned().collect())
+            .unwrap_or_default()
+    }
+
+    /// Drop the buffer for `pid`, returning how many samples it held
+    pub fn remove(&self, pid: Pid) -> usize {
+        self.samples
+            .remove(&pid)
+            .map(|(_, buffer)| buffer.len())
+            .unwrap_or(0)
+    }
+
+    /// Drop buffers for processes that no longer exist
+    pub fn retain(&self, mut alive: impl FnMut(Pid) -> bool) {
+        self.samples.retain(|pid, _| alive(*pid));
+    }
+
+    /// Whether any samples are held for `pid`
+    pub fn has_history(&self, pid: Pid) -> bool {
+        self.samples.contains_key(&pid)
+    }
+}
+
+impl Default for ResourceHistory {
+    fn default() -> Self {
+        Self::new(ResourceHistoryConfig::default())
+    }
+}
+
+impl ResourceCleanup for ResourceHistory {
+    fn cleanup(&self, pid: Pid) -> CleanupStats {
+        let samples = self.remove(pid);
+        CleanupStats {
+            resources_freed: usize::from(samples > 0),
+            bytes_freed: samples * std::mem::size_of::<(Instant, ResourceUsage)>(),
+            ..Default::default()
+        }
+    }
+
+    fn resource_type(&self) -> &'static str {
+        "resource_history"
+    }
+
+    fn has_resources(&self, pid: Pid) -> bool {
+        self.has_history(pid)
+    }
+}
+
+/// Background task that records a sample for every live process each interval
+///
+/// The task is aborted when the last handle is dropped.
+pub struct ResourceSamplerTask {
+    handle: tokio::task::JoinHandle<()>,
+}
+
+impl ResourceSamplerTask {
+    /// Spawn the sampler on the current tokio runtime
+    ///
+    /// `sample` is called once per interval and is expected to record into
+    /// the history itself. Returns `None` outside a runtime, in which case
+    /// samples are only taken on demand.
+    pub fn spawn<F>(interval: Duration, sample: F) -> Option<Self>
+    where
+        F: Fn() + Send + 'static,
+    {
+        let Ok(runtime) = tokio::runtime::Handle::try_current() else {
+            warn!("No tokio runtime; resource history sampling is on-demand only");
+            return None;
+        };
+
+        let handle = runtime.spawn(async move {
+            let mut ticker = tokio::time::interval(interval);
+            ticker.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Skip);
+            loop {
+                ticker.tick().await;
+                sample();
+            }
+        });
+
+        info!("Resource history sampler spawned ({:?} interval)", interval);
+        Some(Self { handle })
+    }
+}
+
+impl Drop for ResourceSamplerTask {
+    fn drop(&mut self) {
+        self.handle.abort();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn usage(memory_bytes: usize) -> ResourceUsage {
+        ResourceUsage {
+            memory_bytes,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_ring_keeps_newest_samples() {
+        let history = ResourceHistory::new(ResourceHistoryConfig::new().with_depth(3));
+        for bytes in 1..=5 {
+            history.record(1, usage(bytes));
+        }
+
+        let samples = history.get(1);
+        let bytes: Vec<_> = samples.iter().map(|(_, u)| u.memory_bytes).collect();
+        assert_eq!(bytes, vec![3, 4, 5]);
+        assert!(samples.windows(2).all(|w| w[0].0 <= w[1].0));
+        assert!(history.get(2).is_empty());
+    }
+
+    #[test]
+    fn test_cleanup_frees_buffer() {
+        let history = ResourceHistory::default();
+        history.record(1, usage(10));
+        history.record(1, usage(20));
+        history.record(2, usage(30));
+
+        assert!(history.has_resources(1));
+        let stats = history.cleanup(1);
+        assert_eq!(stats.resources_freed, 1);
+        assert!(!history.has_resources(1));
+        assert!(history.has_history(2));
+
+        history.retain(|pid| pid != 2);
+        assert!(!history.has_history(2));
+    }
+}
@@ -1,14 +1,18 @@
 /*!
  * Process Lifecycle Management
- * Process initialization, cleanup, and resource budgeting
+ * Process initialization, cleanup, freezing, resource budgeting and usage history
  */
 
 pub mod budget;
 mod cleanup;
+pub mod freeze;
+pub mod history;
 pub mod lifecycle;
 
 // Re-export public types
 pub use budget::{ResourceBudget, ResourceTracker, ResourceUsage};
+pub use freeze::{FreezeState, Freezer, SyscallGuard};
+pub use history::{ResourceHistory, ResourceHistoryConfig, ResourceSamplerTask};
 pub use lifecycle::{LifecycleError, LifecycleRegistry, LifecycleResult, ProcessInitConfig};
 
 // Internal cleanup utilities
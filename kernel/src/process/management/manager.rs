@@ -3,15 +3,21 @@
  * Handles process creation, scheduling, and lifecycle
  */
 
+use super::pid::{MonotonicPidAllocator, PidAllocator};
 use super::priority;
+use super::tree::ProcessTree;
 use crate::core::types::{Pid, Priority};
 use crate::core::{ShardManager, WorkloadProfile};
 use crate::ipc::IPCManager;
 use crate::memory::MemoryManager;
 use crate::monitoring::Collector;
+use crate::permissions::{Escalation, EscalationKind};
 use crate::process::core::types::{ExecutionConfig, ProcessInfo, ProcessState};
 use crate::process::execution::{PreemptionController, ProcessExecutor};
-use crate::process::lifecycle::{self as cleanup, LifecycleRegistry, ProcessInitConfig};
+use crate::process::lifecycle::{
+    self as cleanup, Freezer, LifecycleRegistry, ProcessInitConfig, ResourceHistory,
+    ResourceSamplerTask,
+};
 use crate::process::resources::ResourceOrchestrator;
 use crate::process::scheduler::{Scheduler, SchedulerTask};
 use crate::security::LimitManager;
@@ -19,7 +25,6 @@
 use dashmap::DashMap;
 use log::info;
 use parking_lot::RwLock;
-use std::sync::atomic::{AtomicU32, Ordering};
 use std::sync::Arc;
 
 // Type alias for backwards compatibility
@@ -28,12 +33,12 @@
 /// Process manager
 ///
 /// # Performance
-/// - Cache-line aligned to prevent false sharing of atomic PID counter (extremely hot path)
-/// - next_pid wrapped in Arc to ensure PID uniqueness across clones (prevents collision bug)
+/// - Cache-line aligned to prevent false sharing of the PID allocator (extremely hot path)
+/// - pid_allocator wrapped in Arc to ensure PID uniqueness across clones (prevents collision bug)
 #[repr(C, align(64))]
 pub struct ProcessManager {
     pub(super) processes: Arc<DashMap<Pid, ProcessInfo, RandomState>>,
-    pub(super) next_pid: Arc<AtomicU32>,
+    pub(super) pid_allocator: Arc<dyn PidAllocator>,
     pub(super) memory_manager: Option<MemoryManager>,
     pub(super) executor: Option<ProcessExecutor>,
     pub(super) limit_manager: Option<LimitManager>,
@@ -44,12 +49,17 @@ pub struct ProcessManager {
     pub(super) fd_manager: Option<crate::syscalls::impls::fd::FdManager>,
     // Comprehensive resource cleanup orchestrator (required)
     pub(super) resource_orchestrator: ResourceOrchestrator,
-    // Track child processes per parent PID for limit enforcement
-    pub(super) child_counts: Arc<DashMap<Pid, u32, RandomState>>,
+    // Parent-child relationships for tree termination and limit enforcement
+    pub(super) tree: ProcessTree,
     // Lifecycle hook coordinator (prevents race conditions during initialization)
     pub(super) lifecycle: Option<LifecycleRegistry>,
     // Observability collector for event streaming
     pub(super) collector: Option<Arc<Collector>>,
+    // Periodic per-process usage samples (freed through resource cleanup)
+    pub(super) resource_history: Option<ResourceHistory>,
+    pub(super) history_sampler: Option<Arc<ResourceSamplerTask>>,
+    // In-flight syscall gates used to quiesce processes for checkpointing
+    pub(super) freezer: Freezer,
 }
 
 impl ProcessManager {
@@ -66,7 +76,7 @@ pub fn new() -> Self {
                 )
                 .into(),
             ),
-            next_pid: Arc::new(AtomicU32::new(1)),
+            pid_allocator: Arc::new(MonotonicPidAllocator::new()),
             memory_manager: None,
             executor: None,
             limit_manager: None,
@@ -76,16 +86,12 @@ pub fn new() -> Self {
             preemption: None,
             fd_manager: None,
             resource_orchestrator: ResourceOrchestrator::new(),
-            child_counts: Arc::new(
-                DashMap::with_capacity_and_hasher_and_shard_amount(
-                    0,
-                    RandomState::new(),
-                    ShardManager::shards(WorkloadProfile::MediumContention), // child tracking: moderate access
-                )
-                .into(),
-            ),
+            tree: ProcessTree::new(),
             lifecycle: None,
             collector: None,
+            resource_history: None,
+            history_sampler: None,
+            freezer: Freezer::new(),
         }
     }
 
@@ -106,8 +112,20 @@ pub fn create_process_with_command(
         priority: Priority,
         config: Option<ExecutionConfig>,
     ) -> u32 {
-        // Allocate PID atomically
-        let pid = self.next_pid.fetch_add(1, Ordering::SeqCst);
+        self.create_linked_process(name, priority, config, None)
+    }
+
+    /// Create a process, linking it under `parent` before it becomes visible
+    pub(super) fn create_linked_process(
+        &self,
+        name: String,
+        priority: Priority,
+        config: Option<ExecutionConfig>,
+        parent: Option<Pid>,
+    ) -> u32 {
+        // Allocate PID through the configured policy
+        let slot = self.pid_allocator.allocate();
+        let pid = slot.pid;
 
         // Create process in Creating state (not yet initialized)
         let mut process = ProcessInfo {
@@ -116,6 +134,7 @@ pub fn create_process_with_command(
             state: ProcessState::Creating, // Start in Creating state
             priority,
             os_pid: None,
+            generation: slot.generation,
         };
 
         // Spawn OS process if command provided and executor available
@@ -163,7 +182,15 @@ pub fn create_process_with_command(
         // CRITICAL: Run lifecycle initialization hooks BEFORE making process schedulable
         // This prevents race conditions where process tries to use uninitialized resources
         process.state = ProcessState::Initializing;
+        if let Some(parent) = parent {
+            self.tree.link(parent, pid);
+        }
         self.processes.insert(pid, process.clone());
+        self.freezer.track(pid);
+        if parent.is_some_and(|parent| !self.processes.contains_key(&parent)) {
+            // The parent exited after it was checked and may have missed us
+            self.tree.unlink(pid);
+        }
 
         if let Some(ref lifecycle) = self.lifecycle {
             let init_config = ProcessInitConfig::default();
@@ -217,11 +244,25 @@ pub fn get_process(&self, pid: Pid) -> Option<ProcessInfo> {
         self.processes.get(&pid).map(|r| r.value().clone())
     }
 
+    /// Check that `pid` still names the process seen at `generation`
+    ///
+    /// Returns false once the pid was recycled for a newer process.
+    #[inline]
+    #[must_use]
+    pub fn is_current(&self, pid: Pid, generation: u32) -> bool {
+        self.processes
+            .get(&pid)
+            .is_some_and(|r| r.value().generation == generation)
+    }
+
     /// Terminate process by PID
     pub fn terminate_process(&self, pid: Pid) -> bool {
         if let Some((_, process)) = self.processes.remove(&pid) {
             info!("Terminating process: PID {}", pid);
 
+            // Detach from parent; surviving children become orphans
+            self.tree.unlink(pid);
+
             // Emit observability event
             if let Some(ref collector) = self.collector {
                 collector.process_terminated(pid, None);
@@ -232,6 +273,7 @@ pub fn terminate_process(&self, pid: Pid) -> bool {
 
             // Remove from scheduler
             cleanup::cleanup_scheduler(pid, &self.scheduler);
+            self.freezer.remove(pid);
 
             // Notify preemption controller
             cleanup::cleanup_preemption(pid, &self.preemption);
@@ -260,14 +302,32 @@ pub fn terminate_process(&self, pid: Pid) -> bool {
                     result.stats.by_type.clone(),
                     result.errors.clone(),
                 );
+                collector.memory_leaked(pid, &result.stats.leaked_by_tag);
             }
 
+            // Only now is the pid free for the allocator to hand out again
+            self.pid_allocator.release(pid);
+
             true
         } else {
             false
         }
     }
 
+    /// Carry out a throttle or kill request queued by the permission audit log
+    ///
+    /// Throttling drops the process to the lowest priority. Returns whether
+    /// the process was found and acted on; other kinds are ignored.
+    pub fn enforce_escalation(&self, escalation: &Escalation) -> bool {
+        match escalation.kind {
+            EscalationKind::Kill => self.terminate_process(escalation.pid),
+            EscalationKind::Throttle => {
+                self.set_process_priority(escalation.pid, crate::scheduler::MIN_PRIORITY)
+            }
+            EscalationKind::Alert | EscalationKind::Callback => false,
+        }
+    }
+
     /// List all processes
     pub fn list_processes(&self) -> Vec<ProcessInfo> {
         use crate::core::optimization::prefetch_read;
@@ -305,6 +365,12 @@ pub fn executor(&self) -> Option<&ProcessExecutor> {
         self.executor.as_ref()
     }
 
+    /// Resource types cleaned up when a process terminates
+    #[must_use]
+    pub fn resource_types(&self) -> Vec<&'static str> {
+        self.resource_orchestrator.registered_types()
+    }
+
     /// Check if process has OS execution
     ///
     /// # Performance
@@ -325,23 +391,7 @@ pub fn has_os_process(&self, pid: Pid) -> bool {
     #[inline]
     #[must_use]
     pub fn get_child_count(&self, pid: Pid) -> u32 {
-        self.child_counts.get(&pid).map(|r| *r.value()).unwrap_or(0)
-    }
-
-    /// Increment child count for a PID
-    #[allow(dead_code)]
-    pub(super) fn increment_child_count(&self, pid: Pid) {
-        // Use entry() for atomic increment
-        *self.child_counts.entry(pid).or_insert(0) += 1;
-    }
-
-    /// Decrement child count for a PID
-    #[allow(dead_code)]
-    pub(super) fn decrement_child_count(&self, pid: Pid) {
-        // Use get_mut() for atomic decrement
-        if let Some(mut count) = self.child_counts.get_mut(&pid) {
-            *count = count.saturating_sub(1);
-        }
+        self.tree.child_count(pid)
     }
 }
 
@@ -349,7 +399,7 @@ impl Clone for ProcessManager {
     fn clone(&self) -> Self {
         Self {
             processes: Arc::clone(&self.processes),
-            next_pid: Arc::clone(&self.next_pid), // Share PID counter to prevent collision
+            pid_allocator: Arc::clone(&self.pid_allocator), // Share allocator to prevent collision
             memory_manager: self.memory_manager.clone(),
             executor: self.executor.clone(),
             limit_manager: None, // Limit manager is not Clone, create new if needed
@@ -359,9 +409,12 @@ fn clone(&self) -> Self {
             preemption: self.preemption.clone(),
             fd_manager: self.fd_manager.clone(),
             resource_orchestrator: self.resource_orchestrator.clone(), // Share orchestrator (Arc-wrapped)
-            child_counts: Arc::clone(&self.child_counts),
+            tree: self.tree.clone(),
             lifecycle: self.lifecycle.clone(),
             collector: self.collector.clone(),
+            resource_history: self.resource_history.clone(),
+            history_sampler: self.history_sampler.clone(),
+            freezer: self.freezer.clone(),
         }
     }
 }
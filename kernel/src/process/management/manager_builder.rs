@@ -4,13 +4,17 @@
  */
 
 use super::manager::ProcessManager;
+use super::pid::{MonotonicPidAllocator, PidAllocator};
+use super::tree::ProcessTree;
 use crate::core::{ShardManager, WorkloadProfile};
 use crate::ipc::IPCManager;
 use crate::memory::MemoryManager;
 use crate::monitoring::Collector;
 use crate::process::core::types::SchedulingPolicy;
 use crate::process::execution::{PreemptionController, ProcessExecutor};
-use crate::process::lifecycle::LifecycleRegistry;
+use crate::process::lifecycle::{
+    Freezer, LifecycleRegistry, ResourceHistory, ResourceHistoryConfig,
+};
 use crate::process::resources::ResourceOrchestrator;
 use crate::process::scheduler::{Scheduler, SchedulerTask};
 use crate::security::LimitManager;
@@ -18,7 +22,6 @@
 use dashmap::DashMap;
 use log::info;
 use parking_lot::RwLock;
-use std::sync::atomic::AtomicU32;
 use std::sync::Arc;
 
 /// Builder for ProcessManager
@@ -32,6 +35,8 @@ pub struct ProcessManagerBuilder {
     resource_orchestrator: ResourceOrchestrator,
     signal_manager: Option<Arc<crate::signals::SignalManagerImpl>>,
     collector: Option<Arc<Collector>>,
+    resource_history: Option<ResourceHistoryConfig>,
+    pid_allocator: Option<Arc<dyn PidAllocator>>,
 }
 
 impl ProcessManagerBuilder {
@@ -47,6 +52,8 @@ pub fn new() -> Self {
             resource_orchestrator: ResourceOrchestrator::new(),
             signal_manager: None,
             collector: None,
+            resource_history: None,
+            pid_allocator: None,
         }
     }
 
@@ -107,6 +114,18 @@ pub fn with_collector(mut self, collector: Arc<Collector>) -> Self {
         self
     }
 
+    /// Record periodic per-process resource usage samples
+    pub fn with_resource_history(mut self, config: ResourceHistoryConfig) -> Self {
+        self.resource_history = Some(config);
+        self
+    }
+
+    /// Use a custom PID allocation policy (monotonic by default)
+    pub fn with_pid_allocator(mut self, allocator: Arc<dyn PidAllocator>) -> Self {
+        self.pid_allocator = Some(allocator);
+        self
+    }
+
     /// Build the ProcessManager
     pub fn build(self) -> ProcessManager {
         let executor = if self.enable_executor {
@@ -151,6 +170,32 @@ pub fn build(self) -> ProcessManager {
             self.resource_orchestrator
         };
 
+        // CPU-topology-aware shard counts for optimal concurrent performance
+        let processes = Arc::new(DashMap::with_capacity_and_hasher_and_shard_amount(
+            0,
+            RandomState::new(),
+            ShardManager::shards(WorkloadProfile::HighContention), // process table: heavy concurrent access
+        ));
+
+        // Usage history is freed through cleanup like any other per-process resource
+        let (orchestrator, resource_history, history_sampler) = match self.resource_history {
+            Some(config) => {
+                let history = ResourceHistory::new(config);
+                let sampler = super::usage::spawn_sampler(
+                    Arc::clone(&processes),
+                    history.clone(),
+                    self.memory_manager.clone(),
+                    self.fd_manager.clone(),
+                );
+                (
+                    orchestrator.register(history.clone()),
+                    Some(history),
+                    sampler,
+                )
+            }
+            None => (orchestrator, None, None),
+        };
+
         let scheduler = self
             .scheduler_policy
             .map(|policy| Arc::new(RwLock::new(Scheduler::new(policy).into())));
@@ -199,6 +244,9 @@ pub fn build(self) -> ProcessManager {
         if orchestrator.resource_count() > 0 {
             features.push("unified-resource-cleanup");
         }
+        if resource_history.is_some() {
+            features.push("resource-history");
+        }
 
         // Build lifecycle registry if we have relevant managers
         // This coordinates initialization hooks across subsystems
@@ -234,16 +282,10 @@ pub fn build(self) -> ProcessManager {
         info!("Process manager initialized with: {}", features.join(", "));
 
         ProcessManager {
-            // CPU-topology-aware shard counts for optimal concurrent performance
-            processes: Arc::new(
-                DashMap::with_capacity_and_hasher_and_shard_amount(
-                    0,
-                    RandomState::new(),
-                    ShardManager::shards(WorkloadProfile::HighContention), // process table: heavy concurrent access
-                )
-                .into(),
-            ),
-            next_pid: Arc::new(AtomicU32::new(1)),
+            processes,
+            pid_allocator: self
+                .pid_allocator
+                .unwrap_or_else(|| Arc::new(MonotonicPidAllocator::new())),
             memory_manager: self.memory_manager,
             executor,
             limit_manager,
@@ -253,16 +295,12 @@ pub fn build(self) -> ProcessManager {
             preemption,
             fd_manager: self.fd_manager,
             resource_orchestrator: orchestrator,
-            child_counts: Arc::new(
-                DashMap::with_capacity_and_hasher_and_shard_amount(
-                    0,
-                    RandomState::new(),
-                    ShardManager::shards(WorkloadProfile::MediumContention), // child tracking: moderate access
-                )
-                .into(),
-            ),
+            tree: ProcessTree::new(),
             lifecycle,
             collector: self.collector,
+            resource_history,
+            history_sampler,
+            freezer: Freezer::new(),
         }
     }
 }
@@ -6,10 +6,12 @@
 use super::manager::ProcessManager;
 use super::priority;
 use crate::core::types::{Pid, Priority};
-use crate::process::core::types::{ProcessStats, SchedulerStats, SchedulingPolicy};
+use crate::process::core::types::{ProcessState, ProcessStats, SchedulerStats, SchedulingPolicy};
+use crate::process::lifecycle::{FreezeState, SyscallGuard};
 use crate::process::scheduler::SchedulerTask;
 use log::info;
 use std::sync::Arc;
+use std::time::Duration;
 
 impl ProcessManager {
     /// Get scheduler statistics
@@ -151,6 +153,144 @@ pub fn lower_process_priority(&self, pid: Pid) -> Result<Priority, String> {
         }
     }
 
+    /// Pause a process so the scheduler never selects it until resumed
+    ///
+    /// The process keeps its place in the scheduler (priority, vruntime and
+    /// CPU accounting) and can still be terminated while paused.
+    pub fn pause_process(&self, pid: Pid) -> Result<(), String> {
+        let scheduler = self
+            .scheduler
+            .as_ref()
+            .ok_or_else(|| "Scheduler not available".to_string())?;
+
+        let state = self
+            .processes
+            .get(&pid)
+            .map(|r| r.value().state)
+            .ok_or_else(|| format!("Process {} not found", pid))?;
+        if state == ProcessState::Paused {
+            return Err(format!("Process {} is already paused", pid));
+        }
+        if !matches!(
+            state,
+            ProcessState::Ready | ProcessState::Running | ProcessState::Waiting
+        ) {
+            return Err(format!(
+                "Process {} cannot be paused in state {:?}",
+                pid, state
+            ));
+        }
+
+        if !scheduler.read().pause(pid) {
+            return Err(format!("Process {} is not scheduled", pid));
+        }
+
+        if let Some(mut entry) = self.processes.get_mut(&pid) {
+            entry.state = ProcessState::Paused;
+        }
+        info!("Paused PID {}", pid);
+        Ok(())
+    }
+
+    /// Resume a paused process
+    pub fn resume_process(&self, pid: Pid) -> Result<(), String> {
+        let scheduler = self
+            .scheduler
+            .as_ref()
+            .ok_or_else(|| "Scheduler not available".to_string())?;
+
+        let state = self
+            .processes
+            .get(&pid)
+            .map(|r| r.value().state)
+            .ok_or_else(|| format!("Process {} not found", pid))?;
+        if state != ProcessState::Paused || !scheduler.read().resume(pid) {
+            return Err(format!("Process {} is not paused", pid));
+        }
+
+        if let Some(mut entry) = self.processes.get_mut(&pid) {
+            entry.state = ProcessState::Ready;
+        }
+        info!("Resumed PID {}", pid);
+        Ok(())
+    }
+
+    /// Freeze a process ahead of checkpoint or migration
+    ///
+    /// Unlike pausing, freezing also quiesces I/O: new syscalls from the
+    /// process fail with WouldBlock and the returned state reports whether
+    /// syscalls already running have drained. Scheduling is paused too when
+    /// a scheduler is present. Use [`Self::wait_frozen`] to wait for the drain.
+    pub fn freeze(&self, pid: Pid) -> Result<FreezeState, String> {
+        let state = self
+            .processes
+            .get(&pid)
+            .map(|r| r.value().state)
+            .ok_or_else(|| format!("Process {} not found", pid))?;
+        if !matches!(
+            state,
+            ProcessState::Ready
+                | ProcessState::Running
+                | ProcessState::Waiting
+                | ProcessState::Paused
+        ) {
+            return Err(format!(
+                "Process {} cannot be frozen in state {:?}",
+                pid, state
+            ));
+        }
+
+        // Close the gate before pausing so nothing new starts in between
+        let freeze_state = self
+            .freezer
+            .freeze(pid)
+            .ok_or_else(|| format!("Process {} is already frozen", pid))?;
+
+        // A process the caller already paused stays paused after thaw
+        if self.scheduler.is_some() && state != ProcessState::Paused {
+            if let Err(e) = self.pause_process(pid) {
+                self.freezer.thaw(pid);
+                return Err(e);
+            }
+            self.freezer.mark_paused(pid);
+        }
+
+        info!("Freezing PID {}: {:?}", pid, freeze_state);
+        Ok(self.freezer.state(pid).unwrap_or(freeze_state))
+    }
+
+    /// Wait up to `timeout` for a frozen process's in-flight syscalls to drain
+    pub fn wait_frozen(&self, pid: Pid, timeout: Duration) -> Result<FreezeState, String> {
+        self.freezer
+            .wait_quiescent(pid, timeout)
+            .ok_or_else(|| format!("Process {} is not frozen", pid))
+    }
+
+    /// Freeze progress, or `None` if the process isn't frozen
+    pub fn freeze_state(&self, pid: Pid) -> Option<FreezeState> {
+        self.freezer.state(pid)
+    }
+
+    /// Thaw a frozen process, resuming it if freezing paused it
+    pub fn thaw(&self, pid: Pid) -> Result<(), String> {
+        let paused = self
+            .freezer
+            .thaw(pid)
+            .ok_or_else(|| format!("Process {} is not frozen", pid))?;
+        if paused {
+            self.resume_process(pid)?;
+        }
+
+        info!("Thawed PID {}", pid);
+        Ok(())
+    }
+
+    /// Mark a syscall from `pid` as in flight, or `None` if it's frozen
+    #[inline]
+    pub fn enter_syscall(&self, pid: Pid) -> Option<SyscallGuard> {
+        self.freezer.enter(pid)
+    }
+
     /// Set scheduler time quantum (requires scheduler)
     pub fn set_time_quantum(&self, quantum_micros: u64) -> Result<(), String> {
         let quantum = std::time::Duration::from_micros(quantum_micros);
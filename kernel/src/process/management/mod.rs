@@ -6,11 +6,15 @@
 pub mod manager;
 pub mod manager_builder;
 pub mod manager_scheduler;
+pub mod pid;
 mod priority;
+mod tree;
+mod usage;
 
 // Re-export public types
 pub use manager::{Process, ProcessManager};
 pub use manager_builder::ProcessManagerBuilder;
+pub use pid::{MonotonicPidAllocator, PidAllocator, PidSlot, RecyclingPidAllocator};
 
 // Type alias for backwards compatibility
 pub use manager::ProcessManager as ProcessManagerImpl;
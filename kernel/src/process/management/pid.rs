@@ -0,0 +1,147 @@
+/*!
+ * PID Allocation
+ * Pluggable process id allocation policies
+ */
+
+use crate::core::types::Pid;
+use ahash::HashMap;
+use parking_lot::Mutex;
+use std::collections::BTreeSet;
+use std::sync::atomic::{AtomicU32, Ordering};
+
+/// A freshly allocated pid and how many times that number has been reused
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PidSlot {
+    pub pid: Pid,
+    pub generation: u32,
+}
+
+/// Policy for assigning process ids
+///
+/// `release` is called once a process has been fully cleaned up, so a
+/// recycling policy never hands out a pid whose resources are still live.
+pub trait PidAllocator: Send + Sync {
+    /// Allocate the next pid
+    fn allocate(&self) -> PidSlot;
+
+    /// Return a pid to the policy after its process terminated
+    fn release(&self, pid: Pid);
+}
+
+/// Increasing pids starting at 1, never reused (the default policy)
+pub struct MonotonicPidAllocator {
+    next: AtomicU32,
+}
+
+impl MonotonicPidAllocator {
+    pub fn new() -> Self {
+        Self {
+            next: AtomicU32::new(1),
+        }
+    }
+}
+
+impl Default for MonotonicPidAllocator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl PidAllocator for MonotonicPidAllocator {
+    #[inline]
+    fn allocate(&self) -> PidSlot {
+        PidSlot {
+            pid: self.next.fetch_add(1, Ordering::SeqCst),
+            generation: 0,
+        }
+    }
+
+    #[inline]
+    fn release(&self, _pid: Pid) {}
+}
+
+/// Reuses the lowest freed pid, bumping its generation on every reuse
+///
+/// Allocation order is deterministic, and a `(pid, generation)` pair held
+/// from before the reuse no longer matches, exposing stale references.
+pub struct RecyclingPidAllocator {
+    state: Mutex<RecycleState>,
+}
+
+struct RecycleState {
+    next: Pid,
+    free: BTreeSet<Pid>,
+    generations: HashMap<Pid, u32>,
+}
+
+impl RecyclingPidAllocator {
+    pub fn new() -> Self {
+        Self {
+            state: Mutex::new(RecycleState {
+                next: 1,
+                free: BTreeSet::new(),
+                generations: HashMap::default(),
+            }),
+        }
+    }
+}
+
+impl Default for RecyclingPidAllocator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl PidAllocator for RecyclingPidAllocator {
+    fn allocate(&self) -> PidSlot {
+        let mut state = self.state.lock();
+        match state.free.pop_first() {
+            Some(pid) => {
+                let generation = state.generations.entry(pid).or_insert(0);
+                *generation = generation.wrapping_add(1);
+                PidSlot {
+                    pid,
+                    generation: *generation,
+                }
+            }
+            None => {
+                let pid = state.next;
+                state.next += 1;
+                PidSlot { pid, generation: 0 }
+            }
+        }
+    }
+
+    fn release(&self, pid: Pid) {
+        let mut state = self.state.lock();
+        // Ignore pids this allocator never handed out
+        if pid != 0 && pid < state.next {
+            state.free.insert(pid);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_recycling_reuses_lowest_free_pid() {
+        let pids = RecyclingPidAllocator::new();
+        let slots: Vec<_> = (0..3).map(|_| pids.allocate().pid).collect();
+        assert_eq!(slots, vec![1, 2, 3]);
+
+        pids.release(3);
+        pids.release(2);
+        pids.release(2);
+        pids.release(99);
+
+        let next = || {
+            let slot = pids.allocate();
+            (slot.pid, slot.generation)
+        };
+        assert_eq!(next(), (2, 1));
+        assert_eq!(next(), (3, 1));
+        assert_eq!(next(), (4, 0));
+    }
+}
@@ -0,0 +1,194 @@
+/*!
+ * Process Tree
+ * Parent-child tracking and recursive termination
+ */
+
+use super::manager::ProcessManager;
+use crate::core::types::{Pid, Priority};
+use crate::core::{ShardManager, WorkloadProfile};
+use crate::process::core::types::ExecutionConfig;
+use ahash::RandomState;
+use dashmap::DashMap;
+use log::info;
+use std::collections::HashSet;
+use std::sync::Arc;
+
+/// Parent-child relationships between processes
+///
+/// Both directions are indexed so that unlinking a pid and walking its
+/// descendants are each a handful of map lookups.
+#[derive(Clone)]
+pub(super) struct ProcessTree {
+    children: Arc<DashMap<Pid, HashSet<Pid>, RandomState>>,
+    parents: Arc<DashMap<Pid, Pid, RandomState>>,
+}
+
+impl ProcessTree {
+    pub(super) fn new() -> Self {
+        // child tracking: moderate access
+        let shards = ShardManager::shards(WorkloadProfile::MediumContention);
+        Self {
+            children: Arc::new(DashMap::with_capacity_and_hasher_and_shard_amount(
+                0,
+                RandomState::new(),
+                shards,
+            )),
+            parents: Arc::new(DashMap::with_capacity_and_hasher_and_shard_amount(
+                0,
+                RandomState::new(),
+                shards,
+            )),
+        }
+    }
+
+    pub(super) fn link(&self, parent: Pid, child: Pid) {
+        self.parents.insert(child, parent);
+        self.children.entry(parent).or_default().insert(child);
+    }
+
+    /// Remove a pid from the tree, detaching it from its parent and
+    /// orphaning its children
+    pub(super) fn unlink(&self, pid: Pid) {
+        if let Some((_, parent)) = self.parents.remove(&pid) {
+            self.children.remove_if_mut(&parent, |_, siblings| {
+                siblings.remove(&pid);
+                siblings.is_empty()
+            });
+        }
+
+        if let Some((_, orphans)) = self.children.remove(&pid) {
+            for orphan in orphans {
+                self.parents.remove_if(&orphan, |_, parent| *parent == pid);
+            }
+        }
+    }
+
+    fn parent(&self, pid: Pid) -> Option<Pid> {
+        self.parents.get(&pid).map(|r| *r.value())
+    }
+
+    fn children(&self, pid: Pid) -> Vec<Pid> {
+        let mut children: Vec<Pid> = self
+            .children
+            .get(&pid)
+            .map(|r| r.value().iter().copied().collect())
+            .unwrap_or_default();
+        children.sort_unstable();
+        children
+    }
+
+    pub(super) fn child_count(&self, pid: Pid) -> u32 {
+        self.children
+            .get(&pid)
+            .map(|r| r.value().len() as u32)
+            .unwrap_or(0)
+    }
+
+    /// All descendants of `pid`, deepest first
+    fn descendants(&self, pid: Pid) -> Vec<Pid> {
+        let mut order = Vec::new();
+        let mut stack = vec![pid];
+        let mut seen = HashSet::new();
+
+        while let Some(current) = stack.pop() {
+            for child in self.children(current) {
+                if seen.insert(child) {
+                    order.push(child);
+                    stack.push(child);
+                }
+            }
+        }
+
+        // Pre-order reversed: every child precedes its parent
+        order.reverse();
+        order
+    }
+}
+
+impl ProcessManager {
+    /// Create a process as a child of `parent`
+    ///
+    /// Returns `None` if the parent does not exist.
+    pub fn create_child_process(
+        &self,
+        parent: Pid,
+        name: String,
+        priority: Priority,
+        config: Option<ExecutionConfig>,
+    ) -> Option<Pid> {
+        if !self.processes.contains_key(&parent) {
+            return None;
+        }
+
+        Some(self.create_linked_process(name, priority, config, Some(parent)))
+    }
+
+    /// Get the parent of a process, if it has one
+    #[must_use]
+    pub fn get_parent(&self, pid: Pid) -> Option<Pid> {
+        self.tree.parent(pid)
+    }
+
+    /// Get the direct children of a process, sorted by PID
+    #[must_use]
+    pub fn get_children(&self, pid: Pid) -> Vec<Pid> {
+        self.tree.children(pid)
+    }
+
+    /// Terminate a process and all of its descendants
+    ///
+    /// Descendants are terminated deepest first, each with full resource
+    /// cleanup. Returns the PIDs that were terminated, in order.
+    pub fn terminate_tree(&self, pid: Pid) -> Vec<Pid> {
+        let mut terminated = Vec::new();
+
+        for target in self.tree.descendants(pid).into_iter().chain([pid]) {
+            if self.terminate_process(target) {
+                terminated.push(target);
+            }
+        }
+
+        if !terminated.is_empty() {
+            info!(
+                "Terminated process tree rooted at PID {} ({} processes)",
+                pid,
+                terminated.len()
+            );
+        }
+
+        terminated
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_unlink_orphans_children() {
+        let tree = ProcessTree::new();
+        tree.link(1, 2);
+        tree.link(2, 3);
+        tree.link(2, 4);
+
+        tree.unlink(2);
+
+        assert_eq!(tree.child_count(1), 0);
+        assert_eq!(tree.parent(3), None);
+        assert_eq!(tree.parent(4), None);
+        assert!(tree.children(2).is_empty());
+    }
+
+    #[test]
+    fn test_descendants_deepest_first() {
+        let tree = ProcessTree::new();
+        tree.link(1, 2);
+        tree.link(2, 3);
+        tree.link(1, 4);
+
+        let order = tree.descendants(1);
+        assert_eq!(order.len(), 3);
+        let pos = |pid| order.iter().position(|&p| p == pid).unwrap();
+        assert!(pos(3) < pos(2));
+    }
+}
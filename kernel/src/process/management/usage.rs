@@ -0,0 +1,109 @@
+/*!
+ * Resource Usage Sampling
+ * Current and historical per-process resource usage
+ */
+
+use super::manager::ProcessManager;
+use crate::core::types::Pid;
+use crate::memory::MemoryManager;
+use crate::process::core::types::ProcessInfo;
+use crate::process::lifecycle::{ResourceHistory, ResourceSamplerTask, ResourceUsage};
+use crate::syscalls::impls::fd::FdManager;
+use ahash::RandomState;
+use dashmap::DashMap;
+use std::sync::Arc;
+use std::time::Instant;
+
+/// Assemble a usage snapshot from the managers a ProcessManager was built with
+///
+/// Only O(1) per-process counters are read so sampling stays cheap.
+fn snapshot(
+    pid: Pid,
+    memory_manager: Option<&MemoryManager>,
+    fd_manager: Option<&FdManager>,
+) -> ResourceUsage {
+    ResourceUsage {
+        memory_bytes: memory_manager
+            .map(|mm| mm.get_process_memory_details(pid).0)
+            .unwrap_or(0),
+        file_descriptors: fd_manager
+            .map(|fds| fds.get_fd_count(pid) as usize)
+            .unwrap_or(0),
+        ..Default::default()
+    }
+}
+
+/// Record one sample per live process and drop buffers of exited ones
+///
+/// Pruning catches a sample that raced with termination and landed after
+/// the process's cleanup already freed its buffer.
+fn sample_all(
+    processes: &DashMap<Pid, ProcessInfo, RandomState>,
+    history: &ResourceHistory,
+    memory_manager: Option<&MemoryManager>,
+    fd_manager: Option<&FdManager>,
+) {
+    let pids: Vec<Pid> = processes.iter().map(|entry| *entry.key()).collect();
+    for pid in pids {
+        history.record(pid, snapshot(pid, memory_manager, fd_manager));
+    }
+    history.retain(|pid| processes.contains_key(&pid));
+}
+
+/// Spawn the periodic sampler for a manager's process table
+pub(super) fn spawn_sampler(
+    processes: Arc<DashMap<Pid, ProcessInfo, RandomState>>,
+    history: ResourceHistory,
+    memory_manager: Option<MemoryManager>,
+    fd_manager: Option<FdManager>,
+) -> Option<Arc<ResourceSamplerTask>> {
+    let interval = history.config().interval;
+    ResourceSamplerTask::spawn(interval, move || {
+        sample_all(
+            &processes,
+            &history,
+            memory_manager.as_ref(),
+            fd_manager.as_ref(),
+        )
+    })
+    .map(Arc::new)
+}
+
+impl ProcessManager {
+    /// Current resource usage of a process
+    ///
+    /// Memory and file descriptor counts are filled in when the matching
+    /// manager is configured; other counters are reported as zero.
+    #[must_use]
+    pub fn resource_usage(&self, pid: Pid) -> Option<ResourceUsage> {
+        self.processes
+            .contains_key(&pid)
+            .then(|| snapshot(pid, self.memory_manager.as_ref(), self.fd_manager.as_ref()))
+    }
+
+    /// Periodic usage samples for a process, oldest first
+    ///
+    /// Empty unless the manager was built with resource history enabled.
+    #[must_use]
+    pub fn resource_history(&self, pid: Pid) -> Vec<(Instant, ResourceUsage)> {
+        self.resource_history
+            .as_ref()
+            .map(|history| history.get(pid))
+            .unwrap_or_default()
+    }
+
+    /// Record a usage sample for every live process now
+    ///
+    /// The background sampler calls this each interval; it is exposed for
+    /// callers without a tokio runtime and for tests.
+    pub fn sample_resource_usage(&self) {
+        if let Some(ref history) = self.resource_history {
+            sample_all(
+                &self.processes,
+                history,
+                self.memory_manager.as_ref(),
+                self.fd_manager.as_ref(),
+            );
+        }
+    }
+}
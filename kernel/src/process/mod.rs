@@ -27,12 +27,16 @@
 
 // Re-export lifecycle types
 pub use lifecycle::{
-    LifecycleError, LifecycleRegistry, LifecycleResult, ProcessInitConfig, ResourceBudget,
-    ResourceTracker, ResourceUsage,
+    FreezeState, Freezer, LifecycleError, LifecycleRegistry, LifecycleResult, ProcessInitConfig,
+    ResourceBudget, ResourceHistory, ResourceHistoryConfig, ResourceTracker, ResourceUsage,
+    SyscallGuard,
 };
 
 // Re-export management types
-pub use management::{Process, ProcessManager, ProcessManagerBuilder, ProcessManagerImpl};
+pub use management::{
+    MonotonicPidAllocator, PidAllocator, PidSlot, Process, ProcessManager, ProcessManagerBuilder,
+    ProcessManagerImpl, RecyclingPidAllocator,
+};
 
 // Re-export scheduler types
 pub use scheduler::{Scheduler, SchedulerCommand, SchedulerTask};
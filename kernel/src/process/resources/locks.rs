@@ -0,0 +1,42 @@
+/*!
+ * File Lock Resource Cleanup
+ * Releases advisory range locks held by a process
+ */
+
+use super::{CleanupStats, ResourceCleanup};
+use crate::core::types::Pid;
+use crate::vfs::RangeLockTable;
+
+/// Advisory file lock cleanup wrapper
+pub struct FileLockResource {
+    locks: RangeLockTable,
+}
+
+impl FileLockResource {
+    pub fn new(locks: RangeLockTable) -> Self {
+        Self { locks }
+    }
+}
+
+impl ResourceCleanup for FileLockResource {
+    fn cleanup(&self, pid: Pid) -> CleanupStats {
+        let count = self.locks.release_process(pid);
+
+        CleanupStats {
+            resources_freed: count,
+            bytes_freed: 0,
+            errors_encountered: 0,
+            cleanup_duration_micros: 0,
+            by_type: std::collections::HashMap::new(),
+            leaked_by_tag: std::collections::HashMap::new(),
+        }
+    }
+
+    fn resource_type(&self) -> &'static str {
+        "file_locks"
+    }
+
+    fn has_resources(&self, pid: Pid) -> bool {
+        self.locks.has_process_locks(pid)
+    }
+}
@@ -20,7 +20,10 @@ pub fn new(manager: MemoryManager) -> Self {
 
 impl ResourceCleanup for MemoryResource {
     fn cleanup(&self, pid: Pid) -> CleanupStats {
-        let bytes = self.manager.free_process_memory(pid);
+        // Anything still allocated at exit was never released by the process
+        let leaked_by_tag = self.manager.free_process_memory_by_tag(pid);
+        let bytes = leaked_by_tag.values().sum();
+        self.manager.clear_oom_score_adj(pid);
 
         CleanupStats {
             resources_freed: if bytes > 0 { 1 } else { 0 },
@@ -28,6 +31,7 @@ fn cleanup(&self, pid: Pid) -> CleanupStats {
             errors_encountered: 0,
             cleanup_duration_micros: 0,
             by_type: std::collections::HashMap::new(),
+            leaked_by_tag,
         }
     }
 
@@ -38,4 +42,9 @@ fn resource_type(&self) -> &'static str {
     fn has_resources(&self, pid: Pid) -> bool {
         self.manager.process_memory(pid) > 0
     }
+
+    fn depends_on(&self) -> &[&'static str] {
+        // Mappings, shared memory segments and FDs may reference allocations
+        &["mappings", "ipc", "file_descriptors"]
+    }
 }
@@ -5,25 +5,33 @@
 
 mod fds;
 mod ipc;
+mod locks;
 mod mappings;
 mod memory;
+mod report;
 mod rings;
+mod security;
 mod signals;
 mod sockets;
 mod tasks;
 
 pub use fds::FdResource;
 pub use ipc::IpcResource;
+pub use locks::FileLockResource;
 pub use mappings::MappingResource;
 pub use memory::MemoryResource;
+pub use report::{ProcessCleanupError, ShutdownReport};
 pub use rings::{IoUringResource, RingResource, ZeroCopyResource};
+pub use security::SecurityResource;
 pub use signals::SignalResource;
 pub use sockets::SocketResource;
 pub use tasks::TaskResource;
 
 use crate::core::types::Pid;
+use parking_lot::Mutex;
 use std::collections::HashMap;
 use std::fmt;
+use std::sync::Arc;
 use std::time::Instant;
 
 /// Resource cleanup statistics
@@ -34,6 +42,8 @@ pub struct CleanupStats {
     pub errors_encountered: usize,
     pub cleanup_duration_micros: u64,
     pub by_type: HashMap<String, usize>,
+    /// Bytes of memory still allocated at exit, by allocation tag
+    pub leaked_by_tag: HashMap<String, usize>,
 }
 
 impl CleanupStats {
@@ -60,6 +70,10 @@ fn merge(&mut self, other: CleanupStats) {
         for (type_name, count) in other.by_type {
             *self.by_type.entry(type_name).or_insert(0) += count;
         }
+
+        for (tag, bytes) in other.leaked_by_tag {
+            *self.leaked_by_tag.entry(tag).or_insert(0) += bytes;
+        }
     }
 }
 
@@ -73,6 +87,31 @@ pub trait ResourceCleanup: Send + Sync {
 
     /// Check if process has any resources
     fn has_resources(&self, pid: Pid) -> bool;
+
+    /// Resource types whose cleanup must finish before this one runs
+    ///
+    /// Since cleanup runs in reverse registration order, these must be
+    /// registered after this resource. Unregistered types are ignored.
+    fn depends_on(&self) -> &[&'static str] {
+        &[]
+    }
+}
+
+/// A resource registered so that one of its dependencies is cleaned up after it
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OrderingViolation {
+    pub resource: &'static str,
+    pub dependency: &'static str,
+}
+
+impl fmt::Display for OrderingViolation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "'{}' depends on '{}' being cleaned up first, but '{}' was registered before it",
+            self.resource, self.dependency, self.dependency
+        )
+    }
 }
 
 /// Resource cleanup orchestrator
@@ -82,8 +121,10 @@ pub trait ResourceCleanup: Send + Sync {
 ///
 /// Uses Arc internally for safe sharing across ProcessManager clones.
 /// The orchestrator is immutable after construction, making Arc ideal.
+/// Clones also share the running shutdown report.
 pub struct ResourceOrchestrator {
     resources: std::sync::Arc<Vec<Box<dyn ResourceCleanup>>>,
+    report: Arc<Mutex<ShutdownReport>>,
 }
 
 impl ResourceOrchestrator {
@@ -91,6 +132,7 @@ impl ResourceOrchestrator {
     pub fn new() -> Self {
         Self {
             resources: std::sync::Arc::new(Vec::new().into()),
+            report: Arc::default(),
         }
     }
 
@@ -99,6 +141,10 @@ pub fn new() -> Self {
     /// Note: This consumes self and returns a new ResourceOrchestrator with
     /// the registered resource. All registration must happen during initialization
     /// before the orchestrator is shared/cloned.
+    ///
+    /// # Panics
+    /// In debug builds, panics if `resource` depends on an already-registered
+    /// type (which would be cleaned up after it). Release builds log an error.
     pub fn register<R: ResourceCleanup + 'static>(self, resource: R) -> Self {
         // During building phase, we should be the only owner of the Arc
         // This is safe because registration happens during initialization
@@ -108,10 +154,26 @@ pub fn register<R: ResourceCleanup + 'static>(self, resource: R) -> Self {
                 panic!("ResourceOrchestrator::register called after being shared - registration must complete during initialization")
             });
 
+        for dependency in resource.depends_on() {
+            if resources_vec
+                .iter()
+                .any(|r| r.resource_type() == *dependency)
+            {
+                let violation = OrderingViolation {
+                    resource: resource.resource_type(),
+                    dependency,
+                };
+                log::error!("Resource cleanup ordering violation: {}", violation);
+                #[cfg(debug_assertions)]
+                panic!("Resource cleanup ordering violation: {}", violation);
+            }
+        }
+
         resources_vec.push(Box::new(resource));
 
         Self {
             resources: std::sync::Arc::new(resources_vec),
+            report: self.report,
         }
     }
 
@@ -175,11 +237,21 @@ pub fn cleanup_process(&self, pid: Pid) -> CleanupResult {
 
         total_stats.cleanup_duration_micros = overall_start.elapsed().as_micros() as u64;
 
-        CleanupResult {
+        let result = CleanupResult {
             pid,
             stats: total_stats,
             errors,
-        }
+        };
+        self.report.lock().record(&result);
+        result
+    }
+
+    /// Cleanup totals for every process cleaned so far
+    ///
+    /// Meant to be taken once processes are terminated at shutdown, giving a
+    /// post-mortem of what was freed, what leaked and what failed.
+    pub fn shutdown_report(&self) -> ShutdownReport {
+        self.report.lock().clone()
     }
 
     /// Get count of registered resource types
@@ -187,9 +259,32 @@ pub fn resource_count(&self) -> usize {
         self.resources.len()
     }
 
+    /// Find resources whose dependencies are cleaned up after them
+    pub fn ordering_violations(&self) -> Vec<OrderingViolation> {
+        let mut violations = Vec::new();
+
+        for (i, resource) in self.resources.iter().enumerate() {
+            for dependency in resource.depends_on() {
+                // Registered earlier means cleaned up later
+                if self.resources[..i]
+                    .iter()
+                    .any(|r| r.resource_type() == *dependency)
+                {
+                    violations.push(OrderingViolation {
+                        resource: resource.resource_type(),
+                        dependency,
+                    });
+                }
+            }
+        }
+
+        violations
+    }
+
     /// Validate that expected resource types are registered
     ///
-    /// Warns if critical resource types are missing to detect potential leaks
+    /// Warns if critical resource types are missing to detect potential leaks,
+    /// and if any resource would be cleaned up before its dependencies
     pub fn validate_coverage(&self, expected_types: &[&str]) {
         let registered: std::collections::HashSet<_> =
             self.resources.iter().map(|r| r.resource_type()).collect();
@@ -202,6 +297,10 @@ pub fn validate_coverage(&self, expected_types: &[&str]) {
                 );
             }
         }
+
+        for violation in self.ordering_violations() {
+            log::warn!("Resource cleanup ordering violation: {}", violation);
+        }
     }
 
     /// Get list of registered resource types
@@ -220,6 +319,7 @@ impl Clone for ResourceOrchestrator {
     fn clone(&self) -> Self {
         Self {
             resources: std::sync::Arc::clone(&self.resources),
+            report: Arc::clone(&self.report),
         }
     }
 }
@@ -275,6 +375,7 @@ fn cleanup(&self, _pid: Pid) -> CleanupStats {
                 errors_encountered: 0,
                 cleanup_duration_micros: 0,
                 by_type: HashMap::new(),
+                leaked_by_tag: HashMap::new(),
             }
         }
 
@@ -313,6 +414,59 @@ fn test_orchestrator_cleanup_order() {
         assert_eq!(r2_count.load(std::sync::atomic::Ordering::SeqCst), 1);
     }
 
+    struct DependentResource {
+        name: &'static str,
+        depends_on: &'static [&'static str],
+    }
+
+    impl ResourceCleanup for DependentResource {
+        fn cleanup(&self, _pid: Pid) -> CleanupStats {
+            CleanupStats::default()
+        }
+
+        fn resource_type(&self) -> &'static str {
+            self.name
+        }
+
+        fn has_resources(&self, _pid: Pid) -> bool {
+            false
+        }
+
+        fn depends_on(&self) -> &[&'static str] {
+            self.depends_on
+        }
+    }
+
+    #[test]
+    fn test_dependencies_registered_after_dependent() {
+        let orchestrator = ResourceOrchestrator::new()
+            .register(DependentResource {
+                name: "memory",
+                depends_on: &["file_descriptors"],
+            })
+            .register(DependentResource {
+                name: "file_descriptors",
+                depends_on: &[],
+            });
+
+        assert!(orchestrator.ordering_violations().is_empty());
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "ordering violation")]
+    fn test_dependency_registered_first_panics() {
+        let _ = ResourceOrchestrator::new()
+            .register(DependentResource {
+                name: "file_descriptors",
+                depends_on: &[],
+            })
+            .register(DependentResource {
+                name: "memory",
+                depends_on: &["file_descriptors"],
+            });
+    }
+
     #[test]
     fn test_orchestrator_clone_preserves_resources() {
         use std::sync::atomic::AtomicUsize;
@@ -353,4 +507,56 @@ fn test_orchestrator_clone_preserves_resources() {
         assert_eq!(orchestrator.resource_count(), 2);
         assert_eq!(cloned.resource_count(), 2);
     }
+
+    struct LeakyResource;
+
+    impl ResourceCleanup for LeakyResource {
+        fn cleanup(&self, _pid: Pid) -> CleanupStats {
+            CleanupStats {
+                resources_freed: 3,
+                bytes_freed: 4096,
+                errors_encountered: 1,
+                leaked_by_tag: HashMap::from([("heap".to_string(), 512)]),
+                ..Default::default()
+            }
+        }
+
+        fn resource_type(&self) -> &'static str {
+            "memory"
+        }
+
+        fn has_resources(&self, pid: Pid) -> bool {
+            pid == 2
+        }
+    }
+
+    #[test]
+    fn test_shutdown_report_aggregates_processes() {
+        let orchestrator = ResourceOrchestrator::new()
+            .register(LeakyResource)
+            .register(TestResource {
+                name: "file_descriptors",
+                cleanup_count: Default::default(),
+            });
+        assert!(orchestrator.shutdown_report().is_complete());
+
+        orchestrator.cleanup_process(1);
+        orchestrator.clone().cleanup_process(2);
+
+        let report = orchestrator.shutdown_report();
+        assert_eq!(report.processes_cleaned, 2);
+        assert_eq!(report.resources_freed, 1 + 1 + 3);
+        assert_eq!(report.bytes_freed, 100 + 100 + 4096);
+        assert_eq!(report.by_type["file_descriptors"], 2);
+        assert_eq!(report.by_type["memory"], 3);
+        assert_eq!(report.leaked_by_tag["heap"], 512);
+        assert_eq!(report.errors_encountered, 1);
+        assert_eq!(report.errors.len(), 1);
+        assert_eq!(report.errors[0].pid, 2);
+        assert!(!report.is_complete());
+
+        let json = crate::core::serialization::json::to_string(&report).unwrap();
+        let parsed: ShutdownReport = crate::core::serialization::json::from_str(&json).unwrap();
+        assert_eq!(parsed, report);
+    }
 }
@@ -0,0 +1,72 @@
+/*!
+ * Shutdown Report
+ * Machine-readable summary of cleanup across terminated processes
+ */
+
+use super::CleanupResult;
+use crate::core::limits::MAX_SHUTDOWN_REPORT_ERRORS;
+use crate::core::types::Pid;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// Cleanup error attributed to the process it came from
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub struct ProcessCleanupError {
+    pub pid: Pid,
+    pub message: String,
+}
+
+/// Aggregated cleanup results for every process an orchestrator has cleaned
+///
+/// Maps are ordered so the serialized report is stable between runs.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub struct ShutdownReport {
+    pub processes_cleaned: usize,
+    pub resources_freed: usize,
+    pub bytes_freed: usize,
+    pub errors_encountered: usize,
+    pub cleanup_duration_micros: u64,
+    pub by_type: BTreeMap<String, usize>,
+    /// Bytes still allocated at exit, by allocation tag
+    pub leaked_by_tag: BTreeMap<String, usize>,
+    /// First errors reported, capped at `MAX_SHUTDOWN_REPORT_ERRORS`
+    pub errors: Vec<ProcessCleanupError>,
+}
+
+impl ShutdownReport {
+    /// Fold one process's cleanup into the report
+    pub(super) fn record(&mut self, result: &CleanupResult) {
+        let stats = &result.stats;
+        self.processes_cleaned += 1;
+        self.resources_freed += stats.resources_freed;
+        self.bytes_freed += stats.bytes_freed;
+        self.errors_encountered += stats.errors_encountered;
+        self.cleanup_duration_micros += stats.cleanup_duration_micros;
+
+        for (type_name, count) in &stats.by_type {
+            *self.by_type.entry(type_name.clone()).or_insert(0) += count;
+        }
+        for (tag, bytes) in &stats.leaked_by_tag {
+            *self.leaked_by_tag.entry(tag.clone()).or_insert(0) += bytes;
+        }
+
+        let room = MAX_SHUTDOWN_REPORT_ERRORS.saturating_sub(self.errors.len());
+        self.errors.extend(
+            result
+                .errors
+                .iter()
+                .take(room)
+                .map(|message| ProcessCleanupError {
+                    pid: result.pid,
+                    message: message.clone(),
+                }),
+        );
+    }
+
+    /// Whether every process was cleaned without errors or leaks
+    pub fn is_complete(&self) -> bool {
+        self.errors_encountered == 0 && self.leaked_by_tag.is_empty()
+    }
+}
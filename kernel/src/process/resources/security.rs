@@ -0,0 +1,39 @@
+/*!
+ * Security State Resource Cleanup
+ * Sandbox, delegations, syscall allow-list, credits and entropy budget
+ *
+ * All of this is keyed by pid alone, so it has to go before the pid is
+ * released; otherwise a recycled pid inherits the previous holder's grants.
+ */
+
+use super::{CleanupStats, ResourceCleanup};
+use crate::core::types::Pid;
+use crate::syscalls::SyscallExecutorWithIpc;
+
+/// Security state cleanup wrapper
+pub struct SecurityResource {
+    executor: SyscallExecutorWithIpc,
+}
+
+impl SecurityResource {
+    pub fn new(executor: SyscallExecutorWithIpc) -> Self {
+        Self { executor }
+    }
+}
+
+impl ResourceCleanup for SecurityResource {
+    fn cleanup(&self, pid: Pid) -> CleanupStats {
+        CleanupStats {
+            resources_freed: self.executor.release_process_state(pid),
+            ..Default::default()
+        }
+    }
+
+    fn resource_type(&self) -> &'static str {
+        "security"
+    }
+
+    fn has_resources(&self, pid: Pid) -> bool {
+        self.executor.has_process_state(pid)
+    }
+}
@@ -28,6 +28,7 @@ fn cleanup(&self, pid: Pid) -> CleanupStats {
             errors_encountered: 0,
             cleanup_duration_micros: 0,
             by_type: std::collections::HashMap::new(),
+            leaked_by_tag: std::collections::HashMap::new(),
         }
     }
 
@@ -3,6 +3,7 @@
  * Uses flat combining counters for 8-10x better throughput in hot scheduling paths
  */
 
+use super::latency::LatencyHistogram;
 use crate::core::sync::lockfree::FlatCombiningCounter;
 use crate::process::core::types::{SchedulerStats, SchedulingPolicy};
 use std::sync::atomic::{AtomicUsize, Ordering};
@@ -14,12 +15,14 @@
 /// - Cache-line aligned to prevent false sharing
 /// - FlatCombiningCounter for 8-10x higher throughput on hot counters
 /// - Batches operations to reduce cache line transfers by 90%
+/// - Wake-to-run latency tracked in a fixed-size lock-free histogram
 #[repr(C, align(64))]
 pub struct AtomicSchedulerStats {
     total_scheduled: FlatCombiningCounter,
     context_switches: FlatCombiningCounter,
     preemptions: FlatCombiningCounter,
     active_processes: AtomicUsize,
+    wake_to_run: LatencyHistogram,
     // These don't change frequently, can use parking_lot::RwLock for snapshots
     policy: parking_lot::RwLock<SchedulingPolicy>,
     quantum: parking_lot::RwLock<Duration>,
@@ -34,6 +37,7 @@ pub fn new(policy: SchedulingPolicy, quantum: Duration) -> Self {
             context_switches: FlatCombiningCounter::new(0),
             preemptions: FlatCombiningCounter::new(0),
             active_processes: AtomicUsize::new(0),
+            wake_to_run: LatencyHistogram::new(),
             policy: parking_lot::RwLock::new(policy),
             quantum: parking_lot::RwLock::new(quantum),
         }
@@ -84,10 +88,22 @@ pub fn dec_active(&self) {
         self.active_processes.fetch_sub(1, Ordering::Relaxed);
     }
 
+    /// Record run-queue latency for a newly dispatched process (lock-free)
+    ///
+    /// # Performance
+    /// Hot path - called on every dispatch from a run queue
+    #[inline(always)]
+    pub fn record_wake_to_run(&self, micros: u64) {
+        self.wake_to_run.record(micros);
+    }
+
     /// Update policy (infrequent operation)
+    ///
+    /// Latency samples are discarded since they describe the previous policy
     #[inline]
     pub fn set_policy(&self, policy: SchedulingPolicy) {
         *self.policy.write() = policy;
+        self.wake_to_run.reset();
     }
 
     /// Update quantum (infrequent operation)
@@ -110,6 +126,9 @@ pub fn snapshot(&self) -> SchedulerStats {
             active_processes: self.active_processes.load(Ordering::Relaxed),
             policy: *self.policy.read(),
             quantum_micros: self.quantum.read().as_micros() as u64,
+            wake_to_run_p50_us: self.wake_to_run.percentile(0.50),
+            wake_to_run_p95_us: self.wake_to_run.percentile(0.95),
+            wake_to_run_p99_us: self.wake_to_run.percentile(0.99),
         }
     }
 }
@@ -19,10 +19,11 @@ pub(super) struct Entry {
     pub last_scheduled: Option<Instant>,
     pub time_slice_remaining: Duration,
     pub cpu_time_micros: u64, // Total CPU time used by this process (microseconds)
+    pub enqueued_at: Option<Instant>, // When the entry became runnable (for wake-to-run latency)
 }
 
 impl Entry {
-    pub fn new(pid: Pid, priority: Priority, quantum: Duration) -> Self {
+    pub fn new(pid: Pid, priority: Priority, quantum: Duration, now: Instant) -> Self {
         Self {
             pid,
             priority,
@@ -30,9 +31,22 @@ pub fn new(pid: Pid, priority: Priority, quantum: Duration) -> Self {
             last_scheduled: None,
             time_slice_remaining: quantum,
             cpu_time_micros: 0,
+            enqueued_at: Some(now),
         }
     }
 
+    /// Time on the CPU since last dispatched (zero if not running)
+    pub fn ran_for(&self, now: Instant) -> Duration {
+        self.last_scheduled
+            .map(|t| now.saturating_duration_since(t))
+            .unwrap_or_default()
+    }
+
+    /// Whether the running slice has used up its quantum by `now`
+    pub fn quantum_expired(&self, now: Instant) -> bool {
+        self.ran_for(now) >= self.time_slice_remaining
+    }
+
     /// Update virtual runtime based on actual runtime and priority
     pub fn update_vruntime(&mut self, actual_runtime: Duration) {
         // Lower priority (higher number) = slower vruntime growth = more CPU time
@@ -0,0 +1,173 @@
+/*!
+ * Run-Queue Latency Histogram
+ * Lock-free log-linear histogram for wake-to-run percentile estimation
+ */
+
+use std::sync::atomic::{AtomicU64, Ordering};
+
+/// Linear sub-buckets per power of two (~25% relative error)
+const SUB_BUCKETS: usize = 4;
+
+/// Powers of two covered, starting at 1us (upper range ~35 minutes)
+const MAGNITUDES: usize = 32;
+
+/// Total bucket count - fixed, so memory is bounded regardless of sample volume
+const BUCKETS: usize = SUB_BUCKETS * MAGNITUDES;
+
+/// Samples retained before every bucket is halved (rolling window via exponential decay)
+const DECAY_THRESHOLD: u64 = 1 << 16;
+
+/// Lock-free latency histogram
+///
+/// # Performance
+/// - Single relaxed `fetch_add` per sample on the hot path
+/// - Fixed 1KB footprint, no allocation after construction
+/// - Older samples decay by half every `DECAY_THRESHOLD` samples so percentiles track recent load
+pub(super) struct LatencyHistogram {
+    buckets: [AtomicU64; BUCKETS],
+    count: AtomicU64,
+}
+
+impl LatencyHistogram {
+    /// Create empty histogram
+    pub fn new() -> Self {
+        Self {
+            buckets: std::array::from_fn(|_| AtomicU64::new(0)),
+            count: AtomicU64::new(0),
+        }
+    }
+
+    /// Record a latency sample in microseconds
+    #[inline(always)]
+    pub fn record(&self, micros: u64) {
+        self.buckets[Self::bucket_index(micros)].fetch_add(1, Ordering::Relaxed);
+
+        // Exactly one recorder observes the threshold crossing and performs the decay
+        if self.count.fetch_add(1, Ordering::Relaxed) + 1 == DECAY_THRESHOLD {
+            self.decay();
+        }
+    }
+
+    /// Estimate the value at percentile `p` (0.0..=1.0), returning the bucket upper bound
+    pub fn percentile(&self, p: f64) -> u64 {
+        let counts: [u64; BUCKETS] =
+            std::array::from_fn(|i| self.buckets[i].load(Ordering::Relaxed));
+        let total: u64 = counts.iter().sum();
+        if total == 0 {
+            return 0;
+        }
+
+        let target = ((total as f64 * p.clamp(0.0, 1.0)).ceil() as u64).max(1);
+        let mut cumulative = 0u64;
+        for (index, &count) in counts.iter().enumerate() {
+            cumulative += count;
+            if cumulative >= target {
+                return Self::bucket_upper_bound(index);
+            }
+        }
+
+        Self::bucket_upper_bound(BUCKETS - 1)
+    }
+
+    /// Clear all samples
+    pub fn reset(&self) {
+        for bucket in &self.buckets {
+            bucket.store(0, Ordering::Relaxed);
+        }
+        self.count.store(0, Ordering::Relaxed);
+    }
+
+    /// Halve every bucket so recent samples dominate the estimate
+    fn decay(&self) {
+        let mut retained = 0u64;
+        for bucket in &self.buckets {
+            let previous = bucket
+                .fetch_update(Ordering::Relaxed, Ordering::Relaxed, |c| Some(c / 2))
+                .unwrap_or(0);
+            retained += previous / 2;
+        }
+        self.count.store(retained, Ordering::Relaxed);
+    }
+
+    /// Map a value to its log-linear bucket
+    #[inline(always)]
+    fn bucket_index(micros: u64) -> usize {
+        if micros < SUB_BUCKETS as u64 {
+            return micros as usize;
+        }
+
+        let magnitude = 63 - micros.leading_zeros() as usize;
+        let sub = ((micros >> (magnitude - 2)) & (SUB_BUCKETS as u64 - 1)) as usize;
+        ((magnitude - 1) * SUB_BUCKETS + sub).min(BUCKETS - 1)
+    }
+
+    /// Inclusive upper bound of a bucket
+    fn bucket_upper_bound(index: usize) -> u64 {
+        if index < SUB_BUCKETS {
+            return index as u64;
+        }
+
+        let magnitude = index / SUB_BUCKETS + 1;
+        let sub = (index % SUB_BUCKETS) as u64;
+        let width = 1u64 << (magnitude - 2);
+        ((SUB_BUCKETS as u64 + sub) << (magnitude - 2)) + width - 1
+    }
+}
+
+impl Default for LatencyHistogram {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bucket_bounds_contain_value() {
+        for value in [0u64, 1, 3, 4, 7, 8, 100, 1_000, 65_535, 1_000_000] {
+            let index = LatencyHistogram::bucket_index(value);
+            assert!(LatencyHistogram::bucket_upper_bound(index) >= value);
+            if index > 0 {
+                assert!(LatencyHistogram::bucket_upper_bound(index - 1) < value);
+            }
+        }
+    }
+
+    #[test]
+    fn test_percentiles() {
+        let hist = LatencyHistogram::new();
+        for _ in 0..90 {
+            hist.record(10);
+        }
+        for _ in 0..10 {
+            hist.record(1_000);
+        }
+
+        assert!(hist.percentile(0.50) >= 10 && hist.percentile(0.50) < 16);
+        assert!(hist.percentile(0.99) >= 1_000 && hist.percentile(0.99) < 1_280);
+    }
+
+    #[test]
+    fn test_reset_and_empty() {
+        let hist = LatencyHistogram::new();
+        assert_eq!(hist.percentile(0.99), 0);
+
+        hist.record(500);
+        assert!(hist.percentile(0.5) > 0);
+
+        hist.reset();
+        assert_eq!(hist.percentile(0.5), 0);
+    }
+
+    #[test]
+    fn test_decay_bounds_count() {
+        let hist = LatencyHistogram::new();
+        for _ in 0..DECAY_THRESHOLD {
+            hist.record(42);
+        }
+        assert!(hist.count.load(Ordering::Relaxed) <= DECAY_THRESHOLD / 2);
+        assert!(hist.percentile(0.5) >= 42);
+    }
+}
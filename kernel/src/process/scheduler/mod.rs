@@ -4,8 +4,11 @@
  */
 
 mod atomic_stats;
+mod clock;
 mod entry;
+mod latency;
 mod operations;
+mod pause;
 mod policy;
 mod stats;
 pub mod task;
@@ -22,6 +25,7 @@
 use std::time::Duration;
 
 // Re-export scheduler task
+pub use clock::{Clock, MonotonicClock, VirtualClock};
 pub use task::{SchedulerCommand, SchedulerTask};
 
 use entry::{Entry, FairEntry};
@@ -33,6 +37,7 @@ enum QueueLocation {
     RoundRobin,
     Priority,
     Fair,
+    Paused,
 }
 
 /// CPU Scheduler
@@ -57,6 +62,9 @@ pub struct Scheduler {
     // Current running process
     current: Arc<RwLock<Option<Entry>>>,
 
+    // Paused processes, parked outside every queue until resumed
+    paused: Arc<DashMap<Pid, Entry>>,
+
     // Process location index for O(1) lookup
     process_locations: Arc<DashMap<Pid, QueueLocation>>,
 
@@ -65,6 +73,9 @@ pub struct Scheduler {
 
     // Observability collector for event streaming
     collector: Option<Arc<Collector>>,
+
+    // Time source for quantum expiry and wake-to-run latency
+    clock: Arc<dyn Clock>,
 }
 
 impl Scheduler {
@@ -87,12 +98,20 @@ pub fn with_quantum(policy: SchedulingPolicy, quantum: Duration) -> Self {
             priority_queue: Arc::new(RwLock::new(BinaryHeap::new().into())),
             fair_queue: Arc::new(RwLock::new(BinaryHeap::new().into())),
             current: Arc::new(RwLock::new(None).into()),
+            paused: Arc::new(DashMap::new()),
             process_locations: Arc::new(DashMap::new().into()),
             stats: Arc::new(AtomicSchedulerStats::new(policy, quantum).into()),
             collector: None,
+            clock: Arc::new(MonotonicClock),
         }
     }
 
+    /// Use a custom time source (e.g. a `VirtualClock` in tests)
+    pub fn with_clock(mut self, clock: Arc<dyn Clock>) -> Self {
+        self.clock = clock;
+        self
+    }
+
     /// Add observability collector
     pub fn with_collector(mut self, collector: Arc<Collector>) -> Self {
         self.collector = Some(collector);
@@ -114,9 +133,11 @@ fn clone(&self) -> Self {
             priority_queue: Arc::clone(&self.priority_queue),
             fair_queue: Arc::clone(&self.fair_queue),
             current: Arc::clone(&self.current),
+            paused: Arc::clone(&self.paused),
             process_locations: Arc::clone(&self.process_locations),
             stats: Arc::clone(&self.stats),
             collector: self.collector.as_ref().map(Arc::clone),
+            clock: Arc::clone(&self.clock),
         }
     }
 }
@@ -208,6 +229,24 @@ fn test_statistics() {
         assert_eq!(stats.policy, SchedulingPolicy::Priority);
     }
 
+    #[test]
+    fn test_wake_to_run_percentiles() {
+        let scheduler = Scheduler::new(SchedulingPolicy::RoundRobin);
+
+        scheduler.add(1, 5);
+        scheduler.add(2, 5);
+        thread::sleep(Duration::from_millis(2));
+        scheduler.schedule();
+
+        let stats = scheduler.stats();
+        assert!(stats.wake_to_run_p50_us >= 2_000);
+        assert!(stats.wake_to_run_p99_us >= stats.wake_to_run_p50_us);
+
+        // Samples from the old policy are discarded
+        scheduler.set_policy(SchedulingPolicy::Fair);
+        assert_eq!(scheduler.stats().wake_to_run_p99_us, 0);
+    }
+
     #[test]
     fn test_policy_change() {
         let scheduler = Scheduler::new(SchedulingPolicy::RoundRobin);
@@ -219,6 +258,56 @@ fn test_policy_change() {
         assert_eq!(scheduler.len(), 2); // Processes should be requeued
     }
 
+    #[test]
+    fn test_paused_process_never_scheduled() {
+        for policy in [
+            SchedulingPolicy::RoundRobin,
+            SchedulingPolicy::Priority,
+            SchedulingPolicy::Fair,
+        ] {
+            let scheduler = Scheduler::with_quantum(policy, Duration::ZERO);
+            scheduler.add(1, 9);
+            scheduler.add(2, 5);
+
+            assert!(scheduler.pause(1));
+            assert!(!scheduler.pause(1));
+            assert!(scheduler.is_paused(1));
+            assert_eq!(scheduler.len(), 2);
+
+            for _ in 0..5 {
+                assert_eq!(scheduler.schedule(), Some(2));
+            }
+
+            // Pausing the running process takes it off the CPU
+            assert!(scheduler.pause(2));
+            assert_eq!(scheduler.current(), None);
+            assert_eq!(scheduler.schedule(), None);
+
+            assert!(scheduler.resume(1));
+            assert!(!scheduler.resume(1));
+            assert_eq!(scheduler.schedule(), Some(1));
+            assert_eq!(scheduler.process_stats(1).unwrap().priority, 9);
+        }
+    }
+
+    #[test]
+    fn test_paused_process_survives_policy_change_and_removal() {
+        let scheduler = Scheduler::new(SchedulingPolicy::RoundRobin);
+        scheduler.add(1, 5);
+        scheduler.add(2, 5);
+        scheduler.pause(1);
+
+        scheduler.set_policy(SchedulingPolicy::Fair);
+        assert!(scheduler.is_paused(1));
+        assert!(scheduler.set_priority(1, 7));
+        assert_eq!(scheduler.all_process_stats().len(), 2);
+
+        assert!(scheduler.remove(1));
+        assert!(!scheduler.is_paused(1));
+        assert!(!scheduler.resume(1));
+        assert_eq!(scheduler.len(), 1);
+    }
+
     #[test]
     fn test_preemption_with_quantum() {
         let scheduler =
@@ -237,4 +326,24 @@ fn test_preemption_with_quantum() {
         let next = scheduler.schedule();
         assert_eq!(next, Some(2));
     }
+
+    #[test]
+    fn test_virtual_clock_drives_preemption() {
+        let clock = Arc::new(VirtualClock::new());
+        let scheduler =
+            Scheduler::with_quantum(SchedulingPolicy::RoundRobin, Duration::from_millis(10))
+                .with_clock(clock.clone());
+
+        scheduler.add(1, 5);
+        scheduler.add(2, 5);
+        assert_eq!(scheduler.schedule(), Some(1));
+
+        // Half a quantum: no preemption no matter how long the test really takes
+        clock.advance(Duration::from_millis(5));
+        assert_eq!(scheduler.schedule(), Some(1));
+
+        clock.advance(Duration::from_millis(5));
+        assert_eq!(scheduler.schedule(), Some(2));
+        assert_eq!(scheduler.stats().preemptions, 1);
+    }
 }
@@ -9,7 +9,6 @@
 use crate::monitoring::{Category, Event, Payload, Severity};
 use crate::process::core::types::{ProcessStats, SchedulingPolicy};
 use log::info;
-use std::time::Instant;
 
 impl Scheduler {
     /// Add process to scheduler
@@ -20,7 +19,7 @@ impl Scheduler {
     pub fn add(&self, pid: Pid, priority: Priority) {
         let quantum = *self.quantum.read();
         let policy = *self.policy.read();
-        let mut entry = Entry::new(pid, priority, quantum);
+        let mut entry = Entry::new(pid, priority, quantum, self.clock.now());
 
         // For fair scheduling, initialize vruntime to min_vruntime to prevent starvation
         if policy == SchedulingPolicy::Fair {
@@ -74,43 +73,7 @@ pub fn remove(&self, pid: Pid) -> bool {
             None => return false, // Process not in scheduler
         };
 
-        let mut removed = false;
-
-        // Remove from the appropriate queue based on cached location
-        match location {
-            QueueLocation::RoundRobin => {
-                let mut queue = self.rr_queue.write();
-                if let Some(pos) = queue.iter().position(|e| e.pid == pid) {
-                    queue.remove(pos);
-                    removed = true;
-                }
-            }
-            QueueLocation::Priority => {
-                let mut queue = self.priority_queue.write();
-                let original_len = queue.len();
-                let entries: Vec<Entry> = queue.drain().filter(|e| e.pid != pid).collect();
-                removed = entries.len() < original_len;
-                for entry in entries {
-                    queue.push(entry);
-                }
-            }
-            QueueLocation::Fair => {
-                let mut queue = self.fair_queue.write();
-                let original_len = queue.len();
-                let entries: Vec<FairEntry> = queue.drain().filter(|e| e.0.pid != pid).collect();
-                removed = entries.len() < original_len;
-                for entry in entries {
-                    queue.push(entry);
-                }
-            }
-            QueueLocation::Current => {
-                let mut current = self.current.write();
-                if current.as_ref().map(|e| e.pid) == Some(pid) {
-                    *current = None;
-                    removed = true;
-                }
-            }
-        }
+        let removed = self.take_entry(pid, location).is_some();
 
         if removed {
             self.stats.dec_active();
@@ -126,14 +89,11 @@ pub fn remove(&self, pid: Pid) -> bool {
     /// Schedule next process (returns None if no processes available)
     pub fn schedule(&self) -> Option<u32> {
         let mut current = self.current.write();
-        let now = Instant::now();
+        let now = self.clock.now();
 
         // Handle current process
         if let Some(ref mut entry) = *current {
-            let elapsed = entry
-                .last_scheduled
-                .map(|t| now.duration_since(t))
-                .unwrap_or_default();
+            let elapsed = entry.ran_for(now);
 
             // Track CPU usage
             entry.cpu_time_micros += elapsed.as_micros() as u64;
@@ -145,7 +105,7 @@ pub fn schedule(&self) -> Option<u32> {
             }
 
             // Check if time quantum expired
-            if elapsed >= entry.time_slice_remaining {
+            if entry.quantum_expired(now) {
                 // Preemption needed
                 let preempted_pid = entry.pid;
                 let mut new_entry = entry.clone();
@@ -155,6 +115,7 @@ pub fn schedule(&self) -> Option<u32> {
                 let quantum = *self.quantum.read();
                 new_entry.time_slice_remaining = quantum;
                 new_entry.last_scheduled = None;
+                new_entry.enqueued_at = Some(now);
 
                 // Capture quantum before moving new_entry
                 let quantum_remaining_us = new_entry.time_slice_remaining.as_micros() as u64;
@@ -214,6 +175,10 @@ pub fn schedule(&self) -> Option<u32> {
 
         if let Some(mut entry) = next {
             let pid = entry.pid;
+            let wake_to_run_us = entry
+                .enqueued_at
+                .take()
+                .map(|t| now.saturating_duration_since(t).as_micros() as u64);
             entry.last_scheduled = Some(now);
             entry.time_slice_remaining = *self.quantum.read();
             *current = Some(entry);
@@ -223,9 +188,24 @@ pub fn schedule(&self) -> Option<u32> {
 
             self.stats.inc_scheduled();
             self.stats.inc_context_switches();
+            if let Some(latency) = wake_to_run_us {
+                self.stats.record_wake_to_run(latency);
+            }
 
-            // Emit context switch event if there was a previous process
             if let Some(ref collector) = self.collector {
+                // Emit run-queue latency for the dispatched process
+                if let Some(wake_to_run_us) = wake_to_run_us {
+                    collector.emit(
+                        Event::new(
+                            Severity::Debug,
+                            Category::Scheduler,
+                            Payload::SchedulerLatency { wake_to_run_us },
+                        )
+                        .with_pid(pid),
+                    );
+                }
+
+                // Emit context switch event if there was a previous process
                 if let Some(prev_entry) = current.as_ref() {
                     collector.emit(Event::new(
                         Severity::Debug,
@@ -258,6 +238,7 @@ pub fn yield_process(&self) -> Option<u32> {
             let mut new_entry = entry;
             new_entry.time_slice_remaining = *self.quantum.read();
             new_entry.last_scheduled = None;
+            new_entry.enqueued_at = Some(self.clock.now());
 
             let policy = *self.policy.read();
             match policy {
@@ -296,7 +277,7 @@ pub fn len(&self) -> usize {
             SchedulingPolicy::Priority => self.priority_queue.read().len(),
             SchedulingPolicy::Fair => self.fair_queue.read().len(),
         };
-        queue_len + if self.current.read().is_some() { 1 } else { 0 }
+        queue_len + self.paused.len() + if self.current.read().is_some() { 1 } else { 0 }
     }
 
     /// Check if scheduler is empty
@@ -360,6 +341,13 @@ pub fn process_stats(&self, pid: Pid) -> Option<ProcessStats> {
                         is_current: false,
                     })
             }
+            QueueLocation::Paused => self.paused.get(&pid).map(|entry| ProcessStats {
+                pid: entry.pid,
+                priority: entry.priority,
+                cpu_time_micros: entry.cpu_time_micros,
+                vruntime: entry.vruntime,
+                is_current: false,
+            }),
         }
     }
 
@@ -418,6 +406,15 @@ pub fn all_process_stats(&self) -> Vec<ProcessStats> {
                 }
             }
 
+            // Paused processes are still owned by the scheduler
+            stats.extend(self.paused.iter().map(|entry| ProcessStats {
+                pid: entry.pid,
+                priority: entry.priority,
+                cpu_time_micros: entry.cpu_time_micros,
+                vruntime: entry.vruntime,
+                is_current: false,
+            }));
+
             stats.into_iter().collect()
         })
     }
@@ -0,0 +1,124 @@
+/*!
+ * Scheduler Pause/Resume
+ * Freeze processes in place for debugging and deterministic tests
+ *
+ * A paused entry is parked outside the run queues, so no policy can select
+ * it, but it stays registered with the scheduler: its priority, vruntime and
+ * CPU accounting are kept and it still counts towards `len()`.
+ */
+
+use super::entry::{Entry, FairEntry};
+use super::{QueueLocation, Scheduler};
+use crate::core::types::Pid;
+use crate::process::core::types::SchedulingPolicy;
+use log::info;
+
+impl Scheduler {
+    /// Stop `pid` from being scheduled until resumed
+    ///
+    /// Returns false if the process isn't in the scheduler or is already paused.
+    pub fn pause(&self, pid: Pid) -> bool {
+        let location = match self.process_locations.get(&pid) {
+            Some(loc) => *loc,
+            None => return false,
+        };
+        if location == QueueLocation::Paused {
+            return false;
+        }
+
+        let Some(mut entry) = self.take_entry(pid, location) else {
+            return false;
+        };
+
+        if location == QueueLocation::Current {
+            // Charge the running slice before parking, as preemption would
+            let elapsed = entry.ran_for(self.clock.now());
+            entry.cpu_time_micros += elapsed.as_micros() as u64;
+            if *self.policy.read() == SchedulingPolicy::Fair {
+                entry.update_vruntime(elapsed);
+            }
+            entry.last_scheduled = None;
+            self.stats.inc_context_switches();
+        }
+
+        self.paused.insert(pid, entry);
+        self.process_locations.insert(pid, QueueLocation::Paused);
+        info!("Process {} paused", pid);
+        true
+    }
+
+    /// Make a paused process schedulable again
+    ///
+    /// Returns false if `pid` isn't paused.
+    pub fn resume(&self, pid: Pid) -> bool {
+        let Some((_, mut entry)) = self.paused.remove(&pid) else {
+            return false;
+        };
+
+        entry.time_slice_remaining = *self.quantum.read();
+        entry.enqueued_at = Some(self.clock.now());
+
+        match *self.policy.read() {
+            SchedulingPolicy::RoundRobin => {
+                self.rr_queue.write().push_back(entry);
+                self.process_locations
+                    .insert(pid, QueueLocation::RoundRobin);
+            }
+            SchedulingPolicy::Priority => {
+                self.priority_queue.write().push(entry);
+                self.process_locations.insert(pid, QueueLocation::Priority);
+            }
+            SchedulingPolicy::Fair => {
+                self.fair_queue.write().push(FairEntry(entry));
+                self.process_locations.insert(pid, QueueLocation::Fair);
+            }
+        }
+
+        info!("Process {} resumed", pid);
+        true
+    }
+
+    /// Check whether a process is paused
+    pub fn is_paused(&self, pid: Pid) -> bool {
+        self.paused.contains_key(&pid)
+    }
+
+    /// PIDs of all paused processes
+    pub fn paused_pids(&self) -> Vec<Pid> {
+        self.paused.iter().map(|entry| *entry.key()).collect()
+    }
+
+    /// Take a process's entry out of wherever it currently lives
+    pub(super) fn take_entry(&self, pid: Pid, location: QueueLocation) -> Option<Entry> {
+        match location {
+            QueueLocation::RoundRobin => {
+                let mut queue = self.rr_queue.write();
+                let pos = queue.iter().position(|e| e.pid == pid)?;
+                queue.remove(pos)
+            }
+            QueueLocation::Priority => {
+                let mut queue = self.priority_queue.write();
+                let (found, rest): (Vec<Entry>, Vec<Entry>) =
+                    queue.drain().partition(|e| e.pid == pid);
+                queue.extend(rest);
+                found.into_iter().next()
+            }
+            QueueLocation::Fair => {
+                let mut queue = self.fair_queue.write();
+                let (found, rest): (Vec<FairEntry>, Vec<FairEntry>) =
+                    queue.drain().partition(|e| e.0.pid == pid);
+                queue.extend(rest);
+                found.into_iter().next().map(|fe| fe.0)
+            }
+            QueueLocation::Current => {
+                let mut current = self.current.write();
+                if current.as_ref().map(|e| e.pid) == Some(pid) {
+                    current.take()
+                } else {
+                    None
+                }
+            }
+            QueueLocation::Paused => self.paused.remove(&pid).map(|(_, entry)| entry),
+        }
+    }
+}
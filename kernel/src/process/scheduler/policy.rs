@@ -42,7 +42,8 @@ pub fn set_policy(&self, new_policy: SchedulingPolicy) {
 
         // Collect current process
         let mut current = self.current.write();
-        if let Some(entry) = current.take() {
+        if let Some(mut entry) = current.take() {
+            entry.enqueued_at = Some(self.clock.now());
             all_entries.push(entry);
         }
         drop(current);
@@ -184,6 +185,17 @@ pub fn set_priority(&self, pid: Pid, new_priority: Priority) -> bool {
                 }
                 found
             }
+            QueueLocation::Paused => match self.paused.get_mut(&pid) {
+                Some(mut entry) => {
+                    entry.priority = new_priority;
+                    info!(
+                        "Updated priority for paused process {} to {}",
+                        pid, new_priority
+                    );
+                    true
+                }
+                None => false,
+            },
         }
     }
 
@@ -11,6 +11,7 @@
     PriorityControl, SchedulerControl, SchedulerPolicy, SchedulerStats, SchedulerSyscalls,
 };
 pub use types::{
-    apply_priority_op, validate_priority, PriorityOp, SchedulerPolicy as Policy, TimeQuantum,
-    DEFAULT_PRIORITY, MAX_PRIORITY, MIN_PRIORITY,
+    apply_priority_op, nice_to_priority, priority_to_nice, validate_priority,
+    validate_priority_with_ceiling, PriorityOp, SchedulerPolicy as Policy, TimeQuantum,
+    DEFAULT_PRIORITY, MAX_NICE, MAX_PRIORITY, MIN_NICE, MIN_PRIORITY,
 };
@@ -16,6 +16,12 @@ pub trait SchedulerControl: Send + Sync {
 
     /// Get currently scheduled process
     fn get_current_scheduled(&self, pid: Pid) -> SyscallResult;
+
+    /// Stop a process from being scheduled until resumed
+    fn pause_process(&self, pid: Pid, target_pid: Pid) -> SyscallResult;
+
+    /// Make a paused process schedulable again
+    fn resume_process(&self, pid: Pid, target_pid: Pid) -> SyscallResult;
 }
 
 /// Scheduler policy management
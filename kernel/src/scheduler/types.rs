@@ -136,6 +136,23 @@ pub enum PriorityOp {
 pub const MAX_PRIORITY: u8 = 10;
 pub const DEFAULT_PRIORITY: u8 = 5;
 
+/// Niceness bounds: one step per priority level, 0 at `DEFAULT_PRIORITY`,
+/// lower values meaning higher priority
+pub const MIN_NICE: i8 = DEFAULT_PRIORITY as i8 - MAX_PRIORITY as i8;
+pub const MAX_NICE: i8 = DEFAULT_PRIORITY as i8 - MIN_PRIORITY as i8;
+
+/// Priority for a niceness; out-of-range values are clamped, as setpriority(2) does
+#[inline]
+pub fn nice_to_priority(nice: i8) -> u8 {
+    (DEFAULT_PRIORITY as i8 - nice.clamp(MIN_NICE, MAX_NICE)) as u8
+}
+
+/// Niceness for a priority
+#[inline]
+pub fn priority_to_nice(priority: u8) -> i8 {
+    DEFAULT_PRIORITY as i8 - priority.min(MAX_PRIORITY) as i8
+}
+
 /// Validate priority value
 ///
 /// # Performance
@@ -155,6 +172,15 @@ fn make_error(priority: u8) -> String {
     }
 }
 
+/// Validate priority value, then clamp it to a per-process `ceiling`
+///
+/// Returns the priority that may actually be granted; it is below the
+/// request when the ceiling applied.
+#[inline]
+pub fn validate_priority_with_ceiling(priority: u8, ceiling: u8) -> Result<u8, String> {
+    validate_priority(priority).map(|priority| priority.min(ceiling))
+}
+
 /// Apply priority operation
 pub fn apply_priority_op(current: u8, op: PriorityOp) -> Result<u8, String> {
     match op {
@@ -222,4 +248,24 @@ fn test_priority_operations() {
         assert_eq!(apply_priority_op(5, PriorityOp::Set(8)).unwrap(), 8);
         assert!(apply_priority_op(5, PriorityOp::Set(15)).is_err());
     }
+
+    #[test]
+    fn test_nice_maps_onto_priority_range() {
+        assert_eq!(nice_to_priority(0), DEFAULT_PRIORITY);
+        assert_eq!(nice_to_priority(MIN_NICE), MAX_PRIORITY);
+        assert_eq!(nice_to_priority(MAX_NICE), MIN_PRIORITY);
+        assert_eq!(nice_to_priority(-20), MAX_PRIORITY);
+        assert_eq!(nice_to_priority(19), MIN_PRIORITY);
+        for nice in MIN_NICE..=MAX_NICE {
+            assert_eq!(priority_to_nice(nice_to_priority(nice)), nice);
+        }
+    }
+
+    #[test]
+    fn test_priority_ceiling() {
+        assert_eq!(validate_priority_with_ceiling(8, MAX_PRIORITY).unwrap(), 8);
+        assert_eq!(validate_priority_with_ceiling(8, 6).unwrap(), 6);
+        assert_eq!(validate_priority_with_ceiling(3, 6).unwrap(), 3);
+        assert!(validate_priority_with_ceiling(15, 6).is_err());
+    }
 }
@@ -14,7 +14,7 @@
 pub use ebpf::EbpfManagerImpl;
 pub use limits::LimitManager;
 pub use namespace::NamespaceManager;
-pub use sandbox::SandboxManager;
+pub use sandbox::{Delegation, DelegationConstraints, SandboxManager};
 pub use traits::*;
 pub use types::*;
 
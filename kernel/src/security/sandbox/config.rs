@@ -2,8 +2,10 @@
  * Sandbox Configuration Logic
  */
 
-use crate::security::types::{Capability, SandboxConfig};
-use std::path::{Path, PathBuf};
+use super::network::host_matches;
+use crate::core::optimization::PrefixSet;
+use crate::security::types::{Capability, NetworkRule, SandboxConfig, SandboxError};
+use std::path::{Component, Path, PathBuf};
 
 /// Safely canonicalize a path with fallback for non-existent paths
 /// Uses parent canonicalization if the path doesn't exist
@@ -24,38 +26,261 @@ fn safe_canonicalize(path: &Path) -> PathBuf {
     path.to_path_buf()
 }
 
-impl SandboxConfig {
-    /// Check if a capability is granted (considering granularity)
-    pub fn has_capability(&self, cap: &Capability) -> bool {
-        self.capabilities.iter().any(|c| c.grants(cap))
+/// Path as a string ending in `/`, for byte-wise prefix matching
+///
+/// Normalizes like the VFS: relative paths are rooted at `/`, and redundant
+/// or trailing separators are dropped, so "/a/b" and "/a/b/" compare equal.
+/// The trailing separator keeps matching component-aware ("/a/bc" is not
+/// under "/a/b"). `None` for non-UTF-8 paths.
+fn prefix_key(path: &Path) -> Option<String> {
+    let mut key = String::with_capacity(path.as_os_str().len() + 1);
+    for component in path.components() {
+        if matches!(component, Component::RootDir) {
+            continue;
+        }
+        key.push('/');
+        key.push_str(component.as_os_str().to_str()?);
+    }
+    key.push('/');
+    Some(key)
+}
+
+/// A list of path prefixes keyed once for repeated lookups
+#[derive(Debug)]
+pub(crate) struct PathPrefixes {
+    paths: Vec<PathBuf>,
+    /// `None` if any prefix is non-UTF-8
+    keys: Option<PrefixSet>,
+}
+
+impl PathPrefixes {
+    pub(crate) fn new(paths: &[PathBuf]) -> Self {
+        let keys = paths
+            .iter()
+            .map(|p| prefix_key(p))
+            .collect::<Option<Vec<String>>>()
+            .map(PrefixSet::new);
+        Self {
+            paths: paths.to_vec(),
+            keys,
+        }
+    }
+
+    #[inline]
+    pub(crate) fn is_empty(&self) -> bool {
+        self.paths.is_empty()
+    }
+
+    /// Whether `path` lies within any of the prefixes
+    pub(crate) fn covers(&self, path: &Path) -> bool {
+        match (&self.keys, prefix_key(path)) {
+            (Some(keys), Some(path)) => keys.find(&path).is_some(),
+            // Non-UTF-8 paths fall back to component comparison
+            _ => self.paths.iter().any(|prefix| path.starts_with(prefix)),
+        }
+    }
+}
+
+/// A sandbox's allowed and blocked paths, keyed for access checks
+///
+/// Build once per config and reuse it; `SandboxConfig::can_access_path`
+/// rebuilds it on every call.
+#[derive(Debug)]
+pub(crate) struct PathRules {
+    allowed: PathPrefixes,
+    blocked: PathPrefixes,
+}
+
+impl PathRules {
+    pub(crate) fn new(config: &SandboxConfig) -> Self {
+        Self {
+            allowed: PathPrefixes::new(&config.allowed_paths),
+            blocked: PathPrefixes::new(&config.blocked_paths),
+        }
     }
 
     /// Check if a path is accessible
     /// Always canonicalizes paths before checking to prevent TOCTOU attacks
-    pub fn can_access_path(&self, path: &Path) -> bool {
+    pub(crate) fn can_access(&self, path: &Path) -> bool {
         // Always canonicalize the path being checked
         let check_path = safe_canonicalize(path);
 
         // First check if explicitly blocked (paths in list are already canonical)
-        for blocked in &self.blocked_paths {
-            if check_path.starts_with(blocked) {
-                return false;
-            }
+        if self.blocked.covers(&check_path) {
+            return false;
         }
 
         // If no allowed paths specified, deny all
-        if self.allowed_paths.is_empty() {
+        if self.allowed.is_empty() {
             return false;
         }
 
         // Check if path is within allowed paths (paths in list are already canonical)
-        for allowed in &self.allowed_paths {
-            if check_path.starts_with(allowed) {
-                return true;
+        self.allowed.covers(&check_path)
+    }
+}
+
+/// Path scope of a file capability, None for unscoped or non-file grants
+fn file_scope(cap: &Capability) -> Option<&Path> {
+    match cap {
+        Capability::ReadFile(Some(path))
+        | Capability::WriteFile(Some(path))
+        | Capability::CreateFile(Some(path))
+        | Capability::DeleteFile(Some(path))
+        | Capability::ListDirectory(Some(path)) => Some(path),
+        _ => None,
+    }
+}
+
+/// Host rule that blocks every host on every port
+fn is_block_all(rule: &NetworkRule) -> bool {
+    matches!(rule, NetworkRule::BlockHost { host, port: None } if host == "*")
+}
+
+impl SandboxConfig {
+    /// Check the config for rules that contradict each other or can never apply
+    ///
+    /// Reports every problem at once:
+    /// - contradictions: a path or host both allowed and blocked, or a
+    ///   block-everything rule (`/`, host `*` on any port) next to allows
+    /// - unreachable rules: allows shadowed by a broader block, and scoped
+    ///   file capabilities that no allowed path can reach
+    /// - missing capabilities: grants that only work alongside another grant
+    pub fn validate(&self) -> Result<(), Vec<SandboxError>> {
+        let mut errors = Vec::new();
+        self.validate_paths(&mut errors);
+        self.validate_network_rules(&mut errors);
+        self.validate_capabilities(&mut errors);
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+
+    fn validate_paths(&self, errors: &mut Vec<SandboxError>) {
+        let allowed: Vec<PathBuf> = self
+            .allowed_paths
+            .iter()
+            .map(|p| safe_canonicalize(p))
+            .collect();
+        let blocked: Vec<PathBuf> = self
+            .blocked_paths
+            .iter()
+            .map(|p| safe_canonicalize(p))
+            .collect();
+        let blocks_all = blocked.iter().any(|p| p == Path::new("/"));
+        let allowed_prefixes = PathPrefixes::new(&allowed);
+        let blocked_prefixes = PathPrefixes::new(&blocked);
+
+        for path in &allowed {
+            if blocks_all {
+                errors.push(SandboxError::Contradiction(
+                    format!("{} is allowed but / is blocked", path.display()).into(),
+                ));
+            } else if blocked.contains(path) {
+                errors.push(SandboxError::Contradiction(
+                    format!("{} is both allowed and blocked", path.display()).into(),
+                ));
+            } else if blocked_prefixes.covers(path) {
+                errors.push(SandboxError::UnreachableRule(
+                    format!("allowed path {} lies under a blocked path", path.display()).into(),
+                ));
+            }
+        }
+
+        for cap in &self.capabilities {
+            let Some(scope) = file_scope(cap).map(safe_canonicalize) else {
+                continue;
+            };
+            // Reachable if the scope overlaps an allowed path in either direction
+            let scope_prefix = PathPrefixes::new(std::slice::from_ref(&scope));
+            let reachable = allowed_prefixes.covers(&scope)
+                || allowed.iter().any(|path| scope_prefix.covers(path));
+
+            if blocked_prefixes.covers(&scope) {
+                errors.push(SandboxError::UnreachableRule(
+                    format!("{} is scoped to a blocked path", cap).into(),
+                ));
+            } else if !reachable {
+                errors.push(SandboxError::UnreachableRule(
+                    format!("{} is scoped outside every allowed path", cap).into(),
+                ));
+            }
+        }
+    }
+
+    fn validate_network_rules(&self, errors: &mut Vec<SandboxError>) {
+        let rules = &self.network_rules;
+        let blocks_all = rules.iter().any(is_block_all);
+
+        for rule in rules {
+            match rule {
+                NetworkRule::AllowAll | NetworkRule::AllowCIDR(_) if blocks_all => {
+                    errors.push(SandboxError::Contradiction(
+                        format!("{:?} is allowed but every host is blocked", rule).into(),
+                    ));
+                }
+                NetworkRule::AllowHost { host, port } => {
+                    let exact = NetworkRule::BlockHost {
+                        host: host.clone(),
+                        port: *port,
+                    };
+                    let shadowed = rules.iter().any(|block| match block {
+                        NetworkRule::BlockHost {
+                            host: blocked_host,
+                            port: blocked_port,
+                        } => {
+                            host_matches(host, blocked_host)
+                                && (blocked_port.is_none() || blocked_port == port)
+                        }
+                        _ => false,
+                    });
+
+                    if blocks_all {
+                        errors.push(SandboxError::Contradiction(
+                            format!("host {} is allowed but every host is blocked", host).into(),
+                        ));
+                    } else if rules.contains(&exact) {
+                        errors.push(SandboxError::Contradiction(
+                            format!("host {} is both allowed and blocked", host).into(),
+                        ));
+                    } else if shadowed {
+                        errors.push(SandboxError::UnreachableRule(
+                            format!("allowed host {} is covered by a broader block", host).into(),
+                        ));
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+
+    fn validate_capabilities(&self, errors: &mut Vec<SandboxError>) {
+        let has_network = self
+            .capabilities
+            .iter()
+            .any(|cap| matches!(cap, Capability::NetworkAccess(_)));
+
+        for cap in &self.capabilities {
+            if matches!(cap, Capability::BindPort(_)) && !has_network {
+                errors.push(SandboxError::MissingCapability(
+                    format!("NetworkAccess (required by {})", cap).into(),
+                ));
             }
         }
+    }
 
-        false
+    /// Check if a capability is granted (considering granularity)
+    pub fn has_capability(&self, cap: &Capability) -> bool {
+        self.capabilities.iter().any(|c| c.grants(cap))
+    }
+
+    /// Check if a path is accessible
+    /// Always canonicalizes paths before checking to prevent TOCTOU attacks
+    pub fn can_access_path(&self, path: &Path) -> bool {
+        PathRules::new(self).can_access(path)
     }
 
     /// Add a capability
@@ -0,0 +1,257 @@
+/*!
+ * Capability Delegation
+ * Grants handed from one process to another, tracked for audit and revocation
+ *
+ * Every delegation is recorded as a `from -> to` edge. Following edges back
+ * from a holder gives the chain the capability arrived through, and revoking
+ * a capability from a process removes its outgoing edges the process can no
+ * longer back, so the revocation cascades down the chain.
+ *
+ * The graph also remembers which grants a delegation added. A cascade only
+ * removes those, so a recipient that held the capability independently
+ * keeps it.
+ */
+
+use crate::core::types::Pid;
+use crate::security::types::{Capability, SecurityError, SecurityResult};
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use std::path::PathBuf;
+
+/// Limits placed on a delegated capability
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub struct DelegationConstraints {
+    /// Narrow a file capability to this subtree
+    pub path: Option<PathBuf>,
+    /// Whether the target may delegate the capability onwards
+    pub redelegate: bool,
+}
+
+impl DelegationConstraints {
+    /// Delegate exactly what the source holds, without onward delegation
+    pub fn new() -> Self {
+        Self {
+            path: None,
+            redelegate: false,
+        }
+    }
+
+    pub fn with_path(mut self, path: impl Into<PathBuf>) -> Self {
+        self.path = Some(path.into());
+        self
+    }
+
+    pub fn with_redelegation(mut self) -> Self {
+        self.redelegate = true;
+        self
+    }
+
+    /// Apply the constraints to `capability`
+    ///
+    /// A path constraint only applies to file capabilities.
+    pub fn scope(&self, capability: &Capability) -> SecurityResult<Capability> {
+        let Some(path) = self.path.clone() else {
+            return Ok(capability.clone());
+        };
+        let path = Some(path);
+        match capability {
+            Capability::ReadFile(_) => Ok(Capability::ReadFile(path)),
+            Capability::WriteFile(_) => Ok(Capability::WriteFile(path)),
+            Capability::CreateFile(_) => Ok(Capability::CreateFile(path)),
+            Capability::DeleteFile(_) => Ok(Capability::DeleteFile(path)),
+            Capability::ListDirectory(_) => Ok(Capability::ListDirectory(path)),
+            other => Err(SecurityError::InvalidConfig(
+                format!("path constraint does not apply to {}", other).into(),
+            )),
+        }
+    }
+}
+
+impl Default for DelegationConstraints {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// A capability one process delegated to another
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub struct Delegation {
+    pub from: Pid,
+    pub to: Pid,
+    /// The capability as granted, after constraints
+    pub capability: Capability,
+    pub redelegate: bool,
+}
+
+/// All live delegations
+#[derive(Debug, Default)]
+pub(super) struct DelegationGraph {
+    edges: Vec<Delegation>,
+    /// Grants that exist only because a delegation added them
+    delegated: HashSet<(Pid, Capability)>,
+}
+
+impl DelegationGraph {
+    /// Record a delegation; `added` is whether it gave the recipient a grant
+    /// it didn't already hold
+    pub(super) fn record(&mut self, delegation: Delegation, added: bool) {
+        if added {
+            self.delegated
+                .insert((delegation.to, delegation.capability.clone()));
+        }
+        if !self.edges.contains(&delegation) {
+            self.edges.push(delegation);
+        }
+    }
+
+    /// Note that `pid` was granted `capability` directly, so cascades keep it
+    pub(super) fn mark_direct(&mut self, pid: Pid, capability: &Capability) {
+        self.delegated.remove(&(pid, capability.clone()));
+    }
+
+    /// Forget provenance of grants to `pid` that `capability` covers, once
+    /// they have been revoked
+    pub(super) fn forget_grants(&mut self, pid: Pid, capability: &Capability) {
+        self.delegated
+            .retain(|(holder, granted)| *holder != pid || !capability.grants(granted));
+    }
+
+    /// Whether a cascade should remove `capability` from `pid`
+    ///
+    /// True only if a delegation added the grant and no remaining delegation
+    /// still backs it. The grant's provenance is forgotten in that case.
+    pub(super) fn release(&mut self, pid: Pid, capability: &Capability) -> bool {
+        let key = (pid, capability.clone());
+        if !self.delegated.contains(&key)
+            || self
+                .edges
+                .iter()
+                .any(|d| d.to == pid && d.capability == *capability)
+        {
+            return false;
+        }
+        self.delegated.remove(&key);
+        true
+    }
+
+    /// Delegations into `pid` that cover `capability`
+    fn inbound<'a>(
+        &'a self,
+        pid: Pid,
+        capability: &'a Capability,
+    ) -> impl Iterator<Item = &'a Delegation> {
+        self.edges
+            .iter()
+            .filter(move |d| d.to == pid && d.capability.grants(capability))
+    }
+
+    /// Whether `pid` holds `capability` only through delegations that forbid
+    /// passing it on
+    pub(super) fn forbids_redelegation(&self, pid: Pid, capability: &Capability) -> bool {
+        let mut inbound = self.inbound(pid, capability).peekable();
+        inbound.peek().is_some() && inbound.all(|d| !d.redelegate)
+    }
+
+    /// Processes `capability` passed through to reach `pid`, origin first
+    pub(super) fn chain(&self, pid: Pid, capability: &Capability) -> Vec<Pid> {
+        let mut chain = vec![pid];
+        let mut current = pid;
+        while let Some(delegation) = self.inbound(current, capability).next() {
+            if chain.contains(&delegation.from) {
+                break;
+            }
+            current = delegation.from;
+            chain.push(current);
+        }
+        chain.reverse();
+        chain
+    }
+
+    /// Remove delegations from `pid` that it can no longer back
+    pub(super) fn take_unbacked(
+        &mut self,
+        pid: Pid,
+        still_holds: impl Fn(&Capability) -> bool,
+    ) -> Vec<Delegation> {
+        let (unbacked, kept) = std::mem::take(&mut self.edges)
+            .into_iter()
+            .partition(|d| d.from == pid && !still_holds(&d.capability));
+        self.edges = kept;
+        unbacked
+    }
+
+    /// Forget every delegation to or from `pid`
+    pub(super) fn remove_pid(&mut self, pid: Pid) {
+        self.edges.retain(|d| d.from != pid && d.to != pid);
+        self.delegated.retain(|(holder, _)| *holder != pid);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn delegation(from: Pid, to: Pid, redelegate: bool) -> Delegation {
+        Delegation {
+            from,
+            to,
+            capability: Capability::ReadFile(Some(PathBuf::from("/data"))),
+            redelegate,
+        }
+    }
+
+    #[test]
+    fn test_chain_and_redelegation() {
+        let mut graph = DelegationGraph::default();
+        graph.record(delegation(1, 2, true), true);
+        graph.record(delegation(2, 3, false), true);
+
+        let cap = Capability::ReadFile(Some(PathBuf::from("/data/file")));
+        assert_eq!(graph.chain(3, &cap), vec![1, 2, 3]);
+        assert!(!graph.forbids_redelegation(2, &cap));
+        assert!(graph.forbids_redelegation(3, &cap));
+        // Direct grants are not constrained
+        assert!(!graph.forbids_redelegation(1, &cap));
+
+        let taken = graph.take_unbacked(2, |_| false);
+        assert_eq!(taken, vec![delegation(2, 3, false)]);
+        assert_eq!(graph.chain(3, &cap), vec![3]);
+    }
+
+    #[test]
+    fn test_release_only_delegation_added_grants() {
+        let mut graph = DelegationGraph::default();
+        let cap = delegation(1, 2, false).capability;
+
+        // Held before the delegation arrived
+        graph.record(delegation(1, 2, false), false);
+        graph.take_unbacked(1, |_| false);
+        assert!(!graph.release(2, &cap));
+
+        // Added by two delegations: released once neither backs it
+        graph.record(delegation(1, 3, false), true);
+        graph.record(delegation(4, 3, false), false);
+        graph.take_unbacked(1, |_| false);
+        assert!(!graph.release(3, &cap));
+        graph.take_unbacked(4, |_| false);
+        assert!(graph.release(3, &cap));
+
+        // Granted directly after being delegated
+        graph.record(delegation(1, 5, false), true);
+        graph.mark_direct(5, &cap);
+        graph.take_unbacked(1, |_| false);
+        assert!(!graph.release(5, &cap));
+    }
+
+    #[test]
+    fn test_path_constraint_only_scopes_files() {
+        let constraints = DelegationConstraints::new().with_path("/data/reports");
+        assert_eq!(
+            constraints.scope(&Capability::WriteFile(None)).unwrap(),
+            Capability::WriteFile(Some(PathBuf::from("/data/reports")))
+        );
+        assert!(constraints.scope(&Capability::SpawnProcess).is_err());
+    }
+}
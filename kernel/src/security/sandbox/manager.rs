@@ -3,25 +3,37 @@
  */
 
 use super::capability;
+use super::config::PathRules;
+use super::delegation::{Delegation, DelegationConstraints, DelegationGraph};
 use super::network;
 use crate::core::types::{Pid, ResourceLimits};
 use crate::core::{ShardManager, WorkloadProfile};
-use crate::monitoring::Collector;
+use crate::memory::{OOM_SCORE_ADJ_MAX, OOM_SCORE_ADJ_MIN};
+use crate::monitoring::{Category, Collector, Event, Payload, Severity};
+use crate::permissions::cache::PermissionCache;
+use crate::scheduler::{validate_priority_with_ceiling, MAX_PRIORITY};
 use crate::security::namespace::{IsolationMode, NamespaceConfig, NamespaceManager};
 use crate::security::traits::*;
 use crate::security::types::*;
 use ahash::RandomState;
 use dashmap::DashMap;
 use log::{info, warn};
+use parking_lot::{Mutex, RwLock};
 use std::path::{Path, PathBuf};
-use std::sync::Arc;
+use std::sync::{Arc, Weak};
 
 /// Sandbox manager that enforces security policies
 #[derive(Clone)]
 pub struct SandboxManager {
     sandboxes: Arc<DashMap<Pid, SandboxConfig, RandomState>>,
+    /// Each sandbox's path lists keyed for matching, rebuilt when it changes
+    path_rules: Arc<DashMap<Pid, Arc<PathRules>, RandomState>>,
     spawned_counts: Arc<DashMap<Pid, u32, RandomState>>,
     namespace_manager: Option<NamespaceManager>,
+    /// Caches holding decisions derived from current grants
+    permission_caches: Arc<RwLock<Vec<Weak<PermissionCache>>>>,
+    /// Capabilities passed between processes, for audit and cascading revocation
+    delegations: Arc<Mutex<DelegationGraph>>,
     collector: Option<Arc<Collector>>,
 }
 
@@ -38,6 +50,14 @@ pub fn new() -> Self {
                 )
                 .into(),
             ),
+            path_rules: Arc::new(
+                DashMap::with_capacity_and_hasher_and_shard_amount(
+                    0,
+                    RandomState::new(),
+                    ShardManager::shards(WorkloadProfile::MediumContention), // checked with sandboxes
+                )
+                .into(),
+            ),
             spawned_counts: Arc::new(
                 DashMap::with_capacity_and_hasher_and_shard_amount(
                     0,
@@ -47,6 +67,8 @@ pub fn new() -> Self {
                 .into(),
             ),
             namespace_manager: None,
+            permission_caches: Arc::default(),
+            delegations: Arc::default(),
             collector: None,
         }
     }
@@ -83,6 +105,14 @@ pub fn with_namespaces() -> Self {
                 )
                 .into(),
             ),
+            path_rules: Arc::new(
+                DashMap::with_capacity_and_hasher_and_shard_amount(
+                    0,
+                    RandomState::new(),
+                    ShardManager::shards(WorkloadProfile::MediumContention), // checked with sandboxes
+                )
+                .into(),
+            ),
             spawned_counts: Arc::new(
                 DashMap::with_capacity_and_hasher_and_shard_amount(
                     0,
@@ -92,6 +122,8 @@ pub fn with_namespaces() -> Self {
                 .into(),
             ),
             namespace_manager: Some(ns_manager),
+            permission_caches: Arc::default(),
+            delegations: Arc::default(),
             collector: None,
         }
     }
@@ -145,6 +177,229 @@ pub fn destroy_namespace(&self, pid: Pid) -> Result<(), String> {
         }
     }
 
+    /// Invalidate `cache` entries for a PID whenever its grants change
+    pub fn register_permission_cache(&self, cache: &Arc<PermissionCache>) {
+        let mut caches = self.permission_caches.write();
+        caches.retain(|cache| cache.strong_count() > 0);
+        caches.push(Arc::downgrade(cache));
+    }
+
+    /// Drop cached decisions that may predate a grant change
+    fn invalidate_cached_decisions(&self, pid: Pid) {
+        for cache in self
+            .permission_caches
+            .read()
+            .iter()
+            .filter_map(Weak::upgrade)
+        {
+            cache.invalidate_pid(pid);
+        }
+    }
+
+    /// Grant every capability in a class
+    pub fn grant_all(&self, pid: Pid, class: CapabilityClass) -> SecurityResult<()> {
+        let mut delegations = self.delegations.lock();
+        let mut sandbox = self
+            .sandboxes
+            .get_mut(&pid)
+            .ok_or(SecurityError::SandboxNotFound(pid))?;
+        for cap in class.capabilities() {
+            delegations.mark_direct(pid, &cap);
+            sandbox.grant_capability(cap);
+        }
+        drop(sandbox);
+        drop(delegations);
+
+        info!("Granted all {:?} capabilities to PID {}", class, pid);
+        self.invalidate_cached_decisions(pid);
+        Ok(())
+    }
+
+    /// Revoke a capability along with any narrower grants it covers
+    ///
+    /// Broader grants are kept, so revoking `ReadFile(Some(path))` leaves
+    /// `ReadFile(None)` in place. Anything `pid` delegated that it can no
+    /// longer back is revoked from the recipients too, down the whole chain.
+    /// Returns whether anything was revoked from `pid`.
+    pub fn revoke(&self, pid: Pid, cap: &Capability) -> SecurityResult<bool> {
+        self.revoke_cascading(pid, cap, false)
+    }
+
+    fn revoke_cascading(&self, pid: Pid, cap: &Capability, cascaded: bool) -> SecurityResult<bool> {
+        // Held across the revoke so a concurrent delegate can't record an
+        // edge the cascade has already swept
+        let mut delegations = self.delegations.lock();
+        let mut sandbox = self
+            .sandboxes
+            .get_mut(&pid)
+            .ok_or(SecurityError::SandboxNotFound(pid))?;
+        let revoked = if cascaded {
+            // Only take back what the delegation added; grants the recipient
+            // holds independently or through another delegation stay
+            delegations.release(pid, cap) && sandbox.capabilities.remove(cap)
+        } else {
+            let before = sandbox.capabilities.len();
+            sandbox.capabilities.retain(|granted| !cap.grants(granted));
+            delegations.forget_grants(pid, cap);
+            sandbox.capabilities.len() < before
+        };
+        drop(sandbox);
+
+        let unbacked = delegations.take_unbacked(pid, |delegated| {
+            self.sandboxes
+                .get(&pid)
+                .is_some_and(|sandbox| sandbox.has_capability(delegated))
+        });
+        drop(delegations);
+
+        if revoked {
+            info!("Revoked capability {} from PID {}", cap, pid);
+            self.invalidate_cached_decisions(pid);
+            self.emit_security(
+                pid,
+                Payload::CapabilityRevoked {
+                    capability: cap.to_string().into(),
+                    cascaded,
+                },
+            );
+        }
+
+        for delegation in unbacked {
+            info!(
+                "Cascading revocation of {} from PID {} to PID {}",
+                delegation.capability, pid, delegation.to
+            );
+            // The recipient may already have exited
+            let _ = self.revoke_cascading(delegation.to, &delegation.capability, true);
+        }
+        Ok(revoked)
+    }
+
+    /// Grant a capability `from_pid` holds to `to_pid`
+    ///
+    /// `constraints` can narrow a file capability to a subtree and decide
+    /// whether the recipient may pass it on. The source must hold the
+    /// narrowed capability, and must not itself have received it through a
+    /// delegation that forbids passing it on. Returns the capability granted.
+    pub fn delegate(
+        &self,
+        from_pid: Pid,
+        to_pid: Pid,
+        capability: &Capability,
+        constraints: DelegationConstraints,
+    ) -> SecurityResult<Capability> {
+        let scoped = constraints.scope(capability)?;
+
+        let mut delegations = self.delegations.lock();
+        let holds = self
+            .sandboxes
+            .get(&from_pid)
+            .ok_or(SecurityError::SandboxNotFound(from_pid))?
+            .has_capability(&scoped);
+        if !holds {
+            return Err(SecurityError::CapabilityMissing(
+                format!("PID {} cannot delegate {}", from_pid, scoped).into(),
+            ));
+        }
+        if delegations.forbids_redelegation(from_pid, &scoped) {
+            return Err(SecurityError::PermissionDenied(
+                format!("PID {} may not redelegate {}", from_pid, scoped).into(),
+            ));
+        }
+
+        let added = self
+            .sandboxes
+            .get_mut(&to_pid)
+            .ok_or(SecurityError::SandboxNotFound(to_pid))?
+            .capabilities
+            .insert(scoped.clone());
+        delegations.record(
+            Delegation {
+                from: from_pid,
+                to: to_pid,
+                capability: scoped.clone(),
+                redelegate: constraints.redelegate,
+            },
+            added,
+        );
+        let chain = delegations.chain(to_pid, &scoped);
+        drop(delegations);
+
+        info!(
+            "PID {} delegated {} to PID {} (chain {:?})",
+            from_pid, scoped, to_pid, chain
+        );
+        self.invalidate_cached_decisions(to_pid);
+        self.emit_security(
+            from_pid,
+            Payload::CapabilityDelegated {
+                to_pid,
+                capability: scoped.to_string().into(),
+            },
+        );
+        Ok(scoped)
+    }
+
+    /// Processes `cap` was delegated through to reach `pid`, origin first
+    ///
+    /// Just `[pid]` if `pid` was granted it directly.
+    pub fn delegation_chain(&self, pid: Pid, cap: &Capability) -> Vec<Pid> {
+        self.delegations.lock().chain(pid, cap)
+    }
+
+    fn emit_security(&self, pid: Pid, payload: Payload) {
+        if let Some(ref collector) = self.collector {
+            collector.emit(Event::new(Severity::Info, Category::Security, payload).with_pid(pid));
+        }
+    }
+
+    /// Validate and register a sandbox
+    ///
+    /// Configs with contradictory or unreachable rules are rejected with
+    /// every problem found, leaving the process without a sandbox (deny all).
+    /// A sandbox the process already had is removed.
+    pub fn try_create_sandbox(&self, config: SandboxConfig) -> Result<(), Vec<SandboxError>> {
+        let pid = config.pid;
+        if let Err(errors) = config.validate() {
+            self.remove_sandbox(pid);
+            return Err(errors);
+        }
+
+        // Create network namespace if capability is granted
+        if config.has_capability(&Capability::NetworkNamespace) {
+            if let Err(e) = self.create_namespace(pid, IsolationMode::Private) {
+                warn!("Failed to create network namespace for PID {}: {}", pid, e);
+            }
+        }
+
+        self.path_rules
+            .insert(pid, Arc::new(PathRules::new(&config)));
+        self.sandboxes.insert(pid, config);
+        info!("Created sandbox for PID {}", pid);
+        Ok(())
+    }
+
+    /// Validate and replace an existing sandbox
+    ///
+    /// Returns `Ok(false)` if the process has no sandbox. Invalid configs are
+    /// rejected with every problem found and the current sandbox is kept.
+    pub fn try_update_sandbox(
+        &self,
+        pid: Pid,
+        config: SandboxConfig,
+    ) -> Result<bool, Vec<SandboxError>> {
+        config.validate()?;
+        if self.sandboxes.contains_key(&pid) {
+            self.path_rules
+                .insert(pid, Arc::new(PathRules::new(&config)));
+            self.sandboxes.insert(pid, config);
+            info!("Updated sandbox for PID {}", pid);
+            Ok(true)
+        } else {
+            Ok(false)
+        }
+    }
+
     /// Check if an operation is allowed
     pub fn check_permission(&self, pid: Pid, cap: &Capability) -> bool {
         if let Some(sandbox) = self.sandboxes.get(&pid) {
@@ -154,7 +409,6 @@ pub fn check_permission(&self, pid: Pid, cap: &Capability) -> bool {
 
                 // Emit security event for denied capability
                 if let Some(ref collector) = self.collector {
-                    use crate::monitoring::{Category, Event, Payload, Severity};
                     collector.emit(
                         Event::new(
                             Severity::Warn,
@@ -177,14 +431,13 @@ pub fn check_permission(&self, pid: Pid, cap: &Capability) -> bool {
 
     /// Check if a path access is allowed
     pub fn check_path_access(&self, pid: Pid, path: &PathBuf) -> bool {
-        if let Some(sandbox) = self.sandboxes.get(&pid) {
-            let allowed = sandbox.can_access_path(path);
+        if let Some(rules) = self.path_rules.get(&pid) {
+            let allowed = rules.can_access(path);
             if !allowed {
                 warn!("PID {} denied path access: {:?}", pid, path);
 
                 // Emit security event for denied path access
                 if let Some(ref collector) = self.collector {
-                    use crate::monitoring::{Category, Event, Payload, Severity};
                     collector.emit(
                         Event::new(
                             Severity::Warn,
@@ -205,6 +458,13 @@ pub fn check_path_access(&self, pid: Pid, path: &PathBuf) -> bool {
         }
     }
 
+    /// Keyed path rules for `pid`, shared with permission evaluation
+    pub(crate) fn path_rules(&self, pid: Pid) -> Option<Arc<PathRules>> {
+        self.path_rules
+            .get(&pid)
+            .map(|rules| Arc::clone(rules.value()))
+    }
+
     /// Check if a file operation is allowed on a specific path
     pub fn check_file_operation(
         &self,
@@ -214,7 +474,10 @@ pub fn check_file_operation(
     ) -> bool {
         if let Some(sandbox) = self.sandboxes.get(&pid) {
             capability::can_access_file(&sandbox.capabilities, operation, path)
-                && sandbox.can_access_path(path)
+                && self
+                    .path_rules
+                    .get(&pid)
+                    .is_some_and(|rules| rules.can_access(path))
         } else {
             false
         }
@@ -228,6 +491,129 @@ pub fn check_network_access(&self, pid: Pid, host: &str, port: Option<u16>) -> b
             false
         }
     }
+
+    /// Cap the scheduler priority a process may be given
+    pub fn set_priority_ceiling(&self, pid: Pid, ceiling: u8) -> SecurityResult<()> {
+        let ceiling = ceiling.min(MAX_PRIORITY);
+        self.sandboxes
+            .get_mut(&pid)
+            .ok_or(SecurityError::SandboxNotFound(pid))?
+            .priority_ceiling = ceiling;
+
+        info!("Set priority ceiling of PID {} to {}", pid, ceiling);
+        Ok(())
+    }
+
+    /// Highest priority `pid` may be given; unsandboxed processes are uncapped
+    pub fn priority_ceiling(&self, pid: Pid) -> u8 {
+        self.sandboxes
+            .get(&pid)
+            .map_or(MAX_PRIORITY, |sandbox| sandbox.priority_ceiling)
+    }
+
+    /// Validate a requested priority for `pid` and clamp it to its ceiling
+    ///
+    /// Emits a security violation when the request exceeds the ceiling.
+    pub fn clamp_priority(&self, pid: Pid, requested: u8) -> Result<u8, String> {
+        let ceiling = self.priority_ceiling(pid);
+        let granted = validate_priority_with_ceiling(requested, ceiling)?;
+
+        if granted < requested {
+            warn!(
+                "Clamped priority request for PID {} from {} to ceiling {}",
+                pid, requested, ceiling
+            );
+            if let Some(ref collector) = self.collector {
+                collector.emit(
+                    Event::new(
+                        Severity::Warn,
+                        Category::Security,
+                        Payload::SecurityViolation {
+                            description: format!(
+                                "priority {} exceeds ceiling {}",
+                                requested, ceiling
+                            )
+                            .into(),
+                        },
+                    )
+                    .with_pid(pid),
+                );
+            }
+        }
+
+        Ok(granted)
+    }
+
+    /// Cap the outbound socket rate a process may use, in bytes/sec
+    pub fn set_pacing_rate_ceiling(&self, pid: Pid, ceiling: u64) -> SecurityResult<()> {
+        let ceiling = ceiling.max(1);
+        self.sandboxes
+            .get_mut(&pid)
+            .ok_or(SecurityError::SandboxNotFound(pid))?
+            .pacing_rate_ceiling = ceiling;
+
+        info!("Set pacing rate ceiling of PID {} to {} B/s", pid, ceiling);
+        Ok(())
+    }
+
+    /// Highest outbound rate `pid` may use; unsandboxed processes are uncapped
+    pub fn pacing_rate_ceiling(&self, pid: Pid) -> u64 {
+        self.sandboxes
+            .get(&pid)
+            .map_or(u64::MAX, |sandbox| sandbox.pacing_rate_ceiling)
+    }
+
+    /// Bound how far below zero a process may lower its OOM score adjustment
+    pub fn set_oom_score_floor(&self, pid: Pid, floor: i16) -> SecurityResult<()> {
+        let floor = floor.clamp(OOM_SCORE_ADJ_MIN, OOM_SCORE_ADJ_MAX);
+        self.sandboxes
+            .get_mut(&pid)
+            .ok_or(SecurityError::SandboxNotFound(pid))?
+            .oom_score_floor = floor;
+
+        info!("Set OOM score floor of PID {} to {}", pid, floor);
+        Ok(())
+    }
+
+    /// Lowest OOM score adjustment `pid` may be given; unsandboxed processes are unbounded
+    pub fn oom_score_floor(&self, pid: Pid) -> i16 {
+        self.sandboxes
+            .get(&pid)
+            .map_or(OOM_SCORE_ADJ_MIN, |sandbox| sandbox.oom_score_floor)
+    }
+
+    /// Clamp a requested OOM score adjustment for `pid` to its floor
+    ///
+    /// Emits a security violation when the request is below the floor.
+    pub fn clamp_oom_score(&self, pid: Pid, requested: i16) -> i16 {
+        let floor = self.oom_score_floor(pid);
+        let granted = requested.clamp(floor, OOM_SCORE_ADJ_MAX);
+
+        if requested < floor {
+            warn!(
+                "Clamped OOM score request for PID {} from {} to floor {}",
+                pid, requested, floor
+            );
+            if let Some(ref collector) = self.collector {
+                collector.emit(
+                    Event::new(
+                        Severity::Warn,
+                        Category::Security,
+                        Payload::SecurityViolation {
+                            description: format!(
+                                "OOM score {} is below floor {}",
+                                requested, floor
+                            )
+                            .into(),
+                        },
+                    )
+                    .with_pid(pid),
+                );
+            }
+        }
+
+        granted
+    }
 }
 
 impl Default for SandboxManager {
@@ -241,16 +627,11 @@ fn default() -> Self {
 impl SandboxProvider for SandboxManager {
     fn create_sandbox(&self, config: SandboxConfig) {
         let pid = config.pid;
-
-        // Create network namespace if capability is granted
-        if config.has_capability(&Capability::NetworkNamespace) {
-            if let Err(e) = self.create_namespace(pid, IsolationMode::Private) {
-                warn!("Failed to create network namespace for PID {}: {}", pid, e);
+        if let Err(errors) = self.try_create_sandbox(config) {
+            for error in &errors {
+                warn!("Rejected sandbox config for PID {}: {}", pid, error);
             }
         }
-
-        self.sandboxes.insert(pid, config);
-        info!("Created sandbox for PID {}", pid);
     }
 
     fn remove_sandbox(&self, pid: Pid) -> bool {
@@ -259,6 +640,8 @@ fn remove_sandbox(&self, pid: Pid) -> bool {
             let _ = self.destroy_namespace(pid);
         }
 
+        self.delegations.lock().remove_pid(pid);
+        self.path_rules.remove(&pid);
         if self.sandboxes.remove(&pid).is_some() {
             info!("Removed sandbox for PID {}", pid);
             true
@@ -276,12 +659,14 @@ fn get_sandbox(&self, pid: Pid) -> Option<SandboxConfig> {
     }
 
     fn update_sandbox(&self, pid: Pid, config: SandboxConfig) -> bool {
-        if self.sandboxes.contains_key(&pid) {
-            self.sandboxes.insert(pid, config);
-            info!("Updated sandbox for PID {}", pid);
-            true
-        } else {
-            false
+        match self.try_update_sandbox(pid, config) {
+            Ok(updated) => updated,
+            Err(errors) => {
+                for error in &errors {
+                    warn!("Rejected sandbox update for PID {}: {}", pid, error);
+                }
+                false
+            }
         }
     }
 
@@ -291,6 +676,11 @@ fn stats(&self) -> SandboxStats {
             active_processes: self.sandboxes.len(),
             permission_denials: 0,
             capability_checks: 0,
+            granted_capabilities: self
+                .sandboxes
+                .iter()
+                .map(|sandbox| sandbox.capabilities.len())
+                .sum(),
         }
     }
 }
@@ -311,8 +701,13 @@ fn check_network_access(&self, pid: Pid) -> bool {
 
 impl CapabilityManager for SandboxManager {
     fn grant_capability(&self, pid: Pid, cap: Capability) -> SecurityResult<()> {
+        let mut delegations = self.delegations.lock();
         if let Some(mut sandbox) = self.sandboxes.get_mut(&pid) {
+            delegations.mark_direct(pid, &cap);
             sandbox.grant_capability(cap);
+            drop(sandbox);
+            drop(delegations);
+            self.invalidate_cached_decisions(pid);
             Ok(())
         } else {
             Err(SecurityError::SandboxNotFound(pid))
@@ -320,12 +715,7 @@ fn grant_capability(&self, pid: Pid, cap: Capability) -> SecurityResult<()> {
     }
 
     fn revoke_capability(&self, pid: Pid, cap: &Capability) -> SecurityResult<()> {
-        if let Some(mut sandbox) = self.sandboxes.get_mut(&pid) {
-            sandbox.revoke_capability(cap);
-            Ok(())
-        } else {
-            Err(SecurityError::SandboxNotFound(pid))
-        }
+        self.revoke(pid, cap).map(|_| ())
     }
 
     fn get_capabilities(&self, pid: Pid) -> Option<Vec<Capability>> {
@@ -381,6 +771,11 @@ fn can_access(&self, pid: Pid, path: &Path) -> bool {
     fn allow_path(&self, pid: Pid, path: PathBuf) -> SecurityResult<()> {
         if let Some(mut sandbox) = self.sandboxes.get_mut(&pid) {
             sandbox.allow_path(path);
+            // Path checks read the keyed rules, not the config
+            self.path_rules
+                .insert(pid, Arc::new(PathRules::new(&sandbox)));
+            drop(sandbox);
+            self.invalidate_cached_decisions(pid);
             Ok(())
         } else {
             Err(SecurityError::SandboxNotFound(pid))
@@ -390,6 +785,11 @@ fn allow_path(&self, pid: Pid, path: PathBuf) -> SecurityResult<()> {
     fn block_path(&self, pid: Pid, path: PathBuf) -> SecurityResult<()> {
         if let Some(mut sandbox) = self.sandboxes.get_mut(&pid) {
             sandbox.block_path(path);
+            // Path checks read the keyed rules, not the config
+            self.path_rules
+                .insert(pid, Arc::new(PathRules::new(&sandbox)));
+            drop(sandbox);
+            self.invalidate_cached_decisions(pid);
             Ok(())
         } else {
             Err(SecurityError::SandboxNotFound(pid))
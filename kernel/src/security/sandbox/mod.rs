@@ -9,8 +9,10 @@
 
 pub mod capability;
 pub mod config;
+pub mod delegation;
 pub mod manager;
 pub mod network;
 pub mod path;
 
+pub use delegation::{Delegation, DelegationConstraints};
 pub use manager::SandboxManager;
@@ -50,7 +50,7 @@ pub fn check_network_access(rules: &[NetworkRule], host: &str, port: Option<u16>
     false
 }
 
-fn host_matches(host: &str, pattern: &str) -> bool {
+pub(super) fn host_matches(host: &str, pattern: &str) -> bool {
     if pattern == "*" || pattern == host {
         return true;
     }
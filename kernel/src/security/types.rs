@@ -76,6 +76,12 @@ pub enum SandboxError {
 
     #[error("Path {0:?} not accessible")]
     PathBlocked(InlineString),
+
+    #[error("Contradictory rules: {0}")]
+    Contradiction(InlineString),
+
+    #[error("Unreachable rule: {0}")]
+    UnreachableRule(InlineString),
 }
 
 /// Resource limits errors
@@ -201,6 +207,61 @@ fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
     }
 }
 
+/// Groups of related capabilities for wildcard grants
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CapabilityClass {
+    FileSystem,
+    Process,
+    Network,
+    System,
+    Ipc,
+}
+
+impl CapabilityClass {
+    /// Broadest capabilities covering every member of the class
+    #[must_use]
+    pub fn capabilities(&self) -> Vec<Capability> {
+        match self {
+            CapabilityClass::FileSystem => vec![
+                Capability::ReadFile(None),
+                Capability::WriteFile(None),
+                Capability::CreateFile(None),
+                Capability::DeleteFile(None),
+                Capability::ListDirectory(None),
+            ],
+            CapabilityClass::Process => vec![Capability::SpawnProcess, Capability::KillProcess],
+            CapabilityClass::Network => vec![
+                Capability::NetworkAccess(NetworkRule::AllowAll),
+                Capability::BindPort(None),
+                Capability::NetworkNamespace,
+            ],
+            CapabilityClass::System => vec![Capability::SystemInfo, Capability::TimeAccess],
+            CapabilityClass::Ipc => vec![Capability::SendMessage, Capability::ReceiveMessage],
+        }
+    }
+}
+
+impl Capability {
+    /// Class this capability belongs to
+    #[must_use]
+    pub fn class(&self) -> CapabilityClass {
+        match self {
+            Capability::ReadFile(_)
+            | Capability::WriteFile(_)
+            | Capability::CreateFile(_)
+            | Capability::DeleteFile(_)
+            | Capability::ListDirectory(_) => CapabilityClass::FileSystem,
+            Capability::SpawnProcess | Capability::KillProcess => CapabilityClass::Process,
+            Capability::NetworkAccess(_)
+            | Capability::BindPort(_)
+            | Capability::NetworkNamespace => CapabilityClass::Network,
+            Capability::SystemInfo | Capability::TimeAccess => CapabilityClass::System,
+            Capability::SendMessage | Capability::ReceiveMessage => CapabilityClass::Ipc,
+        }
+    }
+}
+
 /// Sandbox configuration for a process
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
@@ -217,6 +278,31 @@ pub struct SandboxConfig {
     pub network_rules: Vec<NetworkRule>,
     #[serde(skip_serializing_if = "is_empty_vec")]
     pub environment_vars: Vec<(String, String)>,
+    /// Highest scheduler priority this process may be given
+    #[serde(default = "default_priority_ceiling")]
+    pub priority_ceiling: u8,
+    /// Lowest OOM score adjustment this process may be given
+    #[serde(default = "default_oom_score_floor")]
+    pub oom_score_floor: i16,
+    /// Scrub this process's memory when it is freed
+    #[serde(default)]
+    pub zero_memory_on_free: bool,
+    /// Highest outbound socket rate in bytes/sec; SO_MAX_PACING_RATE can only go lower
+    #[serde(default = "default_pacing_rate_ceiling")]
+    pub pacing_rate_ceiling: u64,
+}
+
+fn default_priority_ceiling() -> u8 {
+    crate::scheduler::MAX_PRIORITY
+}
+
+/// Unprivileged processes may not shield themselves from the OOM killer
+fn default_oom_score_floor() -> i16 {
+    0
+}
+
+fn default_pacing_rate_ceiling() -> u64 {
+    u64::MAX
 }
 
 impl SandboxConfig {
@@ -237,6 +323,10 @@ pub fn minimal(pid: Pid) -> Self {
             ],
             network_rules: vec![],
             environment_vars: vec![],
+            priority_ceiling: default_priority_ceiling(),
+            oom_score_floor: default_oom_score_floor(),
+            zero_memory_on_free: true,
+            pacing_rate_ceiling: default_pacing_rate_ceiling(),
         };
         // Canonicalize all paths for security
         config.canonicalize_paths();
@@ -268,6 +358,10 @@ pub fn standard(pid: Pid) -> Self {
             blocked_paths: vec![PathBuf::from("/etc/passwd"), PathBuf::from("/etc/shadow")],
             network_rules: vec![],
             environment_vars: vec![],
+            priority_ceiling: default_priority_ceiling(),
+            oom_score_floor: default_oom_score_floor(),
+            zero_memory_on_free: false,
+            pacing_rate_ceiling: default_pacing_rate_ceiling(),
         };
         // Canonicalize all paths for security
         config.canonicalize_paths();
@@ -305,6 +399,10 @@ pub fn privileged(pid: Pid) -> Self {
             blocked_paths: vec![],
             network_rules: vec![NetworkRule::AllowAll],
             environment_vars: vec![],
+            priority_ceiling: default_priority_ceiling(),
+            oom_score_floor: crate::memory::OOM_SCORE_ADJ_MIN,
+            zero_memory_on_free: false,
+            pacing_rate_ceiling: default_pacing_rate_ceiling(),
         };
         // Canonicalize all paths for security
         config.canonicalize_paths();
@@ -390,6 +488,9 @@ pub struct SandboxStats {
     pub permission_denials: u64,
     #[serde(skip_serializing_if = "is_zero_u64")]
     pub capability_checks: u64,
+    /// Capabilities currently granted across all sandboxes
+    #[serde(skip_serializing_if = "is_zero_usize")]
+    pub granted_capabilities: usize,
 }
 
 /// Security audit event
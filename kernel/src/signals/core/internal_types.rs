@@ -5,9 +5,11 @@
 
 use super::types::{PendingSignal, Signal, SignalAction};
 use crate::core::types::Pid;
+use crate::signals::management::signalfd::SignalFdQueue;
 use ahash::HashMap;
 use std::cmp::Ordering;
 use std::collections::{BinaryHeap, HashSet};
+use std::sync::Arc;
 
 // Configuration constants - centralized in core::limits
 pub(crate) use crate::core::limits::{
@@ -51,6 +53,10 @@ pub(crate) struct ProcessSignals {
     pub pending: BinaryHeap<PrioritySignal>,
     pub blocked: HashSet<Signal>,
     pub handlers: HashMap<Signal, SignalAction>,
+    /// Open signalfds; matching signals bypass `pending` and handlers
+    pub signalfds: Vec<Arc<SignalFdQueue>>,
+    /// Deliverable signals received so far; interruptible sleeps watch it
+    pub arrivals: u64,
 }
 
 impl ProcessSignals {
@@ -60,6 +66,8 @@ pub fn new(pid: Pid) -> Self {
             pending: BinaryHeap::new(),
             blocked: HashSet::new(),
             handlers: HashMap::default(),
+            signalfds: Vec::new(),
+            arrivals: 0,
         }
     }
 
@@ -70,4 +78,9 @@ pub fn has_pending(&self) -> bool {
     pub fn can_queue(&self) -> bool {
         self.pending.len() < MAX_PENDING_SIGNALS
     }
+
+    /// Signalfd that `signal` is routed to, if any
+    pub fn signalfd_for(&self, signal: Signal) -> Option<&Arc<SignalFdQueue>> {
+        self.signalfds.iter().find(|queue| queue.accepts(signal))
+    }
 }
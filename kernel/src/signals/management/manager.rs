@@ -3,6 +3,7 @@
  * Central manager for process signals with queuing and delivery
  */
 
+use crate::core::sync::{WaitError, WaitQueue};
 use crate::core::types::Pid;
 use crate::core::{ShardManager, WorkloadProfile};
 use crate::signals::core::internal_types::{
@@ -11,13 +12,15 @@
 use crate::signals::core::traits::*;
 use crate::signals::core::types::*;
 use crate::signals::handler::{CallbackRegistry, SignalHandler, SignalOutcome};
+use crate::signals::management::signalfd::{SignalFd, SignalFdQueue};
 use ahash::RandomState;
 use dashmap::DashMap;
 use log::{debug, info, warn};
 use parking_lot::RwLock;
+use std::collections::{BinaryHeap, HashSet};
 use std::sync::atomic::{AtomicU64, Ordering as AtomicOrdering};
 use std::sync::Arc;
-use std::time::{SystemTime, UNIX_EPOCH};
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
 
 /// Signal manager implementation
 ///
@@ -30,7 +33,10 @@ pub struct SignalManagerImpl {
     handler: Arc<SignalHandler>,
     callbacks: Arc<CallbackRegistry>,
     next_handler_id: Arc<AtomicU64>,
+    next_signalfd_id: Arc<AtomicU64>,
     stats: Arc<RwLock<SignalStats>>,
+    /// Processes in an interruptible sleep, keyed by PID
+    sleepers: Arc<WaitQueue<Pid>>,
 }
 
 impl SignalManagerImpl {
@@ -50,6 +56,7 @@ pub fn new() -> Self {
             handler: Arc::new(SignalHandler::new(callbacks.clone().into())),
             callbacks,
             next_handler_id: Arc::new(AtomicU64::new(1)),
+            next_signalfd_id: Arc::new(AtomicU64::new(1)),
             stats: Arc::new(
                 RwLock::new(SignalStats {
                     total_signals_sent: 0,
@@ -61,6 +68,7 @@ pub fn new() -> Self {
                 })
                 .into(),
             ),
+            sleepers: Arc::new(WaitQueue::long_wait()),
         }
     }
 
@@ -101,6 +109,114 @@ pub fn has_process_signals(&self, pid: Pid) -> bool {
         self.processes.get(&pid).is_some()
     }
 
+    /// Route `signals` for `pid` to a new signalfd instead of its handlers
+    ///
+    /// Matching signals already pending are moved to the signalfd. The
+    /// routing ends when the returned SignalFd is dropped.
+    pub fn create_signalfd(&self, pid: Pid, signals: &[Signal]) -> SignalResult<SignalFd> {
+        if signals.is_empty() {
+            return Err(SignalError::OperationFailed(
+                "signalfd requires at least one signal".to_string(),
+            ));
+        }
+        if let Some(signal) = signals.iter().find(|signal| !signal.can_catch()) {
+            return Err(SignalError::PermissionDenied(format!(
+                "Signal {:?} cannot be routed to a signalfd",
+                signal
+            )));
+        }
+
+        let mut proc = self
+            .processes
+            .get_mut(&pid)
+            .ok_or(SignalError::ProcessNotFound(pid))?;
+
+        let id = self.next_signalfd_id.fetch_add(1, AtomicOrdering::SeqCst);
+        let queue = Arc::new(SignalFdQueue::new(
+            id,
+            signals.iter().copied().collect::<HashSet<_>>(),
+        ));
+
+        // Take over matching signals that are already pending
+        let (routed, remaining): (Vec<_>, Vec<_>) = std::mem::take(&mut proc.pending)
+            .into_sorted_vec()
+            .into_iter()
+            .rev()
+            .partition(|ps| queue.accepts(ps.signal.signal));
+        proc.pending = BinaryHeap::from(remaining);
+        for ps in &routed {
+            queue.push(ps.signal.clone());
+        }
+        proc.signalfds.push(Arc::clone(&queue));
+        drop(proc);
+
+        if !routed.is_empty() {
+            let mut stats = self.stats.write();
+            stats.pending_signals = stats.pending_signals.saturating_sub(routed.len());
+        }
+
+        info!(
+            "Created signalfd {} for PID {} routing {:?}",
+            id, pid, signals
+        );
+        Ok(SignalFd::new(pid, queue, self.clone()))
+    }
+
+    /// Stop routing signals to a signalfd
+    pub(super) fn remove_signalfd(&self, pid: Pid, id: u64) {
+        if let Some(mut proc) = self.processes.get_mut(&pid) {
+            proc.signalfds.retain(|queue| queue.id != id);
+            debug!("Removed signalfd {} for PID {}", id, pid);
+        }
+    }
+
+    /// Sleep for up to `duration`, waking early if a deliverable signal arrives
+    ///
+    /// Returns the unslept time when interrupted (nanosleep-style), or None
+    /// if the full duration elapsed. Blocked signals are never queued, so
+    /// they don't wake the sleeper; neither do signals routed to a signalfd.
+    pub fn interruptible_sleep(&self, pid: Pid, duration: Duration) -> Option<Duration> {
+        let start = Instant::now();
+        let seen = self.arrivals(pid);
+
+        match self
+            .sleepers
+            .wait_while(pid, Some(duration), || self.arrivals(pid) == seen)
+        {
+            Ok(()) => {
+                let remaining = duration.saturating_sub(start.elapsed());
+                debug!(
+                    "Sleep of PID {} interrupted by signal ({:?} remaining)",
+                    pid, remaining
+                );
+                Some(remaining)
+            }
+            Err(WaitError::Timeout) => None,
+            Err(e) => {
+                // Finish the sleep rather than report a spurious interruption
+                warn!("Interruptible sleep for PID {} failed: {}", pid, e);
+                std::thread::sleep(duration.saturating_sub(start.elapsed()));
+                None
+            }
+        }
+    }
+
+    /// Count of deliverable signals received by `pid`
+    fn arrivals(&self, pid: Pid) -> u64 {
+        self.processes
+            .get(&pid)
+            .map(|proc| proc.arrivals)
+            .unwrap_or(0)
+    }
+
+    /// Record a deliverable signal for `pid` and wake its interruptible sleeps
+    fn interrupt_sleepers(&self, pid: Pid) {
+        if let Some(mut proc) = self.processes.get_mut(&pid) {
+            proc.arrivals = proc.arrivals.wrapping_add(1);
+        }
+        self.sleepers.wake_all(pid);
+    }
+
     /// Get current timestamp
     fn timestamp() -> u64 {
         SystemTime::now()
@@ -122,6 +238,28 @@ fn queue_signal(&self, sender_pid: Pid, target_pid: Pid, signal: Signal) -> Sign
             .get_mut(&target_pid)
             .ok_or(SignalError::ProcessNotFound(target_pid))?;
 
+        // Signals routed to a signalfd skip the handler queue (and the mask,
+        // as with Linux signalfd where the signal is normally blocked)
+        if let Some(signalfd) = proc.signalfd_for(signal) {
+            let routed = signalfd.push(PendingSignal {
+                signal,
+                sender_pid,
+                timestamp: Self::timestamp(),
+            });
+            drop(proc);
+
+            if !routed {
+                warn!("Signalfd queue full for PID {}", target_pid);
+                return Err(SignalError::QueueFull(target_pid));
+            }
+            self.stats.write().total_signals_queued += 1;
+            debug!(
+                "Routed signal {:?} from PID {} to signalfd of PID {}",
+                signal, sender_pid, target_pid
+            );
+            return Ok(());
+        }
+
         // Check queue capacity
         if !proc.can_queue() {
             warn!("Signal queue full for PID {}", target_pid);
@@ -144,6 +282,9 @@ fn queue_signal(&self, sender_pid: Pid, target_pid: Pid, signal: Signal) -> Sign
             },
             priority: signal.priority(),
         });
+        proc.arrivals = proc.arrivals.wrapping_add(1);
+        drop(proc);
+        self.sleepers.wake_all(target_pid);
 
         let mut stats = self.stats.write();
         stats.total_signals_queued += 1;
@@ -191,6 +332,7 @@ fn send(&self, sender_pid: Pid, target_pid: Pid, signal: Signal) -> SignalResult
         if matches!(signal, Signal::SIGKILL | Signal::SIGSTOP) {
             let action = self.handler.default_action(signal);
             let outcome = self.process_signal(target_pid, signal, action)?;
+            self.interrupt_sleepers(target_pid);
 
             let mut stats = self.stats.write();
             stats.total_signals_sent += 1;
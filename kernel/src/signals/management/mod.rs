@@ -5,7 +5,9 @@
 
 mod delivery;
 mod manager;
+pub(crate) mod signalfd;
 
 // Re-export public API
 pub use delivery::SignalDeliveryHook;
 pub use manager::SignalManagerImpl;
+pub use signalfd::SignalFd;
@@ -0,0 +1,214 @@
+/*!
+ * Signal File Descriptors
+ * signalfd-style delivery of pending signals through a readable fd
+ */
+
+use super::manager::SignalManagerImpl;
+use crate::core::limits::MAX_PENDING_SIGNALS;
+use crate::core::serialization::json;
+use crate::core::sync::readiness::{notify_readiness, ReadinessKey};
+use crate::core::types::Pid;
+use crate::signals::core::types::{PendingSignal, Signal};
+use parking_lot::Mutex;
+use std::collections::{HashSet, VecDeque};
+use std::io;
+use std::sync::Arc;
+
+/// Signal queue shared between a SignalFd and the signal manager
+#[derive(Debug)]
+pub(crate) struct SignalFdQueue {
+    pub id: u64,
+    signals: HashSet<Signal>,
+    pending: Mutex<VecDeque<PendingSignal>>,
+}
+
+impl SignalFdQueue {
+    pub fn new(id: u64, signals: HashSet<Signal>) -> Self {
+        Self {
+            id,
+            signals,
+            pending: Mutex::new(VecDeque::new()),
+        }
+    }
+
+    /// Whether `signal` is routed to this fd
+    #[inline]
+    pub fn accepts(&self, signal: Signal) -> bool {
+        self.signals.contains(&signal)
+    }
+
+    /// Queue a signal, returning false if the queue is full
+    pub fn push(&self, signal: PendingSignal) -> bool {
+        let mut pending = self.pending.lock();
+        if pending.len() >= MAX_PENDING_SIGNALS {
+            return false;
+        }
+        pending.push_back(signal);
+        drop(pending);
+        notify_readiness(ReadinessKey::of(self));
+        true
+    }
+}
+
+/// Readable handle for signals routed away from handlers
+///
+/// Signals in the subscribed set are queued here instead of the process's
+/// pending queue, so they are never delivered to handlers while the fd is
+/// open. Dropping the last handle unsubscribes.
+pub struct SignalFd {
+    pid: Pid,
+    queue: Arc<SignalFdQueue>,
+    manager: SignalManagerImpl,
+}
+
+impl SignalFd {
+    pub(super) fn new(pid: Pid, queue: Arc<SignalFdQueue>, manager: SignalManagerImpl) -> Self {
+        Self {
+            pid,
+            queue,
+            manager,
+        }
+    }
+
+    /// Owning process
+    pub fn pid(&self) -> Pid {
+        self.pid
+    }
+
+    /// Signals routed to this fd
+    pub fn signals(&self) -> Vec<Signal> {
+        self.queue.signals.iter().copied().collect()
+    }
+
+    /// Key pollers of this fd wait on
+    pub fn readiness_key(&self) -> ReadinessKey {
+        ReadinessKey::of(&*self.queue)
+    }
+
+    /// Number of signals waiting to be read
+    pub fn pending_count(&self) -> usize {
+        self.queue.pending.lock().len()
+    }
+
+    /// Take all queued signals in arrival order
+    pub fn take_pending(&self) -> Vec<PendingSignal> {
+        self.queue.pending.lock().drain(..).collect()
+    }
+
+    /// Read queued signals into `buf` as a JSON array of PendingSignal
+    ///
+    /// Only whole signals are read; those that don't fit stay queued. Fails
+    /// with `WouldBlock` if nothing is queued and `InvalidInput` if `buf`
+    /// can't hold a single signal.
+    pub fn read_into(&self, buf: &mut [u8]) -> io::Result<usize> {
+        let mut pending = self.queue.pending.lock();
+        if pending.is_empty() {
+            return Err(io::ErrorKind::WouldBlock.into());
+        }
+
+        let mut out = vec![b'['];
+        while let Some(signal) = pending.front() {
+            let encoded = json::to_vec(signal)
+                .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e.to_string()))?;
+            let separator = usize::from(out.len() > 1);
+            // Room for this signal plus the closing bracket
+            if out.len() + separator + encoded.len() + 1 > buf.len() {
+                break;
+            }
+            if separator == 1 {
+                out.push(b',');
+            }
+            out.extend_from_slice(&encoded);
+            pending.pop_front();
+        }
+
+        if out.len() == 1 {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "buffer too small for a pending signal",
+            ));
+        }
+
+        out.push(b']');
+        buf[..out.len()].copy_from_slice(&out);
+        Ok(out.len())
+    }
+}
+
+impl Drop for SignalFd {
+    fn drop(&mut self) {
+        self.manager.remove_signalfd(self.pid, self.queue.id);
+    }
+}
+
+impl std::fmt::Debug for SignalFd {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SignalFd")
+            .field("pid", &self.pid)
+            .field("id", &self.queue.id)
+            .field("signals", &self.queue.signals)
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::signals::core::traits::{SignalDelivery, SignalQueue, SignalStateManager};
+
+    #[test]
+    fn test_read_keeps_signals_that_dont_fit() {
+        let manager = SignalManagerImpl::new();
+        manager.initialize_process(2).unwrap();
+        let signalfd = manager
+            .create_signalfd(2, &[Signal::SIGUSR1, Signal::SIGUSR2])
+            .unwrap();
+
+        manager.send(1, 2, Signal::SIGUSR1).unwrap();
+        manager.send(1, 2, Signal::SIGUSR2).unwrap();
+        assert_eq!(signalfd.pending_count(), 2);
+        assert_eq!(manager.pending_count(2), 0);
+
+        // Room for exactly one encoded signal
+        let one = json::to_vec(&signalfd.queue.pending.lock()[0])
+            .unwrap()
+            .len()
+            + 2;
+        let mut buf = vec![0u8; one];
+        let n = signalfd.read_into(&mut buf).unwrap();
+        let read: Vec<PendingSignal> = serde_json::from_slice(&buf[..n]).unwrap();
+        assert_eq!(read.len(), 1);
+        assert_eq!(read[0].signal, Signal::SIGUSR1);
+        assert_eq!(signalfd.pending_count(), 1);
+
+        let mut tiny = [0u8; 4];
+        assert_eq!(
+            signalfd.read_into(&mut tiny).unwrap_err().kind(),
+            io::ErrorKind::InvalidInput
+        );
+    }
+
+    #[test]
+    fn test_existing_pending_signals_move_to_signalfd() {
+        let manager = SignalManagerImpl::new();
+        manager.initialize_process(2).unwrap();
+        manager.send(1, 2, Signal::SIGUSR1).unwrap();
+        manager.send(1, 2, Signal::SIGTERM).unwrap();
+
+        let signalfd = manager.create_signalfd(2, &[Signal::SIGUSR1]).unwrap();
+        assert_eq!(manager.pending_signals(2), vec![Signal::SIGTERM]);
+        assert_eq!(signalfd.take_pending()[0].signal, Signal::SIGUSR1);
+
+        drop(signalfd);
+        manager.send(1, 2, Signal::SIGUSR1).unwrap();
+        assert_eq!(manager.pending_count(2), 2);
+    }
+
+    #[test]
+    fn test_uncatchable_signals_rejected() {
+        let manager = SignalManagerImpl::new();
+        manager.initialize_process(2).unwrap();
+        assert!(manager.create_signalfd(2, &[Signal::SIGKILL]).is_err());
+        assert!(manager.create_signalfd(2, &[]).is_err());
+    }
+}
@@ -16,4 +16,4 @@
 };
 pub use handler::{CallbackRegistry, HandlerFn, SignalHandler, SignalOutcome};
 pub use integration_support::{outcome_to_state, requires_immediate_action, should_interrupt};
-pub use management::{SignalDeliveryHook, SignalManagerImpl};
+pub use management::{SignalDeliveryHook, SignalFd, SignalManagerImpl};
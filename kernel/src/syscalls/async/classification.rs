@@ -68,11 +68,15 @@ pub fn classify(&self) -> SyscallClass {
             // ================================================================
 
             // Memory management (DashMap lookups, atomic counters)
-            Syscall::GetMemoryStats | Syscall::GetProcessMemoryStats { .. } => SyscallClass::Fast,
+            Syscall::GetMemoryStats
+            | Syscall::GetProcessMemoryStats { .. }
+            | Syscall::GetMemoryMaps { .. } => SyscallClass::Fast,
 
             // Process state queries (cached in ProcessManager)
             Syscall::GetProcessInfo { .. }
             | Syscall::GetProcessList
+            | Syscall::GetProcessChildren { .. }
+            | Syscall::GetProcessNice { .. }
             | Syscall::GetProcessState { .. }
             | Syscall::GetProcessStats { .. } => SyscallClass::Fast,
 
@@ -84,16 +88,35 @@ pub fn classify(&self) -> SyscallClass {
             // Environment variables (HashMap lookup)
             Syscall::GetEnvironmentVar { .. } => SyscallClass::Fast,
 
-            // File descriptor operations (in-memory registry)
-            Syscall::Dup { .. } | Syscall::Dup2 { .. } | Syscall::Fcntl { .. } => {
+            // Non-blocking GetRandom never waits on the rate limit
+            Syscall::GetRandom { flags, .. }
+                if flags & crate::syscalls::impls::GRND_NONBLOCK != 0 =>
+            {
                 SyscallClass::Fast
             }
 
+            // F_SETLKW waits for conflicting range locks to be released
+            Syscall::Fcntl { cmd: 7, .. } => SyscallClass::Blocking,
+
+            // File descriptor operations (in-memory registry)
+            Syscall::Dup { .. }
+            | Syscall::Dup2 { .. }
+            | Syscall::Fcntl { .. }
+            | Syscall::PipeFd { .. } => SyscallClass::Fast,
+
             // IPC stats (in-memory counter reads)
             Syscall::PipeStats { .. } | Syscall::ShmStats { .. } | Syscall::QueueStats { .. } => {
                 SyscallClass::Fast
             }
 
+            // Non-blocking IPC reads return WouldBlock instead of waiting
+            Syscall::ReadPipe {
+                nonblocking: true, ..
+            }
+            | Syscall::ReceiveQueue {
+                nonblocking: true, ..
+            } => SyscallClass::Fast,
+
             // Socket stats (in-memory counter reads)
             // GetSocketInfo removed - use network syscalls instead
 
@@ -114,6 +137,9 @@ pub fn classify(&self) -> SyscallClass {
             // File I/O (kernel syscalls, can block on slow storage)
             Syscall::ReadFile { .. }
             | Syscall::WriteFile { .. }
+            | Syscall::AppendFile { .. }
+            | Syscall::ReadFileVectored { .. }
+            | Syscall::WriteFileVectored { .. }
             | Syscall::CreateFile { .. }
             | Syscall::DeleteFile { .. }
             | Syscall::ListDirectory { .. }
@@ -121,12 +147,19 @@ pub fn classify(&self) -> SyscallClass {
             | Syscall::FileStat { .. }
             | Syscall::MoveFile { .. }
             | Syscall::CopyFile { .. }
+            | Syscall::HashFile { .. }
             | Syscall::CreateDirectory { .. }
             | Syscall::RemoveDirectory { .. }
             | Syscall::TruncateFile { .. }
+            | Syscall::ListMounts
             | Syscall::Open { .. }
             | Syscall::Close { .. }
-            | Syscall::Lseek { .. } => SyscallClass::Blocking,
+            | Syscall::Lseek { .. }
+            | Syscall::Read { .. }
+            | Syscall::Write { .. }
+            | Syscall::Fsync { .. }
+            | Syscall::Sendfile { .. }
+            | Syscall::Poll { .. } => SyscallClass::Blocking,
 
             // Directory operations
             Syscall::SetWorkingDirectory { .. } => SyscallClass::Blocking,
@@ -159,12 +192,16 @@ pub fn classify(&self) -> SyscallClass {
             | Syscall::DetachShm { .. }
             | Syscall::WriteShm { .. }
             | Syscall::ReadShm { .. }
+            | Syscall::ResizeShm { .. }
             | Syscall::DestroyShm { .. } => SyscallClass::Blocking,
 
             // Queue operations (can block on full/empty)
             Syscall::CreateQueue { .. }
             | Syscall::SendQueue { .. }
             | Syscall::ReceiveQueue { .. }
+            | Syscall::AckQueue { .. }
+            | Syscall::SetQueueAckPolicy { .. }
+            | Syscall::TakeDeadLetters { .. }
             | Syscall::SubscribeQueue { .. }
             | Syscall::UnsubscribeQueue { .. }
             | Syscall::CloseQueue { .. }
@@ -180,6 +217,8 @@ pub fn classify(&self) -> SyscallClass {
             | Syscall::SetTimeQuantum { .. }
             | Syscall::BoostPriority { .. }
             | Syscall::LowerPriority { .. }
+            | Syscall::PauseProcess { .. }
+            | Syscall::ResumeProcess { .. }
             | Syscall::YieldProcess
             | Syscall::ScheduleNext
             | Syscall::GetCurrentScheduled => SyscallClass::Blocking,
@@ -192,13 +231,20 @@ pub fn classify(&self) -> SyscallClass {
             | Syscall::GetPendingSignals
             | Syscall::GetSignalStats
             | Syscall::WaitForSignal { .. }
-            | Syscall::GetSignalState { .. } => SyscallClass::Blocking,
+            | Syscall::GetSignalState { .. }
+            | Syscall::SignalfdCreate { .. } => SyscallClass::Blocking,
 
             // Time operations (blocking by definition)
             Syscall::Sleep { .. } => SyscallClass::Blocking,
 
+            // Waits for the entropy rate limit to refill
+            Syscall::GetRandom { .. } => SyscallClass::Blocking,
+
+            // Timerfds allocate an fd like signalfd
+            Syscall::TimerfdCreate | Syscall::TimerfdSet { .. } => SyscallClass::Blocking,
+
             // Memory management operations (potential GC)
-            Syscall::TriggerGC { .. } => SyscallClass::Blocking,
+            Syscall::TriggerGC { .. } | Syscall::CompactMemory => SyscallClass::Blocking,
 
             // Environment modification (can trigger side effects)
             Syscall::SetEnvironmentVar { .. } => SyscallClass::Blocking,
@@ -247,6 +293,19 @@ fn test_fast_syscalls() {
 
         // Process queries should be fast
         assert!(Syscall::GetProcessList.is_fast());
+
+        // Non-blocking IPC reads never wait
+        assert!(Syscall::ReadPipe {
+            pipe_id: 1,
+            size: 16,
+            nonblocking: true
+        }
+        .is_fast());
+        assert!(Syscall::ReceiveQueue {
+            queue_id: 1,
+            nonblocking: true
+        }
+        .is_fast());
     }
 
     #[test]
@@ -277,6 +336,20 @@ fn test_blocking_syscalls() {
             capacity: None
         }
         .is_blocking());
+        assert!(Syscall::ReadPipe {
+            pipe_id: 1,
+            size: 16,
+            nonblocking: false
+        }
+        .is_blocking());
+
+        assert!(Syscall::Fcntl {
+            fd: 3,
+            cmd: 7,
+            arg: 0,
+            lock: None
+        }
+        .is_blocking());
 
         // Sleep is obviously blocking
         assert!(Syscall::Sleep { duration_ms: 100 }.is_blocking());
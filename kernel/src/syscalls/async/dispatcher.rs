@@ -15,6 +15,7 @@
 use super::io::AsyncFileOps;
 use super::ipc::AsyncIpcOps;
 use crate::core::types::Pid;
+use crate::ipc::PipeWriteMode;
 use crate::syscalls::iouring::{IoUringManager, SyscallOpType, SyscallSubmissionEntry};
 use crate::syscalls::types::{Syscall, SyscallResult};
 use std::sync::Arc;
@@ -60,6 +61,11 @@ pub fn new(
         }
     }
 
+    /// Async IPC operations used for pipe and queue syscalls
+    pub fn ipc_ops(&self) -> &Arc<AsyncIpcOps> {
+        &self.ipc_ops
+    }
+
     /// Disable adaptive dispatch (always use tokio::fs)
     pub fn disable_adaptive(&mut self) {
         self.adaptive_enabled = false;
@@ -163,8 +169,14 @@ async fn execute_tokio(&self, pid: Pid, syscall: Syscall) -> SyscallResult {
             Syscall::CopyFile { source, destination } => {
                 self.file_ops.copy(pid, &source, &destination).await
             }
-            Syscall::MoveFile { source, destination } => {
-                self.file_ops.rename(pid, &source, &destination).await
+            Syscall::MoveFile {
+                source,
+                destination,
+                noreplace,
+            } => {
+                self.file_ops
+                    .rename(pid, &source, &destination, noreplace)
+                    .await
             }
             Syscall::ListDirectory { path } => self.file_ops.read_dir(pid, &path).await,
             Syscall::CreateDirectory { path } => self.file_ops.create_dir(pid, &path).await,
@@ -174,12 +186,26 @@ async fn execute_tokio(&self, pid: Pid, syscall: Syscall) -> SyscallResult {
             Syscall::WritePipe {
                 pipe_id,
                 data,
+                partial,
             } => {
-                self.ipc_ops.pipe_write(pipe_id as u64, pid, &data, None).await
+                let mode = if partial {
+                    PipeWriteMode::Partial
+                } else {
+                    PipeWriteMode::Atomic
+                };
+                self.ipc_ops
+                    .pipe_write(pipe_id as u64, pid, &data, mode, None)
+                    .await
             }
             Syscall::ReadPipe {
                 pipe_id,
                 size,
+                nonblocking: true,
+            } => self.ipc_ops.try_pipe_read(pipe_id as u64, pid, size),
+            Syscall::ReadPipe {
+                pipe_id,
+                size,
+                nonblocking: false,
             } => {
                 self.ipc_ops.pipe_read(pipe_id as u64, pid, size, None).await
             }
@@ -188,9 +214,22 @@ async fn execute_tokio(&self, pid: Pid, syscall: Syscall) -> SyscallResult {
                 data,
                 priority,
             } => {
-                self.ipc_ops.queue_send(queue_id as u64, pid, data, priority, None).await
+                // Queues that opted in shed rather than wait, so one slow
+                // consumer can't stall dispatch
+                if self.ipc_ops.sheds_load(queue_id as u64) {
+                    self.ipc_ops.try_send_or_shed(queue_id as u64, pid, data, priority)
+                } else {
+                    self.ipc_ops.queue_send(queue_id as u64, pid, data, priority, None).await
+                }
             }
-            Syscall::ReceiveQueue { queue_id } => {
+            Syscall::ReceiveQueue {
+                queue_id,
+                nonblocking: true,
+            } => self.ipc_ops.try_queue_receive(queue_id as u64, pid),
+            Syscall::ReceiveQueue {
+                queue_id,
+                nonblocking: false,
+            } => {
                 self.ipc_ops.queue_receive(queue_id as u64, pid, None).await
             }
 
@@ -381,6 +420,45 @@ async fn test_batch_threshold() {
         let _results = dispatcher.execute_batch(1, syscalls).await;
     }
 
+    #[tokio::test]
+    async fn test_queue_send_sheds_only_when_opted_in() {
+        use crate::ipc::{QueueManager, QueueType};
+        use crate::memory::MemoryManager;
+        use crate::security::SandboxManager;
+        use crate::vfs::MountManager;
+
+        let memory_manager = MemoryManager::new();
+        let queue_manager = QueueManager::new(memory_manager.clone());
+        let file_ops = Arc::new(AsyncFileOps::new(
+            Arc::new(SandboxManager::new()),
+            Arc::new(MountManager::new()),
+        ));
+        let ipc_ops = Arc::new(AsyncIpcOps::new(
+            crate::ipc::PipeManager::new(memory_manager.clone()),
+            queue_manager.clone(),
+            crate::ipc::ShmManager::new(memory_manager),
+        ));
+        let dispatcher = AdaptiveDispatcher::new(file_ops, ipc_ops, None);
+
+        let queue_id = queue_manager.create(1, QueueType::Fifo, Some(2)).unwrap();
+        let send = |byte: u8| Syscall::SendQueue {
+            queue_id,
+            data: vec![byte],
+            priority: None,
+        };
+        for byte in 0..2 {
+            assert!(dispatcher.execute(1, send(byte)).await.is_success());
+        }
+
+        // A full queue fails the send unless the owner opted into shedding
+        assert!(!dispatcher.execute(1, send(2)).await.is_success());
+        assert_eq!(queue_manager.messages_shed(), 0);
+
+        queue_manager.set_load_shedding(queue_id, 1, true).unwrap();
+        assert!(dispatcher.execute(1, send(3)).await.is_success());
+        assert_eq!(queue_manager.messages_shed(), 1);
+    }
+
     fn create_test_dispatcher() -> AdaptiveDispatcher {
         use crate::memory::MemoryManager;
         use crate::security::SandboxManager;
@@ -57,6 +57,7 @@
 use crate::monitoring::{span_syscall, Collector};
 use crate::syscalls::core::executor::SyscallExecutorWithIpc;
 use crate::syscalls::types::{Syscall, SyscallResult};
+use std::sync::atomic::{AtomicU64, Ordering};
 use std::sync::Arc;
 use std::time::Instant;
 use tracing::{error, info};
@@ -75,6 +76,18 @@ pub struct AsyncSyscallExecutor {
 
     /// Optional observability collector
     collector: Option<Arc<Collector>>,
+
+    /// Fast/slow path counters, shared between clones
+    counters: Arc<PathCounters>,
+}
+
+/// Execution counts and cumulative latency per path
+#[derive(Debug, Default)]
+struct PathCounters {
+    fast_path_count: AtomicU64,
+    slow_path_count: AtomicU64,
+    fast_path_time_ns: AtomicU64,
+    slow_path_time_ns: AtomicU64,
 }
 
 impl AsyncSyscallExecutor {
@@ -84,6 +97,7 @@ pub fn new(sync_executor: SyscallExecutorWithIpc) -> Self {
             collector: sync_executor.optional().collector.clone(),
             dispatcher: None,
             sync_executor,
+            counters: Arc::default(),
         }
     }
 
@@ -101,6 +115,7 @@ pub fn with_dispatcher(
             collector: sync_executor.optional().collector.clone(),
             dispatcher: Some(dispatcher),
             sync_executor,
+            counters: Arc::default(),
         }
     }
 
@@ -116,14 +131,19 @@ pub fn with_dispatcher(
     /// - Blocking syscalls: ~1-10μs dispatch overhead + operation time
     pub async fn execute(&self, pid: Pid, syscall: Syscall) -> SyscallResult {
         // Classify syscall at compile-time (inlined, zero cost)
+        let start = Instant::now();
         match syscall.classify() {
             SyscallClass::Fast => {
                 // Fast path: Direct synchronous execution
-                self.execute_fast_path(pid, syscall)
+                let result = self.execute_fast_path(pid, syscall);
+                self.counters.record_fast(start);
+                result
             }
             SyscallClass::Blocking => {
                 // Slow path: Async execution
-                self.execute_async_path(pid, syscall).await
+                let result = self.execute_async_path(pid, syscall).await;
+                self.counters.record_slow(start);
+                result
             }
         }
     }
@@ -176,8 +196,13 @@ async fn execute_async_path(&self, pid: Pid, syscall: Syscall) -> SyscallResult
                 "Executing syscall (adaptive async path)"
             );
 
-            // True async I/O (tokio::fs or io_uring)
-            dispatcher.execute(pid, syscall).await
+            // The dispatcher bypasses the sync executor, so admit here too and
+            // hold the freeze guard across dispatch so freezing waits for us
+            match self.sync_executor.admit_syscall(pid, &syscall) {
+                // True async I/O (tokio::fs or io_uring)
+                Ok(_in_flight) => dispatcher.execute(pid, syscall).await,
+                Err(e) => e.into(),
+            }
         } else {
             // Fallback: spawn_blocking for backward compatibility
             info!(
@@ -215,7 +240,7 @@ async fn execute_async_path(&self, pid: Pid, syscall: Syscall) -> SyscallResult
                     span.record("data_size", d.len());
                 }
             }
-            SyscallResult::Error { message } => {
+            SyscallResult::Error { message, .. } => {
                 span.record_error(message);
             }
             SyscallResult::PermissionDenied { reason } => {
@@ -305,6 +330,43 @@ pub fn sync_executor(&self) -> &SyscallExecutorWithIpc {
     pub fn has_dispatcher(&self) -> bool {
         self.dispatcher.is_some()
     }
+
+    /// Snapshot execution statistics
+    ///
+    /// IPC queue figures are only available with an adaptive dispatcher.
+    pub fn stats(&self) -> AsyncExecutorStats {
+        let mut stats = AsyncExecutorStats {
+            fast_path_count: self.counters.fast_path_count.load(Ordering::Relaxed),
+            slow_path_count: self.counters.slow_path_count.load(Ordering::Relaxed),
+            fast_path_time_ns: self.counters.fast_path_time_ns.load(Ordering::Relaxed),
+            slow_path_time_ns: self.counters.slow_path_time_ns.load(Ordering::Relaxed),
+            ..Default::default()
+        };
+
+        if let Some(ref dispatcher) = self.dispatcher {
+            let ipc_ops = dispatcher.ipc_ops();
+            let depth = ipc_ops.total_queue_depth();
+            stats.ipc_queue_depth = depth.depth;
+            stats.ipc_queue_capacity = depth.capacity;
+            stats.ipc_messages_shed = ipc_ops.messages_shed();
+        }
+
+        stats
+    }
+}
+
+impl PathCounters {
+    fn record_fast(&self, start: Instant) {
+        self.fast_path_count.fetch_add(1, Ordering::Relaxed);
+        self.fast_path_time_ns
+            .fetch_add(start.elapsed().as_nanos() as u64, Ordering::Relaxed);
+    }
+
+    fn record_slow(&self, start: Instant) {
+        self.slow_path_count.fetch_add(1, Ordering::Relaxed);
+        self.slow_path_time_ns
+            .fetch_add(start.elapsed().as_nanos() as u64, Ordering::Relaxed);
+    }
 }
 
 // ============================================================================
@@ -325,6 +387,15 @@ pub struct AsyncExecutorStats {
 
     /// Total slow-path execution time (nanoseconds)
     pub slow_path_time_ns: u64,
+
+    /// Messages waiting across IPC queues
+    pub ipc_queue_depth: usize,
+
+    /// Combined capacity of IPC queues
+    pub ipc_queue_capacity: usize,
+
+    /// Messages dropped by load shedding
+    pub ipc_messages_shed: u64,
 }
 
 impl AsyncExecutorStats {
@@ -346,6 +417,15 @@ pub fn avg_slow_path_ns(&self) -> Option<f64> {
         }
     }
 
+    /// Get IPC queue fill ratio
+    pub fn ipc_queue_utilization(&self) -> f64 {
+        if self.ipc_queue_capacity == 0 {
+            0.0
+        } else {
+            self.ipc_queue_depth as f64 / self.ipc_queue_capacity as f64
+        }
+    }
+
     /// Get fast-path ratio
     pub fn fast_path_ratio(&self) -> f64 {
         let total = self.fast_path_count + self.slow_path_count;
@@ -374,6 +454,108 @@ fn create_test_executor() -> AsyncSyscallExecutor {
         AsyncSyscallExecutor::new(sync_executor)
     }
 
+    /// Executor with an adaptive dispatcher, plus the managers it shares
+    fn create_dispatcher_executor() -> (
+        AsyncSyscallExecutor,
+        SyscallExecutorWithIpc,
+        crate::ipc::PipeManager,
+        crate::process::ProcessManagerImpl,
+    ) {
+        use crate::ipc::{PipeManager, QueueManager, ShmManager};
+        use crate::memory::MemoryManager;
+        use crate::process::ProcessManagerImpl;
+        use crate::syscalls::r#async::{AsyncFileOps, AsyncIpcOps};
+        use crate::vfs::MountManager;
+
+        let sandbox = SandboxManager::new();
+        let memory_manager = MemoryManager::new();
+        let pipe_manager = PipeManager::new(memory_manager.clone());
+        let shm_manager = ShmManager::new(memory_manager.clone());
+        let process_manager = ProcessManagerImpl::new();
+        let sync_executor = SyscallExecutorWithIpc::with_full_features(
+            sandbox.clone(),
+            pipe_manager.clone(),
+            shm_manager.clone(),
+            process_manager.clone(),
+            memory_manager.clone(),
+        );
+        let dispatcher = AdaptiveDispatcher::new(
+            Arc::new(AsyncFileOps::new(
+                Arc::new(sandbox),
+                Arc::new(MountManager::new()),
+            )),
+            Arc::new(AsyncIpcOps::new(
+                pipe_manager.clone(),
+                QueueManager::new(memory_manager),
+                shm_manager,
+            )),
+            None,
+        );
+        let executor =
+            AsyncSyscallExecutor::with_dispatcher(sync_executor.clone(), Arc::new(dispatcher));
+        (executor, sync_executor, pipe_manager, process_manager)
+    }
+
+    #[tokio::test]
+    async fn test_dispatcher_path_refuses_frozen_process() {
+        let (executor, _, pipe_manager, process_manager) = create_dispatcher_executor();
+
+        let pid = process_manager.create_process("migrating".to_string(), 5);
+        let pipe_id = pipe_manager.create(pid, pid, None).unwrap();
+        pipe_manager.write(pipe_id, pid, b"data").unwrap();
+        let read = Syscall::ReadPipe {
+            pipe_id,
+            size: 4,
+            nonblocking: false,
+        };
+        assert!(read.is_blocking());
+
+        // Frozen callers are refused before reaching the dispatcher
+        process_manager.freeze(pid).unwrap();
+        let result = executor.execute(pid, read.clone()).await;
+        assert!(matches!(
+            result,
+            SyscallResult::Error { ref message, .. } if message.contains("frozen")
+        ));
+
+        process_manager.thaw(pid).unwrap();
+        let result = executor.execute(pid, read).await;
+        assert!(matches!(result, SyscallResult::Success { .. }));
+    }
+
+    #[tokio::test]
+    async fn test_dispatcher_path_charges_credits() {
+        use crate::core::errors::ErrorCategory;
+        use crate::syscalls::CreditBudget;
+
+        let (executor, sync_executor, pipe_manager, process_manager) = create_dispatcher_executor();
+
+        let pid = process_manager.create_process("metered".to_string(), 5);
+        let pipe_id = pipe_manager.create(pid, pid, None).unwrap();
+        pipe_manager.write(pipe_id, pid, b"abcd").unwrap();
+        let read = Syscall::ReadPipe {
+            pipe_id,
+            size: 2,
+            nonblocking: false,
+        };
+        assert!(read.is_blocking());
+
+        let cost = sync_executor.syscall_credits().cost(&read) as u64;
+        sync_executor.set_syscall_budget(pid, CreditBudget::new(cost, 0));
+
+        let result = executor.execute(pid, read.clone()).await;
+        assert!(matches!(result, SyscallResult::Success { .. }));
+
+        // The budget is spent, so the next read is refused before dispatch
+        let result = executor.execute(pid, read).await;
+        assert!(matches!(
+            result,
+            SyscallResult::Error { code: Some(code), .. }
+                if code.category == ErrorCategory::QuotaExceeded
+        ));
+        assert_eq!(pipe_manager.stats(pipe_id).unwrap().buffered, 2);
+    }
+
     #[tokio::test]
     #[ignore] // Requires process manager setup
     async fn test_fast_path_execution() {
@@ -7,7 +7,7 @@
 use crate::core::types::Pid;
 use crate::permissions::{PermissionChecker, PermissionRequest};
 use crate::syscalls::types::SyscallResult;
-use crate::vfs::MountManager;
+use crate::vfs::{MountManager, VfsError};
 use std::path::PathBuf;
 use std::sync::Arc;
 use tokio::fs;
@@ -196,7 +196,13 @@ pub async fn copy(&self, pid: Pid, source: &PathBuf, dest: &PathBuf) -> SyscallR
 
     /// Move/rename file asynchronously
     #[inline]
-    pub async fn rename(&self, pid: Pid, source: &PathBuf, dest: &PathBuf) -> SyscallResult {
+    pub async fn rename(
+        &self,
+        pid: Pid,
+        source: &PathBuf,
+        dest: &PathBuf,
+        noreplace: bool,
+    ) -> SyscallResult {
         let req_src = PermissionRequest::file_delete(pid, source.clone());
         let resp_src = self.permission_checker.check_and_audit(&req_src);
 
@@ -212,11 +218,31 @@ pub async fn rename(&self, pid: Pid, source: &PathBuf, dest: &PathBuf) -> Syscal
         }
 
         // TODO: Integrate with VFS properly - for now use paths directly
-        match fs::rename(source, dest).await {
+        let result = if noreplace {
+            // tokio::fs has no renameat2; it would use spawn_blocking anyway
+            let (src, dst) = (source.clone(), dest.clone());
+            tokio::task::spawn_blocking(move || {
+                crate::vfs::local::rename_noreplace(&src, &dst)
+            })
+            .await
+            .unwrap_or_else(|e| Err(std::io::Error::other(e)))
+        } else {
+            fs::rename(source, dest).await
+        };
+
+        match result {
             Ok(_) => {
                 info!("PID {} moved file: {:?} -> {:?}", pid, source, dest);
                 SyscallResult::success()
             }
+            Err(e) if e.kind() == std::io::ErrorKind::AlreadyExists => {
+                trace!("Move target {:?} already exists", dest);
+                let err = VfsError::AlreadyExists(dest.display().to_string().into());
+                SyscallResult::error_with_code(
+                    format!("Move failed: {}", err),
+                    err.typed_code(),
+                )
+            }
             Err(e) => {
                 error!("Failed to move file {:?} -> {:?}: {}", source, dest, e);
                 SyscallResult::error(format!("Move failed: {}", e))
@@ -6,8 +6,9 @@
  */
 
 use crate::core::types::{Pid, Size};
-use crate::ipc::{PipeManager, QueueManager, ShmManager};
-use crate::syscalls::types::SyscallResult;
+use crate::ipc::types::{IpcError, IpcResult};
+use crate::ipc::{PipeError, PipeManager, PipeWriteMode, QueueDepth, QueueManager, ShmManager};
+use crate::syscalls::types::{SyscallError, SyscallResult};
 use std::time::Duration;
 use tracing::{error, info};
 
@@ -44,6 +45,7 @@ pub async fn pipe_write(
         pipe_id: u64,
         pid: Pid,
         data: &[u8],
+        mode: PipeWriteMode,
         timeout: Option<Duration>,
     ) -> SyscallResult {
         let data_vec = data.to_vec();
@@ -52,11 +54,14 @@ pub async fn pipe_write(
         let result = if let Some(duration) = timeout {
             tokio::time::timeout(
                 duration,
-                self.pipe_manager.write_async(pipe_id, pid, data_vec),
+                self.pipe_manager.write_async(pipe_id, pid, data_vec, mode),
             )
             .await
         } else {
-            Ok(self.pipe_manager.write_async(pipe_id, pid, data_vec).await)
+            Ok(self
+                .pipe_manager
+                .write_async(pipe_id, pid, data_vec, mode)
+                .await)
         };
 
         match result {
@@ -66,7 +71,7 @@ pub async fn pipe_write(
             }
             Ok(Err(e)) => {
                 error!("Async pipe write failed: {}", e);
-                SyscallResult::error(format!("Pipe write failed: {}", e))
+                SyscallResult::error_with_code(format!("Pipe write failed: {}", e), e.typed_code())
             }
             Err(_) => {
                 error!("Async pipe write timeout");
@@ -104,7 +109,7 @@ pub async fn pipe_read(
             }
             Ok(Err(e)) => {
                 error!("Async pipe read failed: {}", e);
-                SyscallResult::error(format!("Pipe read failed: {}", e))
+                SyscallResult::error_with_code(format!("Pipe read failed: {}", e), e.typed_code())
             }
             Err(_) => {
                 error!("Async pipe read timeout");
@@ -113,6 +118,28 @@ pub async fn pipe_read(
         }
     }
 
+    /// Read from pipe without waiting
+    ///
+    /// Returns `SyscallError::WouldBlock` if the pipe is empty but still open.
+    #[inline]
+    pub fn try_pipe_read(&self, pipe_id: u64, pid: Pid, size: Size) -> SyscallResult {
+        use crate::ipc::pipe::PipeError;
+
+        match self.pipe_manager.read(pipe_id as u32, pid, size) {
+            Ok(data) => {
+                info!("PID {} read {} bytes from pipe {} (non-blocking)", pid, data.len(), pipe_id);
+                SyscallResult::success_with_data(data)
+            }
+            Err(PipeError::WouldBlock(_)) => {
+                SyscallError::would_block(format!("pipe {} is empty", pipe_id)).into()
+            }
+            Err(e) => {
+                error!("Non-blocking pipe read failed: {}", e);
+                SyscallResult::error_with_code(format!("Pipe read failed: {}", e), e.typed_code())
+            }
+        }
+    }
+
     // ========================================================================
     // Async Queue Operations
     // ========================================================================
@@ -147,7 +174,7 @@ pub async fn queue_send(
             }
             Ok(Err(e)) => {
                 error!("Async queue send failed: {}", e);
-                SyscallResult::error(format!("Queue send failed: {}", e))
+                SyscallResult::error_with_code(format!("Queue send failed: {}", e), e.typed_code())
             }
             Err(_) => {
                 error!("Async queue send timeout");
@@ -156,6 +183,57 @@ pub async fn queue_send(
         }
     }
 
+    /// Send message to queue, shedding instead of failing when it is near capacity
+    ///
+    /// Drops the lowest-priority queued message to make room, so a slow
+    /// consumer can't stall the dispatcher. Shed messages are counted in
+    /// `messages_shed`.
+    #[inline]
+    pub fn try_send_or_shed(
+        &self,
+        queue_id: u64,
+        from_pid: Pid,
+        data: Vec<u8>,
+        priority: Option<u8>,
+    ) -> SyscallResult {
+        match self
+            .queue_manager
+            .try_send_or_shed(queue_id as u32, from_pid, data, priority)
+        {
+            Ok(shed) => {
+                info!(
+                    "PID {} sent message to queue {} (async, shed: {})",
+                    from_pid, queue_id, shed
+                );
+                SyscallResult::success()
+            }
+            Err(e) => {
+                error!("Async queue send failed: {}", e);
+                SyscallResult::error_with_code(format!("Queue send failed: {}", e), e.typed_code())
+            }
+        }
+    }
+
+    /// Backlog and capacity of a queue
+    pub fn queue_depth(&self, queue_id: u64) -> Option<QueueDepth> {
+        self.queue_manager.depth(queue_id as u32).ok()
+    }
+
+    /// Combined backlog and capacity of all queues
+    pub fn total_queue_depth(&self) -> QueueDepth {
+        self.queue_manager.total_depth()
+    }
+
+    /// Whether a queue opted into load shedding
+    pub fn sheds_load(&self, queue_id: u64) -> bool {
+        self.queue_manager.sheds_load(queue_id as u32)
+    }
+
+    /// Messages dropped by `try_send_or_shed`
+    pub fn messages_shed(&self) -> u64 {
+        self.queue_manager.messages_shed()
+    }
+
     /// Receive message from queue asynchronously
     ///
     /// Waits for message if queue is empty (true async blocking)
@@ -185,7 +263,7 @@ pub async fn queue_receive(
             }
             Ok(Err(e)) => {
                 error!("Async queue receive failed: {}", e);
-                SyscallResult::error(format!("Queue receive failed: {}", e))
+                SyscallResult::error_with_code(format!("Queue receive failed: {}", e), e.typed_code())
             }
             Err(_) => {
                 error!("Async queue receive timeout");
@@ -194,6 +272,29 @@ pub async fn queue_receive(
         }
     }
 
+    /// Receive message from queue without waiting
+    ///
+    /// Returns `SyscallError::WouldBlock` if the queue is empty.
+    #[inline]
+    pub fn try_queue_receive(&self, queue_id: u64, pid: Pid) -> SyscallResult {
+        let received = self
+            .queue_manager
+            .receive(queue_id as u32, pid)
+            .and_then(|msg| msg.map(|msg| self.queue_manager.read_message_data(&msg)).transpose());
+
+        match received {
+            Ok(Some(data)) => {
+                info!("PID {} received message from queue {} (non-blocking)", pid, queue_id);
+                SyscallResult::success_with_data(data)
+            }
+            Ok(None) => SyscallError::would_block(format!("queue {} is empty", queue_id)).into(),
+            Err(e) => {
+                error!("Non-blocking queue receive failed: {}", e);
+                SyscallResult::error_with_code(format!("Queue receive failed: {}", e), e.typed_code())
+            }
+        }
+    }
+
     // ========================================================================
     // Shared Memory Operations (Synchronous - No async needed)
     // ========================================================================
@@ -208,7 +309,7 @@ pub fn shm_read(&self, shm_id: u64, pid: Pid, offset: usize, size: usize) -> Sys
             }
             Err(e) => {
                 error!("Shared memory read failed: {}", e);
-                SyscallResult::error(format!("Shm read failed: {}", e))
+                SyscallResult::error_with_code(format!("Shm read failed: {}", e), e.typed_code())
             }
         }
     }
@@ -229,7 +330,7 @@ pub fn shm_write(
             }
             Err(e) => {
                 error!("Shared memory write failed: {}", e);
-                SyscallResult::error(format!("Shm write failed: {}", e))
+                SyscallResult::error_with_code(format!("Shm write failed: {}", e), e.typed_code())
             }
         }
     }
@@ -253,7 +354,12 @@ pub async fn zerocopy_transfer(
         // Future: Use io_uring splice or sendfile-style zero-copy
         let data = match self.shm_manager.read(source_shm as u32, pid, 0, size) {
             Ok(d) => d,
-            Err(e) => return SyscallResult::error(format!("Source read failed: {}", e)),
+            Err(e) => {
+                return SyscallResult::error_with_code(
+                    format!("Source read failed: {}", e),
+                    e.typed_code(),
+                )
+            }
         };
 
         match self.shm_manager.write(dest_shm as u32, pid, 0, &data) {
@@ -263,7 +369,7 @@ pub async fn zerocopy_transfer(
             }
             Err(e) => {
                 error!("Zero-copy transfer failed: {}", e);
-                SyscallResult::error(format!("Transfer failed: {}", e))
+                SyscallResult::error_with_code(format!("Transfer failed: {}", e), e.typed_code())
             }
         }
     }
@@ -275,23 +381,33 @@ pub async fn zerocopy_transfer(
 
 /// Extension trait for PipeManager to add async operations
 trait PipeManagerAsync {
-    async fn write_async(&self, pipe_id: u64, pid: Pid, data: Vec<u8>) -> Result<usize, String>;
-    async fn read_async(&self, pipe_id: u64, pid: Pid, size: Size) -> Result<Vec<u8>, String>;
+    async fn write_async(
+        &self,
+        pipe_id: u64,
+        pid: Pid,
+        data: Vec<u8>,
+        mode: PipeWriteMode,
+    ) -> Result<usize, PipeError>;
+    async fn read_async(&self, pipe_id: u64, pid: Pid, size: Size) -> Result<Vec<u8>, PipeError>;
 }
 
 impl PipeManagerAsync for PipeManager {
-    async fn write_async(&self, pipe_id: u64, pid: Pid, data: Vec<u8>) -> Result<usize, String> {
+    async fn write_async(
+        &self,
+        pipe_id: u64,
+        pid: Pid,
+        data: Vec<u8>,
+        mode: PipeWriteMode,
+    ) -> Result<usize, PipeError> {
         // Direct sync call - PipeManager uses lock-free structures
         // No spawn_blocking needed since operations are fast
-        self.write(pipe_id as u32, pid, &data)
-            .map_err(|e| format!("{}", e))
+        self.write_with_mode(pipe_id as u32, pid, &data, mode)
     }
 
-    async fn read_async(&self, pipe_id: u64, pid: Pid, size: Size) -> Result<Vec<u8>, String> {
+    async fn read_async(&self, pipe_id: u64, pid: Pid, size: Size) -> Result<Vec<u8>, PipeError> {
         // Direct sync call - PipeManager uses lock-free structures
         // For blocking reads, the internal wait is already handled
         self.read(pipe_id as u32, pid, size)
-            .map_err(|e| format!("{}", e))
     }
 }
 
@@ -303,8 +419,8 @@ async fn send_async(
         from_pid: Pid,
         data: Vec<u8>,
         priority: Option<u8>,
-    ) -> Result<(), String>;
-    async fn receive_async(&self, queue_id: u64, pid: Pid) -> Result<crate::ipc::QueueMessage, String>;
+    ) -> IpcResult<()>;
+    async fn receive_async(&self, queue_id: u64, pid: Pid) -> IpcResult<crate::ipc::QueueMessage>;
 }
 
 impl QueueManagerAsync for QueueManager {
@@ -314,18 +430,16 @@ async fn send_async(
         from_pid: Pid,
         data: Vec<u8>,
         priority: Option<u8>,
-    ) -> Result<(), String> {
+    ) -> IpcResult<()> {
         // QueueManager uses flume channels which are already async-capable
         // Direct call - no spawn_blocking needed
         self.send(queue_id as u32, from_pid, data, priority)
-            .map_err(|e| format!("{}", e))
     }
 
-    async fn receive_async(&self, queue_id: u64, pid: Pid) -> Result<crate::ipc::QueueMessage, String> {
+    async fn receive_async(&self, queue_id: u64, pid: Pid) -> IpcResult<crate::ipc::QueueMessage> {
         // Direct call - flume handles the async waiting internally
-        self.receive(queue_id as u32, pid)
-            .map_err(|e| format!("{}", e))?
-            .ok_or_else(|| "No message available".to_string())
+        self.receive(queue_id as u32, pid)?
+            .ok_or_else(|| IpcError::WouldBlock("No message available".into()))
     }
 }
 
@@ -351,7 +465,15 @@ async fn test_async_pipe_write_read() {
         let data = b"Hello, async IPC!";
 
         // Write
-        let result = ops.pipe_write(pipe_id, pid, data, Some(Duration::from_secs(1))).await;
+        let result = ops
+            .pipe_write(
+                pipe_id,
+                pid,
+                data,
+                PipeWriteMode::Atomic,
+                Some(Duration::from_secs(1)),
+            )
+            .await;
         assert!(matches!(result, SyscallResult::Success { .. }));
 
         // Read
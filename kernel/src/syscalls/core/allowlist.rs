@@ -0,0 +1,112 @@
+/*!
+ * Syscall Allow-Lists
+ * Restricts a process to a fixed set of syscalls, checked before dispatch
+ *
+ * Defense in depth for untrusted code: capabilities still apply to whatever
+ * the list lets through. Processes without a list may issue any syscall.
+ */
+
+use crate::core::types::Pid;
+use crate::core::{ShardManager, WorkloadProfile};
+use crate::syscalls::types::Syscall;
+use ahash::RandomState;
+use dashmap::DashMap;
+use std::collections::HashSet;
+use std::mem::Discriminant;
+use std::sync::Arc;
+
+/// Per-process syscall allow-lists keyed by Syscall variant
+#[derive(Clone)]
+pub struct SyscallAllowList {
+    lists: Arc<DashMap<Pid, HashSet<Discriminant<Syscall>>, RandomState>>,
+}
+
+impl SyscallAllowList {
+    pub fn new() -> Self {
+        Self {
+            // checked on every syscall, written rarely
+            lists: Arc::new(DashMap::with_capacity_and_hasher_and_shard_amount(
+                0,
+                RandomState::new(),
+                ShardManager::shards(WorkloadProfile::HighContention),
+            )),
+        }
+    }
+
+    /// Restrict `pid` to the variants of the given syscalls
+    ///
+    /// Only the variant matters; field values are ignored. An empty list
+    /// removes the restriction.
+    pub fn set<'a>(&self, pid: Pid, syscalls: impl IntoIterator<Item = &'a Syscall>) {
+        let allowed: HashSet<_> = syscalls.into_iter().map(std::mem::discriminant).collect();
+        if allowed.is_empty() {
+            self.lists.remove(&pid);
+        } else {
+            self.lists.insert(pid, allowed);
+        }
+    }
+
+    /// Remove the restriction for `pid`, returning whether one existed
+    pub fn clear(&self, pid: Pid) -> bool {
+        self.lists.remove(&pid).is_some()
+    }
+
+    /// Whether `pid` has an allow-list
+    pub fn is_restricted(&self, pid: Pid) -> bool {
+        self.lists.contains_key(&pid)
+    }
+
+    /// Whether `pid` may issue `syscall`
+    #[inline]
+    pub fn is_allowed(&self, pid: Pid, syscall: &Syscall) -> bool {
+        self.lists
+            .get(&pid)
+            .is_none_or(|allowed| allowed.contains(&std::mem::discriminant(syscall)))
+    }
+}
+
+impl Default for SyscallAllowList {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    #[test]
+    fn test_absent_or_empty_list_allows_all() {
+        let lists = SyscallAllowList::new();
+        assert!(lists.is_allowed(1, &Syscall::GetProcessList));
+
+        lists.set(1, &[]);
+        assert!(!lists.is_restricted(1));
+        assert!(lists.is_allowed(1, &Syscall::GetProcessList));
+    }
+
+    #[test]
+    fn test_matches_variant_not_fields() {
+        let lists = SyscallAllowList::new();
+        lists.set(
+            1,
+            &[Syscall::ReadFile {
+                path: PathBuf::new(),
+            }],
+        );
+
+        assert!(lists.is_allowed(
+            1,
+            &Syscall::ReadFile {
+                path: PathBuf::from("/any/path"),
+            }
+        ));
+        assert!(!lists.is_allowed(1, &Syscall::GetProcessList));
+        // Other processes are unaffected
+        assert!(lists.is_allowed(2, &Syscall::GetProcessList));
+
+        assert!(lists.clear(1));
+        assert!(lists.is_allowed(1, &Syscall::GetProcessList));
+    }
+}
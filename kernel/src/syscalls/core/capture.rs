@@ -0,0 +1,197 @@
+/*!
+ * Slow Syscall Argument Capture
+ * Bounded, redacted argument snapshots attached to SyscallSlow events
+ *
+ * Snapshots are only built once a syscall has already exceeded the slow
+ * threshold, so calls that finish in time never pay for serialization.
+ *
+ * Redaction is an allowlist: only fields that describe a call (descriptors,
+ * sizes, flags, paths, ids) are rendered. Anything else, including fields
+ * added to Syscall later, is recorded by length only, so buffers, URLs,
+ * environment values and process arguments never reach the event stream.
+ */
+
+use crate::core::limits::{SLOW_SYSCALL_ARGS_MAX_LEN, SLOW_SYSCALL_ARG_VALUE_MAX_LEN};
+use crate::syscalls::types::Syscall;
+use serde_json::Value;
+
+/// Fields rendered verbatim; every other field is recorded by length only
+const VISIBLE_FIELDS: &[&str] = &[
+    "address",
+    "algorithm",
+    "arg",
+    "backlog",
+    "capacity",
+    "case_sensitive",
+    "cmd",
+    "command",
+    "count",
+    "destination",
+    "domain",
+    "drain_timeout_ms",
+    "duration_ms",
+    "entry_id",
+    "fd",
+    "fds",
+    "flags",
+    "format",
+    "formats",
+    "global",
+    "handler_id",
+    "in_fd",
+    "include_path",
+    "interval_ms",
+    "key",
+    "len",
+    "length",
+    "level",
+    "limit",
+    "lock",
+    "mmap_id",
+    "mode",
+    "msg_id",
+    "new_size",
+    "newfd",
+    "nice",
+    "nonblocking",
+    "noreplace",
+    "offset",
+    "oldfd",
+    "optname",
+    "out_fd",
+    "partial",
+    "path",
+    "pipe_id",
+    "policy",
+    "populate",
+    "priority",
+    "prot",
+    "protocol",
+    "quantum_micros",
+    "queue_id",
+    "queue_type",
+    "read_only",
+    "reader_pid",
+    "recursive",
+    "retry",
+    "score",
+    "segment_id",
+    "shared",
+    "signal",
+    "signals",
+    "size",
+    "sizes",
+    "socket_type",
+    "sockfd",
+    "source",
+    "target_pid",
+    "threshold",
+    "timeout_ms",
+    "watch_id",
+    "whence",
+    "writer_pid",
+];
+
+/// Serde tag naming the syscall variant (already carried as the event name)
+const TAG_FIELD: &str = "syscall";
+
+/// Render a bounded, redacted `key=value` snapshot of a syscall's arguments
+///
+/// Fields off the allowlist are replaced by `<N bytes>`, long values are truncated to
+/// `SLOW_SYSCALL_ARG_VALUE_MAX_LEN`, and the whole snapshot is capped at
+/// `SLOW_SYSCALL_ARGS_MAX_LEN`.
+pub fn snapshot_args(syscall: &Syscall) -> String {
+    let fields = match serde_json::to_value(syscall) {
+        Ok(Value::Object(fields)) => fields,
+        _ => return String::new(),
+    };
+
+    let mut snapshot = String::new();
+    for (key, value) in fields.iter().filter(|(key, _)| key.as_str() != TAG_FIELD) {
+        let rendered = if !VISIBLE_FIELDS.contains(&key.as_str()) {
+            format!("<{} bytes>", redacted_len(value))
+        } else {
+            let raw = match value {
+                Value::String(s) => s.clone(),
+                other => other.to_string(),
+            };
+            truncate(raw, SLOW_SYSCALL_ARG_VALUE_MAX_LEN)
+        };
+
+        if !snapshot.is_empty() {
+            snapshot.push_str(", ");
+        }
+        snapshot.push_str(key);
+        snapshot.push('=');
+        snapshot.push_str(&rendered);
+    }
+
+    truncate(snapshot, SLOW_SYSCALL_ARGS_MAX_LEN)
+}
+
+/// Byte length of a redacted value (nested buffers and strings are summed)
+fn redacted_len(value: &Value) -> usize {
+    match value {
+        Value::Array(items) => items
+            .iter()
+            .map(|item| match item {
+                Value::Array(_) | Value::String(_) | Value::Object(_) => redacted_len(item),
+                _ => 1,
+            })
+            .sum(),
+        Value::Object(fields) => fields.values().map(redacted_len).sum(),
+        Value::String(s) => s.len(),
+        _ => 0,
+    }
+}
+
+/// Truncate `s` to at most `max` bytes on a char boundary, marking the cut
+fn truncate(mut s: String, max: usize) -> String {
+    if s.len() <= max {
+        return s;
+    }
+    let mut end = max.saturating_sub(3);
+    while !s.is_char_boundary(end) {
+        end -= 1;
+    }
+    s.truncate(end);
+    s.push_str("...");
+    s
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    #[test]
+    fn test_snapshot_redacts_buffers_and_bounds_length() {
+        let snapshot = snapshot_args(&Syscall::WriteFile {
+            path: PathBuf::from("/tmp/report.txt"),
+            data: vec![0xAA; 4096],
+        });
+        assert_eq!(snapshot, "data=<4096 bytes>, path=/tmp/report.txt");
+
+        let snapshot = snapshot_args(&Syscall::ReadFile {
+            path: PathBuf::from("x".repeat(4 * SLOW_SYSCALL_ARGS_MAX_LEN)),
+        });
+        assert!(snapshot.len() <= SLOW_SYSCALL_ARG_VALUE_MAX_LEN + "path=".len());
+        assert!(snapshot.ends_with("..."));
+    }
+
+    #[test]
+    fn test_snapshot_hides_fields_off_the_allowlist() {
+        let snapshot = snapshot_args(&Syscall::SetEnvironmentVar {
+            key: "API_TOKEN".to_string(),
+            value: "s3cret".to_string(),
+        });
+        assert_eq!(snapshot, "key=API_TOKEN, value=<6 bytes>");
+
+        let snapshot = snapshot_args(&Syscall::NetworkRequest {
+            url: "https://example.com/?token=s3cret".to_string(),
+            retry: None,
+        });
+        assert!(!snapshot.contains("s3cret"));
+        assert!(snapshot.contains("url=<33 bytes>"));
+    }
+}
@@ -0,0 +1,214 @@
+/*!
+ * Syscall Credits
+ * Per-process credit budgets charged a configurable cost per syscall
+ *
+ * Every syscall variant has a cost, 1 unless configured otherwise. A process
+ * with a budget pays that cost from a token bucket that refills continuously
+ * up to its capacity; a call it cannot pay for is rejected without being
+ * charged. Processes without a budget are unlimited.
+ */
+
+use crate::core::types::Pid;
+use crate::core::{ShardManager, WorkloadProfile};
+use crate::syscalls::types::Syscall;
+use ahash::RandomState;
+use dashmap::DashMap;
+use serde::{Deserialize, Serialize};
+use std::mem::Discriminant;
+use std::sync::Arc;
+use std::time::Instant;
+
+/// Cost of a syscall with no configured cost
+pub const DEFAULT_SYSCALL_COST: u32 = 1;
+
+/// Credit budget for a process
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CreditBudget {
+    /// Credits available after the process has been idle
+    pub capacity: u64,
+    /// Credits restored per second
+    pub refill_per_sec: u64,
+}
+
+impl CreditBudget {
+    pub fn new(capacity: u64, refill_per_sec: u64) -> Self {
+        Self {
+            capacity,
+            refill_per_sec,
+        }
+    }
+}
+
+/// A syscall the caller could not pay for
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CreditShortfall {
+    pub cost: u32,
+    /// Whole credits left in the bucket
+    pub available: u64,
+    pub budget: CreditBudget,
+    /// Whether this is the first rejection since the process last paid
+    pub throttling_started: bool,
+}
+
+/// Token bucket for one process
+#[derive(Debug)]
+struct CreditBucket {
+    budget: CreditBudget,
+    credits: f64,
+    refilled_at: Instant,
+    throttled: bool,
+}
+
+impl CreditBucket {
+    fn new(budget: CreditBudget, now: Instant) -> Self {
+        Self {
+            budget,
+            credits: budget.capacity as f64,
+            refilled_at: now,
+            throttled: false,
+        }
+    }
+
+    fn refill(&mut self, now: Instant) {
+        let elapsed = now.saturating_duration_since(self.refilled_at);
+        self.refilled_at = now;
+        self.credits = (self.credits + elapsed.as_secs_f64() * self.budget.refill_per_sec as f64)
+            .min(self.budget.capacity as f64);
+    }
+
+    /// Take `cost` credits, or leave the bucket untouched if there are too few
+    fn try_take(&mut self, cost: u32, now: Instant) -> Result<(), CreditShortfall> {
+        self.refill(now);
+
+        if self.credits >= cost as f64 {
+            self.credits -= cost as f64;
+            self.throttled = false;
+            return Ok(());
+        }
+
+        let throttling_started = !self.throttled;
+        self.throttled = true;
+        Err(CreditShortfall {
+            cost,
+            available: self.credits as u64,
+            budget: self.budget,
+            throttling_started,
+        })
+    }
+}
+
+/// Syscall cost table and per-process credit buckets
+#[derive(Clone)]
+pub struct SyscallCredits {
+    costs: Arc<DashMap<Discriminant<Syscall>, u32, RandomState>>,
+    buckets: Arc<DashMap<Pid, CreditBucket, RandomState>>,
+}
+
+impl SyscallCredits {
+    pub fn new() -> Self {
+        Self {
+            costs: Arc::new(DashMap::with_hasher(RandomState::new())),
+            // charged on every syscall, like the allow-lists
+            buckets: Arc::new(DashMap::with_capacity_and_hasher_and_shard_amount(
+                0,
+                RandomState::new(),
+                ShardManager::shards(WorkloadProfile::HighContention),
+            )),
+        }
+    }
+
+    /// Set the cost of the variant of `syscall`; field values are ignored
+    pub fn set_cost(&self, syscall: &Syscall, cost: u32) {
+        self.costs.insert(std::mem::discriminant(syscall), cost);
+    }
+
+    /// Cost charged for `syscall`
+    #[inline]
+    pub fn cost(&self, syscall: &Syscall) -> u32 {
+        self.costs
+            .get(&std::mem::discriminant(syscall))
+            .map_or(DEFAULT_SYSCALL_COST, |cost| *cost)
+    }
+
+    /// Give `pid` a budget, starting with a full bucket
+    pub fn set_budget(&self, pid: Pid, budget: CreditBudget) {
+        self.buckets
+            .insert(pid, CreditBucket::new(budget, Instant::now()));
+    }
+
+    /// Remove the budget for `pid`, returning whether one existed
+    pub fn clear(&self, pid: Pid) -> bool {
+        self.buckets.remove(&pid).is_some()
+    }
+
+    /// Budget for `pid`, if it has one
+    pub fn budget(&self, pid: Pid) -> Option<CreditBudget> {
+        self.buckets.get(&pid).map(|bucket| bucket.budget)
+    }
+
+    /// Whole credits `pid` has left, or None if it is unlimited
+    pub fn available(&self, pid: Pid) -> Option<u64> {
+        self.buckets.get_mut(&pid).map(|mut bucket| {
+            bucket.refill(Instant::now());
+            bucket.credits as u64
+        })
+    }
+
+    /// Charge `pid` for `syscall`
+    #[inline]
+    pub fn charge(&self, pid: Pid, syscall: &Syscall) -> Result<(), CreditShortfall> {
+        match self.buckets.get_mut(&pid) {
+            Some(mut bucket) => bucket.try_take(self.cost(syscall), Instant::now()),
+            None => Ok(()),
+        }
+    }
+}
+
+impl Default for SyscallCredits {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    #[test]
+    fn test_bucket_rejects_without_charging_then_refills() {
+        let start = Instant::now();
+        let mut bucket = CreditBucket::new(CreditBudget::new(3, 10), start);
+
+        assert!(bucket.try_take(2, start).is_ok());
+        let shortfall = bucket.try_take(2, start).unwrap_err();
+        assert_eq!(shortfall.available, 1);
+        assert!(shortfall.throttling_started);
+        // Rejected calls are free, and only the first one starts throttling
+        assert!(!bucket.try_take(2, start).unwrap_err().throttling_started);
+        assert!(bucket.try_take(1, start).is_ok());
+
+        // 100ms at 10/s restores one credit; refill never exceeds capacity
+        assert!(bucket
+            .try_take(1, start + Duration::from_millis(100))
+            .is_ok());
+        let later = start + Duration::from_secs(60);
+        assert!(bucket.try_take(3, later).is_ok());
+        assert!(bucket.try_take(1, later).is_err());
+    }
+
+    #[test]
+    fn test_costs_default_and_unbudgeted_processes_are_unlimited() {
+        let credits = SyscallCredits::new();
+        assert_eq!(credits.cost(&Syscall::GetProcessList), DEFAULT_SYSCALL_COST);
+
+        credits.set_cost(&Syscall::GetProcessList, 5);
+        assert_eq!(credits.cost(&Syscall::GetProcessList), 5);
+        assert_eq!(credits.cost(&Syscall::GetSystemInfo), DEFAULT_SYSCALL_COST);
+
+        for _ in 0..100 {
+            assert!(credits.charge(1, &Syscall::GetProcessList).is_ok());
+        }
+        assert_eq!(credits.available(1), None);
+    }
+}
@@ -6,14 +6,18 @@
 use crate::core::types::Pid;
 use crate::monitoring::{span_syscall, Collector, MetricsCollector};
 use crate::permissions::PermissionManager;
+use crate::process::SyscallGuard;
 use crate::security::SandboxManager;
 use std::sync::{Arc, OnceLock};
-use std::time::Instant;
-use tracing::{error, info};
+use std::time::{Duration, Instant};
+use tracing::{error, info, warn};
 
+use super::allowlist::SyscallAllowList;
+use super::credits::{CreditBudget, SyscallCredits};
 use super::handler::SyscallHandlerRegistry;
 use super::handlers::*;
-use crate::syscalls::types::{Syscall, SyscallResult};
+use super::validation;
+use crate::syscalls::types::{Syscall, SyscallError, SyscallResult};
 
 /// Global system start time for uptime tracking
 pub static SYSTEM_START: OnceLock<Instant> = OnceLock::new();
@@ -99,6 +103,10 @@ pub struct SyscallExecutorWithIpc {
     pub(super) clipboard_manager: crate::core::ClipboardManager,
     pub(super) timeout_executor: crate::syscalls::timeout::executor::TimeoutExecutor,
     pub(super) timeout_config: crate::syscalls::timeout::config::SyscallTimeoutConfig,
+    pub(super) syscall_allowlist: SyscallAllowList,
+    pub(super) syscall_credits: SyscallCredits,
+    pub(super) entropy: crate::syscalls::impls::EntropySource,
+    pub(super) slow_syscall_threshold: Option<Duration>,
 
     // Handler registry
     handler_registry: SyscallHandlerRegistry,
@@ -124,6 +132,10 @@ fn clone(&self) -> Self {
             clipboard_manager: self.clipboard_manager.clone(),
             timeout_executor: self.timeout_executor.clone(),
             timeout_config: self.timeout_config.clone(),
+            syscall_allowlist: self.syscall_allowlist.clone(),
+            syscall_credits: self.syscall_credits.clone(),
+            entropy: self.entropy.clone(),
+            slow_syscall_threshold: self.slow_syscall_threshold,
             handler_registry: self.handler_registry.clone(),
             ipc: self.ipc.clone(),
             optional: self.optional.clone(),
@@ -156,6 +168,10 @@ pub fn with_ipc_direct(
             clipboard_manager: crate::core::ClipboardManager::new(),
             timeout_executor: crate::syscalls::timeout::executor::TimeoutExecutor::disabled(),
             timeout_config: crate::syscalls::timeout::config::SyscallTimeoutConfig::new(),
+            syscall_allowlist: SyscallAllowList::new(),
+            syscall_credits: SyscallCredits::new(),
+            entropy: crate::syscalls::impls::EntropySource::new(),
+            slow_syscall_threshold: None,
             handler_registry: SyscallHandlerRegistry::new(),
             ipc: IpcManagers {
                 pipe_manager,
@@ -192,6 +208,10 @@ pub fn with_full_features(
             clipboard_manager: crate::core::ClipboardManager::new(),
             timeout_executor: crate::syscalls::timeout::executor::TimeoutExecutor::disabled(),
             timeout_config: crate::syscalls::timeout::config::SyscallTimeoutConfig::default(),
+            syscall_allowlist: SyscallAllowList::new(),
+            syscall_credits: SyscallCredits::new(),
+            entropy: crate::syscalls::impls::EntropySource::new(),
+            slow_syscall_threshold: None,
             handler_registry: SyscallHandlerRegistry::new(),
             ipc: IpcManagers {
                 pipe_manager,
@@ -248,9 +268,22 @@ pub fn with_metrics(mut self, metrics: Arc<MetricsCollector>) -> Self {
         self
     }
 
+    /// Capture redacted argument snapshots for syscalls slower than `threshold`
+    ///
+    /// Slow calls emit a `SyscallSlow` event through the collector; calls
+    /// that finish in time never build a snapshot.
+    pub fn with_slow_syscall_capture(mut self, threshold: Duration) -> Self {
+        self.slow_syscall_threshold = Some(threshold);
+        info!("Slow syscall capture enabled (threshold: {:?})", threshold);
+        self
+    }
+
     /// Add observability collector
     pub fn with_collector(mut self, collector: Arc<Collector>) -> Self {
         self.optional.collector = Some(collector.clone());
+        self.socket_manager
+            .circuit_breaker()
+            .set_collector(collector.clone());
         // Enable timeout executor with observability
         if self.timeout_config.enabled {
             use crate::monitoring::TimeoutObserver;
@@ -271,6 +304,53 @@ pub fn with_timeout_config(
         self
     }
 
+    /// Set HTTP connection pool configuration
+    pub fn with_http_pool_config(mut self, config: crate::syscalls::impls::HttpPoolConfig) -> Self {
+        self.socket_manager = self.socket_manager.with_http_pool_config(config);
+        info!("Custom HTTP pool configuration applied");
+        self
+    }
+
+    /// Send NetworkRequest through a custom HTTP transport
+    pub fn with_http_transport(
+        mut self,
+        transport: std::sync::Arc<dyn crate::syscalls::impls::HttpTransport>,
+    ) -> Self {
+        self.socket_manager = self.socket_manager.with_http_transport(transport);
+        info!("Custom HTTP transport applied");
+        self
+    }
+
+    /// Set the per-host circuit breaker for NetworkRequest
+    pub fn with_circuit_breaker(mut self, breaker: crate::core::CircuitBreaker) -> Self {
+        if let Some(ref collector) = self.optional.collector {
+            breaker.set_collector(collector.clone());
+        }
+        self.socket_manager = self.socket_manager.with_circuit_breaker(breaker);
+        info!("Custom circuit breaker applied");
+        self
+    }
+
+    /// Open socket connections through a custom transport
+    pub fn with_transport(
+        mut self,
+        transport: std::sync::Arc<dyn crate::syscalls::impls::SocketTransport>,
+    ) -> Self {
+        self.socket_manager = self.socket_manager.with_transport(transport);
+        info!("Custom socket transport applied");
+        self
+    }
+
+    /// Rate-limit GetRandom per process with a custom limit
+    pub fn with_entropy_limit(mut self, limit: crate::syscalls::impls::BandwidthLimit) -> Self {
+        self.entropy = self.entropy.with_limit(limit);
+        info!(
+            "GetRandom limited to {} bytes/s per process",
+            limit.bytes_per_sec
+        );
+        self
+    }
+
     /// Finalize executor with handler registry
     pub fn build(mut self) -> Self {
         self.handler_registry = Self::build_handler_registry(&self);
@@ -315,6 +395,174 @@ pub fn socket_manager_mut(&mut self) -> &mut crate::syscalls::impls::network::So
         &mut self.socket_manager
     }
 
+    /// Get reference to the per-process syscall allow-lists
+    pub fn syscall_allowlist(&self) -> &SyscallAllowList {
+        &self.syscall_allowlist
+    }
+
+    /// Restrict a process to the variants of the given syscalls
+    ///
+    /// An empty list lifts the restriction.
+    pub fn set_syscall_allowlist<'a>(
+        &self,
+        pid: Pid,
+        syscalls: impl IntoIterator<Item = &'a Syscall>,
+    ) {
+        self.syscall_allowlist.set(pid, syscalls);
+        info!(pid = pid, "Syscall allow-list updated");
+    }
+
+    /// Drop every piece of pid-keyed security state held for `pid`
+    ///
+    /// Covers the sandbox and its delegations, the syscall allow-list, credit
+    /// budget, entropy bucket and cached permission decisions. Must run before
+    /// the pid can be recycled, or the next holder inherits these grants.
+    /// Returns how many kinds of state were actually present.
+    pub fn release_process_state(&self, pid: Pid) -> usize {
+        use crate::security::SandboxProvider;
+
+        self.permission_manager.invalidate_cache(pid);
+        [
+            self.sandbox_manager.remove_sandbox(pid),
+            self.syscall_allowlist.clear(pid),
+            self.syscall_credits.clear(pid),
+            self.entropy.clear(pid),
+        ]
+        .into_iter()
+        .filter(|&cleared| cleared)
+        .count()
+    }
+
+    /// Whether any pid-keyed security state is held for `pid`
+    pub fn has_process_state(&self, pid: Pid) -> bool {
+        use crate::security::SandboxProvider;
+
+        self.sandbox_manager.has_sandbox(pid)
+            || self.syscall_allowlist.is_restricted(pid)
+            || self.syscall_credits.budget(pid).is_some()
+    }
+
+    /// Count a syscall as in flight for `pid`, refusing it while frozen
+    pub(crate) fn enter_freeze_gate(&self, pid: Pid) -> Result<Option<SyscallGuard>, SyscallError> {
+        match &self.optional.process_manager {
+            Some(pm) => pm
+                .enter_syscall(pid)
+                .map(Some)
+                .ok_or_else(|| SyscallError::would_block(format!("Process {} is frozen", pid))),
+            None => Ok(None),
+        }
+    }
+
+    /// Run the checks every syscall passes before dispatch
+    ///
+    /// Enforces the allow-list, rejects out-of-range arguments, refuses
+    /// frozen callers and charges the caller's credits. Hold the returned
+    /// guard until the syscall completes.
+    pub(crate) fn admit_syscall(
+        &self,
+        pid: Pid,
+        syscall: &Syscall,
+    ) -> Result<Option<SyscallGuard>, SyscallError> {
+        self.check_syscall_allowed(pid, syscall)
+            .and_then(|()| validation::validate(syscall))
+            .and_then(|()| self.enter_freeze_gate(pid))
+            .and_then(|guard| self.charge_syscall_credits(pid, syscall).map(|()| guard))
+    }
+
+    /// Check a syscall against the caller's allow-list
+    ///
+    /// Emits a security violation event when the syscall is blocked.
+    pub fn check_syscall_allowed(&self, pid: Pid, syscall: &Syscall) -> Result<(), SyscallError> {
+        if self.syscall_allowlist.is_allowed(pid, syscall) {
+            return Ok(());
+        }
+
+        let syscall_name = syscall.name();
+        warn!(
+            pid = pid,
+            syscall = syscall_name,
+            "Syscall blocked by allow-list"
+        );
+
+        if let Some(ref collector) = self.optional.collector {
+            use crate::monitoring::{Category, Event, Payload, Severity};
+            collector.emit(
+                Event::new(
+                    Severity::Warn,
+                    Category::Security,
+                    Payload::SecurityViolation {
+                        description: format!("syscall {} not in allow-list", syscall_name).into(),
+                    },
+                )
+                .with_pid(pid),
+            );
+        }
+
+        Err(SyscallError::permission_denied(format!(
+            "Syscall {} not allowed for this process",
+            syscall_name
+        )))
+    }
+
+    /// Get reference to the syscall cost table and credit budgets
+    pub fn syscall_credits(&self) -> &SyscallCredits {
+        &self.syscall_credits
+    }
+
+    /// Limit how many syscall credits a process may spend
+    ///
+    /// Processes without a budget are unlimited.
+    pub fn set_syscall_budget(&self, pid: Pid, budget: CreditBudget) {
+        self.syscall_credits.set_budget(pid, budget);
+        info!(
+            pid = pid,
+            capacity = budget.capacity,
+            refill_per_sec = budget.refill_per_sec,
+            "Syscall credit budget updated"
+        );
+    }
+
+    /// Charge a syscall against the caller's credit budget
+    ///
+    /// Emits a rate limit event when the process starts being throttled.
+    pub fn charge_syscall_credits(&self, pid: Pid, syscall: &Syscall) -> Result<(), SyscallError> {
+        let Err(shortfall) = self.syscall_credits.charge(pid, syscall) else {
+            return Ok(());
+        };
+
+        let syscall_name = syscall.name();
+        if shortfall.throttling_started {
+            warn!(
+                pid = pid,
+                syscall = syscall_name,
+                "Syscall credits exhausted"
+            );
+
+            if let Some(ref collector) = self.optional.collector {
+                use crate::monitoring::{Category, Event, Payload, Severity};
+                let capacity = shortfall.budget.capacity;
+                collector.emit(
+                    Event::new(
+                        Severity::Warn,
+                        Category::Security,
+                        Payload::RateLimitExceeded {
+                            limit: capacity.min(u32::MAX as u64) as u32,
+                            current: capacity
+                                .saturating_sub(shortfall.available)
+                                .min(u32::MAX as u64) as u32,
+                        },
+                    )
+                    .with_pid(pid),
+                );
+            }
+        }
+
+        Err(SyscallError::rate_limited(format!(
+            "{} costs {} credits, {} available",
+            syscall_name, shortfall.cost, shortfall.available
+        )))
+    }
+
     /// Get reference to file descriptor manager
     pub fn fd_manager(&self) -> &crate::syscalls::impls::fd::FdManager {
         &self.fd_manager
@@ -355,6 +603,11 @@ pub fn optional(&self) -> &OptionalManagers {
         &self.optional
     }
 
+    /// Names of the syscall handlers this executor dispatches to
+    pub fn handler_names(&self) -> Vec<&'static str> {
+        self.handler_registry.list_handlers()
+    }
+
     /// Execute a system call with sandboxing
     pub fn execute(&self, pid: Pid, syscall: Syscall) -> SyscallResult {
         // Create a rich structured span for this syscall
@@ -375,20 +628,44 @@ pub fn execute(&self, pid: Pid, syscall: Syscall) -> SyscallResult {
         // Track timing for observability
         let start = Instant::now();
 
-        // Dispatch to appropriate handler via registry
-        let result = self
-            .handler_registry
-            .dispatch(pid, &syscall)
-            .unwrap_or_else(|| {
-                error!("No handler found for syscall: {:?}", syscall);
-                SyscallResult::error(format!("Unhandled syscall: {}", syscall_name))
-            });
+        // The freeze guard is held across dispatch so freezing waits for us
+        let result = match self.admit_syscall(pid, &syscall) {
+            Ok(_in_flight) => self
+                .handler_registry
+                .dispatch(pid, &syscall)
+                .unwrap_or_else(|| {
+                    error!("No handler found for syscall: {:?}", syscall);
+                    SyscallResult::error(format!("Unhandled syscall: {}", syscall_name))
+                }),
+            Err(e) => {
+                warn!(pid = pid, syscall = syscall_name, "Rejected syscall: {}", e);
+                e.into()
+            }
+        };
+
+        let elapsed = start.elapsed();
 
         // Emit observability event
         if let Some(ref collector) = self.optional.collector {
-            let duration_us = start.elapsed().as_micros() as u64;
+            let duration_us = elapsed.as_micros() as u64;
             let success = matches!(result, SyscallResult::Success { .. });
             collector.syscall_exit(pid, syscall_name.to_string(), duration_us, success);
+
+            if let Some(threshold) = self.slow_syscall_threshold {
+                if elapsed >= threshold {
+                    collector.syscall_slow(
+                        pid,
+                        syscall_name.to_string(),
+                        elapsed.as_millis() as u64,
+                        threshold.as_millis() as u64,
+                        Some(super::capture::snapshot_args(&syscall)),
+                    );
+                }
+            }
+        }
+
+        if let Some(ref metrics) = self.optional.metrics {
+            metrics.record_syscall(syscall_name, elapsed);
         }
 
         // Record result in span
@@ -399,7 +676,7 @@ pub fn execute(&self, pid: Pid, syscall: Syscall) -> SyscallResult {
                     span.record("data_size", d.len());
                 }
             }
-            SyscallResult::Error { message } => {
+            SyscallResult::Error { message, .. } => {
                 span.record_error(message);
             }
             SyscallResult::PermissionDenied { reason } => {
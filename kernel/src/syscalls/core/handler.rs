@@ -56,6 +56,11 @@ pub fn dispatch(&self, pid: Pid, syscall: &Syscall) -> Option<SyscallResult> {
     pub fn handler_count(&self) -> usize {
         self.handlers.len()
     }
+
+    /// Names of the registered handlers, in dispatch order
+    pub fn list_handlers(&self) -> Vec<&'static str> {
+        self.handlers.iter().map(|handler| handler.name()).collect()
+    }
 }
 
 impl Default for SyscallHandlerRegistry {
@@ -89,6 +94,7 @@ fn test_registry_dispatch() {
         let registry = SyscallHandlerRegistry::new().register(Arc::new(TestHandler));
 
         assert_eq!(registry.handler_count(), 1);
+        assert_eq!(registry.list_handlers(), vec!["test_handler"]);
 
         let result = registry.dispatch(1, &Syscall::GetSystemInfo);
         assert!(result.is_some());
@@ -114,6 +114,7 @@ async fn test_async_handler_dispatch() {
                 1,
                 &Syscall::NetworkRequest {
                     url: "http://example.com".into(),
+                    retry: None,
                 },
             )
             .await;
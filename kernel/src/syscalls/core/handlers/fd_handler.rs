@@ -35,9 +35,22 @@ fn handle(&self, pid: Pid, syscall: &Syscall) -> Option<SyscallResult> {
             Syscall::Lseek { fd, offset, whence } => {
                 Some(self.executor.lseek(pid, *fd, *offset, *whence))
             }
-            Syscall::Fcntl { fd, cmd, arg } => {
-                Some(self.executor.fcntl(pid, *fd, *cmd, *arg).into())
+            Syscall::Read { fd, count } => Some(self.executor.read_fd(pid, *fd, *count)),
+            Syscall::Write { fd, ref data } => Some(self.executor.write_fd(pid, *fd, data)),
+            Syscall::Fsync { fd } => Some(self.executor.fsync_fd(pid, *fd)),
+            Syscall::Fcntl { fd, cmd, arg, lock } => {
+                Some(self.executor.fcntl(pid, *fd, *cmd, *arg, *lock))
             }
+            Syscall::Sendfile {
+                out_fd,
+                in_fd,
+                offset,
+                count,
+            } => Some(
+                self.executor
+                    .sendfile(pid, *out_fd, *in_fd, *offset, *count),
+            ),
+            Syscall::Poll { fds, timeout_ms } => Some(self.executor.poll(pid, fds, *timeout_ms)),
             _ => None, // Not an fd syscall
         }
     }
@@ -28,6 +28,17 @@ fn handle(&self, pid: Pid, syscall: &Syscall) -> Option<SyscallResult> {
             Syscall::WriteFile { ref path, ref data } => {
                 Some(self.executor.write_file(pid, path, data))
             }
+            Syscall::AppendFile { ref path, ref data } => {
+                Some(self.executor.append_file(pid, path, data))
+            }
+            Syscall::ReadFileVectored {
+                ref path,
+                ref sizes,
+            } => Some(self.executor.read_file_vectored(pid, path, sizes)),
+            Syscall::WriteFileVectored {
+                ref path,
+                ref segments,
+            } => Some(self.executor.write_file_vectored(pid, path, segments)),
             Syscall::CreateFile { ref path } => Some(self.executor.create_file(pid, path).into()),
             Syscall::DeleteFile { ref path } => Some(self.executor.delete_file(pid, path).into()),
             Syscall::ListDirectory { ref path } => {
@@ -38,11 +49,19 @@ fn handle(&self, pid: Pid, syscall: &Syscall) -> Option<SyscallResult> {
             Syscall::MoveFile {
                 ref source,
                 ref destination,
-            } => Some(self.executor.move_file(pid, source, destination).into()),
+                noreplace,
+            } => Some(
+                self.executor
+                    .move_file(pid, source, destination, *noreplace),
+            ),
             Syscall::CopyFile {
                 ref source,
                 ref destination,
             } => Some(self.executor.copy_file(pid, source, destination).into()),
+            Syscall::HashFile {
+                ref path,
+                ref algorithm,
+            } => Some(self.executor.hash_file(pid, path, algorithm)),
             Syscall::CreateDirectory { ref path } => {
                 Some(self.executor.create_directory(pid, path))
             }
@@ -50,6 +69,7 @@ fn handle(&self, pid: Pid, syscall: &Syscall) -> Option<SyscallResult> {
                 Some(self.executor.remove_directory(pid, path))
             }
             Syscall::GetWorkingDirectory => Some(self.executor.get_working_directory(pid).into()),
+            Syscall::ListMounts => Some(self.executor.list_mounts(pid)),
             Syscall::SetWorkingDirectory { ref path } => {
                 Some(self.executor.set_working_directory(pid, path))
             }
@@ -33,17 +33,22 @@ fn handle(&self, pid: Pid, syscall: &Syscall) -> Option<SyscallResult> {
                 self.executor
                     .create_pipe(pid, *reader_pid, *writer_pid, *capacity),
             ),
-            Syscall::WritePipe { pipe_id, ref data } => {
-                Some(self.executor.write_pipe(pid, *pipe_id, data))
-            }
-            Syscall::ReadPipe { pipe_id, size } => {
-                Some(self.executor.read_pipe(pid, *pipe_id, *size))
-            }
+            Syscall::WritePipe {
+                pipe_id,
+                ref data,
+                partial,
+            } => Some(self.executor.write_pipe(pid, *pipe_id, data, *partial)),
+            Syscall::ReadPipe {
+                pipe_id,
+                size,
+                nonblocking,
+            } => Some(self.executor.read_pipe(pid, *pipe_id, *size, *nonblocking)),
             Syscall::ClosePipe { pipe_id } => Some(self.executor.close_pipe(pid, *pipe_id).into()),
             Syscall::DestroyPipe { pipe_id } => {
                 Some(self.executor.destroy_pipe(pid, *pipe_id).into())
             }
             Syscall::PipeStats { pipe_id } => Some(self.executor.pipe_stats(pid, *pipe_id).into()),
+            Syscall::PipeFd { pipe_id } => Some(self.executor.open_pipe_fd(pid, *pipe_id)),
 
             // Shared memory operations
             Syscall::CreateShm { size } => Some(self.executor.create_shm(pid, *size).into()),
@@ -76,6 +81,10 @@ fn handle(&self, pid: Pid, syscall: &Syscall) -> Option<SyscallResult> {
                     .read_shm(pid, *segment_id, *offset, *size)
                     .into(),
             ),
+            Syscall::ResizeShm {
+                segment_id,
+                new_size,
+            } => Some(self.executor.resize_shm(pid, *segment_id, *new_size).into()),
             Syscall::DestroyShm { segment_id } => {
                 Some(self.executor.destroy_shm(pid, *segment_id).into())
             }
@@ -101,8 +110,25 @@ fn handle(&self, pid: Pid, syscall: &Syscall) -> Option<SyscallResult> {
                     .send_queue(pid, *queue_id, data, *priority)
                     .into(),
             ),
-            Syscall::ReceiveQueue { queue_id } => {
-                Some(self.executor.receive_queue(pid, *queue_id).into())
+            Syscall::ReceiveQueue {
+                queue_id,
+                nonblocking,
+            } => Some(self.executor.receive_queue(pid, *queue_id, *nonblocking)),
+            Syscall::AckQueue { queue_id, msg_id } => {
+                Some(self.executor.ack_queue(pid, *queue_id, *msg_id))
+            }
+            Syscall::SetQueueAckPolicy {
+                queue_id,
+                visibility_timeout_ms,
+                max_attempts,
+            } => Some(self.executor.set_queue_ack_policy(
+                pid,
+                *queue_id,
+                *visibility_timeout_ms,
+                *max_attempts,
+            )),
+            Syscall::TakeDeadLetters { queue_id } => {
+                Some(self.executor.take_dead_letters(pid, *queue_id))
             }
             Syscall::SubscribeQueue { queue_id } => {
                 Some(self.executor.subscribe_queue(pid, *queue_id))
@@ -113,9 +139,10 @@ fn handle(&self, pid: Pid, syscall: &Syscall) -> Option<SyscallResult> {
             Syscall::CloseQueue { queue_id } => {
                 Some(self.executor.close_queue(pid, *queue_id).into())
             }
-            Syscall::DestroyQueue { queue_id } => {
-                Some(self.executor.destroy_queue(pid, *queue_id).into())
-            }
+            Syscall::DestroyQueue {
+                queue_id,
+                drain_timeout_ms,
+            } => Some(self.executor.destroy_queue(pid, *queue_id, *drain_timeout_ms)),
             Syscall::QueueStats { queue_id } => {
                 Some(self.executor.queue_stats(pid, *queue_id).into())
             }
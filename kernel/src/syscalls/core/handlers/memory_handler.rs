@@ -28,9 +28,13 @@ fn handle(&self, pid: Pid, syscall: &Syscall) -> Option<SyscallResult> {
             Syscall::GetProcessMemoryStats { target_pid } => {
                 Some(self.executor.get_process_memory_stats(pid, *target_pid))
             }
+            Syscall::GetMemoryMaps { target_pid } => {
+                Some(self.executor.get_memory_maps(pid, *target_pid))
+            }
             Syscall::TriggerGC { target_pid } => {
                 Some(self.executor.trigger_gc(pid, *target_pid).into())
             }
+            Syscall::CompactMemory => Some(self.executor.compact_memory(pid)),
             _ => None, // Not a memory syscall
         }
     }
@@ -30,9 +30,10 @@ fn handle(&self, pid: Pid, syscall: &Syscall) -> Option<SyscallResult> {
                 length,
                 prot,
                 shared,
+                populate,
             } => Some(
                 self.executor
-                    .mmap(pid, path, *offset, *length, *prot, *shared),
+                    .mmap(pid, path, *offset, *length, *prot, *shared, *populate),
             ),
             Syscall::MmapRead {
                 mmap_id,
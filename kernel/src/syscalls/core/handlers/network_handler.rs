@@ -24,8 +24,8 @@ impl SyscallHandler for NetworkHandler {
     #[inline]
     fn handle(&self, pid: Pid, syscall: &Syscall) -> Option<SyscallResult> {
         match syscall {
-            Syscall::NetworkRequest { ref url } => {
-                Some(self.executor.network_request(pid, url).into())
+            Syscall::NetworkRequest { ref url, retry } => {
+                Some(self.executor.network_request(pid, url, *retry))
             }
             Syscall::Socket {
                 domain,
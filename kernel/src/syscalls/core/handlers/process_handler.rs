@@ -35,6 +35,9 @@ fn handle(&self, pid: Pid, syscall: &Syscall) -> Option<SyscallResult> {
                 Some(self.executor.get_process_info(pid, *target_pid))
             }
             Syscall::GetProcessList => Some(self.executor.get_process_list(pid).into()),
+            Syscall::GetProcessChildren { target_pid } => {
+                Some(self.executor.get_process_children(pid, *target_pid))
+            }
             Syscall::SetProcessPriority {
                 target_pid,
                 priority,
@@ -42,6 +45,15 @@ fn handle(&self, pid: Pid, syscall: &Syscall) -> Option<SyscallResult> {
                 self.executor
                     .set_process_priority(pid, *target_pid, *priority),
             ),
+            Syscall::GetProcessNice { target_pid } => {
+                Some(self.executor.get_process_nice(pid, *target_pid))
+            }
+            Syscall::SetProcessNice { target_pid, nice } => {
+                Some(self.executor.set_process_nice(pid, *target_pid, *nice))
+            }
+            Syscall::SetOomScore { target_pid, score } => {
+                Some(self.executor.set_oom_score(pid, *target_pid, *score))
+            }
             Syscall::GetProcessState { target_pid } => {
                 Some(self.executor.get_process_state(pid, *target_pid))
             }
@@ -48,6 +48,12 @@ fn handle(&self, pid: Pid, syscall: &Syscall) -> Option<SyscallResult> {
             Syscall::LowerPriority { target_pid } => {
                 Some(self.executor.lower_priority(pid, *target_pid))
             }
+            Syscall::PauseProcess { target_pid } => {
+                Some(self.executor.pause_process(pid, *target_pid))
+            }
+            Syscall::ResumeProcess { target_pid } => {
+                Some(self.executor.resume_process(pid, *target_pid))
+            }
             _ => None, // Not a scheduler syscall
         }
     }
@@ -50,6 +50,9 @@ fn handle(&self, pid: Pid, syscall: &Syscall) -> Option<SyscallResult> {
             Syscall::GetSignalState { target_pid } => {
                 Some(self.executor.get_signal_state(pid, *target_pid))
             }
+            Syscall::SignalfdCreate { signals } => {
+                Some(self.executor.signalfd_create(pid, signals))
+            }
             _ => None, // Not a signal syscall
         }
     }
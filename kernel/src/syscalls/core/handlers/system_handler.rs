@@ -26,6 +26,7 @@ fn handle(&self, pid: Pid, syscall: &Syscall) -> Option<SyscallResult> {
         match syscall {
             Syscall::GetSystemInfo => Some(self.executor.get_system_info(pid).into()),
             Syscall::GetCurrentTime => Some(self.executor.get_current_time(pid).into()),
+            Syscall::GetRandom { len, flags } => Some(self.executor.get_random(pid, *len, *flags)),
             Syscall::GetEnvironmentVar { ref key } => {
                 Some(self.executor.get_env_var(pid, key).into())
             }
@@ -26,6 +26,15 @@ fn handle(&self, pid: Pid, syscall: &Syscall) -> Option<SyscallResult> {
         match syscall {
             Syscall::Sleep { duration_ms } => Some(self.executor.sleep(pid, *duration_ms).into()),
             Syscall::GetUptime => Some(self.executor.get_uptime(pid).into()),
+            Syscall::TimerfdCreate => Some(self.executor.timerfd_create(pid)),
+            Syscall::TimerfdSet {
+                fd,
+                duration_ms,
+                interval_ms,
+            } => Some(
+                self.executor
+                    .timerfd_set(pid, *fd, *duration_ms, *interval_ms),
+            ),
             _ => None, // Not a time syscall
         }
     }
@@ -2,15 +2,26 @@
  * Core Syscall Execution Infrastructure
  *
  * Provides the foundational components for syscall execution:
+ * - AllowList: Per-process syscall allow-lists checked before dispatch
+ * - Capture: Redacted argument snapshots for slow syscalls
+ * - Credits: Per-syscall costs charged against per-process credit budgets
  * - Executor: Main syscall dispatcher with type-state pattern
  * - Handler: Trait and registry for syscall handlers
  * - Handlers: Category-specific handler implementations
+ * - Validation: Argument range checks run before dispatch
  */
 
+pub mod allowlist;
+pub mod capture;
+pub mod credits;
 pub mod executor;
 pub mod handler;
 pub mod handlers;
+pub mod validation;
 
 // Re-export commonly used types
+pub use allowlist::SyscallAllowList;
+pub use capture::snapshot_args;
+pub use credits::{CreditBudget, CreditShortfall, SyscallCredits, DEFAULT_SYSCALL_COST};
 pub use executor::{IpcManagers, OptionalManagers, SyscallExecutorWithIpc, SYSTEM_START};
 pub use handler::{SyscallHandler, SyscallHandlerRegistry};
@@ -0,0 +1,238 @@
+/*!
+ * Syscall Argument Validation
+ * Rejects out-of-range enum-like arguments before dispatch
+ *
+ * Handlers still own semantic checks (does the fd exist, is the caller
+ * allowed); this pass only catches values no handler could accept, so
+ * callers get the offending field back instead of a generic failure.
+ */
+
+use crate::signals::Signal;
+use crate::syscalls::types::{FileLock, Syscall, SyscallError};
+
+// lseek whence
+const SEEK_SET: u32 = 0;
+const SEEK_END: u32 = 2;
+
+// fcntl commands (F_DUPFD..=F_GETOWN, plus F_DUPFD_CLOEXEC)
+const F_DUPFD: u32 = 0;
+const F_GETOWN: u32 = 9;
+const F_DUPFD_CLOEXEC: u32 = 1030;
+
+// socket domains
+const AF_UNIX: u32 = 1;
+const AF_INET: u32 = 2;
+const AF_INET6: u32 = 10;
+
+// socket types, and the flags that may be OR'd into them
+const SOCK_STREAM: u32 = 1;
+const SOCK_DGRAM: u32 = 2;
+const SOCK_RAW: u32 = 3;
+const SOCK_SEQPACKET: u32 = 5;
+const SOCK_NONBLOCK: u32 = 0o4000;
+const SOCK_CLOEXEC: u32 = 0o2000000;
+
+/// Validate enum-like syscall arguments
+pub fn validate(syscall: &Syscall) -> Result<(), SyscallError> {
+    match syscall {
+        Syscall::Lseek { whence, .. } => {
+            check("whence", *whence, (SEEK_SET..=SEEK_END).contains(whence))
+        }
+        Syscall::Fcntl { cmd, lock, .. } => {
+            check(
+                "cmd",
+                *cmd,
+                (F_DUPFD..=F_GETOWN).contains(cmd) || *cmd == F_DUPFD_CLOEXEC,
+            )?;
+            match lock {
+                Some(lock) => check(
+                    "lock_type",
+                    lock.lock_type,
+                    lock.lock_type <= FileLock::F_UNLCK,
+                ),
+                None => Ok(()),
+            }
+        }
+        Syscall::Socket {
+            domain,
+            socket_type,
+            ..
+        } => {
+            check(
+                "domain",
+                *domain,
+                matches!(*domain, AF_UNIX | AF_INET | AF_INET6),
+            )?;
+            let base_type = socket_type & !(SOCK_NONBLOCK | SOCK_CLOEXEC);
+            check(
+                "socket_type",
+                *socket_type,
+                matches!(
+                    base_type,
+                    SOCK_STREAM | SOCK_DGRAM | SOCK_RAW | SOCK_SEQPACKET
+                ),
+            )
+        }
+        Syscall::SendSignal { signal, .. }
+        | Syscall::RegisterSignalHandler { signal, .. }
+        | Syscall::BlockSignal { signal }
+        | Syscall::UnblockSignal { signal } => {
+            check("signal", *signal, Signal::from_number(*signal).is_ok())
+        }
+        _ => Ok(()),
+    }
+}
+
+#[inline]
+fn check(field: &str, value: u32, valid: bool) -> Result<(), SyscallError> {
+    if valid {
+        Ok(())
+    } else {
+        Err(SyscallError::argument_out_of_range(field, value))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rejected_field(syscall: Syscall) -> String {
+        match validate(&syscall) {
+            Err(SyscallError::ArgumentOutOfRange { field, .. }) => field.to_string(),
+            other => panic!("expected ArgumentOutOfRange, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_whence() {
+        for whence in 0..=2 {
+            assert!(validate(&Syscall::Lseek {
+                fd: 3,
+                offset: 0,
+                whence
+            })
+            .is_ok());
+        }
+        assert_eq!(
+            rejected_field(Syscall::Lseek {
+                fd: 3,
+                offset: 0,
+                whence: 3
+            }),
+            "whence"
+        );
+    }
+
+    #[test]
+    fn test_fcntl_cmd() {
+        assert!(validate(&Syscall::Fcntl {
+            fd: 3,
+            cmd: 1,
+            arg: 0,
+            lock: None
+        })
+        .is_ok());
+        assert!(validate(&Syscall::Fcntl {
+            fd: 3,
+            cmd: F_DUPFD_CLOEXEC,
+            arg: 0,
+            lock: None
+        })
+        .is_ok());
+        assert_eq!(
+            rejected_field(Syscall::Fcntl {
+                fd: 3,
+                cmd: 999,
+                arg: 0,
+                lock: None
+            }),
+            "cmd"
+        );
+        assert_eq!(
+            rejected_field(Syscall::Fcntl {
+                fd: 3,
+                cmd: 6,
+                arg: 0,
+                lock: Some(FileLock {
+                    lock_type: 3,
+                    ..FileLock::default()
+                })
+            }),
+            "lock_type"
+        );
+    }
+
+    #[test]
+    fn test_socket_domain_and_type() {
+        assert!(validate(&Syscall::Socket {
+            domain: AF_INET,
+            socket_type: SOCK_STREAM | SOCK_NONBLOCK,
+            protocol: 0
+        })
+        .is_ok());
+        assert_eq!(
+            rejected_field(Syscall::Socket {
+                domain: 42,
+                socket_type: SOCK_STREAM,
+                protocol: 0
+            }),
+            "domain"
+        );
+        assert_eq!(
+            rejected_field(Syscall::Socket {
+                domain: AF_INET6,
+                socket_type: 7,
+                protocol: 0
+            }),
+            "socket_type"
+        );
+    }
+
+    #[test]
+    fn test_signal_numbers() {
+        assert!(validate(&Syscall::SendSignal {
+            target_pid: 1,
+            signal: 15
+        })
+        .is_ok());
+        assert!(validate(&Syscall::BlockSignal { signal: 40 }).is_ok());
+
+        for syscall in [
+            Syscall::SendSignal {
+                target_pid: 1,
+                signal: 0,
+            },
+            Syscall::RegisterSignalHandler {
+                signal: 16,
+                handler_id: 1,
+            },
+            Syscall::BlockSignal { signal: 64 },
+            Syscall::UnblockSignal { signal: 1000 },
+        ] {
+            assert_eq!(rejected_field(syscall), "signal");
+        }
+    }
+
+    #[test]
+    fn test_error_carries_value() {
+        let err = validate(&Syscall::Lseek {
+            fd: 3,
+            offset: 0,
+            whence: 7,
+        })
+        .unwrap_err();
+        assert_eq!(
+            err,
+            SyscallError::ArgumentOutOfRange {
+                field: "whence".into(),
+                value: 7
+            }
+        );
+        assert_eq!(err.to_string(), "Invalid argument: whence = 7");
+    }
+
+    #[test]
+    fn test_unrelated_syscalls_pass() {
+        assert!(validate(&Syscall::GetProcessList).is_ok());
+    }
+}
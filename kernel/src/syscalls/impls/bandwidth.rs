@@ -0,0 +1,142 @@
+/*!
+ * Network Bandwidth Throttling
+ * Per-process token buckets for outbound socket traffic
+ *
+ * Each limited process owns one bucket shared by all its sockets. Sends are
+ * charged up front and may drive the bucket into debt; the sender then
+ * sleeps until the debt is repaid, so the long-run rate converges on the
+ * limit while bursts up to the bucket size go out immediately.
+ */
+
+use serde::{Deserialize, Serialize};
+use std::time::{Duration, Instant};
+
+/// Outbound bandwidth limit for a process
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BandwidthLimit {
+    /// Sustained rate in bytes per second
+    pub bytes_per_sec: u64,
+    /// Bytes that may be sent at once after the process has been idle
+    pub burst: u64,
+}
+
+impl BandwidthLimit {
+    /// Limit with a one-second burst
+    pub fn new(bytes_per_sec: u64) -> Self {
+        Self {
+            bytes_per_sec: bytes_per_sec.max(1),
+            burst: bytes_per_sec.max(1),
+        }
+    }
+
+    pub fn with_burst(mut self, burst: u64) -> Self {
+        self.burst = burst.max(1);
+        self
+    }
+}
+
+/// Outcome of charging a send against a bucket
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Reservation {
+    /// How long the sender must wait before sending
+    pub delay: Duration,
+    /// Whether this send moved the process from unthrottled to throttled
+    pub throttling_started: bool,
+}
+
+/// Token bucket for one process
+#[derive(Debug)]
+pub(super) struct TokenBucket {
+    limit: BandwidthLimit,
+    /// Available bytes; negative while senders are waiting
+    tokens: f64,
+    refilled_at: Instant,
+    throttled: bool,
+}
+
+impl TokenBucket {
+    pub(super) fn new(limit: BandwidthLimit, now: Instant) -> Self {
+        Self {
+            limit,
+            tokens: limit.burst as f64,
+            refilled_at: now,
+            throttled: false,
+        }
+    }
+
+    pub(super) fn limit(&self) -> BandwidthLimit {
+        self.limit
+    }
+
+    /// Lower the sustained rate (and burst) to at most `bytes_per_sec`
+    pub(super) fn cap(&mut self, bytes_per_sec: u64, now: Instant) {
+        self.refill(now);
+        self.limit.bytes_per_sec = self.limit.bytes_per_sec.min(bytes_per_sec.max(1));
+        self.limit.burst = self.limit.burst.min(self.limit.bytes_per_sec);
+        self.tokens = self.tokens.min(self.limit.burst as f64);
+    }
+
+    fn refill(&mut self, now: Instant) {
+        let elapsed = now.saturating_duration_since(self.refilled_at);
+        self.refilled_at = now;
+        self.tokens = (self.tokens + elapsed.as_secs_f64() * self.limit.bytes_per_sec as f64)
+            .min(self.limit.burst as f64);
+    }
+
+    /// Charge `bytes` and return how long the sender must wait
+    pub(super) fn reserve(&mut self, bytes: usize, now: Instant) -> Reservation {
+        self.refill(now);
+        self.tokens -= bytes as f64;
+
+        if self.tokens >= 0.0 {
+            self.throttled = false;
+            return Reservation {
+                delay: Duration::ZERO,
+                throttling_started: false,
+            };
+        }
+
+        let delay = Duration::from_secs_f64(-self.tokens / self.limit.bytes_per_sec as f64);
+        let throttling_started = !self.throttled;
+        self.throttled = true;
+        Reservation {
+            delay,
+            throttling_started,
+        }
+    }
+
+    /// Return bytes that were charged but not sent
+    pub(super) fn refund(&mut self, bytes: usize) {
+        self.tokens = (self.tokens + bytes as f64).min(self.limit.burst as f64);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bucket_allows_burst_then_delays() {
+        let start = Instant::now();
+        let mut bucket = TokenBucket::new(BandwidthLimit::new(1000).with_burst(500), start);
+
+        let first = bucket.reserve(500, start);
+        assert_eq!(first.delay, Duration::ZERO);
+        assert!(!first.throttling_started);
+
+        let second = bucket.reserve(250, start);
+        assert_eq!(second.delay, Duration::from_millis(250));
+        assert!(second.throttling_started);
+
+        // Still paying off the debt: waits stack, but the event fires once
+        let third = bucket.reserve(250, start);
+        assert_eq!(third.delay, Duration::from_millis(500));
+        assert!(!third.throttling_started);
+
+        // Refill never exceeds the burst size
+        let later = start + Duration::from_secs(10);
+        assert_eq!(bucket.reserve(500, later).delay, Duration::ZERO);
+        bucket.refund(500);
+        assert!(bucket.reserve(501, later).delay > Duration::ZERO);
+    }
+}
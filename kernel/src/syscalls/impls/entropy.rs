@@ -0,0 +1,154 @@
+/*!
+ * Entropy Source
+ * Cryptographically secure random bytes for sandboxed processes
+ *
+ * Bytes come from the OS RNG (getrandom(2) on Linux), so processes don't
+ * need VFS access to /dev/urandom. Each process draws from its own token
+ * bucket: a request over budget waits for the bucket to refill, or fails
+ * with WouldBlock when it carries `GRND_NONBLOCK`.
+ */
+
+use super::bandwidth::{BandwidthLimit, TokenBucket};
+use crate::core::limits::{ENTROPY_BURST_BYTES, ENTROPY_BYTES_PER_SEC, GETRANDOM_MAX_LEN};
+use crate::core::types::Pid;
+use crate::syscalls::core::executor::SyscallExecutorWithIpc;
+use crate::syscalls::types::{SyscallError, SyscallResult};
+use ahash::RandomState;
+use dashmap::DashMap;
+use log::{debug, error};
+use rand::rngs::OsRng;
+use rand::RngCore;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+/// Fail with WouldBlock instead of waiting (mirrors getrandom(2))
+pub const GRND_NONBLOCK: u32 = 0x0001;
+
+/// Accepted for compatibility; all bytes come from the same OS RNG
+pub const GRND_RANDOM: u32 = 0x0002;
+
+/// Per-process rate limiting for the entropy path
+#[derive(Clone)]
+pub struct EntropySource {
+    /// Limit given to processes on their first request
+    limit: BandwidthLimit,
+    buckets: Arc<DashMap<Pid, TokenBucket, RandomState>>,
+}
+
+impl EntropySource {
+    pub fn new() -> Self {
+        Self {
+            limit: BandwidthLimit::new(ENTROPY_BYTES_PER_SEC).with_burst(ENTROPY_BURST_BYTES),
+            buckets: Arc::new(DashMap::with_hasher(RandomState::new())),
+        }
+    }
+
+    /// Use a different per-process rate limit
+    pub fn with_limit(mut self, limit: BandwidthLimit) -> Self {
+        self.limit = limit;
+        self
+    }
+
+    /// Per-process rate limit
+    pub fn limit(&self) -> BandwidthLimit {
+        self.limit
+    }
+
+    /// Charge `len` bytes to `pid`, returning how long to wait before filling
+    ///
+    /// Nonblocking requests over budget are refunded and return `None`.
+    pub fn reserve(&self, pid: Pid, len: usize, nonblocking: bool) -> Option<Duration> {
+        let now = Instant::now();
+        let mut bucket = self
+            .buckets
+            .entry(pid)
+            .or_insert_with(|| TokenBucket::new(self.limit, now));
+
+        let reservation = bucket.reserve(len, now);
+        if reservation.delay > Duration::ZERO && nonblocking {
+            bucket.refund(len);
+            return None;
+        }
+        Some(reservation.delay)
+    }
+
+    /// Forget `pid`'s bucket, returning whether it had one
+    pub fn clear(&self, pid: Pid) -> bool {
+        self.buckets.remove(&pid).is_some()
+    }
+
+    /// Fill `buf` from the OS RNG
+    pub fn fill(&self, buf: &mut [u8]) -> Result<(), rand::Error> {
+        OsRng.try_fill_bytes(buf)
+    }
+}
+
+impl Default for EntropySource {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SyscallExecutorWithIpc {
+    /// Return `len` cryptographically secure random bytes
+    ///
+    /// Like getrandom(2) this needs no capability; the per-process rate
+    /// limit is what protects the entropy path.
+    pub(in crate::syscalls) fn get_random(
+        &self,
+        pid: Pid,
+        len: usize,
+        flags: u32,
+    ) -> SyscallResult {
+        if flags & !(GRND_NONBLOCK | GRND_RANDOM) != 0 {
+            return SyscallError::argument_out_of_range("flags", flags).into();
+        }
+        if len > GETRANDOM_MAX_LEN {
+            return SyscallError::argument_out_of_range("len", len as i64).into();
+        }
+
+        let nonblocking = flags & GRND_NONBLOCK != 0;
+        let Some(delay) = self.entropy.reserve(pid, len, nonblocking) else {
+            return SyscallError::would_block(format!("entropy budget exhausted for PID {}", pid))
+                .into();
+        };
+        if !delay.is_zero() {
+            std::thread::sleep(delay);
+        }
+
+        let mut bytes = vec![0u8; len];
+        if let Err(e) = self.entropy.fill(&mut bytes) {
+            error!("OS RNG failed for PID {}: {}", pid, e);
+            return SyscallResult::error(format!("Random source unavailable: {}", e));
+        }
+
+        if let Some(ref metrics) = self.optional.metrics {
+            metrics.inc_counter("entropy.bytes_dispensed", len as f64);
+        }
+        debug!("PID {} drew {} random bytes", pid, len);
+        SyscallResult::success_with_data(bytes)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_nonblocking_reserve_refunds() {
+        let source = EntropySource::new().with_limit(BandwidthLimit::new(1000).with_burst(100));
+
+        assert_eq!(source.reserve(1, 100, true), Some(Duration::ZERO));
+        assert_eq!(source.reserve(1, 50, true), None);
+        // The refused request cost nothing: a blocking one waits only for its own bytes
+        assert_eq!(
+            source.reserve(1, 50, false),
+            Some(Duration::from_millis(50))
+        );
+
+        // Buckets are per process
+        assert_eq!(source.reserve(2, 100, true), Some(Duration::ZERO));
+        assert!(source.clear(1));
+        assert!(!source.clear(1));
+    }
+}
@@ -5,11 +5,12 @@
 */
 
 use crate::core::guard::FdGuard;
+use crate::core::limits::{DEFAULT_CHUNK_SIZE, VFS_LOCK_WAIT_TIMEOUT, WRITE_COMBINE_THRESHOLD};
 use crate::core::serialization::json;
 use crate::core::types::Pid;
 use crate::monitoring::span_operation;
 use crate::permissions::{PermissionChecker, PermissionRequest};
-use crate::vfs::{FileSystem, OpenFlags, OpenMode};
+use crate::vfs::{FileSystem, OpenFlags, OpenMode, VfsError};
 
 use ahash::RandomState;
 use crossbeam_queue::SegQueue;
@@ -25,7 +26,26 @@
 use super::handle::FileHandle;
 use crate::syscalls::core::executor::SyscallExecutorWithIpc;
 use crate::syscalls::timeout::executor::TimeoutError;
-use crate::syscalls::types::SyscallResult;
+use crate::syscalls::types::{FileLock, SyscallResult};
+
+// fcntl record-locking commands
+const F_GETLK: u32 = 5;
+const F_SETLK: u32 = 6;
+const F_SETLKW: u32 = 7;
+
+/// Open flag buffering small writes until the buffer fills, Fsync or Close
+///
+/// Kernel-specific; chosen above every POSIX open flag.
+pub const O_WRITE_COMBINE: u32 = 0x1000_0000;
+
+/// Apply the buffering requested in open `flags` to a new handle
+fn open_handle(handle: FileHandle, flags: u32) -> Arc<FileHandle> {
+    if flags & O_WRITE_COMBINE != 0 {
+        Arc::new(handle.with_write_combining(WRITE_COMBINE_THRESHOLD))
+    } else {
+        Arc::new(handle)
+    }
+}
 
 /// File descriptor manager
 ///
@@ -76,6 +96,13 @@ pub fn get_fd_count(&self, pid: Pid) -> u32 {
             .unwrap_or(0)
     }
 
+    /// Look up the handle behind an FD
+    pub(in crate::syscalls) fn get_handle(&self, fd: u32) -> Option<Arc<FileHandle>> {
+        self.open_files
+            .get(&fd)
+            .map(|handle_ref| Arc::clone(handle_ref.value()))
+    }
+
     /// Check if process has any open FDs
     pub fn has_process_fds(&self, pid: Pid) -> bool {
         self.get_fd_count(pid) > 0
@@ -247,7 +274,8 @@ pub(in crate::syscalls) fn open(
 
                 match vfs.open(path, vfs_flags, vfs_mode) {
                     Ok(vfs_file) => {
-                        let handle = Arc::new(FileHandle::from_vfs(vfs_file));
+                        let handle =
+                            open_handle(FileHandle::from_vfs(vfs_file).with_path(path), flags);
                         let path_str = path.to_string_lossy().to_string();
                         let fd_guard =
                             self.fd_manager()
@@ -276,6 +304,15 @@ pub(in crate::syscalls) fn open(
                             }
                         };
                     }
+                    Err(VfsError::ReadOnly) => {
+                        // Read-only mounts must not be bypassed via the std::fs fallback
+                        warn!(
+                            "PID {} denied write open on read-only mount: {:?}",
+                            pid, path
+                        );
+                        span.record_error("Read-only filesystem");
+                        return SyscallResult::error("Read-only filesystem");
+                    }
                     Err(e) => {
                         warn!(
                             "VFS open failed for {:?}: {}, falling back to std::fs",
@@ -321,7 +358,7 @@ pub(in crate::syscalls) fn open(
 
             match options.open(&std_path) {
                 Ok(file) => {
-                    let handle = Arc::new(FileHandle::from_std(file));
+                    let handle = open_handle(FileHandle::from_std(file).with_path(path), flags);
                     let path_str = path.to_string_lossy().to_string();
                     let fd_guard = self
                         .fd_manager()
@@ -368,9 +405,16 @@ pub(in crate::syscalls) fn close_fd(&self, pid: Pid, fd: u32) -> SyscallResult {
         // No capability check - closing is always allowed
 
         // Remove file from fd_manager
-        if self.fd_manager().open_files.remove(&fd).is_some() {
+        if let Some((_, handle)) = self.fd_manager().open_files.remove(&fd) {
             // Untrack FD from process
             self.fd_manager().untrack_fd(pid, fd);
+
+            // The FD is gone either way; a failed flush is still reported
+            if let Err(e) = handle.flush() {
+                error!("PID {} lost buffered writes closing FD {}: {}", pid, fd, e);
+                span.record_error("Flush failed");
+                return SyscallResult::error(format!("Close failed: {}", e));
+            }
             info!("PID {} closed FD {}", pid, fd);
             span.record_result(true);
             SyscallResult::success()
@@ -555,18 +599,88 @@ pub(in crate::syscalls) fn lseek(
         }
     }
 
+    /// Read up to `count` bytes from an fd
+    ///
+    /// Reads are capped at DEFAULT_CHUNK_SIZE; like POSIX read, fewer bytes
+    /// than requested may be returned.
+    pub(in crate::syscalls) fn read_fd(&self, pid: Pid, fd: u32, count: usize) -> SyscallResult {
+        // Note: read operates on already-open fds with validated permissions
+
+        // Clone the handle so the map shard isn't locked during the read
+        let handle = match self.fd_manager().open_files.get(&fd) {
+            Some(handle_ref) => Arc::clone(handle_ref.value()),
+            None => return SyscallResult::error("Invalid file descriptor"),
+        };
+
+        let mut buf = vec![0u8; count.min(DEFAULT_CHUNK_SIZE)];
+        match handle.read(&mut buf) {
+            Ok(n) => {
+                buf.truncate(n);
+                trace!("PID {} read {} bytes from FD {}", pid, n, fd);
+                SyscallResult::success_with_data(buf)
+            }
+            Err(e) if e.kind() == std::io::ErrorKind::WouldBlock => {
+                SyscallResult::error("Resource temporarily unavailable")
+            }
+            Err(e) => {
+                error!("Read failed for FD {}: {}", fd, e);
+                SyscallResult::error(format!("Read failed: {}", e))
+            }
+        }
+    }
+
+    /// Write `data` to an fd
+    ///
+    /// On a write-combining fd the data may only reach the file on a later
+    /// Fsync or Close.
+    pub(in crate::syscalls) fn write_fd(&self, pid: Pid, fd: u32, data: &[u8]) -> SyscallResult {
+        // Note: write operates on already-open fds with validated permissions
+
+        // Clone the handle so the map shard isn't locked during the write
+        let handle = match self.fd_manager().open_files.get(&fd) {
+            Some(handle_ref) => Arc::clone(handle_ref.value()),
+            None => return SyscallResult::error("Invalid file descriptor"),
+        };
+
+        match handle.write(data) {
+            Ok(n) => {
+                trace!("PID {} wrote {} bytes to FD {}", pid, n, fd);
+                match json::to_vec(&serde_json::json!({ "bytes_written": n })) {
+                    Ok(data) => SyscallResult::success_with_data(data),
+                    Err(e) => {
+                        warn!("Failed to serialize write result: {}", e);
+                        SyscallResult::error("Internal serialization error")
+                    }
+                }
+            }
+            Err(e) if e.kind() == std::io::ErrorKind::WouldBlock => {
+                SyscallResult::error("Resource temporarily unavailable")
+            }
+            Err(e) => {
+                error!("Write failed for FD {}: {}", fd, e);
+                SyscallResult::error(format!("Write failed: {}", e))
+            }
+        }
+    }
+
     pub(in crate::syscalls) fn fcntl(
         &self,
         pid: Pid,
         fd: u32,
         cmd: u32,
         arg: u32,
+        lock: Option<FileLock>,
     ) -> SyscallResult {
         // Note: fcntl operates on already-open fds with validated permissions
 
         // Verify FD exists
-        if !self.fd_manager().open_files.contains_key(&fd) {
-            return SyscallResult::error("Invalid file descriptor");
+        let handle = match self.fd_manager().open_files.get(&fd) {
+            Some(handle_ref) => Arc::clone(handle_ref.value()),
+            None => return SyscallResult::error("Invalid file descriptor"),
+        };
+
+        if matches!(cmd, F_GETLK | F_SETLK | F_SETLKW) {
+            return self.fcntl_lock(pid, fd, &handle, cmd, lock);
         }
 
         // Basic fcntl commands (F_GETFD, F_SETFD, etc.)
@@ -587,40 +701,204 @@ pub(in crate::syscalls) fn fcntl(
         }
     }
 
-    pub(in crate::syscalls) fn fsync_fd(&self, pid: Pid, fd: u32) -> SyscallResult {
-        // Fsync synchronizes file data and metadata to disk
-        // Can block for extended periods on slow storage (NFS, USB, etc.)
+    /// Advisory range locking (F_GETLK, F_SETLK, F_SETLKW) via the VFS lock table
+    fn fcntl_lock(
+        &self,
+        pid: Pid,
+        fd: u32,
+        handle: &FileHandle,
+        cmd: u32,
+        lock: Option<FileLock>,
+    ) -> SyscallResult {
+        let Some(lock) = lock else {
+            return SyscallResult::error("Lock description required");
+        };
+        let Some(path) = handle.path() else {
+            return SyscallResult::error("File descriptor does not support locking");
+        };
+        let Some(locks) = self
+            .optional()
+            .vfs
+            .as_ref()
+            .and_then(|vfs| vfs.range_locks())
+        else {
+            return SyscallResult::error("Range locks not supported");
+        };
 
-        if let Some(handle_arc) = self.fd_manager().open_files.get(&fd) {
-            // Clone Arc for use in closure
-            let handle = Arc::clone(&handle_arc);
-
-            // Use timeout executor - fsync can block on slow storage
-            let result = self.timeout_executor().execute_with_deadline(
-                || handle.sync(),
-                self.timeout_config().file_sync,
-                "fsync",
-            );
+        let range = lock.range();
+        let exclusive = lock.lock_type == FileLock::F_WRLCK;
+
+        if cmd == F_GETLK {
+            let holder = locks.conflict(path, range, exclusive, pid);
+            let data = match holder {
+                Some(holder) => serde_json::json!({
+                    "result": 0,
+                    "lock": {
+                        "lock_type": if holder.exclusive { FileLock::F_WRLCK } else { FileLock::F_RDLCK },
+                        "start": holder.range.start,
+                        "len": if holder.range.end == u64::MAX { 0 } else { holder.range.end - holder.range.start },
+                        "pid": holder.pid,
+                    }
+                }),
+                None => serde_json::json!({
+                    "result": 0,
+                    "lock": { "lock_type": FileLock::F_UNLCK }
+                }),
+            };
+            return match json::to_vec(&data) {
+                Ok(data) => SyscallResult::success_with_data(data),
+                Err(e) => {
+                    warn!("Failed to serialize fcntl result: {}", e);
+                    SyscallResult::error("Internal serialization error")
+                }
+            };
+        }
 
-            match result {
-                Ok(()) => {
-                    info!("PID {} synchronized FD {} to disk", pid, fd);
-                    SyscallResult::success()
+        let result = match (lock.lock_type, cmd) {
+            (FileLock::F_UNLCK, _) => locks.unlock(path, range, pid),
+            (_, F_SETLKW) => locks.lock_wait(path, range, exclusive, pid, VFS_LOCK_WAIT_TIMEOUT),
+            _ => locks.lock(path, range, exclusive, pid),
+        };
+
+        match result {
+            Ok(()) => {
+                info!(
+                    "PID {} set lock type {} on FD {} ({}..+{})",
+                    pid, lock.lock_type, fd, lock.start, lock.len
+                );
+                SyscallResult::success()
+            }
+            Err(VfsError::WouldBlock(_)) => {
+                SyscallResult::error("Resource temporarily unavailable: range is locked")
+            }
+            Err(e) => SyscallResult::error(format!("Lock failed: {}", e)),
+        }
+    }
+
+    /// Copy up to `count` bytes from `in_fd` to `out_fd` inside the kernel
+    ///
+    /// `out_fd` may be a file or a connected TCP socket. In-memory sources
+    /// are written straight from their backing buffer; others are streamed
+    /// through a pooled chunk buffer. Like sendfile(2), fewer bytes than
+    /// requested may be transferred.
+    pub(in crate::syscalls) fn sendfile(
+        &self,
+        pid: Pid,
+        out_fd: u32,
+        in_fd: u32,
+        offset: Option<u64>,
+        count: usize,
+    ) -> SyscallResult {
+        let span = span_operation("fd_sendfile");
+        let _guard = span.enter();
+        span.record("pid", &format!("{}", pid));
+        span.record("in_fd", &format!("{}", in_fd));
+        span.record("out_fd", &format!("{}", out_fd));
+
+        // Note: both fds were permission-checked when opened
+
+        if in_fd == out_fd {
+            span.record_error("Source and destination are the same FD");
+            return SyscallResult::error("Source and destination must differ");
+        }
+
+        // Clone handles so the map shards aren't locked during the copy
+        let input = match self.fd_manager().open_files.get(&in_fd) {
+            Some(handle_ref) => Arc::clone(handle_ref.value()),
+            None => {
+                span.record_error("Invalid input file descriptor");
+                return SyscallResult::error("Invalid input file descriptor");
+            }
+        };
+        let output = self
+            .fd_manager()
+            .open_files
+            .get(&out_fd)
+            .map(|handle_ref| Arc::clone(handle_ref.value()));
+
+        let result = match output {
+            Some(output) if Arc::ptr_eq(&input, &output) => {
+                // dup'd descriptors share a handle; locking it twice would deadlock
+                span.record_error("Source and destination share a file");
+                return SyscallResult::error("Source and destination must differ");
+            }
+            Some(output) => input.transfer_to(&output, offset, count),
+            None => match self.socket_manager().clone_stream(out_fd) {
+                Some(stream) => {
+                    stream.and_then(|mut stream| input.transfer_into(&mut stream, offset, count))
                 }
-                Err(TimeoutError::Timeout { elapsed_ms, .. }) => {
-                    error!(
-                        "Fsync timed out for PID {}, FD {} after {}ms (slow storage?)",
-                        pid, fd, elapsed_ms
-                    );
-                    SyscallResult::error(format!("Fsync timed out after {}ms", elapsed_ms))
+                None => {
+                    span.record_error("Invalid output file descriptor");
+                    return SyscallResult::error("Invalid output file descriptor");
                 }
-                Err(TimeoutError::Operation(e)) => {
-                    error!("Fsync failed for FD {}: {}", fd, e);
-                    SyscallResult::error(format!("Fsync failed: {}", e))
+            },
+        };
+
+        match result {
+            Ok(bytes_sent) => {
+                trace!(
+                    "PID {} sent {} bytes from FD {} to FD {}",
+                    pid,
+                    bytes_sent,
+                    in_fd,
+                    out_fd
+                );
+                span.record("bytes_sent", &format!("{}", bytes_sent));
+                span.record_result(true);
+                match json::to_vec(&serde_json::json!({ "bytes_sent": bytes_sent })) {
+                    Ok(data) => SyscallResult::success_with_data(data),
+                    Err(e) => {
+                        warn!("Failed to serialize sendfile result: {}", e);
+                        span.record_error("Serialization failed");
+                        SyscallResult::error("Internal serialization error")
+                    }
                 }
             }
-        } else {
-            SyscallResult::error("Invalid file descriptor")
+            Err(e) if e.kind() == std::io::ErrorKind::WouldBlock => {
+                span.record_error("Would block");
+                SyscallResult::error("Resource temporarily unavailable")
+            }
+            Err(e) => {
+                error!("Sendfile from FD {} to FD {} failed: {}", in_fd, out_fd, e);
+                span.record_error(&format!("Sendfile failed: {}", e));
+                SyscallResult::error(format!("Sendfile failed: {}", e))
+            }
+        }
+    }
+
+    pub(in crate::syscalls) fn fsync_fd(&self, pid: Pid, fd: u32) -> SyscallResult {
+        // Fsync synchronizes file data and metadata to disk
+        // Can block for extended periods on slow storage (NFS, USB, etc.)
+
+        // Clone the handle so the map shard isn't locked during the sync
+        let handle = match self.fd_manager().open_files.get(&fd) {
+            Some(handle_ref) => Arc::clone(handle_ref.value()),
+            None => return SyscallResult::error("Invalid file descriptor"),
+        };
+
+        // Use timeout executor - fsync can block on slow storage
+        let result = self.timeout_executor().execute_with_deadline(
+            || handle.sync(),
+            self.timeout_config().file_sync,
+            "fsync",
+        );
+
+        match result {
+            Ok(()) => {
+                info!("PID {} synchronized FD {} to disk", pid, fd);
+                SyscallResult::success()
+            }
+            Err(TimeoutError::Timeout { elapsed_ms, .. }) => {
+                error!(
+                    "Fsync timed out for PID {}, FD {} after {}ms (slow storage?)",
+                    pid, fd, elapsed_ms
+                );
+                SyscallResult::error(format!("Fsync timed out after {}ms", elapsed_ms))
+            }
+            Err(TimeoutError::Operation(e)) => {
+                error!("Fsync failed for FD {}: {}", fd, e);
+                SyscallResult::error(format!("Fsync failed: {}", e))
+            }
         }
     }
 
@@ -629,36 +907,35 @@ pub(in crate::syscalls) fn fdatasync_fd(&self, pid: Pid, fd: u32) -> SyscallResu
         // Fdatasync synchronizes file data (not metadata) to disk
         // Can block for extended periods on slow storage
 
-        if let Some(handle_arc) = self.fd_manager().open_files.get(&fd) {
-            // Clone Arc for use in closure
-            let handle = Arc::clone(&handle_arc);
-
-            // Use timeout executor - fdatasync can block on slow storage
-            let result = self.timeout_executor().execute_with_deadline(
-                || handle.sync_data(),
-                self.timeout_config().file_sync,
-                "fdatasync",
-            );
+        // Clone the handle so the map shard isn't locked during the sync
+        let handle = match self.fd_manager().open_files.get(&fd) {
+            Some(handle_ref) => Arc::clone(handle_ref.value()),
+            None => return SyscallResult::error("Invalid file descriptor"),
+        };
 
-            match result {
-                Ok(()) => {
-                    info!("PID {} synchronized FD {} data to disk", pid, fd);
-                    SyscallResult::success()
-                }
-                Err(TimeoutError::Timeout { elapsed_ms, .. }) => {
-                    error!(
-                        "Fdatasync timed out for PID {}, FD {} after {}ms (slow storage?)",
-                        pid, fd, elapsed_ms
-                    );
-                    SyscallResult::error(format!("Fdatasync timed out after {}ms", elapsed_ms))
-                }
-                Err(TimeoutError::Operation(e)) => {
-                    error!("Fdatasync failed for FD {}: {}", fd, e);
-                    SyscallResult::error(format!("Fdatasync failed: {}", e))
-                }
+        // Use timeout executor - fdatasync can block on slow storage
+        let result = self.timeout_executor().execute_with_deadline(
+            || handle.sync_data(),
+            self.timeout_config().file_sync,
+            "fdatasync",
+        );
+
+        match result {
+            Ok(()) => {
+                info!("PID {} synchronized FD {} data to disk", pid, fd);
+                SyscallResult::success()
+            }
+            Err(TimeoutError::Timeout { elapsed_ms, .. }) => {
+                error!(
+                    "Fdatasync timed out for PID {}, FD {} after {}ms (slow storage?)",
+                    pid, fd, elapsed_ms
+                );
+                SyscallResult::error(format!("Fdatasync timed out after {}ms", elapsed_ms))
+            }
+            Err(TimeoutError::Operation(e)) => {
+                error!("Fdatasync failed for FD {}: {}", fd, e);
+                SyscallResult::error(format!("Fdatasync failed: {}", e))
             }
-        } else {
-            SyscallResult::error("Invalid file descriptor")
         }
     }
 }
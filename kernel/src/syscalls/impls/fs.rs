@@ -11,13 +11,14 @@
 use crate::permissions::{Action, PermissionChecker, PermissionRequest, Resource};
 use crate::syscalls::timeout::executor::TimeoutError;
 
-use log::{error, info, trace};
+use log::{error, info, trace, warn};
 use std::fs;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 #[cfg(unix)]
 use std::os::unix::fs::PermissionsExt;
 
+use super::vfs_adapter::vfs_error_result;
 use crate::syscalls::core::executor::SyscallExecutorWithIpc;
 use crate::syscalls::types::SyscallResult;
 
@@ -35,6 +36,33 @@ pub(in crate::syscalls) fn write_file(
         self.vfs_write(pid, path, data)
     }
 
+    pub(in crate::syscalls) fn append_file(
+        &self,
+        pid: Pid,
+        path: &Path,
+        data: &[u8],
+    ) -> SyscallResult {
+        self.vfs_append(pid, path, data)
+    }
+
+    pub(in crate::syscalls) fn read_file_vectored(
+        &self,
+        pid: Pid,
+        path: &Path,
+        sizes: &[usize],
+    ) -> SyscallResult {
+        self.vfs_read_vectored(pid, path, sizes)
+    }
+
+    pub(in crate::syscalls) fn write_file_vectored(
+        &self,
+        pid: Pid,
+        path: &Path,
+        segments: &[Vec<u8>],
+    ) -> SyscallResult {
+        self.vfs_write_vectored(pid, path, segments)
+    }
+
     pub(in crate::syscalls) fn create_file(&self, pid: Pid, path: &PathBuf) -> SyscallResult {
         self.vfs_write(pid, path, &[])
     }
@@ -126,6 +154,7 @@ pub(in crate::syscalls) fn move_file(
         pid: Pid,
         source: &PathBuf,
         destination: &PathBuf,
+        noreplace: bool,
     ) -> SyscallResult {
         // Check permission for source (read/delete)
         let req_src = PermissionRequest::file_delete(pid, source.clone());
@@ -142,6 +171,18 @@ pub(in crate::syscalls) fn move_file(
             return SyscallResult::permission_denied(resp_dst.reason());
         }
 
+        if let Err(result) = self
+            .vfs_check_writable(source)
+            .and_then(|_| self.vfs_check_writable(destination))
+        {
+            return result;
+        }
+
+        // The backend claims the destination atomically, so there is nothing to roll back
+        if noreplace {
+            return self.vfs_rename_noreplace(pid, source, destination);
+        }
+
         // Create transaction guard for atomic move operation
         // If the move fails, we ensure proper cleanup
         // NOTE: TransactionGuard is appropriate here - manages multi-step operation with rollback
@@ -226,6 +267,50 @@ pub(in crate::syscalls) fn copy_file(
             return SyscallResult::permission_denied(resp_dst.reason());
         }
 
+        if let Err(result) = self.vfs_check_writable(destination) {
+            return result;
+        }
+
+        // Mounted paths belong to the VFS; falling back to std::fs for them
+        // would copy whatever host path happens to share the name
+        if let Some(vfs) = &self.optional().vfs {
+            if vfs.mount_point(source).is_some() || vfs.mount_point(destination).is_some() {
+                let vfs_clone = vfs.clone();
+                let src_clone = source.clone();
+                let dst_clone = destination.clone();
+
+                let result = self.timeout_executor().execute_with_deadline(
+                    || vfs_clone.copy_reflink(&src_clone, &dst_clone),
+                    self.timeout_config().file_io,
+                    "vfs_copy",
+                );
+
+                return match result {
+                    Ok(reflinked) => {
+                        info!(
+                            "PID {} copied file via VFS: {:?} -> {:?} (reflink: {})",
+                            pid, source, destination, reflinked
+                        );
+                        Self::copy_result(reflinked)
+                    }
+                    Err(TimeoutError::Timeout { elapsed_ms, .. }) => {
+                        error!(
+                            "VFS copy timed out for {:?} -> {:?} after {}ms",
+                            source, destination, elapsed_ms
+                        );
+                        SyscallResult::error(format!("Timeout after {}ms", elapsed_ms))
+                    }
+                    Err(TimeoutError::Operation(e)) => {
+                        error!(
+                            "VFS copy failed for {:?} -> {:?}: {}",
+                            source, destination, e
+                        );
+                        vfs_error_result(e)
+                    }
+                };
+            }
+        }
+
         // Create transaction guard for atomic copy with rollback
         // If copy fails partially, clean up the destination
         // NOTE: TransactionGuard is appropriate here - manages cleanup on failure
@@ -258,19 +343,19 @@ pub(in crate::syscalls) fn copy_file(
         let src_clone = source.clone();
         let dst_clone = destination.clone();
         let result = self.timeout_executor().execute_with_deadline(
-            || fs::copy(&src_clone, &dst_clone),
+            || crate::vfs::local::reflink_or_copy(&src_clone, &dst_clone),
             self.timeout_config().file_io,
             "file_copy",
         );
 
         match result {
-            Ok(bytes) => {
+            Ok(reflinked) => {
                 info!(
-                    "PID {} copied file: {:?} -> {:?} ({} bytes)",
-                    pid, source, destination, bytes
+                    "PID {} copied file: {:?} -> {:?} (reflink: {})",
+                    pid, source, destination, reflinked
                 );
                 transaction.commit().ok();
-                SyscallResult::success()
+                Self::copy_result(reflinked)
             }
             Err(TimeoutError::Timeout { elapsed_ms, .. }) => {
                 error!(
@@ -289,6 +374,17 @@ pub(in crate::syscalls) fn copy_file(
         }
     }
 
+    /// Copy outcome, reporting whether storage was shared rather than duplicated
+    fn copy_result(reflinked: bool) -> SyscallResult {
+        match json::to_vec(&serde_json::json!({ "reflinked": reflinked })) {
+            Ok(data) => SyscallResult::success_with_data(data),
+            Err(e) => {
+                error!("Failed to serialize copy result: {}", e);
+                SyscallResult::error("Failed to serialize copy result")
+            }
+        }
+    }
+
     pub(in crate::syscalls) fn create_directory(&self, pid: Pid, path: &PathBuf) -> SyscallResult {
         self.vfs_create_dir(pid, path)
     }
@@ -297,6 +393,50 @@ pub(in crate::syscalls) fn remove_directory(&self, pid: Pid, path: &PathBuf) ->
         self.vfs_remove_dir(pid, path)
     }
 
+    pub(in crate::syscalls) fn list_mounts(&self, pid: Pid) -> SyscallResult {
+        let vfs = match &self.optional().vfs {
+            Some(vfs) => vfs,
+            None => return SyscallResult::error("VFS not configured"),
+        };
+
+        // A quota's first read scans its mount; report a slow one like any file I/O
+        let vfs_clone = vfs.clone();
+        let table = match self.timeout_executor().execute_with_deadline(
+            || Ok::<_, std::convert::Infallible>(vfs_clone.mount_table()),
+            self.timeout_config().file_io,
+            "list_mounts",
+        ) {
+            Ok(table) => table,
+            Err(TimeoutError::Timeout { elapsed_ms, .. }) => {
+                error!("Listing mounts timed out after {}ms", elapsed_ms);
+                return SyscallResult::error(format!("Timeout after {}ms", elapsed_ms));
+            }
+            Err(TimeoutError::Operation(never)) => match never {},
+        };
+
+        let mounts: Vec<_> = table
+            .into_iter()
+            .map(|mount| {
+                serde_json::json!({
+                    "path": mount.path,
+                    "fs_type": mount.fs_type,
+                    "flags": mount.flags.options(),
+                    "quota": mount.quota,
+                    "used": mount.used,
+                })
+            })
+            .collect();
+        trace!("PID {} listed {} mounts", pid, mounts.len());
+
+        match json::to_vec(&mounts) {
+            Ok(data) => SyscallResult::success_with_data(data),
+            Err(e) => {
+                error!("Failed to serialize mount table: {}", e);
+                SyscallResult::error("Failed to serialize mount table")
+            }
+        }
+    }
+
     pub(in crate::syscalls) fn get_working_directory(&self, pid: Pid) -> SyscallResult {
         use crate::core::PooledBuffer;
 
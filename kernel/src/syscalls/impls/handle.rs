@@ -1,12 +1,25 @@
 /*!
  * File Handle Abstraction
  * Unified handle for VFS and standard filesystem operations
+ *
+ * A handle may combine writes: small writes collect in a buffer that reaches
+ * the file once it fills, and before any read, seek, sync, truncate or close,
+ * so every other operation sees the data in order.
  */
 
-use crate::vfs::{OpenFile, VfsError, VfsResult};
-use parking_lot::RwLock;
+use super::timerfd::TimerFd;
+use crate::core::limits::DEFAULT_CHUNK_SIZE;
+use crate::core::sync::readiness::ReadinessKey;
+use crate::core::types::Pid;
+use crate::core::PooledBuffer;
+use crate::ipc::{PipeError, PipeId, PipeManager};
+use crate::signals::SignalFd;
+use crate::syscalls::iouring::CompletionNotifier;
+use crate::vfs::{OpenFile, Readiness, VfsError, VfsResult};
+use parking_lot::{Mutex, RwLock};
 use std::fs::File;
 use std::io::{Read, Seek, SeekFrom, Write};
+use std::path::{Path, PathBuf};
 
 /// File handle wrapping VFS OpenFile trait
 ///
@@ -15,6 +28,21 @@
 /// - Trait object for zero-cost abstraction across filesystem backends
 pub struct FileHandle {
     inner: RwLock<Box<dyn OpenFile>>,
+    /// Path the handle was opened with (keys advisory range locks)
+    path: Option<PathBuf>,
+    /// Timer behind a timerfd, so it can be re-armed through the FD
+    timer: Option<TimerFd>,
+    /// Source behind a pipe or event fd, so poll can wait for its wakeups
+    wakeup: Option<ReadinessKey>,
+    /// Buffer for small writes, when write combining is enabled
+    combiner: Option<WriteCombiner>,
+}
+
+/// Writes waiting to reach the file
+struct WriteCombiner {
+    pending: Mutex<Vec<u8>>,
+    /// Buffered bytes that trigger a flush
+    threshold: usize,
 }
 
 impl FileHandle {
@@ -23,6 +51,10 @@ impl FileHandle {
     pub fn from_vfs(file: Box<dyn OpenFile>) -> Self {
         Self {
             inner: RwLock::new(file),
+            path: None,
+            timer: None,
+            wakeup: None,
+            combiner: None,
         }
     }
 
@@ -31,26 +63,201 @@ pub fn from_vfs(file: Box<dyn OpenFile>) -> Self {
     pub fn from_std(file: File) -> Self {
         Self {
             inner: RwLock::new(Box::new(StdFileHandle { file })),
+            path: None,
+            timer: None,
+            wakeup: None,
+            combiner: None,
+        }
+    }
+
+    /// Create from a signalfd (reads return pending signals as JSON)
+    #[inline]
+    pub fn from_signalfd(signalfd: SignalFd) -> Self {
+        let wakeup = Some(signalfd.readiness_key());
+        Self {
+            inner: RwLock::new(Box::new(SignalFdHandle { signalfd })),
+            path: None,
+            timer: None,
+            wakeup,
+            combiner: None,
+        }
+    }
+
+    /// Create from a timerfd (reads return the expiration count)
+    #[inline]
+    pub fn from_timerfd(timer: TimerFd) -> Self {
+        Self {
+            inner: RwLock::new(Box::new(TimerFdHandle {
+                timer: timer.clone(),
+            })),
+            path: None,
+            wakeup: Some(timer.readiness_key()),
+            timer: Some(timer),
+            combiner: None,
+        }
+    }
+
+    /// Create from an io_uring completion notifier (reads return the completion count)
+    #[inline]
+    pub fn from_completion_notifier(notifier: CompletionNotifier) -> Self {
+        let wakeup = Some(notifier.readiness_key());
+        Self {
+            inner: RwLock::new(Box::new(CompletionFdHandle { notifier })),
+            path: None,
+            timer: None,
+            wakeup,
+            combiner: None,
+        }
+    }
+
+    /// Create from one end of a pipe
+    ///
+    /// `pid` is the process holding the fd: the pipe's reader gets the read
+    /// end and its writer the write end.
+    #[inline]
+    pub fn from_pipe(pipes: PipeManager, pipe_id: PipeId, pid: Pid) -> Self {
+        Self {
+            inner: RwLock::new(Box::new(PipeFdHandle {
+                pipes,
+                pipe_id,
+                pid,
+            })),
+            path: None,
+            timer: None,
+            wakeup: Some(ReadinessKey::Pipe(pipe_id)),
+            combiner: None,
         }
     }
 
+    /// Record the path this handle was opened with
+    #[inline]
+    pub fn with_path(mut self, path: impl Into<PathBuf>) -> Self {
+        self.path = Some(path.into());
+        self
+    }
+
+    /// Buffer writes until `threshold` bytes are pending
+    ///
+    /// Writes of at least `threshold` bytes bypass the buffer after flushing it.
+    #[inline]
+    pub fn with_write_combining(mut self, threshold: usize) -> Self {
+        self.combiner = Some(WriteCombiner {
+            pending: Mutex::new(Vec::with_capacity(threshold)),
+            threshold,
+        });
+        self
+    }
+
+    /// Whether writes are buffered
+    #[inline]
+    pub fn is_write_combining(&self) -> bool {
+        self.combiner.is_some()
+    }
+
+    /// Bytes written but not yet passed to the file
+    pub fn buffered_len(&self) -> usize {
+        self.combiner
+            .as_ref()
+            .map_or(0, |combiner| combiner.pending.lock().len())
+    }
+
+    /// Path the handle was opened with, if it came from a path
+    #[inline]
+    pub fn path(&self) -> Option<&Path> {
+        self.path.as_deref()
+    }
+
+    /// Timer behind this handle, if it is a timerfd
+    #[inline]
+    pub fn timerfd(&self) -> Option<&TimerFd> {
+        self.timer.as_ref()
+    }
+
+    /// Pipe behind this handle, if it is a pipe fd
+    #[inline]
+    pub fn pipe_id(&self) -> Option<PipeId> {
+        match self.wakeup {
+            Some(ReadinessKey::Pipe(pipe_id)) => Some(pipe_id),
+            _ => None,
+        }
+    }
+
+    /// Source whose readiness changes wake pollers of this handle
+    ///
+    /// None for handles that are always ready or backed by an OS descriptor.
+    #[inline]
+    pub fn readiness_key(&self) -> Option<ReadinessKey> {
+        self.wakeup
+    }
+
+    /// Whether the handle can be read or written without blocking
+    pub fn readiness(&self) -> Readiness {
+        self.inner.read().readiness()
+    }
+
     /// Read into buffer
     pub fn read(&self, buf: &mut [u8]) -> std::io::Result<usize> {
+        self.flush_pending()?;
         self.inner.write().read(buf)
     }
 
     /// Write from buffer
+    ///
+    /// With write combining, a write that fits in the buffer is accepted
+    /// whole and reaches the file on a later flush.
     pub fn write(&self, buf: &[u8]) -> std::io::Result<usize> {
-        self.inner.write().write(buf)
+        let Some(ref combiner) = self.combiner else {
+            return self.inner.write().write(buf);
+        };
+
+        let mut pending = combiner.pending.lock();
+        if pending.len() + buf.len() < combiner.threshold {
+            pending.extend_from_slice(buf);
+            return Ok(buf.len());
+        }
+
+        let mut file = self.inner.write();
+        if buf.len() >= combiner.threshold {
+            file.write_all(&pending)?;
+            pending.clear();
+            file.write_all(buf)?;
+        } else {
+            pending.extend_from_slice(buf);
+            file.write_all(&pending)?;
+            pending.clear();
+        }
+        Ok(buf.len())
+    }
+
+    /// Pass buffered writes to the file and flush it
+    pub fn flush(&self) -> std::io::Result<()> {
+        self.flush_pending()?;
+        self.inner.write().flush()
+    }
+
+    /// Pass buffered writes to the file
+    fn flush_pending(&self) -> std::io::Result<()> {
+        let Some(ref combiner) = self.combiner else {
+            return Ok(());
+        };
+        let mut pending = combiner.pending.lock();
+        if !pending.is_empty() {
+            self.inner.write().write_all(&pending)?;
+            pending.clear();
+        }
+        Ok(())
     }
 
     /// Seek to position
     pub fn seek(&self, pos: SeekFrom) -> std::io::Result<u64> {
+        self.flush_pending()?;
         self.inner.write().seek(pos)
     }
 
     /// Sync to storage
     pub fn sync(&self) -> VfsResult<()> {
+        self.flush_pending()
+            .map_err(|e| VfsError::IoError(e.to_string().into()))?;
         self.inner.write().sync()
     }
 
@@ -63,8 +270,140 @@ pub fn sync_data(&self) -> std::io::Result<()> {
 
     /// Set file length
     pub fn set_len(&self, size: u64) -> VfsResult<()> {
+        self.flush_pending()
+            .map_err(|e| VfsError::IoError(e.to_string().into()))?;
         self.inner.write().set_len(size)
     }
+
+    /// Copy up to `count` bytes from this file into another handle
+    ///
+    /// Both handles are locked for the duration; callers must not pass the
+    /// same handle twice. Locks are taken in address order so transfers
+    /// running in opposite directions can't deadlock.
+    pub fn transfer_to(
+        &self,
+        out: &FileHandle,
+        offset: Option<u64>,
+        count: usize,
+    ) -> std::io::Result<usize> {
+        self.flush_pending()?;
+        out.flush_pending()?;
+
+        let (mut file, mut out) = if (self as *const Self) < (out as *const Self) {
+            let file = self.inner.write();
+            (file, out.inner.write())
+        } else {
+            let out = out.inner.write();
+            (self.inner.write(), out)
+        };
+        transfer_locked(&mut **file, &mut *out, offset, count)
+    }
+
+    /// Copy up to `count` bytes from this file into a writer
+    ///
+    /// With `offset`, reads start there and the file position is left
+    /// untouched; otherwise reads start at, and advance, the current
+    /// position. In-memory files are written straight from their backing
+    /// buffer; everything else goes through a pooled chunk buffer.
+    pub fn transfer_into(
+        &self,
+        out: &mut dyn Write,
+        offset: Option<u64>,
+        count: usize,
+    ) -> std::io::Result<usize> {
+        self.flush_pending()?;
+        let mut file = self.inner.write();
+        transfer_locked(&mut **file, out, offset, count)
+    }
+}
+
+/// Body of the transfers, run with the source file already locked
+fn transfer_locked(
+    file: &mut dyn OpenFile,
+    out: &mut dyn Write,
+    offset: Option<u64>,
+    count: usize,
+) -> std::io::Result<usize> {
+    let start = match offset {
+        Some(pos) => pos,
+        None => file.stream_position()?,
+    };
+
+    let sent = match file.contents() {
+        Some(data) => {
+            let from = (start as usize).min(data.len());
+            let to = from.saturating_add(count).min(data.len());
+            write_some(out, &data[from..to])?
+        }
+        None => {
+            let original = file.stream_position()?;
+            file.seek(SeekFrom::Start(start))?;
+            let result = copy_chunked(file, out, count);
+            if offset.is_some() {
+                file.seek(SeekFrom::Start(original))?;
+            }
+            result?
+        }
+    };
+
+    if offset.is_none() {
+        file.seek(SeekFrom::Start(start + sent as u64))?;
+    }
+    Ok(sent)
+}
+
+impl Drop for FileHandle {
+    fn drop(&mut self) {
+        // Last reference gone without a close that could report the error
+        if let Err(e) = self.flush_pending() {
+            log::warn!("Dropped buffered writes on {:?}: {}", self.path, e);
+        }
+    }
+}
+
+/// Write as much of `data` as the writer accepts
+///
+/// Stops early on a short or would-block write once some bytes went out,
+/// so the caller can report a partial transfer like sendfile(2).
+fn write_some(out: &mut dyn Write, data: &[u8]) -> std::io::Result<usize> {
+    let mut sent = 0;
+    while sent < data.len() {
+        match out.write(&data[sent..]) {
+            Ok(0) => break,
+            Ok(n) => sent += n,
+            Err(e) if e.kind() == std::io::ErrorKind::Interrupted => {}
+            Err(e) if e.kind() == std::io::ErrorKind::WouldBlock && sent > 0 => break,
+            Err(e) => return Err(e),
+        }
+    }
+    Ok(sent)
+}
+
+/// Buffered fallback for files without an in-memory view
+fn copy_chunked(
+    file: &mut dyn OpenFile,
+    out: &mut dyn Write,
+    count: usize,
+) -> std::io::Result<usize> {
+    let mut buf = PooledBuffer::get(count.min(DEFAULT_CHUNK_SIZE));
+    buf.resize(count.min(DEFAULT_CHUNK_SIZE), 0);
+
+    let mut sent = 0;
+    while sent < count {
+        let want = (count - sent).min(buf.len());
+        let n = file.read(&mut buf[..want])?;
+        if n == 0 {
+            break;
+        }
+        let written = write_some(out, &buf[..n])?;
+        sent += written;
+        if written < n {
+            // Rewind past what the reader consumed but the writer refused
+            file.seek(SeekFrom::Current(written as i64 - n as i64))?;
+            break;
+        }
+    }
+    Ok(sent)
 }
 
 /// Standard file handle implementing OpenFile
@@ -148,6 +487,306 @@ fn set_len(&mut self, size: u64) -> VfsResult<()> {
     }
 }
 
+/// Signalfd handle implementing OpenFile
+///
+/// Adapter exposing a SignalFd through the FD table; only reads are supported
+struct SignalFdHandle {
+    signalfd: SignalFd,
+}
+
+impl Read for SignalFdHandle {
+    #[inline]
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        self.signalfd.read_into(buf)
+    }
+}
+
+impl Write for SignalFdHandle {
+    fn write(&mut self, _buf: &[u8]) -> std::io::Result<usize> {
+        Err(std::io::Error::new(
+            std::io::ErrorKind::Unsupported,
+            "signalfd is not writable",
+        ))
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+impl Seek for SignalFdHandle {
+    fn seek(&mut self, _pos: SeekFrom) -> std::io::Result<u64> {
+        Err(std::io::Error::new(
+            std::io::ErrorKind::Unsupported,
+            "signalfd is not seekable",
+        ))
+    }
+}
+
+impl OpenFile for SignalFdHandle {
+    fn sync(&mut self) -> VfsResult<()> {
+        Ok(())
+    }
+
+    fn metadata(&self) -> VfsResult<crate::vfs::Metadata> {
+        let now = std::time::SystemTime::now();
+        Ok(crate::vfs::Metadata {
+            file_type: crate::vfs::FileType::Unknown,
+            size: 0,
+            permissions: crate::vfs::Permissions::new(0o600),
+            modified: now,
+            accessed: now,
+            created: now,
+        })
+    }
+
+    fn set_len(&mut self, _size: u64) -> VfsResult<()> {
+        Err(VfsError::NotSupported(
+            "signalfd cannot be truncated".into(),
+        ))
+    }
+
+    fn readiness(&self) -> Readiness {
+        Readiness {
+            readable: self.signalfd.pending_count() > 0,
+            ..Readiness::default()
+        }
+    }
+}
+
+/// Timerfd handle implementing OpenFile
+///
+/// Adapter exposing a TimerFd through the FD table; only reads are supported
+struct TimerFdHandle {
+    timer: TimerFd,
+}
+
+impl Read for TimerFdHandle {
+    #[inline]
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        self.timer.read_into(buf)
+    }
+}
+
+impl Write for TimerFdHandle {
+    fn write(&mut self, _buf: &[u8]) -> std::io::Result<usize> {
+        Err(std::io::Error::new(
+            std::io::ErrorKind::Unsupported,
+            "timerfd is not writable",
+        ))
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+impl Seek for TimerFdHandle {
+    fn seek(&mut self, _pos: SeekFrom) -> std::io::Result<u64> {
+        Err(std::io::Error::new(
+            std::io::ErrorKind::Unsupported,
+            "timerfd is not seekable",
+        ))
+    }
+}
+
+impl OpenFile for TimerFdHandle {
+    fn sync(&mut self) -> VfsResult<()> {
+        Ok(())
+    }
+
+    fn metadata(&self) -> VfsResult<crate::vfs::Metadata> {
+        let now = std::time::SystemTime::now();
+        Ok(crate::vfs::Metadata {
+            file_type: crate::vfs::FileType::Unknown,
+            size: 0,
+            permissions: crate::vfs::Permissions::new(0o600),
+            modified: now,
+            accessed: now,
+            created: now,
+        })
+    }
+
+    fn set_len(&mut self, _size: u64) -> VfsResult<()> {
+        Err(VfsError::NotSupported("timerfd cannot be truncated".into()))
+    }
+
+    fn readiness(&self) -> Readiness {
+        Readiness {
+            readable: self.timer.remaining().is_some_and(|left| left.is_zero()),
+            ..Readiness::default()
+        }
+    }
+}
+
+/// Completion fd handle implementing OpenFile
+///
+/// Adapter exposing an io_uring CompletionNotifier through the FD table; only
+/// reads are supported
+struct CompletionFdHandle {
+    notifier: CompletionNotifier,
+}
+
+impl Read for CompletionFdHandle {
+    #[inline]
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        self.notifier.read_into(buf)
+    }
+}
+
+impl Write for CompletionFdHandle {
+    fn write(&mut self, _buf: &[u8]) -> std::io::Result<usize> {
+        Err(std::io::Error::new(
+            std::io::ErrorKind::Unsupported,
+            "completion fd is not writable",
+        ))
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+impl Seek for CompletionFdHandle {
+    fn seek(&mut self, _pos: SeekFrom) -> std::io::Result<u64> {
+        Err(std::io::Error::new(
+            std::io::ErrorKind::Unsupported,
+            "completion fd is not seekable",
+        ))
+    }
+}
+
+impl OpenFile for CompletionFdHandle {
+    fn sync(&mut self) -> VfsResult<()> {
+        Ok(())
+    }
+
+    fn metadata(&self) -> VfsResult<crate::vfs::Metadata> {
+        let now = std::time::SystemTime::now();
+        Ok(crate::vfs::Metadata {
+            file_type: crate::vfs::FileType::Unknown,
+            size: 0,
+            permissions: crate::vfs::Permissions::new(0o600),
+            modified: now,
+            accessed: now,
+            created: now,
+        })
+    }
+
+    fn set_len(&mut self, _size: u64) -> VfsResult<()> {
+        Err(VfsError::NotSupported(
+            "completion fd cannot be truncated".into(),
+        ))
+    }
+
+    fn readiness(&self) -> Readiness {
+        Readiness {
+            readable: self.notifier.is_readable(),
+            ..Readiness::default()
+        }
+    }
+}
+
+/// Pipe handle implementing OpenFile
+///
+/// Adapter exposing one end of a PipeManager pipe through the FD table.
+/// Reads and writes never block; they fail with WouldBlock instead.
+struct PipeFdHandle {
+    pipes: PipeManager,
+    pipe_id: PipeId,
+    pid: Pid,
+}
+
+fn pipe_io_error(err: PipeError) -> std::io::Error {
+    match err {
+        PipeError::WouldBlock(_) => std::io::ErrorKind::WouldBlock.into(),
+        PipeError::Closed => std::io::ErrorKind::BrokenPipe.into(),
+        PipeError::PermissionDenied(msg) => {
+            std::io::Error::new(std::io::ErrorKind::PermissionDenied, msg)
+        }
+        other => std::io::Error::other(other.to_string()),
+    }
+}
+
+impl Read for PipeFdHandle {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        let data = self
+            .pipes
+            .read(self.pipe_id, self.pid, buf.len())
+            .map_err(pipe_io_error)?;
+        buf[..data.len()].copy_from_slice(&data);
+        Ok(data.len())
+    }
+}
+
+impl Write for PipeFdHandle {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.pipes
+            .write(self.pipe_id, self.pid, buf)
+            .map_err(pipe_io_error)
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+impl Seek for PipeFdHandle {
+    fn seek(&mut self, _pos: SeekFrom) -> std::io::Result<u64> {
+        Err(std::io::Error::new(
+            std::io::ErrorKind::Unsupported,
+            "pipe is not seekable",
+        ))
+    }
+}
+
+impl OpenFile for PipeFdHandle {
+    fn sync(&mut self) -> VfsResult<()> {
+        Ok(())
+    }
+
+    fn metadata(&self) -> VfsResult<crate::vfs::Metadata> {
+        let now = std::time::SystemTime::now();
+        let size = self
+            .pipes
+            .stats(self.pipe_id)
+            .map(|stats| stats.buffered as u64)
+            .unwrap_or(0);
+        Ok(crate::vfs::Metadata {
+            file_type: crate::vfs::FileType::Fifo,
+            size,
+            permissions: crate::vfs::Permissions::new(0o600),
+            modified: now,
+            accessed: now,
+            created: now,
+        })
+    }
+
+    fn set_len(&mut self, _size: u64) -> VfsResult<()> {
+        Err(VfsError::NotSupported("pipe cannot be truncated".into()))
+    }
+
+    fn readiness(&self) -> Readiness {
+        // A destroyed pipe reads as hung up
+        let Ok(stats) = self.pipes.stats(self.pipe_id) else {
+            return Readiness {
+                hangup: true,
+                ..Readiness::default()
+            };
+        };
+        let is_reader = stats.reader_pid == self.pid;
+        let is_writer = stats.writer_pid == self.pid;
+        Readiness {
+            // EOF after close counts as readable, like poll(2)
+            readable: is_reader && (stats.buffered > 0 || stats.closed),
+            writable: is_writer && !stats.closed && stats.buffered < stats.capacity,
+            hangup: stats.closed,
+            // Writing to a closed pipe fails with EPIPE, like poll(2)
+            error: is_writer && stats.closed,
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -183,4 +822,114 @@ fn test_file_handle_seek() {
         assert_eq!(handle.read(&mut buf).unwrap(), 5);
         assert_eq!(&buf, b"56789");
     }
+
+    #[test]
+    fn test_write_combining_flushes_on_threshold_and_read() {
+        let temp = NamedTempFile::new().unwrap();
+        let handle = FileHandle::from_std(temp.reopen().unwrap()).with_write_combining(8);
+
+        assert_eq!(handle.write(b"abc").unwrap(), 3);
+        assert_eq!(handle.write(b"def").unwrap(), 3);
+        assert_eq!(handle.buffered_len(), 6);
+        assert!(std::fs::read(temp.path()).unwrap().is_empty());
+
+        // Reaching the threshold writes everything pending
+        handle.write(b"gh").unwrap();
+        assert_eq!(handle.buffered_len(), 0);
+        assert_eq!(std::fs::read(temp.path()).unwrap(), b"abcdefgh");
+
+        // Large writes go straight through after what is pending
+        handle.write(b"i").unwrap();
+        handle.write(b"0123456789").unwrap();
+        assert_eq!(std::fs::read(temp.path()).unwrap(), b"abcdefghi0123456789");
+
+        // Seeking flushes first, so the position accounts for buffered data
+        handle.write(b"j").unwrap();
+        assert_eq!(handle.seek(SeekFrom::Current(0)).unwrap(), 20);
+
+        handle.write(b"k").unwrap();
+        drop(handle);
+        assert_eq!(
+            std::fs::read(temp.path()).unwrap(),
+            b"abcdefghi0123456789jk"
+        );
+    }
+
+    #[test]
+    fn test_transfer_from_memfs() {
+        use crate::vfs::{FileSystem, MemFS, OpenFlags, OpenMode};
+        use std::path::Path;
+
+        let fs = MemFS::new();
+        fs.write(Path::new("/src"), b"hello world").unwrap();
+        let src = FileHandle::from_vfs(
+            fs.open(
+                Path::new("/src"),
+                OpenFlags::read_only(),
+                OpenMode::default(),
+            )
+            .unwrap(),
+        );
+
+        // Explicit offset leaves the position alone
+        let mut out = Vec::new();
+        assert_eq!(src.transfer_into(&mut out, Some(6), 100).unwrap(), 5);
+        assert_eq!(out, b"world");
+        assert_eq!(src.seek(SeekFrom::Current(0)).unwrap(), 0);
+
+        // No offset advances it
+        let mut out = Vec::new();
+        assert_eq!(src.transfer_into(&mut out, None, 5).unwrap(), 5);
+        assert_eq!(out, b"hello");
+        assert_eq!(src.seek(SeekFrom::Current(0)).unwrap(), 5);
+    }
+
+    #[test]
+    fn test_transfer_buffered_between_handles() {
+        let mut temp = NamedTempFile::new().unwrap();
+        temp.write_all(b"0123456789").unwrap();
+        temp.flush().unwrap();
+        let src = FileHandle::from_std(File::open(temp.path()).unwrap());
+        src.seek(SeekFrom::Start(2)).unwrap();
+
+        let dest_file = NamedTempFile::new().unwrap();
+        let dest = FileHandle::from_std(dest_file.reopen().unwrap());
+
+        assert_eq!(src.transfer_to(&dest, None, 4).unwrap(), 4);
+        assert_eq!(src.seek(SeekFrom::Current(0)).unwrap(), 6);
+        assert_eq!(std::fs::read(dest_file.path()).unwrap(), b"2345");
+    }
+
+    #[test]
+    fn test_opposite_transfers_do_not_deadlock() {
+        use std::sync::Arc;
+
+        let a_file = NamedTempFile::new().unwrap();
+        let b_file = NamedTempFile::new().unwrap();
+        std::fs::write(a_file.path(), b"aaaa").unwrap();
+        std::fs::write(b_file.path(), b"bbbb").unwrap();
+        let open = |f: &NamedTempFile| {
+            let file = std::fs::OpenOptions::new()
+                .read(true)
+                .write(true)
+                .open(f.path())
+                .unwrap();
+            Arc::new(FileHandle::from_std(file))
+        };
+        let (a, b) = (open(&a_file), open(&b_file));
+
+        let handles: Vec<_> = [(a.clone(), b.clone()), (b, a)]
+            .into_iter()
+            .map(|(src, dest)| {
+                std::thread::spawn(move || {
+                    for _ in 0..1000 {
+                        src.transfer_to(&dest, Some(0), 1).unwrap();
+                    }
+                })
+            })
+            .collect();
+        for handle in handles {
+            handle.join().unwrap();
+        }
+    }
 }
@@ -0,0 +1,246 @@
+/*!
+ * File Content Hashing
+ * In-kernel digests for integrity checks (HashFile)
+ *
+ * Files are hashed where they live instead of being copied into userspace.
+ * In-memory files are hashed straight from their buffer; anything else is
+ * streamed in fixed-size chunks so large files never need to fit in memory.
+ */
+
+use crate::core::limits::DEFAULT_CHUNK_SIZE;
+use crate::core::types::Pid;
+use crate::monitoring::span_operation;
+use crate::permissions::{PermissionChecker, PermissionRequest};
+use crate::syscalls::core::executor::SyscallExecutorWithIpc;
+use crate::syscalls::types::{SyscallError, SyscallResult};
+use crate::vfs::{FileSystem, OpenFlags, OpenMode};
+
+use log::{error, info};
+use sha2::{Digest, Sha256};
+use std::fs::File;
+use std::io::{self, Read};
+use std::path::Path;
+
+/// Digest algorithms accepted by HashFile
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HashAlgorithm {
+    Sha256,
+    Blake3,
+}
+
+impl HashAlgorithm {
+    /// Algorithm names accepted by [`from_name`](Self::from_name)
+    pub const SUPPORTED: &'static [&'static str] = &["sha256", "blake3"];
+
+    /// Look up an algorithm by name (case-insensitive)
+    pub fn from_name(name: &str) -> Result<Self, SyscallError> {
+        match name.to_ascii_lowercase().as_str() {
+            "sha256" | "sha-256" => Ok(Self::Sha256),
+            "blake3" => Ok(Self::Blake3),
+            _ => Err(SyscallError::NotImplemented(
+                format!(
+                    "hash algorithm '{}' (supported: {})",
+                    name,
+                    Self::SUPPORTED.join(", ")
+                )
+                .into(),
+            )),
+        }
+    }
+
+    /// Canonical algorithm name
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::Sha256 => "sha256",
+            Self::Blake3 => "blake3",
+        }
+    }
+
+    /// Digest length in bytes
+    pub const fn digest_len(self) -> usize {
+        32
+    }
+
+    /// Hash an in-memory buffer
+    pub fn digest(self, data: &[u8]) -> Vec<u8> {
+        let mut digester = Digester::new(self);
+        digester.update(data);
+        digester.finalize()
+    }
+
+    /// Hash everything `reader` yields, one chunk at a time
+    pub fn digest_reader(self, reader: &mut (impl Read + ?Sized)) -> io::Result<Vec<u8>> {
+        let mut digester = Digester::new(self);
+        let mut chunk = vec![0u8; DEFAULT_CHUNK_SIZE];
+        loop {
+            match reader.read(&mut chunk) {
+                Ok(0) => return Ok(digester.finalize()),
+                Ok(n) => digester.update(&chunk[..n]),
+                Err(e) if e.kind() == io::ErrorKind::Interrupted => continue,
+                Err(e) => return Err(e),
+            }
+        }
+    }
+}
+
+enum Digester {
+    Sha256(Sha256),
+    // blake3's hasher carries a ~2KB chunk stack
+    Blake3(Box<blake3::Hasher>),
+}
+
+impl Digester {
+    fn new(algorithm: HashAlgorithm) -> Self {
+        match algorithm {
+            HashAlgorithm::Sha256 => Self::Sha256(Sha256::new()),
+            HashAlgorithm::Blake3 => Self::Blake3(Box::default()),
+        }
+    }
+
+    fn update(&mut self, data: &[u8]) {
+        match self {
+            Self::Sha256(hasher) => hasher.update(data),
+            Self::Blake3(hasher) => {
+                hasher.update(data);
+            }
+        }
+    }
+
+    fn finalize(self) -> Vec<u8> {
+        match self {
+            Self::Sha256(hasher) => hasher.finalize().to_vec(),
+            Self::Blake3(hasher) => hasher.finalize().as_bytes().to_vec(),
+        }
+    }
+}
+
+impl SyscallExecutorWithIpc {
+    /// Hash a file's contents, returning the raw digest bytes
+    /// Can block on slow storage (NFS, USB, slow disks)
+    pub(in crate::syscalls) fn hash_file(
+        &self,
+        pid: Pid,
+        path: &Path,
+        algorithm: &str,
+    ) -> SyscallResult {
+        let span = span_operation("hash_file");
+        let _guard = span.enter();
+        span.record("pid", &format!("{}", pid));
+        span.record("path", &format!("{:?}", path));
+
+        let algorithm = match HashAlgorithm::from_name(algorithm) {
+            Ok(algorithm) => algorithm,
+            Err(e) => {
+                span.record_error(&e.to_string());
+                return e.into();
+            }
+        };
+        span.record("algorithm", algorithm.name());
+
+        let canonical_path = path.canonicalize().unwrap_or_else(|_| path.to_path_buf());
+        let request = PermissionRequest::file_read(pid, canonical_path.clone());
+        let response = self.permission_manager().check_and_audit(&request);
+
+        if !response.is_allowed() {
+            span.record_error(response.reason());
+            return SyscallResult::permission_denied(response.reason());
+        }
+
+        let result = match &self.optional().vfs {
+            Some(vfs) => self
+                .timeout_executor()
+                .execute_with_deadline(
+                    || -> Result<Vec<u8>, String> {
+                        let mut file = vfs
+                            .open(path, OpenFlags::read_only(), OpenMode::default())
+                            .map_err(|e| e.to_string())?;
+                        match file.contents() {
+                            Some(data) => Ok(algorithm.digest(data)),
+                            None => algorithm
+                                .digest_reader(&mut file)
+                                .map_err(|e| e.to_string()),
+                        }
+                    },
+                    self.timeout_config().file_io,
+                    "vfs_hash_file",
+                )
+                .map_err(|e| e.to_string()),
+            None => self
+                .timeout_executor()
+                .execute_with_deadline(
+                    || {
+                        File::open(&canonical_path)
+                            .and_then(|mut f| algorithm.digest_reader(&mut f))
+                    },
+                    self.timeout_config().file_io,
+                    "fs_hash_file",
+                )
+                .map_err(|e| e.to_string()),
+        };
+
+        match result {
+            Ok(digest) => {
+                info!("PID {} hashed file: {:?} ({})", pid, path, algorithm.name());
+                span.record_result(true);
+                SyscallResult::success_with_data(digest)
+            }
+            Err(e) => {
+                error!("Hashing failed for {:?}: {}", path, e);
+                span.record_error(&format!("Hash failed: {}", e));
+                SyscallResult::error(format!("Hash failed: {}", e))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Cursor;
+
+    fn hex(bytes: &[u8]) -> String {
+        bytes.iter().map(|b| format!("{:02x}", b)).collect()
+    }
+
+    #[test]
+    fn test_known_digests() {
+        assert_eq!(
+            hex(&HashAlgorithm::Sha256.digest(b"abc")),
+            "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+        );
+        assert_eq!(
+            hex(&HashAlgorithm::Blake3.digest(b"abc")),
+            "6437b3ac38465133ffb63b75273a8db548c558465d79db03fd359c6cd5bd9d85"
+        );
+    }
+
+    #[test]
+    fn test_streaming_matches_buffered() {
+        // Spans several chunks with a partial tail
+        let data: Vec<u8> = (0..DEFAULT_CHUNK_SIZE * 3 + 17)
+            .map(|i| (i % 251) as u8)
+            .collect();
+
+        for algorithm in [HashAlgorithm::Sha256, HashAlgorithm::Blake3] {
+            let streamed = algorithm.digest_reader(&mut Cursor::new(&data)).unwrap();
+            assert_eq!(streamed, algorithm.digest(&data));
+            assert_eq!(streamed.len(), algorithm.digest_len());
+        }
+    }
+
+    #[test]
+    fn test_algorithm_names() {
+        assert_eq!(
+            HashAlgorithm::from_name("SHA256").unwrap(),
+            HashAlgorithm::Sha256
+        );
+        assert_eq!(
+            HashAlgorithm::from_name("blake3").unwrap(),
+            HashAlgorithm::Blake3
+        );
+        assert!(matches!(
+            HashAlgorithm::from_name("md5"),
+            Err(SyscallError::NotImplemented(_))
+        ));
+    }
+}
@@ -0,0 +1,323 @@
+/*!
+ * HTTP Connection Pool
+ * Keep-alive client reuse for NetworkRequest
+ *
+ * Clients are pooled per process and per (scheme, host, port). Keeping the
+ * owning PID in the key means a connection opened inside one process's
+ * network namespace is never handed to another process, and all of a
+ * process's connections can be torn down when it exits.
+ */
+
+use crate::core::limits::{HTTP_POOL_IDLE_TIMEOUT, HTTP_POOL_MAX_IDLE_PER_HOST};
+use crate::core::types::Pid;
+use ahash::RandomState;
+use dashmap::DashMap;
+use log::{debug, info};
+use reqwest::blocking::Client;
+use reqwest::Url;
+use serde::{Deserialize, Serialize};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+/// HTTP pool configuration
+#[derive(Debug, Clone, Copy)]
+pub struct HttpPoolConfig {
+    /// Close pooled connections unused for this long
+    pub idle_timeout: Duration,
+    /// Idle keep-alive connections kept per host
+    pub max_idle_per_host: usize,
+}
+
+impl HttpPoolConfig {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_idle_timeout(mut self, idle_timeout: Duration) -> Self {
+        self.idle_timeout = idle_timeout;
+        self
+    }
+
+    pub fn with_max_idle_per_host(mut self, max_idle_per_host: usize) -> Self {
+        self.max_idle_per_host = max_idle_per_host;
+        self
+    }
+}
+
+impl Default for HttpPoolConfig {
+    fn default() -> Self {
+        Self {
+            idle_timeout: HTTP_POOL_IDLE_TIMEOUT,
+            max_idle_per_host: HTTP_POOL_MAX_IDLE_PER_HOST,
+        }
+    }
+}
+
+/// HTTP pool statistics
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct HttpPoolStats {
+    /// Requests served by an existing pooled client
+    pub hits: u64,
+    /// Requests that had to create a client
+    pub misses: u64,
+    /// Clients dropped for sitting idle past the timeout
+    pub evictions: u64,
+    /// Clients currently pooled
+    pub pooled: usize,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+struct PoolKey {
+    pid: Pid,
+    scheme: String,
+    host: String,
+    port: u16,
+}
+
+impl PoolKey {
+    fn from_url(pid: Pid, url: &Url) -> Option<Self> {
+        Some(Self {
+            pid,
+            scheme: url.scheme().to_string(),
+            host: url.host_str()?.to_ascii_lowercase(),
+            port: url.port_or_known_default()?,
+        })
+    }
+}
+
+struct PoolEntry {
+    client: Client,
+    last_used: Instant,
+}
+
+#[derive(Default)]
+struct PoolCounters {
+    hits: AtomicU64,
+    misses: AtomicU64,
+    evictions: AtomicU64,
+}
+
+/// Keep-alive pool of HTTP clients
+///
+/// Each pooled client owns its own keep-alive connections, so reusing the
+/// client for a host reuses the connection. Idle clients are evicted lazily
+/// on access and whenever a new client is created.
+#[derive(Clone)]
+pub struct HttpPool {
+    config: HttpPoolConfig,
+    entries: Arc<DashMap<PoolKey, PoolEntry, RandomState>>,
+    counters: Arc<PoolCounters>,
+}
+
+impl HttpPool {
+    pub fn new(config: HttpPoolConfig) -> Self {
+        Self {
+            config,
+            entries: Arc::new(DashMap::with_hasher(RandomState::new())),
+            counters: Arc::new(PoolCounters::default()),
+        }
+    }
+
+    /// Pool configuration
+    pub fn config(&self) -> HttpPoolConfig {
+        self.config
+    }
+
+    /// Get a client for `url` on behalf of `pid`, reusing a pooled one if fresh
+    pub(in crate::syscalls) fn client(&self, pid: Pid, url: &Url) -> reqwest::Result<Client> {
+        let Some(key) = PoolKey::from_url(pid, url) else {
+            // No host to pool on; let reqwest report the problem
+            return self.build_client();
+        };
+
+        if let Some(mut entry) = self.entries.get_mut(&key) {
+            if entry.last_used.elapsed() < self.config.idle_timeout {
+                entry.last_used = Instant::now();
+                self.counters.hits.fetch_add(1, Ordering::Relaxed);
+                return Ok(entry.client.clone());
+            }
+        }
+
+        self.counters.misses.fetch_add(1, Ordering::Relaxed);
+        self.evict_idle();
+
+        let client = self.build_client()?;
+        let entry = self.entries.entry(key).or_insert_with(|| PoolEntry {
+            client,
+            last_used: Instant::now(),
+        });
+        debug!(
+            "Pooled HTTP client for PID {} ({})",
+            pid,
+            url.origin().ascii_serialization()
+        );
+        Ok(entry.client.clone())
+    }
+
+    fn build_client(&self) -> reqwest::Result<Client> {
+        Client::builder()
+            .user_agent("ai-os-kernel/0.1.0")
+            .pool_idle_timeout(self.config.idle_timeout)
+            .pool_max_idle_per_host(self.config.max_idle_per_host)
+            .build()
+    }
+
+    /// Drop clients idle past the timeout, returning how many were evicted
+    pub fn evict_idle(&self) -> usize {
+        let timeout = self.config.idle_timeout;
+        let before = self.entries.len();
+        self.entries
+            .retain(|_, entry| entry.last_used.elapsed() < timeout);
+        let evicted = before.saturating_sub(self.entries.len());
+
+        if evicted > 0 {
+            self.counters
+                .evictions
+                .fetch_add(evicted as u64, Ordering::Relaxed);
+            debug!("Evicted {} idle HTTP clients", evicted);
+        }
+        evicted
+    }
+
+    /// Close every pooled connection owned by a process
+    pub fn release_process(&self, pid: Pid) -> usize {
+        let before = self.entries.len();
+        self.entries.retain(|key, _| key.pid != pid);
+        let released = before.saturating_sub(self.entries.len());
+
+        if released > 0 {
+            info!("Closed {} pooled HTTP clients for PID {}", released, pid);
+        }
+        released
+    }
+
+    /// Whether a process has pooled connections
+    pub fn has_process_connections(&self, pid: Pid) -> bool {
+        self.entries.iter().any(|entry| entry.key().pid == pid)
+    }
+
+    /// Get pool statistics
+    pub fn stats(&self) -> HttpPoolStats {
+        HttpPoolStats {
+            hits: self.counters.hits.load(Ordering::Relaxed),
+            misses: self.counters.misses.load(Ordering::Relaxed),
+            evictions: self.counters.evictions.load(Ordering::Relaxed),
+            pooled: self.entries.len(),
+        }
+    }
+}
+
+impl Default for HttpPool {
+    fn default() -> Self {
+        Self::new(HttpPoolConfig::default())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::{BufRead, BufReader, Write};
+    use std::net::TcpListener;
+    use std::sync::atomic::AtomicUsize;
+
+    fn url(s: &str) -> Url {
+        Url::parse(s).unwrap()
+    }
+
+    #[test]
+    fn test_reuse_per_process_and_origin() {
+        let pool = HttpPool::default();
+
+        pool.client(1, &url("http://example.com/a")).unwrap();
+        pool.client(1, &url("http://EXAMPLE.com:80/b")).unwrap();
+        assert_eq!(pool.stats().hits, 1);
+        assert_eq!(pool.stats().misses, 1);
+
+        // Different process, scheme or port each get their own client
+        pool.client(2, &url("http://example.com/")).unwrap();
+        pool.client(1, &url("https://example.com/")).unwrap();
+        pool.client(1, &url("http://example.com:8080/")).unwrap();
+
+        let stats = pool.stats();
+        assert_eq!(stats.misses, 4);
+        assert_eq!(stats.pooled, 4);
+    }
+
+    #[test]
+    fn test_idle_eviction() {
+        let pool = HttpPool::new(HttpPoolConfig::new().with_idle_timeout(Duration::ZERO));
+
+        pool.client(1, &url("http://example.com/")).unwrap();
+        pool.client(1, &url("http://example.com/")).unwrap();
+
+        let stats = pool.stats();
+        assert_eq!(stats.hits, 0);
+        assert_eq!(stats.misses, 2);
+        assert_eq!(stats.evictions, 1);
+        assert_eq!(stats.pooled, 1);
+    }
+
+    #[test]
+    fn test_release_process() {
+        let pool = HttpPool::default();
+        pool.client(1, &url("http://a.test/")).unwrap();
+        pool.client(1, &url("http://b.test/")).unwrap();
+        pool.client(2, &url("http://a.test/")).unwrap();
+
+        assert_eq!(pool.release_process(1), 2);
+        assert!(!pool.has_process_connections(1));
+        assert!(pool.has_process_connections(2));
+        assert_eq!(pool.stats().pooled, 1);
+    }
+
+    #[test]
+    fn test_connection_reused_across_requests() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let addr = listener.local_addr().unwrap();
+        let accepted = Arc::new(AtomicUsize::new(0));
+
+        let accepted_clone = Arc::clone(&accepted);
+        std::thread::spawn(move || {
+            for stream in listener.incoming() {
+                let Ok(stream) = stream else { break };
+                accepted_clone.fetch_add(1, Ordering::SeqCst);
+                std::thread::spawn(move || {
+                    let mut reader = BufReader::new(stream.try_clone().unwrap());
+                    let mut writer = stream;
+                    let mut line = String::new();
+                    loop {
+                        // Consume one request (headers only; GETs have no body)
+                        loop {
+                            line.clear();
+                            if reader.read_line(&mut line).unwrap_or(0) == 0 {
+                                return;
+                            }
+                            if line == "\r\n" {
+                                break;
+                            }
+                        }
+                        let _ = writer.write_all(
+                            b"HTTP/1.1 200 OK\r\nContent-Length: 2\r\nConnection: keep-alive\r\n\r\nok",
+                        );
+                    }
+                });
+            }
+        });
+
+        let pool = HttpPool::default();
+        let target = url(&format!("http://{}/", addr));
+        for _ in 0..3 {
+            let body = pool
+                .client(7, &target)
+                .unwrap()
+                .get(target.clone())
+                .send()
+                .unwrap();
+            assert_eq!(body.text().unwrap(), "ok");
+        }
+
+        assert_eq!(accepted.load(Ordering::SeqCst), 1);
+        assert_eq!(pool.stats().hits, 2);
+    }
+}
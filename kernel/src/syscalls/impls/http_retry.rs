@@ -0,0 +1,253 @@
+/*!
+ * HTTP Retry
+ * Exponential-backoff retries for transient NetworkRequest failures
+ *
+ * Only failures that say nothing about the request itself are retried:
+ * refused or dropped connections and timeouts. DNS failures and any HTTP
+ * response (including 4xx) are returned on the first attempt. Requests go
+ * through an `HttpTransport` so the retry loop can be driven by a fake in
+ * tests.
+ */
+
+use crate::core::types::Pid;
+use crate::syscalls::types::RetryPolicy;
+use ahash::RandomState;
+use log::debug;
+use reqwest::Url;
+use std::error::Error as _;
+use std::time::{Duration, Instant};
+use thiserror::Error;
+
+use super::http_pool::HttpPool;
+
+/// Delay before retry `retry`, jittered to between half and all of the backoff
+pub fn jittered_backoff(policy: &RetryPolicy, retry: u32) -> Duration {
+    let delay = policy.backoff(retry);
+    if delay.is_zero() {
+        return delay;
+    }
+    // Each RandomState is freshly seeded, which is random enough for jitter
+    let unit = (RandomState::new().hash_one(retry) >> 11) as f64 / (1u64 << 53) as f64;
+    delay.mul_f64(0.5 + unit / 2.0)
+}
+
+/// Response from an HTTP transport
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HttpResponse {
+    pub status: u16,
+    pub body: Vec<u8>,
+}
+
+/// Failure to get any response from the server
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum TransportError {
+    #[error("connection failed: {0}")]
+    Connect(String),
+
+    #[error("timed out: {0}")]
+    Timeout(String),
+
+    #[error("DNS lookup failed: {0}")]
+    Dns(String),
+
+    #[error("{0}")]
+    Other(String),
+}
+
+impl TransportError {
+    /// Whether another attempt might succeed
+    pub fn is_retryable(&self) -> bool {
+        matches!(self, Self::Connect(_) | Self::Timeout(_))
+    }
+}
+
+impl From<reqwest::Error> for TransportError {
+    fn from(err: reqwest::Error) -> Self {
+        let message = err.to_string();
+        if err.is_timeout() {
+            return Self::Timeout(message);
+        }
+        if err.is_connect() {
+            // reqwest wraps resolver failures as connect errors; the cause
+            // chain is the only place they can be told apart
+            let mut source = err.source();
+            while let Some(cause) = source {
+                let text = cause.to_string();
+                if text.contains("dns error") || text.contains("failed to lookup address") {
+                    return Self::Dns(message);
+                }
+                source = cause.source();
+            }
+            return Self::Connect(message);
+        }
+        Self::Other(message)
+    }
+}
+
+/// Performs HTTP GETs for NetworkRequest
+pub trait HttpTransport: Send + Sync {
+    fn get(&self, pid: Pid, url: &Url) -> Result<HttpResponse, TransportError>;
+}
+
+impl HttpTransport for HttpPool {
+    fn get(&self, pid: Pid, url: &Url) -> Result<HttpResponse, TransportError> {
+        let response = self.client(pid, url)?.get(url.clone()).send()?;
+        let status = response.status().as_u16();
+        let body = response.bytes()?.to_vec();
+        Ok(HttpResponse { status, body })
+    }
+}
+
+/// Run `attempt` until it succeeds, fails permanently or retries run out
+///
+/// `on_failure` sees every failed attempt with its retry count (0 for the
+/// first attempt), before any backoff. A retry whose backoff would run past
+/// `deadline` is not attempted; the last error is returned instead.
+pub fn retry_with_backoff<T>(
+    policy: &RetryPolicy,
+    deadline: Option<Instant>,
+    mut attempt: impl FnMut() -> Result<T, TransportError>,
+    mut on_failure: impl FnMut(u32, &TransportError),
+) -> Result<T, TransportError> {
+    let retries = policy.retries();
+    let mut retry = 0;
+    loop {
+        match attempt() {
+            Ok(value) => return Ok(value),
+            Err(err) => {
+                on_failure(retry, &err);
+                if !err.is_retryable() || retry >= retries {
+                    return Err(err);
+                }
+                let delay = jittered_backoff(policy, retry + 1);
+                if deadline.is_some_and(|deadline| Instant::now() + delay >= deadline) {
+                    debug!("Not retrying past deadline after: {}", err);
+                    return Err(err);
+                }
+                retry += 1;
+                debug!("Retry {}/{} in {:?} after: {}", retry, retries, delay, err);
+                std::thread::sleep(delay);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::limits::{NETWORK_RETRY_MAX_DELAY_MS, NETWORK_RETRY_MAX_RETRIES};
+
+    #[test]
+    fn test_backoff_doubles_up_to_cap() {
+        let policy = RetryPolicy::new(5)
+            .with_base_delay(Duration::from_millis(100))
+            .with_max_delay(Duration::from_millis(350));
+
+        assert_eq!(policy.backoff(1), Duration::from_millis(100));
+        assert_eq!(policy.backoff(2), Duration::from_millis(200));
+        assert_eq!(policy.backoff(3), Duration::from_millis(350));
+        assert_eq!(policy.backoff(40), Duration::from_millis(350));
+
+        for retry in 1..=4 {
+            let delay = jittered_backoff(&policy, retry);
+            assert!(delay <= policy.backoff(retry));
+            assert!(delay >= policy.backoff(retry) / 2);
+        }
+    }
+
+    #[test]
+    fn test_retries_transient_failures() {
+        let policy = RetryPolicy::new(3).with_base_delay(Duration::from_millis(1));
+        let mut calls = 0;
+        let mut failures = Vec::new();
+
+        let result = retry_with_backoff(
+            &policy,
+            None,
+            || {
+                calls += 1;
+                if calls <= 2 {
+                    Err(TransportError::Connect("refused".into()))
+                } else {
+                    Ok(calls)
+                }
+            },
+            |retry, _| failures.push(retry),
+        );
+
+        assert_eq!(result, Ok(3));
+        assert_eq!(failures, vec![0, 1]);
+    }
+
+    #[test]
+    fn test_permanent_failures_not_retried() {
+        let policy = RetryPolicy::new(3).with_base_delay(Duration::from_millis(1));
+        let mut calls = 0;
+
+        let result: Result<(), _> = retry_with_backoff(
+            &policy,
+            None,
+            || {
+                calls += 1;
+                Err(TransportError::Dns("NXDOMAIN".into()))
+            },
+            |_, _| {},
+        );
+
+        assert!(matches!(result, Err(TransportError::Dns(_))));
+        assert_eq!(calls, 1);
+    }
+
+    #[test]
+    fn test_gives_up_after_max_retries() {
+        let policy = RetryPolicy::new(2).with_base_delay(Duration::from_millis(1));
+        let mut calls = 0;
+
+        let result: Result<(), _> = retry_with_backoff(
+            &policy,
+            None,
+            || {
+                calls += 1;
+                Err(TransportError::Timeout("slow".into()))
+            },
+            |_, _| {},
+        );
+
+        assert!(matches!(result, Err(TransportError::Timeout(_))));
+        assert_eq!(calls, 3);
+    }
+
+    #[test]
+    fn test_backoff_clamped_to_kernel_max() {
+        let policy = RetryPolicy::new(u32::MAX)
+            .with_base_delay(Duration::from_secs(3600))
+            .with_max_delay(Duration::MAX);
+
+        assert_eq!(policy.retries(), NETWORK_RETRY_MAX_RETRIES);
+        assert_eq!(
+            policy.backoff(1),
+            Duration::from_millis(NETWORK_RETRY_MAX_DELAY_MS)
+        );
+    }
+
+    #[test]
+    fn test_stops_retrying_at_deadline() {
+        let policy = RetryPolicy::new(5).with_base_delay(Duration::from_millis(200));
+        let deadline = Instant::now() + Duration::from_millis(50);
+        let mut calls = 0;
+
+        let result: Result<(), _> = retry_with_backoff(
+            &policy,
+            Some(deadline),
+            || {
+                calls += 1;
+                Err(TransportError::Connect("refused".into()))
+            },
+            |_, _| {},
+        );
+
+        assert!(matches!(result, Err(TransportError::Connect(_))));
+        assert_eq!(calls, 1);
+        assert!(Instant::now() < deadline);
+    }
+}
@@ -6,6 +6,7 @@
 
 use crate::core::serialization::json;
 use crate::core::types::Pid;
+use crate::ipc::{MapFlags, ProtFlags};
 use crate::permissions::{Action, PermissionChecker, PermissionRequest, Resource};
 
 use log::{error, info, warn};
@@ -77,12 +78,15 @@ pub(in crate::syscalls) fn get_process_memory_stats(
 
         let (allocated_bytes, peak_bytes, allocation_count) =
             memory_manager.get_process_memory_details(target_pid);
+        let (private_bytes, shared_bytes) = memory_manager.get_process_sharing_details(target_pid);
 
         let stats = ProcessMemoryStats {
             pid: target_pid,
             allocated_bytes,
             peak_bytes,
             allocation_count,
+            shared_bytes,
+            private_bytes,
         };
 
         match json::to_vec(&stats) {
@@ -97,6 +101,73 @@ pub(in crate::syscalls) fn get_process_memory_stats(
         }
     }
 
+    pub(in crate::syscalls) fn get_memory_maps(&self, pid: Pid, target_pid: Pid) -> SyscallResult {
+        // Block addresses are offsets in the kernel's simulated address space,
+        // not host pointers, so another process's map reveals no more than its
+        // memory stats and is gated the same way
+        if target_pid != pid {
+            let request =
+                PermissionRequest::new(pid, Resource::Process { pid: target_pid }, Action::Inspect);
+            let response = self.permission_manager().check_and_audit(&request);
+
+            if !response.is_allowed() {
+                return SyscallResult::permission_denied(response.reason());
+            }
+        }
+
+        let memory_manager = match &self.optional().memory_manager {
+            Some(mm) => mm,
+            None => return SyscallResult::error("Memory manager not available"),
+        };
+
+        let mut blocks = memory_manager.process_allocations(target_pid);
+        blocks.sort_unstable_by_key(|block| block.address);
+        let mut maps: Vec<_> = blocks
+            .into_iter()
+            .map(|block| {
+                serde_json::json!({
+                    "kind": "anonymous",
+                    "address": block.address,
+                    "size": block.size,
+                    "allocated": block.allocated,
+                    "tag": block.tag,
+                })
+            })
+            .collect();
+
+        // Mappings have no address in the kernel heap; they're keyed by id
+        if let Some(mmap_manager) = &self.ipc().mmap_manager {
+            let mut mappings = mmap_manager.list_mappings(target_pid);
+            mappings.sort_unstable_by_key(|mapping| mapping.id);
+            maps.extend(mappings.into_iter().map(|mapping| {
+                serde_json::json!({
+                    "kind": "file",
+                    "mmap_id": mapping.id,
+                    "size": mapping.length,
+                    "offset": mapping.offset,
+                    "path": mapping.path,
+                    "prot": prot_string(mapping.prot, mapping.flags),
+                })
+            }));
+        }
+
+        match json::to_vec(&maps) {
+            Ok(data) => {
+                info!(
+                    "PID {} read {} memory maps of PID {}",
+                    pid,
+                    maps.len(),
+                    target_pid
+                );
+                SyscallResult::success_with_data(data)
+            }
+            Err(e) => {
+                error!("Failed to serialize memory maps: {}", e);
+                SyscallResult::error("Serialization failed")
+            }
+        }
+    }
+
     pub(in crate::syscalls) fn trigger_gc(
         &self,
         pid: Pid,
@@ -173,4 +244,59 @@ pub(in crate::syscalls) fn trigger_gc(
 
         result.unwrap_or_else(|e| SyscallResult::error(e))
     }
+
+    pub(in crate::syscalls) fn compact_memory(&self, pid: Pid) -> SyscallResult {
+        let request = PermissionRequest::new(
+            pid,
+            Resource::System {
+                name: "memory".into(),
+            },
+            Action::Execute,
+        );
+        let response = self.permission_manager().check_and_audit(&request);
+
+        if !response.is_allowed() {
+            return SyscallResult::permission_denied(response.reason());
+        }
+
+        let memory_manager = match &self.optional().memory_manager {
+            Some(mm) => mm,
+            None => return SyscallResult::error("Memory manager not available"),
+        };
+
+        let reclaimed = memory_manager.compact();
+        let allocator = memory_manager.allocator_stats();
+        info!(
+            "PID {} compacted memory, merged {} bytes ({} free blocks remain)",
+            pid, reclaimed, allocator.free_blocks
+        );
+
+        match json::to_vec(&serde_json::json!({
+            "reclaimed_bytes": reclaimed,
+            "free_blocks": allocator.free_blocks,
+            "free_bytes": allocator.free_bytes
+        })) {
+            Ok(data) => SyscallResult::success_with_data(data),
+            Err(e) => {
+                warn!("Failed to serialize compaction result: {}", e);
+                SyscallResult::error("Internal serialization error")
+            }
+        }
+    }
+}
+
+/// Permission column of `/proc/pid/maps`, e.g. `rw-p` or `r--s`
+fn prot_string(prot: ProtFlags, flags: MapFlags) -> String {
+    let bit = |set: bool, c: char| if set { c } else { '-' };
+    [
+        bit(prot.read, 'r'),
+        bit(prot.write, 'w'),
+        bit(prot.exec, 'x'),
+        match flags {
+            MapFlags::Shared => 's',
+            MapFlags::Private => 'p',
+        },
+    ]
+    .iter()
+    .collect()
 }
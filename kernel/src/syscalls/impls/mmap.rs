@@ -5,15 +5,16 @@
 
 use crate::core::serialization::bincode;
 use crate::core::types::Pid;
-use crate::ipc::{MapFlags, ProtFlags};
+use crate::ipc::{MapFlags, MmapError, ProtFlags};
 use crate::permissions::{PermissionChecker, PermissionRequest};
 use log::{error, info};
 use std::path::PathBuf;
 
 use crate::syscalls::core::executor::SyscallExecutorWithIpc;
-use crate::syscalls::types::SyscallResult;
+use crate::syscalls::types::{SyscallError, SyscallResult};
 
 impl SyscallExecutorWithIpc {
+    #[allow(clippy::too_many_arguments)]
     pub(in crate::syscalls) fn mmap(
         &self,
         pid: Pid,
@@ -22,6 +23,7 @@ pub(in crate::syscalls) fn mmap(
         length: usize,
         prot: u8,
         shared: bool,
+        populate: bool,
     ) -> SyscallResult {
         // Check file read permission using centralized manager
         let path_buf = PathBuf::from(path);
@@ -61,7 +63,15 @@ pub(in crate::syscalls) fn mmap(
             MapFlags::Private
         };
 
-        match mmap_manager.mmap(pid, path.to_string(), offset, length, prot_flags, map_flags) {
+        match mmap_manager.mmap(
+            pid,
+            path.to_string(),
+            offset,
+            length,
+            prot_flags,
+            map_flags,
+            populate,
+        ) {
             Ok(mmap_id) => {
                 info!(
                     "PID {} created mmap {} for file '{}' ({} bytes)",
@@ -75,6 +85,11 @@ pub(in crate::syscalls) fn mmap(
                     }
                 }
             }
+            Err(MmapError::Memory(e)) => {
+                error!("Failed to create mmap for PID {}: {}", pid, e);
+                SyscallResult::error_with_code(format!("Mmap failed: {}", e), e.typed_code())
+            }
+            Err(MmapError::InvalidArgument(msg)) => SyscallError::invalid_argument(msg).into(),
             Err(e) => {
                 error!("Failed to create mmap for PID {}: {}", pid, e);
                 SyscallResult::error(format!("Mmap failed: {}", e))
@@ -2,37 +2,63 @@
  * Syscall Implementations
  *
  * Category-specific syscall implementations for SyscallExecutorWithIpc:
+ * - bandwidth: Per-process token buckets throttling socket sends
+ * - entropy: Rate-limited random bytes from the OS RNG
  * - fd: File descriptor operations
  * - fs: Filesystem operations
  * - handle: Unified file handle abstraction
+ * - hash: In-kernel file content hashing
+ * - http_pool: Keep-alive connection pool for HTTP requests
+ * - http_retry: Backoff retries for transient HTTP failures
  * - memory: Memory management
  * - mmap: Memory-mapped files
  * - network: Network operations
+ * - poll: Readiness multiplexing across descriptors
  * - process: Process management
  * - scheduler: CPU scheduling
  * - signals: Signal handling
  * - system: System information
  * - time: Time and sleep operations
+ * - timerfd: One-shot and periodic timer file descriptors
+ * - transport: Pluggable connection layer for stream sockets
  * - vfs_adapter: VFS integration layer
  */
 
+pub mod bandwidth;
 pub mod clipboard;
+pub mod entropy;
 pub mod fd;
 pub mod fs;
 pub mod handle;
+pub mod hash;
+pub mod http_pool;
+pub mod http_retry;
 pub mod memory;
 pub mod mmap;
 pub mod network;
+pub mod poll;
 pub mod process;
 pub mod scheduler;
 pub mod search;
 pub mod signals;
 pub mod system;
 pub mod time;
+pub mod timerfd;
+pub mod transport;
 pub mod vfs_adapter;
 pub mod watch;
 
 // Re-export commonly used types
-pub use fd::FdManager;
+pub use bandwidth::BandwidthLimit;
+pub use entropy::{EntropySource, GRND_NONBLOCK, GRND_RANDOM};
+pub use fd::{FdManager, O_WRITE_COMBINE};
 pub use handle::FileHandle;
-pub use network::{Socket, SocketManager, SocketStats};
+pub use hash::HashAlgorithm;
+pub use http_pool::{HttpPool, HttpPoolConfig, HttpPoolStats};
+pub use http_retry::{HttpResponse, HttpTransport, TransportError};
+pub use network::{
+    abstract_socket_name, ListenerAcceptStats, ReusePortStats, Socket, SocketManager, SocketStats,
+    ABSTRACT_SOCKET_PREFIX, SOL_SOCKET, SO_MAX_PACING_RATE, SO_REUSEPORT,
+};
+pub use timerfd::TimerFd;
+pub use transport::{SocketTransport, TcpTransport, TransportStream};
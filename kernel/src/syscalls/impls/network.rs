@@ -6,7 +6,7 @@
 
 use crate::syscalls::timeout::executor::TimeoutError;
 
-use crate::core::{serialization::json, types::Pid, PooledBuffer};
+use crate::core::{serialization::json, types::Pid, CircuitBreaker, PooledBuffer};
 use crate::monitoring::span_operation;
 use crate::permissions::{PermissionChecker, PermissionRequest};
 
@@ -14,13 +14,21 @@
 use crossbeam_queue::SegQueue;
 use dashmap::DashMap;
 use log::{error, info, trace, warn};
-use std::collections::HashSet;
-use std::net::{TcpListener, TcpStream, UdpSocket};
+use std::collections::{HashSet, VecDeque};
+use std::io;
+use std::net::{SocketAddr, TcpListener, TcpStream, ToSocketAddrs, UdpSocket};
+use std::os::unix::net::{UnixListener, UnixStream};
 use std::sync::atomic::{AtomicU32, Ordering};
 use std::sync::Arc;
+use std::time::{Duration, Instant};
 
+use super::bandwidth::{BandwidthLimit, Reservation, TokenBucket};
+use super::http_pool::{HttpPool, HttpPoolConfig, HttpPoolStats};
+use super::http_retry::HttpTransport;
+use super::transport::{SocketTransport, TransportStream};
 use crate::syscalls::core::executor::SyscallExecutorWithIpc;
-use crate::syscalls::types::SyscallResult;
+use crate::syscalls::types::{RetryPolicy, SyscallResult};
+use crate::vfs::Readiness;
 
 /// Unified socket abstraction - eliminates need for separate collections per type
 ///
@@ -33,6 +41,10 @@ pub enum Socket {
     TcpListener(TcpListener),
     TcpStream(TcpStream),
     UdpSocket(UdpSocket),
+    /// Listener bound to an abstract `@name`
+    UnixListener(UnixListener),
+    /// Stream opened by an injected `SocketTransport` or on an abstract name
+    Stream(Box<dyn TransportStream>),
 }
 
 impl Socket {
@@ -42,10 +54,226 @@ fn type_name(&self) -> &'static str {
             Socket::TcpListener(_) => "TcpListener",
             Socket::TcpStream(_) => "TcpStream",
             Socket::UdpSocket(_) => "UdpSocket",
+            Socket::UnixListener(_) => "UnixListener",
+            Socket::Stream(_) => "Stream",
+        }
+    }
+
+    /// Connected stream for Send and Recv, if this is one
+    fn stream_mut(&mut self) -> Option<&mut dyn TransportStream> {
+        match self {
+            Socket::TcpStream(stream) => Some(stream),
+            Socket::Stream(stream) => Some(stream.as_mut()),
+            _ => None,
+        }
+    }
+}
+
+/// Zero-timeout poll(2) of the OS socket
+#[cfg(unix)]
+fn socket_readiness(socket: &Socket) -> Readiness {
+    use std::os::fd::AsRawFd;
+
+    let fd = match socket {
+        Socket::TcpListener(listener) => listener.as_raw_fd(),
+        Socket::TcpStream(stream) => stream.as_raw_fd(),
+        Socket::UdpSocket(socket) => socket.as_raw_fd(),
+        Socket::UnixListener(listener) => listener.as_raw_fd(),
+        Socket::Stream(stream) => return stream.readiness(),
+    };
+    poll_readiness(fd)
+}
+
+/// Zero-timeout poll(2) of a raw descriptor
+#[cfg(unix)]
+pub(super) fn poll_readiness(fd: std::os::fd::RawFd) -> Readiness {
+    use nix::libc;
+
+    let mut pollfd = libc::pollfd {
+        fd,
+        events: libc::POLLIN | libc::POLLOUT,
+        revents: 0,
+    };
+    // SAFETY: one valid pollfd, and the socket outlives the call
+    if unsafe { libc::poll(&mut pollfd, 1, 0) } < 0 {
+        return Readiness::default();
+    }
+    Readiness {
+        readable: pollfd.revents & libc::POLLIN != 0,
+        writable: pollfd.revents & libc::POLLOUT != 0,
+        hangup: pollfd.revents & libc::POLLHUP != 0,
+        error: pollfd.revents & libc::POLLERR != 0,
+    }
+}
+
+/// Without poll(2), OS sockets are never reported ready
+#[cfg(not(unix))]
+fn socket_readiness(socket: &Socket) -> Readiness {
+    match socket {
+        Socket::Stream(stream) => stream.readiness(),
+        _ => Readiness::default(),
+    }
+}
+
+/// Socket option level for generic socket options [LINUX-COMPAT]
+pub const SOL_SOCKET: u32 = 1;
+
+/// Allow multiple sockets to bind the same address with load-balanced accept [LINUX-COMPAT]
+pub const SO_REUSEPORT: u32 = 15;
+
+/// Cap outbound bytes per second; applies to every socket of the process [LINUX-COMPAT]
+pub const SO_MAX_PACING_RATE: u32 = 47;
+
+/// Listener sharing a reuseport group
+#[derive(Debug)]
+struct ReusePortMember {
+    sockfd: u32,
+    /// Connections assigned to this listener but not yet accepted
+    pending: VecDeque<(TcpStream, SocketAddr)>,
+    accepted: u64,
+}
+
+impl ReusePortMember {
+    fn new(sockfd: u32) -> Self {
+        Self {
+            sockfd,
+            pending: VecDeque::new(),
+            accepted: 0,
+        }
+    }
+}
+
+/// Listeners bound to one address via SO_REUSEPORT
+///
+/// # Design
+/// One OS listener backs the whole group. Whenever a member accepts, every
+/// connection waiting in the OS backlog is assigned round-robin to a member's
+/// pending queue, so the distribution is fair regardless of which member
+/// calls accept first. A member only ever receives its own connections.
+#[derive(Debug)]
+struct ReusePortGroup {
+    listener: TcpListener,
+    members: Vec<ReusePortMember>,
+    next: usize,
+}
+
+impl ReusePortGroup {
+    fn new(listener: TcpListener, sockfd: u32) -> Self {
+        Self {
+            listener,
+            members: vec![ReusePortMember::new(sockfd)],
+            next: 0,
+        }
+    }
+
+    /// Assign the next connection to a member in round-robin order
+    fn assign(&mut self, connection: (TcpStream, SocketAddr)) {
+        let index = self.next % self.members.len();
+        self.next = self.next.wrapping_add(1);
+        self.members[index].pending.push_back(connection);
+    }
+
+    /// Move all connections waiting in the OS backlog to member queues
+    fn distribute(&mut self) -> io::Result<()> {
+        loop {
+            match self.listener.accept() {
+                Ok((stream, addr)) => {
+                    // Accepted streams may inherit the listener's non-blocking mode
+                    stream.set_nonblocking(false)?;
+                    self.assign((stream, addr));
+                }
+                Err(e) if e.kind() == io::ErrorKind::WouldBlock => return Ok(()),
+                Err(e) => return Err(e),
+            }
+        }
+    }
+
+    /// Take the next connection assigned to a member (WouldBlock if none)
+    fn take(&mut self, sockfd: u32) -> io::Result<(TcpStream, SocketAddr)> {
+        let member = self
+            .members
+            .iter_mut()
+            .find(|m| m.sockfd == sockfd)
+            .ok_or_else(|| io::Error::from(io::ErrorKind::NotFound))?;
+        let connection = member
+            .pending
+            .pop_front()
+            .ok_or_else(|| io::Error::from(io::ErrorKind::WouldBlock))?;
+        member.accepted += 1;
+        Ok(connection)
+    }
+
+    /// Remove a member, handing its queued connections to the remaining members
+    fn leave(&mut self, sockfd: u32) {
+        if let Some(index) = self.members.iter().position(|m| m.sockfd == sockfd) {
+            let member = self.members.remove(index);
+            if !self.members.is_empty() {
+                for connection in member.pending {
+                    self.assign(connection);
+                }
+            }
         }
     }
 }
 
+/// Prefix marking an address as a name in the abstract socket namespace
+pub const ABSTRACT_SOCKET_PREFIX: char = '@';
+
+/// Name in an `@name` address, or None for a network address
+pub fn abstract_socket_name(address: &str) -> Option<&str> {
+    address
+        .strip_prefix(ABSTRACT_SOCKET_PREFIX)
+        .filter(|name| !name.is_empty())
+}
+
+/// Listener registered under an abstract name
+///
+/// # Design
+/// Binding `@name` opens a Linux abstract-namespace unix socket, so nothing
+/// is created in the filesystem and the OS frees the name when the listener
+/// closes. The registry records which kernel socket holds each name: Connect
+/// only reaches names bound through this kernel, never host sockets that
+/// happen to share the namespace, and process exit releases the name.
+#[derive(Debug, Clone, Copy)]
+struct AbstractBinding {
+    pid: Pid,
+    sockfd: u32,
+}
+
+/// OS address for an abstract name
+#[cfg(target_os = "linux")]
+fn abstract_socket_addr(name: &str) -> io::Result<std::os::unix::net::SocketAddr> {
+    use std::os::linux::net::SocketAddrExt;
+    std::os::unix::net::SocketAddr::from_abstract_name(name.as_bytes())
+}
+
+/// Only Linux has an abstract socket namespace
+#[cfg(not(target_os = "linux"))]
+fn abstract_socket_addr(name: &str) -> io::Result<std::os::unix::net::SocketAddr> {
+    Err(io::Error::new(
+        io::ErrorKind::Unsupported,
+        format!(
+            "{}{} needs Linux abstract sockets",
+            ABSTRACT_SOCKET_PREFIX, name
+        ),
+    ))
+}
+
+/// Per-listener accept statistics within a reuseport group
+#[derive(Debug, Clone)]
+pub struct ListenerAcceptStats {
+    pub sockfd: u32,
+    pub accepted: u64,
+    pub pending: usize,
+}
+
+/// Accept distribution for one SO_REUSEPORT address
+#[derive(Debug, Clone)]
+pub struct ReusePortStats {
+    pub address: SocketAddr,
+    pub listeners: Vec<ListenerAcceptStats>,
+}
+
 /// Socket manager for tracking open sockets
 ///
 /// # Design Philosophy
@@ -68,6 +296,24 @@ pub struct SocketManager {
     process_sockets: Arc<DashMap<Pid, HashSet<u32>, RandomState>>,
     /// Lock-free queue for FD recycling (prevents FD exhaustion)
     free_fds: Arc<SegQueue<u32>>,
+    /// Sockets with SO_REUSEPORT set, mapped to their group address once bound
+    reuseport_fds: Arc<DashMap<u32, Option<SocketAddr>, RandomState>>,
+    /// Reuseport groups keyed by bound address
+    reuseport_groups: Arc<DashMap<SocketAddr, ReusePortGroup, RandomState>>,
+    /// Keep-alive clients for NetworkRequest
+    http_pool: HttpPool,
+    /// Transport overriding the pool for NetworkRequest
+    http_transport: Option<Arc<dyn HttpTransport>>,
+    /// Transport overriding the OS TCP stack for Connect
+    transport: Option<Arc<dyn SocketTransport>>,
+    /// Per-host circuits for NetworkRequest
+    circuit: CircuitBreaker,
+    /// Per-process default retry policies for NetworkRequest
+    retry_policies: Arc<DashMap<Pid, RetryPolicy, RandomState>>,
+    /// Per-process outbound bandwidth buckets for Send and SendTo
+    bandwidth: Arc<DashMap<Pid, TokenBucket, RandomState>>,
+    /// Listeners bound to `@name` addresses, keyed by name
+    abstract_names: Arc<DashMap<String, AbstractBinding, RandomState>>,
 }
 
 impl SocketManager {
@@ -81,6 +327,130 @@ pub fn new() -> Self {
             sockets: Arc::new(DashMap::with_hasher(RandomState::new().into())),
             process_sockets: Arc::new(DashMap::with_hasher(RandomState::new().into())),
             free_fds: Arc::new(SegQueue::new().into()),
+            reuseport_fds: Arc::new(DashMap::with_hasher(RandomState::new())),
+            reuseport_groups: Arc::new(DashMap::with_hasher(RandomState::new())),
+            http_pool: HttpPool::default(),
+            http_transport: None,
+            transport: None,
+            circuit: CircuitBreaker::default(),
+            retry_policies: Arc::new(DashMap::with_hasher(RandomState::new())),
+            bandwidth: Arc::new(DashMap::with_hasher(RandomState::new())),
+            abstract_names: Arc::new(DashMap::with_hasher(RandomState::new())),
+        }
+    }
+
+    /// Replace the HTTP connection pool configuration
+    ///
+    /// Pooled clients are discarded.
+    pub fn with_http_pool_config(mut self, config: HttpPoolConfig) -> Self {
+        self.http_pool = HttpPool::new(config);
+        self
+    }
+
+    /// Keep-alive pool used by NetworkRequest
+    pub fn http_pool(&self) -> &HttpPool {
+        &self.http_pool
+    }
+
+    /// Send NetworkRequest through `transport` instead of the pool
+    pub fn with_http_transport(mut self, transport: Arc<dyn HttpTransport>) -> Self {
+        self.http_transport = Some(transport);
+        self
+    }
+
+    /// Transport used by NetworkRequest
+    pub fn http_transport(&self) -> &dyn HttpTransport {
+        match &self.http_transport {
+            Some(transport) => transport.as_ref(),
+            None => &self.http_pool,
+        }
+    }
+
+    /// Fail NetworkRequest fast on hosts that keep failing, per `breaker`
+    pub fn with_circuit_breaker(mut self, breaker: CircuitBreaker) -> Self {
+        self.circuit = breaker;
+        self
+    }
+
+    /// Per-host circuit breaker for NetworkRequest
+    pub fn circuit_breaker(&self) -> &CircuitBreaker {
+        &self.circuit
+    }
+
+    /// Open Connect streams through `transport` instead of the OS TCP stack
+    pub fn with_transport(mut self, transport: Arc<dyn SocketTransport>) -> Self {
+        self.transport = Some(transport);
+        self
+    }
+
+    /// Connect a stream to `address` through the configured transport
+    fn open_stream(&self, address: &str) -> io::Result<Socket> {
+        match &self.transport {
+            Some(transport) => transport.connect(address).map(Socket::Stream),
+            None => TcpStream::connect(address).map(Socket::TcpStream),
+        }
+    }
+
+    /// Set the retry policy NetworkRequest uses for `pid` when none is given
+    pub fn set_retry_policy(&self, pid: Pid, policy: RetryPolicy) {
+        self.retry_policies.insert(pid, policy);
+    }
+
+    /// Default retry policy for `pid` (no retries unless one was set)
+    pub fn retry_policy(&self, pid: Pid) -> RetryPolicy {
+        self.retry_policies
+            .get(&pid)
+            .map(|policy| *policy)
+            .unwrap_or_default()
+    }
+
+    /// Limit outbound Send/SendTo traffic for `pid`, replacing any earlier limit
+    pub fn set_bandwidth_limit(&self, pid: Pid, limit: BandwidthLimit) {
+        self.bandwidth
+            .insert(pid, TokenBucket::new(limit, Instant::now()));
+    }
+
+    /// Remove the bandwidth limit for `pid`
+    pub fn clear_bandwidth_limit(&self, pid: Pid) {
+        self.bandwidth.remove(&pid);
+    }
+
+    /// Current bandwidth limit for `pid`, if any
+    pub fn bandwidth_limit(&self, pid: Pid) -> Option<BandwidthLimit> {
+        self.bandwidth.get(&pid).map(|bucket| bucket.limit())
+    }
+
+    /// Charge `bytes` against `pid`'s bandwidth limit, held to `ceiling`
+    ///
+    /// A finite ceiling limits the process even if it never set a pacing
+    /// rate. Returns None if the process is unlimited.
+    pub(in crate::syscalls) fn reserve_bandwidth(
+        &self,
+        pid: Pid,
+        bytes: usize,
+        ceiling: u64,
+    ) -> Option<Reservation> {
+        let now = Instant::now();
+        let mut bucket = match self.bandwidth.get_mut(&pid) {
+            Some(bucket) => bucket,
+            None if ceiling == u64::MAX => return None,
+            None => self
+                .bandwidth
+                .entry(pid)
+                .or_insert_with(|| TokenBucket::new(BandwidthLimit::new(ceiling), now)),
+        };
+        if bucket.limit().bytes_per_sec > ceiling {
+            bucket.cap(ceiling, now);
+        }
+        Some(bucket.reserve(bytes, now))
+    }
+
+    /// Return charged bytes that were not actually sent
+    pub(in crate::syscalls) fn refund_bandwidth(&self, pid: Pid, bytes: usize) {
+        if bytes > 0 {
+            if let Some(mut bucket) = self.bandwidth.get_mut(&pid) {
+                bucket.refund(bytes);
+            }
         }
     }
 
@@ -110,6 +480,54 @@ pub fn get_socket_count(&self, pid: Pid) -> u32 {
             .unwrap_or(0)
     }
 
+    /// Duplicate the handle of a connected TCP stream
+    ///
+    /// Returns None if `sockfd` is not a TCP stream. The clone shares the
+    /// socket, so blocking I/O through it doesn't keep the map shard locked.
+    pub(in crate::syscalls) fn clone_stream(
+        &self,
+        sockfd: u32,
+    ) -> Option<std::io::Result<TcpStream>> {
+        let socket = self.sockets.get(&sockfd)?;
+        match socket.value() {
+            Socket::TcpStream(stream) => Some(stream.try_clone()),
+            _ => None,
+        }
+    }
+
+    /// Check whether a socket is ready for I/O without blocking
+    ///
+    /// Returns None if `sockfd` is not an open socket.
+    pub(in crate::syscalls) fn readiness(&self, sockfd: u32) -> Option<Readiness> {
+        let socket = self.sockets.get(&sockfd)?;
+        Some(socket_readiness(socket.value()))
+    }
+
+    /// OS descriptor behind a socket, for blocking in poll(2)
+    ///
+    /// None for unknown sockets and for injected streams without one.
+    pub(in crate::syscalls) fn raw_fd(&self, sockfd: u32) -> Option<std::os::fd::RawFd> {
+        use std::os::fd::AsRawFd;
+
+        let socket = self.sockets.get(&sockfd)?;
+        match socket.value() {
+            Socket::TcpListener(listener) => Some(listener.as_raw_fd()),
+            Socket::TcpStream(stream) => Some(stream.as_raw_fd()),
+            Socket::UdpSocket(socket) => Some(socket.as_raw_fd()),
+            Socket::UnixListener(listener) => Some(listener.as_raw_fd()),
+            Socket::Stream(stream) => stream.raw_fd(),
+        }
+    }
+
+    /// Register an already-open socket for a process
+    #[cfg(test)]
+    pub(in crate::syscalls) fn insert(&self, pid: Pid, socket: Socket) -> u32 {
+        let sockfd = self.allocate_fd();
+        self.track_socket(pid, sockfd);
+        self.sockets.insert(sockfd, socket);
+        sockfd
+    }
+
     /// Track that a process owns a socket
     fn track_socket(&self, pid: Pid, sockfd: u32) {
         self.process_sockets
@@ -137,19 +555,27 @@ pub fn cleanup_process_sockets(&self, pid: Pid) -> usize {
         let _guard = span.enter();
         span.record("pid", &format!("{}", pid));
 
+        // Pooled HTTP connections belong to the process too
+        let pooled_count = self.http_pool.release_process(pid);
+        self.retry_policies.remove(&pid);
+        self.bandwidth.remove(&pid);
+        self.abstract_names.retain(|_, binding| binding.pid != pid);
+
         // Remove all socket FDs owned by this process (atomic operation)
         let sockets_to_close = if let Some((_, sockets)) = self.process_sockets.remove(&pid) {
             sockets
         } else {
             trace!("No sockets to cleanup for PID {}", pid);
-            span.record("closed_count", "0");
+            span.record("closed_count", &format!("{}", pooled_count));
             span.record_result(true);
-            return 0;
+            return pooled_count;
         };
 
         let socket_count = sockets_to_close.len();
         let mut closed_count = 0;
         for sockfd in sockets_to_close {
+            self.leave_reuseport(sockfd);
+
             // Single lookup in unified collection (no type map needed)
             if let Some((_, socket)) = self.sockets.remove(&sockfd) {
                 closed_count += 1;
@@ -166,17 +592,18 @@ pub fn cleanup_process_sockets(&self, pid: Pid) -> usize {
             "Cleaned up {}/{} sockets for PID {}",
             closed_count, socket_count, pid
         );
-        span.record("closed_count", &format!("{}", closed_count));
+        span.record("closed_count", &format!("{}", closed_count + pooled_count));
         span.record_result(true);
-        closed_count
+        closed_count + pooled_count
     }
 
-    /// Check if process has any open sockets (O(1) check)
+    /// Check if process has any open sockets or pooled HTTP connections
     pub fn has_process_sockets(&self, pid: Pid) -> bool {
         self.process_sockets
             .get(&pid)
             .map(|sockets| !sockets.is_empty())
             .unwrap_or(false)
+            || self.http_pool.has_process_connections(pid)
     }
 
     /// Get socket statistics
@@ -189,8 +616,10 @@ pub fn stats(&self) -> SocketStats {
         for entry in self.sockets.iter() {
             match entry.value() {
                 Socket::TcpListener(_) => tcp_listeners += 1,
-                Socket::TcpStream(_) => tcp_streams += 1,
+                Socket::TcpStream(_) | Socket::Stream(_) => tcp_streams += 1,
                 Socket::UdpSocket(_) => udp_sockets += 1,
+                // Counted by abstract_names
+                Socket::UnixListener(_) => {}
             }
         }
 
@@ -199,7 +628,194 @@ pub fn stats(&self) -> SocketStats {
             total_tcp_streams: tcp_streams,
             total_udp_sockets: udp_sockets,
             recycled_fds_available: self.free_fds.len(),
+            reuseport_groups: self.reuseport_groups.len(),
+            abstract_names: self.abstract_names.len(),
+            http_pool: self.http_pool.stats(),
+        }
+    }
+
+    /// Apply SO_MAX_PACING_RATE set through one of `pid`'s sockets
+    ///
+    /// The limit covers the whole process and never exceeds `ceiling`, so a
+    /// process can only lower its rate. Zero or all ones asks for no limit,
+    /// which leaves just the ceiling. Returns the rate granted.
+    pub fn set_pacing_rate(
+        &self,
+        pid: Pid,
+        sockfd: u32,
+        rate: u64,
+        ceiling: u64,
+    ) -> Result<u64, &'static str> {
+        let owned = self
+            .process_sockets
+            .get(&pid)
+            .is_some_and(|sockets| sockets.contains(&sockfd));
+        if !owned {
+            return Err("Invalid socket descriptor");
+        }
+
+        let requested = if rate == 0 || rate == u32::MAX as u64 {
+            u64::MAX
+        } else {
+            rate
+        };
+        let granted = requested.min(ceiling);
+        if granted == u64::MAX {
+            self.clear_bandwidth_limit(pid);
+        } else {
+            self.set_bandwidth_limit(pid, BandwidthLimit::new(granted));
+        }
+        Ok(granted)
+    }
+
+    /// Enable or disable SO_REUSEPORT on a socket (TCP only, must happen before bind)
+    pub fn set_reuseport(&self, pid: Pid, sockfd: u32, enable: bool) -> Result<(), &'static str> {
+        let owned = self
+            .process_sockets
+            .get(&pid)
+            .is_some_and(|sockets| sockets.contains(&sockfd));
+        if !owned {
+            return Err("Invalid socket descriptor");
+        }
+        if self.sockets.contains_key(&sockfd) {
+            return Err("SO_REUSEPORT must be set before bind");
+        }
+
+        if enable {
+            self.reuseport_fds.insert(sockfd, None);
+        } else {
+            self.reuseport_fds.remove(&sockfd);
+        }
+        Ok(())
+    }
+
+    /// Check whether SO_REUSEPORT is set on a socket
+    pub fn reuseport_enabled(&self, sockfd: u32) -> bool {
+        self.reuseport_fds.contains_key(&sockfd)
+    }
+
+    /// Bind a reuseport socket, joining an existing group for the address if present
+    ///
+    /// Returns the number of listeners in the group after binding.
+    fn bind_reuseport(&self, sockfd: u32, address: &str) -> io::Result<usize> {
+        let addrs: Vec<SocketAddr> = address.to_socket_addrs()?.collect();
+
+        for addr in &addrs {
+            if let Some(mut group) = self.reuseport_groups.get_mut(addr) {
+                let handle = group.listener.try_clone()?;
+                group.members.push(ReusePortMember::new(sockfd));
+                let size = group.members.len();
+                drop(group);
+
+                self.sockets.insert(sockfd, Socket::TcpListener(handle));
+                self.reuseport_fds.insert(sockfd, Some(*addr));
+                return Ok(size);
+            }
+        }
+
+        let listener = TcpListener::bind(&addrs[..])?;
+        listener.set_nonblocking(true)?;
+        let local_addr = listener.local_addr()?;
+        let handle = listener.try_clone()?;
+
+        self.reuseport_groups
+            .insert(local_addr, ReusePortGroup::new(listener, sockfd));
+        self.sockets.insert(sockfd, Socket::TcpListener(handle));
+        self.reuseport_fds.insert(sockfd, Some(local_addr));
+        Ok(1)
+    }
+
+    /// Accept the next connection assigned to a reuseport member
+    ///
+    /// Returns None if the socket is not part of a reuseport group.
+    fn accept_reuseport(&self, sockfd: u32) -> Option<io::Result<(TcpStream, SocketAddr)>> {
+        let addr = (*self.reuseport_fds.get(&sockfd)?)?;
+        let mut group = self.reuseport_groups.get_mut(&addr)?;
+
+        Some(group.distribute().and_then(|_| group.take(sockfd)))
+    }
+
+    /// Remove a socket from its reuseport group, dropping the group when empty
+    fn leave_reuseport(&self, sockfd: u32) {
+        let Some((_, Some(addr))) = self.reuseport_fds.remove(&sockfd) else {
+            return;
+        };
+
+        let now_empty = self.reuseport_groups.get_mut(&addr).map(|mut group| {
+            group.leave(sockfd);
+            group.members.is_empty()
+        });
+        if now_empty == Some(true) {
+            self.reuseport_groups
+                .remove_if(&addr, |_, group| group.members.is_empty());
+            trace!("Closed reuseport group for {}", addr);
+        }
+    }
+
+    /// Bind a listener for `pid` under an abstract name
+    ///
+    /// Fails with `AddrInUse` if another socket, in this kernel or on the
+    /// host, already holds the name.
+    fn bind_abstract(&self, pid: Pid, sockfd: u32, name: &str) -> io::Result<()> {
+        use dashmap::mapref::entry::Entry;
+
+        let Entry::Vacant(entry) = self.abstract_names.entry(name.to_string()) else {
+            return Err(io::Error::new(
+                io::ErrorKind::AddrInUse,
+                format!("{}{} is already bound", ABSTRACT_SOCKET_PREFIX, name),
+            ));
+        };
+
+        let listener = UnixListener::bind_addr(&abstract_socket_addr(name)?)?;
+        self.sockets.insert(sockfd, Socket::UnixListener(listener));
+        entry.insert(AbstractBinding { pid, sockfd });
+        Ok(())
+    }
+
+    /// Connect to the listener bound to an abstract name
+    ///
+    /// Fails with `ConnectionRefused` unless the name was bound through this
+    /// kernel.
+    fn connect_abstract(&self, name: &str) -> io::Result<Socket> {
+        if !self.abstract_names.contains_key(name) {
+            return Err(io::Error::new(
+                io::ErrorKind::ConnectionRefused,
+                format!("nothing bound to {}{}", ABSTRACT_SOCKET_PREFIX, name),
+            ));
         }
+        let stream = UnixStream::connect_addr(&abstract_socket_addr(name)?)?;
+        Ok(Socket::Stream(Box::new(stream)))
+    }
+
+    /// Release any abstract name held by a socket
+    fn release_abstract(&self, sockfd: u32) {
+        self.abstract_names
+            .retain(|_, binding| binding.sockfd != sockfd);
+    }
+
+    /// Process holding an abstract name, if it is bound
+    pub fn abstract_owner(&self, name: &str) -> Option<Pid> {
+        self.abstract_names.get(name).map(|binding| binding.pid)
+    }
+
+    /// Get per-listener accept counts for every reuseport group
+    pub fn reuseport_stats(&self) -> Vec<ReusePortStats> {
+        self.reuseport_groups
+            .iter()
+            .map(|entry| ReusePortStats {
+                address: *entry.key(),
+                listeners: entry
+                    .value()
+                    .members
+                    .iter()
+                    .map(|m| ListenerAcceptStats {
+                        sockfd: m.sockfd,
+                        accepted: m.accepted,
+                        pending: m.pending.len(),
+                    })
+                    .collect(),
+            })
+            .collect()
     }
 }
 
@@ -210,6 +826,9 @@ pub struct SocketStats {
     pub total_tcp_streams: usize,
     pub total_udp_sockets: usize,
     pub recycled_fds_available: usize,
+    pub reuseport_groups: usize,
+    pub abstract_names: usize,
+    pub http_pool: HttpPoolStats,
 }
 
 impl SocketStats {
@@ -226,6 +845,15 @@ fn clone(&self) -> Self {
             sockets: Arc::clone(&self.sockets),
             process_sockets: Arc::clone(&self.process_sockets),
             free_fds: Arc::clone(&self.free_fds),
+            reuseport_fds: Arc::clone(&self.reuseport_fds),
+            reuseport_groups: Arc::clone(&self.reuseport_groups),
+            http_pool: self.http_pool.clone(),
+            http_transport: self.http_transport.clone(),
+            transport: self.transport.clone(),
+            circuit: self.circuit.clone(),
+            retry_policies: Arc::clone(&self.retry_policies),
+            bandwidth: Arc::clone(&self.bandwidth),
+            abstract_names: Arc::clone(&self.abstract_names),
         }
     }
 }
@@ -328,6 +956,7 @@ pub(in crate::syscalls) fn bind(&self, pid: Pid, sockfd: u32, address: &str) ->
         span.record("address", address);
 
         // Parse host:port from address and check bind permission
+        // (an abstract `@name` is checked as a host without a port)
         use crate::permissions::{Action, Resource};
         let parts: Vec<&str> = address.split(':').collect();
         let host = parts.get(0).unwrap_or(&"").to_string();
@@ -341,6 +970,45 @@ pub(in crate::syscalls) fn bind(&self, pid: Pid, sockfd: u32, address: &str) ->
             return SyscallResult::permission_denied(response.reason());
         }
 
+        if let Some(name) = abstract_socket_name(address) {
+            return match self.socket_manager().bind_abstract(pid, sockfd, name) {
+                Ok(()) => {
+                    info!("PID {} bound unix socket {} to {}", pid, sockfd, address);
+                    span.record("socket_type", "UNIX");
+                    span.record_result(true);
+                    SyscallResult::success()
+                }
+                Err(e) => {
+                    warn!("Failed to bind socket {} to {}: {}", sockfd, address, e);
+                    span.record_error(&format!("Bind failed: {}", e));
+                    SyscallResult::error(format!("Bind failed: {}", e))
+                }
+            };
+        }
+
+        // SO_REUSEPORT sockets share one listener per address
+        if self.socket_manager().reuseport_enabled(sockfd) {
+            return match self.socket_manager().bind_reuseport(sockfd, address) {
+                Ok(listeners) => {
+                    info!(
+                        "PID {} bound reuseport TCP socket {} to {} ({} listeners)",
+                        pid, sockfd, address, listeners
+                    );
+                    span.record("socket_type", "TCP");
+                    span.record_result(true);
+                    SyscallResult::success()
+                }
+                Err(e) => {
+                    warn!(
+                        "Failed to bind reuseport socket {} to {}: {}",
+                        sockfd, address, e
+                    );
+                    span.record_error(&format!("Bind failed: {}", e));
+                    SyscallResult::error(format!("Bind failed: {}", e))
+                }
+            };
+        }
+
         // Try to bind a TCP listener
         match TcpListener::bind(address) {
             Ok(listener) => {
@@ -401,10 +1069,10 @@ pub(in crate::syscalls) fn listen(&self, pid: Pid, sockfd: u32, backlog: u32) ->
             return SyscallResult::permission_denied(response.reason());
         }
 
-        // Verify socket exists and is a TCP listener
+        // Verify socket exists and is a listener
         if let Some(socket) = self.socket_manager().sockets.get(&sockfd) {
             match socket.value() {
-                Socket::TcpListener(_) => {
+                Socket::TcpListener(_) | Socket::UnixListener(_) => {
                     info!(
                         "PID {} listening on socket {} with backlog {}",
                         pid, sockfd, backlog
@@ -456,13 +1124,23 @@ enum AcceptError {
 
         let result = self.timeout_executor().execute_with_retry(
             || {
+                if let Some(result) = self.socket_manager().accept_reuseport(sockfd) {
+                    return match result {
+                        Ok((stream, addr)) => Ok((Socket::TcpStream(stream), addr.to_string())),
+                        Err(ref e) if e.kind() == std::io::ErrorKind::WouldBlock => {
+                            Err(AcceptError::NoPendingConnections)
+                        }
+                        Err(e) => Err(AcceptError::Other(e.to_string())),
+                    };
+                }
+
                 if let Some(socket) = self.socket_manager().sockets.get(&sockfd) {
                     match socket.value() {
                         Socket::TcpListener(listener) => {
                             match listener.accept() {
                                 Ok((stream, addr)) => {
                                     drop(socket); // Release lock before returning
-                                    Ok((stream, addr))
+                                    Ok((Socket::TcpStream(stream), addr.to_string()))
                                 }
                                 Err(ref e) if e.kind() == std::io::ErrorKind::WouldBlock => {
                                     Err(AcceptError::NoPendingConnections)
@@ -470,6 +1148,18 @@ enum AcceptError {
                                 Err(e) => Err(AcceptError::Other(e.to_string().into())),
                             }
                         }
+                        Socket::UnixListener(listener) => match listener.accept() {
+                            // Abstract-name clients connect unnamed
+                            Ok((stream, _)) => {
+                                drop(socket); // Release lock before returning
+                                let stream: Box<dyn TransportStream> = Box::new(stream);
+                                Ok((Socket::Stream(stream), "unix".to_string()))
+                            }
+                            Err(ref e) if e.kind() == std::io::ErrorKind::WouldBlock => {
+                                Err(AcceptError::NoPendingConnections)
+                            }
+                            Err(e) => Err(AcceptError::Other(e.to_string())),
+                        },
                         _ => Err(AcceptError::NotListener),
                     }
                 } else {
@@ -485,9 +1175,7 @@ enum AcceptError {
             Ok((stream, addr)) => {
                 // Allocate new FD for the accepted connection
                 let client_fd = self.socket_manager().allocate_fd();
-                self.socket_manager()
-                    .sockets
-                    .insert(client_fd, Socket::TcpStream(stream));
+                self.socket_manager().sockets.insert(client_fd, stream);
 
                 // Track the new client socket for this process
                 self.socket_manager().track_socket(pid, client_fd);
@@ -497,12 +1185,12 @@ enum AcceptError {
                     pid, sockfd, client_fd, addr
                 );
                 span.record("client_fd", &format!("{}", client_fd));
-                span.record("client_address", &addr.to_string());
+                span.record("client_address", &addr);
                 span.record_result(true);
 
                 match json::to_vec(&serde_json::json!({
                     "client_fd": client_fd,
-                    "address": addr.to_string()
+                    "address": addr
                 })) {
                     Ok(data) => SyscallResult::success_with_data(data),
                     Err(e) => {
@@ -565,18 +1253,18 @@ pub(in crate::syscalls) fn connect(
         }
 
         // Use timeout executor for blocking connect
-        let address_owned = address.to_string();
         let result = self.timeout_executor().execute_with_deadline(
-            || TcpStream::connect(&address_owned),
+            || match abstract_socket_name(address) {
+                Some(name) => self.socket_manager().connect_abstract(name),
+                None => self.socket_manager().open_stream(address),
+            },
             self.timeout_config().network,
             "socket_connect",
         );
 
         match result {
-            Ok(stream) => {
-                self.socket_manager()
-                    .sockets
-                    .insert(sockfd, Socket::TcpStream(stream));
+            Ok(socket) => {
+                self.socket_manager().sockets.insert(sockfd, socket);
                 info!("PID {} connected socket {} to {}", pid, sockfd, address);
                 span.record_result(true);
                 SyscallResult::success()
@@ -597,6 +1285,64 @@ pub(in crate::syscalls) fn connect(
         }
     }
 
+    /// Wait until `pid`'s bandwidth limit allows sending `bytes`
+    ///
+    /// Fails without charging the process if the wait would outlast the
+    /// network timeout.
+    fn throttle_send(&self, pid: Pid, bytes: usize) -> Result<(), String> {
+        let ceiling = self.sandbox_manager().pacing_rate_ceiling(pid);
+        let Some(reservation) = self.socket_manager().reserve_bandwidth(pid, bytes, ceiling) else {
+            return Ok(());
+        };
+        if reservation.delay.is_zero() {
+            return Ok(());
+        }
+
+        if let Some(timeout) = self.timeout_config().network.duration() {
+            if reservation.delay > timeout {
+                self.socket_manager().refund_bandwidth(pid, bytes);
+                return Err(format!(
+                    "Send would block: bandwidth limit needs {}ms",
+                    reservation.delay.as_millis()
+                ));
+            }
+        }
+
+        if reservation.throttling_started {
+            self.emit_network_throttled(pid, reservation.delay);
+        }
+        trace!(
+            "Throttling PID {} for {:?} to send {} bytes",
+            pid,
+            reservation.delay,
+            bytes
+        );
+        std::thread::sleep(reservation.delay);
+        Ok(())
+    }
+
+    fn emit_network_throttled(&self, pid: Pid, delay: Duration) {
+        let Some(ref collector) = self.optional().collector else {
+            return;
+        };
+        let Some(limit) = self.socket_manager().bandwidth_limit(pid) else {
+            return;
+        };
+
+        use crate::monitoring::{Category, Event, Payload, Severity};
+        collector.emit(
+            Event::new(
+                Severity::Info,
+                Category::Network,
+                Payload::NetworkThrottled {
+                    bytes_per_sec: limit.bytes_per_sec,
+                    delay_ms: delay.as_millis() as u64,
+                },
+            )
+            .with_pid(pid),
+        );
+    }
+
     pub(in crate::syscalls) fn send(
         &self,
         pid: Pid,
@@ -612,8 +1358,6 @@ pub(in crate::syscalls) fn send(
 
         // Send on existing connection - permissions already checked at connect/accept time
 
-        use std::io::Write;
-
         #[derive(Debug)]
         enum SendError {
             WouldBlock,
@@ -622,6 +1366,11 @@ enum SendError {
             Other(String),
         }
 
+        if let Err(e) = self.throttle_send(pid, data.len()) {
+            span.record_error(&e);
+            return SyscallResult::error(e);
+        }
+
         let mut data_buf = PooledBuffer::get(data.len());
         data_buf.extend_from_slice(data);
         let data_to_send = data_buf.into_vec();
@@ -629,15 +1378,15 @@ enum SendError {
         let result = self.timeout_executor().execute_with_retry(
             || {
                 if let Some(mut socket) = self.socket_manager().sockets.get_mut(&sockfd) {
-                    match socket.value_mut() {
-                        Socket::TcpStream(stream) => match stream.write(&data_to_send) {
+                    match socket.value_mut().stream_mut() {
+                        Some(stream) => match stream.send(&data_to_send) {
                             Ok(bytes_sent) => Ok(bytes_sent),
                             Err(ref e) if e.kind() == std::io::ErrorKind::WouldBlock => {
                                 Err(SendError::WouldBlock)
                             }
                             Err(e) => Err(SendError::Other(e.to_string().into())),
                         },
-                        _ => Err(SendError::NotStream),
+                        None => Err(SendError::NotStream),
                     }
                 } else {
                     Err(SendError::InvalidSocket)
@@ -648,6 +1397,10 @@ enum SendError {
             "socket_send",
         );
 
+        // Short or failed writes only pay for what went out
+        let unsent = data.len() - result.as_ref().map_or(0, |&bytes_sent| bytes_sent);
+        self.socket_manager().refund_bandwidth(pid, unsent);
+
         match result {
             Ok(bytes_sent) => {
                 info!(
@@ -708,8 +1461,6 @@ pub(in crate::syscalls) fn recv(
 
         // Receive on existing connection - permissions already checked at connect/accept time
 
-        use std::io::Read;
-
         #[derive(Debug)]
         enum RecvError {
             WouldBlock,
@@ -721,11 +1472,11 @@ enum RecvError {
         let result = self.timeout_executor().execute_with_retry(
             || {
                 if let Some(mut socket) = self.socket_manager().sockets.get_mut(&sockfd) {
-                    match socket.value_mut() {
-                        Socket::TcpStream(stream) => {
+                    match socket.value_mut().stream_mut() {
+                        Some(stream) => {
                             let mut buffer = PooledBuffer::get(size);
                             buffer.resize(size, 0);
-                            match stream.read(&mut buffer) {
+                            match stream.recv(&mut buffer) {
                                 Ok(bytes_read) => {
                                     buffer.truncate(bytes_read);
                                     Ok(buffer.into_vec())
@@ -736,7 +1487,7 @@ enum RecvError {
                                 Err(e) => Err(RecvError::Other(e.to_string().into())),
                             }
                         }
-                        _ => Err(RecvError::NotStream),
+                        None => Err(RecvError::NotStream),
                     }
                 } else {
                     Err(RecvError::InvalidSocket)
@@ -815,6 +1566,12 @@ pub(in crate::syscalls) fn sendto(
             return SyscallResult::permission_denied(response.reason());
         }
 
+        // Datagrams can't be split, so the whole payload is charged
+        if let Err(e) = self.throttle_send(pid, data.len()) {
+            span.record_error(&e);
+            return SyscallResult::error(e);
+        }
+
         // Try to send via UDP socket
         if let Some(socket) = self.socket_manager().sockets.get(&sockfd) {
             match socket.value() {
@@ -926,6 +1683,9 @@ pub(in crate::syscalls) fn close_socket(&self, pid: Pid, sockfd: u32) -> Syscall
         // Close doesn't require permission check - closing is always allowed
 
         // Single lookup in unified collection
+        self.socket_manager().leave_reuseport(sockfd);
+        self.socket_manager().release_abstract(sockfd);
+
         if let Some((_, socket)) = self.socket_manager().sockets.remove(&sockfd) {
             // Untrack socket from process (O(1) with HashSet)
             self.socket_manager().untrack_socket(pid, sockfd);
@@ -956,7 +1716,7 @@ pub(in crate::syscalls) fn setsockopt(
         sockfd: u32,
         level: u32,
         optname: u32,
-        _optval: &[u8],
+        optval: &[u8],
     ) -> SyscallResult {
         let span = span_operation("socket_setsockopt");
         let _guard = span.enter();
@@ -967,6 +1727,54 @@ pub(in crate::syscalls) fn setsockopt(
 
         // Socket options on existing socket - permissions checked at creation time
 
+        if level == SOL_SOCKET && optname == SO_REUSEPORT {
+            let enable = optval.iter().any(|&b| b != 0);
+            return match self.socket_manager().set_reuseport(pid, sockfd, enable) {
+                Ok(()) => {
+                    info!(
+                        "PID {} set SO_REUSEPORT={} on socket {}",
+                        pid, enable, sockfd
+                    );
+                    span.record_result(true);
+                    SyscallResult::success()
+                }
+                Err(e) => {
+                    span.record_error(e);
+                    SyscallResult::error(e)
+                }
+            };
+        }
+
+        if level == SOL_SOCKET && optname == SO_MAX_PACING_RATE {
+            // Little-endian u32 or u64 bytes/sec, as Linux accepts either width
+            let rate = match optval.len() {
+                4 => u32::from_le_bytes(optval.try_into().unwrap_or_default()) as u64,
+                8 => u64::from_le_bytes(optval.try_into().unwrap_or_default()),
+                _ => {
+                    span.record_error("Invalid option length");
+                    return SyscallResult::error("SO_MAX_PACING_RATE expects a 4 or 8 byte value");
+                }
+            };
+            let ceiling = self.sandbox_manager().pacing_rate_ceiling(pid);
+            return match self
+                .socket_manager()
+                .set_pacing_rate(pid, sockfd, rate, ceiling)
+            {
+                Ok(granted) => {
+                    info!(
+                        "PID {} set SO_MAX_PACING_RATE={} (granted {}) via socket {}",
+                        pid, rate, granted, sockfd
+                    );
+                    span.record_result(true);
+                    SyscallResult::success()
+                }
+                Err(e) => {
+                    span.record_error(e);
+                    SyscallResult::error(e)
+                }
+            };
+        }
+
         warn!(
             "SetSockOpt syscall not fully implemented: sockfd={}, level={}, optname={}",
             sockfd, level, optname
@@ -992,13 +1800,23 @@ pub(in crate::syscalls) fn getsockopt(
 
         // Socket options on existing socket - permissions checked at creation time
 
-        warn!(
-            "GetSockOpt syscall not fully implemented: sockfd={}, level={}, optname={}",
-            sockfd, level, optname
-        );
+        let value = if level == SOL_SOCKET && optname == SO_REUSEPORT {
+            self.socket_manager().reuseport_enabled(sockfd) as u64
+        } else if level == SOL_SOCKET && optname == SO_MAX_PACING_RATE {
+            self.socket_manager()
+                .bandwidth_limit(pid)
+                .map_or(u64::MAX, |limit| limit.bytes_per_sec)
+                .min(self.sandbox_manager().pacing_rate_ceiling(pid))
+        } else {
+            warn!(
+                "GetSockOpt syscall not fully implemented: sockfd={}, level={}, optname={}",
+                sockfd, level, optname
+            );
+            0
+        };
 
         let result = match json::to_vec(&serde_json::json!({
-            "value": 0
+            "value": value
         })) {
             Ok(data) => data,
             Err(e) => {
@@ -1013,3 +1831,386 @@ pub(in crate::syscalls) fn getsockopt(
         SyscallResult::success_with_data(result)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Register a socket for a process and bind it with SO_REUSEPORT
+    fn reuseport_listener(manager: &SocketManager, pid: Pid, address: &str) -> u32 {
+        let sockfd = manager.allocate_fd();
+        manager.track_socket(pid, sockfd);
+        manager.set_reuseport(pid, sockfd, true).unwrap();
+        manager.bind_reuseport(sockfd, address).unwrap();
+        sockfd
+    }
+
+    fn accept_stats(manager: &SocketManager, sockfd: u32) -> (u64, usize) {
+        let stats = manager.reuseport_stats();
+        let listener = stats[0]
+            .listeners
+            .iter()
+            .find(|l| l.sockfd == sockfd)
+            .unwrap();
+        (listener.accepted, listener.pending)
+    }
+
+    #[test]
+    fn test_reuseport_round_robin_accept() {
+        let manager = SocketManager::new();
+        let first = reuseport_listener(&manager, 10, "127.0.0.1:0");
+        let address = manager.reuseport_stats()[0].address;
+        let second = reuseport_listener(&manager, 11, &address.to_string());
+        assert_eq!(manager.stats().reuseport_groups, 1);
+        assert_eq!(manager.stats().total_tcp_listeners, 2);
+
+        let _clients: Vec<TcpStream> = (0..4)
+            .map(|_| TcpStream::connect(address).unwrap())
+            .collect();
+
+        // The first accept distributes the whole backlog, so each listener
+        // only receives its own share regardless of who accepts first
+        for _ in 0..2 {
+            assert!(manager.accept_reuseport(first).unwrap().is_ok());
+        }
+        let err = manager.accept_reuseport(first).unwrap().unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::WouldBlock);
+        assert_eq!(accept_stats(&manager, first), (2, 0));
+        assert_eq!(accept_stats(&manager, second), (0, 2));
+
+        for _ in 0..2 {
+            assert!(manager.accept_reuseport(second).unwrap().is_ok());
+        }
+        assert_eq!(accept_stats(&manager, second), (2, 0));
+    }
+
+    #[test]
+    fn test_reuseport_leave_hands_off_pending() {
+        let manager = SocketManager::new();
+        let first = reuseport_listener(&manager, 20, "127.0.0.1:0");
+        let address = manager.reuseport_stats()[0].address;
+        let second = reuseport_listener(&manager, 21, &address.to_string());
+
+        let _clients: Vec<TcpStream> = (0..2)
+            .map(|_| TcpStream::connect(address).unwrap())
+            .collect();
+
+        assert!(manager.accept_reuseport(first).unwrap().is_ok());
+        assert_eq!(accept_stats(&manager, second), (0, 1));
+
+        // Closing a member moves its queued connection to the survivors
+        assert_eq!(manager.cleanup_process_sockets(21), 1);
+        assert_eq!(accept_stats(&manager, first), (1, 1));
+        assert!(manager.accept_reuseport(first).unwrap().is_ok());
+
+        // Last member leaving drops the group
+        assert_eq!(manager.cleanup_process_sockets(20), 1);
+        assert!(manager.reuseport_stats().is_empty());
+        assert!(manager.accept_reuseport(first).is_none());
+    }
+
+    #[test]
+    fn test_reuseport_must_be_set_before_bind() {
+        let manager = SocketManager::new();
+        let sockfd = reuseport_listener(&manager, 30, "127.0.0.1:0");
+
+        assert!(manager.reuseport_enabled(sockfd));
+        assert!(manager.set_reuseport(30, sockfd, false).is_err());
+        assert!(manager.set_reuseport(31, sockfd, true).is_err());
+    }
+
+    /// Abstract names are host-wide, so tests must not share them
+    #[cfg(target_os = "linux")]
+    fn unique_abstract_name(tag: &str) -> String {
+        format!("ai-os-test-{}-{}", std::process::id(), tag)
+    }
+
+    #[test]
+    #[cfg(target_os = "linux")]
+    fn test_abstract_names_rendezvous_and_release() {
+        assert_eq!(abstract_socket_name("@service"), Some("service"));
+        assert_eq!(abstract_socket_name("@"), None);
+        assert_eq!(abstract_socket_name("127.0.0.1:80"), None);
+
+        let name = unique_abstract_name("rendezvous");
+        let manager = SocketManager::new();
+        let server = manager.allocate_fd();
+        manager.track_socket(50, server);
+        manager.bind_abstract(50, server, &name).unwrap();
+        assert_eq!(manager.abstract_owner(&name), Some(50));
+
+        // Names are unique regardless of which process asks
+        let rival = manager.allocate_fd();
+        manager.track_socket(51, rival);
+        let err = manager.bind_abstract(51, rival, &name).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::AddrInUse);
+
+        // A client connecting by name reaches the bound listener
+        let _client = manager.connect_abstract(&name).unwrap();
+        let accepted = match manager.sockets.get(&server).unwrap().value() {
+            Socket::UnixListener(listener) => listener.accept(),
+            other => panic!("expected listener, got {:?}", other),
+        };
+        assert!(accepted.is_ok());
+
+        // Exit releases the name, in the registry and the OS, for others
+        assert_eq!(manager.cleanup_process_sockets(50), 1);
+        let err = manager.connect_abstract(&name).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::ConnectionRefused);
+        assert!(manager.bind_abstract(51, rival, &name).is_ok());
+        manager.release_abstract(rival);
+        assert_eq!(manager.stats().abstract_names, 0);
+    }
+
+    #[test]
+    #[cfg(target_os = "linux")]
+    fn test_abstract_bind_connect_syscalls() {
+        use crate::ipc::{PipeManager, ShmManager};
+        use crate::memory::MemoryManager;
+        use crate::security::traits::SandboxProvider;
+        use crate::security::{SandboxConfig, SandboxManager};
+        use crate::syscalls::Syscall;
+
+        let memory_manager = MemoryManager::new();
+        let sandbox_manager = SandboxManager::new();
+        let executor = SyscallExecutorWithIpc::with_ipc_direct(
+            sandbox_manager.clone(),
+            PipeManager::new(memory_manager.clone()),
+            ShmManager::new(memory_manager),
+        );
+        sandbox_manager.create_sandbox(SandboxConfig::privileged(60));
+        sandbox_manager.create_sandbox(SandboxConfig::privileged(61));
+
+        let open_socket = |pid: Pid| -> u32 {
+            let result = executor.execute(
+                pid,
+                Syscall::Socket {
+                    domain: 1,
+                    socket_type: 1,
+                    protocol: 0,
+                },
+            );
+            let SyscallResult::Success { data: Some(data) } = result else {
+                panic!("socket failed: {:?}", result);
+            };
+            let value: serde_json::Value = serde_json::from_slice(&data).unwrap();
+            value["sockfd"].as_u64().unwrap() as u32
+        };
+        let address = format!("@{}", unique_abstract_name("syscalls"));
+
+        let server = open_socket(60);
+        let bind = |pid: Pid, sockfd: u32| {
+            executor.execute(
+                pid,
+                Syscall::Bind {
+                    sockfd,
+                    address: address.clone(),
+                },
+            )
+        };
+        assert!(bind(60, server).is_success());
+        assert!(executor
+            .execute(
+                60,
+                Syscall::Listen {
+                    sockfd: server,
+                    backlog: 1
+                }
+            )
+            .is_success());
+
+        // A second bind of the same name fails
+        let rival = open_socket(61);
+        assert!(!bind(61, rival).is_success());
+
+        // The client talks to the server over a real unix socket
+        let client = open_socket(61);
+        assert!(executor
+            .execute(
+                61,
+                Syscall::Connect {
+                    sockfd: client,
+                    address: address.clone(),
+                }
+            )
+            .is_success());
+        let result = executor.execute(60, Syscall::Accept { sockfd: server });
+        let SyscallResult::Success { data: Some(data) } = result else {
+            panic!("accept failed: {:?}", result);
+        };
+        let value: serde_json::Value = serde_json::from_slice(&data).unwrap();
+        let accepted = value["client_fd"].as_u64().unwrap() as u32;
+
+        assert!(executor
+            .execute(
+                61,
+                Syscall::Send {
+                    sockfd: client,
+                    data: b"hello".to_vec(),
+                    flags: 0,
+                }
+            )
+            .is_success());
+        let result = executor.execute(
+            60,
+            Syscall::Recv {
+                sockfd: accepted,
+                size: 16,
+                flags: 0,
+            },
+        );
+        let SyscallResult::Success { data: Some(data) } = result else {
+            panic!("recv failed: {:?}", result);
+        };
+        assert_eq!(data, b"hello");
+
+        // Connecting to a name nobody bound is refused
+        assert!(!executor
+            .execute(
+                61,
+                Syscall::Connect {
+                    sockfd: rival,
+                    address: "@ai-os-test-unbound".to_string(),
+                }
+            )
+            .is_success());
+    }
+
+    #[test]
+    fn test_pacing_rate_cannot_exceed_sandbox_ceiling() {
+        use crate::ipc::{PipeManager, ShmManager};
+        use crate::memory::MemoryManager;
+        use crate::security::traits::SandboxProvider;
+        use crate::security::{SandboxConfig, SandboxManager};
+
+        let memory_manager = MemoryManager::new();
+        let sandbox_manager = SandboxManager::new();
+        let executor = SyscallExecutorWithIpc::with_ipc_direct(
+            sandbox_manager.clone(),
+            PipeManager::new(memory_manager.clone()),
+            ShmManager::new(memory_manager),
+        );
+        sandbox_manager.create_sandbox(SandboxConfig::standard(42));
+        sandbox_manager.set_pacing_rate_ceiling(42, 50_000).unwrap();
+
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let client = TcpStream::connect(listener.local_addr().unwrap()).unwrap();
+        let sockets = executor.socket_manager();
+        let sockfd = sockets.insert(42, Socket::TcpStream(client));
+        let set_rate = |rate: u64| {
+            executor
+                .setsockopt(
+                    42,
+                    sockfd,
+                    SOL_SOCKET,
+                    SO_MAX_PACING_RATE,
+                    &rate.to_le_bytes(),
+                )
+                .is_success()
+        };
+
+        // Asking for more than the ceiling, or for no limit, gets the ceiling
+        assert!(set_rate(100_000));
+        assert_eq!(
+            sockets.bandwidth_limit(42),
+            Some(BandwidthLimit::new(50_000))
+        );
+        assert!(set_rate(0));
+        assert_eq!(
+            sockets.bandwidth_limit(42),
+            Some(BandwidthLimit::new(50_000))
+        );
+
+        // Lowering is always allowed
+        assert!(set_rate(10_000));
+        assert_eq!(
+            sockets.bandwidth_limit(42),
+            Some(BandwidthLimit::new(10_000))
+        );
+        let result = executor.getsockopt(42, sockfd, SOL_SOCKET, SO_MAX_PACING_RATE);
+        let SyscallResult::Success { data: Some(data) } = result else {
+            panic!("getsockopt failed: {:?}", result);
+        };
+        let value: serde_json::Value = serde_json::from_slice(&data).unwrap();
+        assert_eq!(value["value"], 10_000);
+    }
+
+    #[test]
+    fn test_ceiling_limits_sends_without_pacing_rate() {
+        let manager = SocketManager::new();
+
+        // Uncapped processes stay unlimited
+        assert!(manager.reserve_bandwidth(43, 100, u64::MAX).is_none());
+        assert!(manager.bandwidth_limit(43).is_none());
+
+        // A ceiling applies even if the process never set a rate
+        assert!(manager.reserve_bandwidth(44, 100, 1_000).is_some());
+        assert_eq!(
+            manager.bandwidth_limit(44),
+            Some(BandwidthLimit::new(1_000))
+        );
+
+        // A ceiling lowered after the fact caps the existing bucket
+        manager.set_bandwidth_limit(45, BandwidthLimit::new(10_000));
+        assert!(manager.reserve_bandwidth(45, 100, 2_000).is_some());
+        assert_eq!(
+            manager.bandwidth_limit(45),
+            Some(BandwidthLimit::new(2_000))
+        );
+    }
+
+    #[test]
+    fn test_send_is_throttled_to_bandwidth_limit() {
+        use crate::ipc::{PipeManager, ShmManager};
+        use crate::memory::MemoryManager;
+        use crate::security::SandboxManager;
+        use std::io::Read;
+
+        let memory_manager = MemoryManager::new();
+        let executor = SyscallExecutorWithIpc::with_ipc_direct(
+            SandboxManager::new(),
+            PipeManager::new(memory_manager.clone()),
+            ShmManager::new(memory_manager),
+        );
+
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let client = TcpStream::connect(listener.local_addr().unwrap()).unwrap();
+        let (mut server, _) = listener.accept().unwrap();
+        let reader = std::thread::spawn(move || {
+            let mut received = Vec::new();
+            server.read_to_end(&mut received).unwrap();
+            received.len()
+        });
+
+        let sockets = executor.socket_manager();
+        let sockfd = sockets.insert(40, Socket::TcpStream(client));
+        let rate = 100_000u64;
+        assert!(executor
+            .setsockopt(
+                40,
+                sockfd,
+                SOL_SOCKET,
+                SO_MAX_PACING_RATE,
+                &rate.to_le_bytes()
+            )
+            .is_success());
+        assert_eq!(sockets.bandwidth_limit(40), Some(BandwidthLimit::new(rate)));
+        sockets.set_bandwidth_limit(40, BandwidthLimit::new(rate).with_burst(10_000));
+
+        // 60 KB at 100 KB/s with a 10 KB burst takes at least half a second
+        let chunk = vec![0u8; 10_000];
+        let started = Instant::now();
+        for _ in 0..6 {
+            assert!(executor.send(40, sockfd, &chunk, 0).is_success());
+        }
+        let elapsed = started.elapsed();
+        assert!(elapsed >= Duration::from_millis(450), "{:?}", elapsed);
+
+        let effective = 50_000.0 / elapsed.as_secs_f64();
+        assert!(effective <= rate as f64 * 1.1, "{} bytes/sec", effective);
+
+        assert_eq!(sockets.cleanup_process_sockets(40), 1);
+        assert!(sockets.bandwidth_limit(40).is_none());
+        assert_eq!(reader.join().unwrap(), 60_000);
+    }
+}
@@ -0,0 +1,339 @@
+/*!
+ * Poll Syscall
+ * Wait for I/O readiness across a set of descriptors
+ *
+ * Descriptors resolve through the FdManager first and the SocketManager
+ * second, as in sendfile, so files, pipes, event fds and sockets are polled
+ * alike. A blocked poll makes one poll(2) call over the OS sockets it
+ * watches plus a readiness waiter registered under the keys of the pipes,
+ * signalfds and completion fds it watches, so any watched source ends the
+ * sleep and changes to unwatched ones don't. Timerfds bound the sleep by
+ * their next expiration. Injected streams with no OS descriptor fall back
+ * to re-checking every `POLL_RECHECK_INTERVAL`. No poll blocks longer than
+ * `MAX_POLL_TIMEOUT`.
+ */
+
+use crate::core::limits::{MAX_POLL_FDS, MAX_POLL_TIMEOUT, POLL_RECHECK_INTERVAL};
+use crate::core::serialization::json;
+use crate::core::sync::readiness::{ReadinessKey, ReadinessWaiter};
+use crate::core::types::Pid;
+use crate::monitoring::span_operation;
+use crate::syscalls::core::executor::SyscallExecutorWithIpc;
+use crate::syscalls::types::{PollFd, SyscallResult};
+use crate::vfs::Readiness;
+
+use log::warn;
+use nix::libc;
+use std::os::fd::RawFd;
+use std::time::{Duration, Instant};
+
+/// Translate readiness into poll(2) return events
+///
+/// POLLIN and POLLOUT are only reported when requested; POLLERR and POLLHUP
+/// always are.
+fn to_revents(events: u16, readiness: Readiness) -> u16 {
+    let mut revents = 0;
+    if readiness.readable {
+        revents |= PollFd::POLLIN;
+    }
+    if readiness.writable {
+        revents |= PollFd::POLLOUT;
+    }
+    revents &= events;
+    if readiness.error {
+        revents |= PollFd::POLLERR;
+    }
+    if readiness.hangup {
+        revents |= PollFd::POLLHUP;
+    }
+    revents
+}
+
+/// Translate requested poll events into poll(2) events
+fn to_os_events(events: u16) -> i16 {
+    let mut os_events = 0;
+    if events & PollFd::POLLIN != 0 {
+        os_events |= libc::POLLIN;
+    }
+    if events & PollFd::POLLOUT != 0 {
+        os_events |= libc::POLLOUT;
+    }
+    os_events
+}
+
+/// What a blocked poll can wait on besides the readiness waiter
+#[derive(Default)]
+struct WaitSources {
+    /// OS descriptors with the poll(2) events requested on them
+    os_fds: Vec<(RawFd, i16)>,
+    /// In-kernel sources whose readiness changes wake the waiter
+    keys: Vec<ReadinessKey>,
+    /// Time until the earliest watched timerfd fires
+    next_timer: Option<Duration>,
+    /// Whether some watched stream can only be re-checked
+    unwaitable: bool,
+}
+
+impl SyscallExecutorWithIpc {
+    /// Wait until any descriptor in `fds` is ready
+    ///
+    /// Returns the ready descriptors with `revents` filled in, or an empty
+    /// list if `timeout_ms` elapses first. A missing or longer timeout is cut
+    /// to `MAX_POLL_TIMEOUT`. Unknown descriptors are reported ready with
+    /// POLLNVAL.
+    pub(in crate::syscalls) fn poll(
+        &self,
+        pid: Pid,
+        fds: &[PollFd],
+        timeout_ms: Option<u64>,
+    ) -> SyscallResult {
+        let span = span_operation("fd_poll");
+        let _guard = span.enter();
+        span.record("pid", &format!("{}", pid));
+        span.record("nfds", &format!("{}", fds.len()));
+
+        if fds.len() > MAX_POLL_FDS {
+            span.record_error("Too many descriptors");
+            return SyscallResult::error(format!(
+                "Too many descriptors: {} (max {})",
+                fds.len(),
+                MAX_POLL_FDS
+            ));
+        }
+
+        let timeout = timeout_ms.map_or(MAX_POLL_TIMEOUT, |ms| {
+            Duration::from_millis(ms).min(MAX_POLL_TIMEOUT)
+        });
+        let deadline = Instant::now() + timeout;
+        let mut waiter: Option<ReadinessWaiter> = None;
+        let mut registered = false;
+
+        let ready = loop {
+            let (ready, sources) = self.poll_ready(fds);
+            if !ready.is_empty() {
+                break ready;
+            }
+
+            let left = deadline.saturating_duration_since(Instant::now());
+            if left.is_zero() {
+                break ready;
+            }
+
+            // Register lazily so non-blocking polls skip it, then re-check
+            // so a change made before registering isn't slept through
+            if !registered {
+                registered = true;
+                waiter = ReadinessWaiter::register(&sources.keys)
+                    .map_err(|e| warn!("Poll falling back to re-checking: {}", e))
+                    .ok();
+                continue;
+            }
+
+            let mut slice = left;
+            if let Some(next_timer) = sources.next_timer {
+                slice = slice.min(next_timer);
+            }
+            match &waiter {
+                Some(waiter) => {
+                    if sources.unwaitable {
+                        slice = slice.min(POLL_RECHECK_INTERVAL);
+                    }
+                    waiter.wait(&sources.os_fds, slice);
+                }
+                None => std::thread::sleep(slice.min(POLL_RECHECK_INTERVAL)),
+            }
+        };
+
+        span.record("ready", &format!("{}", ready.len()));
+        span.record_result(true);
+        match json::to_vec(&ready) {
+            Ok(data) => SyscallResult::success_with_data(data),
+            Err(e) => {
+                warn!("Failed to serialize poll result: {}", e);
+                span.record_error("Serialization failed");
+                SyscallResult::error("Internal serialization error")
+            }
+        }
+    }
+
+    /// Check every descriptor once
+    ///
+    /// Also returns what the caller can wait on if nothing is ready.
+    fn poll_ready(&self, fds: &[PollFd]) -> (Vec<PollFd>, WaitSources) {
+        let mut ready = Vec::new();
+        let mut sources = WaitSources::default();
+
+        for pollfd in fds {
+            let revents = match self.fd_manager().get_handle(pollfd.fd) {
+                Some(handle) => {
+                    if let Some(key) = handle.readiness_key() {
+                        sources.keys.push(key);
+                    }
+                    if let Some(left) = handle.timerfd().and_then(|timer| timer.remaining()) {
+                        sources.next_timer =
+                            Some(sources.next_timer.map_or(left, |next| next.min(left)));
+                    }
+                    to_revents(pollfd.events, handle.readiness())
+                }
+                None => match self.socket_manager().readiness(pollfd.fd) {
+                    Some(readiness) => {
+                        match self.socket_manager().raw_fd(pollfd.fd) {
+                            Some(fd) => sources.os_fds.push((fd, to_os_events(pollfd.events))),
+                            None => sources.unwaitable = true,
+                        }
+                        to_revents(pollfd.events, readiness)
+                    }
+                    None => PollFd::POLLNVAL,
+                },
+            };
+
+            if revents != 0 {
+                ready.push(PollFd { revents, ..*pollfd });
+            }
+        }
+
+        (ready, sources)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ipc::{PipeManager, ShmManager};
+    use crate::memory::MemoryManager;
+    use crate::security::SandboxManager;
+    use crate::syscalls::impls::Socket;
+    use std::net::TcpListener;
+
+    fn poll(executor: &SyscallExecutorWithIpc, fds: &[PollFd], timeout_ms: u64) -> Vec<PollFd> {
+        match executor.poll(1, fds, Some(timeout_ms)) {
+            SyscallResult::Success { data: Some(data) } => json::from_slice(&data).unwrap(),
+            other => panic!("Expected poll to succeed, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_poll_readable_pipe_and_idle_socket() {
+        let memory_manager = MemoryManager::new();
+        let executor = SyscallExecutorWithIpc::with_ipc_direct(
+            SandboxManager::new(),
+            PipeManager::new(memory_manager.clone()),
+            ShmManager::new(memory_manager),
+        );
+
+        let pipes = executor.ipc().pipe_manager();
+        let pipe_id = pipes.create(1, 1, None).unwrap();
+        pipes.write(pipe_id, 1, b"ping").unwrap();
+        let pipe_fd = executor.pipe_fd(1, pipe_id).unwrap();
+
+        // Listener with no pending connections never becomes readable
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let sockfd = executor
+            .socket_manager()
+            .insert(1, Socket::TcpListener(listener));
+
+        let fds = [
+            PollFd::new(pipe_fd, PollFd::POLLIN),
+            PollFd::new(sockfd, PollFd::POLLIN),
+        ];
+        let ready = poll(&executor, &fds, 1000);
+        assert_eq!(
+            ready,
+            vec![PollFd {
+                revents: PollFd::POLLIN,
+                ..fds[0]
+            }]
+        );
+
+        let started = Instant::now();
+        assert!(poll(&executor, &fds[1..], 30).is_empty());
+        assert!(started.elapsed() >= Duration::from_millis(30));
+    }
+
+    #[test]
+    fn test_blocked_poll_wakes_on_any_watched_pipe() {
+        let memory_manager = MemoryManager::new();
+        let executor = SyscallExecutorWithIpc::with_ipc_direct(
+            SandboxManager::new(),
+            PipeManager::new(memory_manager.clone()),
+            ShmManager::new(memory_manager),
+        );
+
+        let pipes = executor.ipc().pipe_manager();
+        let first = pipes.create(1, 1, None).unwrap();
+        let second = pipes.create(1, 1, None).unwrap();
+        let fds = [
+            PollFd::new(executor.pipe_fd(1, first).unwrap(), PollFd::POLLIN),
+            PollFd::new(executor.pipe_fd(1, second).unwrap(), PollFd::POLLIN),
+        ];
+
+        let pipes = pipes.clone();
+        let writer = std::thread::spawn(move || {
+            std::thread::sleep(Duration::from_millis(50));
+            pipes.write(second, 1, b"late").unwrap();
+        });
+
+        // Only the second pipe becomes ready, well before the timeout
+        let started = Instant::now();
+        let ready = poll(&executor, &fds, 10_000);
+        writer.join().unwrap();
+        assert!(started.elapsed() < Duration::from_secs(5));
+        assert_eq!(
+            ready,
+            vec![PollFd {
+                revents: PollFd::POLLIN,
+                ..fds[1]
+            }]
+        );
+    }
+
+    #[test]
+    fn test_closed_pipe_reports_error_to_writer() {
+        let memory_manager = MemoryManager::new();
+        let executor = SyscallExecutorWithIpc::with_ipc_direct(
+            SandboxManager::new(),
+            PipeManager::new(memory_manager.clone()),
+            ShmManager::new(memory_manager),
+        );
+
+        let pipes = executor.ipc().pipe_manager();
+        let pipe_id = pipes.create(1, 1, None).unwrap();
+        let fds = [PollFd::new(
+            executor.pipe_fd(1, pipe_id).unwrap(),
+            PollFd::POLLOUT,
+        )];
+        pipes.close(pipe_id, 1).unwrap();
+
+        let ready = poll(&executor, &fds, 1000);
+        assert_eq!(
+            ready,
+            vec![PollFd {
+                revents: PollFd::POLLERR | PollFd::POLLHUP,
+                ..fds[0]
+            }]
+        );
+    }
+
+    #[test]
+    fn test_revents_masks_unrequested_events() {
+        let both = Readiness::READ_WRITE;
+        assert_eq!(to_revents(PollFd::POLLIN, both), PollFd::POLLIN);
+        assert_eq!(
+            to_revents(PollFd::POLLIN | PollFd::POLLOUT, both),
+            PollFd::POLLIN | PollFd::POLLOUT
+        );
+
+        let hangup = Readiness {
+            hangup: true,
+            ..Readiness::default()
+        };
+        assert_eq!(to_revents(PollFd::POLLOUT, hangup), PollFd::POLLHUP);
+
+        let error = Readiness {
+            error: true,
+            ..Readiness::default()
+        };
+        assert_eq!(to_revents(PollFd::POLLIN, error), PollFd::POLLERR);
+        assert_eq!(to_revents(PollFd::POLLIN, Readiness::default()), 0);
+    }
+}
@@ -10,13 +10,27 @@
 use crate::core::types::{Pid, Priority};
 use crate::monitoring::span_operation;
 use crate::permissions::{Action, PermissionChecker, PermissionRequest, Resource};
+use crate::process::ProcessInfo;
+use crate::scheduler::{nice_to_priority, priority_to_nice};
 use log::{error, info, warn};
+use serde::Serialize;
+use std::path::Path;
 use std::process::Command;
 
-use crate::security::{ResourceLimitProvider, SandboxProvider};
+use crate::security::ResourceLimitProvider;
 
 use crate::syscalls::core::executor::SyscallExecutorWithIpc;
 use crate::syscalls::types::{ProcessOutput, SyscallResult};
+use crate::vfs::VfsError;
+
+/// Process info with its niceness and OOM score adjustment
+#[derive(Serialize)]
+struct ProcessInfoView {
+    #[serde(flatten)]
+    process: ProcessInfo,
+    nice: i8,
+    oom_score_adj: i16,
+}
 
 impl SyscallExecutorWithIpc {
     pub(in crate::syscalls) fn spawn_process(
@@ -53,6 +67,18 @@ pub(in crate::syscalls) fn spawn_process(
             }
         }
 
+        // Bare command names are looked up on the host PATH; only absolute
+        // paths can land on a VFS mount
+        if let Some(vfs) = &self.optional().vfs {
+            if Path::new(command).is_absolute() {
+                if let Err(e @ VfsError::PermissionDenied(_)) = vfs.check_exec(command) {
+                    warn!("PID {} refused exec from noexec mount: {}", pid, command);
+                    span.record_error("Exec refused: noexec mount");
+                    return SyscallResult::permission_denied(e.to_string());
+                }
+            }
+        }
+
         if let Some(limits) = self.sandbox_manager().get_limits(pid) {
             if !self.sandbox_manager().can_spawn_process(pid) {
                 let current = self.sandbox_manager().get_spawn_count(pid);
@@ -118,7 +144,7 @@ pub(in crate::syscalls) fn kill_process(&self, pid: Pid, target_pid: Pid) -> Sys
             return SyscallResult::permission_denied(response.reason());
         }
 
-        self.sandbox_manager().remove_sandbox(target_pid);
+        self.release_process_state(target_pid);
 
         info!(
             "PID {} terminated PID {} and cleaned up sandbox",
@@ -152,7 +178,11 @@ pub(in crate::syscalls) fn get_process_info(&self, pid: Pid, target_pid: Pid) ->
         };
 
         match process_manager.get_process(target_pid) {
-            Some(process) => match json::to_vec(&process) {
+            Some(process) => match json::to_vec(&ProcessInfoView {
+                nice: priority_to_nice(process.priority),
+                oom_score_adj: self.oom_score_adj(target_pid),
+                process,
+            }) {
                 Ok(data) => {
                     info!("PID {} retrieved info for PID {}", pid, target_pid);
                     span.record_result(true);
@@ -171,6 +201,58 @@ pub(in crate::syscalls) fn get_process_info(&self, pid: Pid, target_pid: Pid) ->
         }
     }
 
+    pub(in crate::syscalls) fn get_process_children(
+        &self,
+        pid: Pid,
+        target_pid: Pid,
+    ) -> SyscallResult {
+        let span = span_operation("process_get_children");
+        let _guard = span.enter();
+        span.record("pid", &format!("{}", pid));
+        span.record("target_pid", &format!("{}", target_pid));
+
+        let request =
+            PermissionRequest::new(pid, Resource::Process { pid: target_pid }, Action::Inspect);
+        let response = self.permission_manager().check(&request);
+
+        if !response.is_allowed() {
+            span.record_error(response.reason());
+            return SyscallResult::permission_denied(response.reason());
+        }
+
+        let process_manager = match &self.optional().process_manager {
+            Some(pm) => pm,
+            None => {
+                span.record_error("Process manager not available");
+                return SyscallResult::error("Process manager not available");
+            }
+        };
+
+        if process_manager.get_process(target_pid).is_none() {
+            span.record_error(&format!("Process {} not found", target_pid));
+            return SyscallResult::error(format!("Process {} not found", target_pid));
+        }
+
+        let children = process_manager.get_children(target_pid);
+        match json::to_vec(&children) {
+            Ok(data) => {
+                info!(
+                    "PID {} listed {} children of PID {}",
+                    pid,
+                    children.len(),
+                    target_pid
+                );
+                span.record_result(true);
+                SyscallResult::success_with_data(data)
+            }
+            Err(e) => {
+                error!("Failed to serialize process children: {}", e);
+                span.record_error("Serialization failed");
+                SyscallResult::error("Serialization failed")
+            }
+        }
+    }
+
     pub(in crate::syscalls) fn get_process_list(&self, pid: Pid) -> SyscallResult {
         let span = span_operation("process_list");
         let _guard = span.enter();
@@ -233,18 +315,140 @@ pub(in crate::syscalls) fn set_process_priority(
             None => return SyscallResult::error("Process manager not available"),
         };
 
+        // Requests above the target's sandbox ceiling are clamped, not rejected
+        let priority = match self.sandbox_manager().clamp_priority(target_pid, priority) {
+            Ok(priority) => priority,
+            Err(e) => return SyscallResult::error(e),
+        };
+
         // Update process priority in process manager, scheduler, and resource limits
         if process_manager.set_process_priority(target_pid, priority) {
             info!(
                 "PID {} successfully set priority of PID {} to {}",
                 pid, target_pid, priority
             );
-            SyscallResult::success()
+            SyscallResult::success_with_data(priority.to_le_bytes().to_vec())
+        } else {
+            SyscallResult::error(format!("Process {} not found", target_pid))
+        }
+    }
+
+    pub(in crate::syscalls) fn get_process_nice(&self, pid: Pid, target_pid: Pid) -> SyscallResult {
+        let request =
+            PermissionRequest::new(pid, Resource::Process { pid: target_pid }, Action::Inspect);
+        let response = self.permission_manager().check(&request);
+
+        if !response.is_allowed() {
+            return SyscallResult::permission_denied(response.reason());
+        }
+
+        let process_manager = match &self.optional().process_manager {
+            Some(pm) => pm,
+            None => return SyscallResult::error("Process manager not available"),
+        };
+
+        match process_manager.get_process(target_pid) {
+            Some(process) => {
+                let nice = priority_to_nice(process.priority);
+                match json::to_vec(&serde_json::json!({
+                    "nice": nice,
+                    "priority": process.priority,
+                    "oom_score_adj": self.oom_score_adj(target_pid),
+                })) {
+                    Ok(data) => SyscallResult::success_with_data(data),
+                    Err(e) => {
+                        error!("Failed to serialize process niceness: {}", e);
+                        SyscallResult::error("Serialization failed")
+                    }
+                }
+            }
+            None => SyscallResult::error(format!("Process {} not found", target_pid)),
+        }
+    }
+
+    pub(in crate::syscalls) fn set_process_nice(
+        &self,
+        pid: Pid,
+        target_pid: Pid,
+        nice: i8,
+    ) -> SyscallResult {
+        let request =
+            PermissionRequest::new(pid, Resource::Process { pid: target_pid }, Action::Write);
+        let response = self.permission_manager().check_and_audit(&request);
+
+        if !response.is_allowed() {
+            return SyscallResult::permission_denied(response.reason());
+        }
+
+        let process_manager = match &self.optional().process_manager {
+            Some(pm) => pm,
+            None => return SyscallResult::error("Process manager not available"),
+        };
+
+        // Niceness outside the scheduler's range is clamped like an over-ceiling priority
+        let priority = match self
+            .sandbox_manager()
+            .clamp_priority(target_pid, nice_to_priority(nice))
+        {
+            Ok(priority) => priority,
+            Err(e) => return SyscallResult::error(e),
+        };
+
+        if process_manager.set_process_priority(target_pid, priority) {
+            let nice = priority_to_nice(priority);
+            info!(
+                "PID {} successfully set niceness of PID {} to {}",
+                pid, target_pid, nice
+            );
+            SyscallResult::success_with_data(nice.to_le_bytes().to_vec())
         } else {
             SyscallResult::error(format!("Process {} not found", target_pid))
         }
     }
 
+    pub(in crate::syscalls) fn set_oom_score(
+        &self,
+        pid: Pid,
+        target_pid: Pid,
+        score: i16,
+    ) -> SyscallResult {
+        let request =
+            PermissionRequest::new(pid, Resource::Process { pid: target_pid }, Action::Write);
+        let response = self.permission_manager().check_and_audit(&request);
+
+        if !response.is_allowed() {
+            return SyscallResult::permission_denied(response.reason());
+        }
+
+        let memory_manager = match &self.optional().memory_manager {
+            Some(mm) => mm,
+            None => return SyscallResult::error("Memory manager not available"),
+        };
+
+        if let Some(ref process_manager) = self.optional().process_manager {
+            if process_manager.get_process(target_pid).is_none() {
+                return SyscallResult::error(format!("Process {} not found", target_pid));
+            }
+        }
+
+        // Requests below the target's sandbox floor are clamped, not rejected
+        let score = self.sandbox_manager().clamp_oom_score(target_pid, score);
+        let score = memory_manager.set_oom_score_adj(target_pid, score);
+        info!(
+            "PID {} set OOM score adjustment of PID {} to {}",
+            pid, target_pid, score
+        );
+        SyscallResult::success_with_data(score.to_le_bytes().to_vec())
+    }
+
+    /// OOM score adjustment of `pid`, or 0 without a memory manager
+    fn oom_score_adj(&self, pid: Pid) -> i16 {
+        self.optional()
+            .memory_manager
+            .as_ref()
+            .map_or(0, |mm| mm.oom_score_adj(pid))
+    }
+
     pub(in crate::syscalls) fn get_process_state(
         &self,
         pid: Pid,
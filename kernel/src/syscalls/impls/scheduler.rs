@@ -6,7 +6,10 @@
 use crate::core::serialization::json;
 use crate::core::types::Pid;
 use crate::permissions::{Action, PermissionChecker, PermissionRequest, Resource};
-use crate::scheduler::{PriorityControl, SchedulerControl, SchedulerPolicy, SchedulerStats};
+use crate::scheduler::{
+    apply_priority_op, PriorityControl, PriorityOp, SchedulerControl, SchedulerPolicy,
+    SchedulerStats,
+};
 use log::{error, info};
 
 use crate::syscalls::core::executor::SyscallExecutorWithIpc;
@@ -408,8 +411,17 @@ pub(in crate::syscalls) fn boost_priority(&self, pid: Pid, target_pid: Pid) -> S
             None => return SyscallResult::error("Process manager not available"),
         };
 
-        match process_manager.boost_process_priority(target_pid) {
-            Ok(new_priority) => {
+        let current = match process_manager.get_process(target_pid) {
+            Some(process) => process.priority,
+            None => return SyscallResult::error(format!("Process {} not found", target_pid)),
+        };
+
+        // A boost past the target's sandbox ceiling is clamped to the ceiling
+        let boosted = apply_priority_op(current, PriorityOp::Boost)
+            .and_then(|boosted| self.sandbox_manager().clamp_priority(target_pid, boosted));
+
+        match boosted {
+            Ok(new_priority) if process_manager.set_process_priority(target_pid, new_priority) => {
                 info!(
                     "PID {} successfully boosted priority of PID {} to {}",
                     pid, target_pid, new_priority
@@ -420,6 +432,7 @@ pub(in crate::syscalls) fn boost_priority(&self, pid: Pid, target_pid: Pid) -> S
                 buf.extend_from_slice(&bytes);
                 SyscallResult::success_with_data(buf.into_vec())
             }
+            Ok(_) => SyscallResult::error("Failed to update priority"),
             Err(e) => {
                 info!(
                     "PID {} attempted to boost priority of PID {}: {}",
@@ -466,6 +479,60 @@ pub(in crate::syscalls) fn lower_priority(&self, pid: Pid, target_pid: Pid) -> S
             }
         }
     }
+
+    /// Pause a process in the scheduler (internal implementation)
+    pub(in crate::syscalls) fn pause_process(&self, pid: Pid, target_pid: Pid) -> SyscallResult {
+        let request =
+            PermissionRequest::new(pid, Resource::Process { pid: target_pid }, Action::Write);
+        let response = self.permission_manager().check_and_audit(&request);
+
+        if !response.is_allowed() {
+            return SyscallResult::permission_denied(response.reason());
+        }
+
+        let process_manager = match &self.optional().process_manager {
+            Some(pm) => pm,
+            None => return SyscallResult::error("Process manager not available"),
+        };
+
+        match process_manager.pause_process(target_pid) {
+            Ok(()) => {
+                info!("PID {} paused PID {}", pid, target_pid);
+                SyscallResult::success()
+            }
+            Err(e) => {
+                info!("PID {} failed to pause PID {}: {}", pid, target_pid, e);
+                SyscallResult::error(e)
+            }
+        }
+    }
+
+    /// Resume a paused process (internal implementation)
+    pub(in crate::syscalls) fn resume_process(&self, pid: Pid, target_pid: Pid) -> SyscallResult {
+        let request =
+            PermissionRequest::new(pid, Resource::Process { pid: target_pid }, Action::Write);
+        let response = self.permission_manager().check_and_audit(&request);
+
+        if !response.is_allowed() {
+            return SyscallResult::permission_denied(response.reason());
+        }
+
+        let process_manager = match &self.optional().process_manager {
+            Some(pm) => pm,
+            None => return SyscallResult::error("Process manager not available"),
+        };
+
+        match process_manager.resume_process(target_pid) {
+            Ok(()) => {
+                info!("PID {} resumed PID {}", pid, target_pid);
+                SyscallResult::success()
+            }
+            Err(e) => {
+                info!("PID {} failed to resume PID {}: {}", pid, target_pid, e);
+                SyscallResult::error(e)
+            }
+        }
+    }
 }
 
 // Implement trait interfaces by delegating to internal methods
@@ -481,6 +548,14 @@ fn yield_process(&self, pid: Pid) -> SyscallResult {
     fn get_current_scheduled(&self, pid: Pid) -> SyscallResult {
         self.get_current_scheduled(pid)
     }
+
+    fn pause_process(&self, pid: Pid, target_pid: Pid) -> SyscallResult {
+        self.pause_process(pid, target_pid)
+    }
+
+    fn resume_process(&self, pid: Pid, target_pid: Pid) -> SyscallResult {
+        self.resume_process(pid, target_pid)
+    }
 }
 
 impl SchedulerPolicy for SyscallExecutorWithIpc {
@@ -10,7 +10,9 @@
     Signal, SignalAction, SignalDelivery, SignalHandlerRegistry, SignalMasking, SignalQueue,
 };
 use log::{error, info};
+use std::sync::Arc;
 
+use super::handle::FileHandle;
 use crate::syscalls::core::executor::SyscallExecutorWithIpc;
 use crate::syscalls::types::SyscallResult;
 
@@ -206,6 +208,60 @@ pub(in crate::syscalls) fn wait_for_signal(
         SyscallResult::error("No matching signals pending")
     }
 
+    /// Create a signalfd for the given signals
+    ///
+    /// Matching signals go to the fd instead of handlers until it is closed.
+    pub(in crate::syscalls) fn signalfd_create(&self, pid: Pid, signals: &[u32]) -> SyscallResult {
+        let signal_manager = match &self.optional().signal_manager {
+            Some(mgr) => mgr,
+            None => return SyscallResult::error("Signal manager not available"),
+        };
+
+        use crate::security::ResourceLimitProvider;
+        if let Some(limits) = self.sandbox_manager().get_limits(pid) {
+            let current_fd_count = self.fd_manager().get_fd_count(pid);
+            if current_fd_count >= limits.max_file_descriptors {
+                return SyscallResult::permission_denied(format!(
+                    "File descriptor limit exceeded: {}/{} FDs open",
+                    current_fd_count, limits.max_file_descriptors
+                ));
+            }
+        }
+
+        let signal_enums = match signals
+            .iter()
+            .map(|&signal| Signal::from_number(signal))
+            .collect::<Result<Vec<_>, _>>()
+        {
+            Ok(sigs) => sigs,
+            Err(e) => return SyscallResult::error(format!("Invalid signal: {}", e)),
+        };
+
+        let signalfd = match signal_manager.create_signalfd(pid, &signal_enums) {
+            Ok(signalfd) => signalfd,
+            Err(e) => return SyscallResult::error(format!("Failed to create signalfd: {}", e)),
+        };
+
+        let handle = Arc::new(FileHandle::from_signalfd(signalfd));
+        let fd_guard = self.fd_manager().allocate_fd_guard(pid, handle, None);
+        let fd = fd_guard.fd();
+
+        match json::to_vec(&serde_json::json!({ "fd": fd })) {
+            Ok(data) => {
+                std::mem::forget(fd_guard);
+                info!(
+                    "PID {} created signalfd {} for signals {:?}",
+                    pid, fd, signal_enums
+                );
+                SyscallResult::success_with_data(data)
+            }
+            Err(e) => {
+                error!("Failed to serialize signalfd result: {}", e);
+                SyscallResult::error("Internal serialization error")
+            }
+        }
+    }
+
     /// Get signal state
     pub(in crate::syscalls) fn get_signal_state(
         &self,
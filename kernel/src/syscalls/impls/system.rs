@@ -6,15 +6,16 @@
 
 use crate::core::serialization::json;
 use crate::core::types::Pid;
-use crate::monitoring::span_operation;
+use crate::monitoring::{span_operation, Category, Event, Payload, Severity};
 use crate::permissions::{Action, PermissionChecker, PermissionRequest, Resource};
 
 use log::{error, info, trace, warn};
-use prost::bytes;
 
 use crate::syscalls::core::executor::SyscallExecutorWithIpc;
+use crate::syscalls::impls::http_retry::{retry_with_backoff, HttpResponse, TransportError};
 use crate::syscalls::timeout::executor::TimeoutError;
-use crate::syscalls::types::{SyscallResult, SystemInfo};
+use crate::syscalls::types::{RetryPolicy, SyscallResult, SystemInfo};
+use std::time::Instant;
 
 impl SyscallExecutorWithIpc {
     pub(in crate::syscalls) fn get_system_info(&self, pid: Pid) -> SyscallResult {
@@ -154,7 +155,12 @@ pub(in crate::syscalls) fn set_env_var(
         SyscallResult::success()
     }
 
-    pub(in crate::syscalls) fn network_request(&self, pid: Pid, url: &str) -> SyscallResult {
+    pub(in crate::syscalls) fn network_request(
+        &self,
+        pid: Pid,
+        url: &str,
+        retry: Option<RetryPolicy>,
+    ) -> SyscallResult {
         use crate::core::memory::arena::with_arena;
 
         with_arena(|arena| {
@@ -185,28 +191,76 @@ pub(in crate::syscalls) fn network_request(&self, pid: Pid, url: &str) -> Syscal
 
             span.record("host", host);
 
-            let url_clone = url.to_string();
-            let result: Result<(reqwest::StatusCode, bytes::Bytes), TimeoutError<reqwest::Error>> =
-                self.timeout_executor().execute_with_deadline(
-                    || {
-                        let client = reqwest::blocking::Client::builder()
-                            .user_agent("ai-os-kernel/0.1.0")
-                            .build()?;
+            let parsed = match reqwest::Url::parse(url) {
+                Ok(parsed) => parsed,
+                Err(e) => {
+                    span.record_error(arena.alloc(format!("Invalid URL: {}", e)));
+                    return SyscallResult::error(format!("Invalid URL: {}", e));
+                }
+            };
+
+            let circuit = self.socket_manager().circuit_breaker();
+            if let Err(open) = circuit.acquire(host) {
+                let reason = format!(
+                    "Host {} unavailable (circuit open, retry in {}ms)",
+                    host,
+                    open.retry_after.as_millis()
+                );
+                span.record_error(arena.alloc(reason.clone()));
+                return SyscallResult::error(reason);
+            }
 
-                        let response = client.get(&url_clone).send()?;
-                        let status = response.status();
-                        let body = response.bytes()?;
+            let policy = retry.unwrap_or_else(|| self.socket_manager().retry_policy(pid));
+            let transport = self.socket_manager().http_transport();
+            let timeout = self.timeout_config().network;
+            let deadline = timeout.duration().map(|limit| Instant::now() + limit);
 
-                        Ok((status, body))
+            let result: Result<HttpResponse, TimeoutError<TransportError>> =
+                self.timeout_executor().execute_with_deadline(
+                    || {
+                        retry_with_backoff(
+                            &policy,
+                            deadline,
+                            || transport.get(pid, &parsed),
+                            |retry_count, err| {
+                                warn!(
+                                    "PID {} request to {} failed (attempt {}): {}",
+                                    pid,
+                                    url,
+                                    retry_count + 1,
+                                    err
+                                );
+                                if let Some(ref collector) = self.optional().collector {
+                                    collector.emit(
+                                        Event::new(
+                                            Severity::Warn,
+                                            Category::Network,
+                                            Payload::NetworkError {
+                                                error: err.to_string().into(),
+                                                retry_count: retry_count.min(u8::MAX as u32) as u8,
+                                            },
+                                        )
+                                        .with_pid(pid),
+                                    );
+                                }
+                            },
+                        )
                     },
-                    self.timeout_config().network,
+                    timeout,
                     "http_request",
                 );
 
+            // Only failures to reach the host count against its circuit
+            match &result {
+                Err(TimeoutError::Timeout { .. }) => circuit.record_failure(host),
+                Err(TimeoutError::Operation(e)) if e.is_retryable() => circuit.record_failure(host),
+                _ => circuit.record_success(host),
+            }
+
             match result {
-                Ok((status, body)) => {
-                    if !status.is_success() {
-                        warn!("PID {} received HTTP {} for {}", pid, status.as_u16(), url);
+                Ok(HttpResponse { status, body }) => {
+                    if !(200..300).contains(&status) {
+                        warn!("PID {} received HTTP {} for {}", pid, status, url);
                     }
 
                     info!(
@@ -214,15 +268,15 @@ pub(in crate::syscalls) fn network_request(&self, pid: Pid, url: &str) -> Syscal
                         pid,
                         url,
                         body.len(),
-                        status.as_u16()
+                        status
                     );
 
                     let bytes_str = arena.alloc_str(&body.len().to_string());
-                    let status_str = arena.alloc_str(&status.as_u16().to_string());
+                    let status_str = arena.alloc_str(&status.to_string());
                     span.record("bytes_received", bytes_str);
                     span.record("status_code", status_str);
                     span.record_result(true);
-                    SyscallResult::success_with_data(body.to_vec())
+                    SyscallResult::success_with_data(body)
                 }
                 Err(TimeoutError::Timeout { elapsed_ms, .. }) => {
                     error!("HTTP request timed out for {} after {}ms (slow network or unresponsive server?)", url, elapsed_ms);
@@ -4,10 +4,11 @@
 * Time and sleep operations
 */
 
+use crate::core::serialization::json;
 use crate::core::types::Pid;
 use crate::permissions::{Action, PermissionChecker, PermissionRequest, Resource};
 
-use log::info;
+use log::{error, info};
 use std::time::Duration;
 
 use crate::syscalls::core::executor::{SyscallExecutorWithIpc, SYSTEM_START};
@@ -39,8 +40,33 @@ pub(in crate::syscalls) fn sleep(&self, pid: Pid, duration_ms: u64) -> SyscallRe
         }
 
         info!("PID {} sleeping for {} ms", pid, duration_ms);
-        std::thread::sleep(Duration::from_millis(duration_ms));
-        SyscallResult::success()
+        let duration = Duration::from_millis(duration_ms);
+        let Some(signal_manager) = &self.optional().signal_manager else {
+            std::thread::sleep(duration);
+            return SyscallResult::success();
+        };
+
+        // A deliverable signal cuts the sleep short, as with nanosleep
+        match signal_manager.interruptible_sleep(pid, duration) {
+            Some(remaining) => {
+                let remaining_ms = u64::try_from(remaining.as_millis()).unwrap_or(u64::MAX);
+                info!(
+                    "PID {} woken by signal with {} ms remaining",
+                    pid, remaining_ms
+                );
+                match json::to_vec(&serde_json::json!({
+                    "interrupted": true,
+                    "remaining_ms": remaining_ms,
+                })) {
+                    Ok(data) => SyscallResult::success_with_data(data),
+                    Err(e) => {
+                        error!("Failed to serialize sleep result: {}", e);
+                        SyscallResult::error("Internal serialization error")
+                    }
+                }
+            }
+            None => SyscallResult::success(),
+        }
     }
 
     pub(in crate::syscalls) fn get_uptime(&self, pid: Pid) -> SyscallResult {
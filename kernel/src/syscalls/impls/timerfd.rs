@@ -0,0 +1,249 @@
+/*!
+ * Timer File Descriptors
+ * One-shot and periodic timers exposed through the FD table (timerfd)
+ *
+ * Expirations are derived from the monotonic clock when the fd is read, so
+ * an armed timer costs no thread or task. Reading returns the number of
+ * expirations since the last read as a little-endian u64, or WouldBlock if
+ * none occurred. Disarming, closing the fd or process cleanup cancels it.
+ */
+
+use crate::core::serialization::json;
+use crate::core::sync::readiness::{notify_readiness, ReadinessKey};
+use crate::core::types::Pid;
+use crate::syscalls::core::executor::SyscallExecutorWithIpc;
+use crate::syscalls::types::SyscallResult;
+
+use super::handle::FileHandle;
+use log::{error, info};
+use parking_lot::Mutex;
+use std::io;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+#[derive(Debug, Default)]
+struct TimerState {
+    /// Next expiration; None while disarmed
+    deadline: Option<Instant>,
+    /// Period after the first expiration; None for one-shot timers
+    interval: Option<Duration>,
+}
+
+impl TimerState {
+    /// Consume the expirations that have elapsed by `now`
+    fn take_expirations(&mut self, now: Instant) -> u64 {
+        let Some(deadline) = self.deadline else {
+            return 0;
+        };
+        if now < deadline {
+            return 0;
+        }
+
+        match self.interval {
+            Some(interval) => {
+                let periods = (now - deadline).as_nanos() / interval.as_nanos();
+                let elapsed = interval.as_nanos().saturating_mul(periods + 1);
+                let elapsed = Duration::from_nanos(u64::try_from(elapsed).unwrap_or(u64::MAX));
+                self.deadline = Some(deadline + elapsed);
+                u64::try_from(periods + 1).unwrap_or(u64::MAX)
+            }
+            None => {
+                self.deadline = None;
+                1
+            }
+        }
+    }
+}
+
+/// Shared timer behind a timerfd
+///
+/// Clones refer to the same timer, so dup'd fds observe one expiration count.
+#[derive(Debug, Clone, Default)]
+pub struct TimerFd {
+    state: Arc<Mutex<TimerState>>,
+}
+
+impl TimerFd {
+    /// Create a disarmed timer
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Arm the timer to fire after `delay`, then every `interval` if given
+    ///
+    /// A zero delay disarms the timer and a zero interval means one-shot.
+    /// Re-arming discards expirations that haven't been read.
+    pub fn set(&self, delay: Duration, interval: Option<Duration>) {
+        let mut state = self.state.lock();
+        if delay.is_zero() {
+            *state = TimerState::default();
+            return;
+        }
+        state.deadline = Some(Instant::now() + delay);
+        state.interval = interval.filter(|i| !i.is_zero());
+        drop(state);
+        // Pollers sized their sleep to the old deadline
+        notify_readiness(self.readiness_key());
+    }
+
+    /// Key pollers of this timer wait on
+    pub fn readiness_key(&self) -> ReadinessKey {
+        ReadinessKey::of(&*self.state)
+    }
+
+    /// Disarm the timer
+    pub fn disarm(&self) {
+        self.set(Duration::ZERO, None);
+    }
+
+    /// Whether the timer will fire again
+    pub fn is_armed(&self) -> bool {
+        self.state.lock().deadline.is_some()
+    }
+
+    /// Time until the next expiration, zero if it is already due
+    pub fn remaining(&self) -> Option<Duration> {
+        self.state
+            .lock()
+            .deadline
+            .map(|deadline| deadline.saturating_duration_since(Instant::now()))
+    }
+
+    /// Expirations since the last call, resetting the count
+    pub fn take_expirations(&self) -> u64 {
+        self.state.lock().take_expirations(Instant::now())
+    }
+
+    /// Read the expiration count into `buf` as a little-endian u64
+    ///
+    /// Fails with WouldBlock when the timer hasn't expired since the last read.
+    pub fn read_into(&self, buf: &mut [u8]) -> io::Result<usize> {
+        const LEN: usize = std::mem::size_of::<u64>();
+        if buf.len() < LEN {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "timerfd reads need an 8-byte buffer",
+            ));
+        }
+
+        match self.take_expirations() {
+            0 => Err(io::ErrorKind::WouldBlock.into()),
+            count => {
+                buf[..LEN].copy_from_slice(&count.to_le_bytes());
+                Ok(LEN)
+            }
+        }
+    }
+}
+
+impl SyscallExecutorWithIpc {
+    /// Create a disarmed timerfd
+    pub(in crate::syscalls) fn timerfd_create(&self, pid: Pid) -> SyscallResult {
+        use crate::security::ResourceLimitProvider;
+        if let Some(limits) = self.sandbox_manager().get_limits(pid) {
+            let current_fd_count = self.fd_manager().get_fd_count(pid);
+            if current_fd_count >= limits.max_file_descriptors {
+                return SyscallResult::permission_denied(format!(
+                    "File descriptor limit exceeded: {}/{} FDs open",
+                    current_fd_count, limits.max_file_descriptors
+                ));
+            }
+        }
+
+        let handle = Arc::new(FileHandle::from_timerfd(TimerFd::new()));
+        let fd_guard = self.fd_manager().allocate_fd_guard(pid, handle, None);
+        let fd = fd_guard.fd();
+
+        match json::to_vec(&serde_json::json!({ "fd": fd })) {
+            Ok(data) => {
+                std::mem::forget(fd_guard);
+                info!("PID {} created timerfd {}", pid, fd);
+                SyscallResult::success_with_data(data)
+            }
+            Err(e) => {
+                error!("Failed to serialize timerfd result: {}", e);
+                SyscallResult::error("Internal serialization error")
+            }
+        }
+    }
+
+    /// Arm or disarm a timerfd
+    pub(in crate::syscalls) fn timerfd_set(
+        &self,
+        pid: Pid,
+        fd: u32,
+        duration_ms: u64,
+        interval_ms: Option<u64>,
+    ) -> SyscallResult {
+        let Some(handle) = self.fd_manager().get_handle(fd) else {
+            return SyscallResult::error("Invalid file descriptor");
+        };
+
+        let Some(timer) = handle.timerfd() else {
+            return SyscallResult::error(format!("FD {} is not a timerfd", fd));
+        };
+
+        timer.set(
+            Duration::from_millis(duration_ms),
+            interval_ms.map(Duration::from_millis),
+        );
+
+        info!(
+            "PID {} set timerfd {} (delay: {} ms, interval: {:?} ms)",
+            pid, fd, duration_ms, interval_ms
+        );
+        SyscallResult::success()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_one_shot_fires_once() {
+        let mut state = TimerState::default();
+        let start = Instant::now();
+        state.deadline = Some(start + Duration::from_millis(10));
+
+        assert_eq!(state.take_expirations(start), 0);
+        assert_eq!(state.take_expirations(start + Duration::from_millis(50)), 1);
+        assert_eq!(state.take_expirations(start + Duration::from_secs(5)), 0);
+        assert!(state.deadline.is_none());
+    }
+
+    #[test]
+    fn test_periodic_counts_missed_periods() {
+        let mut state = TimerState::default();
+        let start = Instant::now();
+        state.deadline = Some(start + Duration::from_millis(10));
+        state.interval = Some(Duration::from_millis(10));
+
+        // Fires at 10, 20, 30 and 40ms
+        assert_eq!(state.take_expirations(start + Duration::from_millis(45)), 4);
+        assert_eq!(state.deadline, Some(start + Duration::from_millis(50)));
+        assert_eq!(state.take_expirations(start + Duration::from_millis(49)), 0);
+        assert_eq!(state.take_expirations(start + Duration::from_millis(50)), 1);
+    }
+
+    #[test]
+    fn test_read_and_disarm() {
+        let timer = TimerFd::new();
+        let mut buf = [0u8; 8];
+        assert_eq!(
+            timer.read_into(&mut buf).unwrap_err().kind(),
+            io::ErrorKind::WouldBlock
+        );
+
+        timer.set(Duration::from_nanos(1), None);
+        std::thread::sleep(Duration::from_millis(1));
+        assert_eq!(timer.read_into(&mut buf).unwrap(), 8);
+        assert_eq!(u64::from_le_bytes(buf), 1);
+
+        timer.set(Duration::from_secs(60), Some(Duration::from_secs(1)));
+        assert!(timer.is_armed());
+        timer.disarm();
+        assert!(!timer.is_armed());
+        assert_eq!(timer.remaining(), None);
+    }
+}
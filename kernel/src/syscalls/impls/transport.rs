@@ -0,0 +1,90 @@
+/*!
+ * Socket Transport
+ * Pluggable connection layer for Connect, Send and Recv
+ *
+ * Connect opens streams through a `SocketTransport`. Production uses the OS
+ * TCP stack; tests inject a scripted transport so the send/recv retry loop,
+ * timeouts and bandwidth throttling run deterministically without sockets.
+ */
+
+use crate::vfs::Readiness;
+use std::fmt;
+use std::io::{self, Read, Write};
+use std::net::TcpStream;
+use std::os::unix::net::UnixStream;
+
+/// Connected byte stream behind a socket
+pub trait TransportStream: Send + Sync + fmt::Debug {
+    /// Write some of `data`, returning how much went out
+    fn send(&mut self, data: &[u8]) -> io::Result<usize>;
+
+    /// Read into `buf`, returning how much arrived (0 at EOF)
+    fn recv(&mut self, buf: &mut [u8]) -> io::Result<usize>;
+
+    /// Whether the stream can be used without blocking
+    fn readiness(&self) -> Readiness {
+        Readiness::READ_WRITE
+    }
+
+    /// OS descriptor a blocked Poll can wait on, if the stream has one
+    fn raw_fd(&self) -> Option<std::os::fd::RawFd> {
+        None
+    }
+}
+
+impl TransportStream for TcpStream {
+    fn send(&mut self, data: &[u8]) -> io::Result<usize> {
+        self.write(data)
+    }
+
+    fn recv(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        self.read(buf)
+    }
+
+    #[cfg(unix)]
+    fn readiness(&self) -> Readiness {
+        use std::os::fd::AsRawFd;
+        super::network::poll_readiness(self.as_raw_fd())
+    }
+
+    fn raw_fd(&self) -> Option<std::os::fd::RawFd> {
+        use std::os::fd::AsRawFd;
+        Some(self.as_raw_fd())
+    }
+}
+
+/// Streams connected through an abstract `@name`
+impl TransportStream for UnixStream {
+    fn send(&mut self, data: &[u8]) -> io::Result<usize> {
+        self.write(data)
+    }
+
+    fn recv(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        self.read(buf)
+    }
+
+    fn readiness(&self) -> Readiness {
+        use std::os::fd::AsRawFd;
+        super::network::poll_readiness(self.as_raw_fd())
+    }
+
+    fn raw_fd(&self) -> Option<std::os::fd::RawFd> {
+        use std::os::fd::AsRawFd;
+        Some(self.as_raw_fd())
+    }
+}
+
+/// Opens outbound connections for Connect
+pub trait SocketTransport: Send + Sync {
+    fn connect(&self, address: &str) -> io::Result<Box<dyn TransportStream>>;
+}
+
+/// Transport over the OS TCP stack
+#[derive(Debug, Clone, Copy, Default)]
+pub struct TcpTransport;
+
+impl SocketTransport for TcpTransport {
+    fn connect(&self, address: &str) -> io::Result<Box<dyn TransportStream>> {
+        Ok(Box::new(TcpStream::connect(address)?))
+    }
+}
@@ -6,6 +6,7 @@
 
 use crate::syscalls::timeout::executor::TimeoutError;
 
+use crate::core::errors::ErrorCode;
 use crate::core::serialization::json;
 use crate::core::types::Pid;
 use crate::monitoring::span_operation;
@@ -13,9 +14,11 @@
 
 use log::{error, info, trace, warn};
 use std::fs;
+use std::io::Read;
 use std::path::Path;
 
-use crate::vfs::{FileSystem, VfsError};
+use crate::vfs::traits::split_segments;
+use crate::vfs::{FileSystem, LocalFS, OpenFlags, OpenMode, VfsError};
 
 use crate::syscalls::core::executor::SyscallExecutorWithIpc;
 use crate::syscalls::types::SyscallResult;
@@ -112,11 +115,45 @@ pub(in crate::syscalls) fn vfs_read(&self, pid: Pid, path: &Path) -> SyscallResu
             Err(TimeoutError::Operation(e)) => {
                 error!("Read failed for {:?}: {}", path, e);
                 span.record_error(&format!("Read failed: {}", e));
-                SyscallResult::error(format!("Read failed: {}", e))
+                SyscallResult::error_with_code(format!("Read failed: {}", e), io_error_code(&e))
             }
         }
     }
 
+    /// Open a file for sequential reading, checking read permission
+    ///
+    /// Uses the VFS when available, otherwise std::fs. For streamed reads
+    /// that hand data out as it arrives instead of buffering the whole file.
+    pub(in crate::syscalls) fn vfs_open_read(
+        &self,
+        pid: Pid,
+        path: &Path,
+    ) -> Result<Box<dyn Read + Send>, SyscallResult> {
+        let canonical_path = path.canonicalize().unwrap_or_else(|_| path.to_path_buf());
+        let request = PermissionRequest::file_read(pid, canonical_path.clone());
+        let response = self.permission_manager().check_and_audit(&request);
+
+        if !response.is_allowed() {
+            return Err(SyscallResult::permission_denied(response.reason()));
+        }
+
+        let opened: Result<Box<dyn Read + Send>, (String, Option<ErrorCode>)> =
+            match &self.optional().vfs {
+                Some(vfs) => vfs
+                    .open(path, OpenFlags::read_only(), OpenMode::default())
+                    .map(|file| Box::new(file) as Box<dyn Read + Send>)
+                    .map_err(|e| vfs_failure(TimeoutError::Operation(e))),
+                None => fs::File::open(&canonical_path)
+                    .map(|file| Box::new(file) as Box<dyn Read + Send>)
+                    .map_err(|e| (e.to_string(), Some(io_error_code(&e)))),
+            };
+
+        opened.map_err(|(e, code)| {
+            error!("Open for streaming failed for {:?}: {}", path, e);
+            failed_with(format!("Open failed: {}", e), code)
+        })
+    }
+
     /// Write file using VFS if available
     /// Can block on slow storage (NFS, USB, slow disks)
     pub(in crate::syscalls) fn vfs_write(
@@ -138,7 +175,10 @@ pub(in crate::syscalls) fn vfs_write(
                 Err(e) => {
                     warn!("Failed to canonicalize path {:?}: {}", path, e);
                     span.record_error(&format!("Path canonicalization failed: {}", e));
-                    return SyscallResult::error(format!("Invalid path: {}", e));
+                    return SyscallResult::error_with_code(
+                        format!("Invalid path: {}", e),
+                        io_error_code(&e),
+                    );
                 }
             }
         } else {
@@ -193,6 +233,12 @@ pub(in crate::syscalls) fn vfs_write(
                     );
                     span.record("vfs_timeout_ms", &format!("{}", elapsed_ms));
                 }
+                Err(TimeoutError::Operation(VfsError::ReadOnly)) => {
+                    // Read-only mounts must not be bypassed via the std::fs fallback
+                    warn!("PID {} denied write on read-only mount: {:?}", pid, path);
+                    span.record_error("Read-only filesystem");
+                    return vfs_error_result(VfsError::ReadOnly);
+                }
                 Err(TimeoutError::Operation(e)) => {
                     warn!(
                         "VFS write failed for {:?}: {}, falling back to std::fs",
@@ -234,11 +280,300 @@ pub(in crate::syscalls) fn vfs_write(
             Err(TimeoutError::Operation(e)) => {
                 error!("Write failed for {:?}: {}", path, e);
                 span.record_error(&format!("Write failed: {}", e));
-                SyscallResult::error(format!("Write failed: {}", e))
+                SyscallResult::error_with_code(format!("Write failed: {}", e), io_error_code(&e))
+            }
+        }
+    }
+
+    /// Read the start of a file into segments of the given sizes (readv)
+    ///
+    /// Returns `{"segments": [...], "total_bytes": n}`.
+    pub(in crate::syscalls) fn vfs_read_vectored(
+        &self,
+        pid: Pid,
+        path: &Path,
+        sizes: &[usize],
+    ) -> SyscallResult {
+        let span = span_operation("vfs_read_vectored");
+        let _guard = span.enter();
+        span.record("pid", &format!("{}", pid));
+        span.record("path", &format!("{:?}", path));
+        span.record("segments", &format!("{}", sizes.len()));
+
+        let canonical_path = path.canonicalize().unwrap_or_else(|_| path.to_path_buf());
+        let request = PermissionRequest::file_read(pid, canonical_path.clone());
+        let response = self.permission_manager().check_and_audit(&request);
+
+        if !response.is_allowed() {
+            span.record_error(response.reason());
+            return SyscallResult::permission_denied(response.reason());
+        }
+
+        let result = match &self.optional().vfs {
+            Some(vfs) => self
+                .timeout_executor()
+                .execute_with_deadline(
+                    || vfs.read_vectored(path, sizes),
+                    self.timeout_config().file_io,
+                    "vfs_read_vectored",
+                )
+                .map_err(vfs_failure),
+            None => self
+                .timeout_executor()
+                .execute_with_deadline(
+                    || fs::read(&canonical_path).map(|data| split_segments(&data, sizes)),
+                    self.timeout_config().file_io,
+                    "fs_read_vectored",
+                )
+                .map_err(io_failure),
+        };
+
+        let segments = match result {
+            Ok(segments) => segments,
+            Err((e, code)) => {
+                error!("Vectored read failed for {:?}: {}", path, e);
+                span.record_error(&format!("Read failed: {}", e));
+                return failed_with(format!("Read failed: {}", e), code);
+            }
+        };
+
+        let total_bytes: usize = segments.iter().map(Vec::len).sum();
+        info!(
+            "PID {} read file vectored: {:?} ({} bytes in {} segments)",
+            pid,
+            path,
+            total_bytes,
+            segments.len()
+        );
+        span.record("bytes_read", &format!("{}", total_bytes));
+        span.record_result(true);
+
+        match json::to_vec(&serde_json::json!({
+            "segments": segments,
+            "total_bytes": total_bytes,
+        })) {
+            Ok(data) => SyscallResult::success_with_data(data),
+            Err(e) => {
+                warn!("Failed to serialize vectored read result: {}", e);
+                SyscallResult::error("Internal serialization error")
+            }
+        }
+    }
+
+    /// Write segments back to back as the new file contents (writev)
+    ///
+    /// Returns `{"total_bytes": n}`.
+    pub(in crate::syscalls) fn vfs_write_vectored(
+        &self,
+        pid: Pid,
+        path: &Path,
+        segments: &[Vec<u8>],
+    ) -> SyscallResult {
+        let span = span_operation("vfs_write_vectored");
+        let _guard = span.enter();
+        span.record("pid", &format!("{}", pid));
+        span.record("path", &format!("{:?}", path));
+        span.record("segments", &format!("{}", segments.len()));
+
+        let file_exists = path.exists();
+        let check_path = if file_exists {
+            match path.canonicalize() {
+                Ok(p) => p,
+                Err(e) => {
+                    span.record_error(&format!("Path canonicalization failed: {}", e));
+                    return SyscallResult::error_with_code(
+                        format!("Invalid path: {}", e),
+                        io_error_code(&e),
+                    );
+                }
+            }
+        } else {
+            path.to_path_buf()
+        };
+
+        let request = if file_exists {
+            PermissionRequest::file_write(pid, check_path)
+        } else {
+            PermissionRequest::file_create(pid, check_path)
+        };
+        let response = self.permission_manager().check_and_audit(&request);
+
+        if !response.is_allowed() {
+            span.record_error(response.reason());
+            return SyscallResult::permission_denied(response.reason());
+        }
+
+        let slices: Vec<&[u8]> = segments.iter().map(Vec::as_slice).collect();
+        let result = match &self.optional().vfs {
+            Some(vfs) => self
+                .timeout_executor()
+                .execute_with_deadline(
+                    || vfs.write_vectored(path, &slices),
+                    self.timeout_config().file_io,
+                    "vfs_write_vectored",
+                )
+                .map_err(vfs_failure),
+            None => self
+                .timeout_executor()
+                .execute_with_deadline(
+                    || {
+                        let data = slices.concat();
+                        fs::write(path, &data).map(|()| data.len())
+                    },
+                    self.timeout_config().file_io,
+                    "fs_write_vectored",
+                )
+                .map_err(io_failure),
+        };
+
+        match result {
+            Ok(total_bytes) => {
+                info!(
+                    "PID {} wrote file vectored: {:?} ({} bytes from {} segments)",
+                    pid,
+                    path,
+                    total_bytes,
+                    segments.len()
+                );
+                span.record("bytes_written", &format!("{}", total_bytes));
+                span.record_result(true);
+
+                match json::to_vec(&serde_json::json!({ "total_bytes": total_bytes })) {
+                    Ok(data) => SyscallResult::success_with_data(data),
+                    Err(e) => {
+                        warn!("Failed to serialize vectored write result: {}", e);
+                        SyscallResult::error("Internal serialization error")
+                    }
+                }
+            }
+            Err((e, code)) => {
+                error!("Vectored write failed for {:?}: {}", path, e);
+                span.record_error(&format!("Write failed: {}", e));
+                failed_with(format!("Write failed: {}", e), code)
             }
         }
     }
 
+    /// Append to a file as one atomic step, creating it if missing
+    ///
+    /// Returns the file length after the append. Concurrent appenders never
+    /// interleave, unlike a read-modify-write through ReadFile/WriteFile.
+    pub(in crate::syscalls) fn vfs_append(
+        &self,
+        pid: Pid,
+        path: &Path,
+        data: &[u8],
+    ) -> SyscallResult {
+        let span = span_operation("vfs_append");
+        let _guard = span.enter();
+        span.record("pid", &format!("{}", pid));
+        span.record("path", &format!("{:?}", path));
+        span.record("data_len", &format!("{}", data.len()));
+
+        let file_exists = path.exists();
+        let check_path = if file_exists {
+            match path.canonicalize() {
+                Ok(p) => p,
+                Err(e) => {
+                    span.record_error(&format!("Path canonicalization failed: {}", e));
+                    return SyscallResult::error_with_code(
+                        format!("Invalid path: {}", e),
+                        io_error_code(&e),
+                    );
+                }
+            }
+        } else {
+            path.to_path_buf()
+        };
+
+        let request = if file_exists {
+            PermissionRequest::file_write(pid, check_path)
+        } else {
+            PermissionRequest::file_create(pid, check_path)
+        };
+        let response = self.permission_manager().check_and_audit(&request);
+
+        if !response.is_allowed() {
+            span.record_error(response.reason());
+            return SyscallResult::permission_denied(response.reason());
+        }
+
+        let result = match &self.optional().vfs {
+            Some(vfs) => self
+                .timeout_executor()
+                .execute_with_deadline(
+                    || vfs.append(path, data),
+                    self.timeout_config().file_io,
+                    "vfs_append",
+                )
+                .map_err(vfs_failure),
+            None => self
+                .timeout_executor()
+                .execute_with_deadline(
+                    || {
+                        use std::io::{Seek, Write};
+                        let mut file = fs::OpenOptions::new()
+                            .create(true)
+                            .append(true)
+                            .open(path)?;
+                        file.write_all(data)?;
+                        file.stream_position()
+                    },
+                    self.timeout_config().file_io,
+                    "fs_append",
+                )
+                .map_err(io_failure),
+        };
+
+        match result {
+            Ok(length) => {
+                info!(
+                    "PID {} appended {} bytes to {:?} (now {} bytes)",
+                    pid,
+                    data.len(),
+                    path,
+                    length
+                );
+                span.record("length", &format!("{}", length));
+                span.record_result(true);
+
+                match json::to_vec(&serde_json::json!({ "length": length })) {
+                    Ok(data) => SyscallResult::success_with_data(data),
+                    Err(e) => {
+                        warn!("Failed to serialize append result: {}", e);
+                        SyscallResult::error("Internal serialization error")
+                    }
+                }
+            }
+            Err((e, code)) => {
+                error!("Append failed for {:?}: {}", path, e);
+                span.record_error(&format!("Append failed: {}", e));
+                failed_with(format!("Append failed: {}", e), code)
+            }
+        }
+    }
+
+    /// Reject paths that resolve into a read-only VFS mount
+    ///
+    /// For operations that bypass the VFS (move/copy), so a read-only mount
+    /// can't be written through the host filesystem.
+    pub(in crate::syscalls) fn vfs_check_writable(&self, path: &Path) -> Result<(), SyscallResult> {
+        let Some(vfs) = &self.optional().vfs else {
+            return Ok(());
+        };
+
+        match vfs.mount_point(path) {
+            Some(mount) if mount.is_readonly() => {
+                warn!(
+                    "Rejected write to read-only mount {:?}: {:?}",
+                    mount.path, path
+                );
+                Err(vfs_error_result(VfsError::ReadOnly))
+            }
+            _ => Ok(()),
+        }
+    }
+
     /// Delete file using VFS if available
     /// Can block on slow storage (NFS, USB, slow disks)
     pub(in crate::syscalls) fn vfs_delete(&self, pid: Pid, path: &Path) -> SyscallResult {
@@ -281,6 +616,12 @@ pub(in crate::syscalls) fn vfs_delete(&self, pid: Pid, path: &Path) -> SyscallRe
                     );
                     span.record("vfs_timeout_ms", &format!("{}", elapsed_ms));
                 }
+                Err(TimeoutError::Operation(VfsError::ReadOnly)) => {
+                    // Read-only mounts must not be bypassed via the std::fs fallback
+                    warn!("PID {} denied delete on read-only mount: {:?}", pid, path);
+                    span.record_error("Read-only filesystem");
+                    return vfs_error_result(VfsError::ReadOnly);
+                }
                 Err(TimeoutError::Operation(e)) => {
                     warn!(
                         "VFS delete failed for {:?}: {}, falling back to std::fs",
@@ -318,7 +659,113 @@ pub(in crate::syscalls) fn vfs_delete(&self, pid: Pid, path: &Path) -> SyscallRe
             Err(TimeoutError::Operation(e)) => {
                 error!("Delete failed for {:?}: {}", path, e);
                 span.record_error(&format!("Delete failed: {}", e));
-                SyscallResult::error(format!("Delete failed: {}", e))
+                SyscallResult::error_with_code(format!("Delete failed: {}", e), io_error_code(&e))
+            }
+        }
+    }
+
+    /// Rename without replacing an existing destination, using VFS if available
+    ///
+    /// Permissions are checked by the caller. The existence check and rename
+    /// happen atomically in the backend, so a taken destination never falls
+    /// back to std::fs, and neither does a timeout, since the VFS rename may
+    /// still land.
+    pub(in crate::syscalls) fn vfs_rename_noreplace(
+        &self,
+        pid: Pid,
+        source: &Path,
+        destination: &Path,
+    ) -> SyscallResult {
+        let span = span_operation("vfs_rename_noreplace");
+        let _guard = span.enter();
+        span.record("pid", &format!("{}", pid));
+        span.record("path", &format!("{:?}", destination));
+
+        if let Some(vfs) = &self.optional().vfs {
+            let vfs_clone = vfs.clone();
+            let src_clone = source.to_path_buf();
+            let dst_clone = destination.to_path_buf();
+
+            let result = self.timeout_executor().execute_with_deadline(
+                || vfs_clone.rename_noreplace(&src_clone, &dst_clone),
+                self.timeout_config().file_io,
+                "vfs_rename_noreplace",
+            );
+
+            match result {
+                Ok(()) => {
+                    info!(
+                        "PID {} moved file via VFS: {:?} -> {:?}",
+                        pid, source, destination
+                    );
+                    span.record("method", "vfs");
+                    span.record_result(true);
+                    return SyscallResult::success();
+                }
+                Err(TimeoutError::Timeout { elapsed_ms, .. }) => {
+                    error!(
+                        "VFS move timed out for {:?} -> {:?} after {}ms",
+                        source, destination, elapsed_ms
+                    );
+                    span.record_error(&format!("Timeout after {}ms", elapsed_ms));
+                    return SyscallResult::error(format!("Timeout after {}ms", elapsed_ms));
+                }
+                Err(TimeoutError::Operation(
+                    e @ (VfsError::AlreadyExists(_) | VfsError::ReadOnly | VfsError::CrossDevice),
+                )) => {
+                    trace!("VFS move {:?} -> {:?} rejected: {}", source, destination, e);
+                    span.record_error(&format!("Move failed: {}", e));
+                    return SyscallResult::error_with_code(
+                        format!("Move failed: {}", e),
+                        e.typed_code(),
+                    );
+                }
+                Err(TimeoutError::Operation(e)) => {
+                    warn!(
+                        "VFS move failed for {:?} -> {:?}: {}, falling back to std::fs",
+                        source, destination, e
+                    );
+                    span.record("vfs_error", &format!("{}", e));
+                }
+            }
+        }
+
+        trace!("Falling back to std::fs for no-replace move");
+        let src_clone = source.to_path_buf();
+        let dst_clone = destination.to_path_buf();
+        let result = self.timeout_executor().execute_with_deadline(
+            || crate::vfs::local::rename_noreplace(&src_clone, &dst_clone),
+            self.timeout_config().file_io,
+            "file_move",
+        );
+
+        match result {
+            Ok(()) => {
+                info!("PID {} moved file: {:?} -> {:?}", pid, source, destination);
+                span.record("method", "std::fs");
+                span.record_result(true);
+                SyscallResult::success()
+            }
+            Err(TimeoutError::Timeout { elapsed_ms, .. }) => {
+                error!(
+                    "Move timed out for {:?} -> {:?} after {}ms (slow storage?)",
+                    source, destination, elapsed_ms
+                );
+                span.record_error(&format!("Timeout after {}ms", elapsed_ms));
+                SyscallResult::error(format!("Timeout after {}ms", elapsed_ms))
+            }
+            Err(TimeoutError::Operation(e)) if e.kind() == std::io::ErrorKind::AlreadyExists => {
+                let err = VfsError::AlreadyExists(destination.display().to_string().into());
+                span.record_error(&format!("Move failed: {}", err));
+                SyscallResult::error_with_code(format!("Move failed: {}", err), err.typed_code())
+            }
+            Err(TimeoutError::Operation(e)) => {
+                error!(
+                    "Failed to move file {:?} -> {:?}: {}",
+                    source, destination, e
+                );
+                span.record_error(&format!("Move failed: {}", e));
+                SyscallResult::error_with_code(format!("Move failed: {}", e), io_error_code(&e))
             }
         }
     }
@@ -395,6 +842,15 @@ pub(in crate::syscalls) fn vfs_create_dir(&self, pid: Pid, path: &Path) -> Sysca
                     );
                     span.record("vfs_timeout_ms", &format!("{}", elapsed_ms));
                 }
+                Err(TimeoutError::Operation(VfsError::ReadOnly)) => {
+                    // Read-only mounts must not be bypassed via the std::fs fallback
+                    warn!(
+                        "PID {} denied create_dir on read-only mount: {:?}",
+                        pid, path
+                    );
+                    span.record_error("Read-only filesystem");
+                    return vfs_error_result(VfsError::ReadOnly);
+                }
                 Err(TimeoutError::Operation(e)) => {
                     warn!(
                         "VFS create_dir failed for {:?}: {}, falling back to std::fs",
@@ -435,7 +891,7 @@ pub(in crate::syscalls) fn vfs_create_dir(&self, pid: Pid, path: &Path) -> Sysca
             Err(TimeoutError::Operation(e)) => {
                 error!("Create directory failed for {:?}: {}", path, e);
                 span.record_error(&format!("Mkdir failed: {}", e));
-                SyscallResult::error(format!("Mkdir failed: {}", e))
+                SyscallResult::error_with_code(format!("Mkdir failed: {}", e), io_error_code(&e))
             }
         }
     }
@@ -482,6 +938,15 @@ pub(in crate::syscalls) fn vfs_remove_dir(&self, pid: Pid, path: &Path) -> Sysca
                     );
                     span.record("vfs_timeout_ms", &format!("{}", elapsed_ms));
                 }
+                Err(TimeoutError::Operation(VfsError::ReadOnly)) => {
+                    // Read-only mounts must not be bypassed via the std::fs fallback
+                    warn!(
+                        "PID {} denied remove_dir on read-only mount: {:?}",
+                        pid, path
+                    );
+                    span.record_error("Read-only filesystem");
+                    return vfs_error_result(VfsError::ReadOnly);
+                }
                 Err(TimeoutError::Operation(e)) => {
                     warn!(
                         "VFS remove_dir failed for {:?}: {}, falling back to std::fs",
@@ -522,7 +987,10 @@ pub(in crate::syscalls) fn vfs_remove_dir(&self, pid: Pid, path: &Path) -> Sysca
             Err(TimeoutError::Operation(e)) => {
                 error!("Remove directory failed for {:?}: {}", path, e);
                 span.record_error(&format!("Remove directory failed: {}", e));
-                SyscallResult::error(format!("Remove directory failed: {}", e))
+                SyscallResult::error_with_code(
+                    format!("Remove directory failed: {}", e),
+                    io_error_code(&e),
+                )
             }
         }
     }
@@ -685,15 +1153,56 @@ pub(in crate::syscalls) fn vfs_list_dir(&self, pid: Pid, path: &Path) -> Syscall
                 Err(TimeoutError::Operation(e)) => {
                     error!("List directory failed for {:?}: {}", path, e);
                     span.record_error(arena.alloc(format!("List failed: {}", e)));
-                    SyscallResult::error(format!("List failed: {}", e))
+                    SyscallResult::error_with_code(format!("List failed: {}", e), io_error_code(&e))
                 }
             }
         })
     }
 }
 
+/// Error result carrying the VFS error's stable code
+pub(super) fn vfs_error_result(err: VfsError) -> SyscallResult {
+    let code = err.typed_code();
+    SyscallResult::error_with_code(vfs_error_to_string(err), code)
+}
+
+/// Message and stable code for a VFS call that failed or timed out
+fn vfs_failure(err: TimeoutError<VfsError>) -> (String, Option<ErrorCode>) {
+    match err {
+        TimeoutError::Operation(e) => {
+            let code = e.typed_code();
+            (vfs_error_to_string(e), Some(code))
+        }
+        timeout => (timeout.to_string(), None),
+    }
+}
+
+/// Error result with the failure's stable code, when it had one
+fn failed_with(message: String, code: Option<ErrorCode>) -> SyscallResult {
+    match code {
+        Some(code) => SyscallResult::error_with_code(message, code),
+        None => SyscallResult::error(message),
+    }
+}
+
+/// Message and stable code for a std::fs call that failed or timed out
+fn io_failure(err: TimeoutError<std::io::Error>) -> (String, Option<ErrorCode>) {
+    match err {
+        TimeoutError::Operation(e) => (e.to_string(), Some(io_error_code(&e))),
+        timeout => (timeout.to_string(), None),
+    }
+}
+
+/// Stable code for a std::fs failure, classified as the local backend would
+fn io_error_code(e: &std::io::Error) -> ErrorCode {
+    let copy = match e.raw_os_error() {
+        Some(errno) => std::io::Error::from_raw_os_error(errno),
+        None => std::io::Error::from(e.kind()),
+    };
+    LocalFS::io_error(copy, "").typed_code()
+}
+
 /// Convert VfsError to error message
-#[allow(dead_code)]
 fn vfs_error_to_string(err: VfsError) -> String {
     match err {
         VfsError::NotFound(msg) => format!("Not found: {}", msg),
@@ -709,5 +1218,7 @@ fn vfs_error_to_string(err: VfsError) -> String {
         VfsError::FileTooLarge => "File too large".into(),
         VfsError::ReadOnly => "Read-only filesystem".into(),
         VfsError::CrossDevice => "Cross-device link".into(),
+        VfsError::WouldBlock(msg) => format!("Would block: {}", msg),
+        VfsError::Unavailable(msg) => format!("Unavailable: {}", msg),
     }
 }
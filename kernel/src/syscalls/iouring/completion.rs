@@ -81,6 +81,11 @@ pub fn is_empty(&self) -> bool {
         self.ring.is_empty()
     }
 
+    /// Check if queue is full; the next push drops the oldest entry
+    pub fn is_full(&self) -> bool {
+        self.ring.is_full()
+    }
+
     /// Get number of pending completions (approximate, lock-free)
     pub fn pending(&self) -> usize {
         self.ring.len()
@@ -98,6 +103,12 @@ pub struct SyscallCompletionEntry {
     pub result: SyscallResult,
     /// User data from submission
     pub user_data: u64,
+    /// Last chunk of a streamed operation
+    ///
+    /// Streams post several entries under one `seq`; they end with an EOF
+    /// entry or one whose status is not success. Always false for
+    /// single-shot operations.
+    pub eof: bool,
 }
 
 impl SyscallCompletionEntry {
@@ -113,6 +124,15 @@ pub fn new(
             status,
             result,
             user_data,
+            eof: false,
+        }
+    }
+
+    /// Create a completion carrying one chunk of a streamed read
+    pub fn chunk(seq: u64, data: Vec<u8>, eof: bool, user_data: u64) -> Self {
+        Self {
+            eof,
+            ..Self::success(seq, SyscallResult::success_with_data(data), user_data)
         }
     }
 
@@ -125,6 +145,7 @@ pub fn success(seq: u64, result: SyscallResult, user_data: u64) -> Self {
     pub fn error(seq: u64, error: String, user_data: u64) -> Self {
         let result = SyscallResult::Error {
             message: error.clone().into(),
+            code: None,
         };
         Self::new(
             seq,
@@ -138,6 +159,7 @@ pub fn error(seq: u64, error: String, user_data: u64) -> Self {
     pub fn cancelled(seq: u64, user_data: u64) -> Self {
         let result = SyscallResult::Error {
             message: "Operation cancelled".into(),
+            code: None,
         };
         Self::new(seq, SyscallCompletionStatus::Cancelled, result, user_data)
     }
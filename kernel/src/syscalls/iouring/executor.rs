@@ -3,12 +3,18 @@
  * Executes async syscall operations from submission queues
  */
 
-use super::completion::SyscallCompletionStatus;
+use super::completion::{SyscallCompletionEntry, SyscallCompletionStatus};
 use super::ring::SyscallCompletionRing;
-use super::submission::SyscallOpType;
+use super::submission::{SyscallOpType, SyscallSubmissionEntry};
+use crate::core::limits::{IOURING_MAX_STREAM_CHUNK, IOURING_STREAM_CHUNK_SIZE};
+use crate::core::types::Size;
+use crate::syscalls::types::SyscallResult;
 use crate::syscalls::SyscallExecutorWithIpc;
+use std::io::{self, Read};
+use std::path::Path;
 use std::sync::Arc;
-use tracing::{debug, error};
+use std::time::Duration;
+use tracing::debug;
 
 /// Executor for io_uring-style syscall operations
 pub struct IoUringExecutor {
@@ -21,24 +27,15 @@ pub fn new(syscall_executor: SyscallExecutorWithIpc) -> Self {
         Self { syscall_executor }
     }
 
+    /// Syscall executor the operations run against
+    pub(super) fn syscall_executor(&self) -> &SyscallExecutorWithIpc {
+        &self.syscall_executor
+    }
+
     /// Execute pending operations from a ring (single)
     pub async fn execute_async(&self, ring: Arc<SyscallCompletionRing>) {
         if let Some(entry) = ring.pop_submission() {
-            let result = self.execute_operation(&entry.op, entry.pid).await;
-
-            let status = match &result {
-                crate::syscalls::types::SyscallResult::Success { .. } => {
-                    SyscallCompletionStatus::Success
-                }
-                crate::syscalls::types::SyscallResult::Error { message } => {
-                    SyscallCompletionStatus::Error(message.to_string())
-                }
-                crate::syscalls::types::SyscallResult::PermissionDenied { reason } => {
-                    SyscallCompletionStatus::Error(format!("Permission denied: {}", reason))
-                }
-            };
-
-            ring.complete(entry.seq, status, result, entry.user_data);
+            self.execute_entry(&ring, entry).await;
         }
     }
 
@@ -70,23 +67,7 @@ pub async fn execute_batch_async(&self, ring: Arc<SyscallCompletionRing>) {
                 }
 
                 let ring = ring.clone();
-                async move {
-                    let result = self.execute_operation(&entry.op, entry.pid).await;
-
-                    let status = match &result {
-                        crate::syscalls::types::SyscallResult::Success { .. } => {
-                            SyscallCompletionStatus::Success
-                        }
-                        crate::syscalls::types::SyscallResult::Error { message } => {
-                            SyscallCompletionStatus::Error(message.to_string())
-                        }
-                        crate::syscalls::types::SyscallResult::PermissionDenied { reason } => {
-                            SyscallCompletionStatus::Error(format!("Permission denied: {}", reason))
-                        }
-                    };
-
-                    ring.complete(entry.seq, status, result, entry.user_data);
-                }
+                async move { self.execute_entry(&ring, entry).await }
             })
             .collect();
 
@@ -94,6 +75,144 @@ pub async fn execute_batch_async(&self, ring: Arc<SyscallCompletionRing>) {
         futures::future::join_all(futures).await;
     }
 
+    /// Run one submission and post its completion(s)
+    async fn execute_entry(&self, ring: &SyscallCompletionRing, entry: SyscallSubmissionEntry) {
+        if ring.take_cancellation(entry.seq) {
+            ring.complete_entry(SyscallCompletionEntry::cancelled(
+                entry.seq,
+                entry.user_data,
+            ));
+            return;
+        }
+
+        // Submissions bypass the sync executor, so refuse frozen callers here
+        // and stay in flight until the last completion is posted
+        let _in_flight = match self.syscall_executor.enter_freeze_gate(entry.pid) {
+            Ok(guard) => guard,
+            Err(e) => {
+                let result = SyscallResult::from(e);
+                ring.complete(
+                    entry.seq,
+                    completion_status(&result),
+                    result,
+                    entry.user_data,
+                );
+                return;
+            }
+        };
+
+        if let SyscallOpType::ReadFileStream { path, chunk_size } = &entry.op {
+            self.stream_file(ring, &entry, path, *chunk_size).await;
+            return;
+        }
+
+        let result = self.execute_operation(&entry.op, entry.pid).await;
+        ring.complete(
+            entry.seq,
+            completion_status(&result),
+            result,
+            entry.user_data,
+        );
+    }
+
+    /// Read a file in chunks, posting one completion per chunk
+    ///
+    /// The last chunk is flagged EOF. Waits for the application to drain a
+    /// full completion queue rather than dropping chunks, and checks for
+    /// cancellation between chunks.
+    async fn stream_file(
+        &self,
+        ring: &SyscallCompletionRing,
+        entry: &SyscallSubmissionEntry,
+        path: &Path,
+        chunk_size: Size,
+    ) {
+        let chunk_size = match chunk_size {
+            0 => IOURING_STREAM_CHUNK_SIZE,
+            n => n.min(IOURING_MAX_STREAM_CHUNK),
+        };
+
+        let mut reader = match self.syscall_executor.vfs_open_read(entry.pid, path) {
+            Ok(reader) => reader,
+            Err(result) => {
+                ring.complete(
+                    entry.seq,
+                    completion_status(&result),
+                    result,
+                    entry.user_data,
+                );
+                return;
+            }
+        };
+
+        // Read one chunk ahead so the last data chunk can carry EOF
+        let mut current = match read_chunk(&mut reader, chunk_size) {
+            Ok(chunk) => chunk,
+            Err(e) => {
+                let error = SyscallCompletionEntry::error(
+                    entry.seq,
+                    format!("Read failed: {}", e),
+                    entry.user_data,
+                );
+                ring.complete_chunk(error);
+                return;
+            }
+        };
+
+        loop {
+            let next = if current.len() < chunk_size {
+                Ok(Vec::new())
+            } else {
+                read_chunk(&mut reader, chunk_size)
+            };
+            let next = match next {
+                Ok(next) => next,
+                Err(e) => {
+                    let error = SyscallCompletionEntry::error(
+                        entry.seq,
+                        format!("Read failed: {}", e),
+                        entry.user_data,
+                    );
+                    ring.complete_chunk(error);
+                    return;
+                }
+            };
+            let eof = next.is_empty();
+
+            // Backpressure: hold the chunk until there is room for it
+            while ring.cq_is_full() {
+                if ring.take_cancellation(entry.seq) {
+                    ring.complete_entry(SyscallCompletionEntry::cancelled(
+                        entry.seq,
+                        entry.user_data,
+                    ));
+                    return;
+                }
+                tokio::time::sleep(Duration::from_millis(1)).await;
+            }
+
+            ring.complete_chunk(SyscallCompletionEntry::chunk(
+                entry.seq,
+                current,
+                eof,
+                entry.user_data,
+            ));
+            if eof {
+                return;
+            }
+
+            tokio::task::yield_now().await;
+            if ring.take_cancellation(entry.seq) {
+                ring.complete_entry(SyscallCompletionEntry::cancelled(
+                    entry.seq,
+                    entry.user_data,
+                ));
+                return;
+            }
+            current = next;
+        }
+    }
+
     /// Execute a single operation
     ///
     /// Executes syscalls directly without spawn_blocking for better performance.
@@ -108,6 +227,10 @@ async fn execute_operation(
         match op {
             // File I/O operations
             SyscallOpType::ReadFile { path } => self.syscall_executor.read_file(pid, path),
+            // Streaming is handled in execute_entry; read whole if called directly
+            SyscallOpType::ReadFileStream { path, .. } => {
+                self.syscall_executor.read_file(pid, path)
+            }
             SyscallOpType::WriteFile { path, data } => {
                 self.syscall_executor.write_file(pid, path, data)
             }
@@ -159,6 +282,7 @@ async fn execute_operation(
                 crate::syscalls::types::SyscallResult::Error {
                     message: "Direct IPC send not supported via io_uring, use SendQueue instead"
                         .into(),
+                    code: None,
                 }
             }
             SyscallOpType::IpcRecv { size: _ } => {
@@ -167,8 +291,26 @@ async fn execute_operation(
                 crate::syscalls::types::SyscallResult::Error {
                     message: "Direct IPC recv not supported via io_uring, use ReceiveQueue instead"
                         .into(),
+                    code: None,
                 }
             }
         }
     }
 }
+
+fn completion_status(result: &SyscallResult) -> SyscallCompletionStatus {
+    match result {
+        SyscallResult::Success { .. } => SyscallCompletionStatus::Success,
+        SyscallResult::Error { message, .. } => SyscallCompletionStatus::Error(message.to_string()),
+        SyscallResult::PermissionDenied { reason } => {
+            SyscallCompletionStatus::Error(format!("Permission denied: {}", reason))
+        }
+    }
+}
+
+/// Read up to `size` bytes; shorter only at end of file
+fn read_chunk(reader: &mut impl Read, size: usize) -> io::Result<Vec<u8>> {
+    let mut chunk = Vec::with_capacity(size);
+    reader.by_ref().take(size as u64).read_to_end(&mut chunk)?;
+    Ok(chunk)
+}
@@ -121,6 +121,7 @@ fn handle(&self, pid: Pid, syscall: &Syscall) -> Option<SyscallResult> {
             Err(e) => {
                 return Some(SyscallResult::Error {
                     message: format!("io_uring submission failed: {}", e).into(),
+                    code: None,
                 });
             }
         };
@@ -131,6 +132,7 @@ fn handle(&self, pid: Pid, syscall: &Syscall) -> Option<SyscallResult> {
                 Ok(completion) => Some(completion.result),
                 Err(e) => Some(SyscallResult::Error {
                     message: format!("io_uring completion failed: {}", e).into(),
+                    code: None,
                 }),
             }
         } else {
@@ -12,15 +12,18 @@
 mod completion;
 mod executor;
 pub mod handlers;
+mod notify;
 mod ring;
 mod submission;
 
 pub use completion::{SyscallCompletionEntry, SyscallCompletionQueue, SyscallCompletionStatus};
 pub use executor::IoUringExecutor;
+pub use notify::CompletionNotifier;
 pub use ring::SyscallCompletionRing;
 pub use submission::{SyscallOpType, SyscallSubmissionEntry, SyscallSubmissionQueue};
 
-use crate::core::types::Pid;
+use crate::core::types::{Fd, Pid};
+use crate::syscalls::impls::FileHandle;
 use ahash::RandomState;
 use dashmap::DashMap;
 use std::sync::Arc;
@@ -164,6 +167,20 @@ pub fn reap_completions(
         Ok(completions)
     }
 
+    /// Cancel a submitted operation
+    ///
+    /// See [`SyscallCompletionRing::cancel`] for which operations stop.
+    pub fn cancel(&self, pid: Pid, seq: u64) -> Result<(), IoUringError> {
+        let ring = self.get_ring(pid).ok_or(IoUringError::RingNotFound(pid))?;
+        ring.cancel(seq);
+        debug!(
+            pid = pid,
+            seq = seq,
+            "io_uring operation cancellation requested"
+        );
+        Ok(())
+    }
+
     /// Wait for a specific completion (blocking)
     pub fn wait_completion(
         &self,
@@ -175,6 +192,39 @@ pub fn wait_completion(
         ring.wait_completion(seq)
     }
 
+    /// Get an fd that becomes readable when completions are posted for `pid`
+    ///
+    /// Reads return the number of completions since the last read as a
+    /// little-endian u64, or WouldBlock if there were none; reap them with
+    /// `reap_completions` as usual. Creates the ring if needed. Closing the
+    /// fd detaches the notifier.
+    pub fn completion_fd(&self, pid: Pid) -> Result<Fd, IoUringError> {
+        use crate::security::ResourceLimitProvider;
+
+        let syscalls = self.executor.syscall_executor();
+        if let Some(limits) = syscalls.sandbox_manager().get_limits(pid) {
+            let current_fd_count = syscalls.fd_manager().get_fd_count(pid);
+            if current_fd_count >= limits.max_file_descriptors {
+                return Err(IoUringError::InvalidOperation(format!(
+                    "File descriptor limit exceeded: {}/{} FDs open",
+                    current_fd_count, limits.max_file_descriptors
+                )));
+            }
+        }
+
+        let ring = self.get_or_create_ring(pid)?;
+        let notifier = CompletionNotifier::new();
+        ring.attach_notifier(&notifier);
+
+        let handle = Arc::new(FileHandle::from_completion_notifier(notifier));
+        let fd_guard = syscalls.fd_manager().allocate_fd_guard(pid, handle, None);
+        let fd = fd_guard.fd();
+        std::mem::forget(fd_guard);
+
+        info!(pid = pid, fd = fd, "io_uring completion fd created");
+        Ok(fd)
+    }
+
     /// Destroy a completion ring
     pub fn destroy_ring(&self, pid: Pid) -> Result<(), IoUringError> {
         self.rings.remove(&pid);
@@ -0,0 +1,158 @@
+/*!
+ * Completion Notification
+ * eventfd-style readiness for io_uring completion rings
+ *
+ * A notifier counts completions posted to a ring since it was last read.
+ * Reading returns that count as a little-endian u64 and resets it, or fails
+ * with WouldBlock if nothing completed, so consumers can wait on the fd from
+ * their own event loop instead of polling reap_completions. Rings only hold
+ * weak references: once every fd for a notifier is closed, it detaches.
+ */
+
+use crate::core::sync::readiness::{notify_readiness, ReadinessKey};
+use parking_lot::RwLock;
+use std::io;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Weak};
+
+/// Completion counter shared between a ring and its fds
+#[derive(Debug, Clone, Default)]
+pub struct CompletionNotifier {
+    count: Arc<AtomicU64>,
+}
+
+impl CompletionNotifier {
+    /// Create a notifier with no pending completions
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record `n` newly posted completions
+    #[inline]
+    pub fn signal(&self, n: u64) {
+        self.count.fetch_add(n, Ordering::AcqRel);
+        notify_readiness(self.readiness_key());
+    }
+
+    /// Key pollers of this notifier's fds wait on
+    #[inline]
+    pub fn readiness_key(&self) -> ReadinessKey {
+        ReadinessKey::of(&*self.count)
+    }
+
+    /// Whether a read would return a count
+    #[inline]
+    pub fn is_readable(&self) -> bool {
+        self.count.load(Ordering::Acquire) > 0
+    }
+
+    /// Completions since the last call, resetting the count
+    #[inline]
+    pub fn take(&self) -> u64 {
+        self.count.swap(0, Ordering::AcqRel)
+    }
+
+    /// Read the completion count into `buf` as a little-endian u64
+    ///
+    /// Fails with WouldBlock when nothing completed since the last read.
+    pub fn read_into(&self, buf: &mut [u8]) -> io::Result<usize> {
+        const LEN: usize = std::mem::size_of::<u64>();
+        if buf.len() < LEN {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "completion fd reads need an 8-byte buffer",
+            ));
+        }
+
+        match self.take() {
+            0 => Err(io::ErrorKind::WouldBlock.into()),
+            count => {
+                buf[..LEN].copy_from_slice(&count.to_le_bytes());
+                Ok(LEN)
+            }
+        }
+    }
+
+    fn downgrade(&self) -> Weak<AtomicU64> {
+        Arc::downgrade(&self.count)
+    }
+}
+
+/// Notifiers attached to one ring
+#[derive(Debug, Default)]
+pub(super) struct NotifierSet {
+    attached: RwLock<Vec<Weak<AtomicU64>>>,
+}
+
+impl NotifierSet {
+    pub(super) fn attach(&self, notifier: &CompletionNotifier) {
+        self.attached.write().push(notifier.downgrade());
+    }
+
+    /// Signal every live notifier, dropping the ones whose fds were closed
+    pub(super) fn signal(&self) {
+        let mut detached = false;
+        {
+            let attached = self.attached.read();
+            if attached.is_empty() {
+                return;
+            }
+            for weak in attached.iter() {
+                match weak.upgrade() {
+                    Some(count) => {
+                        count.fetch_add(1, Ordering::AcqRel);
+                        notify_readiness(ReadinessKey::of(&*count));
+                    }
+                    None => detached = true,
+                }
+            }
+        }
+
+        if detached {
+            self.attached.write().retain(|weak| weak.strong_count() > 0);
+        }
+    }
+
+    pub(super) fn len(&self) -> usize {
+        self.attached
+            .read()
+            .iter()
+            .filter(|weak| weak.strong_count() > 0)
+            .count()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_read_returns_and_resets_count() {
+        let notifier = CompletionNotifier::new();
+        let mut buf = [0u8; 8];
+
+        let err = notifier.read_into(&mut buf).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::WouldBlock);
+
+        notifier.signal(3);
+        assert!(notifier.is_readable());
+        assert_eq!(notifier.read_into(&mut buf).unwrap(), 8);
+        assert_eq!(u64::from_le_bytes(buf), 3);
+        assert!(!notifier.is_readable());
+    }
+
+    #[test]
+    fn test_dropped_notifier_detaches() {
+        let set = NotifierSet::default();
+        let notifier = CompletionNotifier::new();
+        set.attach(&notifier);
+
+        set.signal();
+        assert_eq!(notifier.take(), 1);
+        assert_eq!(set.len(), 1);
+
+        drop(notifier);
+        set.signal();
+        assert_eq!(set.len(), 0);
+    }
+}
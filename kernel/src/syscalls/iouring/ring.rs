@@ -4,11 +4,14 @@
  */
 
 use super::completion::{SyscallCompletionEntry, SyscallCompletionQueue, SyscallCompletionStatus};
+use super::notify::{CompletionNotifier, NotifierSet};
 use super::submission::{SyscallSubmissionEntry, SyscallSubmissionQueue};
 use super::IoUringError;
 use crate::core::sync::lockfree::SeqlockStats;
 use crate::core::sync::WaitQueue;
 use crate::core::types::Pid;
+use parking_lot::Mutex;
+use std::collections::HashMap;
 use std::sync::Arc;
 use std::time::{Duration, Instant};
 
@@ -29,6 +32,11 @@ pub struct SyscallCompletionRing {
     stats: Arc<RingStats>,
     /// Efficient wait queue for completion notifications
     wait_queue: WaitQueue<u64>,
+    /// Completion fds to signal when an entry is posted
+    notifiers: NotifierSet,
+    /// Submitted operations that haven't finished, and whether each was
+    /// asked to stop; entries go away when the operation finishes
+    in_flight: Mutex<HashMap<u64, bool>>,
 }
 
 impl SyscallCompletionRing {
@@ -47,15 +55,23 @@ pub fn new(pid: Pid, sq_size: usize, cq_size: usize) -> Self {
             ),
             // Use low_latency config for syscall completions
             wait_queue: WaitQueue::low_latency(),
+            notifiers: NotifierSet::default(),
+            in_flight: Mutex::default(),
         }
     }
 
-    /// Submit an entry to the submission queue (lock-free)
+    /// Submit an entry to the submission queue
     ///
     /// # Performance
-    /// Hot path - zero-contention atomic operation
+    /// Hot path - the queue push is lock-free, but it happens under the
+    /// in-flight mutex so cancellation sees every submitted operation
     pub fn submit(&self, entry: SyscallSubmissionEntry) -> Result<u64, IoUringError> {
+        // Hold the lock across the push so the operation can't finish before
+        // it is recorded as in flight
+        let mut in_flight = self.in_flight.lock();
         let seq = self.submission_queue.push(entry)?;
+        in_flight.insert(seq, false);
+        drop(in_flight);
         self.stats.write(|c| c.submissions += 1);
         Ok(seq)
     }
@@ -87,11 +103,69 @@ pub fn complete(
         result: crate::syscalls::types::SyscallResult,
         user_data: u64,
     ) {
-        let entry = SyscallCompletionEntry::new(seq, status, result, user_data);
-        let _ = self.completion_queue.push(entry);
-        self.stats.write(|c| c.completions += 1);
+        self.post(
+            SyscallCompletionEntry::new(seq, status, result, user_data),
+            true,
+        );
+    }
+
+    /// Post one chunk of a streamed operation
+    ///
+    /// Only the final entry (EOF, error or cancellation) counts towards the
+    /// completion stats.
+    pub fn complete_chunk(&self, entry: SyscallCompletionEntry) {
+        let finished = entry.eof || !entry.status.is_success();
+        self.post(entry, finished);
+    }
+
+    /// Post a completion that ends its operation
+    pub fn complete_entry(&self, entry: SyscallCompletionEntry) {
+        self.post(entry, true);
+    }
+
+    fn post(&self, entry: SyscallCompletionEntry, finished: bool) {
+        let seq = entry.seq;
+        let posted = self.completion_queue.push(entry).is_ok();
+        if finished {
+            self.stats.write(|c| c.completions += 1);
+            self.in_flight.lock().remove(&seq);
+        }
 
         self.wait_queue.wake_one(seq);
+        if posted {
+            self.notifiers.signal();
+        }
+    }
+
+    /// Ask a submitted operation to stop
+    ///
+    /// Operations not yet started complete as cancelled; streamed reads stop
+    /// at the next chunk boundary. Operations already running to completion
+    /// are unaffected, and cancelling a finished operation does nothing.
+    pub fn cancel(&self, seq: u64) {
+        if let Some(cancelled) = self.in_flight.lock().get_mut(&seq) {
+            *cancelled = true;
+        }
+    }
+
+    /// Whether `seq` has been cancelled, consuming the request
+    pub fn take_cancellation(&self, seq: u64) -> bool {
+        self.in_flight
+            .lock()
+            .get_mut(&seq)
+            .is_some_and(std::mem::take)
+    }
+
+    /// Signal `notifier` for every completion posted from now on
+    ///
+    /// The ring holds it weakly, so dropping the last clone detaches it.
+    pub fn attach_notifier(&self, notifier: &CompletionNotifier) {
+        self.notifiers.attach(notifier);
+    }
+
+    /// Number of notifiers still attached
+    pub fn notifier_count(&self) -> usize {
+        self.notifiers.len()
     }
 
     /// Wait for a completion with timeout (blocking)
@@ -173,6 +247,11 @@ pub fn cq_is_empty(&self) -> bool {
         self.completion_queue.is_empty()
     }
 
+    /// Check if completion queue is full (lock-free)
+    pub fn cq_is_full(&self) -> bool {
+        self.completion_queue.is_full()
+    }
+
     /// Get statistics (lock-free)
     pub fn stats(&self) -> RingStatistics {
         let c = self.stats.read();
@@ -197,3 +276,42 @@ pub struct RingStatistics {
     pub submissions: u64,
     pub completions: u64,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::syscalls::types::SyscallResult;
+    use std::path::PathBuf;
+
+    #[test]
+    fn test_cancellations_are_pruned() {
+        let ring = SyscallCompletionRing::new(1, 8, 8);
+        let submit = |ring: &SyscallCompletionRing| {
+            ring.submit(SyscallSubmissionEntry::read_file(1, PathBuf::from("/x"), 0))
+                .unwrap()
+        };
+
+        // Finishing a cancelled operation forgets it
+        let seq = submit(&ring);
+        ring.cancel(seq);
+        ring.complete(
+            seq,
+            SyscallCompletionStatus::Cancelled,
+            SyscallResult::success(),
+            0,
+        );
+        assert!(ring.in_flight.lock().is_empty());
+
+        // Cancelling after completion records nothing
+        let seq = submit(&ring);
+        ring.complete(
+            seq,
+            SyscallCompletionStatus::Success,
+            SyscallResult::success(),
+            0,
+        );
+        ring.cancel(seq);
+        assert!(!ring.take_cancellation(seq));
+        assert!(ring.in_flight.lock().is_empty());
+    }
+}
@@ -116,6 +116,18 @@ pub fn read_file(pid: Pid, path: PathBuf, user_data: u64) -> Self {
         Self::new(pid, SyscallOpType::ReadFile { path }, user_data)
     }
 
+    /// Create a streamed read operation
+    ///
+    /// Posts one completion per `chunk_size` bytes, the last flagged EOF. A
+    /// `chunk_size` of 0 uses the default.
+    pub fn read_file_stream(pid: Pid, path: PathBuf, chunk_size: Size, user_data: u64) -> Self {
+        Self::new(
+            pid,
+            SyscallOpType::ReadFileStream { path, chunk_size },
+            user_data,
+        )
+    }
+
     /// Create a write file operation
     pub fn write_file(pid: Pid, path: PathBuf, data: Vec<u8>, user_data: u64) -> Self {
         Self::new(pid, SyscallOpType::WriteFile { path, data }, user_data)
@@ -185,6 +197,11 @@ pub enum SyscallOpType {
     ReadFile {
         path: PathBuf,
     },
+    /// Read a file in chunks, posting a completion as each one arrives
+    ReadFileStream {
+        path: PathBuf,
+        chunk_size: Size,
+    },
     WriteFile {
         path: PathBuf,
         data: Vec<u8>,
@@ -252,6 +269,7 @@ pub fn is_io_bound(&self) -> bool {
         matches!(
             self,
             SyscallOpType::ReadFile { .. }
+                | SyscallOpType::ReadFileStream { .. }
                 | SyscallOpType::WriteFile { .. }
                 | SyscallOpType::Open { .. }
                 | SyscallOpType::Fsync { .. }
@@ -279,6 +297,7 @@ pub fn is_batchable(&self) -> bool {
     pub fn name(&self) -> &'static str {
         match self {
             SyscallOpType::ReadFile { .. } => "read_file",
+            SyscallOpType::ReadFileStream { .. } => "read_file_stream",
             SyscallOpType::WriteFile { .. } => "write_file",
             SyscallOpType::Open { .. } => "open",
             SyscallOpType::Close { .. } => "close",
@@ -4,12 +4,15 @@
  */
 
 use crate::core::serialization::{bincode, json};
-use crate::core::types::Pid;
+use crate::core::types::{Fd, Pid};
+use crate::ipc::{PipeError, PipeId, PipeWriteMode};
 use crate::permissions::{Action, PermissionChecker, PermissionRequest, Resource};
 use crate::syscalls::core::executor::SyscallExecutorWithIpc;
+use crate::syscalls::impls::FileHandle;
 use crate::syscalls::timeout::executor::TimeoutError;
-use crate::syscalls::types::SyscallResult;
+use crate::syscalls::types::{SyscallError, SyscallResult};
 use log::{error, info};
+use std::sync::Arc;
 
 impl SyscallExecutorWithIpc {
     pub(in crate::syscalls) fn create_pipe(
@@ -48,16 +51,24 @@ pub(in crate::syscalls) fn create_pipe(
             }
             Err(e) => {
                 error!("Failed to create pipe: {}", e);
-                SyscallResult::error(format!("Pipe creation failed: {}", e))
+                SyscallResult::error_with_code(
+                    format!("Pipe creation failed: {}", e),
+                    e.typed_code(),
+                )
             }
         }
     }
 
+    /// Write to a pipe, waiting for space
+    ///
+    /// By default the write is all-or-nothing. With `partial`, writes larger
+    /// than PIPE_BUF take whatever space is free and return the byte count.
     pub(in crate::syscalls) fn write_pipe(
         &self,
         pid: Pid,
         pipe_id: u32,
         data: &[u8],
+        partial: bool,
     ) -> SyscallResult {
         let request = PermissionRequest::new(
             pid,
@@ -74,11 +85,15 @@ pub(in crate::syscalls) fn write_pipe(
 
         // Direct access - no Option check!
         let pipe_manager = &self.ipc().pipe_manager();
+        let mode = if partial {
+            PipeWriteMode::Partial
+        } else {
+            PipeWriteMode::Atomic
+        };
 
         // Use generic timeout executor for all blocking operations
-        use crate::ipc::pipe::PipeError;
         let result = self.timeout_executor().execute_with_retry(
-            || pipe_manager.write(pipe_id, pid, data),
+            || pipe_manager.write_with_mode(pipe_id, pid, data, mode),
             |e| matches!(e, PipeError::WouldBlock(_)),
             self.timeout_config().pipe_write,
             "pipe_write",
@@ -104,16 +119,21 @@ pub(in crate::syscalls) fn write_pipe(
             }
             Err(TimeoutError::Operation(e)) => {
                 error!("Pipe write failed: {}", e);
-                SyscallResult::error(format!("Pipe write failed: {}", e))
+                SyscallResult::error_with_code(format!("Pipe write failed: {}", e), e.typed_code())
             }
         }
     }
 
+    /// Read from a pipe, waiting for data unless `nonblocking`
+    ///
+    /// Non-blocking reads of an empty, open pipe fail with
+    /// `SyscallError::WouldBlock`; a closed, drained pipe reads as empty (EOF).
     pub(in crate::syscalls) fn read_pipe(
         &self,
         pid: Pid,
         pipe_id: u32,
         size: usize,
+        nonblocking: bool,
     ) -> SyscallResult {
         let request = PermissionRequest::new(
             pid,
@@ -131,8 +151,31 @@ pub(in crate::syscalls) fn read_pipe(
         // Direct access - no Option check!
         let pipe_manager = &self.ipc().pipe_manager();
 
+        if nonblocking {
+            return match pipe_manager.read(pipe_id, pid, size) {
+                Ok(data) => {
+                    info!(
+                        "PID {} read {} bytes from pipe {} (non-blocking)",
+                        pid,
+                        data.len(),
+                        pipe_id
+                    );
+                    SyscallResult::success_with_data(data)
+                }
+                Err(PipeError::WouldBlock(_)) => {
+                    SyscallError::would_block(format!("pipe {} is empty", pipe_id)).into()
+                }
+                Err(e) => {
+                    error!("Pipe read failed: {}", e);
+                    SyscallResult::error_with_code(
+                        format!("Pipe read failed: {}", e),
+                        e.typed_code(),
+                    )
+                }
+            };
+        }
+
         // Use generic timeout executor for all blocking operations
-        use crate::ipc::pipe::PipeError;
         let result = self.timeout_executor().execute_with_retry(
             || pipe_manager.read(pipe_id, pid, size),
             |e| matches!(e, PipeError::WouldBlock(_)),
@@ -159,7 +202,7 @@ pub(in crate::syscalls) fn read_pipe(
             }
             Err(TimeoutError::Operation(e)) => {
                 error!("Pipe read failed: {}", e);
-                SyscallResult::error(format!("Pipe read failed: {}", e))
+                SyscallResult::error_with_code(format!("Pipe read failed: {}", e), e.typed_code())
             }
         }
     }
@@ -175,7 +218,7 @@ pub(in crate::syscalls) fn close_pipe(&self, pid: Pid, pipe_id: u32) -> SyscallR
             }
             Err(e) => {
                 error!("Pipe close failed: {}", e);
-                SyscallResult::error(format!("Pipe close failed: {}", e))
+                SyscallResult::error_with_code(format!("Pipe close failed: {}", e), e.typed_code())
             }
         }
     }
@@ -191,7 +234,10 @@ pub(in crate::syscalls) fn destroy_pipe(&self, pid: Pid, pipe_id: u32) -> Syscal
             }
             Err(e) => {
                 error!("Pipe destroy failed: {}", e);
-                SyscallResult::error(format!("Pipe destroy failed: {}", e))
+                SyscallResult::error_with_code(
+                    format!("Pipe destroy failed: {}", e),
+                    e.typed_code(),
+                )
             }
         }
     }
@@ -213,8 +259,70 @@ pub(in crate::syscalls) fn pipe_stats(&self, pid: Pid, pipe_id: u32) -> SyscallR
             },
             Err(e) => {
                 error!("Pipe stats failed: {}", e);
-                SyscallResult::error(format!("Pipe stats failed: {}", e))
+                SyscallResult::error_with_code(format!("Pipe stats failed: {}", e), e.typed_code())
             }
         }
     }
+
+    /// Open the caller's end of a pipe as a file descriptor
+    ///
+    /// Returns `{"fd": fd}`; see `pipe_fd`.
+    pub(in crate::syscalls) fn open_pipe_fd(&self, pid: Pid, pipe_id: PipeId) -> SyscallResult {
+        let fd = match self.pipe_fd(pid, pipe_id) {
+            Ok(fd) => fd,
+            Err(PipeError::PermissionDenied(reason)) => {
+                return SyscallResult::permission_denied(reason);
+            }
+            Err(e) => {
+                error!("Pipe fd failed: {}", e);
+                return SyscallResult::error_with_code(
+                    format!("Pipe fd failed: {}", e),
+                    e.typed_code(),
+                );
+            }
+        };
+
+        match json::to_vec(&serde_json::json!({ "fd": fd })) {
+            Ok(data) => SyscallResult::success_with_data(data),
+            Err(e) => {
+                error!("Failed to serialize pipe fd result: {}", e);
+                SyscallResult::error("Internal serialization error")
+            }
+        }
+    }
+
+    /// Expose the caller's end of a pipe as a file descriptor
+    ///
+    /// The pipe's reader gets a readable fd and its writer a writable one,
+    /// so the pipe can be used with Read and Poll. Reads and writes through
+    /// the fd never block. Closing the fd leaves the pipe open.
+    pub fn pipe_fd(&self, pid: Pid, pipe_id: PipeId) -> Result<Fd, PipeError> {
+        use crate::security::ResourceLimitProvider;
+
+        let pipe_manager = self.ipc().pipe_manager();
+        let stats = pipe_manager.stats(pipe_id)?;
+        if stats.reader_pid != pid && stats.writer_pid != pid {
+            return Err(PipeError::PermissionDenied(
+                "Not a pipe endpoint".to_string(),
+            ));
+        }
+
+        if let Some(limits) = self.sandbox_manager().get_limits(pid) {
+            let current_fd_count = self.fd_manager().get_fd_count(pid);
+            if current_fd_count >= limits.max_file_descriptors {
+                return Err(PipeError::PermissionDenied(format!(
+                    "File descriptor limit exceeded: {}/{} FDs open",
+                    current_fd_count, limits.max_file_descriptors
+                )));
+            }
+        }
+
+        let handle = Arc::new(FileHandle::from_pipe(pipe_manager.clone(), pipe_id, pid));
+        let fd_guard = self.fd_manager().allocate_fd_guard(pid, handle, None);
+        let fd = fd_guard.fd();
+        std::mem::forget(fd_guard);
+
+        info!("PID {} opened pipe {} as FD {}", pid, pipe_id, fd);
+        Ok(fd)
+    }
 }
@@ -3,6 +3,7 @@
  * Handle message queue creation, send, receive, and pub/sub
  */
 
+use crate::core::limits::MAX_QUEUE_DRAIN_TIMEOUT;
 use crate::core::serialization::bincode;
 use crate::core::serialization::json;
 use crate::core::types::Pid;
@@ -10,8 +11,18 @@
 use crate::permissions::{Action, PermissionChecker, PermissionRequest, Resource};
 use crate::security::Capability;
 use crate::syscalls::core::executor::SyscallExecutorWithIpc;
-use crate::syscalls::types::SyscallResult;
+use crate::syscalls::types::{SyscallError, SyscallResult};
 use log::{error, info};
+use std::time::Duration;
+
+/// A received message as returned to the caller
+#[derive(serde::Serialize)]
+struct MessageResponse {
+    id: u64,
+    from: u32,
+    data: Vec<u8>,
+    priority: u8,
+}
 
 impl SyscallExecutorWithIpc {
     pub(in crate::syscalls) fn create_queue(
@@ -47,8 +58,11 @@ pub(in crate::syscalls) fn create_queue(
             "fifo" => crate::ipc::QueueType::Fifo,
             "priority" => crate::ipc::QueueType::Priority,
             "pubsub" => crate::ipc::QueueType::PubSub,
+            "at_least_once" => crate::ipc::QueueType::AtLeastOnce,
             _ => {
-                return SyscallResult::error("Invalid queue type (must be: fifo, priority, pubsub)")
+                return SyscallResult::error(
+                    "Invalid queue type (must be: fifo, priority, pubsub, at_least_once)",
+                )
             }
         };
 
@@ -69,7 +83,10 @@ pub(in crate::syscalls) fn create_queue(
             Err(e) => {
                 error!("Failed to create queue: {}", e);
                 span.record_error(&format!("Queue creation failed: {}", e));
-                SyscallResult::error(format!("Queue creation failed: {}", e))
+                SyscallResult::error_with_code(
+                    format!("Queue creation failed: {}", e),
+                    e.typed_code(),
+                )
             }
         }
     }
@@ -126,12 +143,21 @@ pub(in crate::syscalls) fn send_queue(
             Err(e) => {
                 error!("Queue send failed: {}", e);
                 span.record_error(&format!("Send failed: {}", e));
-                SyscallResult::error(format!("Send failed: {}", e))
+                SyscallResult::error_with_code(format!("Send failed: {}", e), e.typed_code())
             }
         }
     }
 
-    pub(in crate::syscalls) fn receive_queue(&self, pid: Pid, queue_id: u32) -> SyscallResult {
+    /// Receive a message, waiting for one unless `nonblocking`
+    ///
+    /// Non-blocking receives on an empty queue fail with
+    /// `SyscallError::WouldBlock`.
+    pub(in crate::syscalls) fn receive_queue(
+        &self,
+        pid: Pid,
+        queue_id: u32,
+        nonblocking: bool,
+    ) -> SyscallResult {
         let span = span_operation("queue_receive");
         let _guard = span.enter();
         span.record("pid", &format!("{}", pid));
@@ -168,16 +194,22 @@ enum ReceiveError {
             Ipc(IpcError),
         }
 
-        let result = self.timeout_executor().execute_with_retry(
-            || match queue_manager.receive(queue_id, pid) {
-                Ok(Some(msg)) => Ok(msg),
-                Ok(None) => Err(ReceiveError::NoMessage),
-                Err(e) => Err(ReceiveError::Ipc(e).into()),
-            },
-            |e| matches!(e, ReceiveError::NoMessage),
-            self.timeout_config().queue_receive,
-            "queue_receive",
-        );
+        let receive = || match queue_manager.receive(queue_id, pid) {
+            Ok(Some(msg)) => Ok(msg),
+            Ok(None) => Err(ReceiveError::NoMessage),
+            Err(e) => Err(ReceiveError::Ipc(e)),
+        };
+
+        let result = if nonblocking {
+            receive().map_err(super::super::TimeoutError::Operation)
+        } else {
+            self.timeout_executor().execute_with_retry(
+                receive,
+                |e| matches!(e, ReceiveError::NoMessage),
+                self.timeout_config().queue_receive,
+                "queue_receive",
+            )
+        };
 
         use crate::core::memory::arena::with_arena;
 
@@ -194,14 +226,6 @@ enum ReceiveError {
                     span.record("bytes_received", bytes_str);
                     span.record_result(true);
 
-                    #[derive(serde::Serialize)]
-                    struct MessageResponse {
-                        id: u64,
-                        from: u32,
-                        data: Vec<u8>,
-                        priority: u8,
-                    }
-
                     let response = MessageResponse {
                         id: msg.id,
                         from: msg.from,
@@ -223,7 +247,7 @@ struct MessageResponse {
                     error!("Failed to read message data: {}", e);
                     let err_msg = arena.alloc(format!("Read failed: {}", e));
                     span.record_error(err_msg);
-                    SyscallResult::error(format!("Read failed: {}", e))
+                    SyscallResult::error_with_code(format!("Read failed: {}", e), e.typed_code())
                 }),
             },
             Err(super::super::TimeoutError::Timeout { elapsed_ms, .. }) => {
@@ -237,7 +261,11 @@ struct MessageResponse {
             Err(super::super::TimeoutError::Operation(ReceiveError::Ipc(e))) => {
                 error!("Queue receive failed: {}", e);
                 span.record_error(&format!("Receive failed: {}", e));
-                SyscallResult::error(format!("Receive failed: {}", e))
+                SyscallResult::error_with_code(format!("Receive failed: {}", e), e.typed_code())
+            }
+            Err(super::super::TimeoutError::Operation(ReceiveError::NoMessage)) if nonblocking => {
+                span.record_error("Would block");
+                SyscallError::would_block(format!("queue {} is empty", queue_id)).into()
             }
             Err(super::super::TimeoutError::Operation(ReceiveError::NoMessage)) => {
                 // Should not reach here (filtered by is_would_block), but handle gracefully
@@ -247,6 +275,130 @@ struct MessageResponse {
         }
     }
 
+    /// Acknowledge a message leased from an at-least-once queue
+    pub(in crate::syscalls) fn ack_queue(
+        &self,
+        pid: Pid,
+        queue_id: u32,
+        msg_id: u64,
+    ) -> SyscallResult {
+        let request = PermissionRequest::new(
+            pid,
+            Resource::IpcChannel {
+                channel_id: queue_id,
+            },
+            Action::Receive,
+        );
+        let response = self.permission_manager().check(&request);
+
+        if !response.is_allowed() {
+            return SyscallResult::permission_denied(response.reason());
+        }
+
+        let queue_manager = match &self.ipc().queue_manager() {
+            Some(qm) => qm,
+            None => return SyscallResult::error("Queue manager not available"),
+        };
+
+        match queue_manager.ack(queue_id, pid, msg_id) {
+            Ok(()) => {
+                info!("PID {} acked message {} on queue {}", pid, msg_id, queue_id);
+                SyscallResult::success()
+            }
+            Err(e) => {
+                error!("Queue ack failed: {}", e);
+                SyscallResult::error_with_code(format!("Ack failed: {}", e), e.typed_code())
+            }
+        }
+    }
+
+    /// Configure redelivery for an at-least-once queue (owner only)
+    pub(in crate::syscalls) fn set_queue_ack_policy(
+        &self,
+        pid: Pid,
+        queue_id: u32,
+        visibility_timeout_ms: u64,
+        max_attempts: u32,
+    ) -> SyscallResult {
+        let queue_manager = match &self.ipc().queue_manager() {
+            Some(qm) => qm,
+            None => return SyscallResult::error("Queue manager not available"),
+        };
+
+        let visibility_timeout = Duration::from_millis(visibility_timeout_ms);
+        match queue_manager.set_ack_policy(queue_id, pid, visibility_timeout, max_attempts) {
+            Ok(()) => {
+                info!(
+                    "PID {} set ack policy on queue {} ({:?} visibility, {} attempts)",
+                    pid, queue_id, visibility_timeout, max_attempts
+                );
+                SyscallResult::success()
+            }
+            Err(e) => {
+                error!("Queue ack policy update failed: {}", e);
+                SyscallResult::error_with_code(
+                    format!("Ack policy update failed: {}", e),
+                    e.typed_code(),
+                )
+            }
+        }
+    }
+
+    /// Drain the dead letters of an at-least-once queue (owner only)
+    ///
+    /// Dead letters keep their queue slot until taken, so owners must drain
+    /// them to free capacity.
+    pub(in crate::syscalls) fn take_dead_letters(&self, pid: Pid, queue_id: u32) -> SyscallResult {
+        let queue_manager = match &self.ipc().queue_manager() {
+            Some(qm) => qm,
+            None => return SyscallResult::error("Queue manager not available"),
+        };
+
+        let messages = match queue_manager.take_dead_letters(queue_id, pid) {
+            Ok(messages) => messages,
+            Err(e) => {
+                error!("Taking dead letters failed: {}", e);
+                return SyscallResult::error_with_code(
+                    format!("Take dead letters failed: {}", e),
+                    e.typed_code(),
+                );
+            }
+        };
+
+        // Keep reading past a failure so the remaining messages are released
+        let mut responses = Vec::with_capacity(messages.len());
+        let mut failed = None;
+        for msg in messages {
+            match queue_manager.read_message_data(&msg) {
+                Ok(data) => responses.push(MessageResponse {
+                    id: msg.id,
+                    from: msg.from,
+                    data,
+                    priority: msg.priority,
+                }),
+                Err(e) => failed = Some(e),
+            }
+        }
+        if let Some(e) = failed {
+            error!("Failed to read dead letter data: {}", e);
+            return SyscallResult::error_with_code(format!("Read failed: {}", e), e.typed_code());
+        }
+
+        info!(
+            "PID {} took {} dead letters from queue {}",
+            pid,
+            responses.len(),
+            queue_id
+        );
+        match bincode::serialize_ipc_message(&responses) {
+            Ok(serialized) => SyscallResult::success_with_data(serialized.to_vec().into()),
+            Err(e) => {
+                error!("Failed to serialize dead letters: {}", e);
+                SyscallResult::error("Serialization failed")
+            }
+        }
+    }
+
     pub(in crate::syscalls) fn subscribe_queue(&self, pid: Pid, queue_id: u32) -> SyscallResult {
         if !self
             .sandbox_manager()
@@ -267,7 +419,7 @@ pub(in crate::syscalls) fn subscribe_queue(&self, pid: Pid, queue_id: u32) -> Sy
             }
             Err(e) => {
                 error!("Queue subscribe failed: {}", e);
-                SyscallResult::error(format!("Subscribe failed: {}", e))
+                SyscallResult::error_with_code(format!("Subscribe failed: {}", e), e.typed_code())
             }
         }
     }
@@ -285,7 +437,7 @@ pub(in crate::syscalls) fn unsubscribe_queue(&self, pid: Pid, queue_id: u32) ->
             }
             Err(e) => {
                 error!("Queue unsubscribe failed: {}", e);
-                SyscallResult::error(format!("Unsubscribe failed: {}", e))
+                SyscallResult::error_with_code(format!("Unsubscribe failed: {}", e), e.typed_code())
             }
         }
     }
@@ -303,25 +455,53 @@ pub(in crate::syscalls) fn close_queue(&self, pid: Pid, queue_id: u32) -> Syscal
             }
             Err(e) => {
                 error!("Queue close failed: {}", e);
-                SyscallResult::error(format!("Close failed: {}", e))
+                SyscallResult::error_with_code(format!("Close failed: {}", e), e.typed_code())
             }
         }
     }
 
-    pub(in crate::syscalls) fn destroy_queue(&self, pid: Pid, queue_id: u32) -> SyscallResult {
+    pub(in crate::syscalls) fn destroy_queue(
+        &self,
+        pid: Pid,
+        queue_id: u32,
+        drain_timeout_ms: Option<u64>,
+    ) -> SyscallResult {
         let queue_manager = match &self.ipc().queue_manager() {
             Some(qm) => qm,
             None => return SyscallResult::error("Queue manager not available"),
         };
 
-        match queue_manager.destroy(queue_id, pid) {
-            Ok(_) => {
-                info!("PID {} destroyed queue {}", pid, queue_id);
-                SyscallResult::success()
+        let Some(drain_timeout_ms) = drain_timeout_ms else {
+            return match queue_manager.destroy(queue_id, pid) {
+                Ok(_) => {
+                    info!("PID {} destroyed queue {}", pid, queue_id);
+                    SyscallResult::success()
+                }
+                Err(e) => {
+                    error!("Queue destroy failed: {}", e);
+                    SyscallResult::error_with_code(format!("Destroy failed: {}", e), e.typed_code())
+                }
+            };
+        };
+
+        let drain_timeout = Duration::from_millis(drain_timeout_ms).min(MAX_QUEUE_DRAIN_TIMEOUT);
+        match queue_manager.destroy_draining(queue_id, pid, drain_timeout) {
+            Ok(dropped) => {
+                info!(
+                    "PID {} destroyed queue {} after draining ({} dropped)",
+                    pid, queue_id, dropped
+                );
+                match json::to_vec(&serde_json::json!({ "dropped": dropped })) {
+                    Ok(data) => SyscallResult::success_with_data(data),
+                    Err(e) => {
+                        error!("Failed to serialize destroy result: {}", e);
+                        SyscallResult::error("Internal serialization error")
+                    }
+                }
             }
             Err(e) => {
                 error!("Queue destroy failed: {}", e);
-                SyscallResult::error(format!("Destroy failed: {}", e))
+                SyscallResult::error_with_code(format!("Destroy failed: {}", e), e.typed_code())
             }
         }
     }
@@ -345,7 +525,7 @@ pub(in crate::syscalls) fn queue_stats(&self, pid: Pid, queue_id: u32) -> Syscal
             },
             Err(e) => {
                 error!("Queue stats failed: {}", e);
-                SyscallResult::error(format!("Stats failed: {}", e))
+                SyscallResult::error_with_code(format!("Stats failed: {}", e), e.typed_code())
             }
         }
     }
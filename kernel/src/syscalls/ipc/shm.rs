@@ -39,7 +39,10 @@ pub(in crate::syscalls) fn create_shm(&self, pid: Pid, size: usize) -> SyscallRe
             }
             Err(e) => {
                 error!("Failed to create shared memory: {}", e);
-                SyscallResult::error(format!("Shared memory creation failed: {}", e))
+                SyscallResult::error_with_code(
+                    format!("Shared memory creation failed: {}", e),
+                    e.typed_code(),
+                )
             }
         }
     }
@@ -76,7 +79,7 @@ pub(in crate::syscalls) fn attach_shm(
             }
             Err(e) => {
                 error!("Shared memory attach failed: {}", e);
-                SyscallResult::error(format!("Attach failed: {}", e))
+                SyscallResult::error_with_code(format!("Attach failed: {}", e), e.typed_code())
             }
         }
     }
@@ -92,7 +95,7 @@ pub(in crate::syscalls) fn detach_shm(&self, pid: Pid, segment_id: u32) -> Sysca
             }
             Err(e) => {
                 error!("Shared memory detach failed: {}", e);
-                SyscallResult::error(format!("Detach failed: {}", e))
+                SyscallResult::error_with_code(format!("Detach failed: {}", e), e.typed_code())
             }
         }
     }
@@ -133,7 +136,7 @@ pub(in crate::syscalls) fn write_shm(
             }
             Err(e) => {
                 error!("Shared memory write failed: {}", e);
-                SyscallResult::error(format!("Write failed: {}", e))
+                SyscallResult::error_with_code(format!("Write failed: {}", e), e.typed_code())
             }
         }
     }
@@ -174,7 +177,44 @@ pub(in crate::syscalls) fn read_shm(
             }
             Err(e) => {
                 error!("Shared memory read failed: {}", e);
-                SyscallResult::error(format!("Read failed: {}", e))
+                SyscallResult::error_with_code(format!("Read failed: {}", e), e.typed_code())
+            }
+        }
+    }
+
+    pub(in crate::syscalls) fn resize_shm(
+        &self,
+        pid: Pid,
+        segment_id: u32,
+        new_size: usize,
+    ) -> SyscallResult {
+        let request = PermissionRequest::new(
+            pid,
+            Resource::IpcChannel {
+                channel_id: segment_id,
+            },
+            Action::Write,
+        );
+        let response = self.permission_manager().check(&request);
+
+        if !response.is_allowed() {
+            return SyscallResult::permission_denied(response.reason());
+        }
+
+        // Direct access - no Option check!
+        let shm_manager = &self.ipc().shm_manager();
+
+        match shm_manager.resize(segment_id, pid, new_size) {
+            Ok(_) => {
+                info!(
+                    "PID {} resized segment {} to {} bytes",
+                    pid, segment_id, new_size
+                );
+                SyscallResult::success()
+            }
+            Err(e) => {
+                error!("Shared memory resize failed: {}", e);
+                SyscallResult::error_with_code(format!("Resize failed: {}", e), e.typed_code())
             }
         }
     }
@@ -190,7 +230,7 @@ pub(in crate::syscalls) fn destroy_shm(&self, pid: Pid, segment_id: u32) -> Sysc
             }
             Err(e) => {
                 error!("Shared memory destroy failed: {}", e);
-                SyscallResult::error(format!("Destroy failed: {}", e))
+                SyscallResult::error_with_code(format!("Destroy failed: {}", e), e.typed_code())
             }
         }
     }
@@ -212,7 +252,7 @@ pub(in crate::syscalls) fn shm_stats(&self, pid: Pid, segment_id: u32) -> Syscal
             },
             Err(e) => {
                 error!("Shared memory stats failed: {}", e);
-                SyscallResult::error(format!("Stats failed: {}", e))
+                SyscallResult::error_with_code(format!("Stats failed: {}", e), e.typed_code())
             }
         }
     }
@@ -194,12 +194,16 @@ fn generate_executor(
 
                 Ok(Box::new(move |pid: Pid, syscall: &Syscall| {
                     match syscall {
-                        Syscall::WritePipe { pipe_id, data } => {
+                        Syscall::WritePipe {
+                            pipe_id,
+                            data,
+                            partial,
+                        } => {
                             if use_fast_path {
                                 // Fast path for frequent pipe writes
-                                executor.write_pipe(pid, *pipe_id, data)
+                                executor.write_pipe(pid, *pipe_id, data, *partial)
                             } else {
-                                executor.write_pipe(pid, *pipe_id, data)
+                                executor.write_pipe(pid, *pipe_id, data, *partial)
                             }
                         }
                         _ => SyscallResult::error("Syscall pattern mismatch"),
@@ -212,12 +216,16 @@ fn generate_executor(
 
                 Ok(Box::new(move |pid: Pid, syscall: &Syscall| {
                     match syscall {
-                        Syscall::ReadPipe { pipe_id, size } => {
+                        Syscall::ReadPipe {
+                            pipe_id,
+                            size,
+                            nonblocking,
+                        } => {
                             if use_fast_path && eliminate_bounds_check {
                                 // Fast path with eliminated bounds checks
-                                executor.read_pipe(pid, *pipe_id, *size)
+                                executor.read_pipe(pid, *pipe_id, *size, *nonblocking)
                             } else {
-                                executor.read_pipe(pid, *pipe_id, *size)
+                                executor.read_pipe(pid, *pipe_id, *size, *nonblocking)
                             }
                         }
                         _ => SyscallResult::error("Syscall pattern mismatch"),
@@ -243,12 +251,15 @@ fn generate_executor(
 
                 Ok(Box::new(move |pid: Pid, syscall: &Syscall| {
                     match syscall {
-                        Syscall::ReceiveQueue { queue_id } => {
+                        Syscall::ReceiveQueue {
+                            queue_id,
+                            nonblocking,
+                        } => {
                             if use_fast_path {
                                 // Fast path for queue operations
-                                executor.receive_queue(pid, *queue_id)
+                                executor.receive_queue(pid, *queue_id, *nonblocking)
                             } else {
-                                executor.receive_queue(pid, *queue_id)
+                                executor.receive_queue(pid, *queue_id, *nonblocking)
                             }
                         }
                         _ => SyscallResult::error("Syscall pattern mismatch"),
@@ -332,7 +343,8 @@ fn generate_executor(
                         length,
                         prot,
                         shared,
-                    } => executor.mmap(pid, path, *length, *offset, *prot, *shared),
+                        populate,
+                    } => executor.mmap(pid, path, *offset, *length, *prot, *shared, *populate),
                     _ => SyscallResult::error("Syscall pattern mismatch"),
                 }))
             }
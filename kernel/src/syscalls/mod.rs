@@ -27,10 +27,19 @@
 mod types_ext;
 
 // Re-export public API from core
-pub use core::{SyscallExecutorWithIpc, SyscallHandler, SyscallHandlerRegistry, SYSTEM_START};
+pub use core::{
+    CreditBudget, CreditShortfall, SyscallAllowList, SyscallCredits, SyscallExecutorWithIpc,
+    SyscallHandler, SyscallHandlerRegistry, DEFAULT_SYSCALL_COST, SYSTEM_START,
+};
 
 // Re-export public API from impls
-pub use impls::{FdManager, FileHandle, Socket, SocketManager, SocketStats};
+pub use impls::{
+    abstract_socket_name, BandwidthLimit, EntropySource, FdManager, FileHandle, HashAlgorithm,
+    HttpPool, HttpPoolConfig, HttpPoolStats, HttpResponse, HttpTransport, ListenerAcceptStats,
+    ReusePortStats, Socket, SocketManager, SocketStats, SocketTransport, TcpTransport, TimerFd,
+    TransportError, TransportStream, ABSTRACT_SOCKET_PREFIX, GRND_NONBLOCK, GRND_RANDOM,
+    O_WRITE_COMBINE, SOL_SOCKET, SO_MAX_PACING_RATE, SO_REUSEPORT,
+};
 
 // Re-export public API from async
 pub use r#async::{AsyncExecutorStats, AsyncSyscallExecutor, SyscallClass};
@@ -40,8 +49,8 @@
 
 // Re-export public API from iouring
 pub use iouring::{
-    IoUringExecutor, IoUringManager, SyscallCompletionEntry, SyscallCompletionRing,
-    SyscallCompletionStatus, SyscallOpType, SyscallSubmissionEntry,
+    CompletionNotifier, IoUringExecutor, IoUringManager, SyscallCompletionEntry,
+    SyscallCompletionRing, SyscallCompletionStatus, SyscallOpType, SyscallSubmissionEntry,
 };
 
 // Re-export public API from jit
@@ -51,13 +60,16 @@
 pub use traits::*;
 
 // Re-export public API from types
-pub use types::{ProcessOutput, Syscall, SyscallError, SyscallResult, SystemInfo};
+pub use types::{
+    FileLock, PollFd, ProcessOutput, RetryPolicy, Syscall, SyscallError, SyscallResult, SystemInfo,
+};
 
 // Re-export ProcessMemoryStats from memory module
 pub use crate::memory::ProcessMemoryStats;
 
 // Re-export scheduler types for convenience
 pub use crate::scheduler::{
-    apply_priority_op, validate_priority, Policy as SchedulerPolicy, PriorityOp, TimeQuantum,
-    DEFAULT_PRIORITY, MAX_PRIORITY, MIN_PRIORITY,
+    apply_priority_op, validate_priority, validate_priority_with_ceiling,
+    Policy as SchedulerPolicy, PriorityOp, TimeQuantum, DEFAULT_PRIORITY, MAX_PRIORITY,
+    MIN_PRIORITY,
 };
@@ -16,6 +16,17 @@ pub trait FileSystemSyscalls: Send + Sync {
     /// Write a file
     async fn write_file(&self, pid: Pid, path: &PathBuf, data: &[u8]) -> SyscallResult;
 
+    /// Read the start of a file into segments of the given sizes
+    async fn read_file_vectored(&self, pid: Pid, path: &PathBuf, sizes: &[usize]) -> SyscallResult;
+
+    /// Write segments back to back as the file contents
+    async fn write_file_vectored(
+        &self,
+        pid: Pid,
+        path: &PathBuf,
+        segments: &[Vec<u8>],
+    ) -> SyscallResult;
+
     /// Create a file
     async fn create_file(&self, pid: Pid, path: &PathBuf) -> SyscallResult;
 
@@ -37,6 +48,9 @@ pub trait FileSystemSyscalls: Send + Sync {
     /// Copy file
     async fn copy_file(&self, pid: Pid, source: &PathBuf, destination: &PathBuf) -> SyscallResult;
 
+    /// Hash file contents, returning the raw digest
+    async fn hash_file(&self, pid: Pid, path: &PathBuf, algorithm: &str) -> SyscallResult;
+
     /// Create directory
     async fn create_directory(&self, pid: Pid, path: &PathBuf) -> SyscallResult;
 
@@ -67,6 +81,9 @@ pub trait ProcessSyscalls: Send + Sync {
     /// Get list of all processes
     async fn get_process_list(&self, pid: Pid) -> SyscallResult;
 
+    /// Get the direct children of a process
+    async fn get_process_children(&self, pid: Pid, target_pid: Pid) -> SyscallResult;
+
     /// Set process priority
     async fn set_process_priority(&self, pid: Pid, target_pid: Pid, priority: u8) -> SyscallResult;
 
@@ -97,10 +114,17 @@ async fn create_pipe(
     ) -> SyscallResult;
 
     /// Write to a pipe
-    async fn write_pipe(&self, pid: Pid, pipe_id: u32, data: &[u8]) -> SyscallResult;
+    async fn write_pipe(&self, pid: Pid, pipe_id: u32, data: &[u8], partial: bool)
+        -> SyscallResult;
 
-    /// Read from a pipe
-    async fn read_pipe(&self, pid: Pid, pipe_id: u32, size: usize) -> SyscallResult;
+    /// Read from a pipe, failing with WouldBlock when empty if `nonblocking`
+    async fn read_pipe(
+        &self,
+        pid: Pid,
+        pipe_id: u32,
+        size: usize,
+        nonblocking: bool,
+    ) -> SyscallResult;
 
     /// Close a pipe end
     async fn close_pipe(&self, pid: Pid, pipe_id: u32) -> SyscallResult;
@@ -161,8 +185,8 @@ async fn send_queue(
         priority: Option<u8>,
     ) -> SyscallResult;
 
-    /// Receive message from queue
-    async fn receive_queue(&self, pid: Pid, queue_id: u32) -> SyscallResult;
+    /// Receive message from queue, failing with WouldBlock when empty if `nonblocking`
+    async fn receive_queue(&self, pid: Pid, queue_id: u32, nonblocking: bool) -> SyscallResult;
 
     /// Subscribe to PubSub queue
     async fn subscribe_queue(&self, pid: Pid, queue_id: u32) -> SyscallResult;
@@ -174,7 +198,12 @@ async fn send_queue(
     async fn close_queue(&self, pid: Pid, queue_id: u32) -> SyscallResult;
 
     /// Destroy queue
-    async fn destroy_queue(&self, pid: Pid, queue_id: u32) -> SyscallResult;
+    async fn destroy_queue(
+        &self,
+        pid: Pid,
+        queue_id: u32,
+        drain_timeout_ms: Option<u64>,
+    ) -> SyscallResult;
 
     /// Get queue statistics
     async fn queue_stats(&self, pid: Pid, queue_id: u32) -> SyscallResult;
@@ -254,8 +283,21 @@ pub trait FileDescriptorSyscalls: Send + Sync {
     /// Seek within file
     async fn lseek(&self, pid: Pid, fd: u32, offset: i64, whence: u32) -> SyscallResult;
 
+    /// Read up to `count` bytes from file descriptor
+    async fn read(&self, pid: Pid, fd: u32, count: usize) -> SyscallResult;
+
     /// File control operations
     async fn fcntl(&self, pid: Pid, fd: u32, cmd: u32, arg: u32) -> SyscallResult;
+
+    /// Copy bytes from one descriptor to another without a userspace round trip
+    async fn sendfile(
+        &self,
+        pid: Pid,
+        out_fd: u32,
+        in_fd: u32,
+        offset: Option<u64>,
+        count: usize,
+    ) -> SyscallResult;
 }
 
 /// Memory management syscalls (async)
@@ -296,6 +338,9 @@ async fn register_signal_handler(
 
     /// Get pending signals
     async fn get_pending_signals(&self, pid: Pid) -> SyscallResult;
+
+    /// Create a signalfd routing `signals` away from handlers
+    async fn signalfd_create(&self, pid: Pid, signals: &[u32]) -> SyscallResult;
 }
 
 /// System information syscalls (async)
@@ -320,6 +365,18 @@ pub trait TimeSyscalls: Send + Sync {
 
     /// Get system uptime
     async fn get_uptime(&self, pid: Pid) -> SyscallResult;
+
+    /// Create a disarmed timerfd
+    async fn timerfd_create(&self, pid: Pid) -> SyscallResult;
+
+    /// Arm a timerfd to expire after `duration_ms`, then every `interval_ms`
+    async fn timerfd_set(
+        &self,
+        pid: Pid,
+        fd: u32,
+        duration_ms: u64,
+        interval_ms: Option<u64>,
+    ) -> SyscallResult;
 }
 
 /// Complete async syscall executor trait combining all categories
@@ -4,6 +4,7 @@
  */
 
 use crate::core::data_structures::InlineString;
+use crate::core::errors::{ErrorCategory, ErrorCode};
 use serde::{Deserialize, Serialize};
 use thiserror::Error;
 
@@ -32,6 +33,10 @@ pub enum SyscallError {
     #[error("Resource unavailable: {0}")]
     Unavailable(InlineString),
 
+    /// Non-blocking operation found nothing ready (EAGAIN)
+    #[error("Operation would block: {0}")]
+    WouldBlock(InlineString),
+
     /// I/O error occurred
     #[error("I/O error: {0}")]
     IoError(InlineString),
@@ -47,6 +52,14 @@ pub enum SyscallError {
     /// Serialization/deserialization error
     #[error("Serialization error: {0}")]
     SerializationError(InlineString),
+
+    /// Caller has run out of syscall credits
+    #[error("Rate limited: {0}")]
+    RateLimited(InlineString),
+
+    /// Argument outside the range the syscall accepts
+    #[error("Invalid argument: {field} = {value}")]
+    ArgumentOutOfRange { field: InlineString, value: i64 },
 }
 
 impl SyscallError {
@@ -68,17 +81,69 @@ pub fn invalid_argument(msg: impl Into<InlineString>) -> Self {
         Self::InvalidArgument(msg.into())
     }
 
+    /// Create an out-of-range error naming the offending field
+    #[inline]
+    pub fn argument_out_of_range(field: impl Into<InlineString>, value: impl Into<i64>) -> Self {
+        Self::ArgumentOutOfRange {
+            field: field.into(),
+            value: value.into(),
+        }
+    }
+
     /// Create a not found error
     #[inline]
     pub fn not_found(msg: impl Into<InlineString>) -> Self {
         Self::NotFound(msg.into())
     }
 
+    /// Create a would-block error for an empty non-blocking read
+    #[inline]
+    pub fn would_block(msg: impl Into<InlineString>) -> Self {
+        Self::WouldBlock(msg.into())
+    }
+
     /// Create a manager not available error
     #[inline]
     pub fn manager_not_available(subsystem: impl Into<InlineString>) -> Self {
         Self::ManagerNotAvailable(subsystem.into())
     }
+
+    /// Create a rate limited error for a caller out of syscall credits
+    #[inline]
+    pub fn rate_limited(msg: impl Into<InlineString>) -> Self {
+        Self::RateLimited(msg.into())
+    }
+
+    /// Stable numeric code for this variant
+    #[inline]
+    pub const fn error_code(&self) -> u32 {
+        self.typed_code().code
+    }
+
+    /// Error class for this variant
+    #[inline]
+    pub const fn category(&self) -> ErrorCategory {
+        self.typed_code().category
+    }
+
+    /// Code and category together
+    pub const fn typed_code(&self) -> ErrorCode {
+        use ErrorCategory as C;
+        match self {
+            Self::PermissionDenied(_) => ErrorCode::new(1001, C::PermissionDenied),
+            Self::OperationFailed(_) => ErrorCode::new(1002, C::Internal),
+            Self::InvalidArgument(_) => ErrorCode::new(1003, C::InvalidArgument),
+            Self::NotFound(_) => ErrorCode::new(1004, C::NotFound),
+            Self::Unavailable(_) => ErrorCode::new(1005, C::Unavailable),
+            Self::WouldBlock(_) => ErrorCode::new(1006, C::WouldBlock),
+            Self::IoError(_) => ErrorCode::new(1007, C::Io),
+            Self::NotImplemented(_) => ErrorCode::new(1008, C::Unsupported),
+            Self::ManagerNotAvailable(_) => ErrorCode::new(1009, C::Unavailable),
+            Self::SerializationError(_) => ErrorCode::new(1010, C::Internal),
+            Self::RateLimited(_) => ErrorCode::new(1011, C::QuotaExceeded),
+            Self::ArgumentOutOfRange { .. } => ErrorCode::new(1012, C::InvalidArgument),
+        }
+    }
 }
 
 #[cfg(test)]
@@ -93,4 +158,33 @@ fn test_syscall_error_helpers() {
         let err = SyscallError::not_found("missing");
         assert!(matches!(err, SyscallError::NotFound(_)));
     }
+
+    #[test]
+    fn test_error_codes_are_unique() {
+        let errors = [
+            SyscallError::permission_denied("x"),
+            SyscallError::operation_failed("x"),
+            SyscallError::invalid_argument("x"),
+            SyscallError::not_found("x"),
+            SyscallError::Unavailable("x".into()),
+            SyscallError::would_block("x"),
+            SyscallError::IoError("x".into()),
+            SyscallError::NotImplemented("x".into()),
+            SyscallError::manager_not_available("x"),
+            SyscallError::SerializationError("x".into()),
+            SyscallError::rate_limited("x"),
+            SyscallError::argument_out_of_range("x", 0),
+        ];
+
+        let codes: std::collections::HashSet<_> = errors.iter().map(|e| e.error_code()).collect();
+        assert_eq!(codes.len(), errors.len());
+        assert!(errors
+            .iter()
+            .all(|e| (1000..2000).contains(&e.error_code())
+                && e.category() != ErrorCategory::Unknown));
+        assert_eq!(
+            SyscallError::not_found("x").category(),
+            ErrorCategory::NotFound
+        );
+    }
 }
@@ -14,6 +14,8 @@
 pub use errors::SyscallError;
 pub use process_types::{ProcessOutput, SystemInfo};
 pub use results::SyscallResult;
+pub use syscall::fs::{FileLock, PollFd};
+pub use syscall::network::RetryPolicy;
 pub use syscall::search::SearchResult;
 pub use syscall::Syscall;
 pub use watch::{FileWatchEvent, WatchHandle};
@@ -5,7 +5,11 @@
 
 use super::errors::SyscallError;
 use crate::core::data_structures::InlineString;
+use crate::core::errors::ErrorCode;
 use crate::core::serialization::serde::skip_serializing_none;
+use crate::ipc::types::IpcError;
+use crate::memory::MemoryError;
+use crate::vfs::VfsError;
 use serde::{Deserialize, Serialize};
 
 /// System call result with modern serde patterns
@@ -22,6 +26,9 @@ pub enum SyscallResult {
     Error {
         /// Human-readable error message
         message: InlineString,
+        /// Stable code when the error came from a typed error enum
+        #[serde(default)]
+        code: Option<ErrorCode>,
     },
     /// Permission denied with reason
     PermissionDenied {
@@ -60,6 +67,17 @@ pub fn success_with_data(data: Vec<u8>) -> Self {
     pub fn error(message: impl Into<InlineString>) -> Self {
         Self::Error {
             message: message.into(),
+            code: None,
+        }
+    }
+
+    /// Create an error result carrying a stable error code
+    #[inline]
+    #[must_use]
+    pub fn error_with_code(message: impl Into<InlineString>, code: ErrorCode) -> Self {
+        Self::Error {
+            message: message.into(),
+            code: Some(code),
         }
     }
 
@@ -117,6 +135,16 @@ pub const fn data(&self) -> Option<&Vec<u8>> {
             _ => None,
         }
     }
+
+    /// Stable error code, if this is an error that carries one
+    #[inline]
+    #[must_use]
+    pub const fn typed_code(&self) -> Option<ErrorCode> {
+        match self {
+            Self::Error { code, .. } => *code,
+            _ => None,
+        }
+    }
 }
 
 /// Convert from SyscallError to SyscallResult
@@ -126,13 +154,29 @@ fn from(err: SyscallError) -> Self {
             SyscallError::PermissionDenied(msg) => Self::PermissionDenied {
                 reason: msg.to_string().into(),
             },
-            other => Self::Error {
-                message: other.to_string().into(),
-            },
+            other => Self::error_with_code(other.to_string(), other.typed_code()),
         }
     }
 }
 
+impl From<VfsError> for SyscallResult {
+    fn from(err: VfsError) -> Self {
+        Self::error_with_code(err.to_string(), err.typed_code())
+    }
+}
+
+impl From<MemoryError> for SyscallResult {
+    fn from(err: MemoryError) -> Self {
+        Self::error_with_code(err.to_string(), err.typed_code())
+    }
+}
+
+impl From<IpcError> for SyscallResult {
+    fn from(err: IpcError) -> Self {
+        Self::error_with_code(err.to_string(), err.typed_code())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -155,4 +199,18 @@ fn test_syscall_result_serialization() {
         let deserialized: SyscallResult = serde_json::from_str(&json).unwrap();
         assert_eq!(result, deserialized);
     }
+
+    #[test]
+    fn test_error_code_from_syscall_error() {
+        let err = SyscallError::NotFound("missing".into());
+        let code = err.typed_code();
+        let result = SyscallResult::from(err);
+        assert_eq!(result.typed_code(), Some(code));
+        assert_eq!(SyscallResult::error("untyped").typed_code(), None);
+
+        // Older payloads without a code still deserialize
+        let legacy: SyscallResult =
+            serde_json::from_str(r#"{"status":"error","message":"boom"}"#).unwrap();
+        assert_eq!(legacy.typed_code(), None);
+    }
 }
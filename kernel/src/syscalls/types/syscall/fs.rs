@@ -3,7 +3,7 @@
  * File and directory operations
  */
 
-use crate::core::types::Fd;
+use crate::core::types::{Fd, Size};
 use serde::{Deserialize, Serialize};
 use std::path::PathBuf;
 
@@ -27,6 +27,30 @@ pub enum FsSyscall {
         data: Vec<u8>,
     },
 
+    /// Append data to file atomically, creating it if missing
+    AppendFile {
+        /// Path to file
+        path: PathBuf,
+        /// Data to append
+        data: Vec<u8>,
+    },
+
+    /// Read the start of a file into consecutive segments (readv)
+    ReadFileVectored {
+        /// Path to file
+        path: PathBuf,
+        /// Size of each segment
+        sizes: Vec<Size>,
+    },
+
+    /// Write segments back to back as the file contents (writev)
+    WriteFileVectored {
+        /// Path to file
+        path: PathBuf,
+        /// Data segments, written in order
+        segments: Vec<Vec<u8>>,
+    },
+
     /// Create empty file
     CreateFile {
         /// Path to file
@@ -63,6 +87,9 @@ pub enum FsSyscall {
         source: PathBuf,
         /// Destination path
         destination: PathBuf,
+        /// Fail with AlreadyExists instead of overwriting the destination
+        #[serde(default)]
+        noreplace: bool,
     },
 
     /// Copy file
@@ -73,6 +100,14 @@ pub enum FsSyscall {
         destination: PathBuf,
     },
 
+    /// Hash file contents in-kernel
+    HashFile {
+        /// Path to file
+        path: PathBuf,
+        /// Digest algorithm ("sha256" or "blake3")
+        algorithm: String,
+    },
+
     /// Create directory
     CreateDirectory {
         /// Path to directory
@@ -88,6 +123,10 @@ pub enum FsSyscall {
     /// Get current working directory
     GetWorkingDirectory,
 
+    /// List mounted filesystems with their flags, quota and usage
+    /// (the `/proc/mounts` view of the VFS)
+    ListMounts,
+
     /// Set current working directory
     SetWorkingDirectory {
         /// Path to directory
@@ -106,7 +145,7 @@ pub enum FsSyscall {
     Open {
         /// Path to file
         path: PathBuf,
-        /// Open flags (O_RDONLY, O_WRONLY, O_RDWR, O_CREAT, O_APPEND, etc.)
+        /// Open flags (O_RDONLY, O_WRONLY, O_RDWR, O_CREAT, O_APPEND, O_WRITE_COMBINE, etc.)
         flags: u32,
         /// File permissions (0644, etc.)
         #[serde(default)]
@@ -143,6 +182,28 @@ pub enum FsSyscall {
         whence: u32,
     },
 
+    /// Read from file descriptor
+    Read {
+        /// File descriptor
+        fd: Fd,
+        /// Maximum bytes to read
+        count: Size,
+    },
+
+    /// Write to file descriptor
+    Write {
+        /// File descriptor
+        fd: Fd,
+        /// Data to write
+        data: Vec<u8>,
+    },
+
+    /// Flush buffered writes and sync a file descriptor to storage
+    Fsync {
+        /// File descriptor
+        fd: Fd,
+    },
+
     /// File control operations
     Fcntl {
         /// File descriptor
@@ -152,5 +213,89 @@ pub enum FsSyscall {
         /// Command argument
         #[serde(default)]
         arg: u32,
+        /// Lock description for F_GETLK, F_SETLK and F_SETLKW
+        #[serde(default)]
+        lock: Option<FileLock>,
+    },
+
+    /// Copy bytes between file descriptors without leaving the kernel
+    Sendfile {
+        /// Destination file or socket descriptor
+        out_fd: Fd,
+        /// Source file descriptor
+        in_fd: Fd,
+        /// Read from this offset without moving `in_fd`; None reads from
+        /// and advances the current position
+        #[serde(default)]
+        offset: Option<u64>,
+        /// Maximum bytes to transfer
+        count: Size,
     },
+
+    /// Wait until any of a set of descriptors is ready for I/O
+    Poll {
+        /// Descriptors and the events to watch on each
+        fds: Vec<PollFd>,
+        /// Give up after this long; None waits indefinitely
+        #[serde(default)]
+        timeout_ms: Option<u64>,
+    },
+}
+
+/// Advisory byte-range lock description (`struct flock`)
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct FileLock {
+    /// F_RDLCK (0), F_WRLCK (1) or F_UNLCK (2)
+    #[serde(default)]
+    pub lock_type: u32,
+    /// Offset of the first locked byte
+    #[serde(default)]
+    pub start: u64,
+    /// Bytes to lock; 0 extends the lock to end of file
+    #[serde(default)]
+    pub len: u64,
+}
+
+/// Descriptor watched by poll (`struct pollfd`)
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct PollFd {
+    /// File or socket descriptor
+    pub fd: Fd,
+    /// Requested events (POLLIN, POLLOUT)
+    #[serde(default)]
+    pub events: u16,
+    /// Returned events; POLLERR, POLLHUP and POLLNVAL are always reported
+    #[serde(default)]
+    pub revents: u16,
+}
+
+impl PollFd {
+    pub const POLLIN: u16 = 0x001;
+    pub const POLLOUT: u16 = 0x004;
+    pub const POLLERR: u16 = 0x008;
+    pub const POLLHUP: u16 = 0x010;
+    pub const POLLNVAL: u16 = 0x020;
+
+    pub fn new(fd: Fd, events: u16) -> Self {
+        Self {
+            fd,
+            events,
+            revents: 0,
+        }
+    }
+}
+
+impl FileLock {
+    pub const F_RDLCK: u32 = 0;
+    pub const F_WRLCK: u32 = 1;
+    pub const F_UNLCK: u32 = 2;
+
+    /// Locked byte range, with `len == 0` running to `u64::MAX`
+    pub fn range(&self) -> std::ops::Range<u64> {
+        let end = match self.len {
+            0 => u64::MAX,
+            len => self.start.saturating_add(len),
+        };
+        self.start..end
+    }
 }
@@ -31,6 +31,10 @@ pub enum IpcSyscall {
         pipe_id: Pid,
         /// Data to write
         data: Vec<u8>,
+        /// Write as much as fits instead of all or nothing; writes of up to
+        /// PIPE_BUF bytes stay atomic
+        #[serde(default)]
+        partial: bool,
     },
 
     /// Read data from pipe
@@ -39,6 +43,9 @@ pub enum IpcSyscall {
         pipe_id: Pid,
         /// Number of bytes to read
         size: Size,
+        /// Fail with WouldBlock instead of waiting when the pipe is empty
+        #[serde(default)]
+        nonblocking: bool,
     },
 
     /// Close pipe end
@@ -59,6 +66,12 @@ pub enum IpcSyscall {
         pipe_id: Pid,
     },
 
+    /// Open the caller's end of a pipe as a file descriptor (for Read and Poll)
+    PipeFd {
+        /// Pipe ID
+        pipe_id: Pid,
+    },
+
     // ========================================================================
     // Shared Memory
     // ========================================================================
@@ -105,6 +118,17 @@ pub enum IpcSyscall {
         size: Size,
     },
 
+    /// Resize shared memory segment in place (owner only)
+    ///
+    /// Contents up to `min(old, new)` are preserved and attachments stay
+    /// valid. Shrinking below the highest written byte fails.
+    ResizeShm {
+        /// Segment ID
+        segment_id: Pid,
+        /// New size in bytes
+        new_size: Size,
+    },
+
     /// Destroy shared memory segment
     DestroyShm {
         /// Segment ID
@@ -134,6 +158,9 @@ pub enum IpcSyscall {
         /// Shared (1) or Private (0) mapping
         #[serde(default)]
         shared: bool,
+        /// Pre-fault all pages at map time (MAP_POPULATE)
+        #[serde(default)]
+        populate: bool,
     },
 
     /// Read from memory-mapped region
@@ -181,7 +208,7 @@ pub enum IpcSyscall {
     // ========================================================================
     /// Create message queue
     CreateQueue {
-        /// Queue type: "fifo", "priority", or "pubsub"
+        /// Queue type: "fifo", "priority", "pubsub", or "at_least_once"
         queue_type: String,
         /// Optional capacity (messages)
         capacity: Option<Size>,
@@ -201,6 +228,36 @@ pub enum IpcSyscall {
     ReceiveQueue {
         /// Queue ID
         queue_id: Pid,
+        /// Fail with WouldBlock instead of waiting when the queue is empty
+        #[serde(default)]
+        nonblocking: bool,
+    },
+
+    /// Acknowledge a message received from an at-least-once queue
+    ///
+    /// Unacknowledged messages are redelivered once their visibility
+    /// timeout expires.
+    AckQueue {
+        /// Queue ID
+        queue_id: Pid,
+        /// ID of the received message
+        msg_id: u64,
+    },
+
+    /// Configure redelivery for an at-least-once queue (owner only)
+    SetQueueAckPolicy {
+        /// Queue ID
+        queue_id: Pid,
+        /// How long a received message stays leased awaiting its ack
+        visibility_timeout_ms: u64,
+        /// Deliveries before an unacknowledged message is dead-lettered
+        max_attempts: u32,
+    },
+
+    /// Remove and return the dead letters of an at-least-once queue (owner only)
+    TakeDeadLetters {
+        /// Queue ID
+        queue_id: Pid,
     },
 
     /// Subscribe to pubsub queue
@@ -225,6 +282,9 @@ pub enum IpcSyscall {
     DestroyQueue {
         /// Queue ID
         queue_id: Pid,
+        /// Wait this long for consumers to drain pending messages (None = drop immediately)
+        #[serde(default)]
+        drain_timeout_ms: Option<u64>,
     },
 
     /// Get queue statistics
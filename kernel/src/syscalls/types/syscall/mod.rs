@@ -36,6 +36,18 @@ pub enum Syscall {
         path: PathBuf,
         data: Vec<u8>,
     },
+    AppendFile {
+        path: PathBuf,
+        data: Vec<u8>,
+    },
+    ReadFileVectored {
+        path: PathBuf,
+        sizes: Vec<Size>,
+    },
+    WriteFileVectored {
+        path: PathBuf,
+        segments: Vec<Vec<u8>>,
+    },
     CreateFile {
         path: PathBuf,
     },
@@ -54,11 +66,17 @@ pub enum Syscall {
     MoveFile {
         source: PathBuf,
         destination: PathBuf,
+        #[serde(default)]
+        noreplace: bool,
     },
     CopyFile {
         source: PathBuf,
         destination: PathBuf,
     },
+    HashFile {
+        path: PathBuf,
+        algorithm: String,
+    },
     CreateDirectory {
         path: PathBuf,
     },
@@ -66,6 +84,7 @@ pub enum Syscall {
         path: PathBuf,
     },
     GetWorkingDirectory,
+    ListMounts,
     SetWorkingDirectory {
         path: PathBuf,
     },
@@ -94,6 +113,17 @@ pub enum Syscall {
         offset: i64,
         whence: u32,
     },
+    Read {
+        fd: Fd,
+        count: Size,
+    },
+    Write {
+        fd: Fd,
+        data: Vec<u8>,
+    },
+    Fsync {
+        fd: Fd,
+    },
 
     // ========================================================================
     // Search Operations (from search module)
@@ -127,6 +157,20 @@ pub enum Syscall {
         cmd: u32,
         #[serde(default)]
         arg: u32,
+        #[serde(default)]
+        lock: Option<fs::FileLock>,
+    },
+    Sendfile {
+        out_fd: Fd,
+        in_fd: Fd,
+        #[serde(default)]
+        offset: Option<u64>,
+        count: Size,
+    },
+    Poll {
+        fds: Vec<fs::PollFd>,
+        #[serde(default)]
+        timeout_ms: Option<u64>,
     },
 
     // ========================================================================
@@ -144,10 +188,24 @@ pub enum Syscall {
         target_pid: Pid,
     },
     GetProcessList,
+    GetProcessChildren {
+        target_pid: Pid,
+    },
     SetProcessPriority {
         target_pid: Pid,
         priority: Priority,
     },
+    GetProcessNice {
+        target_pid: Pid,
+    },
+    SetProcessNice {
+        target_pid: Pid,
+        nice: i8,
+    },
+    SetOomScore {
+        target_pid: Pid,
+        score: i16,
+    },
     GetProcessState {
         target_pid: Pid,
     },
@@ -170,10 +228,14 @@ pub enum Syscall {
     WritePipe {
         pipe_id: Pid,
         data: Vec<u8>,
+        #[serde(default)]
+        partial: bool,
     },
     ReadPipe {
         pipe_id: Pid,
         size: Size,
+        #[serde(default)]
+        nonblocking: bool,
     },
     ClosePipe {
         pipe_id: Pid,
@@ -184,6 +246,9 @@ pub enum Syscall {
     PipeStats {
         pipe_id: Pid,
     },
+    PipeFd {
+        pipe_id: Pid,
+    },
 
     CreateShm {
         size: Size,
@@ -208,6 +273,10 @@ pub enum Syscall {
         offset: usize,
         size: Size,
     },
+    ResizeShm {
+        segment_id: Pid,
+        new_size: Size,
+    },
     DestroyShm {
         segment_id: Pid,
     },
@@ -223,6 +292,8 @@ pub enum Syscall {
         prot: u8,
         #[serde(default)]
         shared: bool,
+        #[serde(default)]
+        populate: bool,
     },
     MmapRead {
         mmap_id: u32,
@@ -257,6 +328,20 @@ pub enum Syscall {
     },
     ReceiveQueue {
         queue_id: Pid,
+        #[serde(default)]
+        nonblocking: bool,
+    },
+    AckQueue {
+        queue_id: Pid,
+        msg_id: u64,
+    },
+    SetQueueAckPolicy {
+        queue_id: Pid,
+        visibility_timeout_ms: u64,
+        max_attempts: u32,
+    },
+    TakeDeadLetters {
+        queue_id: Pid,
     },
     SubscribeQueue {
         queue_id: Pid,
@@ -269,6 +354,8 @@ pub enum Syscall {
     },
     DestroyQueue {
         queue_id: Pid,
+        #[serde(default)]
+        drain_timeout_ms: Option<u64>,
     },
     QueueStats {
         queue_id: Pid,
@@ -279,6 +366,8 @@ pub enum Syscall {
     // ========================================================================
     NetworkRequest {
         url: String,
+        #[serde(default)]
+        retry: Option<network::RetryPolicy>,
     },
     Socket {
         domain: u32,
@@ -367,12 +456,23 @@ pub enum Syscall {
     LowerPriority {
         target_pid: Pid,
     },
+    PauseProcess {
+        target_pid: Pid,
+    },
+    ResumeProcess {
+        target_pid: Pid,
+    },
 
     // ========================================================================
     // System Operations (from system module)
     // ========================================================================
     GetSystemInfo,
     GetCurrentTime,
+    GetRandom {
+        len: usize,
+        #[serde(default)]
+        flags: u32,
+    },
     GetEnvironmentVar {
         key: String,
     },
@@ -384,13 +484,24 @@ pub enum Syscall {
         duration_ms: u64,
     },
     GetUptime,
+    TimerfdCreate,
+    TimerfdSet {
+        fd: u32,
+        duration_ms: u64,
+        #[serde(default)]
+        interval_ms: Option<u64>,
+    },
     GetMemoryStats,
     GetProcessMemoryStats {
         target_pid: Pid,
     },
+    GetMemoryMaps {
+        target_pid: Pid,
+    },
     TriggerGC {
         target_pid: Option<u32>,
     },
+    CompactMemory,
     SendSignal {
         target_pid: Pid,
         signal: u32,
@@ -414,6 +525,9 @@ pub enum Syscall {
     GetSignalState {
         target_pid: Option<Pid>,
     },
+    SignalfdCreate {
+        signals: Vec<u32>,
+    },
 
     // ========================================================================
     // Clipboard Operations
@@ -3,8 +3,12 @@
  * Socket and network operations
  */
 
+use crate::core::limits::{
+    NETWORK_RETRY_BASE_DELAY_MS, NETWORK_RETRY_MAX_DELAY_MS, NETWORK_RETRY_MAX_RETRIES,
+};
 use crate::core::types::{Size, SockFd};
 use serde::{Deserialize, Serialize};
+use std::time::Duration;
 
 /// Network operations
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
@@ -16,6 +20,9 @@ pub enum NetworkSyscall {
     NetworkRequest {
         /// URL to fetch
         url: String,
+        /// Retry policy, overriding the process default
+        #[serde(default)]
+        retry: Option<RetryPolicy>,
     },
 
     /// Create socket
@@ -134,3 +141,74 @@ pub enum NetworkSyscall {
         optname: u32,
     },
 }
+
+/// Retry policy for NetworkRequest
+///
+/// The delay before retry `n` (1-based) is `base_delay_ms * 2^(n-1)`, capped
+/// at `max_delay_ms`, then jittered down to as little as half that.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RetryPolicy {
+    /// Retries after the first attempt; clamped to NETWORK_RETRY_MAX_RETRIES
+    pub max_retries: u32,
+    #[serde(default = "default_base_delay_ms")]
+    pub base_delay_ms: u64,
+    #[serde(default = "default_max_delay_ms")]
+    pub max_delay_ms: u64,
+}
+
+fn default_base_delay_ms() -> u64 {
+    NETWORK_RETRY_BASE_DELAY_MS
+}
+
+fn default_max_delay_ms() -> u64 {
+    NETWORK_RETRY_MAX_DELAY_MS
+}
+
+impl RetryPolicy {
+    pub fn new(max_retries: u32) -> Self {
+        Self {
+            max_retries,
+            base_delay_ms: NETWORK_RETRY_BASE_DELAY_MS,
+            max_delay_ms: NETWORK_RETRY_MAX_DELAY_MS,
+        }
+    }
+
+    /// Policy that never retries
+    pub fn none() -> Self {
+        Self::new(0)
+    }
+
+    pub fn with_base_delay(mut self, delay: Duration) -> Self {
+        self.base_delay_ms = u64::try_from(delay.as_millis()).unwrap_or(u64::MAX);
+        self
+    }
+
+    pub fn with_max_delay(mut self, delay: Duration) -> Self {
+        self.max_delay_ms = u64::try_from(delay.as_millis()).unwrap_or(u64::MAX);
+        self
+    }
+
+    /// Retries allowed, after clamping
+    pub fn retries(&self) -> u32 {
+        self.max_retries.min(NETWORK_RETRY_MAX_RETRIES)
+    }
+
+    /// Un-jittered delay before retry `retry` (1-based)
+    ///
+    /// Never longer than NETWORK_RETRY_MAX_DELAY_MS, whatever the policy asks for.
+    pub fn backoff(&self, retry: u32) -> Duration {
+        let factor = 1u64 << retry.saturating_sub(1).min(32);
+        let delay_ms = self
+            .base_delay_ms
+            .saturating_mul(factor)
+            .min(self.max_delay_ms)
+            .min(NETWORK_RETRY_MAX_DELAY_MS);
+        Duration::from_millis(delay_ms)
+    }
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self::none()
+    }
+}
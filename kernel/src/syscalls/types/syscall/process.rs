@@ -36,6 +36,12 @@ pub enum ProcessSyscall {
     /// Get list of all processes
     GetProcessList,
 
+    /// Get the direct children of a process
+    GetProcessChildren {
+        /// Process ID to query
+        target_pid: Pid,
+    },
+
     /// Set process priority
     SetProcessPriority {
         /// Process ID to modify
@@ -44,6 +50,28 @@ pub enum ProcessSyscall {
         priority: Priority,
     },
 
+    /// Get process niceness
+    GetProcessNice {
+        /// Process ID to query
+        target_pid: Pid,
+    },
+
+    /// Set process niceness, mapped onto the scheduler priority
+    SetProcessNice {
+        /// Process ID to modify
+        target_pid: Pid,
+        /// Niceness; lower is more favourable
+        nice: i8,
+    },
+
+    /// Set the OOM score adjustment weighting victim selection
+    SetOomScore {
+        /// Process ID to modify
+        target_pid: Pid,
+        /// Adjustment from -1000 (never reclaimed) to 1000
+        score: i16,
+    },
+
     /// Get process state
     GetProcessState {
         /// Process ID to query
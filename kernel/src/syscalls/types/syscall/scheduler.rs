@@ -62,4 +62,16 @@ pub enum SchedulerSyscall {
         /// Process ID to lower
         target_pid: Pid,
     },
+
+    /// Stop a process from being scheduled until resumed
+    PauseProcess {
+        /// Process ID to pause
+        target_pid: Pid,
+    },
+
+    /// Make a paused process schedulable again
+    ResumeProcess {
+        /// Process ID to resume
+        target_pid: Pid,
+    },
 }
@@ -18,6 +18,18 @@ pub enum SystemSyscall {
     /// Get current system time
     GetCurrentTime,
 
+    /// Get cryptographically secure random bytes from the OS RNG
+    ///
+    /// Rate-limited per process; over the limit the call waits, or fails
+    /// with WouldBlock when `GRND_NONBLOCK` is set.
+    GetRandom {
+        /// Number of bytes
+        len: usize,
+        /// getrandom(2) style flags (`GRND_NONBLOCK`, `GRND_RANDOM`)
+        #[serde(default)]
+        flags: u32,
+    },
+
     /// Get environment variable
     GetEnvironmentVar {
         /// Variable name
@@ -32,7 +44,8 @@ pub enum SystemSyscall {
         value: String,
     },
 
-    /// Sleep for duration
+    /// Sleep for duration, returning early with the remaining time if a
+    /// deliverable signal arrives
     Sleep {
         /// Duration in milliseconds
         duration_ms: u64,
@@ -41,6 +54,20 @@ pub enum SystemSyscall {
     /// Get system uptime
     GetUptime,
 
+    /// Create a disarmed timer fd
+    TimerfdCreate,
+
+    /// Arm or disarm a timer fd
+    TimerfdSet {
+        /// Timer file descriptor
+        fd: u32,
+        /// Delay until the first expiration in milliseconds (0 disarms)
+        duration_ms: u64,
+        /// Period after the first expiration in milliseconds (None = one-shot)
+        #[serde(default)]
+        interval_ms: Option<u64>,
+    },
+
     /// Get global memory statistics
     GetMemoryStats,
 
@@ -50,12 +77,21 @@ pub enum SystemSyscall {
         target_pid: Pid,
     },
 
+    /// List a process's memory blocks and file mappings (`/proc/pid/maps`)
+    GetMemoryMaps {
+        /// Process ID to query; other processes need KillProcess standing
+        target_pid: Pid,
+    },
+
     /// Trigger garbage collection
     TriggerGC {
         /// Optional target process ID (None = global GC)
         target_pid: Option<u32>,
     },
 
+    /// Merge adjacent free memory blocks
+    CompactMemory,
+
     /// Send signal to process
     SendSignal {
         /// Target process ID
@@ -103,4 +139,10 @@ pub enum SystemSyscall {
         /// Optional target PID (None = current process)
         target_pid: Option<Pid>,
     },
+
+    /// Create an fd that receives the given signals instead of handlers
+    SignalfdCreate {
+        /// Signal numbers to route to the fd
+        signals: Vec<u32>,
+    },
 }
@@ -11,7 +11,10 @@ pub fn name(&self) -> &'static str {
         match self {
             // File System Operations
             Syscall::ReadFile { .. } => "read_file",
+            Syscall::ReadFileVectored { .. } => "read_file_vectored",
+            Syscall::WriteFileVectored { .. } => "write_file_vectored",
             Syscall::WriteFile { .. } => "write_file",
+            Syscall::AppendFile { .. } => "append_file",
             Syscall::CreateFile { .. } => "create_file",
             Syscall::DeleteFile { .. } => "delete_file",
             Syscall::ListDirectory { .. } => "list_directory",
@@ -19,15 +22,22 @@ pub fn name(&self) -> &'static str {
             Syscall::FileStat { .. } => "file_stat",
             Syscall::MoveFile { .. } => "move_file",
             Syscall::CopyFile { .. } => "copy_file",
+            Syscall::HashFile { .. } => "hash_file",
             Syscall::CreateDirectory { .. } => "create_directory",
+            Syscall::ListMounts => "list_mounts",
 
             // File Descriptor Operations
             Syscall::Open { .. } => "open",
             Syscall::Close { .. } => "close",
             Syscall::Lseek { .. } => "lseek",
+            Syscall::Read { .. } => "read",
+            Syscall::Write { .. } => "write",
+            Syscall::Fsync { .. } => "fsync",
             Syscall::Dup { .. } => "dup",
             Syscall::Dup2 { .. } => "dup2",
             Syscall::Fcntl { .. } => "fcntl",
+            Syscall::Sendfile { .. } => "sendfile",
+            Syscall::Poll { .. } => "poll",
 
             // IPC - Pipes
             Syscall::CreatePipe { .. } => "create_pipe",
@@ -36,6 +46,7 @@ pub fn name(&self) -> &'static str {
             Syscall::ClosePipe { .. } => "close_pipe",
             Syscall::DestroyPipe { .. } => "destroy_pipe",
             Syscall::PipeStats { .. } => "pipe_stats",
+            Syscall::PipeFd { .. } => "pipe_fd",
 
             // IPC - Shared Memory
             Syscall::CreateShm { .. } => "create_shm",
@@ -43,6 +54,7 @@ pub fn name(&self) -> &'static str {
             Syscall::DetachShm { .. } => "detach_shm",
             Syscall::ReadShm { .. } => "read_shm",
             Syscall::WriteShm { .. } => "write_shm",
+            Syscall::ResizeShm { .. } => "resize_shm",
             Syscall::DestroyShm { .. } => "destroy_shm",
             Syscall::ShmStats { .. } => "shm_stats",
 
@@ -58,6 +70,9 @@ pub fn name(&self) -> &'static str {
             Syscall::CreateQueue { .. } => "create_queue",
             Syscall::SendQueue { .. } => "send_queue",
             Syscall::ReceiveQueue { .. } => "receive_queue",
+            Syscall::AckQueue { .. } => "ack_queue",
+            Syscall::SetQueueAckPolicy { .. } => "set_queue_ack_policy",
+            Syscall::TakeDeadLetters { .. } => "take_dead_letters",
             Syscall::SubscribeQueue { .. } => "subscribe_queue",
             Syscall::UnsubscribeQueue { .. } => "unsubscribe_queue",
             Syscall::CloseQueue { .. } => "close_queue",
@@ -71,21 +86,31 @@ pub fn name(&self) -> &'static str {
             Syscall::GetAllProcessSchedulerStats => "get_all_process_scheduler_stats",
             Syscall::BoostPriority { .. } => "boost_priority",
             Syscall::LowerPriority { .. } => "lower_priority",
+            Syscall::PauseProcess { .. } => "pause_process",
+            Syscall::ResumeProcess { .. } => "resume_process",
 
             // Signal Operations
             Syscall::SendSignal { .. } => "send_signal",
+            Syscall::SignalfdCreate { .. } => "signalfd_create",
 
             // Process Operations
             Syscall::SpawnProcess { .. } => "spawn_process",
             Syscall::GetProcessInfo { .. } => "get_process_info",
+            Syscall::GetProcessChildren { .. } => "get_process_children",
+            Syscall::GetProcessNice { .. } => "get_process_nice",
+            Syscall::SetProcessNice { .. } => "set_process_nice",
+            Syscall::SetOomScore { .. } => "set_oom_score",
 
             // Memory Operations
             Syscall::GetMemoryStats => "get_memory_stats",
             Syscall::GetProcessMemoryStats { .. } => "get_process_memory_stats",
+            Syscall::GetMemoryMaps { .. } => "get_memory_maps",
             Syscall::TriggerGC { .. } => "trigger_gc",
+            Syscall::CompactMemory => "compact_memory",
 
             // System Info Operations
             Syscall::GetSystemInfo => "get_system_info",
+            Syscall::GetRandom { .. } => "get_random",
 
             // Network Operations
             Syscall::Socket { .. } => "socket",
@@ -104,6 +129,8 @@ pub fn name(&self) -> &'static str {
             // Time Operations
             Syscall::Sleep { .. } => "sleep",
             Syscall::GetUptime => "get_uptime",
+            Syscall::TimerfdCreate => "timerfd_create",
+            Syscall::TimerfdSet { .. } => "timerfd_set",
 
             // Additional syscalls (catch-all for extended syscalls)
             _ => "syscall",
@@ -0,0 +1,443 @@
+/*!
+ * Circuit Breaker Filesystem Wrapper
+ * Fails fast on a mount whose backend keeps erroring
+ *
+ * Only `VfsError::Unavailable` (timeouts, EIO, lost connections) counts
+ * against the circuit: NotFound, InvalidArgument and even generic I/O errors
+ * can be provoked by a caller, and one process must not be able to cut a
+ * mount off for everyone. While the circuit is open every operation returns
+ * `VfsError::Unavailable` without touching the backend.
+ *
+ * Mounts get a circuit only with `MountFlags::FAIL_FAST`.
+ */
+
+use std::ops::Range;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use super::locks::RangeLockTable;
+use super::traits::{FileSystem, OpenFile};
+use super::types::*;
+use crate::core::circuit_breaker::{BreakerState, CircuitBreaker};
+use crate::core::types::Pid;
+
+/// Filesystem wrapper that routes calls through a circuit breaker
+pub struct CircuitBreakerFS {
+    inner: Arc<dyn FileSystem>,
+    breaker: CircuitBreaker,
+    resource: String,
+}
+
+impl CircuitBreakerFS {
+    /// Guard `inner` with `breaker`'s circuit for `resource`
+    pub fn new(
+        inner: Arc<dyn FileSystem>,
+        breaker: CircuitBreaker,
+        resource: impl Into<String>,
+    ) -> Self {
+        Self {
+            inner,
+            breaker,
+            resource: resource.into(),
+        }
+    }
+
+    /// Circuit key for this filesystem
+    pub fn resource(&self) -> &str {
+        &self.resource
+    }
+
+    /// Current circuit state
+    pub fn state(&self) -> BreakerState {
+        self.breaker.state(&self.resource)
+    }
+
+    fn guarded<T>(&self, op: impl FnOnce(&dyn FileSystem) -> VfsResult<T>) -> VfsResult<T> {
+        if let Err(open) = self.breaker.acquire(&self.resource) {
+            return Err(VfsError::Unavailable(
+                format!(
+                    "{} unavailable (circuit open, retry in {:?})",
+                    self.resource, open.retry_after
+                )
+                .into(),
+            ));
+        }
+
+        let result = op(self.inner.as_ref());
+        match &result {
+            Err(VfsError::Unavailable(_)) => self.breaker.record_failure(&self.resource),
+            _ => self.breaker.record_success(&self.resource),
+        }
+        result
+    }
+}
+
+impl FileSystem for CircuitBreakerFS {
+    fn read(&self, path: &Path) -> VfsResult<Vec<u8>> {
+        self.guarded(|fs| fs.read(path))
+    }
+
+    fn write(&self, path: &Path, data: &[u8]) -> VfsResult<()> {
+        self.guarded(|fs| fs.write(path, data))
+    }
+
+    fn write_vectored(&self, path: &Path, segments: &[&[u8]]) -> VfsResult<usize> {
+        self.guarded(|fs| fs.write_vectored(path, segments))
+    }
+
+    fn read_vectored(&self, path: &Path, sizes: &[usize]) -> VfsResult<Vec<Vec<u8>>> {
+        self.guarded(|fs| fs.read_vectored(path, sizes))
+    }
+
+    fn append(&self, path: &Path, data: &[u8]) -> VfsResult<u64> {
+        self.guarded(|fs| fs.append(path, data))
+    }
+
+    fn create(&self, path: &Path) -> VfsResult<()> {
+        self.guarded(|fs| fs.create(path))
+    }
+
+    fn delete(&self, path: &Path) -> VfsResult<()> {
+        self.guarded(|fs| fs.delete(path))
+    }
+
+    fn exists(&self, path: &Path) -> bool {
+        // Can't report failure, so never used as a probe
+        self.state() == BreakerState::Closed && self.inner.exists(path)
+    }
+
+    fn metadata(&self, path: &Path) -> VfsResult<Metadata> {
+        self.guarded(|fs| fs.metadata(path))
+    }
+
+    fn list_dir(&self, path: &Path) -> VfsResult<Vec<Entry>> {
+        self.guarded(|fs| fs.list_dir(path))
+    }
+
+    fn create_dir(&self, path: &Path) -> VfsResult<()> {
+        self.guarded(|fs| fs.create_dir(path))
+    }
+
+    fn remove_dir(&self, path: &Path) -> VfsResult<()> {
+        self.guarded(|fs| fs.remove_dir(path))
+    }
+
+    fn remove_dir_all(&self, path: &Path) -> VfsResult<()> {
+        self.guarded(|fs| fs.remove_dir_all(path))
+    }
+
+    fn copy(&self, from: &Path, to: &Path) -> VfsResult<()> {
+        self.guarded(|fs| fs.copy(from, to))
+    }
+
+    fn copy_reflink(&self, from: &Path, to: &Path) -> VfsResult<bool> {
+        self.guarded(|fs| fs.copy_reflink(from, to))
+    }
+
+    fn rename(&self, from: &Path, to: &Path) -> VfsResult<()> {
+        self.guarded(|fs| fs.rename(from, to))
+    }
+
+    fn rename_noreplace(&self, from: &Path, to: &Path) -> VfsResult<()> {
+        self.guarded(|fs| fs.rename_noreplace(from, to))
+    }
+
+    fn symlink(&self, src: &Path, dst: &Path) -> VfsResult<()> {
+        self.guarded(|fs| fs.symlink(src, dst))
+    }
+
+    fn read_link(&self, path: &Path) -> VfsResult<PathBuf> {
+        self.guarded(|fs| fs.read_link(path))
+    }
+
+    fn truncate(&self, path: &Path, size: u64) -> VfsResult<()> {
+        self.guarded(|fs| fs.truncate(path, size))
+    }
+
+    fn set_permissions(&self, path: &Path, perms: Permissions) -> VfsResult<()> {
+        self.guarded(|fs| fs.set_permissions(path, perms))
+    }
+
+    fn open(&self, path: &Path, flags: OpenFlags, mode: OpenMode) -> VfsResult<Box<dyn OpenFile>> {
+        self.guarded(|fs| fs.open(path, flags, mode))
+    }
+
+    // Advisory locks live in memory, not in the backend
+
+    fn range_locks(&self) -> Option<&RangeLockTable> {
+        self.inner.range_locks()
+    }
+
+    fn lock_range(
+        &self,
+        path: &Path,
+        range: Range<u64>,
+        exclusive: bool,
+        pid: Pid,
+    ) -> VfsResult<()> {
+        self.inner.lock_range(path, range, exclusive, pid)
+    }
+
+    fn unlock_range(&self, path: &Path, range: Range<u64>, pid: Pid) -> VfsResult<()> {
+        self.inner.unlock_range(path, range, pid)
+    }
+
+    fn name(&self) -> &str {
+        self.inner.name()
+    }
+
+    fn readonly(&self) -> bool {
+        self.inner.readonly()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::circuit_breaker::BreakerConfig;
+    use crate::monitoring::{Collector, Payload};
+    use crate::vfs::{LocalFS, MemFS, MountFlags, MountManager};
+    use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+    use std::time::Duration;
+
+    /// MemFS whose I/O fails while `failing` is set
+    struct FlakyFS {
+        inner: MemFS,
+        failing: AtomicBool,
+        calls: AtomicUsize,
+    }
+
+    impl FlakyFS {
+        fn new() -> Self {
+            Self {
+                inner: MemFS::new(),
+                failing: AtomicBool::new(false),
+                calls: AtomicUsize::new(0),
+            }
+        }
+
+        fn check(&self) -> VfsResult<()> {
+            self.calls.fetch_add(1, Ordering::SeqCst);
+            if self.failing.load(Ordering::SeqCst) {
+                return Err(VfsError::Unavailable("backend offline".into()));
+            }
+            Ok(())
+        }
+    }
+
+    impl FileSystem for FlakyFS {
+        fn read(&self, path: &Path) -> VfsResult<Vec<u8>> {
+            self.check()?;
+            self.inner.read(path)
+        }
+        fn write(&self, path: &Path, data: &[u8]) -> VfsResult<()> {
+            self.check()?;
+            self.inner.write(path, data)
+        }
+        fn append(&self, path: &Path, data: &[u8]) -> VfsResult<u64> {
+            self.check()?;
+            self.inner.append(path, data)
+        }
+        fn create(&self, path: &Path) -> VfsResult<()> {
+            self.check()?;
+            self.inner.create(path)
+        }
+        fn delete(&self, path: &Path) -> VfsResult<()> {
+            self.check()?;
+            self.inner.delete(path)
+        }
+        fn exists(&self, path: &Path) -> bool {
+            self.inner.exists(path)
+        }
+        fn metadata(&self, path: &Path) -> VfsResult<Metadata> {
+            self.check()?;
+            self.inner.metadata(path)
+        }
+        fn list_dir(&self, path: &Path) -> VfsResult<Vec<Entry>> {
+            self.check()?;
+            self.inner.list_dir(path)
+        }
+        fn create_dir(&self, path: &Path) -> VfsResult<()> {
+            self.check()?;
+            self.inner.create_dir(path)
+        }
+        fn remove_dir(&self, path: &Path) -> VfsResult<()> {
+            self.check()?;
+            self.inner.remove_dir(path)
+        }
+        fn remove_dir_all(&self, path: &Path) -> VfsResult<()> {
+            self.check()?;
+            self.inner.remove_dir_all(path)
+        }
+        fn copy(&self, from: &Path, to: &Path) -> VfsResult<()> {
+            self.check()?;
+            self.inner.copy(from, to)
+        }
+        fn rename(&self, from: &Path, to: &Path) -> VfsResult<()> {
+            self.check()?;
+            self.inner.rename(from, to)
+        }
+        fn symlink(&self, src: &Path, dst: &Path) -> VfsResult<()> {
+            self.check()?;
+            self.inner.symlink(src, dst)
+        }
+        fn read_link(&self, path: &Path) -> VfsResult<PathBuf> {
+            self.check()?;
+            self.inner.read_link(path)
+        }
+        fn truncate(&self, path: &Path, size: u64) -> VfsResult<()> {
+            self.check()?;
+            self.inner.truncate(path, size)
+        }
+        fn set_permissions(&self, path: &Path, perms: Permissions) -> VfsResult<()> {
+            self.check()?;
+            self.inner.set_permissions(path, perms)
+        }
+        fn open(
+            &self,
+            path: &Path,
+            flags: OpenFlags,
+            mode: OpenMode,
+        ) -> VfsResult<Box<dyn OpenFile>> {
+            self.check()?;
+            self.inner.open(path, flags, mode)
+        }
+        fn name(&self) -> &str {
+            "flaky"
+        }
+    }
+
+    fn breaker(cooldown: Duration) -> CircuitBreaker {
+        CircuitBreaker::new(
+            BreakerConfig::new()
+                .with_failure_threshold(2)
+                .with_cooldown(cooldown),
+        )
+    }
+
+    #[test]
+    fn test_open_circuit_skips_backend() {
+        let backend = Arc::new(FlakyFS::new());
+        let fs =
+            CircuitBreakerFS::new(backend.clone(), breaker(Duration::from_secs(60)), "/remote");
+
+        // Missing files are answers, not backend failures
+        for _ in 0..3 {
+            assert!(matches!(
+                fs.read(Path::new("/missing")),
+                Err(VfsError::NotFound(_))
+            ));
+        }
+        assert_eq!(fs.state(), BreakerState::Closed);
+
+        backend.failing.store(true, Ordering::SeqCst);
+        for _ in 0..2 {
+            assert!(matches!(
+                fs.read(Path::new("/a")),
+                Err(VfsError::Unavailable(_))
+            ));
+        }
+        assert_eq!(fs.state(), BreakerState::Open);
+
+        let calls = backend.calls.load(Ordering::SeqCst);
+        assert!(matches!(
+            fs.write(Path::new("/a"), b"x"),
+            Err(VfsError::Unavailable(_))
+        ));
+        assert!(!fs.exists(Path::new("/a")));
+        assert_eq!(backend.calls.load(Ordering::SeqCst), calls);
+    }
+
+    #[test]
+    fn test_mount_circuit_cycle() {
+        let collector = Arc::new(Collector::new());
+        let mgr = MountManager::new()
+            .with_circuit_breaker(breaker(Duration::from_millis(50)))
+            .with_collector(collector.clone());
+        let backend = Arc::new(FlakyFS::new());
+        mgr.mount_with_flags("/remote", backend.clone(), MountFlags::FAIL_FAST)
+            .unwrap();
+        mgr.mount("/local", Arc::new(MemFS::new())).unwrap();
+
+        let file = Path::new("/remote/data");
+        mgr.write(file, b"v1").unwrap();
+
+        // closed -> open
+        backend.failing.store(true, Ordering::SeqCst);
+        assert!(mgr.read(file).is_err());
+        assert!(mgr.read(file).is_err());
+        let breaker = mgr.circuit_breaker();
+        assert_eq!(breaker.state("/remote"), BreakerState::Open);
+        assert!(matches!(mgr.read(file), Err(VfsError::Unavailable(_))));
+
+        // Other mounts keep working
+        mgr.write(Path::new("/local/x"), b"ok").unwrap();
+
+        // open -> half-open -> open: the probe still fails
+        std::thread::sleep(Duration::from_millis(60));
+        let calls = backend.calls.load(Ordering::SeqCst);
+        assert!(mgr.read(file).is_err());
+        assert_eq!(backend.calls.load(Ordering::SeqCst), calls + 1);
+        assert_eq!(breaker.state("/remote"), BreakerState::Open);
+
+        // open -> half-open -> closed: the backend recovered
+        backend.failing.store(false, Ordering::SeqCst);
+        std::thread::sleep(Duration::from_millis(60));
+        assert_eq!(mgr.read(file).unwrap(), b"v1");
+        assert_eq!(breaker.state("/remote"), BreakerState::Closed);
+
+        let stats = breaker.stats();
+        assert_eq!(stats.len(), 1);
+        assert_eq!(stats[0].resource, "/remote");
+        assert_eq!(stats[0].trips, 2);
+        assert_eq!(stats[0].rejected, 1);
+
+        let mut sub = collector.subscribe();
+        let mut transitions = Vec::new();
+        while let Some(event) = sub.next() {
+            if let Payload::CircuitStateChanged { to, .. } = event.payload {
+                transitions.push(to.to_string());
+            }
+        }
+        assert_eq!(
+            transitions,
+            ["open", "half_open", "open", "half_open", "closed"]
+        );
+    }
+
+    #[test]
+    fn test_caller_errors_do_not_trip_circuit() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let mgr = MountManager::new().with_circuit_breaker(breaker(Duration::from_secs(60)));
+        let local = LocalFS::new(temp.path());
+        local.create_dir(Path::new("dir")).unwrap();
+        local.write(Path::new("dir/file"), b"x").unwrap();
+        mgr.mount_with_flags("/data", Arc::new(local), MountFlags::FAIL_FAST)
+            .unwrap();
+
+        for _ in 0..5 {
+            assert!(mgr.read(Path::new("/data/dir")).is_err());
+            assert!(mgr.remove_dir(Path::new("/data/dir")).is_err());
+            assert!(mgr.read(Path::new("/data/missing")).is_err());
+        }
+        assert_eq!(mgr.circuit_breaker().state("/data"), BreakerState::Closed);
+        assert_eq!(mgr.read(Path::new("/data/dir/file")).unwrap(), b"x");
+    }
+
+    #[test]
+    fn test_mounts_without_fail_fast_have_no_circuit() {
+        let mgr = MountManager::new().with_circuit_breaker(breaker(Duration::from_secs(60)));
+        let backend = Arc::new(FlakyFS::new());
+        mgr.mount("/remote", backend.clone()).unwrap();
+
+        backend.failing.store(true, Ordering::SeqCst);
+        for _ in 0..5 {
+            assert!(matches!(
+                mgr.read(Path::new("/remote/a")),
+                Err(VfsError::Unavailable(_))
+            ));
+        }
+        assert_eq!(backend.calls.load(Ordering::SeqCst), 5);
+        assert!(mgr.circuit_breaker().stats().is_empty());
+    }
+}
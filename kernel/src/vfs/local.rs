@@ -4,19 +4,44 @@
  */
 
 use std::fs;
-use std::io::{Read, Seek, SeekFrom, Write};
+use std::io::{IoSlice, IoSliceMut, Read, Seek, SeekFrom, Write};
 use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
 use std::time::SystemTime;
 
 use super::traits::{FileSystem, OpenFile};
 use super::types::*;
 use crate::core::{simd_memcpy, PooledBuffer};
 
+/// Read-ahead statistics shared by all files opened through a LocalFS
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ReadAheadStats {
+    /// Reads served entirely from a prefetched buffer
+    pub hits: u64,
+    /// Reads that had to go to the host file
+    pub misses: u64,
+    /// Number of prefetches issued
+    pub prefetches: u64,
+    /// Bytes read from the host file by prefetches
+    pub prefetched_bytes: u64,
+}
+
+#[derive(Debug, Default)]
+struct ReadAheadCounters {
+    hits: AtomicU64,
+    misses: AtomicU64,
+    prefetches: AtomicU64,
+    prefetched_bytes: AtomicU64,
+}
+
 /// Local filesystem implementation using std::fs
 #[derive(Debug, Clone)]
 pub struct LocalFS {
     root: PathBuf,
     readonly: bool,
+    read_ahead: usize,
+    read_ahead_counters: Arc<ReadAheadCounters>,
 }
 
 impl LocalFS {
@@ -25,14 +50,40 @@ pub fn new<P: Into<PathBuf>>(root: P) -> Self {
         Self {
             root: root.into(),
             readonly: false,
+            read_ahead: 0,
+            read_ahead_counters: Arc::new(ReadAheadCounters::default()),
         }
     }
 
     /// Create read-only local filesystem
     pub fn readonly<P: Into<PathBuf>>(root: P) -> Self {
         Self {
-            root: root.into(),
             readonly: true,
+            ..Self::new(root)
+        }
+    }
+
+    /// Prefetch up to `window` bytes ahead of sequential reads (0 disables)
+    ///
+    /// See `LOCALFS_READ_AHEAD_WINDOW` for a sensible default.
+    pub fn with_read_ahead(mut self, window: usize) -> Self {
+        self.read_ahead = window;
+        self
+    }
+
+    /// Read-ahead window in bytes (0 when disabled)
+    pub fn read_ahead(&self) -> usize {
+        self.read_ahead
+    }
+
+    /// Read-ahead statistics across all files opened through this filesystem
+    pub fn read_ahead_stats(&self) -> ReadAheadStats {
+        let c = &self.read_ahead_counters;
+        ReadAheadStats {
+            hits: c.hits.load(Ordering::Relaxed),
+            misses: c.misses.load(Ordering::Relaxed),
+            prefetches: c.prefetches.load(Ordering::Relaxed),
+            prefetched_bytes: c.prefetched_bytes.load(Ordering::Relaxed),
         }
     }
 
@@ -94,13 +145,43 @@ fn check_write(&self) -> VfsResult<()> {
     }
 
     /// Convert std::io::Error to VfsError
-    fn io_error(e: std::io::Error, context: impl Into<String>) -> VfsError {
+    ///
+    /// Only failures of the backing storage itself map to
+    /// `VfsError::Unavailable`; mistakes a caller can provoke get their own
+    /// variants so they never count against a mount's circuit.
+    pub(crate) fn io_error(e: std::io::Error, context: impl Into<String>) -> VfsError {
+        use nix::libc;
         use std::io::ErrorKind;
         let context_str: String = context.into();
         match e.kind() {
-            ErrorKind::NotFound => VfsError::NotFound(context_str.into()),
-            ErrorKind::PermissionDenied => VfsError::PermissionDenied(context_str.into()),
-            ErrorKind::AlreadyExists => VfsError::AlreadyExists(context_str.into()),
+            ErrorKind::NotFound => return VfsError::NotFound(context_str.into()),
+            ErrorKind::PermissionDenied => return VfsError::PermissionDenied(context_str.into()),
+            ErrorKind::AlreadyExists => return VfsError::AlreadyExists(context_str.into()),
+            ErrorKind::TimedOut => {
+                return VfsError::Unavailable(format!("{}: {}", context_str, e).into())
+            }
+            _ => {}
+        }
+
+        match e.raw_os_error() {
+            Some(libc::EISDIR) => VfsError::IsADirectory(context_str.into()),
+            Some(libc::ENOTDIR) => VfsError::NotADirectory(context_str.into()),
+            Some(libc::EINVAL | libc::ENOTEMPTY) => {
+                VfsError::InvalidArgument(format!("{}: {}", context_str, e).into())
+            }
+            Some(libc::ENAMETOOLONG | libc::ELOOP) => VfsError::InvalidPath(context_str.into()),
+            Some(libc::ENOSPC | libc::EDQUOT) => VfsError::OutOfSpace,
+            Some(libc::EFBIG) => VfsError::FileTooLarge,
+            Some(libc::EROFS) => VfsError::ReadOnly,
+            Some(libc::EXDEV) => VfsError::CrossDevice,
+            Some(
+                libc::EIO
+                | libc::ENOTCONN
+                | libc::ETIMEDOUT
+                | libc::ESTALE
+                | libc::EHOSTDOWN
+                | libc::ENETDOWN,
+            ) => VfsError::Unavailable(format!("{}: {}", context_str, e).into()),
             _ => VfsError::IoError(format!("{}: {}", context_str, e).into()),
         }
     }
@@ -181,10 +262,12 @@ fn write(&self, path: &Path, data: &[u8]) -> VfsResult<()> {
         }
     }
 
-    fn append(&self, path: &Path, data: &[u8]) -> VfsResult<()> {
+    fn append(&self, path: &Path, data: &[u8]) -> VfsResult<u64> {
         self.check_write()?;
         let full_path = self.resolve(path);
 
+        // O_APPEND: the OS moves to end of file and writes as one step, so
+        // concurrent appenders never overwrite each other
         use std::fs::OpenOptions;
         let mut file = OpenOptions::new()
             .create(true)
@@ -192,16 +275,12 @@ fn append(&self, path: &Path, data: &[u8]) -> VfsResult<()> {
             .open(&full_path)
             .map_err(|e| Self::io_error(e, format!("open for append {}", path.display())))?;
 
-        // Use SIMD-accelerated copy for large appends (> 64 bytes)
-        if data.len() >= 64 {
-            let mut append_buf = vec![0u8; data.len()];
-            append_buf.copy_from_slice(data);
-            file.write_all(&append_buf)
-                .map_err(|e| Self::io_error(e, format!("append {}", path.display())))
-        } else {
-            file.write_all(data)
-                .map_err(|e| Self::io_error(e, format!("append {}", path.display())))
-        }
+        file.write_all(data)
+            .map_err(|e| Self::io_error(e, format!("append {}", path.display())))?;
+
+        // The offset now sits just past this write, even if others appended since
+        file.stream_position()
+            .map_err(|e| Self::io_error(e, format!("append {}", path.display())))
     }
 
     fn create(&self, path: &Path) -> VfsResult<()> {
@@ -318,6 +397,21 @@ fn copy(&self, from: &Path, to: &Path) -> VfsResult<()> {
         Ok(())
     }
 
+    fn copy_reflink(&self, from: &Path, to: &Path) -> VfsResult<bool> {
+        self.check_write()?;
+        let from_full = self.resolve(from);
+        let to_full = self.resolve(to);
+
+        if let Some(parent) = to_full.parent() {
+            fs::create_dir_all(parent).map_err(|e| {
+                Self::io_error(e, format!("create parent dirs for {}", to.display()))
+            })?;
+        }
+
+        reflink_or_copy(&from_full, &to_full)
+            .map_err(|e| Self::io_error(e, format!("copy {} to {}", from.display(), to.display())))
+    }
+
     fn rename(&self, from: &Path, to: &Path) -> VfsResult<()> {
         self.check_write()?;
         let from_full = self.resolve(from);
@@ -334,6 +428,22 @@ fn rename(&self, from: &Path, to: &Path) -> VfsResult<()> {
         })
     }
 
+    fn rename_noreplace(&self, from: &Path, to: &Path) -> VfsResult<()> {
+        self.check_write()?;
+        let from_full = self.resolve(from);
+        let to_full = self.resolve(to);
+
+        if let Some(parent) = to_full.parent() {
+            fs::create_dir_all(parent).map_err(|e| {
+                Self::io_error(e, format!("create parent dirs for {}", to.display()))
+            })?;
+        }
+
+        rename_noreplace(&from_full, &to_full).map_err(|e| {
+            Self::io_error(e, format!("rename {} to {}", from.display(), to.display()))
+        })
+    }
+
     fn symlink(&self, src: &Path, dst: &Path) -> VfsResult<()> {
         self.check_write()?;
         let dst_full = self.resolve(dst);
@@ -427,7 +537,98 @@ fn open(&self, path: &Path, flags: OpenFlags, _mode: OpenMode) -> VfsResult<Box<
             .open(&full_path)
             .map_err(|e| Self::io_error(e, format!("open {}", path.display())))?;
 
-        Ok(Box::new(LocalFile { file }))
+        Ok(Box::new(LocalFile::new(
+            file,
+            self.read_ahead,
+            Arc::clone(&self.read_ahead_counters),
+        )))
+    }
+
+    fn write_vectored(&self, path: &Path, segments: &[&[u8]]) -> VfsResult<usize> {
+        self.check_write()?;
+        let full_path = self.resolve(path);
+
+        if let Some(parent) = full_path.parent() {
+            fs::create_dir_all(parent).map_err(|e| {
+                Self::io_error(e, format!("create parent dirs for {}", path.display()))
+            })?;
+        }
+
+        let mut file = fs::File::create(&full_path)
+            .map_err(|e| Self::io_error(e, format!("write {}", path.display())))?;
+
+        // writev(2) on Unix; loop until short writes have drained every slice
+        let mut slices: Vec<IoSlice> = segments.iter().map(|s| IoSlice::new(s)).collect();
+        let mut remaining = &mut slices[..];
+        let mut total = 0;
+        // Drop leading empty slices so all-empty input writes nothing
+        IoSlice::advance_slices(&mut remaining, 0);
+        while !remaining.is_empty() {
+            match file.write_vectored(remaining) {
+                Ok(0) => {
+                    return Err(VfsError::IoError(
+                        format!("write {}: wrote zero bytes", path.display()).into(),
+                    ))
+                }
+                Ok(n) => {
+                    total += n;
+                    IoSlice::advance_slices(&mut remaining, n);
+                }
+                Err(e) if e.kind() == std::io::ErrorKind::Interrupted => {}
+                Err(e) => return Err(Self::io_error(e, format!("write {}", path.display()))),
+            }
+        }
+
+        Ok(total)
+    }
+
+    fn read_vectored(&self, path: &Path, sizes: &[usize]) -> VfsResult<Vec<Vec<u8>>> {
+        let full_path = self.resolve(path);
+        let mut file = fs::File::open(&full_path)
+            .map_err(|e| Self::io_error(e, format!("read {}", path.display())))?;
+
+        // Sizes come from the caller; never allocate past the end of the file
+        let len = file
+            .metadata()
+            .map_err(|e| Self::io_error(e, format!("stat {}", path.display())))?
+            .len();
+        let mut available = usize::try_from(len).unwrap_or(usize::MAX);
+        let mut segments: Vec<Vec<u8>> = sizes
+            .iter()
+            .map(|&size| {
+                let size = size.min(available);
+                available -= size;
+                vec![0; size]
+            })
+            .collect();
+        let mut total = 0;
+        {
+            // readv(2) on Unix; loop until every slice is filled or EOF
+            let mut slices: Vec<IoSliceMut> =
+                segments.iter_mut().map(|s| IoSliceMut::new(s)).collect();
+            let mut remaining = &mut slices[..];
+            // Drop leading zero-sized slices
+            IoSliceMut::advance_slices(&mut remaining, 0);
+            while !remaining.is_empty() {
+                match file.read_vectored(remaining) {
+                    Ok(0) => break,
+                    Ok(n) => {
+                        total += n;
+                        IoSliceMut::advance_slices(&mut remaining, n);
+                    }
+                    Err(e) if e.kind() == std::io::ErrorKind::Interrupted => {}
+                    Err(e) => return Err(Self::io_error(e, format!("read {}", path.display()))),
+                }
+            }
+        }
+
+        // Slices are filled in order, so EOF leaves a short one then empties
+        for segment in &mut segments {
+            let filled = segment.len().min(total);
+            segment.truncate(filled);
+            total -= filled;
+        }
+        Ok(segments)
     }
 
     fn name(&self) -> &str {
@@ -440,19 +641,142 @@ fn readonly(&self) -> bool {
 }
 
 /// Local file handle
+///
+/// With read-ahead enabled, a read that continues where the previous one
+/// ended fills a buffer of the next `window` bytes, and following reads are
+/// served from it. Any read at another offset marks the file as randomly
+/// accessed and stops prefetching until reads become sequential again.
 struct LocalFile {
     file: fs::File,
+    /// Logical offset seen by callers
+    pos: u64,
+    /// Offset of the host file cursor, which runs ahead of `pos` after a prefetch
+    file_pos: u64,
+    window: usize,
+    buffer: Vec<u8>,
+    /// File offset of `buffer[0]`
+    buffer_start: u64,
+    /// Where the previous read ended, for sequential detection
+    last_read_end: u64,
+    counters: Arc<ReadAheadCounters>,
+}
+
+impl LocalFile {
+    fn new(file: fs::File, window: usize, counters: Arc<ReadAheadCounters>) -> Self {
+        Self {
+            file,
+            pos: 0,
+            file_pos: 0,
+            window,
+            buffer: Vec::new(),
+            buffer_start: 0,
+            last_read_end: 0,
+            counters,
+        }
+    }
+
+    /// Copy from the prefetch buffer if it covers the current offset
+    fn read_buffered(&mut self, buf: &mut [u8]) -> Option<usize> {
+        let buffer_end = self.buffer_start + self.buffer.len() as u64;
+        if self.pos < self.buffer_start || self.pos >= buffer_end {
+            return None;
+        }
+
+        let offset = (self.pos - self.buffer_start) as usize;
+        let n = buf.len().min(self.buffer.len() - offset);
+        buf[..n].copy_from_slice(&self.buffer[offset..offset + n]);
+        self.pos += n as u64;
+        Some(n)
+    }
+
+    /// Move the host cursor to the logical offset
+    fn sync_cursor(&mut self) -> std::io::Result<()> {
+        if self.file_pos != self.pos {
+            self.file_pos = self.file.seek(SeekFrom::Start(self.pos))?;
+        }
+        Ok(())
+    }
+
+    /// Fill the buffer with up to `window` bytes from the current offset
+    fn prefetch(&mut self) -> std::io::Result<()> {
+        self.sync_cursor()?;
+        self.buffer.clear();
+        self.buffer.resize(self.window, 0);
+
+        let mut filled = 0;
+        while filled < self.window {
+            match self.file.read(&mut self.buffer[filled..]) {
+                Ok(0) => break,
+                Ok(n) => filled += n,
+                Err(e) if e.kind() == std::io::ErrorKind::Interrupted => {}
+                Err(e) => {
+                    self.buffer.clear();
+                    return Err(e);
+                }
+            }
+        }
+
+        self.buffer.truncate(filled);
+        self.buffer_start = self.pos;
+        self.file_pos = self.pos + filled as u64;
+        self.counters.prefetches.fetch_add(1, Ordering::Relaxed);
+        self.counters
+            .prefetched_bytes
+            .fetch_add(filled as u64, Ordering::Relaxed);
+        Ok(())
+    }
+
+    fn invalidate(&mut self) {
+        self.buffer.clear();
+    }
 }
 
 impl Read for LocalFile {
     fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
-        self.file.read(buf)
+        if self.window == 0 {
+            // Seeks only move `pos`, so catch the host cursor up first
+            self.sync_cursor()?;
+            let n = self.file.read(buf)?;
+            self.pos += n as u64;
+            self.file_pos = self.pos;
+            return Ok(n);
+        }
+
+        let sequential = self.pos == self.last_read_end;
+        if let Some(n) = self.read_buffered(buf) {
+            self.counters.hits.fetch_add(1, Ordering::Relaxed);
+            self.last_read_end = self.pos;
+            return Ok(n);
+        }
+
+        self.counters.misses.fetch_add(1, Ordering::Relaxed);
+        let n = if sequential && buf.len() < self.window {
+            self.prefetch()?;
+            self.read_buffered(buf).unwrap_or(0)
+        } else {
+            // Random access or a read larger than the window: go direct
+            self.invalidate();
+            self.sync_cursor()?;
+            let n = self.file.read(buf)?;
+            self.pos += n as u64;
+            self.file_pos = self.pos;
+            n
+        };
+
+        self.last_read_end = self.pos;
+        Ok(n)
     }
 }
 
 impl Write for LocalFile {
     fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
-        self.file.write(buf)
+        self.invalidate();
+        self.sync_cursor()?;
+        let n = self.file.write(buf)?;
+        // Append mode writes land at EOF regardless of the cursor
+        self.pos = self.file.stream_position()?;
+        self.file_pos = self.pos;
+        Ok(n)
     }
 
     fn flush(&mut self) -> std::io::Result<()> {
@@ -462,7 +786,24 @@ fn flush(&mut self) -> std::io::Result<()> {
 
 impl Seek for LocalFile {
     fn seek(&mut self, pos: SeekFrom) -> std::io::Result<u64> {
-        self.file.seek(pos)
+        let target = match pos {
+            SeekFrom::Start(offset) => Some(offset),
+            SeekFrom::Current(delta) => self.pos.checked_add_signed(delta),
+            SeekFrom::End(_) => {
+                self.pos = self.file.seek(pos)?;
+                self.file_pos = self.pos;
+                return Ok(self.pos);
+            }
+        };
+
+        // Only moves the logical offset; the buffer stays valid for short seeks
+        self.pos = target.ok_or_else(|| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                "invalid seek to a negative or overflowing position",
+            )
+        })?;
+        Ok(self.pos)
     }
 }
 
@@ -470,24 +811,100 @@ impl OpenFile for LocalFile {
     fn sync(&mut self) -> VfsResult<()> {
         self.file
             .sync_all()
-            .map_err(|e| VfsError::IoError(format!("sync: {}", e).into()))
+            .map_err(|e| LocalFS::io_error(e, "sync"))
     }
 
     fn metadata(&self) -> VfsResult<Metadata> {
         let md = self
             .file
             .metadata()
-            .map_err(|e| VfsError::IoError(format!("metadata: {}", e).into()))?;
+            .map_err(|e| LocalFS::io_error(e, "metadata"))?;
         Ok(LocalFS::convert_metadata(md))
     }
 
     fn set_len(&mut self, size: u64) -> VfsResult<()> {
+        self.invalidate();
         self.file
             .set_len(size)
-            .map_err(|e| VfsError::IoError(format!("set_len: {}", e).into()))
+            .map_err(|e| LocalFS::io_error(e, "set_len"))
     }
 }
 
+/// Rename `from` to `to`, failing with `AlreadyExists` if `to` exists
+///
+/// Uses `renameat2(RENAME_NOREPLACE)` on Linux. Where that isn't available
+/// (other platforms, or filesystems that reject the flag) it falls back to
+/// hard-linking then unlinking the source: the link fails if the destination
+/// exists, so the claim stays atomic, but the fallback only handles files.
+pub(crate) fn rename_noreplace(from: &Path, to: &Path) -> std::io::Result<()> {
+    #[cfg(target_os = "linux")]
+    {
+        use nix::libc;
+        use std::ffi::CString;
+        use std::os::unix::ffi::OsStrExt;
+
+        let from_c = CString::new(from.as_os_str().as_bytes())?;
+        let to_c = CString::new(to.as_os_str().as_bytes())?;
+        // SAFETY: both pointers are NUL-terminated strings that outlive the call
+        let ret = unsafe {
+            libc::renameat2(
+                libc::AT_FDCWD,
+                from_c.as_ptr(),
+                libc::AT_FDCWD,
+                to_c.as_ptr(),
+                libc::RENAME_NOREPLACE,
+            )
+        };
+        if ret == 0 {
+            return Ok(());
+        }
+
+        let err = std::io::Error::last_os_error();
+        if !matches!(err.raw_os_error(), Some(libc::EINVAL | libc::ENOSYS)) {
+            return Err(err);
+        }
+    }
+
+    fs::hard_link(from, to)?;
+    fs::remove_file(from)
+}
+
+/// Copy `from` to `to`, cloning extents instead of bytes where possible
+///
+/// Uses the `FICLONE` ioctl on Linux, which shares storage on filesystems
+/// that support it (btrfs, XFS, bcachefs). Anywhere else, or when the
+/// filesystem refuses, it falls back to a byte copy. Returns whether the
+/// copy was a reflink.
+pub(crate) fn reflink_or_copy(from: &Path, to: &Path) -> std::io::Result<bool> {
+    #[cfg(target_os = "linux")]
+    {
+        use nix::libc;
+        use std::os::unix::io::AsRawFd;
+
+        // _IOW(0x94, 9, int)
+        const FICLONE: libc::c_ulong = 0x4004_9409;
+
+        let src = fs::File::open(from)?;
+        // Not truncated up front: if the clone is refused, the destination
+        // stays intact until the byte copy replaces it
+        let dst = fs::OpenOptions::new()
+            .write(true)
+            .create(true)
+            .truncate(false)
+            .open(to)?;
+        // SAFETY: both descriptors are open for the duration of the call
+        let ret = unsafe { libc::ioctl(dst.as_raw_fd(), FICLONE as _, src.as_raw_fd()) };
+        if ret == 0 {
+            // A longer old destination keeps its tail past the cloned range
+            dst.set_len(src.metadata()?.len())?;
+            return Ok(true);
+        }
+    }
+
+    fs::copy(from, to)?;
+    Ok(false)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -512,6 +929,25 @@ fn test_local_fs_basic() {
         assert!(!fs.exists(Path::new("test.txt")));
     }
 
+    #[test]
+    fn test_local_fs_rename_noreplace() {
+        let temp = TempDir::new().unwrap();
+        let fs = LocalFS::new(temp.path());
+
+        fs.write(Path::new("a.txt"), b"a").unwrap();
+        fs.write(Path::new("b.txt"), b"b").unwrap();
+
+        let result = fs.rename_noreplace(Path::new("a.txt"), Path::new("b.txt"));
+        assert!(matches!(result, Err(VfsError::AlreadyExists(_))));
+        assert_eq!(fs.read(Path::new("a.txt")).unwrap(), b"a");
+        assert_eq!(fs.read(Path::new("b.txt")).unwrap(), b"b");
+
+        fs.rename_noreplace(Path::new("a.txt"), Path::new("c.txt"))
+            .unwrap();
+        assert!(!fs.exists(Path::new("a.txt")));
+        assert_eq!(fs.read(Path::new("c.txt")).unwrap(), b"a");
+    }
+
     #[test]
     fn test_local_fs_directories() {
         let temp = TempDir::new().unwrap();
@@ -532,6 +968,114 @@ fn test_local_fs_directories() {
         assert_eq!(entries[0].name, "file.txt");
     }
 
+    #[test]
+    fn test_read_ahead_sequential_and_random() {
+        let temp = TempDir::new().unwrap();
+        let fs = LocalFS::new(temp.path()).with_read_ahead(64);
+        let content: Vec<u8> = (0..=255u8).collect();
+        fs.write(Path::new("data.bin"), &content).unwrap();
+
+        let mut file = fs
+            .open(
+                Path::new("data.bin"),
+                OpenFlags::read_only(),
+                OpenMode::default(),
+            )
+            .unwrap();
+        let mut read = Vec::new();
+        let mut chunk = [0u8; 16];
+        loop {
+            let n = file.read(&mut chunk).unwrap();
+            if n == 0 {
+                break;
+            }
+            read.extend_from_slice(&chunk[..n]);
+        }
+        assert_eq!(read, content);
+
+        // 16 chunks from 4 prefetches of 64 bytes, plus the EOF probe
+        let stats = fs.read_ahead_stats();
+        assert_eq!(stats.prefetches, 5);
+        assert_eq!(stats.prefetched_bytes, 256);
+        assert_eq!(stats.hits, 12);
+
+        // Jumping around disables prefetch
+        for offset in [200u64, 10, 150] {
+            file.seek(SeekFrom::Start(offset)).unwrap();
+            file.read_exact(&mut chunk[..1]).unwrap();
+            assert_eq!(chunk[0], offset as u8);
+        }
+        assert_eq!(fs.read_ahead_stats().prefetches, 5);
+
+        // Writes are visible to the next read
+        let mut file = fs
+            .open(
+                Path::new("data.bin"),
+                OpenFlags::read_write(),
+                OpenMode::default(),
+            )
+            .unwrap();
+        file.read_exact(&mut chunk[..4]).unwrap();
+        file.write_all(b"xy").unwrap();
+        file.seek(SeekFrom::Start(4)).unwrap();
+        file.read_exact(&mut chunk[..3]).unwrap();
+        assert_eq!(&chunk[..3], b"xy\x06");
+    }
+
+    #[test]
+    fn test_seek_then_read_without_read_ahead() {
+        let temp = TempDir::new().unwrap();
+        let fs = LocalFS::new(temp.path());
+        let content: Vec<u8> = (0..=255u8).collect();
+        fs.write(Path::new("data.bin"), &content).unwrap();
+
+        let mut file = fs
+            .open(
+                Path::new("data.bin"),
+                OpenFlags::read_only(),
+                OpenMode::default(),
+            )
+            .unwrap();
+        let mut chunk = [0u8; 4];
+        file.read_exact(&mut chunk).unwrap();
+        assert_eq!(chunk, [0, 1, 2, 3]);
+
+        file.seek(SeekFrom::Start(100)).unwrap();
+        file.read_exact(&mut chunk).unwrap();
+        assert_eq!(chunk, [100, 101, 102, 103]);
+
+        file.seek(SeekFrom::Current(-50)).unwrap();
+        file.read_exact(&mut chunk).unwrap();
+        assert_eq!(chunk, [58, 59, 60, 61]);
+        assert_eq!(fs.read_ahead_stats().prefetches, 0);
+    }
+
+    #[test]
+    fn test_vectored_io() {
+        let temp = TempDir::new().unwrap();
+        let fs = LocalFS::new(temp.path());
+
+        let written = fs
+            .write_vectored(Path::new("dir/v.txt"), &[b"hello", b" ", b"", b"world"])
+            .unwrap();
+        assert_eq!(written, 11);
+        assert_eq!(fs.read(Path::new("dir/v.txt")).unwrap(), b"hello world");
+
+        let segments = fs
+            .read_vectored(Path::new("dir/v.txt"), &[5, 1, 10, 3])
+            .unwrap();
+        assert_eq!(
+            segments,
+            vec![b"hello".to_vec(), b" ".to_vec(), b"world".to_vec(), vec![]]
+        );
+
+        // Oversized requests are clamped to the file, not allocated up front
+        let segments = fs
+            .read_vectored(Path::new("dir/v.txt"), &[usize::MAX, usize::MAX])
+            .unwrap();
+        assert_eq!(segments, vec![b"hello world".to_vec(), vec![]]);
+    }
+
     #[test]
     fn test_readonly() {
         let temp = TempDir::new().unwrap();
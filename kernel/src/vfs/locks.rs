@@ -0,0 +1,367 @@
+/*!
+ * Range Locks
+ * Advisory byte-range locks (fcntl F_SETLK/F_GETLK semantics)
+ *
+ * Locks are keyed by path and owned by a process. Like POSIX record locks
+ * they are advisory: they only coordinate processes that ask for them, and
+ * a process never conflicts with its own locks. Relocking part of a range a
+ * process already holds replaces that part, which is how locks are upgraded,
+ * downgraded and split.
+ */
+
+use super::types::{VfsError, VfsResult};
+use crate::core::sync::WaitQueue;
+use crate::core::types::Pid;
+use ahash::RandomState;
+use dashmap::DashMap;
+use std::ops::Range;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+/// Wakeups are best-effort, so blocked lockers recheck at least this often
+const LOCK_WAIT_SLICE: Duration = Duration::from_millis(10);
+
+/// Wait queue key signalled whenever any lock is released or downgraded
+const LOCK_RELEASED: u8 = 0;
+
+/// A held byte-range lock
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RangeLock {
+    /// Owning process
+    pub pid: Pid,
+    /// Locked bytes; an end of `u64::MAX` extends to end of file
+    pub range: Range<u64>,
+    /// Exclusive (write) lock, otherwise shared (read)
+    pub exclusive: bool,
+}
+
+impl RangeLock {
+    #[inline]
+    fn overlaps(&self, range: &Range<u64>) -> bool {
+        self.range.start < range.end && range.start < self.range.end
+    }
+
+    /// Whether this lock blocks `pid` from taking `range`
+    #[inline]
+    fn conflicts(&self, range: &Range<u64>, exclusive: bool, pid: Pid) -> bool {
+        self.pid != pid && (exclusive || self.exclusive) && self.overlaps(range)
+    }
+}
+
+/// In-kernel advisory lock table keyed by path
+#[derive(Clone)]
+pub struct RangeLockTable {
+    locks: Arc<DashMap<PathBuf, Vec<RangeLock>, RandomState>>,
+    waiters: Arc<WaitQueue<u8>>,
+}
+
+impl RangeLockTable {
+    pub fn new() -> Self {
+        Self {
+            locks: Arc::new(DashMap::with_hasher(RandomState::new())),
+            waiters: Arc::new(WaitQueue::with_defaults()),
+        }
+    }
+
+    /// Take a shared or exclusive lock on `range` of `path` for `pid`
+    ///
+    /// Fails with `VfsError::WouldBlock` if another process holds a
+    /// conflicting lock.
+    pub fn lock(&self, path: &Path, range: Range<u64>, exclusive: bool, pid: Pid) -> VfsResult<()> {
+        check_range(&range)?;
+
+        let mut held = self.locks.entry(path.to_path_buf()).or_default();
+        if held
+            .iter()
+            .any(|lock| lock.conflicts(&range, exclusive, pid))
+        {
+            return Err(VfsError::WouldBlock(
+                format!("{} is locked by another process", path.display()).into(),
+            ));
+        }
+
+        let replaced = carve(&mut held, pid, &range);
+        held.push(RangeLock {
+            pid,
+            range,
+            exclusive,
+        });
+        drop(held);
+
+        // A downgrade may unblock shared waiters
+        if replaced && !exclusive {
+            self.waiters.wake_all(LOCK_RELEASED);
+        }
+        Ok(())
+    }
+
+    /// Like [`lock`](Self::lock), but waits up to `timeout` for conflicting
+    /// locks to be released
+    pub fn lock_wait(
+        &self,
+        path: &Path,
+        range: Range<u64>,
+        exclusive: bool,
+        pid: Pid,
+        timeout: Duration,
+    ) -> VfsResult<()> {
+        let deadline = Instant::now() + timeout;
+        loop {
+            match self.lock(path, range.clone(), exclusive, pid) {
+                Err(VfsError::WouldBlock(msg)) => {
+                    let now = Instant::now();
+                    if now >= deadline {
+                        return Err(VfsError::WouldBlock(msg));
+                    }
+                    let _ = self
+                        .waiters
+                        .wait(LOCK_RELEASED, Some((deadline - now).min(LOCK_WAIT_SLICE)));
+                }
+                result => return result,
+            }
+        }
+    }
+
+    /// Release `pid`'s locks within `range` of `path`
+    ///
+    /// Locks partially covered by `range` are trimmed. Unlocking bytes that
+    /// aren't locked is not an error.
+    pub fn unlock(&self, path: &Path, range: Range<u64>, pid: Pid) -> VfsResult<()> {
+        check_range(&range)?;
+
+        let released = self
+            .locks
+            .get_mut(path)
+            .is_some_and(|mut held| carve(&mut held, pid, &range));
+        self.locks.remove_if(path, |_, held| held.is_empty());
+
+        if released {
+            self.waiters.wake_all(LOCK_RELEASED);
+        }
+        Ok(())
+    }
+
+    /// First lock that would block `pid` from taking `range` (F_GETLK)
+    pub fn conflict(
+        &self,
+        path: &Path,
+        range: Range<u64>,
+        exclusive: bool,
+        pid: Pid,
+    ) -> Option<RangeLock> {
+        self.locks.get(path).and_then(|held| {
+            held.iter()
+                .find(|lock| lock.conflicts(&range, exclusive, pid))
+                .cloned()
+        })
+    }
+
+    /// Locks currently held on `path`
+    pub fn locks(&self, path: &Path) -> Vec<RangeLock> {
+        self.locks
+            .get(path)
+            .map(|held| held.clone())
+            .unwrap_or_default()
+    }
+
+    /// Release every lock held by `pid`, returning how many were dropped
+    pub fn release_process(&self, pid: Pid) -> usize {
+        let mut released = 0;
+        self.locks.retain(|_, held| {
+            let before = held.len();
+            held.retain(|lock| lock.pid != pid);
+            released += before - held.len();
+            !held.is_empty()
+        });
+
+        if released > 0 {
+            self.waiters.wake_all(LOCK_RELEASED);
+        }
+        released
+    }
+
+    /// Whether `pid` holds any locks
+    pub fn has_process_locks(&self, pid: Pid) -> bool {
+        self.locks
+            .iter()
+            .any(|entry| entry.value().iter().any(|lock| lock.pid == pid))
+    }
+}
+
+impl Default for RangeLockTable {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+fn check_range(range: &Range<u64>) -> VfsResult<()> {
+    if range.start < range.end {
+        Ok(())
+    } else {
+        Err(VfsError::InvalidArgument(
+            format!("empty lock range {}..{}", range.start, range.end).into(),
+        ))
+    }
+}
+
+/// Cut `range` out of `pid`'s locks, splitting any that straddle it
+fn carve(held: &mut Vec<RangeLock>, pid: Pid, range: &Range<u64>) -> bool {
+    if !held
+        .iter()
+        .any(|lock| lock.pid == pid && lock.overlaps(range))
+    {
+        return false;
+    }
+
+    let mut kept = Vec::with_capacity(held.len() + 1);
+    for lock in held.drain(..) {
+        if lock.pid != pid || !lock.overlaps(range) {
+            kept.push(lock);
+            continue;
+        }
+        if lock.range.start < range.start {
+            kept.push(RangeLock {
+                range: lock.range.start..range.start,
+                ..lock.clone()
+            });
+        }
+        if range.end < lock.range.end {
+            kept.push(RangeLock {
+                range: range.end..lock.range.end,
+                ..lock
+            });
+        }
+    }
+    *held = kept;
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SHARED: bool = false;
+    const EXCLUSIVE: bool = true;
+
+    fn path() -> &'static Path {
+        Path::new("/data/shared.db")
+    }
+
+    #[test]
+    fn test_conflict_matrix() {
+        // (held, requested, conflicts)
+        let cases = [
+            (SHARED, SHARED, false),
+            (SHARED, EXCLUSIVE, true),
+            (EXCLUSIVE, SHARED, true),
+            (EXCLUSIVE, EXCLUSIVE, true),
+        ];
+
+        for (held, requested, conflicts) in cases {
+            let table = RangeLockTable::new();
+            table.lock(path(), 0..100, held, 1).unwrap();
+
+            let result = table.lock(path(), 50..150, requested, 2);
+            assert_eq!(
+                matches!(result, Err(VfsError::WouldBlock(_))),
+                conflicts,
+                "held exclusive={} requested exclusive={}",
+                held,
+                requested
+            );
+            assert_eq!(
+                table.conflict(path(), 50..150, requested, 2).is_some(),
+                conflicts
+            );
+
+            // Disjoint ranges and other paths never conflict
+            table.lock(path(), 100..200, requested, 2).unwrap();
+            table
+                .lock(Path::new("/data/other"), 0..100, requested, 2)
+                .unwrap();
+        }
+    }
+
+    #[test]
+    fn test_own_locks_never_conflict() {
+        let table = RangeLockTable::new();
+        table.lock(path(), 0..100, SHARED, 1).unwrap();
+
+        // Upgrade the middle; the rest stays shared
+        table.lock(path(), 25..75, EXCLUSIVE, 1).unwrap();
+        let mut locks = table.locks(path());
+        locks.sort_by_key(|lock| lock.range.start);
+        let layout: Vec<_> = locks
+            .iter()
+            .map(|lock| (lock.range.clone(), lock.exclusive))
+            .collect();
+        assert_eq!(
+            layout,
+            vec![(0..25, SHARED), (25..75, EXCLUSIVE), (75..100, SHARED)]
+        );
+
+        assert!(table.lock(path(), 0..10, SHARED, 2).is_ok());
+        assert!(table.lock(path(), 30..40, SHARED, 2).is_err());
+    }
+
+    #[test]
+    fn test_unlock_splits_range() {
+        let table = RangeLockTable::new();
+        table.lock(path(), 0..u64::MAX, EXCLUSIVE, 1).unwrap();
+        table.unlock(path(), 10..20, 1).unwrap();
+
+        assert!(table.lock(path(), 10..20, EXCLUSIVE, 2).is_ok());
+        assert!(table.lock(path(), 20..21, SHARED, 2).is_err());
+
+        // Unlocking what another process holds is a no-op
+        table.unlock(path(), 0..u64::MAX, 2).unwrap();
+        table.unlock(path(), 0..u64::MAX, 1).unwrap();
+        assert_eq!(table.locks(path()).len(), 0);
+        assert!(table.lock(path(), 0..10, EXCLUSIVE, 2).is_ok());
+    }
+
+    #[test]
+    fn test_release_process() {
+        let table = RangeLockTable::new();
+        table.lock(path(), 0..10, EXCLUSIVE, 1).unwrap();
+        table.lock(Path::new("/data/b"), 0..10, SHARED, 1).unwrap();
+        table.lock(Path::new("/data/b"), 0..10, SHARED, 2).unwrap();
+
+        assert!(table.has_process_locks(1));
+        assert_eq!(table.release_process(1), 2);
+        assert!(!table.has_process_locks(1));
+        assert!(table.has_process_locks(2));
+        assert!(table.lock(path(), 0..10, EXCLUSIVE, 3).is_ok());
+    }
+
+    #[test]
+    fn test_empty_range_rejected() {
+        let table = RangeLockTable::new();
+        assert!(matches!(
+            table.lock(path(), 5..5, SHARED, 1),
+            Err(VfsError::InvalidArgument(_))
+        ));
+    }
+
+    #[test]
+    fn test_lock_wait() {
+        let table = RangeLockTable::new();
+        table.lock(path(), 0..10, EXCLUSIVE, 1).unwrap();
+
+        assert!(matches!(
+            table.lock_wait(path(), 0..10, SHARED, 2, Duration::from_millis(20)),
+            Err(VfsError::WouldBlock(_))
+        ));
+
+        let holder = table.clone();
+        let release = std::thread::spawn(move || {
+            std::thread::sleep(Duration::from_millis(20));
+            holder.unlock(path(), 0..10, 1).unwrap();
+        });
+        table
+            .lock_wait(path(), 0..10, SHARED, 2, Duration::from_secs(5))
+            .unwrap();
+        release.join().unwrap();
+    }
+}
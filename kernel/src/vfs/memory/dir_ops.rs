@@ -19,7 +19,7 @@ pub(super) fn list_dir_impl(&self, path: &Path) -> VfsResult<Vec<Entry>> {
         with_arena(|arena| {
             let path = self.normalize(path);
 
-            match self.nodes.get(&path).map(|n| n.clone()) {
+            match self.nodes.load().get(&path).map(|n| n.clone()) {
                 Some(Node::Directory { children, .. }) => {
                     let mut entries =
                         bumpalo::collections::Vec::with_capacity_in(children.len(), arena);
@@ -30,7 +30,7 @@ pub(super) fn list_dir_impl(&self, path: &Path) -> VfsResult<Vec<Entry>> {
                             prefetch_read(children_vec[i + 2].1 as *const PathBuf);
                         }
 
-                        if let Some(node) = self.nodes.get(*child_path) {
+                        if let Some(node) = self.nodes.load().get(*child_path) {
                             entries.push(Entry::new_unchecked(
                                 (*name).clone(),
                                 node.file_type().into(),
@@ -52,7 +52,7 @@ pub(super) fn create_dir_impl(&self, path: &Path) -> VfsResult<()> {
 
         // Check parent directory write permissions
         if let Some(parent_path) = self.parent_path(&path) {
-            if let Some(parent_node) = self.nodes.get(&parent_path) {
+            if let Some(parent_node) = self.nodes.load().get(&parent_path) {
                 if let Node::Directory { permissions, .. } = parent_node.value() {
                     // Check if parent directory is writable (owner write permission)
                     if permissions.mode & 0o200 == 0 {
@@ -70,7 +70,7 @@ pub(super) fn create_dir_impl(&self, path: &Path) -> VfsResult<()> {
         for component in path.components().skip(1) {
             current.push(component);
 
-            if !self.nodes.contains_key(&current) {
+            if !self.nodes.load().contains_key(&current) {
                 // Safe: current always has parent since we start from "/" and push components
                 let parent = current
                     .parent()
@@ -86,7 +86,7 @@ pub(super) fn create_dir_impl(&self, path: &Path) -> VfsResult<()> {
                     })?
                     .to_string();
 
-                self.nodes.insert(
+                self.nodes.load().insert(
                     current.clone(),
                     Node::Directory {
                         children: HashMap::default(),
@@ -95,7 +95,7 @@ pub(super) fn create_dir_impl(&self, path: &Path) -> VfsResult<()> {
                     },
                 );
 
-                if let Some(mut entry) = self.nodes.get_mut(&parent) {
+                if let Some(mut entry) = self.nodes.load().get_mut(&parent) {
                     if let Node::Directory { children, .. } = entry.value_mut() {
                         children.insert(name, current.clone());
                     }
@@ -111,7 +111,7 @@ pub(super) fn remove_dir_impl(&self, path: &Path) -> VfsResult<()> {
 
         // Check parent directory write permissions
         if let Some(parent_path) = self.parent_path(&path) {
-            if let Some(parent_node) = self.nodes.get(&parent_path) {
+            if let Some(parent_node) = self.nodes.load().get(&parent_path) {
                 if let Node::Directory { permissions, .. } = parent_node.value() {
                     if permissions.mode & 0o200 == 0 {
                         return Err(VfsError::PermissionDenied(
@@ -123,7 +123,7 @@ pub(super) fn remove_dir_impl(&self, path: &Path) -> VfsResult<()> {
             }
         }
 
-        match self.nodes.get(&path).map(|n| n.clone()) {
+        match self.nodes.load().get(&path).map(|n| n.clone()) {
             Some(Node::Directory { children, .. }) => {
                 if !children.is_empty() {
                     return Err(VfsError::InvalidArgument(
@@ -131,7 +131,7 @@ pub(super) fn remove_dir_impl(&self, path: &Path) -> VfsResult<()> {
                     ));
                 }
 
-                self.nodes.remove(&path);
+                self.nodes.load().remove(&path);
 
                 // Remove from parent
                 if let Some(parent) = self.parent_path(&path) {
@@ -159,7 +159,7 @@ pub(super) fn remove_dir_all_impl(&self, path: &Path) -> VfsResult<()> {
             to_visit.push(path.clone());
 
             while let Some(current) = to_visit.pop() {
-                if let Some(entry) = self.nodes.get(&current) {
+                if let Some(entry) = self.nodes.load().get(&current) {
                     if let Node::Directory { children, .. } = entry.value() {
                         for child_path in children.values() {
                             to_visit.push(child_path.clone());
@@ -178,12 +178,12 @@ pub(super) fn remove_dir_all_impl(&self, path: &Path) -> VfsResult<()> {
                     crate::core::optimization::prefetch_read(&to_remove[i + 3] as *const PathBuf);
                 }
 
-                if let Some(entry) = self.nodes.get(path_to_remove) {
+                if let Some(entry) = self.nodes.load().get(path_to_remove) {
                     if let Node::File { data, .. } = entry.value() {
                         total_size += data.lock().len();
                     }
                 }
-                self.nodes.remove(path_to_remove);
+                self.nodes.load().remove(path_to_remove);
             }
 
             if let Some(parent) = self.parent_path(&path) {
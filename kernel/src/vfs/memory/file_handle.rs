@@ -97,6 +97,11 @@ fn set_len(&mut self, size: u64) -> VfsResult<()> {
         data.resize(size as usize, 0);
         Ok(())
     }
+
+    fn contents(&self) -> Option<&[u8]> {
+        // Unreadable handles fall back to read(), which reports the error
+        self.flags.read.then(|| self.cursor.get_ref().as_slice())
+    }
 }
 
 impl Drop for MemFile {
@@ -6,6 +6,7 @@
 use std::path::Path;
 use std::time::SystemTime;
 
+use super::super::traits::split_segments;
 use super::super::types::*;
 use super::node::Node;
 use super::MemFS;
@@ -18,7 +19,7 @@ pub(super) fn read_impl(&self, path: &Path) -> VfsResult<Vec<u8>> {
         with_arena(|arena| {
             let path = self.normalize(path);
 
-            match self.nodes.get(&path).map(|n| n.clone()) {
+            match self.nodes.load().get(&path).map(|n| n.clone()) {
                 Some(Node::File { data, .. }) => {
                     let cow_guard = data.lock();
                     let content = cow_guard.read(|buf| {
@@ -37,12 +38,43 @@ pub(super) fn read_impl(&self, path: &Path) -> VfsResult<Vec<u8>> {
         })
     }
 
+    /// Split the file into segments under a single lock of its buffer
+    pub(super) fn read_vectored_impl(
+        &self,
+        path: &Path,
+        sizes: &[usize],
+    ) -> VfsResult<Vec<Vec<u8>>> {
+        let path = self.normalize(path);
+
+        match self.nodes.load().get(&path).map(|n| n.clone()) {
+            Some(Node::File { data, .. }) => {
+                let cow_guard = data.lock();
+                Ok(cow_guard.read(|buf| split_segments(buf, sizes)))
+            }
+            Some(Node::Directory { .. }) => {
+                Err(VfsError::IsADirectory(format!("{}", path.display()).into()))
+            }
+            None => Err(VfsError::NotFound(format!("{}", path.display()).into())),
+        }
+    }
+
+    /// Gather segments into the new node buffer in one pass
+    pub(super) fn write_vectored_impl(&self, path: &Path, segments: &[&[u8]]) -> VfsResult<usize> {
+        let total = segments.iter().map(|s| s.len()).sum();
+        let mut buffer = PooledBuffer::get(total);
+        for segment in segments {
+            buffer.extend_from_slice(segment);
+        }
+        self.write_impl(path, &buffer)?;
+        Ok(total)
+    }
+
     pub(super) fn write_impl(&self, path: &Path, data: &[u8]) -> VfsResult<()> {
         let path = self.normalize(path);
         self.ensure_parent(&path)?;
 
         // Check if file exists and is readonly
-        let file_exists = if let Some(node) = self.nodes.get(&path) {
+        let file_exists = if let Some(node) = self.nodes.load().get(&path) {
             if let Node::File { permissions, .. } = node.value() {
                 if permissions.is_readonly() {
                     return Err(VfsError::PermissionDenied(
@@ -58,7 +90,7 @@ pub(super) fn write_impl(&self, path: &Path, data: &[u8]) -> VfsResult<()> {
         // If creating a new file, check parent directory write permissions
         if !file_exists {
             if let Some(parent_path) = self.parent_path(&path) {
-                if let Some(parent_node) = self.nodes.get(&parent_path) {
+                if let Some(parent_node) = self.nodes.load().get(&parent_path) {
                     if let Node::Directory { permissions, .. } = parent_node.value() {
                         if permissions.mode & 0o200 == 0 {
                             return Err(VfsError::PermissionDenied(
@@ -71,7 +103,7 @@ pub(super) fn write_impl(&self, path: &Path, data: &[u8]) -> VfsResult<()> {
             }
         }
 
-        let space_needed = if let Some(node) = self.nodes.get(&path) {
+        let space_needed = if let Some(node) = self.nodes.load().get(&path) {
             if let Node::File { data: old_data, .. } = node.value() {
                 let old_len = old_data.lock().len();
                 if data.len() > old_len {
@@ -90,7 +122,7 @@ pub(super) fn write_impl(&self, path: &Path, data: &[u8]) -> VfsResult<()> {
 
         let now = SystemTime::now();
 
-        let old_size = if let Some(node) = self.nodes.get(&path) {
+        let old_size = if let Some(node) = self.nodes.load().get(&path) {
             if let Node::File { data: old_data, .. } = node.value() {
                 old_data.lock().len()
             } else {
@@ -101,7 +133,7 @@ pub(super) fn write_impl(&self, path: &Path, data: &[u8]) -> VfsResult<()> {
         };
 
         // Add child to parent if new file
-        if !self.nodes.contains_key(&path) {
+        if !self.nodes.load().contains_key(&path) {
             if let Some(parent) = self.parent_path(&path) {
                 let file_name = self.file_name(&path)?;
                 let result = self.add_child(&parent, &file_name, &path);
@@ -120,7 +152,7 @@ pub(super) fn write_impl(&self, path: &Path, data: &[u8]) -> VfsResult<()> {
         use crate::core::memory::CowMemory;
         use std::sync::Arc;
 
-        self.nodes.insert(
+        self.nodes.load().insert(
             path,
             Node::File {
                 data: Arc::new(parking_lot::Mutex::new(
@@ -141,11 +173,54 @@ pub(super) fn write_impl(&self, path: &Path, data: &[u8]) -> VfsResult<()> {
         Ok(())
     }
 
-    pub(super) fn append_impl(&self, path: &Path, data: &[u8]) -> VfsResult<()> {
+    /// Point `to` at a CoW clone of `from`'s buffer
+    ///
+    /// The copy shares pages with the source until either file is written.
+    /// Space is charged for the full logical size, as for a byte copy.
+    pub(super) fn copy_reflink_impl(&self, from: &Path, to: &Path) -> VfsResult<()> {
+        use crate::core::memory::CowMemory;
+        use std::sync::Arc;
+
+        let from = self.normalize(from);
+        let shared: CowMemory = match self.nodes.load().get(&from).map(|n| n.clone()) {
+            Some(Node::File { data, .. }) => data.lock().clone_cow(),
+            Some(Node::Directory { .. }) => {
+                return Err(VfsError::IsADirectory(format!("{}", from.display()).into()))
+            }
+            None => return Err(VfsError::NotFound(format!("{}", from.display()).into())),
+        };
+        let len = shared.len();
+
+        // Create or truncate the destination with the usual permission checks
+        self.check_and_reserve_space(len)?;
+        if let Err(e) = self.write_impl(to, &[]) {
+            self.release_space(len);
+            return Err(e);
+        }
+
+        let to = self.normalize(to);
+        match self.nodes.load().get_mut(&to) {
+            Some(mut entry) => {
+                if let Node::File { data, modified, .. } = entry.value_mut() {
+                    *data = Arc::new(parking_lot::Mutex::new(shared));
+                    *modified = SystemTime::now();
+                }
+                Ok(())
+            }
+            None => {
+                // Deleted between the truncate and the swap
+                self.release_space(len);
+                Err(VfsError::NotFound(format!("{}", to.display()).into()))
+            }
+        }
+    }
+
+    /// Extend the file under its entry lock, or create it if missing
+    pub(super) fn append_impl(&self, path: &Path, data: &[u8]) -> VfsResult<u64> {
         let path = self.normalize(path);
 
         // Check if file exists and is readonly
-        if let Some(node) = self.nodes.get(&path) {
+        if let Some(node) = self.nodes.load().get(&path) {
             if let Node::File { permissions, .. } = node.value() {
                 if permissions.is_readonly() {
                     return Err(VfsError::PermissionDenied(
@@ -158,21 +233,20 @@ pub(super) fn append_impl(&self, path: &Path, data: &[u8]) -> VfsResult<()> {
         // Reserve space atomically
         self.check_and_reserve_space(data.len())?;
 
-        match self.nodes.get(&path) {
-            Some(entry) => match entry.value() {
-                Node::File { data: cow_data, .. } => {
-                    let mut cow_guard = cow_data.lock();
-                    cow_guard.write(|buf| {
+        // Single write guard: re-locking the entry while holding it deadlocks
+        match self.nodes.load().get_mut(&path) {
+            Some(mut entry) => match entry.value_mut() {
+                Node::File {
+                    data: cow_data,
+                    modified,
+                    ..
+                } => {
+                    let len = cow_data.lock().write(|buf| {
                         buf.extend_from_slice(data);
+                        buf.len() as u64
                     });
-                    drop(cow_guard);
-
-                    if let Some(mut entry_mut) = self.nodes.get_mut(&path) {
-                        if let Node::File { modified, .. } = entry_mut.value_mut() {
-                            *modified = SystemTime::now();
-                        }
-                    }
-                    Ok(())
+                    *modified = SystemTime::now();
+                    Ok(len)
                 }
                 Node::Directory { .. } => {
                     self.release_space(data.len());
@@ -181,7 +255,15 @@ pub(super) fn append_impl(&self, path: &Path, data: &[u8]) -> VfsResult<()> {
             },
             None => {
                 self.release_space(data.len());
-                self.write_impl(&path, data)
+
+                // Another appender may have created the file since the lookup
+                let create_guard = self.append_create.lock();
+                if self.nodes.load().contains_key(&path) {
+                    drop(create_guard);
+                    return self.append_impl(&path, data);
+                }
+                self.write_impl(&path, data)?;
+                Ok(data.len() as u64)
             }
         }
     }
@@ -195,7 +277,7 @@ pub(super) fn delete_impl(&self, path: &Path) -> VfsResult<()> {
 
         // Check parent directory write permissions
         if let Some(parent_path) = self.parent_path(&path) {
-            if let Some(parent_node) = self.nodes.get(&parent_path) {
+            if let Some(parent_node) = self.nodes.load().get(&parent_path) {
                 if let Node::Directory { permissions, .. } = parent_node.value() {
                     if permissions.mode & 0o200 == 0 {
                         return Err(VfsError::PermissionDenied(
@@ -207,10 +289,10 @@ pub(super) fn delete_impl(&self, path: &Path) -> VfsResult<()> {
             }
         }
 
-        match self.nodes.get(&path).map(|n| n.clone()) {
+        match self.nodes.load().get(&path).map(|n| n.clone()) {
             Some(Node::File { data, .. }) => {
                 let size = data.lock().len();
-                self.nodes.remove(&path);
+                self.nodes.load().remove(&path);
 
                 if let Some(parent) = self.parent_path(&path) {
                     let file_name = self.file_name(&path)?;
@@ -233,7 +315,7 @@ pub(super) fn truncate_impl(&self, path: &Path, size: u64) -> VfsResult<()> {
         let path = self.normalize(path);
         let new_size = size as usize;
 
-        let old_size = match self.nodes.get(&path).map(|n| n.clone()) {
+        let old_size = match self.nodes.load().get(&path).map(|n| n.clone()) {
             Some(Node::Directory { .. }) => {
                 return Err(VfsError::IsADirectory(path.display().to_string().into()))
             }
@@ -255,19 +337,12 @@ pub(super) fn truncate_impl(&self, path: &Path, size: u64) -> VfsResult<()> {
             self.check_and_reserve_space(additional)?;
         }
 
-        if let Some(entry) = self.nodes.get(&path) {
-            if let Node::File { data, .. } = entry.value() {
-                let mut cow_guard = data.lock();
-                cow_guard.write(|buf| {
+        if let Some(mut entry) = self.nodes.load().get_mut(&path) {
+            if let Node::File { data, modified, .. } = entry.value_mut() {
+                data.lock().write(|buf| {
                     buf.resize(new_size, 0);
                 });
-                drop(cow_guard);
-
-                if let Some(mut entry_mut) = self.nodes.get_mut(&path) {
-                    if let Node::File { modified, .. } = entry_mut.value_mut() {
-                        *modified = SystemTime::now();
-                    }
-                }
+                *modified = SystemTime::now();
 
                 self.update_size_atomic(old_size, new_size);
                 Ok(())
@@ -284,4 +359,89 @@ pub(super) fn truncate_impl(&self, path: &Path, size: u64) -> VfsResult<()> {
             Err(VfsError::NotFound(path.display().to_string().into()))
         }
     }
+
+    /// Move a node, optionally refusing to replace an existing destination
+    ///
+    /// With `noreplace` the destination is claimed through its map entry, so
+    /// racing renames onto one path see exactly one winner. The source is
+    /// taken first and put back if the claim fails.
+    pub(super) fn rename_impl(&self, from: &Path, to: &Path, noreplace: bool) -> VfsResult<()> {
+        use dashmap::mapref::entry::Entry;
+
+        let from = self.normalize(from);
+        let to = self.normalize(to);
+        let nodes = self.nodes.load();
+
+        if noreplace && from == to {
+            return Err(if nodes.contains_key(&from) {
+                VfsError::AlreadyExists(to.display().to_string().into())
+            } else {
+                VfsError::NotFound(from.display().to_string().into())
+            });
+        }
+
+        let node = nodes
+            .remove(&from)
+            .ok_or_else(|| VfsError::NotFound(from.display().to_string().into()))?
+            .1;
+
+        if noreplace {
+            let claimed = match nodes.entry(to.clone()) {
+                Entry::Occupied(_) => Err(node),
+                Entry::Vacant(slot) => {
+                    slot.insert(node);
+                    Ok(())
+                }
+            };
+            if let Err(node) = claimed {
+                nodes.insert(from, node);
+                return Err(VfsError::AlreadyExists(to.display().to_string().into()));
+            }
+        } else {
+            nodes.insert(to.clone(), node);
+        }
+
+        // Update parent directories
+        if let Some(from_parent) = self.parent_path(&from) {
+            let from_name = self.file_name(&from)?;
+            self.remove_child(&from_parent, &from_name)?;
+        }
+
+        if let Some(to_parent) = self.parent_path(&to) {
+            let to_name = self.file_name(&to)?;
+            self.add_child(&to_parent, &to_name, &to)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::super::traits::FileSystem;
+    use super::*;
+
+    fn buffer_id(fs: &MemFS, path: &str) -> usize {
+        match fs.nodes.load().get(Path::new(path)).map(|n| n.clone()) {
+            Some(Node::File { data, .. }) => data.lock().buffer_id(),
+            _ => panic!("{} is not a file", path),
+        }
+    }
+
+    #[test]
+    fn test_reflink_shares_storage_until_mutation() {
+        let fs = MemFS::new();
+        fs.write(Path::new("/src.bin"), b"shared payload").unwrap();
+
+        assert!(fs
+            .copy_reflink(Path::new("/src.bin"), Path::new("/dst.bin"))
+            .unwrap());
+        assert_eq!(buffer_id(&fs, "/src.bin"), buffer_id(&fs, "/dst.bin"));
+        assert_eq!(fs.read(Path::new("/dst.bin")).unwrap(), b"shared payload");
+
+        fs.append(Path::new("/dst.bin"), b"!").unwrap();
+        assert_ne!(buffer_id(&fs, "/src.bin"), buffer_id(&fs, "/dst.bin"));
+        assert_eq!(fs.read(Path::new("/src.bin")).unwrap(), b"shared payload");
+        assert_eq!(fs.read(Path::new("/dst.bin")).unwrap(), b"shared payload!");
+    }
 }
@@ -22,10 +22,18 @@ fn write(&self, path: &Path, data: &[u8]) -> VfsResult<()> {
         self.write_impl(path, data)
     }
 
-    fn append(&self, path: &Path, data: &[u8]) -> VfsResult<()> {
+    fn append(&self, path: &Path, data: &[u8]) -> VfsResult<u64> {
         self.append_impl(path, data)
     }
 
+    fn write_vectored(&self, path: &Path, segments: &[&[u8]]) -> VfsResult<usize> {
+        self.write_vectored_impl(path, segments)
+    }
+
+    fn read_vectored(&self, path: &Path, sizes: &[usize]) -> VfsResult<Vec<Vec<u8>>> {
+        self.read_vectored_impl(path, sizes)
+    }
+
     fn create(&self, path: &Path) -> VfsResult<()> {
         self.create_impl(path)
     }
@@ -36,13 +44,13 @@ fn delete(&self, path: &Path) -> VfsResult<()> {
 
     fn exists(&self, path: &Path) -> bool {
         let path = self.normalize(path);
-        self.nodes.contains_key(&path)
+        self.nodes.load().contains_key(&path)
     }
 
     fn metadata(&self, path: &Path) -> VfsResult<Metadata> {
         let path = self.normalize(path);
 
-        match self.nodes.get(&path).map(|n| n.clone()) {
+        match self.nodes.load().get(&path).map(|n| n.clone()) {
             Some(node) => {
                 let now = SystemTime::now();
                 let size = match &node {
@@ -84,31 +92,17 @@ fn copy(&self, from: &Path, to: &Path) -> VfsResult<()> {
         self.write(to, &data)
     }
 
-    fn rename(&self, from: &Path, to: &Path) -> VfsResult<()> {
-        let from = self.normalize(from);
-        let to = self.normalize(to);
-
-        let node = self
-            .nodes
-            .remove(&from)
-            .ok_or_else(|| VfsError::NotFound(from.display().to_string().into()))?
-            .1;
-
-        // Update parent directories
-        if let Some(from_parent) = self.parent_path(&from) {
-            let from_name = self.file_name(&from)?;
-            self.remove_child(&from_parent, &from_name)?;
-        }
+    fn copy_reflink(&self, from: &Path, to: &Path) -> VfsResult<bool> {
+        self.copy_reflink_impl(from, to)?;
+        Ok(true)
+    }
 
-        if let Some(to_parent) = self.parent_path(&to) {
-            let to_name = self.file_name(&to)?;
-            self.nodes.insert(to.clone(), node);
-            self.add_child(&to_parent, &to_name, &to)?;
-        } else {
-            self.nodes.insert(to, node);
-        }
+    fn rename(&self, from: &Path, to: &Path) -> VfsResult<()> {
+        self.rename_impl(from, to, false)
+    }
 
-        Ok(())
+    fn rename_noreplace(&self, from: &Path, to: &Path) -> VfsResult<()> {
+        self.rename_impl(from, to, true)
     }
 
     fn symlink(&self, _src: &Path, _dst: &Path) -> VfsResult<()> {
@@ -130,7 +124,7 @@ fn truncate(&self, path: &Path, size: u64) -> VfsResult<()> {
     fn set_permissions(&self, path: &Path, perms: Permissions) -> VfsResult<()> {
         let path = self.normalize(path);
 
-        match self.nodes.get_mut(&path) {
+        match self.nodes.load().get_mut(&path) {
             Some(mut entry) => match entry.value_mut() {
                 Node::File { permissions, .. } => {
                     *permissions = perms;
@@ -176,7 +170,7 @@ fn open(&self, path: &Path, flags: OpenFlags, mode: OpenMode) -> VfsResult<Box<d
             use crate::core::memory::CowMemory;
             use std::sync::Arc;
 
-            self.nodes.insert(
+            self.nodes.load().insert(
                 path.clone(),
                 Node::File {
                     data: Arc::new(parking_lot::Mutex::new(CowMemory::new(Vec::new().into()))),
@@ -8,9 +8,11 @@
 mod file_ops;
 mod metadata_ops;
 mod node;
+mod snapshot;
 
 use ahash::RandomState;
 use dashmap::DashMap;
+use parking_lot::Mutex;
 use std::collections::HashMap;
 use std::path::{Path, PathBuf};
 use std::sync::atomic::{AtomicUsize, Ordering};
@@ -18,18 +20,27 @@
 use std::time::SystemTime;
 
 use super::types::*;
+use crate::core::sync::RcuCell;
 use node::Node;
 
+pub use snapshot::MemFsSnapshot;
+
 /// In-memory filesystem implementation
 ///
+/// The node map sits behind an RCU cell so `restore` can swap in a whole
+/// snapshot atomically; clones share the same cell.
+///
 /// # Performance
 /// - Cache-line aligned to prevent false sharing of atomic size counter (high-frequency file operations)
 #[repr(C, align(64))]
 #[derive(Debug, Clone)]
 pub struct MemFS {
-    pub(super) nodes: Arc<DashMap<PathBuf, Node, RandomState>>,
+    pub(super) nodes: RcuCell<DashMap<PathBuf, Node, RandomState>>,
     pub(super) max_size: Option<usize>,
     pub(super) current_size: Arc<AtomicUsize>,
+    /// Serializes appends that create their file, so two first appends
+    /// can't overwrite each other
+    pub(super) append_create: Arc<Mutex<()>>,
 }
 
 impl MemFS {
@@ -48,9 +59,10 @@ pub fn new() -> Self {
         );
 
         Self {
-            nodes: Arc::new(nodes),
+            nodes: RcuCell::new(nodes),
             max_size: None,
             current_size: Arc::new(AtomicUsize::new(0).into()),
+            append_create: Arc::new(Mutex::new(())),
         }
     }
 
@@ -158,7 +170,8 @@ pub(super) fn file_name(&self, path: &Path) -> VfsResult<String> {
     pub(super) fn ensure_parent(&self, path: &Path) -> VfsResult<()> {
         if let Some(parent) = self.parent_path(path) {
             // Check and validate parent in one atomic operation to avoid TOCTOU
-            let node = self.nodes.get(&parent).ok_or_else(|| {
+            let nodes = self.nodes.load();
+            let node = nodes.get(&parent).ok_or_else(|| {
                 VfsError::NotFound(
                     format!("parent directory not found: {}", parent.display()).into(),
                 )
@@ -178,7 +191,7 @@ pub(super) fn add_child(
         child_name: &str,
         child_path: &PathBuf,
     ) -> VfsResult<()> {
-        if let Some(mut node) = self.nodes.get_mut(parent_path) {
+        if let Some(mut node) = self.nodes.load().get_mut(parent_path) {
             if let Node::Directory { children, .. } = node.value_mut() {
                 children.insert(child_name.to_string(), child_path.clone());
                 Ok(())
@@ -196,7 +209,7 @@ pub(super) fn add_child(
 
     /// Remove child from parent directory
     pub(super) fn remove_child(&self, parent_path: &Path, child_name: &str) -> VfsResult<()> {
-        if let Some(mut node) = self.nodes.get_mut(parent_path) {
+        if let Some(mut node) = self.nodes.load().get_mut(parent_path) {
             if let Node::Directory { children, .. } = node.value_mut() {
                 children.remove(child_name);
                 Ok(())
@@ -0,0 +1,97 @@
+/*!
+ * MemFS Snapshots
+ * Copy-on-write capture and rollback of the whole filesystem tree
+ */
+
+use ahash::RandomState;
+use dashmap::DashMap;
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+
+use super::node::Node;
+use super::MemFS;
+
+/// Immutable point-in-time copy of a MemFS tree
+///
+/// File contents are shared with the live filesystem until either side
+/// writes, so taking a snapshot is O(nodes) rather than O(bytes). A snapshot
+/// can be restored any number of times.
+#[derive(Debug, Clone)]
+pub struct MemFsSnapshot {
+    nodes: Arc<HashMap<PathBuf, Node>>,
+    size: usize,
+}
+
+impl MemFsSnapshot {
+    /// Number of files and directories captured
+    pub fn node_count(&self) -> usize {
+        self.nodes.len()
+    }
+
+    /// Total bytes of file data captured
+    pub fn size(&self) -> usize {
+        self.size
+    }
+}
+
+/// Copy a node, giving file data a new handle onto the shared buffer
+///
+/// The live `Node::File` mutex is what append/truncate write through, so each
+/// side needs its own; `CowMemory` copies the buffer on the first write.
+fn share_node(node: &Node) -> Node {
+    match node {
+        Node::File {
+            data,
+            permissions,
+            modified,
+            created,
+        } => Node::File {
+            data: Arc::new(parking_lot::Mutex::new(data.lock().clone_cow())),
+            permissions: *permissions,
+            modified: *modified,
+            created: *created,
+        },
+        Node::Directory { .. } => node.clone(),
+    }
+}
+
+impl MemFS {
+    /// Capture the current tree for a later `restore`
+    ///
+    /// Writes made while the snapshot is being taken may or may not be
+    /// included, per node.
+    pub fn snapshot(&self) -> MemFsSnapshot {
+        let live = self.nodes.load();
+        let mut nodes = HashMap::with_capacity(live.len());
+        let mut size = 0;
+
+        for entry in live.iter() {
+            let node = share_node(entry.value());
+            if let Node::File { data, .. } = &node {
+                size += data.lock().len();
+            }
+            nodes.insert(entry.key().clone(), node);
+        }
+
+        MemFsSnapshot {
+            nodes: Arc::new(nodes),
+            size,
+        }
+    }
+
+    /// Roll the filesystem back to `snapshot`
+    ///
+    /// The node map is swapped in one step, so readers see either the old
+    /// tree or the restored one. Open handles keep their buffered contents.
+    pub fn restore(&self, snapshot: &MemFsSnapshot) {
+        let nodes = DashMap::with_capacity_and_hasher(snapshot.nodes.len(), RandomState::new());
+        for (path, node) in snapshot.nodes.iter() {
+            nodes.insert(path.clone(), share_node(node));
+        }
+
+        self.nodes.store(nodes);
+        self.current_size.store(snapshot.size, Ordering::SeqCst);
+    }
+}
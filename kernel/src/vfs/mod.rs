@@ -3,23 +3,33 @@
  * Pluggable filesystem abstraction layer with observability
  */
 
+pub mod circuit;
 pub mod init;
 pub mod local;
+pub mod locks;
 pub mod memory;
 pub mod mount;
 pub mod observable;
 pub mod observable_wrapper;
 pub mod paths;
+mod quota;
 pub mod traits;
 pub mod types;
+pub mod watch;
 
 // Re-exports
+pub use circuit::CircuitBreakerFS;
 pub use init::{init_vfs, sync_native_apps};
-pub use local::LocalFS;
+pub use local::{LocalFS, ReadAheadStats};
+pub use locks::{RangeLock, RangeLockTable};
 pub use memory::MemFS;
-pub use mount::{MountManager, MountPoint};
+pub use mount::{MountFlags, MountInfo, MountManager, MountPoint};
 pub use observable::{EventBroadcaster, FileEvent, Observable};
 pub use observable_wrapper::ObservableFS;
 pub use paths::{app, mounts, storage, user};
+pub use quota::MountUsage;
 pub use traits::{FileSystem, OpenFile};
-pub use types::{Entry, FileType, Metadata, OpenFlags, OpenMode, Permissions, VfsError, VfsResult};
+pub use types::{
+    Entry, FileType, Metadata, OpenFlags, OpenMode, Permissions, Readiness, VfsError, VfsResult,
+};
+pub use watch::WatchConfig;
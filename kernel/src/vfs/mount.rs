@@ -6,44 +6,235 @@
 use ahash::RandomState;
 use dashmap::DashMap;
 use parking_lot::RwLock;
+use std::ops::{BitOr, BitOrAssign};
 use std::path::{Path, PathBuf};
 use std::sync::Arc;
 use std::time::Instant;
 
+use super::circuit::CircuitBreakerFS;
+use super::locks::RangeLockTable;
+use super::quota::{file_size, size_delta, tree_size, MountQuota, MountUsage, QuotaFile};
 use super::traits::{FileSystem, OpenFile};
 use super::types::*;
+use crate::core::circuit_breaker::CircuitBreaker;
 use crate::monitoring::Collector;
 
+/// Mount option bitset
+///
+/// Flags apply to every path resolved into the mount, including nested
+/// directories that aren't themselves mount points.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct MountFlags(u8);
+
+impl MountFlags {
+    /// Reject writes, creates, deletes and metadata changes with `VfsError::ReadOnly`
+    pub const READ_ONLY: Self = Self(1 << 0);
+    /// Disallow executing files from this mount
+    pub const NO_EXEC: Self = Self(1 << 1);
+    /// Ignore privilege-elevating permission bits on files from this mount
+    pub const NO_SUID: Self = Self(1 << 2);
+    /// Fail fast with `VfsError::Unavailable` while the backend keeps failing
+    pub const FAIL_FAST: Self = Self(1 << 3);
+
+    /// No flags set
+    #[inline]
+    #[must_use]
+    pub const fn empty() -> Self {
+        Self(0)
+    }
+
+    /// Raw bit representation
+    #[inline]
+    #[must_use]
+    pub const fn bits(&self) -> u8 {
+        self.0
+    }
+
+    /// Check if all flags in `other` are set
+    #[inline]
+    #[must_use]
+    pub const fn contains(&self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+
+    /// Check if no flags are set
+    #[inline]
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.0 == 0
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn is_read_only(&self) -> bool {
+        self.contains(Self::READ_ONLY)
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn is_no_exec(&self) -> bool {
+        self.contains(Self::NO_EXEC)
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn is_no_suid(&self) -> bool {
+        self.contains(Self::NO_SUID)
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn is_fail_fast(&self) -> bool {
+        self.contains(Self::FAIL_FAST)
+    }
+
+    /// Option names as listed in `/proc/mounts` (`ro`/`rw`, `noexec`, `nosuid`)
+    #[must_use]
+    pub fn options(&self) -> Vec<&'static str> {
+        let mut options = vec![if self.is_read_only() { "ro" } else { "rw" }];
+        if self.is_no_exec() {
+            options.push("noexec");
+        }
+        if self.is_no_suid() {
+            options.push("nosuid");
+        }
+        if self.is_fail_fast() {
+            options.push("failfast");
+        }
+        options
+    }
+}
+
+impl BitOr for MountFlags {
+    type Output = Self;
+
+    fn bitor(self, rhs: Self) -> Self {
+        Self(self.0 | rhs.0)
+    }
+}
+
+impl BitOrAssign for MountFlags {
+    fn bitor_assign(&mut self, rhs: Self) {
+        self.0 |= rhs.0;
+    }
+}
+
+/// Clear setuid/setgid bits (enforces `MountFlags::NO_SUID`)
+#[inline]
+fn strip_suid(perms: Permissions) -> Permissions {
+    Permissions::new(perms.mode & !0o6000)
+}
+
 /// Mount point configuration
 #[derive(Debug, Clone)]
 pub struct MountPoint {
     pub path: PathBuf,
     pub name: String,
+    pub flags: MountFlags,
+    /// Byte limit for files under the mount, None for unlimited
+    pub quota: Option<u64>,
+    /// Mirrors `MountFlags::READ_ONLY` for callers predating mount flags
+    #[deprecated(note = "use `flags` or `is_readonly()`")]
     pub readonly: bool,
 }
 
 impl MountPoint {
     pub fn new<P: Into<PathBuf>, S: Into<String>>(path: P, name: S) -> Self {
-        Self {
-            path: path.into(),
-            name: name.into(),
-            readonly: false,
-        }
+        Self::with_flags(path, name, MountFlags::empty())
     }
 
     pub fn readonly<P: Into<PathBuf>, S: Into<String>>(path: P, name: S) -> Self {
+        Self::with_flags(path, name, MountFlags::READ_ONLY)
+    }
+
+    pub fn with_flags<P: Into<PathBuf>, S: Into<String>>(
+        path: P,
+        name: S,
+        flags: MountFlags,
+    ) -> Self {
+        #[allow(deprecated)]
         Self {
             path: path.into(),
             name: name.into(),
-            readonly: true,
+            flags,
+            quota: None,
+            readonly: flags.is_read_only(),
+        }
+    }
+
+    /// Limit the mount to `bytes` of file data
+    pub fn with_quota(mut self, bytes: u64) -> Self {
+        self.quota = Some(bytes);
+        self
+    }
+
+    /// Check if the mount rejects writes
+    #[allow(deprecated)]
+    pub fn is_readonly(&self) -> bool {
+        self.flags.is_read_only() || self.readonly
+    }
+
+    /// Mount flags, including a read-only bit set through the legacy field
+    fn effective_flags(&self) -> MountFlags {
+        if self.is_readonly() {
+            self.flags | MountFlags::READ_ONLY
+        } else {
+            self.flags
         }
     }
 }
 
+/// Live mount table entry (the `/proc/mounts` view of a mount)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MountInfo {
+    pub path: PathBuf,
+    /// Backend type name reported by the mounted filesystem
+    pub fs_type: String,
+    pub flags: MountFlags,
+    /// Byte limit, None for unlimited
+    pub quota: Option<u64>,
+    /// Bytes used by files under the mount, tracked only for quota mounts
+    pub used: Option<u64>,
+}
+
 /// Internal mount entry with filesystem and options
 struct MountEntry {
     fs: Arc<dyn FileSystem>,
-    readonly: bool,
+    flags: MountFlags,
+    quota: Option<Arc<MountQuota>>,
+}
+
+impl MountEntry {
+    fn mount_point(&self, path: &Path) -> MountPoint {
+        MountPoint {
+            quota: self.quota.as_ref().map(|quota| quota.limit()),
+            ..MountPoint::with_flags(path, self.fs.name(), self.flags)
+        }
+    }
+}
+
+/// A path resolved to the mount that serves it
+struct Resolved {
+    fs: Arc<dyn FileSystem>,
+    rel_path: PathBuf,
+    flags: MountFlags,
+    quota: Option<Arc<MountQuota>>,
+}
+
+impl Resolved {
+    /// Run a write that changes usage by `delta()`, enforcing the mount quota
+    ///
+    /// `delta` is only evaluated for quota-limited mounts.
+    fn charged<T>(
+        &self,
+        delta: impl FnOnce(&dyn FileSystem) -> i64,
+        op: impl FnOnce() -> VfsResult<T>,
+    ) -> VfsResult<T> {
+        match &self.quota {
+            Some(quota) => quota.apply(self.fs.as_ref(), delta(self.fs.as_ref()), op),
+            None => op(),
+        }
+    }
 }
 
 /// Mount manager for filesystem routing
@@ -52,6 +243,8 @@ pub struct MountManager {
     mount_order: Arc<RwLock<Vec<PathBuf>>>, // Longest paths first for proper resolution
     collector: Option<Arc<Collector>>,
     slow_operation_threshold_ms: u64, // Threshold for slow operation warnings (default: 100ms)
+    locks: RangeLockTable,            // Advisory range locks, keyed by full path across all mounts
+    breaker: CircuitBreaker,          // One circuit per FAIL_FAST mount path
 }
 
 impl MountManager {
@@ -62,20 +255,42 @@ pub fn new() -> Self {
             mount_order: Arc::new(RwLock::new(Vec::new().into())),
             collector: None,
             slow_operation_threshold_ms: 100, // Default 100ms threshold
+            locks: RangeLockTable::new(),
+            breaker: CircuitBreaker::default(),
         }
     }
 
     /// Add observability collector
     pub fn with_collector(mut self, collector: Arc<Collector>) -> Self {
-        self.collector = Some(collector);
+        self.set_collector(collector);
         self
     }
 
     /// Set collector after construction
     pub fn set_collector(&mut self, collector: Arc<Collector>) {
+        self.breaker.set_collector(Arc::clone(&collector));
         self.collector = Some(collector);
     }
 
+    /// Use `breaker` for `FAIL_FAST` mounts added from now on
+    pub fn with_circuit_breaker(mut self, breaker: CircuitBreaker) -> Self {
+        if let Some(collector) = &self.collector {
+            breaker.set_collector(Arc::clone(collector));
+        }
+        self.breaker = breaker;
+        self
+    }
+
+    /// Per-mount circuit breaker, keyed by mount path
+    pub fn circuit_breaker(&self) -> &CircuitBreaker {
+        &self.breaker
+    }
+
+    /// Advisory range lock table shared by all mounts
+    pub fn lock_table(&self) -> &RangeLockTable {
+        &self.locks
+    }
+
     /// Set slow operation threshold in milliseconds
     pub fn set_slow_threshold(&mut self, threshold_ms: u64) {
         self.slow_operation_threshold_ms = threshold_ms;
@@ -83,7 +298,7 @@ pub fn set_slow_threshold(&mut self, threshold_ms: u64) {
 
     /// Mount a filesystem at specified path
     pub fn mount<P: Into<PathBuf>>(&self, mount_path: P, fs: Arc<dyn FileSystem>) -> VfsResult<()> {
-        self.mount_with_options(mount_path, fs, false)
+        self.mount_with_flags(mount_path, fs, MountFlags::empty())
     }
 
     /// Mount a filesystem at specified path with readonly option
@@ -93,7 +308,38 @@ pub fn mount_with_options<P: Into<PathBuf>>(
         fs: Arc<dyn FileSystem>,
         readonly: bool,
     ) -> VfsResult<()> {
-        let mount_path = self.normalize_path(&mount_path.into());
+        let flags = if readonly {
+            MountFlags::READ_ONLY
+        } else {
+            MountFlags::empty()
+        };
+        self.mount_with_flags(mount_path, fs, flags)
+    }
+
+    /// Mount a filesystem at specified path with mount flags
+    pub fn mount_with_flags<P: Into<PathBuf>>(
+        &self,
+        mount_path: P,
+        fs: Arc<dyn FileSystem>,
+        flags: MountFlags,
+    ) -> VfsResult<()> {
+        self.insert_mount(mount_path.into(), fs, flags, None)
+    }
+
+    /// Mount a filesystem using a MountPoint configuration
+    pub fn mount_from_config(&self, config: &MountPoint, fs: Arc<dyn FileSystem>) -> VfsResult<()> {
+        let quota = config.quota.map(|limit| Arc::new(MountQuota::new(limit)));
+        self.insert_mount(config.path.clone(), fs, config.effective_flags(), quota)
+    }
+
+    fn insert_mount(
+        &self,
+        mount_path: PathBuf,
+        fs: Arc<dyn FileSystem>,
+        flags: MountFlags,
+        quota: Option<Arc<MountQuota>>,
+    ) -> VfsResult<()> {
+        let mount_path = self.normalize_path(&mount_path);
 
         if self.mounts.contains_key(&mount_path) {
             return Err(VfsError::AlreadyExists(
@@ -101,8 +347,21 @@ pub fn mount_with_options<P: Into<PathBuf>>(
             ));
         }
 
+        // Opt-in: a shared circuit lets failures seen by one process cut the
+        // mount off for all of them
+        let fs: Arc<dyn FileSystem> = if flags.is_fail_fast() {
+            let resource = mount_path.to_string_lossy();
+            self.breaker.reset(&resource);
+            Arc::new(CircuitBreakerFS::new(
+                fs,
+                self.breaker.clone(),
+                resource.into_owned(),
+            ))
+        } else {
+            fs
+        };
         self.mounts
-            .insert(mount_path.clone(), MountEntry { fs, readonly });
+            .insert(mount_path.clone(), MountEntry { fs, flags, quota });
 
         // Update mount order (longest paths first)
         let mut order = self.mount_order.write();
@@ -112,11 +371,6 @@ pub fn mount_with_options<P: Into<PathBuf>>(
         Ok(())
     }
 
-    /// Mount a filesystem using a MountPoint configuration
-    pub fn mount_from_config(&self, config: &MountPoint, fs: Arc<dyn FileSystem>) -> VfsResult<()> {
-        self.mount_with_options(&config.path, fs, config.readonly)
-    }
-
     /// Unmount filesystem at specified path
     pub fn unmount<P: AsRef<Path>>(&self, mount_path: P) -> VfsResult<()> {
         let mount_path = self.normalize_path(mount_path.as_ref());
@@ -135,6 +389,28 @@ pub fn unmount<P: AsRef<Path>>(&self, mount_path: P) -> VfsResult<()> {
 
     /// Resolve path to (filesystem, relative_path, readonly)
     fn resolve(&self, path: &Path) -> VfsResult<(Arc<dyn FileSystem>, PathBuf, bool)> {
+        self.resolve_with_flags(path)
+            .map(|(fs, rel_path, flags)| (fs, rel_path, flags.is_read_only()))
+    }
+
+    /// Resolve path to (filesystem, relative_path, mount flags)
+    fn resolve_with_flags(
+        &self,
+        path: &Path,
+    ) -> VfsResult<(Arc<dyn FileSystem>, PathBuf, MountFlags)> {
+        self.resolve_mount(path)
+            .map(|resolved| (resolved.fs, resolved.rel_path, resolved.flags))
+    }
+
+    /// Resolve a path that is about to be modified, rejecting readonly mounts
+    fn resolve_writable(&self, path: &Path) -> VfsResult<Resolved> {
+        let resolved = self.resolve_mount(path)?;
+        self.check_readonly(resolved.flags.is_read_only())?;
+        Ok(resolved)
+    }
+
+    /// Resolve path to its mount, including the mount's quota
+    fn resolve_mount(&self, path: &Path) -> VfsResult<Resolved> {
         use crate::core::memory::arena::with_arena;
 
         with_arena(|_arena| {
@@ -154,7 +430,8 @@ fn resolve(&self, path: &Path) -> VfsResult<(Arc<dyn FileSystem>, PathBuf, bool)
                     })?;
 
                     let fs = entry.fs.clone();
-                    let readonly = entry.readonly;
+                    let flags = entry.flags;
+                    let quota = entry.quota.clone();
                     let rel_path = if path == *mount_path {
                         PathBuf::from("/")
                     } else {
@@ -162,7 +439,12 @@ fn resolve(&self, path: &Path) -> VfsResult<(Arc<dyn FileSystem>, PathBuf, bool)
                             .map(|p| PathBuf::from("/").join(p))
                             .unwrap_or_else(|_| PathBuf::from("/"))
                     };
-                    return Ok((fs, rel_path, readonly));
+                    return Ok(Resolved {
+                        fs,
+                        rel_path,
+                        flags,
+                        quota,
+                    });
                 }
             }
 
@@ -181,6 +463,98 @@ fn check_readonly(&self, readonly: bool) -> VfsResult<()> {
         }
     }
 
+    /// Get the mount point governing a path (longest matching prefix)
+    pub fn mount_point<P: AsRef<Path>>(&self, path: P) -> Option<MountPoint> {
+        let path = self.normalize_path(path.as_ref());
+        let order = self.mount_order.read();
+
+        order
+            .iter()
+            .find(|mount_path| path.starts_with(mount_path))
+            .and_then(|mount_path| {
+                self.mounts
+                    .get(mount_path)
+                    .map(|entry| entry.mount_point(mount_path))
+            })
+    }
+
+    /// List all mount points with their flags
+    pub fn mount_points(&self) -> Vec<MountPoint> {
+        self.mounts
+            .iter()
+            .map(|entry| entry.value().mount_point(entry.key()))
+            .collect()
+    }
+
+    /// Bytes used by the mount governing a path, against its quota
+    ///
+    /// Unlimited mounts are measured by walking their tree.
+    pub fn usage<P: AsRef<Path>>(&self, path: P) -> VfsResult<MountUsage> {
+        let resolved = self.resolve_mount(path.as_ref())?;
+        Ok(Self::measure(
+            resolved.fs.as_ref(),
+            resolved.quota.as_deref(),
+        ))
+    }
+
+    fn measure(fs: &dyn FileSystem, quota: Option<&MountQuota>) -> MountUsage {
+        match quota {
+            Some(quota) => MountUsage {
+                used: quota.used(fs),
+                quota: Some(quota.limit()),
+            },
+            None => MountUsage {
+                used: tree_size(fs, Path::new("/")),
+                quota: None,
+            },
+        }
+    }
+
+    /// Snapshot of the live mount table, ordered by path
+    ///
+    /// Usage comes from quota counters, so listing never walks an unlimited
+    /// mount; use [`Self::usage`] to measure one explicitly. Entries are
+    /// collected before counters are read, since a quota's first read scans
+    /// its mount and shouldn't hold up concurrent mounts and unmounts.
+    pub fn mount_table(&self) -> Vec<MountInfo> {
+        let mut entries: Vec<_> = self
+            .mounts
+            .iter()
+            .map(|entry| {
+                let mount = entry.value();
+                (
+                    entry.key().clone(),
+                    Arc::clone(&mount.fs),
+                    mount.flags,
+                    mount.quota.clone(),
+                )
+            })
+            .collect();
+        entries.sort_unstable_by(|a, b| a.0.cmp(&b.0));
+
+        entries
+            .into_iter()
+            .map(|(path, fs, flags, quota)| MountInfo {
+                path,
+                fs_type: fs.name().to_string(),
+                flags,
+                quota: quota.as_ref().map(|quota| quota.limit()),
+                used: quota.as_ref().map(|quota| quota.used(fs.as_ref())),
+            })
+            .collect()
+    }
+
+    /// Check whether files under a path may be executed
+    pub fn check_exec<P: AsRef<Path>>(&self, path: P) -> VfsResult<()> {
+        let (_, _, flags) = self.resolve_with_flags(path.as_ref())?;
+        if flags.is_no_exec() {
+            return Err(VfsError::PermissionDenied(
+                format!("mount is noexec: {}", path.as_ref().display()).into(),
+            ));
+        }
+        Ok(())
+    }
+
     /// Normalize path (make absolute)
     fn normalize_path(&self, path: &Path) -> PathBuf {
         if path.is_absolute() {
@@ -223,6 +597,8 @@ fn clone(&self) -> Self {
             mount_order: Arc::clone(&self.mount_order),
             collector: self.collector.as_ref().map(Arc::clone),
             slow_operation_threshold_ms: self.slow_operation_threshold_ms,
+            locks: self.locks.clone(),
+            breaker: self.breaker.clone(),
         }
     }
 }
@@ -267,17 +643,39 @@ fn read(&self, path: &Path) -> VfsResult<Vec<u8>> {
 
     fn write(&self, path: &Path, data: &[u8]) -> VfsResult<()> {
         self.track_operation("write", || {
-            let (fs, rel_path, readonly) = self.resolve(path)?;
-            self.check_readonly(readonly)?;
-            fs.write(&rel_path, data)
+            let m = self.resolve_writable(path)?;
+            m.charged(
+                |fs| size_delta(data.len() as u64, file_size(fs, &m.rel_path)),
+                || m.fs.write(&m.rel_path, data),
+            )
         })
     }
 
-    fn append(&self, path: &Path, data: &[u8]) -> VfsResult<()> {
+    fn write_vectored(&self, path: &Path, segments: &[&[u8]]) -> VfsResult<usize> {
+        self.track_operation("write_vectored", || {
+            let m = self.resolve_writable(path)?;
+            let total: usize = segments.iter().map(|segment| segment.len()).sum();
+            m.charged(
+                |fs| size_delta(total as u64, file_size(fs, &m.rel_path)),
+                || m.fs.write_vectored(&m.rel_path, segments),
+            )
+        })
+    }
+
+    fn read_vectored(&self, path: &Path, sizes: &[usize]) -> VfsResult<Vec<Vec<u8>>> {
+        self.track_operation("read_vectored", || {
+            let (fs, rel_path, _) = self.resolve(path)?;
+            fs.read_vectored(&rel_path, sizes)
+        })
+    }
+
+    fn append(&self, path: &Path, data: &[u8]) -> VfsResult<u64> {
         self.track_operation("append", || {
-            let (fs, rel_path, readonly) = self.resolve(path)?;
-            self.check_readonly(readonly)?;
-            fs.append(&rel_path, data)
+            let m = self.resolve_writable(path)?;
+            m.charged(
+                |_| size_delta(data.len() as u64, 0),
+                || m.fs.append(&m.rel_path, data),
+            )
         })
     }
 
@@ -289,9 +687,11 @@ fn create(&self, path: &Path) -> VfsResult<()> {
 
     fn delete(&self, path: &Path) -> VfsResult<()> {
         self.track_operation("delete", || {
-            let (fs, rel_path, readonly) = self.resolve(path)?;
-            self.check_readonly(readonly)?;
-            fs.delete(&rel_path)
+            let m = self.resolve_writable(path)?;
+            m.charged(
+                |fs| size_delta(0, file_size(fs, &m.rel_path)),
+                || m.fs.delete(&m.rel_path),
+            )
         })
     }
 
@@ -302,8 +702,12 @@ fn exists(&self, path: &Path) -> bool {
     }
 
     fn metadata(&self, path: &Path) -> VfsResult<Metadata> {
-        let (fs, rel_path, _) = self.resolve(path)?;
-        fs.metadata(&rel_path)
+        let (fs, rel_path, flags) = self.resolve_with_flags(path)?;
+        let mut metadata = fs.metadata(&rel_path)?;
+        if flags.is_no_suid() {
+            metadata.permissions = strip_suid(metadata.permissions);
+        }
+        Ok(metadata)
     }
 
     fn list_dir(&self, path: &Path) -> VfsResult<Vec<Entry>> {
@@ -326,44 +730,121 @@ fn remove_dir(&self, path: &Path) -> VfsResult<()> {
     }
 
     fn remove_dir_all(&self, path: &Path) -> VfsResult<()> {
-        let (fs, rel_path, readonly) = self.resolve(path)?;
-        self.check_readonly(readonly)?;
-        fs.remove_dir_all(&rel_path)
+        let m = self.resolve_writable(path)?;
+        m.charged(
+            |fs| size_delta(0, tree_size(fs, &m.rel_path)),
+            || m.fs.remove_dir_all(&m.rel_path),
+        )
     }
 
     fn copy(&self, from: &Path, to: &Path) -> VfsResult<()> {
         self.track_operation("copy", || {
             let (from_fs, from_rel, _) = self.resolve(from)?;
-            let (to_fs, to_rel, to_readonly) = self.resolve(to)?;
-            self.check_readonly(to_readonly)?;
-
-            // Same filesystem - use native copy
-            if Arc::ptr_eq(&from_fs, &to_fs) {
-                from_fs.copy(&from_rel, &to_rel)
-            } else {
-                // Cross-filesystem - read and write
-                let data = from_fs.read(&from_rel)?;
-                to_fs.write(&to_rel, &data)
-            }
+            let to = self.resolve_writable(to)?;
+            let size = file_size(from_fs.as_ref(), &from_rel);
+            to.charged(
+                |fs| size_delta(size, file_size(fs, &to.rel_path)),
+                || {
+                    // Same filesystem - use native copy
+                    if Arc::ptr_eq(&from_fs, &to.fs) {
+                        from_fs.copy(&from_rel, &to.rel_path)
+                    } else {
+                        // Cross-filesystem - read and write
+                        let data = from_fs.read(&from_rel)?;
+                        to.fs.write(&to.rel_path, &data)
+                    }
+                },
+            )
+        })
+    }
+
+    fn copy_reflink(&self, from: &Path, to: &Path) -> VfsResult<bool> {
+        self.track_operation("copy_reflink", || {
+            let (from_fs, from_rel, _) = self.resolve(from)?;
+            let to = self.resolve_writable(to)?;
+            let size = file_size(from_fs.as_ref(), &from_rel);
+            to.charged(
+                |fs| size_delta(size, file_size(fs, &to.rel_path)),
+                || {
+                    // Storage can only be shared within one filesystem
+                    if Arc::ptr_eq(&from_fs, &to.fs) {
+                        from_fs.copy_reflink(&from_rel, &to.rel_path)
+                    } else {
+                        let data = from_fs.read(&from_rel)?;
+                        to.fs.write(&to.rel_path, &data)?;
+                        Ok(false)
+                    }
+                },
+            )
         })
     }
 
     fn rename(&self, from: &Path, to: &Path) -> VfsResult<()> {
-        let (from_fs, from_rel, from_readonly) = self.resolve(from)?;
-        let (to_fs, to_rel, to_readonly) = self.resolve(to)?;
-        self.check_readonly(from_readonly)?;
-        self.check_readonly(to_readonly)?;
-
-        // Same filesystem - use native rename
-        if Arc::ptr_eq(&from_fs, &to_fs) {
-            from_fs.rename(&from_rel, &to_rel)
+        let from = self.resolve_writable(from)?;
+        let to = self.resolve_writable(to)?;
+        let same_quota = match (&from.quota, &to.quota) {
+            (Some(a), Some(b)) => Arc::ptr_eq(a, b),
+            (None, None) => true,
+            _ => false,
+        };
+
+        // The moved bytes only count against the destination if it's
+        // governed by a different quota; overwriting frees the old target
+        let size = if same_quota {
+            0
         } else {
-            // Cross-filesystem - copy and delete
-            let data = from_fs.read(&from_rel)?;
-            to_fs.write(&to_rel, &data)?;
-            from_fs.delete(&from_rel)?;
-            Ok(())
+            file_size(from.fs.as_ref(), &from.rel_path)
+        };
+        to.charged(
+            |fs| size_delta(size, file_size(fs, &to.rel_path)),
+            || {
+                // Same filesystem - use native rename
+                if Arc::ptr_eq(&from.fs, &to.fs) {
+                    from.fs.rename(&from.rel_path, &to.rel_path)
+                } else {
+                    // Cross-filesystem - copy and delete
+                    let data = from.fs.read(&from.rel_path)?;
+                    to.fs.write(&to.rel_path, &data)?;
+                    from.fs.delete(&from.rel_path)?;
+                    Ok(())
+                }
+            },
+        )?;
+
+        if let (false, Some(quota)) = (same_quota, &from.quota) {
+            quota.charge(from.fs.as_ref(), size_delta(0, size))?;
         }
+        Ok(())
+    }
+
+    fn rename_noreplace(&self, from: &Path, to: &Path) -> VfsResult<()> {
+        let from = self.resolve_writable(from)?;
+        let to = self.resolve_writable(to)?;
+
+        // Copy-and-delete can't claim the destination atomically
+        if !Arc::ptr_eq(&from.fs, &to.fs) {
+            return Err(VfsError::CrossDevice);
+        }
+
+        let same_quota = match (&from.quota, &to.quota) {
+            (Some(a), Some(b)) => Arc::ptr_eq(a, b),
+            (None, None) => true,
+            _ => false,
+        };
+        let size = if same_quota {
+            0
+        } else {
+            file_size(from.fs.as_ref(), &from.rel_path)
+        };
+        to.charged(
+            |_| size_delta(size, 0),
+            || from.fs.rename_noreplace(&from.rel_path, &to.rel_path),
+        )?;
+
+        if let (false, Some(quota)) = (same_quota, &from.quota) {
+            quota.charge(from.fs.as_ref(), size_delta(0, size))?;
+        }
+        Ok(())
     }
 
     fn symlink(&self, src: &Path, dst: &Path) -> VfsResult<()> {
@@ -378,24 +859,57 @@ fn read_link(&self, path: &Path) -> VfsResult<PathBuf> {
     }
 
     fn truncate(&self, path: &Path, size: u64) -> VfsResult<()> {
-        let (fs, rel_path, readonly) = self.resolve(path)?;
-        self.check_readonly(readonly)?;
-        fs.truncate(&rel_path, size)
+        let m = self.resolve_writable(path)?;
+        m.charged(
+            |fs| size_delta(size, file_size(fs, &m.rel_path)),
+            || m.fs.truncate(&m.rel_path, size),
+        )
     }
 
     fn set_permissions(&self, path: &Path, perms: Permissions) -> VfsResult<()> {
-        let (fs, rel_path, readonly) = self.resolve(path)?;
-        self.check_readonly(readonly)?;
+        let (fs, rel_path, flags) = self.resolve_with_flags(path)?;
+        self.check_readonly(flags.is_read_only())?;
+        let perms = if flags.is_no_suid() {
+            strip_suid(perms)
+        } else {
+            perms
+        };
         fs.set_permissions(&rel_path, perms)
     }
 
     fn open(&self, path: &Path, flags: OpenFlags, mode: OpenMode) -> VfsResult<Box<dyn OpenFile>> {
-        let (fs, rel_path, readonly) = self.resolve(path)?;
         // Check readonly only if opening for write
-        if flags.write || flags.append || flags.truncate || flags.create || flags.create_new {
-            self.check_readonly(readonly)?;
+        if !(flags.write || flags.append || flags.truncate || flags.create || flags.create_new) {
+            let (fs, rel_path, _) = self.resolve(path)?;
+            return fs.open(&rel_path, flags, mode);
         }
-        fs.open(&rel_path, flags, mode)
+
+        let m = self.resolve_writable(path)?;
+        let Some(quota) = m.quota else {
+            return m.fs.open(&m.rel_path, flags, mode);
+        };
+
+        // Opening may truncate, so settle the size change before handing out
+        // a handle that charges for growth
+        let before = file_size(m.fs.as_ref(), &m.rel_path);
+        let file = m.fs.open(&m.rel_path, flags, mode)?;
+        let after = if flags.truncate {
+            0
+        } else {
+            file.metadata().map_or(before, |metadata| metadata.size)
+        };
+        quota.charge(m.fs.as_ref(), size_delta(after, before))?;
+        Ok(Box::new(QuotaFile::new(
+            file,
+            quota,
+            m.fs,
+            after,
+            flags.append,
+        )))
+    }
+
+    fn range_locks(&self) -> Option<&RangeLockTable> {
+        Some(&self.locks)
     }
 
     fn name(&self) -> &str {
@@ -530,4 +1044,176 @@ fn test_list_mounts() {
             .iter()
             .any(|(p, _)| p == &PathBuf::from("/tmp")));
     }
+
+    #[test]
+    fn test_mount_flags_bitset() {
+        let flags = MountFlags::READ_ONLY | MountFlags::NO_EXEC;
+        assert!(flags.is_read_only());
+        assert!(flags.is_no_exec());
+        assert!(!flags.is_no_suid());
+        assert!(flags.contains(MountFlags::READ_ONLY));
+        assert!(!flags.contains(MountFlags::READ_ONLY | MountFlags::NO_SUID));
+        assert!(MountFlags::empty().is_empty());
+    }
+
+    #[test]
+    #[allow(deprecated)]
+    fn test_legacy_readonly_field() {
+        assert!(MountPoint::readonly("/ro", "ro").readonly);
+        assert!(!MountPoint::new("/rw", "rw").readonly);
+
+        // Setting the old field still mounts read-only
+        let mut config = MountPoint::new("/data", "data");
+        config.readonly = true;
+        let mgr = MountManager::new();
+        mgr.mount_from_config(&config, Arc::new(MemFS::new()))
+            .unwrap();
+        assert!(mgr.mount_point("/data/file").unwrap().is_readonly());
+        assert!(mgr.write(Path::new("/data/file"), b"x").is_err());
+    }
+
+    #[test]
+    fn test_mount_point_query() {
+        let mgr = MountManager::new();
+        mgr.mount("/data", Arc::new(MemFS::new())).unwrap();
+        mgr.mount_with_flags(
+            "/data/storage",
+            Arc::new(MemFS::new()),
+            MountFlags::READ_ONLY | MountFlags::NO_EXEC,
+        )
+        .unwrap();
+
+        let mount = mgr.mount_point("/data/storage/app/file.txt").unwrap();
+        assert_eq!(mount.path, PathBuf::from("/data/storage"));
+        assert!(mount.is_readonly());
+        assert!(mount.flags.is_no_exec());
+
+        let mount = mgr.mount_point("/data/other.txt").unwrap();
+        assert_eq!(mount.path, PathBuf::from("/data"));
+        assert!(mount.flags.is_empty());
+
+        assert!(mgr.mount_point("/elsewhere").is_none());
+        assert_eq!(mgr.mount_points().len(), 2);
+    }
+
+    #[test]
+    fn test_noexec_mount() {
+        let mgr = MountManager::new();
+        mgr.mount_with_flags("/apps", Arc::new(MemFS::new()), MountFlags::NO_EXEC)
+            .unwrap();
+        mgr.mount("/bin", Arc::new(MemFS::new())).unwrap();
+
+        // Writes are still allowed on a noexec mount
+        mgr.write(Path::new("/apps/tool"), b"#!/bin/sh").unwrap();
+
+        assert!(matches!(
+            mgr.check_exec("/apps/tool"),
+            Err(VfsError::PermissionDenied(_))
+        ));
+        assert!(mgr.check_exec("/bin/tool").is_ok());
+    }
+
+    #[test]
+    fn test_nosuid_mount_strips_setuid_bits() {
+        let mgr = MountManager::new();
+        mgr.mount_with_flags("/data", Arc::new(MemFS::new()), MountFlags::NO_SUID)
+            .unwrap();
+        mgr.write(Path::new("/data/tool"), b"bin").unwrap();
+
+        mgr.set_permissions(Path::new("/data/tool"), Permissions::new(0o4755))
+            .unwrap();
+        let metadata = mgr.metadata(Path::new("/data/tool")).unwrap();
+        assert_eq!(metadata.permissions.mode, 0o755);
+    }
+
+    #[test]
+    fn test_local_mount_quota() {
+        let temp = TempDir::new().unwrap();
+        std::fs::write(temp.path().join("existing.bin"), [0u8; 40]).unwrap();
+
+        let mgr = MountManager::new();
+        let config = MountPoint::new("/storage", "local").with_quota(100);
+        mgr.mount_from_config(&config, Arc::new(LocalFS::new(temp.path().to_path_buf())))
+            .unwrap();
+        assert_eq!(mgr.mount_point("/storage").unwrap().quota, Some(100));
+
+        // Files already on disk count toward the quota
+        assert_eq!(mgr.usage("/storage").unwrap().used, 40);
+
+        mgr.write(Path::new("/storage/a.bin"), &[1u8; 50]).unwrap();
+        assert!(matches!(
+            mgr.write(Path::new("/storage/b.bin"), &[1u8; 20]),
+            Err(VfsError::OutOfSpace)
+        ));
+        assert!(!temp.path().join("b.bin").exists());
+        assert!(matches!(
+            mgr.append(Path::new("/storage/a.bin"), &[1u8; 20]),
+            Err(VfsError::OutOfSpace)
+        ));
+
+        // Shrinking and deleting free space again
+        mgr.write(Path::new("/storage/a.bin"), &[1u8; 10]).unwrap();
+        mgr.delete(Path::new("/storage/existing.bin")).unwrap();
+        mgr.write(Path::new("/storage/b.bin"), &[1u8; 80]).unwrap();
+
+        let usage = mgr.usage("/storage/b.bin").unwrap();
+        assert_eq!(usage.used, 90);
+        assert_eq!(usage.available(), Some(10));
+    }
+
+    #[test]
+    fn test_quota_charges_appends_through_handles() {
+        use std::io::Write;
+
+        let temp = TempDir::new().unwrap();
+        std::fs::write(temp.path().join("log"), [0u8; 60]).unwrap();
+
+        let mgr = MountManager::new();
+        let config = MountPoint::new("/storage", "local").with_quota(100);
+        mgr.mount_from_config(&config, Arc::new(LocalFS::new(temp.path().to_path_buf())))
+            .unwrap();
+
+        let mut file = mgr
+            .open(
+                Path::new("/storage/log"),
+                OpenFlags::append_only(),
+                OpenMode::default(),
+            )
+            .unwrap();
+        file.write_all(&[1u8; 30]).unwrap();
+        assert_eq!(mgr.usage("/storage").unwrap().used, 90);
+
+        // Shorter than the file, but still past the quota once appended
+        let err = file.write_all(&[1u8; 30]).unwrap_err();
+        assert_eq!(err.kind(), std::io::ErrorKind::StorageFull);
+        assert_eq!(mgr.usage("/storage").unwrap().used, 90);
+    }
+
+    #[test]
+    fn test_quota_applies_to_open_handles() {
+        use std::io::Write;
+
+        let mgr = MountManager::new();
+        let config = MountPoint::new("/data", "memory").with_quota(16);
+        mgr.mount_from_config(&config, Arc::new(MemFS::new()))
+            .unwrap();
+
+        let mut file = mgr
+            .open(
+                Path::new("/data/log"),
+                OpenFlags::create(),
+                OpenMode::default(),
+            )
+            .unwrap();
+        file.write_all(&[1u8; 12]).unwrap();
+        let err = file.write_all(&[1u8; 8]).unwrap_err();
+        assert_eq!(err.kind(), std::io::ErrorKind::StorageFull);
+        assert_eq!(mgr.usage("/data").unwrap().used, 12);
+
+        // Unlimited mounts report usage without a quota
+        mgr.mount("/tmp", Arc::new(MemFS::new())).unwrap();
+        mgr.write(Path::new("/tmp/x"), b"abc").unwrap();
+        let usage = mgr.usage("/tmp").unwrap();
+        assert_eq!((usage.used, usage.quota), (3, None));
+    }
 }
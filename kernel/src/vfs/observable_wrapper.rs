@@ -7,9 +7,11 @@
 use std::sync::Arc;
 use tokio::sync::broadcast;
 
+use super::locks::RangeLockTable;
 use super::observable::{EventBroadcaster, FileEvent, Observable};
 use super::traits::{FileSystem, OpenFile};
 use super::types::*;
+use super::watch::{self, WatchConfig};
 
 /// Wrapper that adds observability to any FileSystem
 pub struct ObservableFS<F: FileSystem> {
@@ -43,6 +45,32 @@ pub fn inner(&self) -> &F {
     }
 }
 
+impl<F: FileSystem + 'static> ObservableFS<F> {
+    /// Watch `root` and every directory beneath it, including ones created later
+    ///
+    /// Uses the default debounce window from `WatchConfig`.
+    pub fn watch_recursive(
+        &self,
+        root: impl AsRef<Path>,
+    ) -> impl futures::Stream<Item = FileEvent> + Send + 'static {
+        self.watch_recursive_with(root, WatchConfig::default())
+    }
+
+    /// Watch `root` recursively with a custom configuration
+    pub fn watch_recursive_with(
+        &self,
+        root: impl AsRef<Path>,
+        config: WatchConfig,
+    ) -> impl futures::Stream<Item = FileEvent> + Send + 'static {
+        watch::watch_recursive(
+            Arc::clone(&self.inner),
+            self.broadcaster.subscribe(),
+            root.as_ref(),
+            config,
+        )
+    }
+}
+
 impl<F: FileSystem> Observable for ObservableFS<F> {
     fn subscribe(&self) -> broadcast::Receiver<FileEvent> {
         self.broadcaster.subscribe()
@@ -77,7 +105,27 @@ fn write(&self, path: &Path, data: &[u8]) -> VfsResult<()> {
         result
     }
 
-    fn append(&self, path: &Path, data: &[u8]) -> VfsResult<()> {
+    fn write_vectored(&self, path: &Path, segments: &[&[u8]]) -> VfsResult<usize> {
+        let existed = self.inner.exists(path);
+        let result = self.inner.write_vectored(path, segments);
+
+        if result.is_ok() {
+            let path = path.to_path_buf();
+            self.emit(if existed {
+                FileEvent::Modified { path }
+            } else {
+                FileEvent::Created { path }
+            });
+        }
+
+        result
+    }
+
+    fn read_vectored(&self, path: &Path, sizes: &[usize]) -> VfsResult<Vec<Vec<u8>>> {
+        self.inner.read_vectored(path, sizes)
+    }
+
+    fn append(&self, path: &Path, data: &[u8]) -> VfsResult<u64> {
         let result = self.inner.append(path, data);
 
         if result.is_ok() {
@@ -173,6 +221,18 @@ fn copy(&self, from: &Path, to: &Path) -> VfsResult<()> {
         result
     }
 
+    fn copy_reflink(&self, from: &Path, to: &Path) -> VfsResult<bool> {
+        let result = self.inner.copy_reflink(from, to);
+
+        if result.is_ok() {
+            self.emit(FileEvent::Created {
+                path: to.to_path_buf(),
+            });
+        }
+
+        result
+    }
+
     fn rename(&self, from: &Path, to: &Path) -> VfsResult<()> {
         let result = self.inner.rename(from, to);
 
@@ -186,6 +246,19 @@ fn rename(&self, from: &Path, to: &Path) -> VfsResult<()> {
         result
     }
 
+    fn rename_noreplace(&self, from: &Path, to: &Path) -> VfsResult<()> {
+        let result = self.inner.rename_noreplace(from, to);
+
+        if result.is_ok() {
+            self.emit(FileEvent::Renamed {
+                from: from.to_path_buf(),
+                to: to.to_path_buf(),
+            });
+        }
+
+        result
+    }
+
     fn symlink(&self, src: &Path, dst: &Path) -> VfsResult<()> {
         let result = self.inner.symlink(src, dst);
 
@@ -230,6 +303,10 @@ fn open(&self, path: &Path, flags: OpenFlags, mode: OpenMode) -> VfsResult<Box<d
         self.inner.open(path, flags, mode)
     }
 
+    fn range_locks(&self) -> Option<&RangeLockTable> {
+        self.inner.range_locks()
+    }
+
     fn name(&self) -> &str {
         self.inner.name()
     }
@@ -314,4 +391,3 @@ async fn test_observable_rename() {
         });
     }
 }
-
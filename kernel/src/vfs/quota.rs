@@ -0,0 +1,267 @@
+/*!
+ * Mount Quotas
+ * Per-mount byte limits enforced by the mount manager for any backend
+ */
+
+use std::io::{self, Read, Seek, SeekFrom, Write};
+use std::path::Path;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, OnceLock};
+
+use super::traits::{FileSystem, OpenFile};
+use super::types::{FileType, Metadata, VfsError, VfsResult};
+
+/// Byte usage of a mount against its quota
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MountUsage {
+    pub used: u64,
+    pub quota: Option<u64>,
+}
+
+impl MountUsage {
+    /// Bytes left before the quota is hit, None if unlimited
+    pub fn available(&self) -> Option<u64> {
+        self.quota.map(|quota| quota.saturating_sub(self.used))
+    }
+}
+
+/// Quota state for one mount
+///
+/// Usage starts from a scan of the mounted tree the first time it's needed,
+/// then is kept current by charging and refunding each change in file size.
+#[derive(Debug)]
+pub(super) struct MountQuota {
+    limit: u64,
+    used: OnceLock<AtomicU64>,
+}
+
+impl MountQuota {
+    pub fn new(limit: u64) -> Self {
+        Self {
+            limit,
+            used: OnceLock::new(),
+        }
+    }
+
+    pub fn limit(&self) -> u64 {
+        self.limit
+    }
+
+    /// Current usage, scanning `fs` on first use
+    pub fn used(&self, fs: &dyn FileSystem) -> u64 {
+        self.counter(fs).load(Ordering::Acquire)
+    }
+
+    fn counter(&self, fs: &dyn FileSystem) -> &AtomicU64 {
+        self.used
+            .get_or_init(|| AtomicU64::new(tree_size(fs, Path::new("/"))))
+    }
+
+    /// Apply a size change, failing with OutOfSpace if growth exceeds the quota
+    pub fn charge(&self, fs: &dyn FileSystem, delta: i64) -> VfsResult<()> {
+        let counter = self.counter(fs);
+        if delta <= 0 {
+            let _ = counter.fetch_update(Ordering::AcqRel, Ordering::Acquire, |used| {
+                Some(used.saturating_sub(delta.unsigned_abs()))
+            });
+            return Ok(());
+        }
+
+        counter
+            .fetch_update(Ordering::AcqRel, Ordering::Acquire, |used| {
+                used.checked_add(delta as u64)
+                    .filter(|total| *total <= self.limit)
+            })
+            .map(|_| ())
+            .map_err(|_| VfsError::OutOfSpace)
+    }
+
+    /// Reserve the change, run `op`, and refund if it fails
+    pub fn apply<T>(
+        &self,
+        fs: &dyn FileSystem,
+        delta: i64,
+        op: impl FnOnce() -> VfsResult<T>,
+    ) -> VfsResult<T> {
+        self.charge(fs, delta)?;
+        op().inspect_err(|_| {
+            // Undo without the limit check, so a refunded shrink always lands
+            let counter = self.counter(fs);
+            if delta > 0 {
+                let _ = counter.fetch_update(Ordering::AcqRel, Ordering::Acquire, |used| {
+                    Some(used.saturating_sub(delta as u64))
+                });
+            } else {
+                counter.fetch_add(delta.unsigned_abs(), Ordering::AcqRel);
+            }
+        })
+    }
+}
+
+/// Size of a regular file, zero if it doesn't exist or isn't a file
+pub(super) fn file_size(fs: &dyn FileSystem, path: &Path) -> u64 {
+    fs.metadata(path)
+        .ok()
+        .filter(|metadata| metadata.file_type == FileType::File)
+        .map_or(0, |metadata| metadata.size)
+}
+
+/// Total size of regular files under `path` (or of `path` itself)
+pub(super) fn tree_size(fs: &dyn FileSystem, path: &Path) -> u64 {
+    let Ok(metadata) = fs.metadata(path) else {
+        return 0;
+    };
+    match metadata.file_type {
+        FileType::File => metadata.size,
+        FileType::Directory => fs
+            .list_dir(path)
+            .map(|entries| {
+                entries
+                    .iter()
+                    .map(|entry| tree_size(fs, &path.join(entry.name.as_str())))
+                    .sum()
+            })
+            .unwrap_or(0),
+        _ => 0,
+    }
+}
+
+/// Signed difference between two sizes
+#[inline]
+pub(super) fn size_delta(new: u64, old: u64) -> i64 {
+    (new as i128 - old as i128).clamp(i64::MIN as i128, i64::MAX as i128) as i64
+}
+
+/// Open file on a quota-limited mount
+///
+/// Charges the mount for every byte the file grows by, so writes through
+/// a handle can't bypass the quota. The length is tracked here rather than
+/// read back because backends may buffer writes until sync.
+pub(super) struct QuotaFile {
+    inner: Box<dyn OpenFile>,
+    quota: Arc<MountQuota>,
+    fs: Arc<dyn FileSystem>,
+    len: u64,
+    /// Opened O_APPEND, so every write lands at the end of the file
+    append: bool,
+}
+
+impl QuotaFile {
+    pub fn new(
+        inner: Box<dyn OpenFile>,
+        quota: Arc<MountQuota>,
+        fs: Arc<dyn FileSystem>,
+        len: u64,
+        append: bool,
+    ) -> Self {
+        Self {
+            inner,
+            quota,
+            fs,
+            len,
+            append,
+        }
+    }
+}
+
+impl Read for QuotaFile {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        self.inner.read(buf)
+    }
+}
+
+impl Write for QuotaFile {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        // An append handle's position only moves to the end once it writes
+        let start = if self.append {
+            self.len
+        } else {
+            self.inner.stream_position()?
+        };
+        let growth = (start + buf.len() as u64).saturating_sub(self.len);
+        if growth > 0 {
+            self.quota
+                .charge(self.fs.as_ref(), size_delta(growth, 0))
+                .map_err(|e| io::Error::new(io::ErrorKind::StorageFull, e))?;
+        }
+
+        // Refund whatever the backend didn't end up writing
+        let written = self.inner.write(buf);
+        let end = start + *written.as_ref().unwrap_or(&0) as u64;
+        let grown = end.saturating_sub(self.len);
+        if grown < growth {
+            let _ = self
+                .quota
+                .charge(self.fs.as_ref(), size_delta(grown, growth));
+        }
+        self.len = self.len.max(end);
+        written
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.inner.flush()
+    }
+}
+
+impl Seek for QuotaFile {
+    fn seek(&mut self, pos: SeekFrom) -> io::Result<u64> {
+        self.inner.seek(pos)
+    }
+}
+
+impl OpenFile for QuotaFile {
+    fn sync(&mut self) -> VfsResult<()> {
+        self.inner.sync()
+    }
+
+    fn metadata(&self) -> VfsResult<Metadata> {
+        self.inner.metadata()
+    }
+
+    fn set_len(&mut self, size: u64) -> VfsResult<()> {
+        self.quota
+            .apply(self.fs.as_ref(), size_delta(size, self.len), || {
+                self.inner.set_len(size)
+            })?;
+        self.len = size;
+        Ok(())
+    }
+
+    fn contents(&self) -> Option<&[u8]> {
+        self.inner.contents()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::vfs::MemFS;
+
+    #[test]
+    fn test_charge_and_refund() {
+        let fs = MemFS::new();
+        let quota = MountQuota::new(10);
+
+        quota.charge(&fs, 6).unwrap();
+        assert!(matches!(quota.charge(&fs, 5), Err(VfsError::OutOfSpace)));
+        quota.charge(&fs, -4).unwrap();
+        quota.charge(&fs, 8).unwrap();
+        assert_eq!(quota.used(&fs), 10);
+
+        // Failed operations give their reservation back
+        let result: VfsResult<()> = quota.apply(&fs, -3, || Err(VfsError::ReadOnly));
+        assert!(result.is_err());
+        assert_eq!(quota.used(&fs), 10);
+    }
+
+    #[test]
+    fn test_usage_scanned_from_existing_tree() {
+        let fs = MemFS::new();
+        fs.create_dir(Path::new("/dir")).unwrap();
+        fs.write(Path::new("/a.txt"), b"12345").unwrap();
+        fs.write(Path::new("/dir/b.txt"), b"123").unwrap();
+
+        let quota = MountQuota::new(100);
+        assert_eq!(quota.used(&fs), 8);
+    }
+}
@@ -4,9 +4,12 @@
  */
 
 use std::io::{Read, Seek, Write};
+use std::ops::Range;
 use std::path::{Path, PathBuf};
 
+use super::locks::RangeLockTable;
 use super::types::*;
+use crate::core::types::Pid;
 
 /// Virtual filesystem trait
 ///
@@ -20,8 +23,11 @@ pub trait FileSystem: Send + Sync {
     /// Write entire file contents (create or overwrite)
     fn write(&self, path: &Path, data: &[u8]) -> VfsResult<()>;
 
-    /// Append data to file
-    fn append(&self, path: &Path, data: &[u8]) -> VfsResult<()>;
+    /// Append data to file, creating it if missing
+    ///
+    /// Returns the file length after this append. Appends to the same file
+    /// are serialized, so concurrent appenders never interleave their data.
+    fn append(&self, path: &Path, data: &[u8]) -> VfsResult<u64>;
 
     /// Create empty file
     fn create(&self, path: &Path) -> VfsResult<()>;
@@ -50,9 +56,29 @@ pub trait FileSystem: Send + Sync {
     /// Copy file
     fn copy(&self, from: &Path, to: &Path) -> VfsResult<()>;
 
+    /// Copy file, sharing storage with the source where the backend can
+    ///
+    /// Returns `true` for a reflink: an O(1) copy whose storage stays shared
+    /// until either side is written. The default copies bytes and returns
+    /// `false`.
+    fn copy_reflink(&self, from: &Path, to: &Path) -> VfsResult<bool> {
+        self.copy(from, to)?;
+        Ok(false)
+    }
+
     /// Move/rename file
     fn rename(&self, from: &Path, to: &Path) -> VfsResult<()>;
 
+    /// Move/rename file, failing with `VfsError::AlreadyExists` if `to` exists
+    ///
+    /// The existence check and the rename must be one atomic step, so that
+    /// when several callers race for the same destination exactly one wins.
+    /// Backends that can't guarantee that leave this unsupported.
+    fn rename_noreplace(&self, from: &Path, to: &Path) -> VfsResult<()> {
+        let _ = (from, to);
+        Err(VfsError::NotSupported("rename without replace".into()))
+    }
+
     /// Create symbolic link
     fn symlink(&self, src: &Path, dst: &Path) -> VfsResult<()>;
 
@@ -68,6 +94,52 @@ pub trait FileSystem: Send + Sync {
     /// Open file with specified flags and mode
     fn open(&self, path: &Path, flags: OpenFlags, mode: OpenMode) -> VfsResult<Box<dyn OpenFile>>;
 
+    /// Write segments back to back as the new file contents (create or overwrite)
+    ///
+    /// Returns the total bytes written. The default concatenates and calls
+    /// `write`; backends override it to avoid the copy.
+    fn write_vectored(&self, path: &Path, segments: &[&[u8]]) -> VfsResult<usize> {
+        let data = segments.concat();
+        self.write(path, &data)?;
+        Ok(data.len())
+    }
+
+    /// Read the start of a file into segments of the given sizes
+    ///
+    /// Segments past end of file come back short or empty. The default reads
+    /// the whole file and splits it.
+    fn read_vectored(&self, path: &Path, sizes: &[usize]) -> VfsResult<Vec<Vec<u8>>> {
+        Ok(split_segments(&self.read(path)?, sizes))
+    }
+
+    /// Advisory lock table, if this filesystem supports range locks
+    fn range_locks(&self) -> Option<&RangeLockTable> {
+        None
+    }
+
+    /// Take an advisory byte-range lock for `pid`
+    ///
+    /// Returns `VfsError::WouldBlock` if another process holds a conflicting
+    /// lock.
+    fn lock_range(
+        &self,
+        path: &Path,
+        range: Range<u64>,
+        exclusive: bool,
+        pid: Pid,
+    ) -> VfsResult<()> {
+        self.range_locks()
+            .ok_or_else(|| VfsError::NotSupported("range locks".into()))?
+            .lock(path, range, exclusive, pid)
+    }
+
+    /// Release `pid`'s advisory locks within `range`
+    fn unlock_range(&self, path: &Path, range: Range<u64>, pid: Pid) -> VfsResult<()> {
+        self.range_locks()
+            .ok_or_else(|| VfsError::NotSupported("range locks".into()))?
+            .unlock(path, range, pid)
+    }
+
     /// Get filesystem name/type
     fn name(&self) -> &str;
 
@@ -77,6 +149,20 @@ fn readonly(&self) -> bool {
     }
 }
 
+/// Split `data` into consecutive segments of the given sizes
+pub(crate) fn split_segments(data: &[u8], sizes: &[usize]) -> Vec<Vec<u8>> {
+    let mut offset: usize = 0;
+    sizes
+        .iter()
+        .map(|&size| {
+            let end = offset.saturating_add(size).min(data.len());
+            let segment = data[offset..end].to_vec();
+            offset = end;
+            segment
+        })
+        .collect()
+}
+
 /// Open file handle trait
 ///
 /// Represents an open file with read/write/seek capabilities.
@@ -90,6 +176,22 @@ pub trait OpenFile: Read + Write + Seek + Send + Sync {
 
     /// Set file length
     fn set_len(&mut self, size: u64) -> VfsResult<()>;
+
+    /// Borrow the whole file when it lives in memory
+    ///
+    /// Lets copies such as sendfile write straight from the backing buffer
+    /// instead of staging through a read. Disk-backed files return None.
+    fn contents(&self) -> Option<&[u8]> {
+        None
+    }
+
+    /// Whether the file can be read or written without blocking
+    ///
+    /// Used by poll. Files backed by storage are always ready; pipes and
+    /// event fds override this.
+    fn readiness(&self) -> Readiness {
+        Readiness::READ_WRITE
+    }
 }
 
 /// Filesystem builder trait for configuration
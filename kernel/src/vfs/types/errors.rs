@@ -4,6 +4,7 @@
  */
 
 use crate::core::data_structures::InlineString;
+use crate::core::errors::{ErrorCategory, ErrorCode};
 use serde::{Deserialize, Deserializer, Serialize};
 use thiserror::Error;
 
@@ -60,6 +61,48 @@ pub enum VfsError {
 
     #[error("Cross-device link")]
     CrossDevice,
+
+    #[error("Would block: {0}")]
+    WouldBlock(#[serde(deserialize_with = "deserialize_nonempty_inline_string")] InlineString),
+
+    #[error("Unavailable: {0}")]
+    Unavailable(#[serde(deserialize_with = "deserialize_nonempty_inline_string")] InlineString),
+}
+
+impl VfsError {
+    /// Stable numeric code for this variant
+    #[inline]
+    pub const fn error_code(&self) -> u32 {
+        self.typed_code().code
+    }
+
+    /// Error class for this variant
+    #[inline]
+    pub const fn category(&self) -> ErrorCategory {
+        self.typed_code().category
+    }
+
+    /// Code and category together
+    pub const fn typed_code(&self) -> ErrorCode {
+        use ErrorCategory as C;
+        match self {
+            Self::NotFound(_) => ErrorCode::new(2001, C::NotFound),
+            Self::AlreadyExists(_) => ErrorCode::new(2002, C::AlreadyExists),
+            Self::PermissionDenied(_) => ErrorCode::new(2003, C::PermissionDenied),
+            Self::NotADirectory(_) => ErrorCode::new(2004, C::InvalidArgument),
+            Self::IsADirectory(_) => ErrorCode::new(2005, C::InvalidArgument),
+            Self::InvalidPath(_) => ErrorCode::new(2006, C::InvalidArgument),
+            Self::IoError(_) => ErrorCode::new(2007, C::Io),
+            Self::NotSupported(_) => ErrorCode::new(2008, C::Unsupported),
+            Self::OutOfSpace => ErrorCode::new(2009, C::QuotaExceeded),
+            Self::InvalidArgument(_) => ErrorCode::new(2010, C::InvalidArgument),
+            Self::FileTooLarge => ErrorCode::new(2011, C::QuotaExceeded),
+            Self::ReadOnly => ErrorCode::new(2012, C::PermissionDenied),
+            Self::CrossDevice => ErrorCode::new(2013, C::Unsupported),
+            Self::WouldBlock(_) => ErrorCode::new(2014, C::WouldBlock),
+            Self::Unavailable(_) => ErrorCode::new(2015, C::Unavailable),
+        }
+    }
 }
 
 /// Deserialize and validate non-empty inline string for error messages
@@ -93,4 +136,36 @@ fn test_vfs_error_validation() {
         let result: Result<VfsError, _> = serde_json::from_str(invalid_json);
         assert!(result.is_err());
     }
+
+    #[test]
+    fn test_error_codes_are_unique() {
+        let errors = [
+            VfsError::NotFound("x".into()),
+            VfsError::AlreadyExists("x".into()),
+            VfsError::PermissionDenied("x".into()),
+            VfsError::NotADirectory("x".into()),
+            VfsError::IsADirectory("x".into()),
+            VfsError::InvalidPath("x".into()),
+            VfsError::IoError("x".into()),
+            VfsError::NotSupported("x".into()),
+            VfsError::OutOfSpace,
+            VfsError::InvalidArgument("x".into()),
+            VfsError::FileTooLarge,
+            VfsError::ReadOnly,
+            VfsError::CrossDevice,
+            VfsError::WouldBlock("x".into()),
+            VfsError::Unavailable("x".into()),
+        ];
+
+        let codes: std::collections::HashSet<_> = errors.iter().map(|e| e.error_code()).collect();
+        assert_eq!(codes.len(), errors.len());
+        assert!(errors
+            .iter()
+            .all(|e| (2000..3000).contains(&e.error_code())
+                && e.category() != ErrorCategory::Unknown));
+        assert_eq!(
+            VfsError::OutOfSpace.category(),
+            ErrorCategory::QuotaExceeded
+        );
+    }
 }
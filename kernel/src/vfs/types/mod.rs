@@ -9,6 +9,7 @@
 mod metadata;
 mod open_flags;
 mod permissions;
+mod readiness;
 
 pub use entry::Entry;
 pub use errors::{VfsError, VfsResult};
@@ -16,3 +17,4 @@
 pub use metadata::Metadata;
 pub use open_flags::{OpenFlags, OpenMode};
 pub use permissions::Permissions;
+pub use readiness::Readiness;
@@ -0,0 +1,25 @@
+/*!
+ * VFS Readiness
+ * I/O readiness of an open file, as reported to poll
+ */
+
+/// Whether an open file can be read or written without blocking
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Readiness {
+    pub readable: bool,
+    pub writable: bool,
+    /// The other end went away; reads return EOF
+    pub hangup: bool,
+    /// An error is pending, or writes would fail with a broken pipe
+    pub error: bool,
+}
+
+impl Readiness {
+    /// Regular files never block
+    pub const READ_WRITE: Self = Self {
+        readable: true,
+        writable: true,
+        hangup: false,
+        error: false,
+    };
+}
@@ -0,0 +1,230 @@
+/*!
+ * Recursive Directory Watch
+ * fsnotify-style watch streams layered on EventBroadcaster
+ */
+
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::Duration;
+
+use futures::Stream;
+use tokio::sync::broadcast::{self, error::RecvError};
+use tokio::time::Instant;
+use tracing::warn;
+
+use super::observable::FileEvent;
+use super::traits::FileSystem;
+use crate::core::limits::VFS_WATCH_DEBOUNCE_MS;
+
+/// Recursive watch configuration
+#[derive(Debug, Clone)]
+pub struct WatchConfig {
+    /// Window in which rapid events are coalesced (zero = deliver immediately)
+    pub debounce: Duration,
+}
+
+impl Default for WatchConfig {
+    fn default() -> Self {
+        Self {
+            debounce: Duration::from_millis(VFS_WATCH_DEBOUNCE_MS),
+        }
+    }
+}
+
+/// Set of directories covered by a recursive watch
+///
+/// Like inotify, an event is delivered when it happens directly inside a
+/// watched directory. Directories created (or moved) under the root join the
+/// set; deleted ones leave it along with their subtrees.
+struct WatchSet {
+    root: PathBuf,
+    dirs: HashSet<PathBuf>,
+}
+
+impl WatchSet {
+    fn new<F: FileSystem + ?Sized>(fs: &F, root: &Path) -> Self {
+        let mut set = Self {
+            root: root.to_path_buf(),
+            dirs: HashSet::new(),
+        };
+        set.rescan(fs);
+        set
+    }
+
+    /// Rebuild the set from the filesystem
+    fn rescan<F: FileSystem + ?Sized>(&mut self, fs: &F) {
+        self.dirs.clear();
+        let root = self.root.clone();
+        self.add_tree(fs, &root);
+    }
+
+    fn add_tree<F: FileSystem + ?Sized>(&mut self, fs: &F, dir: &Path) {
+        let mut stack = vec![dir.to_path_buf()];
+        while let Some(dir) = stack.pop() {
+            if let Ok(entries) = fs.list_dir(&dir) {
+                stack.extend(
+                    entries
+                        .iter()
+                        .filter(|entry| entry.is_dir())
+                        .map(|entry| dir.join(entry.name.as_str())),
+                );
+            }
+            self.dirs.insert(dir);
+        }
+    }
+
+    fn remove_tree(&mut self, dir: &Path) {
+        self.dirs.retain(|watched| !watched.starts_with(dir));
+    }
+
+    fn covers(&self, path: &Path) -> bool {
+        path == self.root
+            || path
+                .parent()
+                .is_some_and(|parent| self.dirs.contains(parent))
+    }
+
+    fn is_dir<F: FileSystem + ?Sized>(fs: &F, path: &Path) -> bool {
+        fs.metadata(path).is_ok_and(|meta| meta.is_dir())
+    }
+
+    /// Update the watch set for `event`, returning whether it should be delivered
+    fn accept<F: FileSystem + ?Sized>(&mut self, fs: &F, event: &FileEvent) -> bool {
+        match event {
+            FileEvent::Created { path } => {
+                if !self.covers(path) {
+                    return false;
+                }
+                if Self::is_dir(fs, path) {
+                    self.add_tree(fs, path);
+                }
+                true
+            }
+            FileEvent::Modified { path } => self.covers(path),
+            FileEvent::Deleted { path } => {
+                let covered = self.covers(path);
+                self.remove_tree(path);
+                covered
+            }
+            FileEvent::Renamed { from, to } => {
+                let from_covered = self.covers(from);
+                let to_covered = self.covers(to);
+                self.remove_tree(from);
+                if to_covered && Self::is_dir(fs, to) {
+                    self.add_tree(fs, to);
+                }
+                from_covered || to_covered
+            }
+        }
+    }
+}
+
+/// Queue `event` unless it repeats the latest pending event for its path
+fn coalesce(pending: &mut Vec<FileEvent>, event: FileEvent) {
+    let latest = pending
+        .iter()
+        .rev()
+        .find(|queued| queued.path() == event.path());
+    let redundant = match (latest, &event) {
+        // A new file's content changes are implied by its creation
+        (Some(FileEvent::Created { .. }), FileEvent::Modified { .. }) => true,
+        (Some(latest), event) => latest == event,
+        (None, _) => false,
+    };
+    if !redundant {
+        pending.push(event);
+    }
+}
+
+/// Stream events under `root` from `receiver`, following new subdirectories
+///
+/// The watch set is populated before returning, so events emitted after this
+/// call are not missed. If the receiver lags, the watch set is rebuilt from
+/// the filesystem and dropped events are lost.
+pub fn watch_recursive<F: FileSystem + ?Sized + 'static>(
+    fs: Arc<F>,
+    mut receiver: broadcast::Receiver<FileEvent>,
+    root: &Path,
+    config: WatchConfig,
+) -> impl Stream<Item = FileEvent> + Send + 'static {
+    let mut watch = WatchSet::new(&*fs, root);
+
+    async_stream::stream! {
+        let mut pending: Vec<FileEvent> = Vec::new();
+        let mut deadline = Instant::now();
+
+        loop {
+            let received = if pending.is_empty() {
+                receiver.recv().await
+            } else {
+                match tokio::time::timeout_at(deadline, receiver.recv()).await {
+                    Ok(received) => received,
+                    Err(_) => {
+                        for event in pending.drain(..) {
+                            yield event;
+                        }
+                        continue;
+                    }
+                }
+            };
+
+            match received {
+                Ok(event) => {
+                    if !watch.accept(&*fs, &event) {
+                        continue;
+                    }
+                    if config.debounce.is_zero() {
+                        yield event;
+                        continue;
+                    }
+                    if pending.is_empty() {
+                        deadline = Instant::now() + config.debounce;
+                    }
+                    coalesce(&mut pending, event);
+                }
+                Err(RecvError::Lagged(skipped)) => {
+                    warn!(
+                        root = %watch.root.display(),
+                        skipped,
+                        "Recursive watch lagged, rescanning watch set"
+                    );
+                    watch.rescan(&*fs);
+                }
+                Err(RecvError::Closed) => {
+                    for event in pending.drain(..) {
+                        yield event;
+                    }
+                    break;
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_coalesce_write_burst() {
+        let path = PathBuf::from("/data/log.txt");
+        let mut pending = Vec::new();
+
+        coalesce(&mut pending, FileEvent::Created { path: path.clone() });
+        for _ in 0..10 {
+            coalesce(&mut pending, FileEvent::Modified { path: path.clone() });
+        }
+        coalesce(&mut pending, FileEvent::Deleted { path: path.clone() });
+        coalesce(&mut pending, FileEvent::Created { path: path.clone() });
+
+        assert_eq!(
+            pending,
+            vec![
+                FileEvent::Created { path: path.clone() },
+                FileEvent::Deleted { path: path.clone() },
+                FileEvent::Created { path },
+            ]
+        );
+    }
+}
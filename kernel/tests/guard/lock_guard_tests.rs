@@ -88,7 +88,7 @@ fn test_lock_guard_poisoning() {
     assert_eq!(guard.poison_reason(), Some("Test poison"));
 
     // Recover from poison
-    guard.recover().unwrap();
+    let guard = guard.recover().unwrap();
     assert!(!guard.is_poisoned());
     assert!(guard.poison_reason().is_none());
 }
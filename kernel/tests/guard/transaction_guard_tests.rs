@@ -126,8 +126,9 @@ fn test_transaction_poisoning() {
     assert_eq!(tx.state(), TransactionState::Poisoned);
 
     // Can recover
-    tx.recover().unwrap();
+    let tx = tx.recover().unwrap();
     assert!(!tx.is_poisoned());
+    assert_eq!(tx.state(), TransactionState::Active);
 }
 
 #[test]
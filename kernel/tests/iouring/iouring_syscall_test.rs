@@ -8,13 +8,19 @@
 use ai_os_kernel::process::ProcessManagerImpl;
 use ai_os_kernel::security::SandboxProvider;
 use ai_os_kernel::syscalls::{
-    IoUringExecutor, IoUringManager, SyscallExecutorWithIpc, SyscallSubmissionEntry,
+    IoUringExecutor, IoUringManager, Syscall, SyscallExecutorWithIpc, SyscallResult,
+    SyscallSubmissionEntry,
 };
 use std::path::PathBuf;
 use std::sync::Arc;
 use tempfile::tempdir;
 
 fn setup_test_manager() -> (IoUringManager, Pid) {
+    let (manager, _, pid) = setup_test_manager_with_executor();
+    (manager, pid)
+}
+
+fn setup_test_manager_with_executor() -> (IoUringManager, SyscallExecutorWithIpc, Pid) {
     let memory_manager = MemoryManager::new();
     let process_manager = ProcessManagerImpl::new();
     let sandbox_manager = ai_os_kernel::security::SandboxManager::new();
@@ -39,10 +45,10 @@ fn setup_test_manager() -> (IoUringManager, Pid) {
         memory_manager.clone(),
     );
 
-    let iouring_executor = Arc::new(IoUringExecutor::new(syscall_executor));
+    let iouring_executor = Arc::new(IoUringExecutor::new(syscall_executor.clone()));
     let manager = IoUringManager::new(iouring_executor);
 
-    (manager, pid)
+    (manager, syscall_executor, pid)
 }
 
 #[tokio::test]
@@ -290,3 +296,142 @@ async fn test_iouring_fsync() {
     let completions = manager.reap_completions(pid, Some(10)).unwrap();
     assert!(!completions.is_empty());
 }
+
+#[tokio::test]
+async fn test_iouring_completion_fd_readable_after_completion() {
+    let (manager, executor, pid) = setup_test_manager_with_executor();
+    let temp_dir = tempdir().unwrap();
+    let test_file = temp_dir.path().join("notify.txt");
+    std::fs::write(&test_file, b"ready").unwrap();
+
+    let fd = manager.completion_fd(pid).unwrap();
+    let read_count = |executor: &SyscallExecutorWithIpc| match executor
+        .execute(pid, Syscall::Read { fd, count: 8 })
+    {
+        SyscallResult::Success { data: Some(data) } => {
+            Some(u64::from_le_bytes(data.as_slice().try_into().unwrap()))
+        }
+        _ => None,
+    };
+
+    // Nothing completed yet
+    assert_eq!(read_count(&executor), None);
+
+    let entry = SyscallSubmissionEntry::read_file(pid, test_file, 7);
+    let seq = manager.submit(pid, entry).unwrap();
+    tokio::time::sleep(tokio::time::Duration::from_millis(100)).await;
+
+    assert_eq!(read_count(&executor), Some(1));
+    // Reading resets the count
+    assert_eq!(read_count(&executor), None);
+
+    let completions = manager.reap_completions(pid, None).unwrap();
+    assert!(completions.iter().any(|c| c.seq == seq));
+
+    // Closing the fd detaches the notifier
+    let ring = manager.get_ring(pid).unwrap();
+    assert_eq!(ring.notifier_count(), 1);
+    assert!(executor.execute(pid, Syscall::Close { fd }).is_success());
+    assert_eq!(ring.notifier_count(), 0);
+}
+
+/// Reap completions for `seq` until one is flagged EOF or not successful
+async fn reap_stream(
+    manager: &IoUringManager,
+    pid: Pid,
+    seq: u64,
+) -> Vec<ai_os_kernel::syscalls::SyscallCompletionEntry> {
+    let mut chunks = Vec::new();
+    for _ in 0..500 {
+        for completion in manager.reap_completions(pid, None).unwrap() {
+            if completion.seq == seq {
+                chunks.push(completion);
+            }
+        }
+        if chunks
+            .last()
+            .is_some_and(|c| c.eof || !c.status.is_success())
+        {
+            break;
+        }
+        tokio::time::sleep(tokio::time::Duration::from_millis(5)).await;
+    }
+    chunks
+}
+
+#[tokio::test]
+async fn test_iouring_stream_file_read_in_chunks() {
+    let (manager, pid) = setup_test_manager();
+    // A completion queue smaller than the chunk count exercises backpressure
+    manager.create_ring(pid, Some(16), Some(2)).unwrap();
+
+    let temp_dir = tempdir().unwrap();
+    let test_file = temp_dir.path().join("stream.bin");
+    let contents: Vec<u8> = (0..10_300u32).map(|i| (i % 251) as u8).collect();
+    std::fs::write(&test_file, &contents).unwrap();
+
+    let entry = SyscallSubmissionEntry::read_file_stream(pid, test_file, 4096, 9);
+    let seq = manager.submit(pid, entry).unwrap();
+
+    let chunks = reap_stream(&manager, pid, seq).await;
+    assert_eq!(chunks.len(), 3);
+    assert!(chunks
+        .iter()
+        .all(|c| c.status.is_success() && c.user_data == 9));
+    assert!(chunks[..2].iter().all(|c| !c.eof));
+    assert!(chunks[2].eof);
+
+    let streamed: Vec<u8> = chunks
+        .iter()
+        .flat_map(|c| c.result.data().unwrap().clone())
+        .collect();
+    assert_eq!(streamed, contents);
+}
+
+#[tokio::test]
+async fn test_iouring_stream_cancel_stops_chunks() {
+    let (manager, pid) = setup_test_manager();
+    manager.create_ring(pid, Some(16), Some(2)).unwrap();
+
+    let temp_dir = tempdir().unwrap();
+    let test_file = temp_dir.path().join("large.bin");
+    std::fs::write(&test_file, vec![7u8; 64 * 1024]).unwrap();
+
+    let entry = SyscallSubmissionEntry::read_file_stream(pid, test_file, 1024, 0);
+    let seq = manager.submit(pid, entry).unwrap();
+
+    // The stream stalls on the full completion queue until cancelled
+    tokio::time::sleep(tokio::time::Duration::from_millis(50)).await;
+    manager.cancel(pid, seq).unwrap();
+
+    let chunks = reap_stream(&manager, pid, seq).await;
+    let last = chunks.last().unwrap();
+    assert!(last.status.is_cancelled());
+    assert!(chunks.iter().all(|c| !c.eof));
+    assert!(chunks.len() < 64);
+}
+
+#[tokio::test]
+async fn test_iouring_refuses_frozen_process() {
+    let (manager, executor, pid) = setup_test_manager_with_executor();
+    let process_manager = executor.optional().process_manager.clone().unwrap();
+    let temp_dir = tempdir().unwrap();
+    let test_file = temp_dir.path().join("frozen.txt");
+    std::fs::write(&test_file, b"checkpoint").unwrap();
+
+    process_manager.freeze(pid).unwrap();
+    let entry = SyscallSubmissionEntry::read_file(pid, test_file.clone(), 1);
+    let seq = manager.submit(pid, entry).unwrap();
+    let completion = manager.wait_completion(pid, seq).unwrap();
+    assert!(completion.status.is_error());
+    assert!(matches!(
+        completion.result,
+        SyscallResult::Error { ref message, .. } if message.contains("frozen")
+    ));
+
+    process_manager.thaw(pid).unwrap();
+    let entry = SyscallSubmissionEntry::read_file(pid, test_file, 2);
+    let seq = manager.submit(pid, entry).unwrap();
+    let completion = manager.wait_completion(pid, seq).unwrap();
+    assert!(completion.status.is_success());
+}
@@ -27,6 +27,20 @@ fn test_basic_message_send_receive() {
     assert_eq!(message.data, data);
 }
 
+#[test]
+fn test_shard_override() {
+    // 3 is rounded up to 4 shards
+    let memory = MemoryManager::new().with_shards(3);
+    let ipc = IPCManager::new(memory.clone()).with_shards(3);
+
+    ipc.send_message(100, 200, b"sharded".to_vec()).unwrap();
+    assert_eq!(ipc.receive_message(200).unwrap().data, b"sharded".to_vec());
+
+    let pipe_id = ipc.pipes().create(100, 200, None).unwrap();
+    assert_eq!(ipc.pipes().write(pipe_id, 200, b"ok").unwrap(), 2);
+    assert!(memory.allocate(64, 100).is_ok());
+}
+
 #[test]
 fn test_message_ordering() {
     let ipc = IPCManager::new(MemoryManager::new());
@@ -3,7 +3,7 @@
  * Tests for Unix-style pipe IPC
  */
 
-use ai_os_kernel::ipc::{PipeError, PipeManager};
+use ai_os_kernel::ipc::{PipeError, PipeManager, PipeWriteMode};
 use ai_os_kernel::MemoryManager;
 use pretty_assertions::assert_eq;
 use serial_test::serial;
@@ -298,23 +298,62 @@ fn test_pipe_partial_write() {
     let reader_pid = 100;
     let writer_pid = 200;
 
-    let capacity = 100;
+    let capacity = 16 * 1024;
     let pipe_id = pm.create(reader_pid, writer_pid, Some(capacity)).unwrap();
 
-    // Fill most of the pipe
-    let data1 = vec![0u8; 90];
+    // Fill most of the pipe, leaving 4KB free
+    let data1 = vec![0u8; 12 * 1024];
     pm.write(pipe_id, writer_pid, &data1).unwrap();
 
-    // Try to write more than available
-    let data2 = vec![1u8; 50];
-    let written = pm.write(pipe_id, writer_pid, &data2).unwrap();
+    // By default a write that does not fit is refused whole
+    let data2 = vec![1u8; 8 * 1024];
+    let result = pm.write(pipe_id, writer_pid, &data2);
+    assert!(matches!(result, Err(PipeError::WouldBlock(_))));
+    assert_eq!(pm.stats(pipe_id).unwrap().buffered, 12 * 1024);
 
-    // Should only write what fits
-    assert_eq!(written, 10);
+    // In partial mode only what fits is written, and the count is returned
+    let written = pm
+        .write_with_mode(pipe_id, writer_pid, &data2, PipeWriteMode::Partial)
+        .unwrap();
+    assert_eq!(written, 4 * 1024);
 
     // Total buffered should be capacity
     let stats = pm.stats(pipe_id).unwrap();
-    assert_eq!(stats.buffered, 100);
+    assert_eq!(stats.buffered, capacity);
+}
+
+#[test]
+fn test_pipe_small_writes_stay_atomic() {
+    let memory_manager = MemoryManager::new();
+    let pm = PipeManager::new(memory_manager);
+
+    let reader_pid = 100;
+    let writer_pid = 200;
+
+    let capacity = 16 * 1024;
+    let pipe_id = pm.create(reader_pid, writer_pid, Some(capacity)).unwrap();
+    pm.write(pipe_id, writer_pid, &vec![0u8; capacity - 10])
+        .unwrap();
+
+    // Writes up to PIPE_BUF are never split, even in partial mode
+    let small = vec![1u8; 50];
+    for mode in [PipeWriteMode::Atomic, PipeWriteMode::Partial] {
+        let result = pm.write_with_mode(pipe_id, writer_pid, &small, mode);
+        assert!(matches!(result, Err(PipeError::WouldBlock(_))));
+    }
+    assert_eq!(pm.stats(pipe_id).unwrap().buffered, capacity - 10);
+
+    // Once the reader drains enough, the whole write goes in
+    pm.read(pipe_id, reader_pid, 40).unwrap();
+    let written = pm
+        .write_with_mode(pipe_id, writer_pid, &small, PipeWriteMode::Partial)
+        .unwrap();
+    assert_eq!(written, small.len());
+
+    // An atomic write larger than the pipe could never succeed
+    let huge = vec![2u8; capacity + 1];
+    let result = pm.write(pipe_id, writer_pid, &huge);
+    assert!(matches!(result, Err(PipeError::CapacityExceeded { .. })));
 }
 
 #[test]
@@ -266,6 +266,82 @@ fn test_destroy_queue() {
     assert!(result.is_err());
 }
 
+#[test]
+fn test_destroy_draining_slow_consumer() {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+    use std::time::Duration;
+
+    let memory_manager = MemoryManager::new();
+    let manager = QueueManager::new(memory_manager);
+
+    let owner_pid = 100;
+    let consumer_pid = 200;
+    let queue_id = manager
+        .create(owner_pid, QueueType::Fifo, Some(20))
+        .unwrap();
+    for i in 0..10u8 {
+        manager.send(queue_id, owner_pid, vec![i], None).unwrap();
+    }
+
+    // Consumer takes one message every 20ms until the queue disappears
+    let received = Arc::new(AtomicUsize::new(0));
+    let consumer = {
+        let manager = manager.clone();
+        let received = Arc::clone(&received);
+        std::thread::spawn(move || loop {
+            match manager.receive(queue_id, consumer_pid) {
+                Ok(Some(msg)) => {
+                    manager.read_message_data(&msg).unwrap();
+                    received.fetch_add(1, Ordering::SeqCst);
+                    std::thread::sleep(Duration::from_millis(20));
+                }
+                Ok(None) => std::thread::sleep(Duration::from_millis(1)),
+                Err(_) => break,
+            }
+        })
+    };
+
+    let dropped = manager
+        .destroy_draining(queue_id, owner_pid, Duration::from_millis(50))
+        .unwrap();
+    consumer.join().unwrap();
+
+    // The deadline hit before the consumer caught up
+    assert!(dropped > 0 && dropped < 10, "dropped {}", dropped);
+    assert_eq!(received.load(Ordering::SeqCst) + dropped, 10);
+    assert!(manager.stats(queue_id).is_err());
+}
+
+#[test]
+fn test_destroy_draining_empty_queue() {
+    use std::time::{Duration, Instant};
+
+    let memory_manager = MemoryManager::new();
+    let manager = QueueManager::new(memory_manager);
+
+    let owner_pid = 100;
+    let queue_id = manager
+        .create(owner_pid, QueueType::Fifo, Some(10))
+        .unwrap();
+
+    // Nothing pending: returns without waiting out the deadline
+    let start = Instant::now();
+    let dropped = manager
+        .destroy_draining(queue_id, owner_pid, Duration::from_secs(5))
+        .unwrap();
+    assert_eq!(dropped, 0);
+    assert!(start.elapsed() < Duration::from_secs(1));
+
+    // Only the owner may destroy
+    let queue_id = manager
+        .create(owner_pid, QueueType::Fifo, Some(10))
+        .unwrap();
+    assert!(manager
+        .destroy_draining(queue_id, 999, Duration::ZERO)
+        .is_err());
+}
+
 #[test]
 fn test_queue_stats() {
     let memory_manager = MemoryManager::new();
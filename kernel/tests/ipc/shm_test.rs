@@ -477,3 +477,103 @@ fn test_shm_zero_copy() {
     let modified = sm.read(segment_id, pid1, 2000, 1000).unwrap();
     assert_eq!(modified, new_data);
 }
+
+#[test]
+fn test_shm_resize_grow_with_attachment() {
+    let memory_manager = MemoryManager::new();
+    let sm = ShmManager::new(memory_manager);
+
+    let owner_pid = 100;
+    let reader_pid = 200;
+    let segment_id = sm.create(1024, owner_pid).unwrap();
+    sm.attach(segment_id, reader_pid, true).unwrap();
+    sm.write(segment_id, owner_pid, 1010, b"tail").unwrap();
+
+    sm.resize(segment_id, owner_pid, 8192).unwrap();
+
+    // Same segment, same attachment, old contents intact, new space zeroed
+    let stats = sm.stats(segment_id).unwrap();
+    assert_eq!(stats.size, 8192);
+    assert!(stats.attached_pids.contains(&reader_pid));
+    assert_eq!(sm.read(segment_id, reader_pid, 1010, 4).unwrap(), b"tail");
+    assert_eq!(
+        sm.read(segment_id, reader_pid, 1024, 64).unwrap(),
+        vec![0u8; 64]
+    );
+
+    // The grown region is writable and visible to the attached reader
+    sm.write(segment_id, owner_pid, 8000, b"grown").unwrap();
+    assert_eq!(sm.read(segment_id, reader_pid, 8000, 5).unwrap(), b"grown");
+}
+
+#[test]
+fn test_shm_resize_shrink_with_attachment() {
+    let memory_manager = MemoryManager::new();
+    let sm = ShmManager::new(memory_manager);
+
+    let owner_pid = 100;
+    let other_pid = 200;
+    let segment_id = sm.create(4096, owner_pid).unwrap();
+    sm.attach(segment_id, other_pid, false).unwrap();
+    sm.write(segment_id, other_pid, 0, b"keep me").unwrap();
+
+    sm.resize(segment_id, owner_pid, 512).unwrap();
+
+    assert_eq!(sm.stats(segment_id).unwrap().size, 512);
+    assert_eq!(sm.read(segment_id, other_pid, 0, 7).unwrap(), b"keep me");
+    assert!(matches!(
+        sm.read(segment_id, other_pid, 500, 100),
+        Err(ShmError::InvalidRange {
+            segment_size: 512,
+            ..
+        })
+    ));
+}
+
+#[test]
+fn test_shm_resize_rejects_shrink_below_in_use() {
+    let memory_manager = MemoryManager::new();
+    let sm = ShmManager::new(memory_manager);
+
+    let owner_pid = 100;
+    let segment_id = sm.create(4096, owner_pid).unwrap();
+    sm.write(segment_id, owner_pid, 2000, &[7u8; 100]).unwrap();
+
+    assert!(matches!(
+        sm.resize(segment_id, owner_pid, 1024),
+        Err(ShmError::ShrinkBelowInUse {
+            requested: 1024,
+            in_use: 2100,
+        })
+    ));
+
+    // Segment is untouched and can still shrink down to the in-use extent
+    assert_eq!(sm.stats(segment_id).unwrap().size, 4096);
+    sm.resize(segment_id, owner_pid, 2100).unwrap();
+    assert_eq!(
+        sm.read(segment_id, owner_pid, 2000, 100).unwrap(),
+        vec![7u8; 100]
+    );
+}
+
+#[test]
+fn test_shm_resize_owner_only() {
+    let memory_manager = MemoryManager::new();
+    let sm = ShmManager::new(memory_manager);
+
+    let segment_id = sm.create(1024, 100).unwrap();
+    sm.attach(segment_id, 200, false).unwrap();
+
+    assert!(matches!(
+        sm.resize(segment_id, 200, 2048),
+        Err(ShmError::PermissionDenied(_))
+    ));
+    assert!(matches!(
+        sm.resize(segment_id, 100, 0),
+        Err(ShmError::InvalidSize(_))
+    ));
+    assert!(matches!(
+        sm.resize(999, 100, 2048),
+        Err(ShmError::NotFound(999))
+    ));
+}
@@ -3,9 +3,13 @@
  * Comprehensive tests for memory allocation, deallocation, and OOM handling
  */
 
-use ai_os_kernel::memory::{MemoryError, MemoryManager};
+use ai_os_kernel::memory::{
+    LargestProcess, MemoryError, MemoryManager, OomPolicy, ProcessMemoryStats, SlabAllocator,
+    OOM_SCORE_ADJ_MIN,
+};
 use pretty_assertions::assert_eq;
 use serial_test::serial;
+use std::time::Duration;
 
 #[test]
 fn test_memory_manager_initialization() {
@@ -90,6 +94,62 @@ fn test_out_of_memory() {
     }
 }
 
+#[test]
+fn test_oom_reclaims_largest_process() {
+    use ai_os_kernel::monitoring::{Collector, Payload};
+    use std::sync::Arc;
+
+    let collector = Arc::new(Collector::new());
+    let mut subscriber = collector.subscribe();
+    let mem_mgr = MemoryManager::with_capacity(1024 * 1024).with_collector(Arc::clone(&collector));
+    let (hog, other, small) = (1, 2, 3);
+
+    mem_mgr.allocate(768 * 1024, hog).unwrap();
+    mem_mgr.allocate(128 * 1024, other).unwrap();
+
+    // Fail fast is the default
+    assert!(matches!(
+        mem_mgr.allocate(256 * 1024, small),
+        Err(MemoryError::OutOfMemory { .. })
+    ));
+    assert_eq!(mem_mgr.process_memory(hog), 768 * 1024);
+
+    // Policy is shared with clones
+    mem_mgr
+        .clone()
+        .set_oom_policy(OomPolicy::reclaim(LargestProcess));
+    mem_mgr.allocate(256 * 1024, small).unwrap();
+
+    assert_eq!(mem_mgr.process_memory(hog), 0);
+    assert_eq!(mem_mgr.process_memory(other), 128 * 1024);
+    assert_eq!(mem_mgr.process_memory(small), 256 * 1024);
+
+    let reclaimed = std::iter::from_fn(|| subscriber.next())
+        .find(|e| matches!(e.payload, Payload::ResourceReclaimed { .. }))
+        .expect("reclaim event");
+    assert_eq!(reclaimed.pid, Some(hog));
+}
+
+#[test]
+fn test_oom_reclaim_retries_once() {
+    let mem_mgr = MemoryManager::with_capacity(1024 * 1024)
+        .with_oom_policy(OomPolicy::reclaim(LargestProcess));
+    mem_mgr.allocate(256 * 1024, 1).unwrap();
+
+    // Reclaiming the only other process wouldn't be enough, so it is spared
+    assert!(matches!(
+        mem_mgr.allocate(2 * 1024 * 1024, 2),
+        Err(MemoryError::OutOfMemory { .. })
+    ));
+    assert_eq!(mem_mgr.process_memory(1), 256 * 1024);
+    mem_mgr.free_process_memory(1);
+
+    // A process is never chosen as its own victim
+    mem_mgr.allocate(512 * 1024, 3).unwrap();
+    assert!(mem_mgr.allocate(768 * 1024, 3).is_err());
+    assert_eq!(mem_mgr.process_memory(3), 512 * 1024);
+}
+
 #[test]
 fn test_oom_after_partial_allocation() {
     let mem_mgr = MemoryManager::new();
@@ -131,6 +191,27 @@ fn test_process_memory_cleanup() {
     assert_eq!(used_after, 0);
 }
 
+#[test]
+fn test_leaked_memory_attributed_by_tag() {
+    let mem_mgr = MemoryManager::new();
+    let pid = 100;
+
+    let addr = mem_mgr.allocate_tagged(1024, pid, "json_buffer").unwrap();
+    mem_mgr.allocate_tagged(4096, pid, "json_buffer").unwrap();
+    mem_mgr.allocate_tagged(512, pid, "pipe_buffer").unwrap();
+    mem_mgr.allocate(256, pid).unwrap();
+
+    let blocks = mem_mgr.process_allocations(pid);
+    let block = blocks.iter().find(|block| block.address == addr).unwrap();
+    assert_eq!(block.tag(), "json_buffer");
+
+    let leaked = mem_mgr.free_process_memory_by_tag(pid);
+    assert_eq!(leaked.get("json_buffer"), Some(&5120));
+    assert_eq!(leaked.get("pipe_buffer"), Some(&512));
+    assert_eq!(leaked.get("untagged"), Some(&256));
+    assert_eq!(mem_mgr.process_memory(pid), 0);
+}
+
 #[test]
 fn test_get_process_memory() {
     let mem_mgr = MemoryManager::new();
@@ -145,6 +226,59 @@ fn test_get_process_memory() {
     assert_eq!(mem_mgr.process_memory(pid2), 20 * 1024 * 1024);
 }
 
+#[test]
+fn test_fork_shared_private_accounting() {
+    const PAGE: usize = 4096;
+    let mem_mgr = MemoryManager::new();
+    let parent = 100;
+    let child = 200;
+
+    for fill in [1u8, 2] {
+        let address = mem_mgr.allocate(PAGE, parent).unwrap();
+        mem_mgr.write_bytes(address, &[fill; PAGE]).unwrap();
+    }
+    assert_eq!(mem_mgr.get_process_sharing_details(parent), (2 * PAGE, 0));
+
+    // A fresh child shares every page with its parent
+    mem_mgr.fork_memory(parent, child);
+    assert_eq!(mem_mgr.get_process_sharing_details(child), (0, 2 * PAGE));
+    assert_eq!(mem_mgr.get_process_sharing_details(parent), (0, 2 * PAGE));
+
+    let stats = mem_mgr.cow_stats();
+    assert_eq!(stats.shared_bytes, 2 * PAGE);
+    assert_eq!(stats.private_bytes, 0);
+
+    // Writing one page makes exactly that page private on both sides
+    let page = mem_mgr.process_allocations(child)[0].address;
+    mem_mgr.write_bytes(page, &[9; 16]).unwrap();
+    assert_eq!(mem_mgr.get_process_sharing_details(child), (PAGE, PAGE));
+    assert_eq!(mem_mgr.get_process_sharing_details(parent), (PAGE, PAGE));
+
+    let stats = mem_mgr.cow_stats();
+    assert_eq!(stats.shared_bytes, PAGE);
+    assert_eq!(stats.private_bytes, 2 * PAGE);
+}
+
+#[test]
+fn test_fork_charges_child_memory() {
+    const PAGE: usize = 4096;
+    let mem_mgr = MemoryManager::new();
+    let parent = 100;
+    let child = 200;
+
+    mem_mgr.allocate(PAGE, parent).unwrap();
+    mem_mgr.allocate(PAGE, parent).unwrap();
+    let before = mem_mgr.stats_consistent().used_memory;
+
+    mem_mgr.fork_memory(parent, child);
+    assert_eq!(mem_mgr.process_memory(child), 2 * PAGE);
+    assert_eq!(mem_mgr.stats_consistent().used_memory, before + 2 * PAGE);
+
+    // Freeing the child returns exactly what the fork charged
+    mem_mgr.free_process_memory(child);
+    assert_eq!(mem_mgr.stats_consistent().used_memory, before);
+}
+
 #[test]
 fn test_invalid_deallocation() {
     let mem_mgr = MemoryManager::new();
@@ -244,6 +378,53 @@ fn test_concurrent_allocations() {
     assert_eq!(used, 10 * 1024 * 1024);
 }
 
+#[test]
+fn test_consistent_stats_under_contention() {
+    use std::sync::atomic::{AtomicBool, Ordering};
+    use std::sync::Arc;
+    use std::thread;
+
+    // Small pool so allocations keep failing and backing out
+    let mem_mgr = Arc::new(MemoryManager::with_capacity(64 * 1024));
+    let stop = Arc::new(AtomicBool::new(false));
+
+    let workers: Vec<_> = (0..4)
+        .map(|i| {
+            let mem_mgr = Arc::clone(&mem_mgr);
+            let stop = Arc::clone(&stop);
+            thread::spawn(move || {
+                let mut held = Vec::new();
+                while !stop.load(Ordering::Relaxed) {
+                    match mem_mgr.allocate(8 * 1024, 100 + i) {
+                        Ok(addr) => held.push(addr),
+                        Err(_) => {
+                            for addr in held.drain(..) {
+                                mem_mgr.deallocate(addr).unwrap();
+                            }
+                        }
+                    }
+                }
+            })
+        })
+        .collect();
+
+    for _ in 0..5_000 {
+        let stats = mem_mgr.stats_consistent();
+        assert!(stats.used_memory <= stats.total_memory);
+        assert_eq!(
+            stats.used_memory + stats.available_memory,
+            stats.total_memory
+        );
+        // Every allocation is the same size, so used tracks the live blocks
+        assert_eq!(stats.used_memory, stats.allocated_blocks * 8 * 1024);
+    }
+
+    stop.store(true, Ordering::Relaxed);
+    for worker in workers {
+        worker.join().unwrap();
+    }
+}
+
 #[test]
 fn test_memory_pressure_thresholds() {
     let mem_mgr = MemoryManager::new();
@@ -257,3 +438,228 @@ fn test_memory_pressure_thresholds() {
     let stats = mem_mgr.stats();
     assert!(stats.usage_percentage > 80.0);
 }
+
+#[test]
+fn test_checkpoint_restore_round_trip() {
+    let mem_mgr = MemoryManager::new();
+    let pid = 100;
+    let restored_pid = 200;
+
+    let small = mem_mgr.allocate(64, pid).unwrap();
+    let large = mem_mgr.allocate(4096, pid).unwrap();
+    let pattern: Vec<u8> = (0..64).map(|i| i as u8).collect();
+    mem_mgr.write_bytes(small, &pattern).unwrap();
+    mem_mgr.write_bytes(large + 100, b"checkpoint").unwrap();
+
+    let snapshot = mem_mgr.checkpoint(pid);
+    assert_eq!(snapshot.source_pid(), pid);
+    assert_eq!(snapshot.block_count(), 2);
+    assert_eq!(snapshot.total_bytes(), 64 + 4096);
+
+    // Mutate the source after the checkpoint
+    mem_mgr.write_bytes(small, &[0xFF; 64]).unwrap();
+    mem_mgr.write_bytes(large + 100, b"mutated!!!").unwrap();
+
+    mem_mgr.restore(&snapshot, restored_pid).unwrap();
+    assert_eq!(mem_mgr.process_memory(restored_pid), 64 + 4096);
+
+    let blocks = mem_mgr.process_allocations(restored_pid);
+    let restored_small = blocks.iter().find(|b| b.size == 64).unwrap().address;
+    let restored_large = blocks.iter().find(|b| b.size == 4096).unwrap().address;
+
+    assert_eq!(mem_mgr.read_bytes(restored_small, 64).unwrap(), pattern);
+    assert_eq!(
+        mem_mgr.read_bytes(restored_large + 100, 10).unwrap(),
+        b"checkpoint".to_vec()
+    );
+
+    // Source keeps its mutations
+    assert_eq!(mem_mgr.read_bytes(small, 64).unwrap(), vec![0xFF; 64]);
+}
+
+#[test]
+fn test_restore_respects_memory_limit() {
+    let mem_mgr = MemoryManager::with_capacity(8192);
+    let pid = 100;
+
+    mem_mgr.allocate(2048, pid).unwrap();
+    mem_mgr.allocate(4096, pid).unwrap();
+    let snapshot = mem_mgr.checkpoint(pid);
+
+    let result = mem_mgr.restore(&snapshot, 200);
+    assert!(matches!(result, Err(MemoryError::OutOfMemory { .. })));
+
+    // Partially restored blocks are rolled back
+    assert_eq!(mem_mgr.process_memory(200), 0);
+    let (_, used, _) = mem_mgr.info();
+    assert_eq!(used, 2048 + 4096);
+}
+
+#[test]
+fn test_default_strategy_is_segregated_free_list() {
+    let mem_mgr = MemoryManager::with_capacity(1024 * 1024);
+    assert_eq!(mem_mgr.stats().allocator_strategy, "segregated_free_list");
+    assert_eq!(mem_mgr.allocator_stats().strategy, "segregated_free_list");
+}
+
+#[test]
+fn test_compact_merges_fragments_across_buckets() {
+    let mem_mgr = MemoryManager::with_capacity(1024 * 1024);
+    let pid = 100;
+
+    // A small and a medium block side by side, pinned by a live neighbour
+    let small = mem_mgr.allocate(100, pid).unwrap();
+    let medium = mem_mgr.allocate(5000, pid).unwrap();
+    let _pinned = mem_mgr.allocate(100, pid).unwrap();
+    assert_eq!(medium, small + 100);
+
+    mem_mgr.deallocate(small).unwrap();
+    mem_mgr.deallocate(medium).unwrap();
+    assert_eq!(mem_mgr.allocator_stats().free_blocks, 2);
+
+    assert_eq!(mem_mgr.compact(), 5100);
+    let stats = mem_mgr.allocator_stats();
+    assert_eq!(stats.free_blocks, 1);
+    assert_eq!(stats.free_bytes, 5100);
+
+    // Nothing left to merge
+    assert_eq!(mem_mgr.compact(), 0);
+    assert_eq!(mem_mgr.allocator_stats().free_blocks, 1);
+
+    // The merged block satisfies an allocation neither fragment could
+    assert_eq!(mem_mgr.allocate(5100, pid).unwrap(), small);
+}
+
+#[test]
+fn test_slab_strategy_recycles_slots() {
+    let mem_mgr = MemoryManager::with_strategy(1024 * 1024, SlabAllocator::new(256));
+    let pid = 100;
+
+    let addrs: Vec<_> = (0..4)
+        .map(|_| mem_mgr.allocate(200, pid).unwrap())
+        .collect();
+    // Fresh slots are carved at the fixed slot size
+    for pair in addrs.windows(2) {
+        assert_eq!(pair[1] - pair[0], 256);
+    }
+
+    mem_mgr.deallocate(addrs[2]).unwrap();
+    assert_eq!(mem_mgr.allocator_stats().free_blocks, 1);
+
+    let reused = mem_mgr.allocate(64, pid).unwrap();
+    assert_eq!(reused, addrs[2]);
+    assert_eq!(mem_mgr.allocator_stats().free_blocks, 0);
+
+    let stats = mem_mgr.stats();
+    assert_eq!(stats.allocator_strategy, "slab");
+    assert_eq!(stats.used_memory, 200 * 3 + 64);
+}
+
+#[test]
+fn test_slab_strategy_rejects_oversized_allocation() {
+    let mem_mgr = MemoryManager::with_strategy(1024 * 1024, SlabAllocator::new(256));
+
+    let result = mem_mgr.allocate(512, 100);
+    assert!(matches!(
+        result,
+        Err(MemoryError::UnsupportedSize {
+            requested: 512,
+            max: 256,
+            ..
+        })
+    ));
+
+    // Rejected allocations are not charged
+    let (_, used, _) = mem_mgr.info();
+    assert_eq!(used, 0);
+}
+
+#[test]
+fn test_slab_strategy_process_cleanup() {
+    let mem_mgr = MemoryManager::with_strategy(1024 * 1024, SlabAllocator::new(128));
+    let pid = 100;
+
+    for _ in 0..10 {
+        mem_mgr.allocate(128, pid).unwrap();
+    }
+    assert_eq!(mem_mgr.free_process_memory(pid), 1280);
+
+    let stats = mem_mgr.allocator_stats();
+    assert_eq!(stats.free_blocks, 10);
+    assert_eq!(stats.free_bytes, 1280);
+}
+
+#[test]
+fn test_stats_diff_reports_signed_deltas_and_rates() {
+    let mem_mgr = MemoryManager::new();
+    let pid = 100;
+
+    let before = mem_mgr.stats();
+    let addresses: Vec<_> = (0..3)
+        .map(|_| mem_mgr.allocate(1000, pid).unwrap())
+        .collect();
+    let after = mem_mgr.stats();
+
+    let delta = before.diff(&after);
+    assert_eq!(delta.total_memory, 0);
+    assert_eq!(delta.used_memory, 3000);
+    assert_eq!(delta.available_memory, -3000);
+    assert_eq!(delta.allocated_blocks, 3);
+    assert_eq!(delta.used_bytes_per_sec(), None);
+
+    let delta = delta.over(Duration::from_secs(2));
+    assert_eq!(delta.used_bytes_per_sec(), Some(1500.0));
+    assert_eq!(delta.blocks_per_sec(), Some(1.5));
+
+    // Freeing shows up as negative deltas
+    mem_mgr.deallocate(addresses[0]).unwrap();
+    let delta = after.diff(&mem_mgr.stats());
+    assert_eq!(delta.used_memory, -1000);
+    assert_eq!(delta.allocated_blocks, -1);
+
+    let earlier = ProcessMemoryStats {
+        allocated_bytes: 4096,
+        peak_bytes: 4096,
+        allocation_count: 4,
+        ..ProcessMemoryStats::new(pid)
+    };
+    let later = ProcessMemoryStats {
+        allocated_bytes: 1024,
+        peak_bytes: 8192,
+        allocation_count: 1,
+        private_bytes: 1024,
+        ..ProcessMemoryStats::new(pid)
+    };
+    let delta = earlier.diff(&later).over(Duration::from_millis(500));
+    assert_eq!(delta.pid, pid);
+    assert_eq!(delta.allocated_bytes, -3072);
+    assert_eq!(delta.peak_bytes, 4096);
+    assert_eq!(delta.allocation_count, -3);
+    assert_eq!(delta.private_bytes, 1024);
+    assert_eq!(delta.allocated_bytes_per_sec(), Some(-6144.0));
+    assert_eq!(delta.allocations_per_sec(), Some(-6.0));
+}
+
+#[test]
+fn test_oom_score_adj_weights_victim_selection() {
+    let mem_mgr = MemoryManager::with_capacity(1024 * 1024)
+        .with_oom_policy(OomPolicy::reclaim(LargestProcess));
+    let (hog, pinned, favoured) = (1, 2, 3);
+    mem_mgr.allocate(512 * 1024, hog).unwrap();
+    mem_mgr.allocate(384 * 1024, pinned).unwrap();
+
+    // The largest process is exempt, and +300 makes the smaller one the victim
+    assert_eq!(mem_mgr.set_oom_score_adj(hog, -2000), OOM_SCORE_ADJ_MIN);
+    assert_eq!(mem_mgr.set_oom_score_adj(pinned, 300), 300);
+    mem_mgr.allocate(256 * 1024, favoured).unwrap();
+    assert_eq!(mem_mgr.process_memory(hog), 512 * 1024);
+    assert_eq!(mem_mgr.process_memory(pinned), 0);
+
+    // With every other process exempt, the allocation fails
+    mem_mgr.set_oom_score_adj(favoured, OOM_SCORE_ADJ_MIN);
+    assert!(mem_mgr.allocate(384 * 1024, 4).is_err());
+    assert_eq!(mem_mgr.process_memory(favoured), 256 * 1024);
+
+    mem_mgr.clear_oom_score_adj(hog);
+    assert_eq!(mem_mgr.oom_score_adj(hog), 0);
+}
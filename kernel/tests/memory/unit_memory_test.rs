@@ -257,3 +257,28 @@ fn test_memory_pressure_thresholds() {
     let stats = mem_mgr.stats();
     assert!(stats.usage_percentage > 80.0);
 }
+
+#[test]
+fn test_gc_threshold_live_reconfiguration() {
+    let mem_mgr = MemoryManager::new();
+    let shared = mem_mgr.clone();
+    let pid = 100;
+
+    assert_eq!(mem_mgr.gc_threshold(), 1000);
+    shared.set_threshold_shared(5);
+    assert_eq!(mem_mgr.gc_threshold(), 5);
+
+    let addresses: Vec<_> = (0..5)
+        .map(|_| mem_mgr.allocate(1024, pid).unwrap())
+        .collect();
+    for &addr in &addresses[..4] {
+        mem_mgr.deallocate(addr).unwrap();
+    }
+    assert_eq!(mem_mgr.deallocated_count(), 4);
+    assert!(!mem_mgr.should_collect());
+
+    // The fifth deallocation reaches the new threshold and collects
+    mem_mgr.deallocate(addresses[4]).unwrap();
+    assert_eq!(mem_mgr.deallocated_count(), 0);
+    assert_eq!(mem_mgr.stats().fragmented_blocks, 0);
+}
@@ -197,6 +197,37 @@ fn test_collector_resource_cleanup() {
     );
 }
 
+#[test]
+fn test_collector_memory_leaked_names_dominant_tag() {
+    let collector = Collector::new();
+    let mut sub = collector.subscribe();
+
+    let mut leaked = std::collections::HashMap::new();
+    leaked.insert("json_buffer".to_string(), 4 * 1024 * 1024);
+    leaked.insert("untagged".to_string(), 1024);
+    collector.memory_leaked(123, &leaked);
+
+    let event = std::iter::from_fn(|| sub.next())
+        .find(|event| matches!(event.payload, Payload::ResourceLeaked { .. }))
+        .expect("leak event");
+    let Payload::ResourceLeaked {
+        resource,
+        count,
+        tag,
+    } = event.payload
+    else {
+        unreachable!();
+    };
+    assert_eq!(resource.as_str(), "memory");
+    assert_eq!(count, 4 * 1024 * 1024 + 1024);
+    assert_eq!(tag.as_deref(), Some("json_buffer"));
+    assert_eq!(event.pid, Some(123));
+
+    // Nothing leaked, nothing reported
+    collector.memory_leaked(124, &std::collections::HashMap::new());
+    assert!(std::iter::from_fn(|| sub.next()).all(|event| event.pid != Some(124)));
+}
+
 #[test]
 fn test_collector_with_errors() {
     let collector = Collector::new();
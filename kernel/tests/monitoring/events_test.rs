@@ -158,6 +158,7 @@ fn test_syscall_event_variants() {
         name: "write".to_string().into(),
         duration_ms: 50,
         threshold_ms: 10,
+        args: None,
     };
 
     // Ensure they can be created
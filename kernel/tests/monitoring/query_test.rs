@@ -163,6 +163,7 @@ fn test_query_duration_stats() {
                 name: "fsync".to_string().into(),
                 duration_ms: 50,
                 threshold_ms: 10,
+                args: None,
             },
         ),
     ];
@@ -0,0 +1,92 @@
+/*!
+ * Trace Filter Tests
+ * Per-process filtering of syscall spans and events
+ */
+
+use ai_os_kernel::monitoring::{
+    set_trace_pids, span_syscall, trace_pids, Category, Collector, Event, Payload, Severity,
+};
+use serial_test::serial;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use tracing::span::{Attributes, Id};
+use tracing_subscriber::layer::{Context, Layer, SubscriberExt};
+
+/// Counts syscall spans created while installed
+struct SyscallSpanCounter(Arc<AtomicUsize>);
+
+impl<S: tracing::Subscriber> Layer<S> for SyscallSpanCounter {
+    fn on_new_span(&self, attrs: &Attributes<'_>, _id: &Id, _ctx: Context<'_, S>) {
+        if attrs.metadata().name() == "syscall" {
+            self.0.fetch_add(1, Ordering::SeqCst);
+        }
+    }
+}
+
+fn count_syscall_spans(f: impl FnOnce()) -> usize {
+    let count = Arc::new(AtomicUsize::new(0));
+    let subscriber = tracing_subscriber::registry().with(SyscallSpanCounter(count.clone()));
+    tracing::subscriber::with_default(subscriber, f);
+    count.load(Ordering::SeqCst)
+}
+
+#[test]
+#[serial]
+fn test_no_span_for_excluded_pids() {
+    set_trace_pids(&[42]);
+    assert_eq!(trace_pids(), vec![42]);
+
+    let spans = count_syscall_spans(|| {
+        let excluded = span_syscall("read", 7);
+        assert!(!excluded.is_traced());
+        assert!(excluded.trace_id().is_empty());
+
+        let included = span_syscall("read", 42);
+        assert!(included.is_traced());
+    });
+    assert_eq!(spans, 1);
+
+    // An empty list traces everyone again
+    set_trace_pids(&[]);
+    assert!(trace_pids().is_empty());
+    let spans = count_syscall_spans(|| {
+        let _span = span_syscall("read", 7);
+    });
+    assert_eq!(spans, 1);
+}
+
+#[test]
+#[serial]
+fn test_collector_drops_syscall_events_for_excluded_pids() {
+    let collector = Collector::new();
+    let mut sub = collector.subscribe();
+
+    set_trace_pids(&[42]);
+    collector.syscall_exit(7, "read".to_string(), 10, true);
+    collector.syscall_exit(42, "write".to_string(), 10, true);
+    // Other categories are unaffected
+    collector.emit(
+        Event::new(
+            Severity::Info,
+            Category::Process,
+            Payload::ProcessCreated {
+                name: "other".into(),
+                priority: 5,
+            },
+        )
+        .with_pid(7),
+    );
+    set_trace_pids(&[]);
+
+    let mut syscall_pids = Vec::new();
+    let mut process_events = 0;
+    while let Some(event) = sub.next() {
+        match event.category {
+            Category::Syscall => syscall_pids.push(event.pid),
+            Category::Process => process_events += 1,
+            _ => {}
+        }
+    }
+    assert_eq!(syscall_pids, vec![Some(42)]);
+    assert_eq!(process_events, 1);
+}
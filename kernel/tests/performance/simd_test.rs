@@ -512,3 +512,119 @@ fn test_performance_comparison() {
     let expected_sum: u64 = numbers.iter().sum();
     assert_eq!(sum, expected_sum);
 }
+
+// ============================================================================
+// Path Selection Statistics
+// ============================================================================
+
+/// The find_byte path this CPU should take for a 1KB haystack
+fn expected_find_path() -> ai_os_kernel::core::SimdPath {
+    use ai_os_kernel::core::SimdPath;
+
+    #[cfg(target_arch = "x86_64")]
+    {
+        if is_x86_feature_detected!("avx512f") && is_x86_feature_detected!("avx512bw") {
+            return SimdPath::Avx512;
+        }
+        if is_x86_feature_detected!("avx2") {
+            return SimdPath::Avx2;
+        }
+        if is_x86_feature_detected!("sse2") {
+            return SimdPath::Sse2;
+        }
+    }
+    SimdPath::Scalar
+}
+
+#[test]
+fn test_find_byte_uses_expected_path() {
+    use ai_os_kernel::core::{set_simd_path_stats_enabled, simd_path_stats};
+
+    // Counters are global and other tests search concurrently, so compare deltas
+    set_simd_path_stats_enabled(true);
+    let path = expected_find_path();
+    let before = simd_path_stats().find_byte.get(path);
+
+    let mut data = vec![0u8; 1024];
+    data[700] = 7;
+    assert_eq!(find_byte(&data, 7), Some(700));
+    // A miss must not fall through to slower paths either
+    assert_eq!(find_byte(&data, 9), None);
+
+    let after = simd_path_stats().find_byte.get(path);
+    assert!(
+        after >= before + 2,
+        "find_byte did not take the {:?} path ({} -> {})",
+        path,
+        before,
+        after
+    );
+}
+
+/// The path_starts_with_any path this CPU should take
+fn expected_prefix_path() -> ai_os_kernel::core::SimdPath {
+    use ai_os_kernel::core::SimdPath;
+
+    #[cfg(target_arch = "x86_64")]
+    {
+        if is_x86_feature_detected!("avx2") {
+            return SimdPath::Avx2;
+        }
+    }
+    SimdPath::Scalar
+}
+
+#[test]
+fn test_policy_path_prefixes_use_simd() {
+    use ai_os_kernel::core::{set_simd_path_stats_enabled, simd_path_stats};
+    use ai_os_kernel::permissions::{EvaluationContext, PermissionRequest, PolicyEngine};
+    use ai_os_kernel::security::{Capability, SandboxConfig};
+    use std::path::PathBuf;
+    use std::time::Instant;
+
+    let mut config = SandboxConfig::minimal(100);
+    config.grant_capability(Capability::ReadFile(None));
+    for tenant in 0..500 {
+        config.allow_path(PathBuf::from(format!("/srv/tenants/t{}", tenant)));
+    }
+    config.block_path(PathBuf::from("/srv/tenants/t13/secrets"));
+
+    let engine = PolicyEngine::new();
+    let ctx = EvaluationContext::new(config);
+    let allowed = |path: &str| {
+        engine
+            .evaluate(
+                &PermissionRequest::file_read(100, PathBuf::from(path)),
+                &ctx,
+            )
+            .is_allowed()
+    };
+
+    set_simd_path_stats_enabled(true);
+    let path = expected_prefix_path();
+    let before = simd_path_stats().path_prefix.get(path);
+
+    let start = Instant::now();
+    assert!(allowed("/srv/tenants/t0/report.txt"));
+    assert!(allowed("/srv/tenants/t499/a/b/c"));
+    // Trailing separators don't matter
+    assert!(allowed("/srv/tenants/t250/"));
+    assert!(allowed("/srv/tenants/t250"));
+    // Matching is per component, not per byte
+    assert!(!allowed("/srv/tenants/t4999/report.txt"));
+    assert!(!allowed("/srv/tenants"));
+    assert!(!allowed("/etc/passwd"));
+    assert!(!allowed("/srv/tenants/t13/secrets/key"));
+    assert!(allowed("/srv/tenants/t13/public"));
+    println!("9 checks against 500 prefixes: {:?}", start.elapsed());
+
+    // The blocked path is rejected before the allowed list is searched
+    let after = simd_path_stats().path_prefix.get(path);
+    assert!(
+        after >= before + 8,
+        "path prefix matching did not take the {:?} path ({} -> {})",
+        path,
+        before,
+        after
+    );
+}
@@ -246,3 +246,303 @@ fn test_concurrent_clone_pid_allocation() {
     );
     assert_eq!(all_pids.len(), 50, "Expected 50 unique PIDs");
 }
+
+#[test]
+fn test_terminate_tree_three_levels() {
+    let mem_mgr = MemoryManager::new();
+    let pm = ProcessManager::builder()
+        .with_memory_manager(mem_mgr.clone())
+        .build();
+
+    let root = pm.create_process("root".to_string(), 5);
+    let child_a = pm
+        .create_child_process(root, "child-a".to_string(), 5, None)
+        .unwrap();
+    let child_b = pm
+        .create_child_process(root, "child-b".to_string(), 5, None)
+        .unwrap();
+    let grandchild = pm
+        .create_child_process(child_a, "grandchild".to_string(), 5, None)
+        .unwrap();
+    let bystander = pm.create_process("bystander".to_string(), 5);
+
+    assert_eq!(pm.get_children(root), vec![child_a, child_b]);
+    assert_eq!(pm.get_children(child_a), vec![grandchild]);
+    assert_eq!(pm.get_parent(grandchild), Some(child_a));
+    assert_eq!(pm.get_child_count(root), 2);
+
+    for pid in [root, child_a, child_b, grandchild, bystander] {
+        mem_mgr.allocate(1024 * 1024, pid).unwrap();
+    }
+
+    let terminated = pm.terminate_tree(root);
+    assert_eq!(terminated.len(), 4);
+    // Children go before their parents
+    let pos = |pid| terminated.iter().position(|&p| p == pid).unwrap();
+    assert!(pos(grandchild) < pos(child_a));
+    assert!(pos(child_a) < pos(root));
+    assert!(pos(child_b) < pos(root));
+
+    for pid in [root, child_a, child_b, grandchild] {
+        assert!(pm.get_process(pid).is_none());
+        assert_eq!(mem_mgr.process_memory(pid), 0);
+        assert!(pm.get_children(pid).is_empty());
+    }
+
+    assert!(pm.get_process(bystander).is_some());
+    assert_eq!(mem_mgr.process_memory(bystander), 1024 * 1024);
+}
+
+#[test]
+fn test_terminate_process_orphans_children() {
+    let pm = ProcessManager::new();
+
+    let parent = pm.create_process("parent".to_string(), 5);
+    let child = pm
+        .create_child_process(parent, "child".to_string(), 5, None)
+        .unwrap();
+
+    assert!(pm.terminate_process(parent));
+    assert!(pm.get_process(child).is_some());
+    assert_eq!(pm.get_parent(child), None);
+
+    assert!(pm
+        .create_child_process(parent, "late".to_string(), 5, None)
+        .is_none());
+}
+
+#[tokio::test]
+async fn test_created_processes_join_the_tree() {
+    use ai_os_kernel::api::handlers::process_handlers::handle_create_process;
+    use ai_os_kernel::api::kernel_proto::CreateProcessRequest;
+    use ai_os_kernel::security::SandboxManager;
+    use tonic::Request;
+
+    let pm = ProcessManager::new();
+    let sandbox_mgr = SandboxManager::new();
+    let parent = pm.create_process("shell".to_string(), 5);
+
+    let create = |parent_pid| {
+        Request::new(CreateProcessRequest {
+            name: "job".to_string(),
+            priority: 5,
+            parent_pid,
+            ..Default::default()
+        })
+    };
+
+    let response = handle_create_process(&pm, &sandbox_mgr, create(Some(parent)))
+        .await
+        .unwrap()
+        .into_inner();
+    assert!(response.success);
+    assert_eq!(pm.get_parent(response.pid), Some(parent));
+    assert_eq!(pm.get_children(parent), vec![response.pid]);
+
+    let response = handle_create_process(&pm, &sandbox_mgr, create(Some(999)))
+        .await
+        .unwrap()
+        .into_inner();
+    assert!(!response.success);
+
+    // Terminating the parent takes its child out of the tree
+    assert_eq!(pm.terminate_tree(parent).len(), 2);
+    assert_eq!(pm.get_child_count(parent), 0);
+}
+
+#[test]
+fn test_resource_history_tracks_growth() {
+    use ai_os_kernel::process::lifecycle::ResourceHistoryConfig;
+
+    let mem_mgr = MemoryManager::new();
+    let pm = ProcessManager::builder()
+        .with_memory_manager(mem_mgr.clone())
+        .with_resource_history(ResourceHistoryConfig::new().with_depth(3))
+        .build();
+
+    let pid = pm.create_process("leaky".to_string(), 5);
+    for _ in 0..4 {
+        mem_mgr.allocate(1024 * 1024, pid).unwrap();
+        pm.sample_resource_usage();
+    }
+
+    let history = pm.resource_history(pid);
+    let memory: Vec<_> = history.iter().map(|(_, u)| u.memory_bytes).collect();
+    assert_eq!(
+        memory,
+        vec![2 * 1024 * 1024, 3 * 1024 * 1024, 4 * 1024 * 1024]
+    );
+    assert_eq!(
+        pm.resource_usage(pid).unwrap().memory_bytes,
+        4 * 1024 * 1024
+    );
+
+    // Termination frees the buffer through resource cleanup
+    pm.terminate_process(pid);
+    assert!(pm.resource_history(pid).is_empty());
+    assert!(pm.resource_usage(pid).is_none());
+}
+
+#[tokio::test]
+async fn test_resource_history_background_sampling() {
+    use ai_os_kernel::process::lifecycle::ResourceHistoryConfig;
+    use std::time::Duration;
+
+    let pm = ProcessManager::builder()
+        .with_memory_manager(MemoryManager::new())
+        .with_resource_history(ResourceHistoryConfig::new().with_interval(Duration::from_millis(5)))
+        .build();
+    let pid = pm.create_process("sampled".to_string(), 5);
+
+    tokio::time::sleep(Duration::from_millis(60)).await;
+    assert!(pm.resource_history(pid).len() >= 2);
+
+    // Without history enabled there are no samples
+    let plain = ProcessManager::new();
+    let other = plain.create_process("plain".to_string(), 5);
+    plain.sample_resource_usage();
+    assert!(plain.resource_history(other).is_empty());
+}
+
+#[tokio::test]
+async fn test_pause_and_resume_process() {
+    use ai_os_kernel::SchedulingPolicy;
+
+    let pm = ProcessManager::builder()
+        .with_scheduler(SchedulingPolicy::RoundRobin)
+        .build();
+    let paused = pm.create_process("paused".to_string(), 5);
+    let other = pm.create_process("other".to_string(), 5);
+
+    pm.pause_process(paused).unwrap();
+    assert_eq!(pm.get_process(paused).unwrap().state, ProcessState::Paused);
+    assert!(pm.pause_process(paused).is_err());
+
+    for _ in 0..5 {
+        assert_eq!(pm.schedule_next(), Some(other));
+        pm.yield_current();
+    }
+
+    pm.resume_process(paused).unwrap();
+    assert_eq!(pm.get_process(paused).unwrap().state, ProcessState::Ready);
+    assert!(pm.resume_process(paused).is_err());
+
+    // Paused processes can still be terminated
+    pm.pause_process(paused).unwrap();
+    assert!(pm.terminate_process(paused));
+    assert!(pm.get_process_stats(paused).is_none());
+}
+
+#[tokio::test]
+async fn test_freeze_waits_for_in_flight_syscalls() {
+    use ai_os_kernel::process::FreezeState;
+    use ai_os_kernel::SchedulingPolicy;
+    use std::time::Duration;
+
+    let pm = ProcessManager::builder()
+        .with_scheduler(SchedulingPolicy::RoundRobin)
+        .build();
+    let pid = pm.create_process("migrating".to_string(), 5);
+
+    // A syscall is already running when the freeze starts
+    let in_flight = pm.enter_syscall(pid).unwrap();
+    assert_eq!(
+        pm.freeze(pid).unwrap(),
+        FreezeState::Draining { in_flight: 1 }
+    );
+    assert_eq!(pm.get_process(pid).unwrap().state, ProcessState::Paused);
+    assert!(pm.enter_syscall(pid).is_none());
+    assert!(pm.freeze(pid).is_err());
+
+    // Not quiescent until the running syscall finishes
+    assert_eq!(
+        pm.wait_frozen(pid, Duration::from_millis(20)).unwrap(),
+        FreezeState::Draining { in_flight: 1 }
+    );
+    let finisher = std::thread::spawn(move || {
+        std::thread::sleep(Duration::from_millis(10));
+        drop(in_flight);
+    });
+    assert!(pm
+        .wait_frozen(pid, Duration::from_secs(5))
+        .unwrap()
+        .is_quiescent());
+    assert_eq!(pm.freeze_state(pid), Some(FreezeState::Frozen));
+    finisher.join().unwrap();
+
+    pm.thaw(pid).unwrap();
+    assert_eq!(pm.get_process(pid).unwrap().state, ProcessState::Ready);
+    assert_eq!(pm.freeze_state(pid), None);
+    assert!(pm.enter_syscall(pid).is_some());
+    assert!(pm.thaw(pid).is_err());
+}
+
+#[test]
+fn test_recycled_pids_carry_distinct_generations() {
+    use ai_os_kernel::process::RecyclingPidAllocator;
+    use std::sync::Arc;
+
+    let pm = ProcessManager::builder()
+        .with_pid_allocator(Arc::new(RecyclingPidAllocator::new()))
+        .build();
+
+    let first = pm.create_process("first".to_string(), 5);
+    let second = pm.create_process("second".to_string(), 5);
+    assert_eq!((first, second), (1, 2));
+    let stale = pm.get_process(first).unwrap().generation;
+    assert_eq!(stale, 0);
+
+    assert!(pm.terminate_process(first));
+    let reused = pm.create_process("reused".to_string(), 5);
+    assert_eq!(reused, first);
+
+    // Same pid number, new generation: the old reference is detectably stale
+    let process = pm.get_process(reused).unwrap();
+    assert_eq!(process.name, "reused");
+    assert_eq!(process.generation, 1);
+    assert!(!pm.is_current(first, stale));
+    assert!(pm.is_current(reused, process.generation));
+
+    assert!(pm.terminate_process(reused));
+    assert_eq!(pm.create_process("again".to_string(), 5), first);
+    assert_eq!(pm.get_process(first).unwrap().generation, 2);
+    assert_eq!(pm.create_process("fresh".to_string(), 5), 3);
+}
+
+#[test]
+fn test_monotonic_pids_are_never_reused() {
+    let pm = ProcessManager::new();
+
+    let pid = pm.create_process("short-lived".to_string(), 5);
+    assert!(pm.terminate_process(pid));
+
+    let next = pm.create_process("next".to_string(), 5);
+    assert_eq!(next, pid + 1);
+    assert_eq!(pm.get_process(next).unwrap().generation, 0);
+}
+
+#[test]
+fn test_enforce_denial_escalations() {
+    use ai_os_kernel::permissions::{Escalation, EscalationKind};
+    use std::time::Duration;
+
+    let pm = ProcessManager::new();
+    let throttled = pm.create_process("noisy".to_string(), 8);
+    let killed = pm.create_process("hostile".to_string(), 5);
+    let escalation = |pid, kind| Escalation {
+        pid,
+        denials: 20,
+        window: Duration::from_secs(60),
+        kind,
+    };
+
+    assert!(pm.enforce_escalation(&escalation(throttled, EscalationKind::Throttle)));
+    assert_eq!(pm.get_process(throttled).unwrap().priority, 0);
+
+    assert!(pm.enforce_escalation(&escalation(killed, EscalationKind::Kill)));
+    assert!(pm.get_process(killed).is_none());
+
+    // Alerts are handled by the audit log itself
+    assert!(!pm.enforce_escalation(&escalation(throttled, EscalationKind::Alert)));
+    assert!(!pm.enforce_escalation(&escalation(killed, EscalationKind::Kill)));
+}
@@ -40,6 +40,7 @@ fn cleanup(&self, _pid: Pid) -> CleanupStats {
             errors_encountered: 0,
             cleanup_duration_micros: 0,
             by_type: std::collections::HashMap::new(),
+            leaked_by_tag: std::collections::HashMap::new(),
         }
     }
 
@@ -152,6 +153,7 @@ fn cleanup(&self, _pid: Pid) -> CleanupStats {
                 errors_encountered: 3,
                 cleanup_duration_micros: 0,
                 by_type: std::collections::HashMap::new(),
+                leaked_by_tag: std::collections::HashMap::new(),
             }
         }
 
@@ -283,3 +285,43 @@ fn test_cleanup_timing() {
     // Should have per-type breakdown
     assert_eq!(result.stats.by_type.get("timed"), Some(&5));
 }
+
+#[test]
+fn test_terminate_clears_security_state_before_pid_reuse() {
+    use ai_os_kernel::ipc::{PipeManager, ShmManager};
+    use ai_os_kernel::memory::MemoryManager;
+    use ai_os_kernel::process::resources::SecurityResource;
+    use ai_os_kernel::process::RecyclingPidAllocator;
+    use ai_os_kernel::security::traits::SandboxProvider;
+    use ai_os_kernel::security::{SandboxConfig, SandboxManager};
+    use ai_os_kernel::syscalls::{CreditBudget, Syscall, SyscallExecutorWithIpc};
+    use ai_os_kernel::ProcessManager;
+
+    let memory_manager = MemoryManager::new();
+    let sandbox_manager = SandboxManager::new();
+    let executor = SyscallExecutorWithIpc::with_ipc_direct(
+        sandbox_manager.clone(),
+        PipeManager::new(memory_manager.clone()),
+        ShmManager::new(memory_manager),
+    )
+    .build();
+    let pm = ProcessManager::builder()
+        .with_pid_allocator(Arc::new(RecyclingPidAllocator::new()))
+        .with_resource_orchestrator(
+            ResourceOrchestrator::new().register(SecurityResource::new(executor.clone())),
+        )
+        .build();
+
+    let pid = pm.create_process("first".to_string(), 5);
+    sandbox_manager.create_sandbox(SandboxConfig::privileged(pid));
+    executor.set_syscall_allowlist(pid, &[Syscall::GetUptime]);
+    executor.set_syscall_budget(pid, CreditBudget::new(10, 1));
+    assert!(pm.terminate_process(pid));
+
+    // The recycled pid starts with none of the previous holder's grants
+    let reused = pm.create_process("second".to_string(), 5);
+    assert_eq!(reused, pid);
+    assert!(!sandbox_manager.has_sandbox(reused));
+    assert!(!executor.syscall_allowlist().is_restricted(reused));
+    assert!(executor.syscall_credits().budget(reused).is_none());
+}
@@ -6,7 +6,7 @@
     Action, PermissionChecker, PermissionManager, PermissionRequest, Resource,
 };
 use ai_os_kernel::security::{
-    Capability, NetworkRule, SandboxConfig, SandboxManager, SandboxProvider,
+    Capability, CapabilityClass, NetworkRule, SandboxConfig, SandboxManager, SandboxProvider,
 };
 use std::path::PathBuf;
 
@@ -244,3 +244,23 @@ fn test_granular_file_permissions() {
     let etc_req = PermissionRequest::file_read(800, PathBuf::from("/etc/passwd"));
     assert!(!manager.check(&etc_req).is_allowed());
 }
+
+#[test]
+fn test_revoke_invalidates_cached_decisions() {
+    let sandbox = SandboxManager::new();
+    sandbox.create_sandbox(SandboxConfig::minimal(100));
+    let manager = PermissionManager::new(sandbox.clone());
+    let request = PermissionRequest::proc_kill(100, 200);
+
+    assert!(!manager.check(&request).is_allowed());
+
+    // The cached denial must not outlive the grant
+    sandbox.grant_all(100, CapabilityClass::Process).unwrap();
+    assert!(manager.check(&request).is_allowed());
+    assert!(manager.check(&request).cached);
+
+    sandbox.revoke(100, &Capability::KillProcess).unwrap();
+    let response = manager.check(&request);
+    assert!(!response.is_allowed());
+    assert!(!response.cached);
+}
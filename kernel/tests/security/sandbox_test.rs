@@ -4,7 +4,8 @@
  */
 
 use ai_os_kernel::security::{
-    Capability, CapabilityManager, NetworkRule, SandboxConfig, SandboxManager, SandboxProvider,
+    Capability, CapabilityClass, CapabilityManager, NetworkRule, SandboxConfig, SandboxError,
+    SandboxManager, SandboxProvider,
 };
 use std::path::PathBuf;
 
@@ -165,6 +166,27 @@ fn test_path_access_with_blocked_paths() {
     );
 }
 
+#[test]
+fn test_path_access_follows_sandbox_updates() {
+    let manager = SandboxManager::new();
+    let pid = 113;
+    let temp_dir = std::env::temp_dir();
+    let data = temp_dir.join("data");
+
+    let mut config = SandboxConfig::minimal(pid);
+    config.allow_path(temp_dir.clone());
+    manager.create_sandbox(config.clone());
+    assert!(manager.check_path_access(pid, &data));
+
+    config.block_path(data.clone());
+    assert!(manager.update_sandbox(pid, config));
+    assert!(!manager.check_path_access(pid, &data));
+    assert!(manager.check_path_access(pid, &temp_dir.join("other")));
+
+    manager.remove_sandbox(pid);
+    assert!(!manager.check_path_access(pid, &temp_dir.join("other")));
+}
+
 #[test]
 fn test_network_rule_allow_all() {
     let manager = SandboxManager::new();
@@ -454,3 +476,425 @@ fn test_empty_allowed_paths_denies_all() {
         "Empty allowed_paths should deny even root"
     );
 }
+
+#[test]
+fn test_grant_all_then_revoke() {
+    let manager = SandboxManager::new();
+    let pid = 100;
+    manager.create_sandbox(SandboxConfig::minimal(pid));
+    let baseline = manager.stats().granted_capabilities;
+
+    manager.grant_all(pid, CapabilityClass::Network).unwrap();
+    assert_eq!(
+        manager.stats().granted_capabilities,
+        baseline + CapabilityClass::Network.capabilities().len()
+    );
+    assert!(manager.check_permission(pid, &Capability::BindPort(Some(8080))));
+    assert!(manager.check_permission(
+        pid,
+        &Capability::NetworkAccess(NetworkRule::AllowHost {
+            host: "example.com".into(),
+            port: Some(443),
+        })
+    ));
+
+    // Revoking the wildcard port grant also denies specific ports
+    assert!(manager.revoke(pid, &Capability::BindPort(None)).unwrap());
+    assert!(!manager.check_permission(pid, &Capability::BindPort(Some(8080))));
+    assert!(!manager.revoke(pid, &Capability::BindPort(None)).unwrap());
+    assert_eq!(
+        manager.stats().granted_capabilities,
+        baseline + CapabilityClass::Network.capabilities().len() - 1
+    );
+
+    assert!(manager.grant_all(999, CapabilityClass::Ipc).is_err());
+}
+
+#[test]
+fn test_priority_ceiling() {
+    use ai_os_kernel::monitoring::{Collector, Payload};
+    use ai_os_kernel::MAX_PRIORITY;
+    use std::sync::Arc;
+
+    let collector = Arc::new(Collector::new());
+    let mut subscriber = collector.subscribe();
+    let manager = SandboxManager::new().with_collector(Arc::clone(&collector));
+    let pid = 100;
+
+    // Unsandboxed and freshly sandboxed processes are uncapped
+    assert_eq!(manager.priority_ceiling(pid), MAX_PRIORITY);
+    assert!(manager.set_priority_ceiling(pid, 4).is_err());
+    manager.create_sandbox(SandboxConfig::minimal(pid));
+    assert_eq!(manager.clamp_priority(pid, MAX_PRIORITY), Ok(MAX_PRIORITY));
+
+    manager.set_priority_ceiling(pid, 4).unwrap();
+    assert_eq!(manager.priority_ceiling(pid), 4);
+    assert_eq!(manager.clamp_priority(pid, 9), Ok(4));
+    assert_eq!(manager.clamp_priority(pid, 2), Ok(2));
+    assert!(manager.clamp_priority(pid, MAX_PRIORITY + 1).is_err());
+
+    // Only the clamped request is reported
+    let violations: Vec<_> = std::iter::from_fn(|| subscriber.next())
+        .filter(|e| matches!(e.payload, Payload::SecurityViolation { .. }))
+        .collect();
+    assert_eq!(violations.len(), 1);
+    assert_eq!(violations[0].pid, Some(pid));
+}
+
+#[test]
+fn test_delegation_lifecycle_cascades_revocation() {
+    use ai_os_kernel::monitoring::{Collector, Payload};
+    use ai_os_kernel::security::DelegationConstraints;
+    use std::sync::Arc;
+
+    let collector = Arc::new(Collector::new());
+    let mut subscriber = collector.subscribe();
+    let manager = SandboxManager::new().with_collector(Arc::clone(&collector));
+    let (supervisor, worker, helper) = (100, 101, 102);
+
+    let mut config = SandboxConfig::minimal(supervisor);
+    config.grant_capability(Capability::ReadFile(Some(PathBuf::from("/data"))));
+    manager.create_sandbox(config);
+    manager.create_sandbox(SandboxConfig::minimal(worker));
+    manager.create_sandbox(SandboxConfig::minimal(helper));
+
+    let reports = Capability::ReadFile(Some(PathBuf::from("/data/reports/q3.csv")));
+    let secrets = Capability::ReadFile(Some(PathBuf::from("/data/secrets")));
+
+    // Only what the source holds can be delegated
+    assert!(manager
+        .delegate(
+            supervisor,
+            worker,
+            &Capability::SpawnProcess,
+            DelegationConstraints::new()
+        )
+        .is_err());
+
+    // Hand the worker a narrower subtree it may pass on
+    let granted = manager
+        .delegate(
+            supervisor,
+            worker,
+            &Capability::ReadFile(None),
+            DelegationConstraints::new()
+                .with_path("/data/reports")
+                .with_redelegation(),
+        )
+        .unwrap();
+    assert_eq!(
+        granted,
+        Capability::ReadFile(Some(PathBuf::from("/data/reports")))
+    );
+    assert!(manager.check_permission(worker, &reports));
+    assert!(!manager.check_permission(worker, &secrets));
+
+    // The worker passes it on, but the helper may not go further
+    manager
+        .delegate(worker, helper, &granted, DelegationConstraints::new())
+        .unwrap();
+    assert!(manager.check_permission(helper, &reports));
+    assert_eq!(
+        manager.delegation_chain(helper, &reports),
+        vec![supervisor, worker, helper]
+    );
+    assert!(manager
+        .delegate(helper, worker, &granted, DelegationConstraints::new())
+        .is_err());
+
+    // Revoking at the root cascades down the chain
+    assert!(manager
+        .revoke(supervisor, &Capability::ReadFile(None))
+        .unwrap());
+    assert!(!manager.check_permission(supervisor, &reports));
+    assert!(!manager.check_permission(worker, &reports));
+    assert!(!manager.check_permission(helper, &reports));
+    assert_eq!(manager.delegation_chain(helper, &reports), vec![helper]);
+
+    let events: Vec<_> = std::iter::from_fn(|| subscriber.next()).collect();
+    let delegated = events
+        .iter()
+        .filter(|e| matches!(e.payload, Payload::CapabilityDelegated { .. }))
+        .count();
+    let cascaded: Vec<_> = events
+        .iter()
+        .filter(|e| matches!(e.payload, Payload::CapabilityRevoked { cascaded: true, .. }))
+        .map(|e| e.pid)
+        .collect();
+    assert_eq!(delegated, 2);
+    assert_eq!(cascaded, vec![Some(worker), Some(helper)]);
+}
+
+#[test]
+fn test_cascade_keeps_independent_grants() {
+    use ai_os_kernel::security::DelegationConstraints;
+
+    let manager = SandboxManager::new();
+    let (supervisor, worker, auditor, intern) = (100, 101, 102, 103);
+    let data = Capability::ReadFile(Some(PathBuf::from("/data")));
+
+    for pid in [supervisor, auditor] {
+        let mut config = SandboxConfig::minimal(pid);
+        config.grant_capability(data.clone());
+        manager.create_sandbox(config);
+    }
+    manager.create_sandbox(SandboxConfig::minimal(worker));
+    manager.create_sandbox(SandboxConfig::minimal(intern));
+
+    // The auditor already held the grant, and the worker is granted it
+    // directly after the delegation; only the intern depends on it
+    for pid in [worker, auditor, intern] {
+        manager
+            .delegate(supervisor, pid, &data, DelegationConstraints::new())
+            .unwrap();
+    }
+    manager.grant_capability(worker, data.clone()).unwrap();
+
+    manager.revoke(supervisor, &data).unwrap();
+    assert!(!manager.check_permission(supervisor, &data));
+    assert!(manager.check_permission(worker, &data));
+    assert!(manager.check_permission(auditor, &data));
+    assert!(!manager.check_permission(intern, &data));
+}
+
+#[test]
+fn test_oom_score_floor() {
+    use ai_os_kernel::memory::{OOM_SCORE_ADJ_MAX, OOM_SCORE_ADJ_MIN};
+    use ai_os_kernel::monitoring::{Collector, Payload};
+    use std::sync::Arc;
+
+    let collector = Arc::new(Collector::new());
+    let mut subscriber = collector.subscribe();
+    let manager = SandboxManager::new().with_collector(Arc::clone(&collector));
+    let pid = 100;
+
+    // Unsandboxed and privileged processes are unbounded
+    assert_eq!(manager.oom_score_floor(pid), OOM_SCORE_ADJ_MIN);
+    assert!(manager.set_oom_score_floor(pid, 0).is_err());
+    manager.create_sandbox(SandboxConfig::privileged(pid));
+    assert_eq!(
+        manager.clamp_oom_score(pid, OOM_SCORE_ADJ_MIN),
+        OOM_SCORE_ADJ_MIN
+    );
+
+    // Unprivileged processes can't lower their adjustment below zero
+    manager.create_sandbox(SandboxConfig::minimal(pid));
+    assert_eq!(manager.oom_score_floor(pid), 0);
+    assert_eq!(manager.clamp_oom_score(pid, OOM_SCORE_ADJ_MIN), 0);
+
+    manager.set_oom_score_floor(pid, -200).unwrap();
+    assert_eq!(manager.clamp_oom_score(pid, -900), -200);
+    assert_eq!(manager.clamp_oom_score(pid, -50), -50);
+    assert_eq!(manager.clamp_oom_score(pid, i16::MAX), OOM_SCORE_ADJ_MAX);
+
+    // Only the requests below the floor are reported
+    let violations: Vec<_> = std::iter::from_fn(|| subscriber.next())
+        .filter(|e| matches!(e.payload, Payload::SecurityViolation { .. }))
+        .collect();
+    assert_eq!(violations.len(), 2);
+    assert_eq!(violations[0].pid, Some(pid));
+}
+
+/// Problems reported by `validate`, as `(kind, message)` sorted for comparison
+fn validation_errors(config: &SandboxConfig) -> Vec<(&'static str, String)> {
+    let mut errors: Vec<_> = config
+        .validate()
+        .unwrap_err()
+        .into_iter()
+        .map(|e| match e {
+            SandboxError::Contradiction(msg) => ("contradiction", msg.to_string()),
+            SandboxError::UnreachableRule(msg) => ("unreachable", msg.to_string()),
+            SandboxError::MissingCapability(msg) => ("missing", msg.to_string()),
+            other => panic!("unexpected validation error: {:?}", other),
+        })
+        .collect();
+    errors.sort();
+    errors
+}
+
+#[test]
+fn test_builtin_configs_validate() {
+    assert!(SandboxConfig::minimal(1).validate().is_ok());
+    assert!(SandboxConfig::standard(1).validate().is_ok());
+    assert!(SandboxConfig::privileged(1).validate().is_ok());
+}
+
+#[test]
+fn test_validate_reports_contradictions() {
+    let mut config = SandboxConfig::minimal(1);
+    config.allowed_paths = vec![PathBuf::from("/srv/app")];
+    config.blocked_paths = vec![PathBuf::from("/srv/app")];
+    config.network_rules.push(NetworkRule::AllowHost {
+        host: "api.example.com".into(),
+        port: Some(443),
+    });
+    config.network_rules.push(NetworkRule::BlockHost {
+        host: "api.example.com".into(),
+        port: Some(443),
+    });
+    assert_eq!(
+        validation_errors(&config),
+        vec![
+            (
+                "contradiction",
+                "/srv/app is both allowed and blocked".to_string()
+            ),
+            (
+                "contradiction",
+                "host api.example.com is both allowed and blocked".to_string()
+            ),
+        ]
+    );
+
+    // Deny-all rules next to allow rules
+    let mut config = SandboxConfig::minimal(1);
+    config.allowed_paths = vec![PathBuf::from("/srv/app")];
+    config.blocked_paths = vec![PathBuf::from("/")];
+    config.network_rules = vec![
+        NetworkRule::BlockHost {
+            host: "*".into(),
+            port: None,
+        },
+        NetworkRule::AllowAll,
+    ];
+    assert_eq!(
+        validation_errors(&config),
+        vec![
+            (
+                "contradiction",
+                "/srv/app is allowed but / is blocked".to_string()
+            ),
+            (
+                "contradiction",
+                "AllowAll is allowed but every host is blocked".to_string()
+            ),
+        ]
+    );
+}
+
+#[test]
+fn test_validate_reports_unreachable_rules() {
+    let mut config = SandboxConfig::minimal(1);
+    config.allowed_paths = vec![PathBuf::from("/srv/app/public")];
+    config.blocked_paths = vec![PathBuf::from("/srv/app")];
+    config.grant_capability(Capability::ReadFile(Some(PathBuf::from("/srv/other"))));
+    config.grant_capability(Capability::WriteFile(Some(PathBuf::from(
+        "/srv/app/uploads",
+    ))));
+    config.network_rules = vec![
+        NetworkRule::BlockHost {
+            host: "*.example.com".into(),
+            port: None,
+        },
+        NetworkRule::AllowHost {
+            host: "api.example.com".into(),
+            port: Some(443),
+        },
+    ];
+
+    assert_eq!(
+        validation_errors(&config),
+        vec![
+            (
+                "unreachable",
+                "ReadFile(Some(\"/srv/other\")) is scoped outside every allowed path".to_string()
+            ),
+            (
+                "unreachable",
+                "WriteFile(Some(\"/srv/app/uploads\")) is scoped to a blocked path".to_string()
+            ),
+            (
+                "unreachable",
+                "allowed host api.example.com is covered by a broader block".to_string()
+            ),
+            (
+                "unreachable",
+                "allowed path /srv/app/public lies under a blocked path".to_string()
+            ),
+        ]
+    );
+
+    // A scope that contains an allowed path is still reachable
+    let mut config = SandboxConfig::minimal(1);
+    config.allowed_paths = vec![PathBuf::from("/srv/app/public")];
+    config.grant_capability(Capability::ReadFile(Some(PathBuf::from("/srv/app"))));
+    assert!(config.validate().is_ok());
+}
+
+#[test]
+fn test_validate_reports_missing_capabilities() {
+    let mut config = SandboxConfig::minimal(1);
+    config.grant_capability(Capability::BindPort(Some(8080)));
+    assert_eq!(
+        validation_errors(&config),
+        vec![(
+            "missing",
+            "NetworkAccess (required by BindPort(Some(8080)))".to_string()
+        )]
+    );
+
+    config.grant_capability(Capability::NetworkAccess(NetworkRule::AllowAll));
+    assert!(config.validate().is_ok());
+}
+
+#[test]
+fn test_manager_rejects_invalid_config() {
+    let manager = SandboxManager::new();
+    let pid = 112;
+
+    let mut config = SandboxConfig::minimal(pid);
+    config.allowed_paths = vec![PathBuf::from("/srv/app")];
+    config.blocked_paths = vec![PathBuf::from("/srv/app")];
+    config.grant_capability(Capability::BindPort(None));
+
+    let errors = manager.try_create_sandbox(config.clone()).unwrap_err();
+    assert_eq!(errors.len(), 2);
+    assert!(!manager.has_sandbox(pid));
+
+    // The infallible entry point rejects it too
+    manager.create_sandbox(config);
+    assert!(!manager.has_sandbox(pid));
+
+    manager
+        .try_create_sandbox(SandboxConfig::standard(pid))
+        .unwrap();
+    assert!(manager.has_sandbox(pid));
+
+    // Updates are validated and keep the current sandbox on rejection
+    let errors = manager.try_update_sandbox(pid, config.clone()).unwrap_err();
+    assert_eq!(errors.len(), 2);
+    assert!(!manager.update_sandbox(pid, config.clone()));
+    assert_eq!(
+        manager.get_sandbox(pid).unwrap().allowed_paths,
+        SandboxConfig::standard(pid).allowed_paths
+    );
+
+    // Re-registering an invalid config drops the old sandbox
+    assert!(manager.try_create_sandbox(config).is_err());
+    assert!(!manager.has_sandbox(pid));
+}
+
+#[test]
+fn test_runtime_path_changes_are_enforced() {
+    use ai_os_kernel::security::PathAccessControl;
+
+    let manager = SandboxManager::new();
+    let pid = 300;
+    let mut config = SandboxConfig::minimal(pid);
+    config.allow_path(PathBuf::from("/tmp"));
+    manager.create_sandbox(config);
+
+    let secret = PathBuf::from("/tmp/secret/key");
+    assert!(manager.check_path_access(pid, &secret));
+
+    manager
+        .block_path(pid, PathBuf::from("/tmp/secret"))
+        .unwrap();
+    assert!(!manager.check_path_access(pid, &secret));
+    assert!(manager.check_path_access(pid, &PathBuf::from("/tmp/other")));
+
+    let logs = PathBuf::from("/var/log/app.log");
+    assert!(!manager.check_path_access(pid, &logs));
+    manager.allow_path(pid, PathBuf::from("/var/log")).unwrap();
+    assert!(manager.check_path_access(pid, &logs));
+}
@@ -1324,3 +1324,57 @@ fn test_mixed_signals_with_callbacks() {
     // SIGUSR2 was ignored, so only 2 signals handled
     assert_eq!(handled_signals.len(), 2);
 }
+
+#[test]
+fn test_interruptible_sleep_wakes_on_signal() {
+    use std::time::{Duration, Instant};
+
+    let manager = SignalManagerImpl::new();
+    let sender: Pid = 1;
+    let target: Pid = 2;
+    manager.initialize_process(target).unwrap();
+
+    let sender_manager = manager.clone();
+    let signaller = std::thread::spawn(move || {
+        std::thread::sleep(Duration::from_millis(50));
+        sender_manager
+            .send(sender, target, Signal::SIGUSR1)
+            .unwrap();
+    });
+
+    let start = Instant::now();
+    let remaining = manager
+        .interruptible_sleep(target, Duration::from_secs(5))
+        .expect("sleep should be interrupted");
+    signaller.join().unwrap();
+
+    assert!(start.elapsed() < Duration::from_secs(2));
+    assert!(remaining > Duration::from_secs(3));
+    assert!(manager.has_pending(target));
+}
+
+#[test]
+fn test_interruptible_sleep_ignores_masked_signal() {
+    use std::time::{Duration, Instant};
+
+    let manager = SignalManagerImpl::new();
+    let sender: Pid = 1;
+    let target: Pid = 2;
+    manager.initialize_process(target).unwrap();
+    manager.block_signal(target, Signal::SIGUSR1).unwrap();
+
+    let sender_manager = manager.clone();
+    let signaller = std::thread::spawn(move || {
+        std::thread::sleep(Duration::from_millis(20));
+        assert!(sender_manager
+            .send(sender, target, Signal::SIGUSR1)
+            .is_err());
+    });
+
+    let start = Instant::now();
+    let remaining = manager.interruptible_sleep(target, Duration::from_millis(200));
+    signaller.join().unwrap();
+
+    assert_eq!(remaining, None);
+    assert!(start.elapsed() >= Duration::from_millis(200));
+}
@@ -63,6 +63,7 @@ async fn test_async_handler_network_request() {
     let handler = TestAsyncHandler;
     let syscall = Syscall::NetworkRequest {
         url: "http://example.com".to_string(),
+        retry: None,
     };
 
     let result = handler.handle_async(1, &syscall).await;
@@ -115,6 +116,7 @@ async fn test_async_handler_registry_dispatch() {
 
     let syscall = Syscall::NetworkRequest {
         url: "http://example.com".to_string(),
+        retry: None,
     };
 
     let result = registry.dispatch(1, &syscall).await;
@@ -133,6 +135,7 @@ async fn test_async_handler_registry_multiple_handlers() {
     // Test first handler
     let syscall1 = Syscall::NetworkRequest {
         url: "http://example.com".to_string(),
+        retry: None,
     };
     let result1 = registry.dispatch(1, &syscall1).await;
     assert!(result1.is_some());
@@ -170,6 +173,7 @@ async fn test_async_handler_concurrent_requests() {
         let task = tokio::spawn(async move {
             let syscall = Syscall::NetworkRequest {
                 url: format!("http://example.com/{}", i),
+                retry: None,
             };
             reg.dispatch(i as Pid, &syscall).await
         });
@@ -212,6 +216,7 @@ fn name(&self) -> &'static str {
 
     let syscall = Syscall::NetworkRequest {
         url: "http://example.com".to_string(),
+        retry: None,
     };
 
     // Use timeout to avoid hanging
@@ -251,13 +256,14 @@ fn name(&self) -> &'static str {
 
     let syscall = Syscall::NetworkRequest {
         url: "http://example.com".to_string(),
+        retry: None,
     };
 
     let result = registry.dispatch(1, &syscall).await;
 
     assert!(result.is_some());
     match result.unwrap() {
-        SyscallResult::Error { message } => {
+        SyscallResult::Error { message, .. } => {
             assert_eq!(message, "Network error");
         }
         _ => panic!("Expected error"),
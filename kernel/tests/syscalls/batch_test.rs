@@ -3,11 +3,12 @@
  * Tests for batch syscall execution (parallel and sequential)
  */
 
-use ai_os_kernel::api::execution::BatchExecutor;
+use ai_os_kernel::api::execution::{AdaptiveBatchConfig, BatchExecutor};
 use ai_os_kernel::security::traits::SandboxProvider;
 use ai_os_kernel::security::{SandboxConfig, SandboxManager};
 use ai_os_kernel::syscalls::{Syscall, SyscallExecutorWithIpc, SyscallResult};
 use std::fs;
+use std::time::{Duration, Instant};
 use tempfile::TempDir;
 
 fn setup_test_env() -> (SyscallExecutorWithIpc, SandboxManager, TempDir, u32) {
@@ -222,3 +223,82 @@ async fn test_batch_read_write_sequence() {
         _ => panic!("Read should succeed"),
     }
 }
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_adaptive_batch_single_submission_latency() {
+    let (executor, _, temp_dir, pid) = setup_test_env();
+    // A window this long would dominate latency if an idle queue waited on it
+    let batch_executor = BatchExecutor::with_config(
+        executor,
+        AdaptiveBatchConfig {
+            max_window: Duration::from_millis(250),
+            ..Default::default()
+        },
+    );
+
+    let path = temp_dir.path().join("probe.txt");
+    fs::write(&path, b"probe").unwrap();
+
+    for _ in 0..5 {
+        let start = Instant::now();
+        let result = batch_executor
+            .submit(pid, Syscall::FileExists { path: path.clone() })
+            .await;
+        let latency = start.elapsed();
+
+        assert!(matches!(result, SyscallResult::Success { .. }));
+        assert!(
+            latency < Duration::from_millis(100),
+            "single queued syscall took {:?}",
+            latency
+        );
+    }
+
+    let stats = batch_executor.stats();
+    assert_eq!(stats.batch_size, 1);
+    assert_eq!(stats.window, Duration::ZERO);
+    assert_eq!(stats.queue_depth, 0);
+    assert_eq!(stats.syscalls_executed, 5);
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_adaptive_batch_burst_then_drain() {
+    let (executor, _, temp_dir, pid) = setup_test_env();
+    let batch_executor = BatchExecutor::new(executor);
+
+    let path = temp_dir.path().join("burst.txt");
+    fs::write(&path, b"burst").unwrap();
+
+    let submissions: Vec<_> = (0..200)
+        .map(|_| {
+            let batch_executor = batch_executor.clone();
+            let path = path.clone();
+            tokio::spawn(async move {
+                batch_executor
+                    .submit(pid, Syscall::FileExists { path })
+                    .await
+            })
+        })
+        .collect();
+
+    for submission in submissions {
+        let result = submission.await.unwrap();
+        assert!(matches!(result, SyscallResult::Success { .. }));
+    }
+
+    let stats = batch_executor.stats();
+    assert_eq!(stats.syscalls_executed, 200);
+    assert!(stats.batches_executed <= 200);
+    assert_eq!(stats.queue_depth, 0);
+
+    // Light traffic shrinks the batch back toward immediate dispatch
+    for _ in 0..16 {
+        batch_executor
+            .submit(pid, Syscall::FileExists { path: path.clone() })
+            .await;
+    }
+
+    let stats = batch_executor.stats();
+    assert_eq!(stats.batch_size, 1);
+    assert_eq!(stats.window, Duration::ZERO);
+}
@@ -3,11 +3,14 @@
  * Tests all 50 syscalls for Phase 5 completion
  */
 
+use ai_os_kernel::core::errors::ErrorCategory;
 use ai_os_kernel::ipc::{PipeManager, ShmManager};
 use ai_os_kernel::memory::MemoryManager;
 use ai_os_kernel::security::traits::SandboxProvider;
 use ai_os_kernel::security::{SandboxConfig, SandboxManager};
-use ai_os_kernel::syscalls::{Syscall, SyscallExecutorWithIpc, SyscallResult};
+use ai_os_kernel::syscalls::{
+    CreditBudget, PollFd, Syscall, SyscallExecutorWithIpc, SyscallResult, O_WRITE_COMBINE,
+};
 use ai_os_kernel::ProcessManager;
 use std::path::PathBuf;
 use tempfile::TempDir;
@@ -81,6 +84,39 @@ fn test_read_write_file() {
     }
 }
 
+fn append(executor: &SyscallExecutorWithIpc, path: &PathBuf, data: &[u8]) -> u64 {
+    let result = executor.execute(
+        1000,
+        Syscall::AppendFile {
+            path: path.clone(),
+            data: data.to_vec(),
+        },
+    );
+    match result {
+        SyscallResult::Success { data: Some(data) } => {
+            let value: serde_json::Value = serde_json::from_slice(&data).unwrap();
+            value["length"].as_u64().unwrap()
+        }
+        other => panic!("Expected append to succeed, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_append_file() {
+    let (executor, _, temp_dir) = create_test_executor();
+    let test_file = temp_dir.path().join("log.txt");
+
+    // Appending creates the file
+    assert_eq!(append(&executor, &test_file, b"first\n"), 6);
+    assert_eq!(append(&executor, &test_file, b"second\n"), 13);
+
+    let result = executor.execute(1000, Syscall::ReadFile { path: test_file });
+    match result {
+        SyscallResult::Success { data: Some(data) } => assert_eq!(data, b"first\nsecond\n"),
+        other => panic!("Expected success with data, got {:?}", other),
+    }
+}
+
 #[test]
 fn test_file_exists() {
     let (executor, _, temp_dir) = create_test_executor();
@@ -162,6 +198,65 @@ fn test_move_copy_file() {
     }
 }
 
+#[test]
+fn test_copy_file_stays_on_vfs() {
+    use ai_os_kernel::vfs::{FileSystem, MemFS, MountManager};
+    use std::sync::Arc;
+
+    let sandbox_mgr = SandboxManager::new();
+    let mut config = SandboxConfig::standard(1000);
+    config.allowed_paths.push(PathBuf::from("/mem"));
+    sandbox_mgr.create_sandbox(config);
+
+    let vfs = MountManager::new();
+    vfs.mount("/mem", Arc::new(MemFS::new())).unwrap();
+    vfs.write(&PathBuf::from("/mem/source.txt"), b"Test data")
+        .unwrap();
+
+    let memory_manager = MemoryManager::new();
+    let pipe_manager = PipeManager::new(memory_manager.clone());
+    let shm_manager = ShmManager::new(memory_manager);
+    let executor = SyscallExecutorWithIpc::with_ipc_direct(sandbox_mgr, pipe_manager, shm_manager)
+        .with_vfs(vfs.clone())
+        .build();
+
+    let result = executor.execute(
+        1000,
+        Syscall::CopyFile {
+            source: PathBuf::from("/mem/source.txt"),
+            destination: PathBuf::from("/mem/dest.txt"),
+        },
+    );
+    assert!(
+        matches!(result, SyscallResult::Success { .. }),
+        "{:?}",
+        result
+    );
+    assert_eq!(
+        vfs.read(&PathBuf::from("/mem/dest.txt")).unwrap(),
+        b"Test data"
+    );
+
+    // A VFS failure is reported as is, not retried against the host filesystem
+    let result = executor.execute(
+        1000,
+        Syscall::CopyFile {
+            source: PathBuf::from("/mem/missing.txt"),
+            destination: PathBuf::from("/mem/dest.txt"),
+        },
+    );
+    assert!(
+        matches!(&result, SyscallResult::Error { code: Some(code), .. }
+            if code.category == ErrorCategory::NotFound),
+        "{:?}",
+        result
+    );
+    assert_eq!(
+        vfs.read(&PathBuf::from("/mem/dest.txt")).unwrap(),
+        b"Test data"
+    );
+}
+
 #[test]
 fn test_directory_operations() {
     let (executor, _, temp_dir) = create_test_executor();
@@ -255,6 +350,328 @@ fn test_truncate_file() {
     }
 }
 
+#[test]
+fn test_vectored_read_write_file() {
+    let (executor, _, temp_dir) = create_test_executor();
+    let test_file = temp_dir.path().join("vectored.txt");
+
+    let result = executor.execute(
+        1000,
+        Syscall::WriteFileVectored {
+            path: test_file.clone(),
+            segments: vec![b"Hello".to_vec(), b", ".to_vec(), b"World!".to_vec()],
+        },
+    );
+    let SyscallResult::Success { data: Some(data) } = result else {
+        panic!("Expected success with data");
+    };
+    let written: serde_json::Value = serde_json::from_slice(&data).unwrap();
+    assert_eq!(written["total_bytes"], 13);
+
+    let result = executor.execute(
+        1000,
+        Syscall::ReadFileVectored {
+            path: test_file,
+            sizes: vec![5, 2, 100],
+        },
+    );
+    let SyscallResult::Success { data: Some(data) } = result else {
+        panic!("Expected success with data");
+    };
+    let read: serde_json::Value = serde_json::from_slice(&data).unwrap();
+    assert_eq!(read["total_bytes"], 13);
+    let segments: Vec<Vec<u8>> = serde_json::from_value(read["segments"].clone()).unwrap();
+    assert_eq!(
+        segments,
+        vec![b"Hello".to_vec(), b", ".to_vec(), b"World!".to_vec()]
+    );
+}
+
+const ABC_SHA256: &str = "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad";
+const ABC_BLAKE3: &str = "6437b3ac38465133ffb63b75273a8db548c558465d79db03fd359c6cd5bd9d85";
+
+fn hash_hex(executor: &SyscallExecutorWithIpc, path: PathBuf, algorithm: &str) -> String {
+    let result = executor.execute(
+        1000,
+        Syscall::HashFile {
+            path,
+            algorithm: algorithm.to_string(),
+        },
+    );
+    let SyscallResult::Success { data: Some(digest) } = result else {
+        panic!("Expected digest, got {:?}", result);
+    };
+    digest.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+#[test]
+fn test_hash_file() {
+    let (executor, _, temp_dir) = create_test_executor();
+    let test_file = temp_dir.path().join("hash.txt");
+    std::fs::write(&test_file, b"abc").unwrap();
+
+    assert_eq!(hash_hex(&executor, test_file.clone(), "sha256"), ABC_SHA256);
+    assert_eq!(hash_hex(&executor, test_file.clone(), "blake3"), ABC_BLAKE3);
+
+    let result = executor.execute(
+        1000,
+        Syscall::HashFile {
+            path: test_file,
+            algorithm: "md5".to_string(),
+        },
+    );
+    let SyscallResult::Error { message, .. } = result else {
+        panic!("Expected error for unsupported algorithm");
+    };
+    assert!(message.contains("md5"), "{}", message);
+}
+
+#[test]
+fn test_hash_file_memfs() {
+    use ai_os_kernel::vfs::{FileSystem, MemFS, MountManager};
+    use std::sync::Arc;
+
+    let sandbox_mgr = SandboxManager::new();
+    let mut config = SandboxConfig::standard(1000);
+    config.allowed_paths.push(PathBuf::from("/mem"));
+    sandbox_mgr.create_sandbox(config);
+
+    let vfs = MountManager::new();
+    vfs.mount("/mem", Arc::new(MemFS::new())).unwrap();
+    vfs.write(&PathBuf::from("/mem/hash.txt"), b"abc").unwrap();
+
+    let memory_manager = MemoryManager::new();
+    let pipe_manager = PipeManager::new(memory_manager.clone());
+    let shm_manager = ShmManager::new(memory_manager);
+    let executor = SyscallExecutorWithIpc::with_ipc_direct(sandbox_mgr, pipe_manager, shm_manager)
+        .with_vfs(vfs)
+        .build();
+
+    let path = PathBuf::from("/mem/hash.txt");
+    assert_eq!(hash_hex(&executor, path.clone(), "sha256"), ABC_SHA256);
+    assert_eq!(hash_hex(&executor, path, "blake3"), ABC_BLAKE3);
+}
+
+fn open_fd(executor: &SyscallExecutorWithIpc, path: PathBuf, flags: u32) -> u32 {
+    let result = executor.execute(
+        1000,
+        Syscall::Open {
+            path,
+            flags,
+            mode: 0o644,
+        },
+    );
+    let SyscallResult::Success { data: Some(data) } = result else {
+        panic!("Expected open to succeed, got {:?}", result);
+    };
+    let opened: serde_json::Value = serde_json::from_slice(&data).unwrap();
+    opened["fd"].as_u64().unwrap() as u32
+}
+
+#[test]
+fn test_sendfile_between_files() {
+    let (executor, _, temp_dir) = create_test_executor();
+    let src_path = temp_dir.path().join("src.txt");
+    let dest_path = temp_dir.path().join("dest.txt");
+    std::fs::write(&src_path, b"0123456789").unwrap();
+
+    let in_fd = open_fd(&executor, src_path, 0);
+    // O_WRONLY | O_CREAT
+    let out_fd = open_fd(&executor, dest_path.clone(), 0x0002 | 0x0040);
+
+    let result = executor.execute(
+        1000,
+        Syscall::Sendfile {
+            out_fd,
+            in_fd,
+            offset: Some(4),
+            count: 100,
+        },
+    );
+    let SyscallResult::Success { data: Some(data) } = result else {
+        panic!("Expected success with data, got {:?}", result);
+    };
+    let sent: serde_json::Value = serde_json::from_slice(&data).unwrap();
+    assert_eq!(sent["bytes_sent"], 6);
+    assert_eq!(std::fs::read(&dest_path).unwrap(), b"456789");
+
+    // Same fd on both ends is rejected rather than deadlocking
+    let result = executor.execute(
+        1000,
+        Syscall::Sendfile {
+            out_fd: in_fd,
+            in_fd,
+            offset: None,
+            count: 1,
+        },
+    );
+    assert!(matches!(result, SyscallResult::Error { .. }));
+
+    let result = executor.execute(
+        1000,
+        Syscall::Sendfile {
+            out_fd: 9999,
+            in_fd,
+            offset: None,
+            count: 1,
+        },
+    );
+    assert!(matches!(result, SyscallResult::Error { .. }));
+}
+
+#[test]
+fn test_write_combining_is_durable_after_fsync_and_close() {
+    let (executor, _, temp_dir) = create_test_executor();
+    let path = temp_dir.path().join("log.txt");
+    let write = |fd, data: &[u8]| {
+        let result = executor.execute(
+            1000,
+            Syscall::Write {
+                fd,
+                data: data.to_vec(),
+            },
+        );
+        let SyscallResult::Success { data: Some(data) } = result else {
+            panic!("Expected write to succeed, got {:?}", result);
+        };
+        let written: serde_json::Value = serde_json::from_slice(&data).unwrap();
+        written["bytes_written"].as_u64().unwrap()
+    };
+
+    // O_WRONLY | O_CREAT | O_WRITE_COMBINE
+    let fd = open_fd(&executor, path.clone(), 0x0002 | 0x0040 | O_WRITE_COMBINE);
+    assert_eq!(write(fd, b"line 1\n"), 7);
+    assert_eq!(write(fd, b"line 2\n"), 7);
+    assert!(std::fs::read(&path).unwrap().is_empty());
+
+    assert!(executor.execute(1000, Syscall::Fsync { fd }).is_success());
+    assert_eq!(std::fs::read(&path).unwrap(), b"line 1\nline 2\n");
+
+    // Close flushes what is left below the threshold
+    write(fd, b"line 3\n");
+    assert!(executor.execute(1000, Syscall::Close { fd }).is_success());
+    assert_eq!(std::fs::read(&path).unwrap(), b"line 1\nline 2\nline 3\n");
+
+    // Unbuffered remains the default
+    let fd = open_fd(&executor, path.clone(), 0x0002 | 0x0400);
+    write(fd, b"line 4\n");
+    assert!(std::fs::read(&path).unwrap().ends_with(b"line 4\n"));
+}
+
+fn poll(executor: &SyscallExecutorWithIpc, fds: Vec<PollFd>, timeout_ms: u64) -> Vec<PollFd> {
+    let result = executor.execute(
+        1000,
+        Syscall::Poll {
+            fds,
+            timeout_ms: Some(timeout_ms),
+        },
+    );
+    let SyscallResult::Success { data: Some(data) } = result else {
+        panic!("Expected poll to succeed, got {:?}", result);
+    };
+    serde_json::from_slice(&data).unwrap()
+}
+
+#[test]
+fn test_poll_pipe_fd() {
+    let (executor, _, _temp_dir) = create_test_executor();
+
+    // Pipe with buffered data, exposed through the fd table
+    let pipes = executor.ipc().pipe_manager();
+    let pipe_id = pipes.create(1000, 1000, None).unwrap();
+    pipes.write(pipe_id, 1000, b"ping").unwrap();
+    let result = executor.execute(1000, Syscall::PipeFd { pipe_id });
+    let SyscallResult::Success { data: Some(data) } = result else {
+        panic!("Expected pipe fd, got {:?}", result);
+    };
+    let pipe_fd = serde_json::from_slice::<serde_json::Value>(&data).unwrap()["fd"]
+        .as_u64()
+        .unwrap() as u32;
+
+    let ready = poll(
+        &executor,
+        vec![PollFd::new(pipe_fd, PollFd::POLLIN | PollFd::POLLOUT)],
+        1000,
+    );
+    assert_eq!(ready.len(), 1);
+    assert_eq!(ready[0].revents, PollFd::POLLIN | PollFd::POLLOUT);
+
+    // Draining the pipe clears its readiness until the timeout
+    let result = executor.execute(
+        1000,
+        Syscall::Read {
+            fd: pipe_fd,
+            count: 16,
+        },
+    );
+    assert!(matches!(result, SyscallResult::Success { data: Some(ref data) } if data == b"ping"));
+    let started = std::time::Instant::now();
+    let ready = poll(&executor, vec![PollFd::new(pipe_fd, PollFd::POLLIN)], 30);
+    assert!(ready.is_empty());
+    assert!(started.elapsed() >= std::time::Duration::from_millis(30));
+
+    // Unknown descriptors are flagged rather than waited on
+    let ready = poll(&executor, vec![PollFd::new(4242, PollFd::POLLIN)], 1000);
+    assert_eq!(ready[0].revents, PollFd::POLLNVAL);
+}
+
+#[test]
+fn test_invalid_arguments_rejected_before_dispatch() {
+    let (executor, _, _temp_dir) = create_test_executor();
+
+    let cases = [
+        (
+            Syscall::Lseek {
+                fd: 3,
+                offset: 0,
+                whence: 9,
+            },
+            "whence = 9",
+        ),
+        (
+            Syscall::Fcntl {
+                fd: 3,
+                cmd: 4242,
+                arg: 0,
+                lock: None,
+            },
+            "cmd = 4242",
+        ),
+        (
+            Syscall::Socket {
+                domain: 99,
+                socket_type: 1,
+                protocol: 0,
+            },
+            "domain = 99",
+        ),
+        (
+            Syscall::Socket {
+                domain: 2,
+                socket_type: 42,
+                protocol: 0,
+            },
+            "socket_type = 42",
+        ),
+        (
+            Syscall::SendSignal {
+                target_pid: 1000,
+                signal: 99,
+            },
+            "signal = 99",
+        ),
+    ];
+
+    for (syscall, expected) in cases {
+        match executor.execute(1000, syscall) {
+            SyscallResult::Error { message, .. } => {
+                assert_eq!(message.as_str(), format!("Invalid argument: {}", expected));
+            }
+            other => panic!("Expected invalid argument error, got {:?}", other),
+        }
+    }
+}
+
 // ============================================================================
 // Process Syscalls (8 tests)
 // ============================================================================
@@ -274,6 +691,49 @@ fn test_spawn_process() {
     assert!(matches!(result, SyscallResult::Success { .. }));
 }
 
+#[test]
+fn test_spawn_from_noexec_mount_is_refused() {
+    use ai_os_kernel::vfs::{MemFS, MountFlags, MountManager};
+    use std::sync::Arc;
+
+    let sandbox_mgr = SandboxManager::new();
+    sandbox_mgr.create_sandbox(SandboxConfig::privileged(1000));
+
+    let vfs = MountManager::new();
+    vfs.mount_with_flags("/noexec", Arc::new(MemFS::new()), MountFlags::NO_EXEC)
+        .unwrap();
+
+    let memory_manager = MemoryManager::new();
+    let pipe_manager = PipeManager::new(memory_manager.clone());
+    let shm_manager = ShmManager::new(memory_manager);
+    let executor = SyscallExecutorWithIpc::with_ipc_direct(sandbox_mgr, pipe_manager, shm_manager)
+        .with_vfs(vfs)
+        .build();
+
+    let result = executor.execute(
+        1000,
+        Syscall::SpawnProcess {
+            command: "/noexec/tool".to_string(),
+            args: vec![],
+        },
+    );
+    assert!(
+        matches!(result, SyscallResult::PermissionDenied { .. }),
+        "{:?}",
+        result
+    );
+
+    // Host commands are not on a mount and still run
+    let result = executor.execute(
+        1000,
+        Syscall::SpawnProcess {
+            command: "echo".to_string(),
+            args: vec!["test".to_string()],
+        },
+    );
+    assert!(matches!(result, SyscallResult::Success { .. }));
+}
+
 #[test]
 fn test_get_process_list() {
     let (executor, _, _) = create_test_executor();
@@ -340,6 +800,369 @@ fn test_environment_variables() {
     assert!(matches!(result, SyscallResult::Success { .. }));
 }
 
+/// Transport that fails with `error` for the first `failures` requests
+struct FlakyTransport {
+    failures: u32,
+    error: ai_os_kernel::syscalls::TransportError,
+    attempts: std::sync::atomic::AtomicU32,
+}
+
+impl FlakyTransport {
+    fn new(failures: u32, error: ai_os_kernel::syscalls::TransportError) -> Self {
+        Self {
+            failures,
+            error,
+            attempts: std::sync::atomic::AtomicU32::new(0),
+        }
+    }
+
+    fn attempts(&self) -> u32 {
+        self.attempts.load(std::sync::atomic::Ordering::SeqCst)
+    }
+}
+
+impl ai_os_kernel::syscalls::HttpTransport for FlakyTransport {
+    fn get(
+        &self,
+        _pid: u32,
+        _url: &reqwest::Url,
+    ) -> Result<ai_os_kernel::syscalls::HttpResponse, ai_os_kernel::syscalls::TransportError> {
+        let attempt = self
+            .attempts
+            .fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+        if attempt < self.failures {
+            return Err(self.error.clone());
+        }
+        Ok(ai_os_kernel::syscalls::HttpResponse {
+            status: 200,
+            body: b"ok".to_vec(),
+        })
+    }
+}
+
+#[test]
+fn test_network_request_retries_transient_failures() {
+    use ai_os_kernel::syscalls::{RetryPolicy, TransportError};
+    use std::sync::Arc;
+    use std::time::Duration;
+
+    let (executor, _, _) = create_test_executor();
+    let transport = Arc::new(FlakyTransport::new(
+        2,
+        TransportError::Connect("connection refused".into()),
+    ));
+    let executor = executor.with_http_transport(transport.clone()).build();
+    let policy = RetryPolicy::new(3).with_base_delay(Duration::from_millis(1));
+
+    let result = executor.execute(
+        1000,
+        Syscall::NetworkRequest {
+            url: "http://example.com/".to_string(),
+            retry: Some(policy),
+        },
+    );
+    assert_eq!(result, SyscallResult::success_with_data(b"ok".to_vec()));
+    assert_eq!(transport.attempts(), 3);
+
+    // Without a policy the first failure is returned
+    let transport = Arc::new(FlakyTransport::new(
+        1,
+        TransportError::Timeout("timed out".into()),
+    ));
+    let (executor, _, _) = create_test_executor();
+    let executor = executor.with_http_transport(transport.clone()).build();
+    let result = executor.execute(
+        1000,
+        Syscall::NetworkRequest {
+            url: "http://example.com/".to_string(),
+            retry: None,
+        },
+    );
+    assert!(result.is_error());
+    assert_eq!(transport.attempts(), 1);
+
+    // A per-process default applies when the request has no policy
+    executor.socket_manager().set_retry_policy(1000, policy);
+    let result = executor.execute(
+        1000,
+        Syscall::NetworkRequest {
+            url: "http://example.com/".to_string(),
+            retry: None,
+        },
+    );
+    assert!(result.is_success());
+}
+
+#[test]
+fn test_network_request_does_not_retry_dns_failure() {
+    use ai_os_kernel::syscalls::{RetryPolicy, TransportError};
+    use std::sync::Arc;
+    use std::time::Duration;
+
+    let (executor, _, _) = create_test_executor();
+    let transport = Arc::new(FlakyTransport::new(
+        1,
+        TransportError::Dns("NXDOMAIN".into()),
+    ));
+    let executor = executor.with_http_transport(transport.clone()).build();
+
+    let result = executor.execute(
+        1000,
+        Syscall::NetworkRequest {
+            url: "http://nonexistent.invalid/".to_string(),
+            retry: Some(RetryPolicy::new(3).with_base_delay(Duration::from_millis(1))),
+        },
+    );
+    assert!(result.is_error());
+    assert_eq!(transport.attempts(), 1);
+}
+
+#[test]
+fn test_network_request_circuit_breaker_cycle() {
+    use ai_os_kernel::core::{BreakerConfig, BreakerState, CircuitBreaker};
+    use ai_os_kernel::syscalls::TransportError;
+    use std::sync::Arc;
+    use std::time::Duration;
+
+    let (executor, _, _) = create_test_executor();
+    let transport = Arc::new(FlakyTransport::new(
+        3,
+        TransportError::Connect("connection refused".into()),
+    ));
+    let breaker = CircuitBreaker::new(
+        BreakerConfig::new()
+            .with_failure_threshold(2)
+            .with_cooldown(Duration::from_millis(50)),
+    );
+    let executor = executor
+        .with_http_transport(transport.clone())
+        .with_circuit_breaker(breaker.clone())
+        .build();
+    let request = || {
+        executor.execute(
+            1000,
+            Syscall::NetworkRequest {
+                url: "http://example.com/data".to_string(),
+                retry: None,
+            },
+        )
+    };
+
+    // closed -> open
+    assert!(request().is_error());
+    assert!(request().is_error());
+    assert_eq!(breaker.state("example.com"), BreakerState::Open);
+
+    // Open circuits fail without reaching the transport
+    assert!(request().is_error());
+    assert_eq!(transport.attempts(), 2);
+
+    // half-open probe fails and reopens, then succeeds and closes
+    std::thread::sleep(Duration::from_millis(60));
+    assert!(request().is_error());
+    assert_eq!(breaker.state("example.com"), BreakerState::Open);
+    std::thread::sleep(Duration::from_millis(60));
+    assert_eq!(request(), SyscallResult::success_with_data(b"ok".to_vec()));
+    assert_eq!(breaker.state("example.com"), BreakerState::Closed);
+
+    let stats = executor.socket_manager().circuit_breaker().stats();
+    assert_eq!(stats.len(), 1);
+    assert_eq!((stats[0].trips, stats[0].rejected), (2, 1));
+}
+
+/// One scripted reaction of a stream to a recv
+#[derive(Debug)]
+enum ScriptedRead {
+    Data(&'static [u8]),
+    WouldBlock,
+}
+
+/// Stream replaying scripted reads and recording what was sent
+#[derive(Debug)]
+struct ScriptedStream {
+    reads: std::collections::VecDeque<ScriptedRead>,
+    sent: std::sync::Arc<std::sync::Mutex<Vec<u8>>>,
+    max_write: usize,
+}
+
+impl ai_os_kernel::syscalls::TransportStream for ScriptedStream {
+    fn send(&mut self, data: &[u8]) -> std::io::Result<usize> {
+        let len = data.len().min(self.max_write);
+        self.sent.lock().unwrap().extend_from_slice(&data[..len]);
+        Ok(len)
+    }
+
+    fn recv(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        match self.reads.pop_front() {
+            Some(ScriptedRead::Data(data)) => {
+                let len = data.len().min(buf.len());
+                buf[..len].copy_from_slice(&data[..len]);
+                Ok(len)
+            }
+            Some(ScriptedRead::WouldBlock) => Err(std::io::ErrorKind::WouldBlock.into()),
+            None => Ok(0),
+        }
+    }
+}
+
+/// Transport handing out one scripted stream per connect, refusing the rest
+struct ScriptedTransport {
+    streams: std::sync::Mutex<Vec<ScriptedStream>>,
+    connects: std::sync::Mutex<Vec<String>>,
+}
+
+impl ScriptedTransport {
+    fn new(streams: Vec<ScriptedStream>) -> Self {
+        Self {
+            streams: std::sync::Mutex::new(streams),
+            connects: std::sync::Mutex::new(Vec::new()),
+        }
+    }
+}
+
+impl ai_os_kernel::syscalls::SocketTransport for ScriptedTransport {
+    fn connect(
+        &self,
+        address: &str,
+    ) -> std::io::Result<Box<dyn ai_os_kernel::syscalls::TransportStream>> {
+        self.connects.lock().unwrap().push(address.to_string());
+        match self.streams.lock().unwrap().pop() {
+            Some(stream) => Ok(Box::new(stream)),
+            None => Err(std::io::ErrorKind::ConnectionRefused.into()),
+        }
+    }
+}
+
+/// Open a socket and connect it through the executor's transport
+fn connect_socket(executor: &SyscallExecutorWithIpc, address: &str) -> (u32, SyscallResult) {
+    let result = executor.execute(
+        1000,
+        Syscall::Socket {
+            domain: 2,
+            socket_type: 1,
+            protocol: 0,
+        },
+    );
+    let SyscallResult::Success { data: Some(data) } = result else {
+        panic!("Expected success with data");
+    };
+    let created: serde_json::Value = serde_json::from_slice(&data).unwrap();
+    let sockfd = created["sockfd"].as_u64().unwrap() as u32;
+
+    let result = executor.execute(
+        1000,
+        Syscall::Connect {
+            sockfd,
+            address: address.to_string(),
+        },
+    );
+    (sockfd, result)
+}
+
+#[test]
+fn test_socket_transport_scripted_send_recv() {
+    use std::sync::{Arc, Mutex};
+
+    let sent = Arc::new(Mutex::new(Vec::new()));
+    let stream = ScriptedStream {
+        reads: [ScriptedRead::WouldBlock, ScriptedRead::Data(b"pong")].into(),
+        sent: sent.clone(),
+        max_write: usize::MAX,
+    };
+    let transport = Arc::new(ScriptedTransport::new(vec![stream]));
+    let (executor, _, _) = create_test_executor();
+    let executor = executor.with_transport(transport.clone()).build();
+
+    let (sockfd, result) = connect_socket(&executor, "10.0.0.1:7000");
+    assert!(result.is_success());
+    assert_eq!(*transport.connects.lock().unwrap(), vec!["10.0.0.1:7000"]);
+    assert_eq!(executor.socket_manager().stats().total_tcp_streams, 1);
+
+    let result = executor.execute(
+        1000,
+        Syscall::Send {
+            sockfd,
+            data: b"ping".to_vec(),
+            flags: 0,
+        },
+    );
+    assert!(result.is_success());
+    assert_eq!(*sent.lock().unwrap(), b"ping");
+
+    // A would-block read is retried until the scripted data arrives
+    let result = executor.execute(
+        1000,
+        Syscall::Recv {
+            sockfd,
+            size: 16,
+            flags: 0,
+        },
+    );
+    assert_eq!(result, SyscallResult::success_with_data(b"pong".to_vec()));
+
+    // With no streams left the transport refuses the connection
+    let (_, result) = connect_socket(&executor, "10.0.0.1:7000");
+    assert!(result.is_error());
+}
+
+#[test]
+fn test_socket_transport_short_writes_refund_bandwidth() {
+    use ai_os_kernel::syscalls::{BandwidthLimit, SyscallTimeoutConfig, TimeoutPolicy};
+    use std::sync::{Arc, Mutex};
+    use std::time::Duration;
+
+    let sent = Arc::new(Mutex::new(Vec::new()));
+    let stream = ScriptedStream {
+        reads: Default::default(),
+        sent: sent.clone(),
+        max_write: 4,
+    };
+    let transport = Arc::new(ScriptedTransport::new(vec![stream]));
+    let (executor, _, _) = create_test_executor();
+    let executor = executor
+        .with_transport(transport)
+        .with_timeout_config(SyscallTimeoutConfig {
+            network: TimeoutPolicy::Io(Duration::from_millis(200).into()),
+            ..Default::default()
+        })
+        .build();
+
+    let (sockfd, result) = connect_socket(&executor, "10.0.0.1:7000");
+    assert!(result.is_success());
+
+    // One byte per second: anything past the burst would wait far longer
+    // than the network timeout
+    executor
+        .socket_manager()
+        .set_bandwidth_limit(1000, BandwidthLimit::new(1).with_burst(10));
+
+    let send = |data: &[u8]| {
+        executor.execute(
+            1000,
+            Syscall::Send {
+                sockfd,
+                data: data.to_vec(),
+                flags: 0,
+            },
+        )
+    };
+
+    let result = send(&[1; 10]);
+    let SyscallResult::Success { data: Some(data) } = result else {
+        panic!("Expected success with data");
+    };
+    let written: serde_json::Value = serde_json::from_slice(&data).unwrap();
+    assert_eq!(written["bytes_sent"], 4);
+
+    // The 6 unsent bytes were refunded, so resending them does not wait
+    assert!(send(&[2; 4]).is_success());
+    assert!(send(&[3; 2]).is_success());
+    assert_eq!(sent.lock().unwrap().len(), 10);
+
+    // The burst is now spent
+    assert!(send(&[4; 4]).is_error());
+}
+
 // ============================================================================
 // Time Syscalls (2 tests)
 // ============================================================================
@@ -352,6 +1175,35 @@ fn test_sleep() {
     assert!(matches!(result, SyscallResult::Success { .. }));
 }
 
+#[test]
+fn test_sleep_interrupted_by_signal() {
+    use ai_os_kernel::signals::{Signal, SignalDelivery, SignalManagerImpl, SignalStateManager};
+    use std::time::{Duration, Instant};
+
+    let (executor, _, _) = create_test_executor();
+    let signal_manager = SignalManagerImpl::new();
+    let executor = executor.with_signals(signal_manager.clone()).build();
+    signal_manager.initialize_process(1000).unwrap();
+
+    let sender = signal_manager.clone();
+    let signaller = std::thread::spawn(move || {
+        std::thread::sleep(Duration::from_millis(50));
+        sender.send(1, 1000, Signal::SIGUSR1).unwrap();
+    });
+
+    let start = Instant::now();
+    let result = executor.execute(1000, Syscall::Sleep { duration_ms: 5000 });
+    signaller.join().unwrap();
+    assert!(start.elapsed() < Duration::from_secs(2));
+
+    let SyscallResult::Success { data: Some(data) } = result else {
+        panic!("sleep should report the interruption: {:?}", result);
+    };
+    let value: serde_json::Value = serde_json::from_slice(&data).unwrap();
+    assert_eq!(value["interrupted"], true);
+    assert!(value["remaining_ms"].as_u64().unwrap() > 3000);
+}
+
 #[test]
 fn test_get_uptime() {
     let (executor, _, _) = create_test_executor();
@@ -361,7 +1213,7 @@ fn test_get_uptime() {
 }
 
 // ============================================================================
-// Memory Syscalls (3 tests)
+// Memory Syscalls (4 tests)
 // ============================================================================
 
 #[test]
@@ -380,6 +1232,55 @@ fn test_get_process_memory_stats() {
     assert!(matches!(result, SyscallResult::Success { .. }));
 }
 
+#[test]
+fn test_get_memory_maps_lists_allocated_blocks() {
+    let sandbox_mgr = SandboxManager::new();
+    sandbox_mgr.create_sandbox(SandboxConfig::privileged(1000));
+    sandbox_mgr.create_sandbox(SandboxConfig::standard(2000));
+    sandbox_mgr.create_sandbox(SandboxConfig::minimal(3000));
+
+    let memory_manager = MemoryManager::new();
+    let executor = SyscallExecutorWithIpc::with_full_features(
+        sandbox_mgr,
+        PipeManager::new(memory_manager.clone()),
+        ShmManager::new(memory_manager.clone()),
+        ProcessManager::new(),
+        memory_manager.clone(),
+    );
+
+    let blocks = [
+        memory_manager.allocate(4096, 2000).unwrap(),
+        memory_manager
+            .allocate_tagged(8192, 2000, "json_buffer")
+            .unwrap(),
+        memory_manager.allocate(512, 2000).unwrap(),
+    ];
+
+    let maps = match executor.execute(2000, Syscall::GetMemoryMaps { target_pid: 2000 }) {
+        SyscallResult::Success { data: Some(data) } => {
+            serde_json::from_slice::<Vec<serde_json::Value>>(&data).unwrap()
+        }
+        other => panic!("Expected memory maps, got {:?}", other),
+    };
+    assert_eq!(maps.len(), blocks.len());
+    for (address, size) in blocks.iter().zip([4096, 8192, 512]) {
+        let entry = maps
+            .iter()
+            .find(|m| m["address"] == *address as u64)
+            .expect("allocated block missing from maps");
+        assert_eq!(entry["size"], size);
+        assert_eq!(entry["allocated"], true);
+    }
+    assert!(maps.iter().any(|m| m["tag"] == "json_buffer"));
+
+    // Reading someone else's layout takes the same standing as their stats
+    let result = executor.execute(3000, Syscall::GetMemoryMaps { target_pid: 2000 });
+    assert!(matches!(result, SyscallResult::PermissionDenied { .. }));
+
+    let result = executor.execute(1000, Syscall::GetMemoryMaps { target_pid: 2000 });
+    assert!(matches!(result, SyscallResult::Success { .. }));
+}
+
 #[test]
 fn test_trigger_gc() {
     let (executor, _, _) = create_test_executor();
@@ -399,7 +1300,7 @@ fn test_trigger_gc() {
 }
 
 // ============================================================================
-// Signal Syscalls (1 test)
+// Signal Syscalls (2 tests)
 // ============================================================================
 
 #[test]
@@ -422,6 +1323,175 @@ fn test_send_signal() {
     ));
 }
 
+#[test]
+fn test_signalfd_read_and_close() {
+    use ai_os_kernel::signals::{
+        PendingSignal, Signal, SignalDelivery, SignalManagerImpl, SignalQueue, SignalStateManager,
+    };
+
+    let (executor, _, _) = create_test_executor();
+    let signal_manager = SignalManagerImpl::new();
+    let executor = executor.with_signals(signal_manager.clone()).build();
+    signal_manager.initialize_process(1000).unwrap();
+
+    let result = executor.execute(
+        1000,
+        Syscall::SignalfdCreate {
+            signals: vec![Signal::SIGUSR1.number()],
+        },
+    );
+    let fd = match result {
+        SyscallResult::Success { data: Some(data) } => {
+            let value: serde_json::Value = serde_json::from_slice(&data).unwrap();
+            value["fd"].as_u64().unwrap() as u32
+        }
+        other => panic!("signalfd_create failed: {:?}", other),
+    };
+
+    // SIGUSR1 goes to the fd, SIGUSR2 still reaches handlers
+    signal_manager.send(1, 1000, Signal::SIGUSR1).unwrap();
+    signal_manager.send(1, 1000, Signal::SIGUSR2).unwrap();
+    assert_eq!(signal_manager.pending_signals(1000), vec![Signal::SIGUSR2]);
+
+    let result = executor.execute(1000, Syscall::Read { fd, count: 4096 });
+    let signals: Vec<PendingSignal> = match result {
+        SyscallResult::Success { data: Some(data) } => serde_json::from_slice(&data).unwrap(),
+        other => panic!("read failed: {:?}", other),
+    };
+    assert_eq!(signals.len(), 1);
+    assert_eq!(signals[0].signal, Signal::SIGUSR1);
+    assert_eq!(signals[0].sender_pid, 1);
+
+    // Drained: nothing left to read
+    let result = executor.execute(1000, Syscall::Read { fd, count: 4096 });
+    assert!(matches!(result, SyscallResult::Error { .. }));
+
+    // Closing the fd restores handler delivery
+    let result = executor.execute(1000, Syscall::Close { fd });
+    assert!(matches!(result, SyscallResult::Success { .. }));
+    signal_manager.send(1, 1000, Signal::SIGUSR1).unwrap();
+    assert_eq!(signal_manager.pending_count(1000), 2);
+}
+
+fn create_timerfd(executor: &SyscallExecutorWithIpc, pid: u32) -> u32 {
+    match executor.execute(pid, Syscall::TimerfdCreate) {
+        SyscallResult::Success { data: Some(data) } => {
+            let value: serde_json::Value = serde_json::from_slice(&data).unwrap();
+            value["fd"].as_u64().unwrap() as u32
+        }
+        other => panic!("timerfd_create failed: {:?}", other),
+    }
+}
+
+fn read_expirations(executor: &SyscallExecutorWithIpc, pid: u32, fd: u32) -> Option<u64> {
+    match executor.execute(pid, Syscall::Read { fd, count: 8 }) {
+        SyscallResult::Success { data: Some(data) } => {
+            Some(u64::from_le_bytes(data.as_slice().try_into().unwrap()))
+        }
+        _ => None,
+    }
+}
+
+#[test]
+fn test_timerfd_one_shot() {
+    let (executor, _, _) = create_test_executor();
+    let fd = create_timerfd(&executor, 1000);
+
+    // Disarmed timers never become readable
+    assert_eq!(read_expirations(&executor, 1000, fd), None);
+
+    let result = executor.execute(
+        1000,
+        Syscall::TimerfdSet {
+            fd,
+            duration_ms: 20,
+            interval_ms: None,
+        },
+    );
+    assert!(result.is_success(), "{:?}", result);
+    assert_eq!(read_expirations(&executor, 1000, fd), None);
+
+    std::thread::sleep(std::time::Duration::from_millis(40));
+    assert_eq!(read_expirations(&executor, 1000, fd), Some(1));
+
+    // One-shot: nothing more after the first expiration is read
+    std::thread::sleep(std::time::Duration::from_millis(40));
+    assert_eq!(read_expirations(&executor, 1000, fd), None);
+
+    let result = executor.execute(1000, Syscall::Close { fd });
+    assert!(result.is_success(), "{:?}", result);
+}
+
+#[test]
+fn test_timerfd_periodic() {
+    let (executor, _, _) = create_test_executor();
+    let fd = create_timerfd(&executor, 1000);
+
+    let result = executor.execute(
+        1000,
+        Syscall::TimerfdSet {
+            fd,
+            duration_ms: 10,
+            interval_ms: Some(10),
+        },
+    );
+    assert!(result.is_success(), "{:?}", result);
+
+    // Missed periods accumulate until the fd is read
+    std::thread::sleep(std::time::Duration::from_millis(55));
+    let first = read_expirations(&executor, 1000, fd).unwrap();
+    assert!(first >= 4, "expected at least 4 expirations, got {}", first);
+
+    std::thread::sleep(std::time::Duration::from_millis(25));
+    let second = read_expirations(&executor, 1000, fd).unwrap();
+    assert!(second >= 1, "expected further expirations, got {}", second);
+
+    // Duration 0 disarms
+    let result = executor.execute(
+        1000,
+        Syscall::TimerfdSet {
+            fd,
+            duration_ms: 0,
+            interval_ms: None,
+        },
+    );
+    assert!(result.is_success(), "{:?}", result);
+    std::thread::sleep(std::time::Duration::from_millis(25));
+    assert_eq!(read_expirations(&executor, 1000, fd), None);
+}
+
+#[test]
+fn test_timerfd_set_rejects_other_fds() {
+    let (executor, _, temp_dir) = create_test_executor();
+    let path = temp_dir.path().join("plain.txt");
+    std::fs::write(&path, b"data").unwrap();
+
+    let fd = match executor.execute(
+        1000,
+        Syscall::Open {
+            path,
+            flags: 0,
+            mode: 0,
+        },
+    ) {
+        SyscallResult::Success { data: Some(data) } => {
+            let value: serde_json::Value = serde_json::from_slice(&data).unwrap();
+            value["fd"].as_u64().unwrap() as u32
+        }
+        other => panic!("open failed: {:?}", other),
+    };
+
+    let result = executor.execute(
+        1000,
+        Syscall::TimerfdSet {
+            fd,
+            duration_ms: 10,
+            interval_ms: None,
+        },
+    );
+    assert!(matches!(result, SyscallResult::Error { .. }));
+}
+
 // ============================================================================
 // Permission Tests
 // ============================================================================
@@ -432,7 +1502,8 @@ fn test_permission_denied() {
     let memory_manager = ai_os_kernel::memory::MemoryManager::new();
     let pipe_manager = ai_os_kernel::ipc::PipeManager::new(memory_manager.clone());
     let shm_manager = ai_os_kernel::ipc::ShmManager::new(memory_manager.clone());
-    let executor = SyscallExecutorWithIpc::with_ipc_direct(sandbox_mgr.clone(), pipe_manager, shm_manager);
+    let executor =
+        SyscallExecutorWithIpc::with_ipc_direct(sandbox_mgr.clone(), pipe_manager, shm_manager);
 
     // Create minimal sandbox without file permissions
     let test_pid = 2000;
@@ -449,3 +1520,468 @@ fn test_permission_denied() {
 
     assert!(matches!(result, SyscallResult::PermissionDenied { .. }));
 }
+
+#[test]
+fn test_syscall_allowlist() {
+    let (executor, _sandbox_mgr, temp_dir) = create_test_executor();
+    let test_pid = 1000;
+    let test_file = temp_dir.path().join("allowed.txt");
+
+    executor.set_syscall_allowlist(
+        test_pid,
+        &[
+            Syscall::WriteFile {
+                path: PathBuf::new(),
+                data: Vec::new(),
+            },
+            Syscall::ReadFile {
+                path: PathBuf::new(),
+            },
+        ],
+    );
+
+    let result = executor.execute(
+        test_pid,
+        Syscall::WriteFile {
+            path: test_file.clone(),
+            data: b"allowed".to_vec(),
+        },
+    );
+    assert!(matches!(result, SyscallResult::Success { .. }));
+
+    let result = executor.execute(test_pid, Syscall::ReadFile { path: test_file });
+    assert!(matches!(result, SyscallResult::Success { .. }));
+
+    // Not on the list, even though the sandbox would allow it
+    let result = executor.execute(test_pid, Syscall::GetProcessList);
+    assert!(matches!(result, SyscallResult::PermissionDenied { .. }));
+
+    // An empty list lifts the restriction
+    executor.set_syscall_allowlist(test_pid, &[]);
+    let result = executor.execute(test_pid, Syscall::GetProcessList);
+    assert!(!matches!(result, SyscallResult::PermissionDenied { .. }));
+}
+
+#[test]
+fn test_syscall_allowlist_does_not_survive_pid_reuse() {
+    use ai_os_kernel::process::resources::{ResourceOrchestrator, SecurityResource};
+    use ai_os_kernel::process::RecyclingPidAllocator;
+    use std::sync::Arc;
+
+    let (executor, sandbox_mgr, _temp_dir) = create_test_executor();
+    let pm = ProcessManager::builder()
+        .with_pid_allocator(Arc::new(RecyclingPidAllocator::new()))
+        .with_resource_orchestrator(
+            ResourceOrchestrator::new().register(SecurityResource::new(executor.clone())),
+        )
+        .build();
+
+    let pid = pm.create_process("restricted".to_string(), 5);
+    sandbox_mgr.create_sandbox(SandboxConfig::privileged(pid));
+    executor.set_syscall_allowlist(pid, &[Syscall::GetUptime]);
+    let result = executor.execute(pid, Syscall::GetProcessList);
+    assert!(matches!(result, SyscallResult::PermissionDenied { .. }));
+
+    assert_eq!(pm.terminate_tree(pid), vec![pid]);
+
+    // Same pid, new holder: the old allow-list no longer applies
+    let reused = pm.create_process("fresh".to_string(), 5);
+    assert_eq!(reused, pid);
+    sandbox_mgr.create_sandbox(SandboxConfig::privileged(reused));
+    let result = executor.execute(reused, Syscall::GetProcessList);
+    assert!(!matches!(result, SyscallResult::PermissionDenied { .. }));
+}
+
+#[test]
+fn test_kill_clears_allowlist() {
+    let (executor, _sandbox_mgr, _temp_dir) = create_test_executor();
+    let pm = executor.optional().process_manager.clone().unwrap();
+
+    let target = pm.create_process("restricted".to_string(), 5);
+    executor.set_syscall_allowlist(target, &[Syscall::GetUptime]);
+
+    let result = executor.execute(1000, Syscall::KillProcess { target_pid: target });
+    assert!(matches!(result, SyscallResult::Success { .. }));
+    assert!(!executor.syscall_allowlist().is_restricted(target));
+}
+
+#[test]
+fn test_syscall_credit_budget_throttles_and_refills() {
+    let (executor, _sandbox_mgr, _temp_dir) = create_test_executor();
+    let test_pid = 1000;
+    let is_rate_limited = |result: &SyscallResult| {
+        matches!(result, SyscallResult::Error { code: Some(code), .. }
+            if code.category == ErrorCategory::QuotaExceeded)
+    };
+
+    executor
+        .syscall_credits()
+        .set_cost(&Syscall::GetProcessList, 2);
+    executor.set_syscall_budget(test_pid, CreditBudget::new(5, 20));
+
+    // 2 + 2 spends 4 of 5 credits; the next call costs more than is left
+    for _ in 0..2 {
+        let result = executor.execute(test_pid, Syscall::GetProcessList);
+        assert!(!is_rate_limited(&result));
+    }
+    let result = executor.execute(test_pid, Syscall::GetProcessList);
+    assert!(is_rate_limited(&result));
+
+    // Cheaper syscalls still fit in what is left
+    let result = executor.execute(test_pid, Syscall::GetSystemInfo);
+    assert!(!is_rate_limited(&result));
+    let result = executor.execute(test_pid, Syscall::GetSystemInfo);
+    assert!(is_rate_limited(&result));
+
+    // Other processes are unaffected
+    let result = executor.execute(2000, Syscall::GetSystemInfo);
+    assert!(!is_rate_limited(&result));
+
+    // 20 credits/s restores enough for another call within 150ms
+    std::thread::sleep(std::time::Duration::from_millis(150));
+    let result = executor.execute(test_pid, Syscall::GetProcessList);
+    assert!(!is_rate_limited(&result));
+
+    executor.syscall_credits().clear(test_pid);
+    assert_eq!(executor.syscall_credits().available(test_pid), None);
+}
+
+#[test]
+fn test_nonblocking_ipc_reads() {
+    let (_executor, sandbox_mgr, _temp_dir) = create_test_executor();
+    let test_pid = 1000;
+
+    let memory_manager = MemoryManager::new();
+    let pipe_manager = PipeManager::new(memory_manager.clone());
+    let queue_manager = ai_os_kernel::ipc::QueueManager::new(memory_manager.clone());
+    let executor = SyscallExecutorWithIpc::with_ipc_direct(
+        sandbox_mgr,
+        pipe_manager.clone(),
+        ShmManager::new(memory_manager),
+    )
+    .with_queues(queue_manager.clone())
+    .build();
+
+    let would_block = |result: &SyscallResult| matches!(result, SyscallResult::Error { message, .. } if message.starts_with("Operation would block"));
+
+    // Pipes
+    let pipe_id = pipe_manager.create(test_pid, test_pid, None).unwrap();
+    let result = executor.execute(
+        test_pid,
+        Syscall::ReadPipe {
+            pipe_id,
+            size: 16,
+            nonblocking: true,
+        },
+    );
+    assert!(would_block(&result), "{:?}", result);
+
+    pipe_manager.write(pipe_id, test_pid, b"ready").unwrap();
+    let result = executor.execute(
+        test_pid,
+        Syscall::ReadPipe {
+            pipe_id,
+            size: 16,
+            nonblocking: true,
+        },
+    );
+    assert_eq!(result.data().map(Vec::as_slice), Some(&b"ready"[..]));
+
+    // Blocking reads still wait for data
+    pipe_manager.write(pipe_id, test_pid, b"later").unwrap();
+    let result = executor.execute(
+        test_pid,
+        Syscall::ReadPipe {
+            pipe_id,
+            size: 16,
+            nonblocking: false,
+        },
+    );
+    assert_eq!(result.data().map(Vec::as_slice), Some(&b"later"[..]));
+
+    // Queues
+    let queue_id = queue_manager
+        .create(test_pid, ai_os_kernel::ipc::QueueType::Fifo, None)
+        .unwrap();
+    let result = executor.execute(
+        test_pid,
+        Syscall::ReceiveQueue {
+            queue_id,
+            nonblocking: true,
+        },
+    );
+    assert!(would_block(&result), "{:?}", result);
+
+    queue_manager
+        .send(queue_id, test_pid, b"event".to_vec(), None)
+        .unwrap();
+    let result = executor.execute(
+        test_pid,
+        Syscall::ReceiveQueue {
+            queue_id,
+            nonblocking: true,
+        },
+    );
+    assert!(result.is_success(), "{:?}", result);
+}
+
+#[test]
+fn test_dead_letters_drain_through_syscalls() {
+    use ai_os_kernel::core::serialization::bincode;
+
+    #[derive(serde::Deserialize)]
+    struct Message {
+        id: u64,
+        from: u32,
+        data: Vec<u8>,
+        #[allow(dead_code)]
+        priority: u8,
+    }
+
+    fn decode<T: serde::de::DeserializeOwned>(result: &SyscallResult) -> T {
+        let data = bytes::Bytes::from(result.data().expect("message data").clone());
+        bincode::deserialize_ipc_message(&data).unwrap()
+    }
+
+    let (_executor, sandbox_mgr, _temp_dir) = create_test_executor();
+    let test_pid = 1000;
+
+    let memory_manager = MemoryManager::new();
+    let queue_manager = ai_os_kernel::ipc::QueueManager::new(memory_manager.clone());
+    let executor = SyscallExecutorWithIpc::with_ipc_direct(
+        sandbox_mgr,
+        PipeManager::new(memory_manager.clone()),
+        ShmManager::new(memory_manager),
+    )
+    .with_queues(queue_manager.clone())
+    .build();
+
+    let queue_id = queue_manager
+        .create(test_pid, ai_os_kernel::ipc::QueueType::AtLeastOnce, Some(1))
+        .unwrap();
+
+    // Only the owner may configure the queue
+    let set_policy = |pid| {
+        executor.execute(
+            pid,
+            Syscall::SetQueueAckPolicy {
+                queue_id,
+                visibility_timeout_ms: 10,
+                max_attempts: 1,
+            },
+        )
+    };
+    assert!(!set_policy(test_pid + 1).is_success());
+    assert!(set_policy(test_pid).is_success());
+
+    queue_manager
+        .send(queue_id, test_pid, b"poison".to_vec(), None)
+        .unwrap();
+    let result = executor.execute(
+        test_pid,
+        Syscall::ReceiveQueue {
+            queue_id,
+            nonblocking: true,
+        },
+    );
+    let received: Message = decode(&result);
+    std::thread::sleep(std::time::Duration::from_millis(20));
+
+    // The expired lease is dead-lettered and still holds the only slot
+    assert!(queue_manager
+        .send(queue_id, test_pid, b"blocked".to_vec(), None)
+        .is_err());
+
+    let result = executor.execute(test_pid, Syscall::TakeDeadLetters { queue_id });
+    let dead: Vec<Message> = decode(&result);
+    assert_eq!(dead.len(), 1);
+    assert_eq!(dead[0].id, received.id);
+    assert_eq!(dead[0].from, test_pid);
+    assert_eq!(dead[0].data, b"poison");
+
+    // Draining frees the slot
+    assert!(queue_manager
+        .send(queue_id, test_pid, b"next".to_vec(), None)
+        .is_ok());
+}
+
+#[test]
+fn test_nice_and_oom_score_clamp_to_sandbox_limits() {
+    use ai_os_kernel::permissions::{
+        Action, DefaultPolicy, EvaluationContext, PermissionRequest, Policy, PolicyDecision,
+        Resource,
+    };
+
+    /// Lets any process adjust any other; the default policy denies process writes
+    struct AllowProcessWrites;
+
+    impl Policy for AllowProcessWrites {
+        fn evaluate(&self, request: &PermissionRequest, _: &EvaluationContext) -> PolicyDecision {
+            match (&request.resource, &request.action) {
+                (Resource::Process { .. }, Action::Write) => PolicyDecision::Allow,
+                _ => PolicyDecision::Abstain,
+            }
+        }
+
+        fn name(&self) -> &str {
+            "allow_process_writes"
+        }
+    }
+
+    let sandbox_mgr = SandboxManager::new();
+    let test_pid = 1000;
+    sandbox_mgr.create_sandbox(SandboxConfig::privileged(test_pid));
+
+    let memory_manager = MemoryManager::new();
+    let process_manager = ProcessManager::new();
+    let executor = SyscallExecutorWithIpc::with_full_features(
+        sandbox_mgr.clone(),
+        PipeManager::new(memory_manager.clone()),
+        ShmManager::new(memory_manager.clone()),
+        process_manager.clone(),
+        memory_manager.clone(),
+    );
+    executor
+        .permission_manager()
+        .reload_policies(vec![Box::new(AllowProcessWrites), Box::new(DefaultPolicy)]);
+
+    let target = process_manager.create_process("worker".to_string(), 5);
+    sandbox_mgr.create_sandbox(SandboxConfig::minimal(target));
+    sandbox_mgr.set_priority_ceiling(target, 7).unwrap();
+    sandbox_mgr.set_oom_score_floor(target, -100).unwrap();
+
+    let get_nice = || {
+        let result = executor.execute(test_pid, Syscall::GetProcessNice { target_pid: target });
+        serde_json::from_slice::<serde_json::Value>(result.data().expect("nice data")).unwrap()
+    };
+    let set_nice = |nice| {
+        let result = executor.execute(
+            test_pid,
+            Syscall::SetProcessNice {
+                target_pid: target,
+                nice,
+            },
+        );
+        i8::from_le_bytes([result.data().expect("granted nice")[0]])
+    };
+    let set_oom_score = |score| {
+        let result = executor.execute(
+            test_pid,
+            Syscall::SetOomScore {
+                target_pid: target,
+                score,
+            },
+        );
+        let data = result.data().expect("granted score");
+        i16::from_le_bytes([data[0], data[1]])
+    };
+
+    assert_eq!(get_nice()["nice"], 0);
+
+    // Round-trips within the sandbox limits
+    assert_eq!(set_nice(3), 3);
+    assert_eq!(get_nice()["nice"], 3);
+    assert_eq!(get_nice()["priority"], 2);
+    assert_eq!(set_oom_score(400), 400);
+    assert_eq!(memory_manager.oom_score_adj(target), 400);
+
+    // Ceiling 7 is nice -2; floor -100 bounds the OOM adjustment
+    assert_eq!(set_nice(-5), -2);
+    assert_eq!(process_manager.get_process(target).unwrap().priority, 7);
+    assert_eq!(set_oom_score(-1000), -100);
+    assert_eq!(get_nice()["oom_score_adj"], -100);
+
+    let result = executor.execute(test_pid, Syscall::GetProcessInfo { target_pid: target });
+    let info: serde_json::Value = serde_json::from_slice(result.data().unwrap()).unwrap();
+    assert_eq!(info["pid"], target);
+    assert_eq!(info["nice"], -2);
+    assert_eq!(info["oom_score_adj"], -100);
+}
+
+#[test]
+fn test_get_random() {
+    use ai_os_kernel::syscalls::{BandwidthLimit, GRND_NONBLOCK};
+    use ai_os_kernel::MetricsCollector;
+    use std::sync::Arc;
+
+    let (executor, _sandbox_mgr, _temp_dir) = create_test_executor();
+    let metrics = Arc::new(MetricsCollector::new());
+    let executor = executor
+        .with_entropy_limit(BandwidthLimit::new(1000).with_burst(256))
+        .with_metrics(metrics.clone());
+    let test_pid = 1000;
+
+    let get_random =
+        |len: usize, flags: u32| executor.execute(test_pid, Syscall::GetRandom { len, flags });
+
+    let first = get_random(128, 0);
+    let bytes = first.data().unwrap().to_vec();
+    assert_eq!(bytes.len(), 128);
+    // 128 random bytes are never all the same value
+    assert!(bytes.iter().any(|&b| b != bytes[0]));
+    let second = get_random(128, GRND_NONBLOCK);
+    assert_ne!(second.data().unwrap(), &bytes[..]);
+
+    // Budget spent: nonblocking fails fast, blocking waits for the refill
+    let result = get_random(64, GRND_NONBLOCK);
+    assert!(
+        matches!(&result, SyscallResult::Error { message, .. } if message.starts_with("Operation would block")),
+        "{:?}",
+        result
+    );
+    let start = std::time::Instant::now();
+    assert_eq!(get_random(50, 0).data().unwrap().len(), 50);
+    assert!(start.elapsed() >= std::time::Duration::from_millis(40));
+
+    assert!(get_random(16, 0x80).is_error());
+    assert!(get_random(usize::MAX, GRND_NONBLOCK).is_error());
+
+    let dispensed = metrics.snapshot().counters["entropy.bytes_dispensed"];
+    assert_eq!(dispensed, 306.0);
+}
+
+#[test]
+fn test_slow_syscall_arg_capture() {
+    use ai_os_kernel::monitoring::{Collector, Payload};
+    use std::sync::Arc;
+    use std::time::Duration;
+
+    let slow_args = |threshold: Duration| {
+        let (executor, _sandbox_mgr, temp_dir) = create_test_executor();
+        let collector = Arc::new(Collector::new());
+        let executor = executor
+            .with_collector(collector.clone())
+            .with_slow_syscall_capture(threshold);
+        let path = temp_dir.path().join("capture.bin");
+
+        let result = executor.execute(
+            1000,
+            Syscall::WriteFile {
+                path: path.clone(),
+                data: vec![0x5A; 2048],
+            },
+        );
+        assert!(result.is_success(), "{:?}", result);
+
+        let mut sub = collector.subscribe();
+        let captured: Vec<_> = std::iter::from_fn(|| sub.next())
+            .filter_map(|event| match event.payload {
+                Payload::SyscallSlow { name, args, .. } => Some((name, args)),
+                _ => None,
+            })
+            .collect();
+        (captured, path)
+    };
+
+    // Every call exceeds a zero threshold: arguments are captured, data by length only
+    let (captured, path) = slow_args(Duration::ZERO);
+    assert_eq!(captured.len(), 1);
+    let (name, args) = &captured[0];
+    assert_eq!(name.as_str(), "write_file");
+    let args = args.as_ref().expect("slow call should capture arguments");
+    assert_eq!(*args, format!("data=<2048 bytes>, path={}", path.display()));
+
+    // A fast call never emits a slow event or builds a snapshot
+    let (captured, _) = slow_args(Duration::from_secs(60));
+    assert!(captured.is_empty(), "{:?}", captured);
+}
@@ -316,6 +316,7 @@ fn test_move_file() {
         Syscall::MoveFile {
             source: source.clone(),
             destination: destination.clone(),
+            noreplace: false,
         },
     );
 
@@ -471,7 +472,7 @@ fn test_invalid_command_injection_prevention() {
     );
 
     match result {
-        SyscallResult::Error { message } => {
+        SyscallResult::Error { message, .. } => {
             assert!(message.contains("shell metacharacters"));
         }
         _ => panic!("Expected error for shell injection, got: {:?}", result),
@@ -486,6 +487,7 @@ fn test_network_request_no_capability() {
         pid,
         Syscall::NetworkRequest {
             url: "https://example.com".to_string(),
+            retry: None,
         },
     );
 
@@ -0,0 +1,9 @@
+/*!
+ * Trace Filter Tests Entry Point
+ *
+ * Kept in its own binary: the pid filter is global and would hide syscall
+ * events from the other observability tests running alongside it.
+ */
+
+#[path = "monitoring/trace_filter_test.rs"]
+mod trace_filter_test;
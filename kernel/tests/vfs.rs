@@ -10,3 +10,9 @@
 
 #[path = "vfs/vfs_fd_integration_test.rs"]
 mod vfs_fd_integration_test;
+
+#[path = "vfs/watch_test.rs"]
+mod watch_test;
+
+#[path = "vfs/lock_test.rs"]
+mod lock_test;
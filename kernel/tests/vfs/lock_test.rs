@@ -0,0 +1,125 @@
+/*!
+ * VFS Range Lock Tests
+ * fcntl record locking through the syscall layer
+ */
+
+use ai_os_kernel::core::types::Pid;
+use ai_os_kernel::process::resources::{FileLockResource, ResourceCleanup};
+use ai_os_kernel::security::{SandboxConfig, SandboxManager, SandboxProvider};
+use ai_os_kernel::syscalls::{FileLock, Syscall, SyscallExecutorWithIpc, SyscallResult};
+use ai_os_kernel::vfs::{FileSystem, MemFS, MountManager};
+use std::path::PathBuf;
+use std::sync::Arc;
+
+const F_GETLK: u32 = 5;
+const F_SETLK: u32 = 6;
+
+fn setup() -> (SyscallExecutorWithIpc, MountManager) {
+    let sandbox = SandboxManager::new();
+    for pid in [1, 2] {
+        let mut config = SandboxConfig::standard(pid);
+        config.allowed_paths.push(PathBuf::from("/mem"));
+        sandbox.create_sandbox(config);
+    }
+
+    let vfs = MountManager::new();
+    vfs.mount("/mem", Arc::new(MemFS::new())).unwrap();
+    vfs.write(&PathBuf::from("/mem/shared.db"), b"0123456789")
+        .unwrap();
+
+    let memory_manager = ai_os_kernel::memory::MemoryManager::new();
+    let pipe_manager = ai_os_kernel::ipc::PipeManager::new(memory_manager.clone());
+    let shm_manager = ai_os_kernel::ipc::ShmManager::new(memory_manager.clone());
+    let executor = SyscallExecutorWithIpc::with_ipc_direct(sandbox, pipe_manager, shm_manager)
+        .with_vfs(vfs.clone())
+        .build();
+    (executor, vfs)
+}
+
+fn open(executor: &SyscallExecutorWithIpc, pid: Pid) -> u32 {
+    let result = executor.execute(
+        pid,
+        Syscall::Open {
+            path: PathBuf::from("/mem/shared.db"),
+            flags: 0x0002, // O_RDWR
+            mode: 0,
+        },
+    );
+    let data: serde_json::Value = serde_json::from_slice(result.data().unwrap()).unwrap();
+    data["fd"].as_u64().unwrap() as u32
+}
+
+fn set_lock(
+    executor: &SyscallExecutorWithIpc,
+    pid: Pid,
+    fd: u32,
+    lock_type: u32,
+    start: u64,
+    len: u64,
+) -> SyscallResult {
+    executor.execute(
+        pid,
+        Syscall::Fcntl {
+            fd,
+            cmd: F_SETLK,
+            arg: 0,
+            lock: Some(FileLock {
+                lock_type,
+                start,
+                len,
+            }),
+        },
+    )
+}
+
+#[test]
+fn test_fcntl_lock_conflicts() {
+    let (executor, _vfs) = setup();
+    let fd1 = open(&executor, 1);
+    let fd2 = open(&executor, 2);
+
+    // Shared locks coexist
+    assert!(set_lock(&executor, 1, fd1, FileLock::F_RDLCK, 0, 5).is_success());
+    assert!(set_lock(&executor, 2, fd2, FileLock::F_RDLCK, 0, 5).is_success());
+
+    // Exclusive conflicts with the other process's shared lock
+    assert!(!set_lock(&executor, 2, fd2, FileLock::F_WRLCK, 0, 0).is_success());
+    assert!(set_lock(&executor, 1, fd1, FileLock::F_UNLCK, 0, 0).is_success());
+    assert!(set_lock(&executor, 2, fd2, FileLock::F_WRLCK, 0, 0).is_success());
+
+    // F_GETLK reports the holder
+    let result = executor.execute(
+        1,
+        Syscall::Fcntl {
+            fd: fd1,
+            cmd: F_GETLK,
+            arg: 0,
+            lock: Some(FileLock {
+                lock_type: FileLock::F_RDLCK,
+                start: 3,
+                len: 1,
+            }),
+        },
+    );
+    let data: serde_json::Value = serde_json::from_slice(result.data().unwrap()).unwrap();
+    assert_eq!(data["lock"]["lock_type"], FileLock::F_WRLCK);
+    assert_eq!(data["lock"]["pid"], 2);
+    assert_eq!(data["lock"]["len"], 0);
+}
+
+#[test]
+fn test_locks_released_on_process_cleanup() {
+    let (executor, vfs) = setup();
+    let fd1 = open(&executor, 1);
+    let fd2 = open(&executor, 2);
+
+    assert!(set_lock(&executor, 1, fd1, FileLock::F_WRLCK, 0, 0).is_success());
+    assert!(!set_lock(&executor, 2, fd2, FileLock::F_RDLCK, 0, 0).is_success());
+
+    let resource = FileLockResource::new(vfs.lock_table().clone());
+    assert!(resource.has_resources(1));
+    assert_eq!(resource.cleanup(1).resources_freed, 1);
+    assert!(!resource.has_resources(1));
+
+    assert!(set_lock(&executor, 2, fd2, FileLock::F_RDLCK, 0, 0).is_success());
+}
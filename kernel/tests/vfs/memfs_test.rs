@@ -71,3 +71,63 @@ fn test_path_normalization() {
     assert!(fs.exists(Path::new("/test.txt")));
     assert!(fs.exists(Path::new("//test.txt")));
 }
+
+#[test]
+fn test_snapshot_restore() {
+    let fs = MemFS::with_capacity(32);
+    fs.create_dir(Path::new("/data")).unwrap();
+    fs.write(Path::new("/data/a.txt"), b"alpha").unwrap();
+    fs.write(Path::new("/data/b.txt"), b"bravo").unwrap();
+    fs.write(Path::new("/keep.txt"), b"keep").unwrap();
+
+    let snapshot = fs.snapshot();
+    assert_eq!(snapshot.size(), 14);
+
+    // In-place writes must not leak into the snapshot
+    fs.append(Path::new("/keep.txt"), b"-changed").unwrap();
+    fs.remove_dir_all(Path::new("/data")).unwrap();
+    fs.write(Path::new("/new.txt"), b"new").unwrap();
+    assert!(!fs.exists(Path::new("/data/a.txt")));
+
+    fs.restore(&snapshot);
+    assert_eq!(fs.read(Path::new("/data/a.txt")).unwrap(), b"alpha");
+    assert_eq!(fs.read(Path::new("/data/b.txt")).unwrap(), b"bravo");
+    assert_eq!(fs.read(Path::new("/keep.txt")).unwrap(), b"keep");
+    assert!(!fs.exists(Path::new("/new.txt")));
+    assert_eq!(fs.list_dir(Path::new("/data")).unwrap().len(), 2);
+
+    // Capacity accounting is back to 14 of 32 bytes
+    fs.write(Path::new("/fill.txt"), &[0u8; 18]).unwrap();
+    assert!(matches!(
+        fs.append(Path::new("/fill.txt"), b"x"),
+        Err(VfsError::OutOfSpace)
+    ));
+
+    // The snapshot is still intact for another rollback
+    fs.restore(&snapshot);
+    assert!(!fs.exists(Path::new("/fill.txt")));
+    assert_eq!(fs.read(Path::new("/keep.txt")).unwrap(), b"keep");
+}
+
+#[test]
+fn test_vectored_io() {
+    let fs = MemFS::new();
+
+    let written = fs
+        .write_vectored(Path::new("/v.bin"), &[b"head", b"", b"-body-", b"tail"])
+        .unwrap();
+    assert_eq!(written, 14);
+    assert_eq!(fs.read(Path::new("/v.bin")).unwrap(), b"head-body-tail");
+
+    let segments = fs.read_vectored(Path::new("/v.bin"), &[4, 6, 8]).unwrap();
+    assert_eq!(
+        segments,
+        vec![b"head".to_vec(), b"-body-".to_vec(), b"tail".to_vec()]
+    );
+
+    fs.create_dir(Path::new("/dir")).unwrap();
+    assert!(matches!(
+        fs.read_vectored(Path::new("/dir"), &[1]),
+        Err(VfsError::IsADirectory(_))
+    ));
+}
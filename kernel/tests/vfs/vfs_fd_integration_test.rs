@@ -6,9 +6,10 @@
 use ai_os_kernel::core::types::Pid;
 use ai_os_kernel::security::{SandboxConfig, SandboxManager, SandboxProvider};
 use ai_os_kernel::syscalls::{Syscall, SyscallExecutorWithIpc};
-use ai_os_kernel::vfs::{FileSystem, MemFS, MountManager};
+use ai_os_kernel::vfs::{FileSystem, LocalFS, MemFS, MountFlags, MountManager, MountPoint};
 use std::path::PathBuf;
 use std::sync::Arc;
+use tempfile::TempDir;
 
 /// Helper to create a sandbox with a configured PID
 fn setup_sandbox(pid: Pid) -> SandboxManager {
@@ -20,6 +21,7 @@ fn setup_sandbox(pid: Pid) -> SandboxManager {
     config.allowed_paths.push(PathBuf::from("/data"));
     config.allowed_paths.push(PathBuf::from("/data1"));
     config.allowed_paths.push(PathBuf::from("/data2"));
+    config.allowed_paths.push(PathBuf::from("/storage"));
 
     sandbox.create_sandbox(config);
     sandbox
@@ -350,6 +352,7 @@ fn test_fcntl_with_vfs() {
             fd,
             cmd: 1, // F_GETFD
             arg: 0,
+            lock: None,
         },
     );
     assert!(fcntl_result.is_success());
@@ -357,3 +360,147 @@ fn test_fcntl_with_vfs() {
     // Close
     executor.execute(pid, Syscall::Close { fd });
 }
+
+/// Test that syscalls cannot write into a read-only mount
+#[test]
+fn test_readonly_mount_rejects_write_syscalls() {
+    let pid: Pid = 1;
+    let sandbox = setup_sandbox(pid);
+
+    let storage = Arc::new(MemFS::new());
+    storage
+        .write(&PathBuf::from("/config.json"), b"{}")
+        .unwrap();
+
+    let vfs = MountManager::new();
+    vfs.mount_with_flags("/storage", storage.clone(), MountFlags::READ_ONLY)
+        .unwrap();
+    assert!(vfs
+        .mount_point("/storage/config.json")
+        .unwrap()
+        .is_readonly());
+
+    let memory_manager = ai_os_kernel::memory::MemoryManager::new();
+    let pipe_manager = ai_os_kernel::ipc::PipeManager::new(memory_manager.clone());
+    let shm_manager = ai_os_kernel::ipc::ShmManager::new(memory_manager.clone());
+    let executor = SyscallExecutorWithIpc::with_ipc_direct(sandbox, pipe_manager, shm_manager)
+        .with_vfs(vfs)
+        .build();
+
+    // Reads succeed
+    let result = executor.execute(
+        pid,
+        Syscall::ReadFile {
+            path: PathBuf::from("/storage/config.json"),
+        },
+    );
+    assert!(result.is_success(), "Read should succeed: {:?}", result);
+    assert_eq!(result.data().unwrap(), b"{}");
+
+    // Writes, creates and deletes fail without falling back to the host filesystem
+    let result = executor.execute(
+        pid,
+        Syscall::WriteFile {
+            path: PathBuf::from("/storage/config.json"),
+            data: b"{\"tampered\": true}".to_vec(),
+        },
+    );
+    assert!(!result.is_success(), "Write should fail: {:?}", result);
+
+    let result = executor.execute(
+        pid,
+        Syscall::CreateFile {
+            path: PathBuf::from("/storage/new.txt"),
+        },
+    );
+    assert!(!result.is_success(), "Create should fail: {:?}", result);
+
+    let result = executor.execute(
+        pid,
+        Syscall::DeleteFile {
+            path: PathBuf::from("/storage/config.json"),
+        },
+    );
+    assert!(!result.is_success(), "Delete should fail: {:?}", result);
+
+    let result = executor.execute(
+        pid,
+        Syscall::CreateDirectory {
+            path: PathBuf::from("/storage/subdir"),
+        },
+    );
+    assert!(!result.is_success(), "Mkdir should fail: {:?}", result);
+
+    let result = executor.execute(
+        pid,
+        Syscall::Open {
+            path: PathBuf::from("/storage/config.json"),
+            flags: 0x0002, // O_WRONLY
+            mode: 0,
+        },
+    );
+    assert!(!result.is_success(), "Write open should fail: {:?}", result);
+
+    // Contents are untouched
+    assert_eq!(storage.read(&PathBuf::from("/config.json")).unwrap(), b"{}");
+    assert!(!storage.exists(&PathBuf::from("/new.txt")));
+    assert!(!PathBuf::from("/storage/new.txt").exists());
+}
+
+#[test]
+fn test_list_mounts_reflects_live_table() {
+    let pid: Pid = 1;
+    let sandbox = setup_sandbox(pid);
+
+    let mem = Arc::new(MemFS::new());
+    mem.write(&PathBuf::from("/notes.txt"), b"0123456789")
+        .unwrap();
+
+    let vfs = MountManager::new();
+    vfs.mount_from_config(&MountPoint::new("/data", "memory").with_quota(1024), mem)
+        .unwrap();
+
+    let memory_manager = ai_os_kernel::memory::MemoryManager::new();
+    let pipe_manager = ai_os_kernel::ipc::PipeManager::new(memory_manager.clone());
+    let shm_manager = ai_os_kernel::ipc::ShmManager::new(memory_manager.clone());
+    let executor = SyscallExecutorWithIpc::with_ipc_direct(sandbox, pipe_manager, shm_manager)
+        .with_vfs(vfs.clone());
+
+    // Mounted after the executor was built
+    let temp = TempDir::new().unwrap();
+    std::fs::write(temp.path().join("a.bin"), [0u8; 4]).unwrap();
+    vfs.mount_with_flags(
+        "/storage",
+        Arc::new(LocalFS::new(temp.path())),
+        MountFlags::READ_ONLY | MountFlags::NO_EXEC,
+    )
+    .unwrap();
+
+    let result = executor.execute(pid, Syscall::ListMounts);
+    assert!(
+        result.is_success(),
+        "ListMounts should succeed: {:?}",
+        result
+    );
+    let mounts: serde_json::Value = serde_json::from_slice(result.data().unwrap()).unwrap();
+
+    assert_eq!(
+        mounts,
+        serde_json::json!([
+            {
+                "path": "/data",
+                "fs_type": "memory",
+                "flags": ["rw"],
+                "quota": 1024,
+                "used": 10,
+            },
+            {
+                "path": "/storage",
+                "fs_type": "local",
+                "flags": ["ro", "noexec"],
+                "quota": null,
+                "used": null,
+            },
+        ])
+    );
+}
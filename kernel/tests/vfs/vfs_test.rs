@@ -383,6 +383,59 @@ fn test_memfs_append() {
     assert_eq!(fs.read(Path::new("/file.txt")).unwrap(), b"hello world");
 }
 
+/// Append fixed-size records from many threads and check none were torn
+fn assert_concurrent_appends_intact(fs: Arc<dyn FileSystem>, path: &'static str) {
+    const THREADS: usize = 8;
+    const RECORDS: usize = 200;
+    const RECORD_LEN: usize = 16;
+
+    let handles: Vec<_> = (0..THREADS)
+        .map(|thread| {
+            let fs = Arc::clone(&fs);
+            std::thread::spawn(move || {
+                let record = format!("t{:02}-{:>11}\n", thread, "x");
+                assert_eq!(record.len(), RECORD_LEN);
+                (0..RECORDS)
+                    .map(|_| fs.append(Path::new(path), record.as_bytes()).unwrap())
+                    .collect::<Vec<u64>>()
+            })
+        })
+        .collect();
+
+    let mut lengths: Vec<u64> = handles
+        .into_iter()
+        .flat_map(|handle| handle.join().unwrap())
+        .collect();
+
+    // Every append saw a distinct end of file on a record boundary
+    lengths.sort_unstable();
+    lengths.dedup();
+    assert_eq!(lengths.len(), THREADS * RECORDS);
+    assert!(lengths.iter().all(|len| *len as usize % RECORD_LEN == 0));
+
+    let data = fs.read(Path::new(path)).unwrap();
+    assert_eq!(data.len(), THREADS * RECORDS * RECORD_LEN);
+    let mut per_thread = [0usize; THREADS];
+    for record in data.chunks(RECORD_LEN) {
+        let record = std::str::from_utf8(record).unwrap();
+        assert!(record.ends_with("x\n"), "torn record {:?}", record);
+        let thread: usize = record[1..3].parse().unwrap();
+        per_thread[thread] += 1;
+    }
+    assert!(per_thread.iter().all(|count| *count == RECORDS));
+}
+
+#[test]
+fn test_memfs_concurrent_append() {
+    assert_concurrent_appends_intact(Arc::new(MemFS::new()), "/shared.log");
+}
+
+#[test]
+fn test_localfs_concurrent_append() {
+    let temp = TempDir::new().unwrap();
+    assert_concurrent_appends_intact(Arc::new(LocalFS::new(temp.path())), "shared.log");
+}
+
 #[test]
 fn test_mount_duplicate_error() {
     let mgr = MountManager::new();
@@ -1030,3 +1083,54 @@ fn test_open_flags_respect_file_permissions() {
         "Should be able to open readonly file for reading"
     );
 }
+
+/// Race `RACERS` threads renaming their own file onto one path without replace
+fn race_rename_noreplace(fs: Arc<dyn FileSystem>) {
+    use ai_os_kernel::vfs::VfsError;
+    use std::sync::Barrier;
+
+    const RACERS: usize = 16;
+    for i in 0..RACERS {
+        fs.write(Path::new(&format!("/claim_{}", i)), &[i as u8])
+            .unwrap();
+    }
+
+    let barrier = Arc::new(Barrier::new(RACERS));
+    let handles: Vec<_> = (0..RACERS)
+        .map(|i| {
+            let fs = fs.clone();
+            let barrier = barrier.clone();
+            std::thread::spawn(move || {
+                barrier.wait();
+                fs.rename_noreplace(Path::new(&format!("/claim_{}", i)), Path::new("/lock"))
+                    .map(|()| i)
+            })
+        })
+        .collect();
+
+    let mut winners = Vec::new();
+    for handle in handles {
+        match handle.join().unwrap() {
+            Ok(i) => winners.push(i),
+            Err(e) => assert!(matches!(e, VfsError::AlreadyExists(_)), "{:?}", e),
+        }
+    }
+
+    assert_eq!(winners.len(), 1, "exactly one rename should win");
+    let winner = winners[0];
+    assert_eq!(fs.read(Path::new("/lock")).unwrap(), vec![winner as u8]);
+    for i in 0..RACERS {
+        assert_eq!(fs.exists(Path::new(&format!("/claim_{}", i))), i != winner);
+    }
+}
+
+#[test]
+fn test_memfs_rename_noreplace_single_winner() {
+    race_rename_noreplace(Arc::new(MemFS::new()));
+}
+
+#[test]
+fn test_localfs_rename_noreplace_single_winner() {
+    let temp = TempDir::new().unwrap();
+    race_rename_noreplace(Arc::new(LocalFS::new(temp.path())));
+}
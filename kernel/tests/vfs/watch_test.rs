@@ -0,0 +1,95 @@
+/*!
+ * Recursive Watch Tests
+ * Tests for watch streams over ObservableFS
+ */
+
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use ai_os_kernel::vfs::{FileEvent, FileSystem, MemFS, ObservableFS, WatchConfig};
+use futures::StreamExt;
+
+/// Collect events until the stream is quiet for `idle`
+async fn collect_events(
+    stream: impl futures::Stream<Item = FileEvent>,
+    idle: Duration,
+) -> Vec<FileEvent> {
+    tokio::pin!(stream);
+    let mut events = Vec::new();
+    while let Ok(Some(event)) = tokio::time::timeout(idle, stream.next()).await {
+        events.push(event);
+    }
+    events
+}
+
+#[tokio::test]
+async fn test_watch_follows_new_subdirectories() {
+    let fs = ObservableFS::new(MemFS::new());
+    fs.create_dir(Path::new("/project")).unwrap();
+
+    let events = fs.watch_recursive_with(
+        "/project",
+        WatchConfig {
+            debounce: Duration::from_millis(20),
+        },
+    );
+
+    fs.create_dir(Path::new("/project/src")).unwrap();
+    for i in 0..5 {
+        fs.write(
+            Path::new("/project/src/main.rs"),
+            format!("v{}", i).as_bytes(),
+        )
+        .unwrap();
+    }
+    fs.write(Path::new("/elsewhere.txt"), b"ignored").unwrap();
+
+    let events = collect_events(events, Duration::from_millis(200)).await;
+    assert_eq!(
+        events,
+        vec![
+            FileEvent::Created {
+                path: PathBuf::from("/project/src"),
+            },
+            FileEvent::Created {
+                path: PathBuf::from("/project/src/main.rs"),
+            },
+        ]
+    );
+}
+
+#[tokio::test]
+async fn test_watch_includes_existing_tree() {
+    let fs = ObservableFS::new(MemFS::new());
+    fs.create_dir(Path::new("/data")).unwrap();
+    fs.create_dir(Path::new("/data/nested")).unwrap();
+    fs.create_dir(Path::new("/data/nested/deep")).unwrap();
+
+    let events = fs.watch_recursive_with(
+        "/data",
+        WatchConfig {
+            debounce: Duration::ZERO,
+        },
+    );
+
+    fs.write(Path::new("/data/nested/deep/a.txt"), b"a")
+        .unwrap();
+    fs.remove_dir_all(Path::new("/data/nested")).unwrap();
+    fs.create_dir(Path::new("/data/nested")).unwrap();
+
+    let events = collect_events(events, Duration::from_millis(100)).await;
+    assert_eq!(
+        events,
+        vec![
+            FileEvent::Created {
+                path: PathBuf::from("/data/nested/deep/a.txt"),
+            },
+            FileEvent::Deleted {
+                path: PathBuf::from("/data/nested"),
+            },
+            FileEvent::Created {
+                path: PathBuf::from("/data/nested"),
+            },
+        ]
+    );
+}